@@ -1648,6 +1648,7 @@ pub enum TurnPlanStepStatus {
     Pending,
     InProgress,
     Completed,
+    Failed,
 }
 
 impl From<CorePlanItemArg> for TurnPlanStep {
@@ -1665,6 +1666,7 @@ impl From<CorePlanStepStatus> for TurnPlanStepStatus {
             CorePlanStepStatus::Pending => Self::Pending,
             CorePlanStepStatus::InProgress => Self::InProgress,
             CorePlanStepStatus::Completed => Self::Completed,
+            CorePlanStepStatus::Failed => Self::Failed,
         }
     }
 }
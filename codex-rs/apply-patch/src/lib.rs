@@ -81,6 +81,9 @@ pub enum ApplyPatchError {
         "patch detected without explicit call to apply_patch. Rerun as [\"apply_patch\", \"<patch>\"]"
     )]
     ImplicitInvocation,
+    /// At least one file could not be patched; the report says which.
+    #[error("{}", .0.failure_message())]
+    NotApplied(PatchApplyReport),
 }
 
 impl From<std::io::Error> for ApplyPatchError {
@@ -505,11 +508,28 @@ pub enum ExtractHeredocError {
 }
 
 /// Applies the patch and prints the result to stdout/stderr.
+///
+/// Application is all-or-nothing; see [`PatchApplyReport`]. When a file
+/// cannot be patched the error is [`ApplyPatchError::NotApplied`], which
+/// carries the report.
 pub fn apply_patch(
     patch: &str,
     stdout: &mut impl std::io::Write,
     stderr: &mut impl std::io::Write,
-) -> Result<(), ApplyPatchError> {
+) -> Result<PatchApplyReport, ApplyPatchError> {
+    apply_patch_with_fuzz(patch, false, stdout, stderr)
+}
+
+/// Like [`apply_patch`], but when `fuzz` is true and a chunk's context cannot
+/// be found, fall back to a fuzzy match that ignores whitespace differences
+/// and tolerates a few character edits per line. Each chunk placed that way
+/// is listed in [`PatchApplyReport::warnings`] and also written to `stderr`.
+pub fn apply_patch_with_fuzz(
+    patch: &str,
+    fuzz: bool,
+    stdout: &mut impl std::io::Write,
+    stderr: &mut impl std::io::Write,
+) -> Result<PatchApplyReport, ApplyPatchError> {
     let hunks = match parse_patch(patch) {
         Ok(source) => source.hunks,
         Err(e) => {
@@ -532,9 +552,7 @@ pub fn apply_patch(
         }
     };
 
-    apply_hunks(&hunks, stdout, stderr)?;
-
-    Ok(())
+    apply_hunks_with_fuzz(&hunks, fuzz, stdout, stderr)
 }
 
 /// Applies hunks and continues to update stdout/stderr
@@ -542,7 +560,16 @@ pub fn apply_hunks(
     hunks: &[Hunk],
     stdout: &mut impl std::io::Write,
     stderr: &mut impl std::io::Write,
-) -> Result<(), ApplyPatchError> {
+) -> Result<PatchApplyReport, ApplyPatchError> {
+    apply_hunks_with_fuzz(hunks, false, stdout, stderr)
+}
+
+fn apply_hunks_with_fuzz(
+    hunks: &[Hunk],
+    fuzz: bool,
+    stdout: &mut impl std::io::Write,
+    stderr: &mut impl std::io::Write,
+) -> Result<PatchApplyReport, ApplyPatchError> {
     let _existing_paths: Vec<&Path> = hunks
         .iter()
         .filter_map(|hunk| match hunk {
@@ -569,25 +596,95 @@ pub fn apply_hunks(
         })
         .collect::<Vec<&Path>>();
 
+    if hunks.is_empty() {
+        let msg = "No files were modified.";
+        writeln!(stderr, "{msg}").map_err(ApplyPatchError::from)?;
+        return Err(ApplyPatchError::IoError(IoError {
+            context: msg.to_string(),
+            source: std::io::Error::other(msg),
+        }));
+    }
+
     // Delegate to a helper that applies each hunk to the filesystem.
-    match apply_hunks_to_files(hunks) {
-        Ok(affected) => {
-            print_summary(&affected, stdout).map_err(ApplyPatchError::from)?;
-            Ok(())
-        }
-        Err(err) => {
-            let msg = err.to_string();
-            writeln!(stderr, "{msg}").map_err(ApplyPatchError::from)?;
-            if let Some(io) = err.downcast_ref::<std::io::Error>() {
-                Err(ApplyPatchError::from(io))
-            } else {
-                Err(ApplyPatchError::IoError(IoError {
-                    context: msg,
-                    source: std::io::Error::other(err),
-                }))
-            }
-        }
+    let (affected, report) = apply_hunks_to_files(hunks, fuzz);
+    for warning in &report.warnings {
+        writeln!(stderr, "Warning: {warning}").map_err(ApplyPatchError::from)?;
+    }
+    if !report.failed.is_empty() {
+        writeln!(stderr, "{}", report.failure_message()).map_err(ApplyPatchError::from)?;
+        return Err(ApplyPatchError::NotApplied(report));
     }
+    print_summary(&affected, stdout).map_err(ApplyPatchError::from)?;
+    Ok(report)
+}
+
+/// A file operation that applying a patch would perform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlannedChange {
+    Add {
+        path: PathBuf,
+        /// Number of lines in the new file.
+        lines: usize,
+    },
+    Delete {
+        path: PathBuf,
+    },
+    Update {
+        path: PathBuf,
+        move_path: Option<PathBuf>,
+        hunks: Vec<HunkSummary>,
+    },
+}
+
+/// Where a single update chunk lands and how many lines it touches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HunkSummary {
+    /// 1-based line in the original file where the replacement starts.
+    pub start_line: usize,
+    pub removed: usize,
+    pub added: usize,
+}
+
+/// Parse and validate `patch` against the filesystem without modifying it.
+///
+/// Returns the file operations [`apply_patch`] would perform, or the same
+/// error it would hit: a malformed patch, or
+/// [`ApplyPatchError::NotApplied`] for a file to update or delete that does
+/// not exist or context lines that cannot be found. Both share the same
+/// planning step, so a dry run that succeeds describes exactly what applying
+/// the patch writes.
+pub fn apply_patch_dry_run(patch: &str) -> Result<Vec<PlannedChange>, ApplyPatchError> {
+    let hunks = parse_patch(patch)?.hunks;
+    let mut report = PatchApplyReport::default();
+    let plan = match plan_writes(&hunks, false, &mut report.warnings) {
+        Ok(plan) => plan,
+        Err(failure) => {
+            report.failed.push(failure);
+            return Err(ApplyPatchError::NotApplied(report));
+        }
+    };
+    Ok(plan
+        .into_iter()
+        .map(|write| match write {
+            PlannedWrite::Add { path, contents } => PlannedChange::Add {
+                path: path.to_path_buf(),
+                lines: contents.lines().count(),
+            },
+            PlannedWrite::Delete { path } => PlannedChange::Delete {
+                path: path.to_path_buf(),
+            },
+            PlannedWrite::Update {
+                path,
+                move_path,
+                hunks,
+                ..
+            } => PlannedChange::Update {
+                path: path.to_path_buf(),
+                move_path: move_path.map(Path::to_path_buf),
+                hunks,
+            },
+        })
+        .collect())
 }
 
 /// Applies each parsed patch hunk to the filesystem.
@@ -599,73 +696,245 @@ pub struct AffectedPaths {
     pub deleted: Vec<PathBuf>,
 }
 
-/// Apply the hunks to the filesystem, returning which files were added, modified, or deleted.
-/// Returns an error if the patch could not be applied.
-fn apply_hunks_to_files(hunks: &[Hunk]) -> anyhow::Result<AffectedPaths> {
-    if hunks.is_empty() {
-        anyhow::bail!("No files were modified.");
+/// A chunk whose context was only found by fuzzy matching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzWarning {
+    pub path: PathBuf,
+    /// 1-based line where the chunk's context was matched.
+    pub line: usize,
+    /// Lines between where the context was searched from and where it matched.
+    pub offset: usize,
+    /// Character edits needed to match the context, ignoring whitespace.
+    pub edits: usize,
+}
+
+impl std::fmt::Display for FuzzWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "fuzzy context match in {} at line {} (offset {}, ",
+            self.path.display(),
+            self.line,
+            self.offset
+        )?;
+        if self.edits == 0 {
+            write!(f, "whitespace differences only)")
+        } else {
+            write!(f, "{} character edits)", self.edits)
+        }
+    }
+}
+
+/// Per-file outcome of applying a patch.
+///
+/// Application is all-or-nothing: every hunk is validated and its new
+/// contents computed before anything is written. If any file fails that
+/// check, nothing is written, `applied` is empty and `failed` holds the first
+/// file that could not be patched with the reason. Only an I/O error while
+/// writing, after validation succeeded, can leave earlier files written;
+/// those stay listed in `applied`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PatchApplyReport {
+    /// Files written, in patch order (the destination for moved files).
+    pub applied: Vec<PathBuf>,
+    pub failed: Vec<(PathBuf, String)>,
+    /// Chunks placed by fuzzy matching, see [`apply_patch_with_fuzz`].
+    pub warnings: Vec<FuzzWarning>,
+}
+
+impl PatchApplyReport {
+    fn failure_message(&self) -> String {
+        match self.failed.first() {
+            Some((_, reason)) => reason.clone(),
+            None => "patch was not applied".to_string(),
+        }
+    }
+}
+
+/// A validated file write waiting to be performed.
+enum PlannedWrite<'a> {
+    Add {
+        path: &'a Path,
+        contents: &'a str,
+    },
+    Delete {
+        path: &'a Path,
+    },
+    Update {
+        path: &'a Path,
+        move_path: Option<&'a Path>,
+        new_contents: String,
+        hunks: Vec<HunkSummary>,
+    },
+}
+
+/// Apply the hunks to the filesystem, returning which files were added, modified, or deleted
+/// along with the per-file report. Nothing is written unless every hunk validates.
+fn apply_hunks_to_files(hunks: &[Hunk], fuzz: bool) -> (AffectedPaths, PatchApplyReport) {
+    let mut affected = AffectedPaths {
+        added: Vec::new(),
+        modified: Vec::new(),
+        deleted: Vec::new(),
+    };
+    let mut report = PatchApplyReport::default();
+    let plan = match plan_writes(hunks, fuzz, &mut report.warnings) {
+        Ok(plan) => plan,
+        Err(failure) => {
+            report.failed.push(failure);
+            return (affected, report);
+        }
+    };
+
+    for write in plan {
+        let result = match &write {
+            PlannedWrite::Add { path, contents } => create_parent_dirs(path).and_then(|()| {
+                std::fs::write(path, contents)
+                    .with_context(|| format!("Failed to write file {}", path.display()))
+            }),
+            PlannedWrite::Delete { path } => std::fs::remove_file(path)
+                .with_context(|| format!("Failed to delete file {}", path.display())),
+            PlannedWrite::Update {
+                path,
+                move_path: Some(dest),
+                new_contents,
+                ..
+            } => create_parent_dirs(dest)
+                .and_then(|()| {
+                    std::fs::write(dest, new_contents)
+                        .with_context(|| format!("Failed to write file {}", dest.display()))
+                })
+                .and_then(|()| {
+                    std::fs::remove_file(path)
+                        .with_context(|| format!("Failed to remove original {}", path.display()))
+                }),
+            PlannedWrite::Update {
+                path,
+                move_path: None,
+                new_contents,
+                ..
+            } => std::fs::write(path, new_contents)
+                .with_context(|| format!("Failed to write file {}", path.display())),
+        };
+        let (path, bucket) = match write {
+            PlannedWrite::Add { path, .. } => (path, &mut affected.added),
+            PlannedWrite::Delete { path } => (path, &mut affected.deleted),
+            PlannedWrite::Update {
+                path, move_path, ..
+            } => (move_path.unwrap_or(path), &mut affected.modified),
+        };
+        match result {
+            Ok(()) => {
+                bucket.push(path.to_path_buf());
+                report.applied.push(path.to_path_buf());
+            }
+            Err(err) => {
+                report.failed.push((path.to_path_buf(), err.to_string()));
+                break;
+            }
+        }
     }
+    (affected, report)
+}
 
-    let mut added: Vec<PathBuf> = Vec::new();
-    let mut modified: Vec<PathBuf> = Vec::new();
-    let mut deleted: Vec<PathBuf> = Vec::new();
+/// Validate every hunk and compute the resulting file contents without
+/// touching the filesystem. Later hunks see the effect of earlier ones, so a
+/// patch may add a file and then update it. Returns the first failure.
+fn plan_writes<'a>(
+    hunks: &'a [Hunk],
+    fuzz: bool,
+    warnings: &mut Vec<FuzzWarning>,
+) -> std::result::Result<Vec<PlannedWrite<'a>>, (PathBuf, String)> {
+    // Contents each path will have once earlier hunks ran; `None` if deleted.
+    let mut pending: HashMap<&Path, Option<String>> = HashMap::new();
+    let mut plan = Vec::with_capacity(hunks.len());
     for hunk in hunks {
         match hunk {
             Hunk::AddFile { path, contents } => {
-                if let Some(parent) = path.parent()
-                    && !parent.as_os_str().is_empty()
-                {
-                    std::fs::create_dir_all(parent).with_context(|| {
-                        format!("Failed to create parent directories for {}", path.display())
-                    })?;
-                }
-                std::fs::write(path, contents)
-                    .with_context(|| format!("Failed to write file {}", path.display()))?;
-                added.push(path.clone());
+                pending.insert(path, Some(contents.clone()));
+                plan.push(PlannedWrite::Add { path, contents });
             }
             Hunk::DeleteFile { path } => {
-                std::fs::remove_file(path)
-                    .with_context(|| format!("Failed to delete file {}", path.display()))?;
-                deleted.push(path.clone());
+                let exists = match pending.get(path.as_path()) {
+                    Some(contents) => contents.is_some(),
+                    None => path.is_file(),
+                };
+                if !exists {
+                    return Err((
+                        path.clone(),
+                        format!(
+                            "Failed to delete file {}: file does not exist",
+                            path.display()
+                        ),
+                    ));
+                }
+                pending.insert(path, None);
+                plan.push(PlannedWrite::Delete { path });
             }
             Hunk::UpdateFile {
                 path,
                 move_path,
                 chunks,
             } => {
-                let AppliedPatch { new_contents, .. } =
-                    derive_new_contents_from_chunks(path, chunks)?;
-                if let Some(dest) = move_path {
-                    if let Some(parent) = dest.parent()
-                        && !parent.as_os_str().is_empty()
-                    {
-                        std::fs::create_dir_all(parent).with_context(|| {
-                            format!("Failed to create parent directories for {}", dest.display())
-                        })?;
+                let failed = |err: ApplyPatchError| (path.clone(), err.to_string());
+                let original_contents = match pending.get(path.as_path()) {
+                    Some(Some(contents)) => contents.clone(),
+                    Some(None) => {
+                        return Err((
+                            path.clone(),
+                            format!(
+                                "Failed to read file to update {}: deleted earlier in this patch",
+                                path.display()
+                            ),
+                        ));
+                    }
+                    None => read_file_to_update(path).map_err(failed)?,
+                };
+                let AppliedPatch {
+                    new_contents,
+                    fuzz_warnings,
+                    hunks,
+                    ..
+                } = derive_new_contents_from_text(original_contents, path, chunks, fuzz)
+                    .map_err(failed)?;
+                warnings.extend(fuzz_warnings);
+                match move_path {
+                    Some(dest) => {
+                        pending.insert(path, None);
+                        pending.insert(dest, Some(new_contents.clone()));
+                    }
+                    None => {
+                        pending.insert(path, Some(new_contents.clone()));
                     }
-                    std::fs::write(dest, new_contents)
-                        .with_context(|| format!("Failed to write file {}", dest.display()))?;
-                    std::fs::remove_file(path)
-                        .with_context(|| format!("Failed to remove original {}", path.display()))?;
-                    modified.push(dest.clone());
-                } else {
-                    std::fs::write(path, new_contents)
-                        .with_context(|| format!("Failed to write file {}", path.display()))?;
-                    modified.push(path.clone());
                 }
+                plan.push(PlannedWrite::Update {
+                    path,
+                    move_path: move_path.as_deref(),
+                    new_contents,
+                    hunks,
+                });
             }
         }
     }
-    Ok(AffectedPaths {
-        added,
-        modified,
-        deleted,
-    })
+    Ok(plan)
+}
+
+fn create_parent_dirs(path: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create parent directories for {}", path.display())
+        })?;
+    }
+    Ok(())
 }
 
 struct AppliedPatch {
     original_contents: String,
     new_contents: String,
+    fuzz_warnings: Vec<FuzzWarning>,
+    /// Where each chunk landed in the original contents.
+    hunks: Vec<HunkSummary>,
 }
 
 /// Return *only* the new file contents (joined into a single `String`) after
@@ -673,26 +942,32 @@ struct AppliedPatch {
 fn derive_new_contents_from_chunks(
     path: &Path,
     chunks: &[UpdateFileChunk],
+    fuzz: bool,
 ) -> std::result::Result<AppliedPatch, ApplyPatchError> {
-    let original_contents = match std::fs::read_to_string(path) {
-        Ok(contents) => contents,
-        Err(err) => {
-            return Err(ApplyPatchError::IoError(IoError {
-                context: format!("Failed to read file to update {}", path.display()),
-                source: err,
-            }));
-        }
-    };
-
-    let mut original_lines: Vec<String> = original_contents.split('\n').map(String::from).collect();
+    let original_contents = read_file_to_update(path)?;
+    derive_new_contents_from_text(original_contents, path, chunks, fuzz)
+}
 
-    // Drop the trailing empty element that results from the final newline so
-    // that line counts match the behaviour of standard `diff`.
-    if original_lines.last().is_some_and(String::is_empty) {
-        original_lines.pop();
-    }
+/// Like [`derive_new_contents_from_chunks`], for contents already in memory.
+fn derive_new_contents_from_text(
+    original_contents: String,
+    path: &Path,
+    chunks: &[UpdateFileChunk],
+    fuzz: bool,
+) -> std::result::Result<AppliedPatch, ApplyPatchError> {
+    let original_lines = split_lines(&original_contents);
 
-    let replacements = compute_replacements(&original_lines, path, chunks)?;
+    let mut fuzz_warnings = Vec::new();
+    let replacements =
+        compute_replacements(&original_lines, path, chunks, fuzz, &mut fuzz_warnings)?;
+    let hunks = replacements
+        .iter()
+        .map(|(start_idx, old_len, new_lines)| HunkSummary {
+            start_line: start_idx + 1,
+            removed: *old_len,
+            added: new_lines.len(),
+        })
+        .collect();
     let new_lines = apply_replacements(original_lines, &replacements);
     let mut new_lines = new_lines;
     if !new_lines.last().is_some_and(String::is_empty) {
@@ -702,17 +977,57 @@ fn derive_new_contents_from_chunks(
     Ok(AppliedPatch {
         original_contents,
         new_contents,
+        fuzz_warnings,
+        hunks,
     })
 }
 
+fn read_file_to_update(path: &Path) -> std::result::Result<String, ApplyPatchError> {
+    std::fs::read_to_string(path).map_err(|err| {
+        ApplyPatchError::IoError(IoError {
+            context: format!("Failed to read file to update {}", path.display()),
+            source: err,
+        })
+    })
+}
+
+fn split_lines(contents: &str) -> Vec<String> {
+    let mut lines: Vec<String> = contents.split('\n').map(String::from).collect();
+
+    // Drop the trailing empty element that results from the final newline so
+    // that line counts match the behaviour of standard `diff`.
+    if lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines
+}
+
 /// Compute a list of replacements needed to transform `original_lines` into the
 /// new lines, given the patch `chunks`. Each replacement is returned as
-/// `(start_index, old_len, new_lines)`.
+/// `(start_index, old_len, new_lines)`. With `fuzz`, context that cannot be
+/// found is retried with [`seek_sequence::seek_sequence_fuzzy`] and every
+/// fuzzy placement is recorded in `warnings`.
 fn compute_replacements(
     original_lines: &[String],
     path: &Path,
     chunks: &[UpdateFileChunk],
+    fuzz: bool,
+    warnings: &mut Vec<FuzzWarning>,
 ) -> std::result::Result<Vec<(usize, usize, Vec<String>)>, ApplyPatchError> {
+    let mut seek_fuzzy = |pattern: &[String], start: usize, eof: bool| {
+        if !fuzz {
+            return None;
+        }
+        let (idx, edits) = seek_sequence::seek_sequence_fuzzy(original_lines, pattern, start, eof)?;
+        warnings.push(FuzzWarning {
+            path: path.to_path_buf(),
+            line: idx + 1,
+            offset: idx.saturating_sub(start),
+            edits,
+        });
+        Some(idx)
+    };
+
     let mut replacements: Vec<(usize, usize, Vec<String>)> = Vec::new();
     let mut line_index: usize = 0;
 
@@ -725,7 +1040,9 @@ fn compute_replacements(
                 std::slice::from_ref(ctx_line),
                 line_index,
                 false,
-            ) {
+            )
+            .or_else(|| seek_fuzzy(std::slice::from_ref(ctx_line), line_index, false))
+            {
                 line_index = idx + 1;
             } else {
                 return Err(ApplyPatchError::ComputeReplacements(format!(
@@ -781,6 +1098,19 @@ fn compute_replacements(
             );
         }
 
+        if found.is_none() {
+            pattern = &chunk.old_lines;
+            new_slice = &chunk.new_lines;
+            found = seek_fuzzy(pattern, line_index, chunk.is_end_of_file);
+            if found.is_none() && pattern.last().is_some_and(String::is_empty) {
+                pattern = &pattern[..pattern.len() - 1];
+                if new_slice.last().is_some_and(String::is_empty) {
+                    new_slice = &new_slice[..new_slice.len() - 1];
+                }
+                found = seek_fuzzy(pattern, line_index, chunk.is_end_of_file);
+            }
+        }
+
         if let Some(start_idx) = found {
             replacements.push((start_idx, pattern.len(), new_slice.to_vec()));
             line_index = start_idx + pattern.len();
@@ -848,7 +1178,8 @@ pub fn unified_diff_from_chunks_with_context(
     let AppliedPatch {
         original_contents,
         new_contents,
-    } = derive_new_contents_from_chunks(path, chunks)?;
+        ..
+    } = derive_new_contents_from_chunks(path, chunks, false)?;
     let text_diff = TextDiff::from_lines(&original_contents, &new_contents);
     let unified_diff = text_diff.unified_diff().context_radius(context).to_string();
     Ok(ApplyPatchFileUpdate {
@@ -1155,6 +1486,272 @@ PATCH"#,
         assert_eq!(contents, "ab\ncd\n");
     }
 
+    #[test]
+    fn test_dry_run_reports_planned_changes_without_touching_disk() {
+        let dir = tempdir().unwrap();
+        let added = dir.path().join("added.txt");
+        let updated = dir.path().join("updated.txt");
+        let deleted = dir.path().join("deleted.txt");
+        fs::write(&updated, "one\ntwo\nthree\n").unwrap();
+        fs::write(&deleted, "bye\n").unwrap();
+        let patch = wrap_patch(&format!(
+            r#"*** Add File: {}
++a
++b
+*** Update File: {}
+@@
+ one
+-two
++2
++two and a half
+*** Delete File: {}"#,
+            added.display(),
+            updated.display(),
+            deleted.display()
+        ));
+
+        let planned = apply_patch_dry_run(&patch).unwrap();
+
+        assert_eq!(
+            planned,
+            vec![
+                PlannedChange::Add {
+                    path: added.clone(),
+                    lines: 2,
+                },
+                PlannedChange::Update {
+                    path: updated.clone(),
+                    move_path: None,
+                    hunks: vec![HunkSummary {
+                        start_line: 1,
+                        removed: 2,
+                        added: 3,
+                    }],
+                },
+                PlannedChange::Delete {
+                    path: deleted.clone(),
+                },
+            ]
+        );
+        assert!(!added.exists());
+        assert_eq!(fs::read_to_string(&updated).unwrap(), "one\ntwo\nthree\n");
+        assert!(deleted.exists());
+    }
+
+    #[test]
+    fn test_dry_run_reports_missing_file_without_creating_anything() {
+        let dir = tempdir().unwrap();
+        let added = dir.path().join("new.txt");
+        let missing = dir.path().join("missing.txt");
+        let patch = wrap_patch(&format!(
+            r#"*** Add File: {}
++hello
+*** Update File: {}
+@@
+-old
++new"#,
+            added.display(),
+            missing.display()
+        ));
+
+        let err = apply_patch_dry_run(&patch).unwrap_err();
+
+        assert!(matches!(err, ApplyPatchError::NotApplied(_)), "{err:?}");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Failed to read file to update {}: No such file or directory (os error 2)",
+                missing.display()
+            )
+        );
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_dry_run_sees_files_added_earlier_in_the_patch() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fresh.txt");
+        let patch = wrap_patch(&format!(
+            r#"*** Add File: {path}
++first
++second
+*** Update File: {path}
+@@
+ first
+-second
++2nd"#,
+            path = path.display()
+        ));
+
+        let planned = apply_patch_dry_run(&patch).unwrap();
+
+        assert_eq!(
+            planned[1],
+            PlannedChange::Update {
+                path: path.clone(),
+                move_path: None,
+                hunks: vec![HunkSummary {
+                    start_line: 1,
+                    removed: 2,
+                    added: 2,
+                }],
+            }
+        );
+        assert!(!path.exists());
+    }
+
+    fn apply_fuzzy(patch: &str, fuzz: bool) -> Result<Vec<FuzzWarning>, ApplyPatchError> {
+        apply_patch_with_fuzz(patch, fuzz, &mut Vec::new(), &mut Vec::new())
+            .map(|report| report.warnings)
+    }
+
+    #[test]
+    fn test_fuzz_leaves_exact_matches_unchanged() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("exact.rs");
+        fs::write(&path, "fn main() {\n    let total = count + 1;\n}\n").unwrap();
+        let patch = wrap_patch(&format!(
+            r#"*** Update File: {}
+@@
+-    let total = count + 1;
++    let total = count + 2;"#,
+            path.display()
+        ));
+
+        assert_eq!(apply_fuzzy(&patch, true).unwrap(), Vec::new());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "fn main() {\n    let total = count + 2;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_fuzz_applies_hunk_despite_whitespace_drift() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("drift.rs");
+        let original = "fn main() {\n    let total  =  count + 1;\n}\n";
+        fs::write(&path, original).unwrap();
+        let patch = wrap_patch(&format!(
+            r#"*** Update File: {}
+@@
+ fn main() {{
+-    let total = count + 1;
++    let total = count + 2;"#,
+            path.display()
+        ));
+
+        assert!(matches!(
+            apply_fuzzy(&patch, false),
+            Err(ApplyPatchError::NotApplied(_))
+        ));
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+
+        let warnings = apply_fuzzy(&patch, true).unwrap();
+        assert_eq!(
+            warnings,
+            vec![FuzzWarning {
+                path: path.clone(),
+                line: 1,
+                offset: 0,
+                edits: 0,
+            }]
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "fn main() {\n    let total = count + 2;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_fuzz_still_rejects_wrong_context() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("wrong.rs");
+        let original = "fn main() {\n    let total = count + 1;\n}\n";
+        fs::write(&path, original).unwrap();
+        let patch = wrap_patch(&format!(
+            r#"*** Update File: {}
+@@
+-    return Err(error);
++    return Ok(());"#,
+            path.display()
+        ));
+
+        assert!(apply_fuzzy(&patch, true).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+    }
+
+    #[test]
+    fn test_failed_file_is_reported_and_nothing_is_written() {
+        let dir = tempdir().unwrap();
+        let first = dir.path().join("first.txt");
+        let second = dir.path().join("second.txt");
+        let added = dir.path().join("added.txt");
+        fs::write(&first, "alpha\n").unwrap();
+        fs::write(&second, "beta\n").unwrap();
+        let patch = wrap_patch(&format!(
+            r#"*** Update File: {}
+@@
+-alpha
++ALPHA
+*** Add File: {}
++new
+*** Update File: {}
+@@
+-gamma
++GAMMA"#,
+            first.display(),
+            added.display(),
+            second.display()
+        ));
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let err = apply_patch(&patch, &mut stdout, &mut stderr).unwrap_err();
+
+        let ApplyPatchError::NotApplied(report) = err else {
+            panic!("expected NotApplied, got {err:?}");
+        };
+        let reason = format!(
+            "Failed to find expected lines in {}:\ngamma",
+            second.display()
+        );
+        assert_eq!(
+            report,
+            PatchApplyReport {
+                applied: Vec::new(),
+                failed: vec![(second.clone(), reason.clone())],
+                warnings: Vec::new(),
+            }
+        );
+        assert_eq!(String::from_utf8(stderr).unwrap(), format!("{reason}\n"));
+        assert!(stdout.is_empty());
+        assert_eq!(fs::read_to_string(&first).unwrap(), "alpha\n");
+        assert_eq!(fs::read_to_string(&second).unwrap(), "beta\n");
+        assert!(!added.exists());
+    }
+
+    #[test]
+    fn test_report_lists_applied_files_and_later_hunks_see_earlier_ones() {
+        let dir = tempdir().unwrap();
+        let added = dir.path().join("added.txt");
+        let patch = wrap_patch(&format!(
+            r#"*** Add File: {}
++one
+*** Update File: {}
+@@
+-one
++two"#,
+            added.display(),
+            added.display()
+        ));
+
+        let report = apply_patch(&patch, &mut Vec::new(), &mut Vec::new()).unwrap();
+
+        assert_eq!(report.applied, vec![added.clone(), added.clone()]);
+        assert!(report.failed.is_empty());
+        assert_eq!(fs::read_to_string(&added).unwrap(), "two\n");
+    }
+
     #[test]
     fn test_delete_file_hunk_removes_file() {
         let dir = tempdir().unwrap();
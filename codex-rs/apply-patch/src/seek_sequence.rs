@@ -109,9 +109,76 @@ pub(crate) fn seek_sequence(
     None
 }
 
+/// Largest per-line edit distance [`seek_sequence_fuzzy`] tolerates.
+const MAX_FUZZ_EDITS_PER_LINE: usize = 3;
+
+/// Opt-in fallback for when [`seek_sequence`] finds nothing. Lines match if
+/// they are equal after collapsing all whitespace, or within a small edit
+/// distance of each other (at most one edit per five characters, capped at
+/// [`MAX_FUZZ_EDITS_PER_LINE`]). Returns the start index of the closest
+/// match together with the total number of character edits it needed.
+pub(crate) fn seek_sequence_fuzzy(
+    lines: &[String],
+    pattern: &[String],
+    start: usize,
+    eof: bool,
+) -> Option<(usize, usize)> {
+    if pattern.is_empty() || pattern.len() > lines.len() {
+        return None;
+    }
+    let last_start = lines.len() - pattern.len();
+    let search_start = if eof { last_start } else { start };
+
+    let mut best: Option<(usize, usize)> = None;
+    for i in search_start..=last_start {
+        let mut edits = 0;
+        let matched = pattern.iter().enumerate().all(|(p_idx, pat)| {
+            line_edits(&lines[i + p_idx], pat)
+                .map(|d| edits += d)
+                .is_some()
+        });
+        if matched && best.is_none_or(|(_, best_edits)| edits < best_edits) {
+            best = Some((i, edits));
+            if edits == 0 {
+                break;
+            }
+        }
+    }
+    best
+}
+
+/// Character edits needed to turn `line` into `pattern` once whitespace runs
+/// are collapsed, or `None` if they differ by more than the tolerance.
+fn line_edits(line: &str, pattern: &str) -> Option<usize> {
+    let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+    let pattern = pattern.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line == pattern {
+        return Some(0);
+    }
+    let longest = line.chars().count().max(pattern.chars().count());
+    let tolerance = (longest / 5).min(MAX_FUZZ_EDITS_PER_LINE);
+    let edits = levenshtein(&line, &pattern);
+    (edits <= tolerance).then_some(edits)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::seek_sequence;
+    use super::seek_sequence_fuzzy;
     use std::string::ToString;
 
     fn to_vec(strings: &[&str]) -> Vec<String> {
@@ -141,6 +208,23 @@ mod tests {
         assert_eq!(seek_sequence(&lines, &pattern, 0, false), Some(0));
     }
 
+    #[test]
+    fn test_fuzzy_match_collapses_inner_whitespace_and_tolerates_small_edits() {
+        let lines = to_vec(&["fn main() {", "    let total =  count + 1;", "}"]);
+        let spaced = to_vec(&["let total = count + 1;"]);
+        assert_eq!(seek_sequence(&lines, &spaced, 0, false), None);
+        assert_eq!(seek_sequence_fuzzy(&lines, &spaced, 0, false), Some((1, 0)));
+
+        let renamed = to_vec(&["let total = counter + 1;"]);
+        assert_eq!(
+            seek_sequence_fuzzy(&lines, &renamed, 0, false),
+            Some((1, 2))
+        );
+
+        let unrelated = to_vec(&["return Err(error);"]);
+        assert_eq!(seek_sequence_fuzzy(&lines, &unrelated, 0, false), None);
+    }
+
     #[test]
     fn test_pattern_longer_than_input_returns_none() {
         let lines = to_vec(&["just one line"]);
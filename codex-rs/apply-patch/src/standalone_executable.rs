@@ -49,7 +49,7 @@ pub fn run_main() -> i32 {
     let mut stdout = std::io::stdout();
     let mut stderr = std::io::stderr();
     match crate::apply_patch(&patch_arg, &mut stdout, &mut stderr) {
-        Ok(()) => {
+        Ok(_) => {
             // Flush to ensure output ordering when used in pipelines.
             let _ = stdout.flush();
             0
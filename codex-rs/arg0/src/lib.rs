@@ -62,7 +62,7 @@ where
                 let mut stdout = std::io::stdout();
                 let mut stderr = std::io::stderr();
                 match codex_apply_patch::apply_patch(&patch_arg, &mut stdout, &mut stderr) {
-                    Ok(()) => 0,
+                    Ok(_) => 0,
                     Err(_) => 1,
                 }
             }
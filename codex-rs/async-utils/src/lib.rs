@@ -1,10 +1,12 @@
 //! Async utilities for cancellation-aware futures.
 //!
 //! Provides the `OrCancelExt` trait for making futures cancellable
-//! with tokio's `CancellationToken`.
+//! with tokio's `CancellationToken`, and `OrTimeoutExt` for bounding them
+//! by a deadline.
 
 use async_trait::async_trait;
 use std::future::Future;
+use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
 /// Error returned when a future is cancelled.
@@ -45,6 +47,40 @@ where
     }
 }
 
+/// Error returned when a future does not complete within its timeout.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TimeoutErr {
+    pub timeout: Duration,
+}
+
+/// Extension trait for bounding futures by a timeout.
+#[async_trait]
+pub trait OrTimeoutExt: Sized {
+    type Output;
+
+    /// Race this future against `timeout`.
+    ///
+    /// Returns `Ok(output)` if the future completes in time, or
+    /// `Err(TimeoutErr)` once the timeout elapses. The future is dropped on
+    /// timeout.
+    async fn or_timeout(self, timeout: Duration) -> Result<Self::Output, TimeoutErr>;
+}
+
+#[async_trait]
+impl<F> OrTimeoutExt for F
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    type Output = F::Output;
+
+    async fn or_timeout(self, timeout: Duration) -> Result<Self::Output, TimeoutErr> {
+        tokio::time::timeout(timeout, self)
+            .await
+            .map_err(|_| TimeoutErr { timeout })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +134,25 @@ mod tests {
 
         assert_eq!(Err(CancelErr::Cancelled), result);
     }
+
+    #[tokio::test]
+    async fn returns_ok_when_future_beats_timeout() {
+        let result = async { 3 }.or_timeout(Duration::from_millis(50)).await;
+
+        assert_eq!(Ok(3), result);
+    }
+
+    #[tokio::test]
+    async fn returns_err_when_timeout_elapses_first() {
+        let timeout = Duration::from_millis(10);
+
+        let result = async {
+            sleep(Duration::from_millis(100)).await;
+            9
+        }
+        .or_timeout(timeout)
+        .await;
+
+        assert_eq!(Err(TimeoutErr { timeout }), result);
+    }
 }
@@ -211,8 +211,8 @@ struct GenerateTsCommand {
 
 #[derive(Debug, Parser)]
 struct OrderReplayArgs {
-    /// Path to a response.json captured under ~/.code/debug_logs/*_response.json
-    /// (legacy ~/.codex/debug_logs/ is still read).
+    /// Path to ~/.code/debug_logs/debug.log (the last response entry is used)
+    /// or a legacy *_response.json capture (~/.codex/debug_logs/ is still read).
     response_json: std::path::PathBuf,
     /// Path to codex-tui.log (typically ~/.code/log/codex-tui.log; legacy
     /// ~/.codex/log/codex-tui.log is still read).
@@ -589,7 +589,16 @@ fn order_replay_main(args: OrderReplayArgs) -> anyhow::Result<()> {
 
     fn parse_response_expected(path: &std::path::Path) -> Result<Vec<(u64, u64)>> {
         let data = fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
-        let v: Value = serde_json::from_str(&data)?;
+        // debug.log holds one JSON entry per line; take the latest response.
+        let v: Value = match serde_json::from_str(&data) {
+            Ok(v) => v,
+            Err(_) => data
+                .lines()
+                .rev()
+                .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+                .find(|entry| entry.get("type").and_then(Value::as_str) == Some("response"))
+                .context("no response entry in debug log")?,
+        };
         let events = v
             .get("events")
             .and_then(|e| e.as_array())
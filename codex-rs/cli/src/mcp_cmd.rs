@@ -151,6 +151,9 @@ fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Result<(
         startup_timeout_sec: None,
         startup_timeout_ms: None,
         tool_timeout_sec: None,
+        reconnect_attempts: None,
+        lazy: false,
+        tools: Vec::new(),
     };
 
     servers.insert(name.clone(), new_entry);
@@ -215,6 +218,9 @@ fn run_list(config_overrides: &CliConfigOverrides, list_args: ListArgs) -> Resul
                     "startup_timeout_sec": cfg.startup_timeout_sec,
                     "startup_timeout_ms": cfg.startup_timeout_ms,
                     "tool_timeout_sec": cfg.tool_timeout_sec,
+                    "reconnect_attempts": cfg.reconnect_attempts,
+                    "lazy": cfg.lazy,
+                    "tools": cfg.tools,
                 })
             })
             .collect();
@@ -312,6 +318,9 @@ fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Result<(
             "startup_timeout_sec": server.startup_timeout_sec,
             "startup_timeout_ms": server.startup_timeout_ms,
             "tool_timeout_sec": server.tool_timeout_sec,
+            "reconnect_attempts": server.reconnect_attempts,
+            "lazy": server.lazy,
+            "tools": server.tools,
         }))?;
         println!("{output}");
         return Ok(());
@@ -347,6 +356,15 @@ fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Result<(
     if let Some(timeout_sec) = server.tool_timeout_sec {
         println!("  tool_timeout_sec: {timeout_sec}");
     }
+    if let Some(attempts) = server.reconnect_attempts {
+        println!("  reconnect_attempts: {attempts}");
+    }
+    if server.lazy {
+        println!("  lazy: true");
+    }
+    if !server.tools.is_empty() {
+        println!("  tools: {}", server.tools.join(", "));
+    }
     println!("  remove: codex mcp remove {}", get_args.name);
 
     Ok(())
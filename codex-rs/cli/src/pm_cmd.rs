@@ -78,6 +78,10 @@ pub struct BotRunArgs {
     #[arg(long = "write-mode", default_value = "none")]
     pub write_mode: String,
 
+    /// Queue priority (0-255); higher priorities dispatch first.
+    #[arg(long = "priority")]
+    pub priority: Option<u8>,
+
     /// Working directory (defaults to current directory).
     #[arg(long = "cwd", short = 'C')]
     pub cwd: Option<PathBuf>,
@@ -312,6 +316,7 @@ fn connect_and_handshake(
         "params": {
             "protocol_version": codex_pm_service::PROTOCOL_VERSION,
             "client_version": env!("CARGO_PKG_VERSION"),
+            "token": codex_pm_service::auth_token(),
         }
     });
 
@@ -584,6 +589,7 @@ fn cmd_bot_run(args: &BotRunArgs) -> Result<(), String> {
         "kind": args.kind,
         "capture_mode": args.capture_mode,
         "write_mode": args.write_mode,
+        "priority": args.priority,
         "subscribe": args.wait,
     });
 
@@ -761,11 +767,13 @@ fn cmd_bot_cancel(args: &BotCancelArgs) -> Result<(), String> {
             serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string())
         );
     } else {
-        println!(
-            "Run {} cancelled (status: {})",
-            result.get("run_id").and_then(|v| v.as_str()).unwrap_or("?"),
-            result.get("status").and_then(|v| v.as_str()).unwrap_or("?"),
-        );
+        let run_id = result.get("run_id").and_then(|v| v.as_str()).unwrap_or("?");
+        let status = result.get("status").and_then(|v| v.as_str()).unwrap_or("?");
+        if result.get("cancelled").and_then(serde_json::Value::as_bool) == Some(false) {
+            println!("Run {run_id} already finished (status: {status})");
+        } else {
+            println!("Run {run_id} cancelled (status: {status})");
+        }
     }
 
     Ok(())
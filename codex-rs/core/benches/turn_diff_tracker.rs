@@ -0,0 +1,81 @@
+// Benchmarks use expect/unwrap for simplicity - test code, not production
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+//! Turn diff snapshot benchmarks
+//!
+//! Measures the cost of pushing one more change and taking a snapshot once a
+//! turn has already touched many files. With incremental snapshots this
+//! should stay roughly flat as the number of tracked files grows.
+//!
+//! ## Running Benchmarks
+//! ```bash
+//! cd codex-rs
+//! cargo bench --bench turn_diff_tracker
+//! ```
+
+use codex_core::protocol::FileChange;
+use codex_core::turn_diff_tracker::TurnDiffTracker;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+fn update() -> FileChange {
+    FileChange::Update {
+        unified_diff: String::new(),
+        move_path: None,
+        original_content: String::new(),
+        new_content: String::new(),
+    }
+}
+
+/// Tracker with `count` edited files, each already folded into a snapshot.
+fn setup_tracker(count: usize) -> (TempDir, TurnDiffTracker, Vec<PathBuf>) {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let mut tracker = TurnDiffTracker::new();
+    let mut paths = Vec::with_capacity(count);
+    for i in 0..count {
+        let path = dir.path().join(format!("file_{i}.txt"));
+        let body: String = (0..50).map(|line| format!("line {line}\n")).collect();
+        fs::write(&path, &body).unwrap();
+        tracker.push_change(&path, &update());
+        fs::write(&path, format!("{body}edited {i}\n")).unwrap();
+        paths.push(path);
+    }
+    tracker.snapshot();
+    (dir, tracker, paths)
+}
+
+fn benchmark_push_and_snapshot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("turn_diff_push_and_snapshot");
+
+    for count in [10, 100, 500] {
+        let (_dir, mut tracker, paths) = setup_tracker(count);
+        let target = paths[count / 2].clone();
+        let mut revision = 0u64;
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| {
+                revision += 1;
+                tracker.push_change(&target, &update());
+                fs::write(&target, format!("revision {revision}\n")).unwrap();
+                black_box(tracker.snapshot());
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn benchmark_cached_snapshot(c: &mut Criterion) {
+    let (_dir, mut tracker, _paths) = setup_tracker(100);
+    c.bench_function("turn_diff_cached_snapshot", |b| {
+        b.iter(|| black_box(tracker.snapshot()));
+    });
+}
+
+criterion_group!(
+    benches,
+    benchmark_push_and_snapshot,
+    benchmark_cached_snapshot
+);
+criterion_main!(benches);
@@ -1,7 +1,9 @@
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 
+use crate::model_prices;
 use crate::protocol::RateLimitSnapshotEvent;
 use chrono::{DateTime, Duration, Utc};
 use fs2::FileExt;
@@ -13,6 +15,10 @@ use crate::protocol::TokenUsage;
 const USAGE_VERSION: u32 = 1;
 const USAGE_SUBDIR: &str = "usage";
 const HOURLY_HISTORY_DAYS: i64 = 7;
+const BUDGET_STATE_FILE: &str = "usage_budget.json";
+
+/// Percentages of the monthly budget that each trigger one alert per month.
+pub const BUDGET_ALERT_THRESHOLDS: [f64; 4] = [50.0, 75.0, 90.0, 100.0];
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TokenTotals {
@@ -41,7 +47,7 @@ impl TokenTotals {
         self.total_tokens = self.total_tokens.saturating_add(usage.total_tokens);
     }
 
-    fn add_totals(&mut self, other: &TokenTotals) {
+    pub(crate) fn add_totals(&mut self, other: &TokenTotals) {
         self.input_tokens = self.input_tokens.saturating_add(other.input_tokens);
         self.cached_input_tokens = self
             .cached_input_tokens
@@ -53,6 +59,17 @@ impl TokenTotals {
         self.total_tokens = self.total_tokens.saturating_add(other.total_tokens);
     }
 
+    /// Estimated USD cost of these totals on `model`, or `None` when the
+    /// model has no known price
+    pub fn estimated_cost(&self, model: &str) -> Option<f64> {
+        model_prices::estimated_cost(
+            model,
+            self.input_tokens,
+            self.cached_input_tokens,
+            self.output_tokens,
+        )
+    }
+
     fn from_usage(usage: &TokenUsage) -> Self {
         let mut totals = TokenTotals::default();
         totals.add_usage(usage);
@@ -80,6 +97,14 @@ struct RateLimitInfo {
     last_usage_limit_hit_at: Option<DateTime<Utc>>,
 }
 
+/// Per-model totals for a single calendar month (UTC), keyed `YYYY-MM`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct MonthlyModelTotals {
+    month: String,
+    #[serde(default)]
+    model_totals: BTreeMap<String, TokenTotals>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct AccountUsageData {
     version: u32,
@@ -96,6 +121,10 @@ struct AccountUsageData {
     tokens_last_hour: TokenTotals,
     #[serde(default)]
     rate_limit: Option<RateLimitInfo>,
+    #[serde(default)]
+    model_totals: BTreeMap<String, TokenTotals>,
+    #[serde(default)]
+    monthly: Option<MonthlyModelTotals>,
 }
 
 impl AccountUsageData {
@@ -109,6 +138,8 @@ impl AccountUsageData {
             hourly_entries: Vec::new(),
             tokens_last_hour: TokenTotals::default(),
             rate_limit: None,
+            model_totals: BTreeMap::new(),
+            monthly: None,
         }
     }
 
@@ -161,6 +192,55 @@ pub struct StoredUsageSummary {
     pub totals: TokenTotals,
     pub last_updated: DateTime<Utc>,
     pub hourly_entries: Vec<StoredUsageEntry>,
+    /// Lifetime totals keyed by model slug, for usage recorded with a model.
+    pub model_totals: BTreeMap<String, TokenTotals>,
+}
+
+/// Lifetime usage of one model summed across all stored accounts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelUsage {
+    pub model: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Estimated USD cost, or `None` when the model has no known pricing.
+    pub estimated_cost: Option<f64>,
+}
+
+/// Estimated spend against the monthly budget, with the alert thresholds
+/// crossed since the previous [`check_budget`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetStatus {
+    pub spent: f64,
+    pub limit: f64,
+    pub pct: f64,
+    pub crossed_thresholds: Vec<f64>,
+}
+
+impl BudgetStatus {
+    /// One user-facing warning per newly crossed threshold.
+    pub fn warnings(&self) -> Vec<String> {
+        self.crossed_thresholds
+            .iter()
+            .map(|threshold| {
+                format!(
+                    "Estimated spend this month passed {threshold:.0}% of your ${:.2} budget (${:.2} so far).",
+                    self.limit, self.spent
+                )
+            })
+            .collect()
+    }
+}
+
+/// Highest alert already shown, so each threshold fires once per month.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct BudgetAlertState {
+    month: String,
+    #[serde(default)]
+    alerted_index: usize,
+}
+
+fn month_key(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m").to_string()
 }
 
 fn usage_dir(codex_home: &Path) -> PathBuf {
@@ -230,12 +310,32 @@ pub fn record_token_usage(
     codex_home: &Path,
     account_id: &str,
     plan: Option<&str>,
+    model: Option<&str>,
     usage: &TokenUsage,
     observed_at: DateTime<Utc>,
 ) -> std::io::Result<()> {
     with_usage_file(codex_home, account_id, plan, |data| {
         data.last_updated = observed_at;
         data.totals.add_usage(usage);
+        if let Some(model) = model {
+            data.model_totals
+                .entry(model.to_string())
+                .or_default()
+                .add_usage(usage);
+            let month = month_key(observed_at);
+            let monthly = data.monthly.get_or_insert_with(Default::default);
+            if monthly.month != month {
+                *monthly = MonthlyModelTotals {
+                    month,
+                    model_totals: BTreeMap::new(),
+                };
+            }
+            monthly
+                .model_totals
+                .entry(model.to_string())
+                .or_default()
+                .add_usage(usage);
+        }
         data.hourly_entries.push(TokenWindowEntry {
             timestamp: observed_at,
             tokens: TokenTotals::from_usage(usage),
@@ -266,9 +366,9 @@ pub fn record_rate_limit_snapshot(
     })
 }
 
-pub fn list_rate_limit_snapshots(
-    codex_home: &Path,
-) -> std::io::Result<Vec<StoredRateLimitSnapshot>> {
+/// Read every parseable account usage file under `codex_home`, skipping
+/// unreadable or malformed entries.
+fn read_all_usage_files(codex_home: &Path) -> std::io::Result<Vec<AccountUsageData>> {
     let usage_dir = usage_dir(codex_home);
     let mut results = Vec::new();
 
@@ -303,11 +403,24 @@ pub fn list_rate_limit_snapshots(
                 Ok(data) => data,
                 Err(_) => continue,
             };
+            results.push(data);
+        }
+    }
+
+    Ok(results)
+}
+
+pub fn list_rate_limit_snapshots(
+    codex_home: &Path,
+) -> std::io::Result<Vec<StoredRateLimitSnapshot>> {
+    let results = read_all_usage_files(codex_home)?
+        .into_iter()
+        .map(|data| {
             let rate = data.rate_limit.unwrap_or_default();
             let primary_next_reset_at = rate.primary_next_reset_at;
             let secondary_next_reset_at =
                 rate.secondary_next_reset_at.or(rate.primary_next_reset_at);
-            results.push(StoredRateLimitSnapshot {
+            StoredRateLimitSnapshot {
                 account_id: data.account_id,
                 plan: data.plan,
                 snapshot: rate.snapshot,
@@ -315,11 +428,119 @@ pub fn list_rate_limit_snapshots(
                 primary_next_reset_at,
                 secondary_next_reset_at,
                 last_usage_limit_hit_at: rate.last_usage_limit_hit_at,
-            });
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Aggregate stored per-model usage across all accounts, most expensive
+/// first. Models without known pricing sort after priced ones, by name.
+pub fn usage_by_model(codex_home: &Path) -> std::io::Result<Vec<ModelUsage>> {
+    let mut combined: BTreeMap<String, TokenTotals> = BTreeMap::new();
+    for data in read_all_usage_files(codex_home)? {
+        for (model, totals) in &data.model_totals {
+            combined
+                .entry(model.clone())
+                .or_default()
+                .add_totals(totals);
         }
     }
 
-    Ok(results)
+    let mut usage: Vec<ModelUsage> = combined
+        .into_iter()
+        .map(|(model, totals)| {
+            let estimated_cost = totals.estimated_cost(&model);
+            ModelUsage {
+                model,
+                input_tokens: totals.input_tokens,
+                output_tokens: totals.output_tokens,
+                estimated_cost,
+            }
+        })
+        .collect();
+    usage.sort_by(|a, b| match (a.estimated_cost, b.estimated_cost) {
+        (Some(a_cost), Some(b_cost)) => b_cost.total_cmp(&a_cost),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.model.cmp(&b.model),
+    });
+    Ok(usage)
+}
+
+/// Estimated USD spend for the calendar month containing `now`, summed
+/// across accounts. Models without known pricing are not counted.
+pub fn monthly_spend(codex_home: &Path, now: DateTime<Utc>) -> std::io::Result<f64> {
+    let month = month_key(now);
+    let spent = read_all_usage_files(codex_home)?
+        .iter()
+        .filter_map(|data| data.monthly.as_ref())
+        .filter(|monthly| monthly.month == month)
+        .flat_map(|monthly| &monthly.model_totals)
+        .filter_map(|(model, totals)| totals.estimated_cost(model))
+        .sum();
+    Ok(spent)
+}
+
+/// Compare this month's estimated spend with `monthly_limit_usd` and report
+/// the [`BUDGET_ALERT_THRESHOLDS`] newly crossed since the last check. The
+/// last alerted level is persisted so each threshold fires once per month.
+pub fn check_budget(
+    codex_home: &Path,
+    monthly_limit_usd: f64,
+    now: DateTime<Utc>,
+) -> std::io::Result<BudgetStatus> {
+    let spent = monthly_spend(codex_home, now)?;
+    let pct = if monthly_limit_usd > 0.0 {
+        spent / monthly_limit_usd * 100.0
+    } else {
+        0.0
+    };
+
+    let month = month_key(now);
+    fs::create_dir_all(codex_home)?;
+    // Hold the lock across read-modify-write so concurrent sessions cannot
+    // both see a threshold as new and alert twice.
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(codex_home.join(BUDGET_STATE_FILE))?;
+    file.lock_exclusive()?;
+
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    let mut state = serde_json::from_str::<BudgetAlertState>(&contents).unwrap_or_default();
+    if state.month != month {
+        state = BudgetAlertState {
+            month,
+            alerted_index: 0,
+        };
+    }
+
+    let mut crossed_thresholds = Vec::new();
+    while state.alerted_index < BUDGET_ALERT_THRESHOLDS.len()
+        && pct >= BUDGET_ALERT_THRESHOLDS[state.alerted_index]
+    {
+        crossed_thresholds.push(BUDGET_ALERT_THRESHOLDS[state.alerted_index]);
+        state.alerted_index += 1;
+    }
+
+    let json = serde_json::to_string_pretty(&state)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.set_len(0)?;
+    file.write_all(json.as_bytes())?;
+    file.flush()?;
+    FileExt::unlock(&file)?;
+
+    Ok(BudgetStatus {
+        spent,
+        limit: monthly_limit_usd,
+        pct,
+        crossed_thresholds,
+    })
 }
 
 pub fn record_usage_limit_hint(
@@ -378,6 +599,7 @@ pub fn load_account_usage(
         totals: data.totals,
         last_updated: data.last_updated,
         hourly_entries,
+        model_totals: data.model_totals,
     }))
 }
 
@@ -403,8 +625,15 @@ mod tests {
         let home = TempDir::new().expect("tempdir");
         let now = Utc::now();
 
-        record_token_usage(home.path(), "acct-1", Some("Team"), &sample_usage(), now)
-            .expect("record usage");
+        record_token_usage(
+            home.path(),
+            "acct-1",
+            Some("Team"),
+            None,
+            &sample_usage(),
+            now,
+        )
+        .expect("record usage");
 
         let path = usage_file_path(home.path(), "acct-1");
         let mut contents = String::new();
@@ -421,4 +650,88 @@ mod tests {
         assert_eq!(parsed.tokens_last_hour.total_tokens, 210);
         assert_eq!(parsed.hourly_entries.len(), 1);
     }
+
+    #[test]
+    fn usage_by_model_sums_across_accounts_and_sorts_by_cost() {
+        let home = TempDir::new().expect("tempdir");
+        let now = Utc::now();
+        let usage = |input_tokens, output_tokens| TokenUsage {
+            input_tokens,
+            cached_input_tokens: 0,
+            output_tokens,
+            reasoning_output_tokens: 0,
+            total_tokens: input_tokens + output_tokens,
+        };
+
+        for (account, model, tokens) in [
+            ("acct-1", "gpt-5-mini", usage(1_000_000, 500_000)),
+            ("acct-1", "gpt-5", usage(200_000, 100_000)),
+            ("acct-2", "gpt-5-mini", usage(1_000_000, 500_000)),
+            ("acct-2", "gpt-5", usage(300_000, 400_000)),
+        ] {
+            record_token_usage(home.path(), account, None, Some(model), &tokens, now)
+                .expect("record usage");
+        }
+
+        let by_model = usage_by_model(home.path()).expect("aggregate usage");
+        let models: Vec<&str> = by_model.iter().map(|m| m.model.as_str()).collect();
+        assert_eq!(models, vec!["gpt-5", "gpt-5-mini"]);
+
+        let gpt5 = &by_model[0];
+        assert_eq!((gpt5.input_tokens, gpt5.output_tokens), (500_000, 500_000));
+        // 0.5M input at $1.25/M + 0.5M output at $10/M.
+        let cost = gpt5.estimated_cost.expect("gpt-5 is priced");
+        assert!((cost - 5.625).abs() < 1e-9, "unexpected cost {cost}");
+
+        let mini = &by_model[1];
+        assert_eq!(
+            (mini.input_tokens, mini.output_tokens),
+            (2_000_000, 1_000_000)
+        );
+        // 2M input at $0.25/M + 1M output at $2/M.
+        let cost = mini.estimated_cost.expect("gpt-5-mini is priced");
+        assert!((cost - 2.5).abs() < 1e-9, "unexpected cost {cost}");
+    }
+
+    #[test]
+    fn budget_thresholds_fire_once_each() {
+        let home = TempDir::new().expect("tempdir");
+        let now = Utc::now();
+        // gpt-5 output is priced at $10 per million tokens.
+        let output = |output_tokens| TokenUsage {
+            input_tokens: 0,
+            cached_input_tokens: 0,
+            output_tokens,
+            reasoning_output_tokens: 0,
+            total_tokens: output_tokens,
+        };
+        let record = |tokens: &TokenUsage, at| {
+            record_token_usage(home.path(), "acct-1", None, Some("gpt-5"), tokens, at)
+                .expect("record usage");
+        };
+
+        record(&output(750_000), now);
+        let status = check_budget(home.path(), 10.0, now).expect("check budget");
+        assert!((status.spent - 7.5).abs() < 1e-9);
+        assert!((status.pct - 75.0).abs() < 1e-9);
+        assert_eq!(status.crossed_thresholds, vec![50.0, 75.0]);
+        assert_eq!(status.warnings().len(), 2);
+
+        let status = check_budget(home.path(), 10.0, now).expect("check budget");
+        assert!(status.crossed_thresholds.is_empty());
+
+        record(&output(150_000), now);
+        let status = check_budget(home.path(), 10.0, now).expect("check budget");
+        assert!((status.pct - 90.0).abs() < 1e-9);
+        assert_eq!(status.crossed_thresholds, vec![90.0]);
+
+        let status = check_budget(home.path(), 10.0, now).expect("check budget");
+        assert!(status.crossed_thresholds.is_empty());
+
+        // A new month starts from a clean slate.
+        let next_month = now + Duration::days(32);
+        record(&output(600_000), next_month);
+        let status = check_budget(home.path(), 10.0, next_month).expect("check budget");
+        assert_eq!(status.crossed_thresholds, vec![50.0]);
+    }
 }
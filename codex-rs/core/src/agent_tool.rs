@@ -14,7 +14,7 @@ use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use uuid::Uuid;
 
-use crate::async_agent_executor::{AsyncAgentExecutor, DirectProcessExecutor, ProviderRegistry};
+use crate::async_agent_executor::{DirectProcessExecutor, ProviderRegistry, QueuedAgentExecutor};
 use crate::config_types::AgentConfig;
 use crate::openai_tools::JsonSchema;
 use crate::openai_tools::OpenAiTool;
@@ -761,6 +761,7 @@ async fn execute_agent(agent_id: String, config: Option<AgentConfig>) {
 
                         // Execute with full permissions in the worktree
                         execute_model_with_permissions(
+                            &agent_id,
                             &model,
                             &full_prompt,
                             false,
@@ -778,7 +779,7 @@ async fn execute_agent(agent_id: String, config: Option<AgentConfig>) {
         // Execute in read-only mode
         full_prompt =
             format!("{full_prompt}\n\n[Running in read-only mode - no modifications allowed]");
-        execute_model_with_permissions(&model, &full_prompt, true, None, config).await
+        execute_model_with_permissions(&agent_id, &model, &full_prompt, true, None, config).await
     };
 
     // SPEC-KIT-927: Calculate execution duration for suspicious completion detection
@@ -1071,7 +1072,18 @@ fn get_provider_registry() -> &'static ProviderRegistry {
     REGISTRY.get_or_init(ProviderRegistry::with_defaults)
 }
 
+/// Most agent CLIs allowed to run at once; further agents wait in FIFO order.
+const MAX_CONCURRENT_AGENTS: usize = 8;
+
+/// Get the global agent executor, shared so the concurrency limit, run
+/// statuses and metrics span every agent the session launches.
+pub fn get_agent_executor() -> &'static QueuedAgentExecutor<DirectProcessExecutor> {
+    static EXECUTOR: OnceLock<QueuedAgentExecutor<DirectProcessExecutor>> = OnceLock::new();
+    EXECUTOR.get_or_init(|| QueuedAgentExecutor::new(DirectProcessExecutor, MAX_CONCURRENT_AGENTS))
+}
+
 async fn execute_model_with_permissions(
+    agent_id: &str,
     model: &str,
     prompt: &str,
     read_only: bool,
@@ -1387,8 +1399,8 @@ async fn execute_model_with_permissions(
         _ => {}
     }
 
-    // Execute via DirectProcessExecutor
-    let executor = DirectProcessExecutor;
+    // Execute via the shared queue so concurrent agents respect the limit
+    let executor = get_agent_executor();
     let timeout_secs = 600; // 10 minutes
 
     tracing::info!(
@@ -1398,7 +1410,8 @@ async fn execute_model_with_permissions(
     );
 
     let output = executor
-        .execute(
+        .run(
+            agent_id,
             &program,
             &args,
             &env,
@@ -158,6 +158,13 @@ pub enum AgentExecutionError {
     /// ```
     #[error("Output capture failed: {0}")]
     OutputCaptureFailed(String),
+
+    /// Execution cancelled
+    ///
+    /// The run was stopped through `AgentCancellationRegistry::cancel`, which
+    /// receives whatever output the agent produced before it stopped.
+    #[error("Execution cancelled")]
+    Cancelled,
 }
 
 // ============================================================================
@@ -218,6 +225,141 @@ pub trait ProviderConfig: Send + Sync {
     /// Returns args array for executing large prompts via stdin.
     /// Example: ["-p", "-"] where "-" means read from stdin
     fn format_large_prompt_args(&self) -> Vec<String>;
+
+    /// Extract token usage from a finished agent's output
+    ///
+    /// Providers whose CLI prints a usage summary override this so batch
+    /// runs can attribute tokens and cost to each agent.
+    ///
+    /// Default: `None` (usage not reported).
+    fn parse_token_usage(&self, _output: &AgentOutput) -> Option<AgentTokenUsage> {
+        None
+    }
+}
+
+/// Token usage reported by an agent run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AgentTokenUsage {
+    /// Model that served the run, used to look up pricing
+    pub model: Option<String>,
+    pub input_tokens: u64,
+    pub cached_input_tokens: u64,
+    pub output_tokens: u64,
+}
+
+impl AgentTokenUsage {
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    /// Estimated USD cost from the model pricing table, if the model is known
+    pub fn estimated_cost(&self) -> Option<f64> {
+        crate::model_prices::estimated_cost(
+            self.model.as_deref()?,
+            self.input_tokens,
+            self.cached_input_tokens,
+            self.output_tokens,
+        )
+    }
+}
+
+/// Last JSON object printed to stdout: the whole output for single-document
+/// JSON modes, or the final line for streaming (JSON Lines) modes.
+fn last_json_object(stdout: &str) -> Option<serde_json::Value> {
+    if let Ok(value @ serde_json::Value::Object(_)) = serde_json::from_str(stdout.trim()) {
+        return Some(value);
+    }
+    stdout
+        .lines()
+        .rev()
+        .map(str::trim)
+        .filter(|line| line.starts_with('{'))
+        .find_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+}
+
+fn json_u64(value: &serde_json::Value, key: &str) -> u64 {
+    value
+        .get(key)
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0)
+}
+
+/// Usage from `claude -p --output-format json` (or the final `result` event
+/// of `stream-json`). Cache reads and writes count as input; the run is
+/// attributed to the model that produced the most output.
+fn parse_claude_usage(stdout: &str) -> Option<AgentTokenUsage> {
+    let result = last_json_object(stdout)?;
+    let usage = result.get("usage")?;
+    let cache_read = json_u64(usage, "cache_read_input_tokens");
+    let model = result
+        .get("modelUsage")
+        .and_then(serde_json::Value::as_object)
+        .and_then(|models| {
+            models
+                .iter()
+                .max_by_key(|(_, m)| json_u64(m, "outputTokens"))
+                .map(|(name, _)| name.clone())
+        });
+    Some(AgentTokenUsage {
+        model,
+        input_tokens: json_u64(usage, "input_tokens")
+            + json_u64(usage, "cache_creation_input_tokens")
+            + cache_read,
+        cached_input_tokens: cache_read,
+        output_tokens: json_u64(usage, "output_tokens"),
+    })
+}
+
+/// Usage from `gemini --output-format json`, summed over `stats.models`.
+/// Thinking tokens are billed as output; the run is attributed to the model
+/// with the most tokens.
+fn parse_gemini_usage(stdout: &str) -> Option<AgentTokenUsage> {
+    let result = last_json_object(stdout)?;
+    let models = result.get("stats")?.get("models")?.as_object()?;
+    let mut usage = AgentTokenUsage::default();
+    let mut top_model: Option<(&String, u64)> = None;
+    for (name, stats) in models {
+        let Some(tokens) = stats.get("tokens") else {
+            continue;
+        };
+        usage.input_tokens += json_u64(tokens, "prompt");
+        usage.cached_input_tokens += json_u64(tokens, "cached");
+        usage.output_tokens += json_u64(tokens, "candidates") + json_u64(tokens, "thoughts");
+        let total = json_u64(tokens, "total");
+        if top_model.is_none_or(|(_, best)| total > best) {
+            top_model = Some((name, total));
+        }
+    }
+    usage.model = Some(top_model?.0.clone());
+    Some(usage)
+}
+
+/// Usage from an OpenAI-compatible JSON response (Chat Completions
+/// `prompt_tokens`/`completion_tokens` or Responses `input_tokens`/
+/// `output_tokens`).
+fn parse_openai_usage(stdout: &str) -> Option<AgentTokenUsage> {
+    let response = last_json_object(stdout)?;
+    let usage = response.get("usage")?;
+    let (input, output, details) = if usage.get("prompt_tokens").is_some() {
+        (
+            "prompt_tokens",
+            "completion_tokens",
+            "prompt_tokens_details",
+        )
+    } else {
+        ("input_tokens", "output_tokens", "input_tokens_details")
+    };
+    Some(AgentTokenUsage {
+        model: response
+            .get("model")
+            .and_then(serde_json::Value::as_str)
+            .map(String::from),
+        input_tokens: json_u64(usage, input),
+        cached_input_tokens: usage
+            .get(details)
+            .map_or(0, |d| json_u64(d, "cached_tokens")),
+        output_tokens: json_u64(usage, output),
+    })
 }
 
 /// Anthropic Claude provider configuration
@@ -281,6 +423,10 @@ impl ProviderConfig for AnthropicProvider {
     fn format_large_prompt_args(&self) -> Vec<String> {
         vec!["-p".to_string(), "-".to_string()]
     }
+
+    fn parse_token_usage(&self, output: &AgentOutput) -> Option<AgentTokenUsage> {
+        parse_claude_usage(&output.stdout)
+    }
 }
 
 /// Google Gemini provider configuration
@@ -354,6 +500,10 @@ impl ProviderConfig for GoogleProvider {
             "-".to_string(),
         ]
     }
+
+    fn parse_token_usage(&self, output: &AgentOutput) -> Option<AgentTokenUsage> {
+        parse_gemini_usage(&output.stdout)
+    }
 }
 
 /// OpenAI provider configuration
@@ -429,6 +579,10 @@ impl ProviderConfig for OpenAIProvider {
             "-".to_string(),
         ]
     }
+
+    fn parse_token_usage(&self, output: &AgentOutput) -> Option<AgentTokenUsage> {
+        parse_openai_usage(&output.stdout)
+    }
 }
 
 /// Deepseek provider configuration (STUB - SPEC-949)
@@ -517,6 +671,10 @@ impl ProviderConfig for DeepseekProvider {
             "-".to_string(),
         ]
     }
+
+    fn parse_token_usage(&self, output: &AgentOutput) -> Option<AgentTokenUsage> {
+        parse_openai_usage(&output.stdout)
+    }
 }
 
 /// Kimi (Moonshot AI) provider configuration (STUB - SPEC-949)
@@ -604,6 +762,10 @@ impl ProviderConfig for KimiProvider {
             "-".to_string(),
         ]
     }
+
+    fn parse_token_usage(&self, output: &AgentOutput) -> Option<AgentTokenUsage> {
+        parse_openai_usage(&output.stdout)
+    }
 }
 
 /// Registry for managing AI CLI providers
@@ -991,6 +1153,619 @@ impl AsyncAgentExecutor for DirectProcessExecutor {
     }
 }
 
+// ============================================================================
+// Cancellation
+// ============================================================================
+
+use codex_async_utils::CancelErr;
+use codex_async_utils::OrCancelExt;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
+
+/// Default time a cancelled agent gets to exit after SIGTERM before SIGKILL.
+pub const DEFAULT_CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(2);
+
+/// How long to keep draining pipes after the process is gone; a leftover
+/// grandchild holding stdout open must not block cancellation.
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Bookkeeping for one agent started via `execute_cancellable`.
+struct RunningAgent {
+    token: CancellationToken,
+    output_rx: oneshot::Receiver<AgentOutput>,
+    artifacts: Vec<PathBuf>,
+}
+
+/// Tracks agents started with `DirectProcessExecutor::execute_cancellable` so
+/// they can be cancelled by id while running.
+///
+/// Cloning is cheap; clones share the same set of running agents.
+#[derive(Clone)]
+pub struct AgentCancellationRegistry {
+    agents: Arc<Mutex<HashMap<String, RunningAgent>>>,
+    grace_period: Duration,
+}
+
+impl Default for AgentCancellationRegistry {
+    fn default() -> Self {
+        Self {
+            agents: Arc::new(Mutex::new(HashMap::new())),
+            grace_period: DEFAULT_CANCEL_GRACE_PERIOD,
+        }
+    }
+}
+
+impl AgentCancellationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override how long a cancelled agent may take to exit before it is
+    /// force-terminated.
+    pub fn with_grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+    /// Whether `agent_id` is currently running.
+    pub fn is_running(&self, agent_id: &str) -> bool {
+        self.agents
+            .lock()
+            .map(|agents| agents.contains_key(agent_id))
+            .unwrap_or(false)
+    }
+
+    /// Register a temp file or directory to delete if `agent_id` is cancelled.
+    pub fn track_artifact(&self, agent_id: &str, path: PathBuf) {
+        if let Ok(mut agents) = self.agents.lock()
+            && let Some(agent) = agents.get_mut(agent_id)
+        {
+            agent.artifacts.push(path);
+        }
+    }
+
+    /// Cancel a running agent: signal its cancellation token, give it the
+    /// grace period to exit after SIGTERM, then force-kill it and delete its
+    /// tracked artifacts.
+    ///
+    /// Returns the output captured up to that point, or `None` if no agent
+    /// with this id is running.
+    pub async fn cancel(&self, agent_id: &str) -> Option<AgentOutput> {
+        let agent = self.agents.lock().ok()?.remove(agent_id)?;
+        agent.token.cancel();
+
+        // The executor kills the process once the grace period runs out, so
+        // this only waits longer if draining its output stalls.
+        let wait = self.grace_period + OUTPUT_DRAIN_TIMEOUT + Duration::from_secs(1);
+        let output = tokio::time::timeout(wait, agent.output_rx)
+            .await
+            .ok()
+            .and_then(Result::ok);
+
+        for path in agent.artifacts {
+            let removed = if path.is_dir() {
+                tokio::fs::remove_dir_all(&path).await
+            } else {
+                tokio::fs::remove_file(&path).await
+            };
+            if let Err(err) = removed
+                && err.kind() != std::io::ErrorKind::NotFound
+            {
+                tracing::warn!("failed to remove agent artifact {}: {err}", path.display());
+            }
+        }
+
+        output
+    }
+
+    fn register(&self, agent_id: &str, token: CancellationToken) -> oneshot::Sender<AgentOutput> {
+        let (output_tx, output_rx) = oneshot::channel();
+        if let Ok(mut agents) = self.agents.lock() {
+            agents.insert(
+                agent_id.to_string(),
+                RunningAgent {
+                    token,
+                    output_rx,
+                    artifacts: Vec::new(),
+                },
+            );
+        }
+        output_tx
+    }
+
+    fn unregister(&self, agent_id: &str) {
+        if let Ok(mut agents) = self.agents.lock() {
+            agents.remove(agent_id);
+        }
+    }
+}
+
+/// Append everything read from `reader` to `sink` as it arrives, so a
+/// cancelled run still has the output produced so far.
+fn spawn_capture<R>(reader: R, sink: Arc<Mutex<Vec<u8>>>) -> tokio::task::JoinHandle<()>
+where
+    R: tokio::io::AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(reader);
+        let mut buf = [0u8; 8192];
+        loop {
+            match reader.read(&mut buf).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    if let Ok(mut sink) = sink.lock() {
+                        sink.extend_from_slice(&buf[..n]);
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn captured(sink: &Arc<Mutex<Vec<u8>>>) -> String {
+    sink.lock()
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+        .unwrap_or_default()
+}
+
+impl DirectProcessExecutor {
+    /// Like [`AsyncAgentExecutor::execute`], but registers the run under
+    /// `agent_id` so [`AgentCancellationRegistry::cancel`] can stop it.
+    ///
+    /// A cancelled run returns [`AgentExecutionError::Cancelled`]; its partial
+    /// output is handed to the `cancel` caller instead.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_cancellable(
+        &self,
+        registry: &AgentCancellationRegistry,
+        agent_id: &str,
+        command: &str,
+        args: &[String],
+        env: &HashMap<String, String>,
+        working_dir: Option<&Path>,
+        timeout_secs: u64,
+        large_input: Option<&str>,
+        provider: &dyn ProviderConfig,
+    ) -> Result<AgentOutput, AgentExecutionError> {
+        let start = std::time::Instant::now();
+
+        let mut child = Command::new(command)
+            .args(args)
+            .envs(env.iter())
+            .current_dir(working_dir.unwrap_or_else(|| Path::new(".")))
+            .stdin(if large_input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    AgentExecutionError::CommandNotFound(command.to_string())
+                } else {
+                    AgentExecutionError::IoError(e)
+                }
+            })?;
+
+        let token = CancellationToken::new();
+        let output_tx = registry.register(agent_id, token.clone());
+
+        if let Some(input) = large_input
+            && let Some(mut stdin) = child.stdin.take()
+        {
+            let written = stdin.write_all(input.as_bytes()).await;
+            drop(stdin);
+            if let Err(err) = written {
+                registry.unregister(agent_id);
+                return Err(AgentExecutionError::IoError(err));
+            }
+        }
+
+        let stdout_buf = Arc::new(Mutex::new(Vec::new()));
+        let stderr_buf = Arc::new(Mutex::new(Vec::new()));
+        let mut readers = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            readers.push(spawn_capture(stdout, Arc::clone(&stdout_buf)));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            readers.push(spawn_capture(stderr, Arc::clone(&stderr_buf)));
+        }
+
+        let timeout_duration = Duration::from_secs(timeout_secs);
+        let waited = tokio::time::timeout(timeout_duration, child.wait().or_cancel(&token)).await;
+        let (exit_code, outcome) = match waited {
+            Ok(Ok(Ok(status))) => (status.code().unwrap_or(-1), Ok(())),
+            Ok(Ok(Err(err))) => (-1, Err(AgentExecutionError::IoError(err))),
+            Ok(Err(CancelErr::Cancelled)) => {
+                let code = terminate_gracefully(&mut child, registry.grace_period).await;
+                (code, Err(AgentExecutionError::Cancelled))
+            }
+            Err(_) => {
+                let _ = child.kill().await;
+                (-1, Err(AgentExecutionError::Timeout(timeout_secs)))
+            }
+        };
+
+        for mut reader in readers {
+            if tokio::time::timeout(OUTPUT_DRAIN_TIMEOUT, &mut reader)
+                .await
+                .is_err()
+            {
+                reader.abort();
+            }
+        }
+        registry.unregister(agent_id);
+
+        let output = AgentOutput {
+            stdout: captured(&stdout_buf),
+            stderr: captured(&stderr_buf),
+            exit_code,
+            duration: start.elapsed(),
+            timed_out: false,
+        };
+
+        match outcome {
+            Ok(()) => {
+                if provider.detect_oauth2_error(&output.stderr) {
+                    return Err(AgentExecutionError::OAuth2Required(output.stderr));
+                }
+                Ok(output)
+            }
+            Err(AgentExecutionError::Cancelled) => {
+                let _ = output_tx.send(output);
+                Err(AgentExecutionError::Cancelled)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn request_exit(child: &tokio::process::Child) {
+    if let Some(pid) = child.id() {
+        // SAFETY: `pid` belongs to a child we have not yet reaped.
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn request_exit(_child: &tokio::process::Child) {}
+
+/// Ask the process to exit with SIGTERM, then SIGKILL it if it is still
+/// running after `grace_period`. Returns its exit code (-1 if signalled).
+async fn terminate_gracefully(child: &mut tokio::process::Child, grace_period: Duration) -> i32 {
+    request_exit(child);
+    match tokio::time::timeout(grace_period, child.wait()).await {
+        Ok(Ok(status)) => status.code().unwrap_or(-1),
+        _ => {
+            let _ = child.kill().await;
+            -1
+        }
+    }
+}
+
+// ============================================================================
+// Concurrency Limiting
+// ============================================================================
+
+use tokio::sync::Semaphore;
+
+/// Lifecycle of an agent submitted to a [`QueuedAgentExecutor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentRunStatus {
+    /// Waiting for a free slot.
+    Queued,
+    /// Holding a slot and executing.
+    Running,
+    /// Finished, successfully or not.
+    Done,
+}
+
+/// Snapshot of how many agents are running and waiting, e.g. for
+/// "3 running, 5 queued" in the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueueDepth {
+    pub running: usize,
+    pub queued: usize,
+}
+
+/// How an agent run ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AgentExitStatus {
+    /// Process exited with this code.
+    Exited(i32),
+    TimedOut,
+    Cancelled,
+    /// Execution failed before producing an exit code.
+    Failed(String),
+}
+
+impl AgentExitStatus {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Exited(0))
+    }
+}
+
+/// Resources consumed by one finished agent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentMetrics {
+    /// `None` when the provider does not report usage.
+    pub tokens: Option<AgentTokenUsage>,
+    /// Time spent running, excluding time queued.
+    pub duration: Duration,
+    /// Estimated USD cost; `None` when usage or model pricing is unknown.
+    pub cost: Option<f64>,
+    pub exit_status: AgentExitStatus,
+}
+
+/// Totals across every finished agent in a batch.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchSummary {
+    pub agents: usize,
+    pub failed: usize,
+    pub input_tokens: u64,
+    pub cached_input_tokens: u64,
+    pub output_tokens: u64,
+    /// Sum of per-agent running time.
+    pub duration: Duration,
+    /// Sum of the costs that could be estimated.
+    pub cost: f64,
+    /// Agents whose cost could not be estimated.
+    pub unpriced_agents: usize,
+}
+
+impl BatchSummary {
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+}
+
+/// Runs agents through an inner executor with at most `max_concurrent` of
+/// them in flight; the rest wait in FIFO order for a slot to free up.
+///
+/// Cloning is cheap; clones share the same limit, status and metrics tables.
+pub struct QueuedAgentExecutor<E> {
+    inner: Arc<E>,
+    slots: Arc<Semaphore>,
+    max_concurrent: usize,
+    statuses: Arc<Mutex<HashMap<String, AgentRunStatus>>>,
+    metrics: Arc<Mutex<HashMap<String, AgentMetrics>>>,
+}
+
+impl<E> Clone for QueuedAgentExecutor<E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            slots: Arc::clone(&self.slots),
+            max_concurrent: self.max_concurrent,
+            statuses: Arc::clone(&self.statuses),
+            metrics: Arc::clone(&self.metrics),
+        }
+    }
+}
+
+impl<E: AsyncAgentExecutor> QueuedAgentExecutor<E> {
+    /// Wrap `inner`, allowing `max_concurrent` agents (at least one) at once.
+    pub fn new(inner: E, max_concurrent: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        Self {
+            inner: Arc::new(inner),
+            slots: Arc::new(Semaphore::new(max_concurrent)),
+            max_concurrent,
+            statuses: Arc::new(Mutex::new(HashMap::new())),
+            metrics: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Current status of `agent_id`, if it has been submitted.
+    pub fn status(&self, agent_id: &str) -> Option<AgentRunStatus> {
+        self.statuses
+            .lock()
+            .ok()
+            .and_then(|statuses| statuses.get(agent_id).copied())
+    }
+
+    /// Number of agents currently running and queued.
+    pub fn queue_depth(&self) -> QueueDepth {
+        let Ok(statuses) = self.statuses.lock() else {
+            return QueueDepth::default();
+        };
+        statuses
+            .values()
+            .fold(QueueDepth::default(), |mut depth, status| {
+                match status {
+                    AgentRunStatus::Queued => depth.queued += 1,
+                    AgentRunStatus::Running => depth.running += 1,
+                    AgentRunStatus::Done => {}
+                }
+                depth
+            })
+    }
+
+    /// Resources used by `agent_id`, once it has finished.
+    pub fn metrics(&self, agent_id: &str) -> Option<AgentMetrics> {
+        self.metrics
+            .lock()
+            .ok()
+            .and_then(|metrics| metrics.get(agent_id).cloned())
+    }
+
+    /// Totals over every finished agent still tracked.
+    pub fn batch_summary(&self) -> BatchSummary {
+        let Ok(metrics) = self.metrics.lock() else {
+            return BatchSummary::default();
+        };
+        metrics
+            .values()
+            .fold(BatchSummary::default(), |mut summary, agent| {
+                summary.agents += 1;
+                if !agent.exit_status.is_success() {
+                    summary.failed += 1;
+                }
+                if let Some(tokens) = &agent.tokens {
+                    summary.input_tokens += tokens.input_tokens;
+                    summary.cached_input_tokens += tokens.cached_input_tokens;
+                    summary.output_tokens += tokens.output_tokens;
+                }
+                summary.duration += agent.duration;
+                match agent.cost {
+                    Some(cost) => summary.cost += cost,
+                    None => summary.unpriced_agents += 1,
+                }
+                summary
+            })
+    }
+
+    /// Forget agents that have finished, along with their metrics.
+    pub fn clear_done(&self) {
+        if let Ok(mut statuses) = self.statuses.lock() {
+            statuses.retain(|_, status| *status != AgentRunStatus::Done);
+        }
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.clear();
+        }
+    }
+
+    /// Queue `agent_id`, wait for a free slot, then run it on the inner
+    /// executor. Arguments are as for [`AsyncAgentExecutor::execute`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn run(
+        &self,
+        agent_id: &str,
+        command: &str,
+        args: &[String],
+        env: &HashMap<String, String>,
+        working_dir: Option<&Path>,
+        timeout_secs: u64,
+        large_input: Option<&str>,
+        provider: &dyn ProviderConfig,
+    ) -> Result<AgentOutput, AgentExecutionError> {
+        self.set_status(agent_id, AgentRunStatus::Queued);
+        let mut guard = RunGuard {
+            executor: self,
+            agent_id,
+            started: None,
+            finished: false,
+        };
+        let permit = match Arc::clone(&self.slots).acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => {
+                return Err(AgentExecutionError::OutputCaptureFailed(
+                    "agent queue closed".to_string(),
+                ));
+            }
+        };
+
+        self.set_status(agent_id, AgentRunStatus::Running);
+        let started = std::time::Instant::now();
+        guard.started = Some(started);
+        let result = self
+            .inner
+            .execute(
+                command,
+                args,
+                env,
+                working_dir,
+                timeout_secs,
+                large_input,
+                provider,
+            )
+            .await;
+        self.record_metrics(agent_id, &result, started.elapsed(), provider);
+        guard.finished = true;
+        drop(guard);
+        drop(permit);
+        result
+    }
+
+    fn record_metrics(
+        &self,
+        agent_id: &str,
+        result: &Result<AgentOutput, AgentExecutionError>,
+        duration: Duration,
+        provider: &dyn ProviderConfig,
+    ) {
+        let (tokens, exit_status) = match result {
+            Ok(output) if output.timed_out => (
+                provider.parse_token_usage(output),
+                AgentExitStatus::TimedOut,
+            ),
+            Ok(output) => (
+                provider.parse_token_usage(output),
+                AgentExitStatus::Exited(output.exit_code),
+            ),
+            Err(AgentExecutionError::Timeout(_)) => (None, AgentExitStatus::TimedOut),
+            Err(AgentExecutionError::Cancelled) => (None, AgentExitStatus::Cancelled),
+            Err(err) => (None, AgentExitStatus::Failed(err.to_string())),
+        };
+        let cost = tokens.as_ref().and_then(AgentTokenUsage::estimated_cost);
+        self.insert_metrics(
+            agent_id,
+            AgentMetrics {
+                tokens,
+                duration,
+                cost,
+                exit_status,
+            },
+        );
+    }
+
+    fn insert_metrics(&self, agent_id: &str, agent_metrics: AgentMetrics) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.insert(agent_id.to_string(), agent_metrics);
+        }
+    }
+
+    fn set_status(&self, agent_id: &str, status: AgentRunStatus) {
+        if let Ok(mut statuses) = self.statuses.lock() {
+            statuses.insert(agent_id.to_string(), status);
+        }
+    }
+}
+
+/// Marks an agent Done when its [`QueuedAgentExecutor::run`] future
+/// finishes or is dropped, so an abandoned run does not stay Queued/Running
+/// and inflate [`QueuedAgentExecutor::queue_depth`]. A run dropped after it
+/// started is recorded as cancelled.
+struct RunGuard<'a, E: AsyncAgentExecutor> {
+    executor: &'a QueuedAgentExecutor<E>,
+    agent_id: &'a str,
+    started: Option<std::time::Instant>,
+    finished: bool,
+}
+
+impl<E: AsyncAgentExecutor> Drop for RunGuard<'_, E> {
+    fn drop(&mut self) {
+        if let Some(started) = self.started
+            && !self.finished
+        {
+            self.executor.insert_metrics(
+                self.agent_id,
+                AgentMetrics {
+                    tokens: None,
+                    duration: started.elapsed(),
+                    cost: None,
+                    exit_status: AgentExitStatus::Cancelled,
+                },
+            );
+        }
+        self.executor
+            .set_status(self.agent_id, AgentRunStatus::Done);
+    }
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
@@ -1261,6 +2036,433 @@ mod tests {
         // No explicit verification needed - timeout + drop guarantees cleanup
     }
 
+    /// Test cancelling a long-running agent mid-flight
+    ///
+    /// Verifies:
+    /// - cancel() returns output written before cancellation
+    /// - an agent ignoring SIGTERM is force-killed after the grace period
+    /// - tracked temp artifacts are removed
+    /// - the executing call returns Cancelled
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_cancel_returns_partial_output() {
+        let registry =
+            AgentCancellationRegistry::new().with_grace_period(Duration::from_millis(300));
+        let artifact = tempfile::NamedTempFile::new()
+            .unwrap()
+            .into_temp_path()
+            .keep()
+            .unwrap();
+
+        let run = tokio::spawn({
+            let registry = registry.clone();
+            async move {
+                DirectProcessExecutor
+                    .execute_cancellable(
+                        &registry,
+                        "agent-1",
+                        "sh",
+                        &[
+                            "-c".to_string(),
+                            "trap '' TERM; echo started; while true; do sleep 0.1; done"
+                                .to_string(),
+                        ],
+                        &HashMap::new(),
+                        None,
+                        600,
+                        None,
+                        &AnthropicProvider::new(),
+                    )
+                    .await
+            }
+        });
+
+        // Wait until the agent is registered and has produced output.
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(registry.is_running("agent-1"));
+        registry.track_artifact("agent-1", artifact.clone());
+
+        let started = std::time::Instant::now();
+        let output = registry
+            .cancel("agent-1")
+            .await
+            .expect("cancel should return partial output");
+
+        assert!(
+            started.elapsed() < Duration::from_secs(3),
+            "cancel took {:?}",
+            started.elapsed()
+        );
+        assert!(
+            output.stdout.contains("started"),
+            "stdout: {}",
+            output.stdout
+        );
+        assert!(!artifact.exists(), "artifact should be removed");
+        assert!(!registry.is_running("agent-1"));
+        assert!(matches!(
+            run.await.unwrap(),
+            Err(AgentExecutionError::Cancelled)
+        ));
+        assert!(registry.cancel("agent-1").await.is_none());
+    }
+
+    /// Executor that blocks until released instead of spawning, reporting
+    /// each start and recording peak concurrency
+    struct GatedExecutor {
+        running: Arc<std::sync::atomic::AtomicUsize>,
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+        started: tokio::sync::mpsc::UnboundedSender<()>,
+        release: Arc<Semaphore>,
+    }
+
+    impl GatedExecutor {
+        fn new() -> (
+            Self,
+            tokio::sync::mpsc::UnboundedReceiver<()>,
+            Arc<Semaphore>,
+        ) {
+            let (started, started_rx) = tokio::sync::mpsc::unbounded_channel();
+            let release = Arc::new(Semaphore::new(0));
+            let executor = Self {
+                running: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                peak: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                started,
+                release: Arc::clone(&release),
+            };
+            (executor, started_rx, release)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncAgentExecutor for GatedExecutor {
+        async fn execute(
+            &self,
+            _command: &str,
+            _args: &[String],
+            _env: &HashMap<String, String>,
+            _working_dir: Option<&Path>,
+            _timeout_secs: u64,
+            _large_input: Option<&str>,
+            _provider: &dyn ProviderConfig,
+        ) -> Result<AgentOutput, AgentExecutionError> {
+            use std::sync::atomic::Ordering;
+            let now = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            let _ = self.started.send(());
+            if let Ok(permit) = self.release.acquire().await {
+                permit.forget();
+            }
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(AgentOutput {
+                stdout: String::new(),
+                stderr: String::new(),
+                exit_code: 0,
+                duration: Duration::ZERO,
+                timed_out: false,
+            })
+        }
+    }
+
+    /// Yield until `executor` reports `expected`, failing after a few seconds
+    async fn wait_for_queue_depth<E: AsyncAgentExecutor>(
+        executor: &QueuedAgentExecutor<E>,
+        expected: QueueDepth,
+    ) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while executor.queue_depth() != expected {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("queue depth never reached the expected value");
+    }
+
+    /// Test queueing agents beyond the concurrency limit
+    ///
+    /// Verifies:
+    /// - no more than max_concurrent agents run at once
+    /// - queue depth reports running and queued agents
+    /// - every queued agent eventually completes and is marked Done
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrency_limit_queues_excess_agents() {
+        let (gated, mut started, release) = GatedExecutor::new();
+        let peak = Arc::clone(&gated.peak);
+        let executor = QueuedAgentExecutor::new(gated, 3);
+
+        let tasks: Vec<_> = (0..8)
+            .map(|i| {
+                let executor = executor.clone();
+                tokio::spawn(async move {
+                    executor
+                        .run(
+                            &format!("agent-{i}"),
+                            "mock",
+                            &[],
+                            &HashMap::new(),
+                            None,
+                            60,
+                            None,
+                            &AnthropicProvider::new(),
+                        )
+                        .await
+                })
+            })
+            .collect();
+
+        for _ in 0..3 {
+            started.recv().await.unwrap();
+        }
+        wait_for_queue_depth(
+            &executor,
+            QueueDepth {
+                running: 3,
+                queued: 5,
+            },
+        )
+        .await;
+
+        release.add_permits(8);
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap().exit_code, 0);
+        }
+        assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+        assert_eq!(executor.queue_depth(), QueueDepth::default());
+        assert_eq!(executor.status("agent-7"), Some(AgentRunStatus::Done));
+        executor.clear_done();
+        assert_eq!(executor.status("agent-7"), None);
+    }
+
+    /// Test dropping queued and running agents
+    ///
+    /// Verifies:
+    /// - aborted runs are marked Done and leave the queue depth
+    /// - a run aborted while executing is recorded as cancelled
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_dropped_runs_leave_the_queue() {
+        let (gated, mut started, _release) = GatedExecutor::new();
+        let executor = QueuedAgentExecutor::new(gated, 1);
+
+        let spawn_run = |agent_id: &'static str| {
+            let executor = executor.clone();
+            tokio::spawn(async move {
+                executor
+                    .run(
+                        agent_id,
+                        "mock",
+                        &[],
+                        &HashMap::new(),
+                        None,
+                        60,
+                        None,
+                        &AnthropicProvider::new(),
+                    )
+                    .await
+            })
+        };
+        let running = spawn_run("running");
+        started.recv().await.unwrap();
+        let queued = spawn_run("queued");
+        wait_for_queue_depth(
+            &executor,
+            QueueDepth {
+                running: 1,
+                queued: 1,
+            },
+        )
+        .await;
+
+        running.abort();
+        queued.abort();
+        assert!(running.await.unwrap_err().is_cancelled());
+        assert!(queued.await.unwrap_err().is_cancelled());
+
+        assert_eq!(executor.queue_depth(), QueueDepth::default());
+        assert_eq!(executor.status("queued"), Some(AgentRunStatus::Done));
+        assert_eq!(
+            executor.metrics("running").map(|m| m.exit_status),
+            Some(AgentExitStatus::Cancelled)
+        );
+        assert_eq!(executor.metrics("queued"), None);
+    }
+
+    /// Executor whose stdout is its joined arguments
+    struct EchoExecutor;
+
+    #[async_trait::async_trait]
+    impl AsyncAgentExecutor for EchoExecutor {
+        async fn execute(
+            &self,
+            _command: &str,
+            args: &[String],
+            _env: &HashMap<String, String>,
+            _working_dir: Option<&Path>,
+            _timeout_secs: u64,
+            _large_input: Option<&str>,
+            _provider: &dyn ProviderConfig,
+        ) -> Result<AgentOutput, AgentExecutionError> {
+            Ok(AgentOutput {
+                stdout: args.join(" "),
+                stderr: String::new(),
+                exit_code: 0,
+                duration: Duration::ZERO,
+                timed_out: false,
+            })
+        }
+    }
+
+    /// Provider reading "<model> <input> <output>" usage from stdout
+    struct UsageProvider;
+
+    impl ProviderConfig for UsageProvider {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn cli_executable(&self) -> &str {
+            "mock"
+        }
+
+        fn required_env_vars(&self) -> Vec<String> {
+            Vec::new()
+        }
+
+        fn detect_oauth2_error(&self, _stderr: &str) -> bool {
+            false
+        }
+
+        fn format_small_prompt_args(&self, prompt: &str) -> Vec<String> {
+            vec![prompt.to_string()]
+        }
+
+        fn format_large_prompt_args(&self) -> Vec<String> {
+            Vec::new()
+        }
+
+        fn parse_token_usage(&self, output: &AgentOutput) -> Option<AgentTokenUsage> {
+            let mut parts = output.stdout.split_whitespace();
+            Some(AgentTokenUsage {
+                model: Some(parts.next()?.to_string()),
+                input_tokens: parts.next()?.parse().ok()?,
+                cached_input_tokens: 0,
+                output_tokens: parts.next()?.parse().ok()?,
+            })
+        }
+    }
+
+    /// Test per-agent metrics and batch totals
+    ///
+    /// Verifies:
+    /// - each agent's tokens, cost and exit status are recorded
+    /// - the batch summary sums tokens and cost across agents
+    #[tokio::test]
+    async fn test_per_agent_metrics_and_batch_summary() {
+        let executor = QueuedAgentExecutor::new(EchoExecutor, 2);
+        for (agent_id, usage) in [("small", "gpt-5 1000 500"), ("large", "gpt-5 4000 2000")] {
+            executor
+                .run(
+                    agent_id,
+                    "mock",
+                    &[usage.to_string()],
+                    &HashMap::new(),
+                    None,
+                    60,
+                    None,
+                    &UsageProvider,
+                )
+                .await
+                .unwrap();
+        }
+
+        let small = executor.metrics("small").unwrap();
+        assert_eq!(
+            small.tokens.as_ref().map(AgentTokenUsage::total_tokens),
+            Some(1500)
+        );
+        assert_eq!(small.exit_status, AgentExitStatus::Exited(0));
+        // gpt-5: $1.25 / 1M input, $10 / 1M output
+        assert!((small.cost.unwrap() - 0.00625).abs() < 1e-9);
+
+        let large = executor.metrics("large").unwrap();
+        assert_eq!(
+            large.tokens.as_ref().map(AgentTokenUsage::total_tokens),
+            Some(6000)
+        );
+        assert!((large.cost.unwrap() - 0.025).abs() < 1e-9);
+
+        let summary = executor.batch_summary();
+        assert_eq!(summary.agents, 2);
+        assert_eq!(summary.failed, 0);
+        assert_eq!(summary.input_tokens, 5000);
+        assert_eq!(summary.output_tokens, 2500);
+        assert_eq!(summary.total_tokens(), 7500);
+        assert_eq!(summary.unpriced_agents, 0);
+        assert!((summary.cost - 0.03125).abs() < 1e-9);
+    }
+
+    fn output_with_stdout(stdout: &str) -> AgentOutput {
+        AgentOutput {
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            duration: Duration::ZERO,
+            timed_out: false,
+        }
+    }
+
+    /// Test usage parsing from `claude -p --output-format json`
+    #[test]
+    fn test_anthropic_parses_json_result_usage() {
+        let output = output_with_stdout(
+            r#"{"type":"result","subtype":"success","result":"done","total_cost_usd":0.01,
+                "usage":{"input_tokens":100,"cache_creation_input_tokens":200,
+                         "cache_read_input_tokens":700,"output_tokens":50},
+                "modelUsage":{"claude-haiku-4-5-20251001":{"outputTokens":5},
+                              "claude-sonnet-4-5-20250929":{"outputTokens":45}}}"#,
+        );
+        let usage = AnthropicProvider::new().parse_token_usage(&output).unwrap();
+        assert_eq!(usage.model.as_deref(), Some("claude-sonnet-4-5-20250929"));
+        assert_eq!(usage.input_tokens, 1000);
+        assert_eq!(usage.cached_input_tokens, 700);
+        assert_eq!(usage.output_tokens, 50);
+        assert!(usage.estimated_cost().is_some());
+
+        // Plain text output carries no usage
+        let text = output_with_stdout("Hello from Claude");
+        assert_eq!(AnthropicProvider::new().parse_token_usage(&text), None);
+    }
+
+    /// Test usage parsing from `gemini --output-format json`
+    #[test]
+    fn test_google_parses_stats_usage() {
+        let output = output_with_stdout(
+            r#"{"response":"done","stats":{"models":{
+                "gemini-2.5-pro":{"tokens":{"prompt":1000,"candidates":200,"total":1300,"cached":400,"thoughts":100}},
+                "gemini-2.5-flash":{"tokens":{"prompt":50,"candidates":10,"total":60,"cached":0}}}}}"#,
+        );
+        let usage = GoogleProvider::new().parse_token_usage(&output).unwrap();
+        assert_eq!(usage.model.as_deref(), Some("gemini-2.5-pro"));
+        assert_eq!(usage.input_tokens, 1050);
+        assert_eq!(usage.cached_input_tokens, 400);
+        assert_eq!(usage.output_tokens, 310);
+    }
+
+    /// Test usage parsing from an OpenAI-compatible JSON response
+    #[test]
+    fn test_openai_parses_response_usage() {
+        let output = output_with_stdout(
+            "progress...\n{\"model\":\"gpt-4o-2024-08-06\",\"usage\":{\"prompt_tokens\":1000,\
+             \"completion_tokens\":100,\"prompt_tokens_details\":{\"cached_tokens\":600}}}\n",
+        );
+        let usage = OpenAIProvider::new().parse_token_usage(&output).unwrap();
+        assert_eq!(usage.model.as_deref(), Some("gpt-4o-2024-08-06"));
+        assert_eq!(usage.input_tokens, 1000);
+        assert_eq!(usage.cached_input_tokens, 600);
+        assert_eq!(usage.output_tokens, 100);
+        // gpt-4o: 400 * $2.50 + 600 * $1.25 + 100 * $10 per 1M
+        assert!((usage.estimated_cost().unwrap() - 0.00275).abs() < 1e-9);
+    }
+
     // ========================================================================
     // Provider Configuration Tests (Phase 3: Multi-Provider Support)
     // ========================================================================
@@ -2,6 +2,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::env;
 use std::fs::File;
 use std::fs::OpenOptions;
@@ -158,6 +159,17 @@ impl CodexAuth {
         self.get_current_auth_json().and_then(|t| t.tokens)
     }
 
+    /// Whether the ChatGPT access token expires within `margin` of `now`.
+    /// Always false for API keys and for tokens without an `exp` claim.
+    fn expires_within(&self, margin: chrono::Duration, now: DateTime<Utc>) -> bool {
+        if self.mode != AuthMode::ChatGPT {
+            return false;
+        }
+        self.get_current_token_data()
+            .and_then(|t| t.access_token_expires_at())
+            .is_some_and(|expires_at| expires_at - now <= margin)
+    }
+
     /// Consider this private to integration tests.
     pub fn create_dummy_chatgpt_auth_for_testing() -> Self {
         let auth_dot_json = AuthDotJson {
@@ -413,6 +425,18 @@ async fn update_tokens(
     Ok(auth_dot_json)
 }
 
+const REFRESH_TOKEN_URL: &str = "https://auth.openai.com/oauth/token";
+
+/// Overrides the OAuth token endpoint used for refreshes (tests and proxies).
+pub const REFRESH_TOKEN_URL_OVERRIDE_ENV_VAR: &str = "CODEX_REFRESH_TOKEN_URL_OVERRIDE";
+
+fn refresh_token_url() -> String {
+    env::var(REFRESH_TOKEN_URL_OVERRIDE_ENV_VAR)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| REFRESH_TOKEN_URL.to_string())
+}
+
 async fn try_refresh_token(
     refresh_token: String,
     client: &reqwest::Client,
@@ -426,7 +450,7 @@ async fn try_refresh_token(
 
     // Use shared client factory to include standard headers
     let response = client
-        .post("https://auth.openai.com/oauth/token")
+        .post(refresh_token_url())
         .header("Content-Type", "application/json")
         .json(&refresh_request)
         .send()
@@ -521,6 +545,196 @@ mod tests {
         assert_eq!(auth_dot_json, same_auth_dot_json);
     }
 
+    #[test]
+    fn rate_limit_fails_over_to_secondary_and_back_after_reset() {
+        let dir = tempdir().unwrap();
+        let home = dir.path();
+        let primary = crate::auth_accounts::upsert_api_key_account(
+            home,
+            "sk-primary".to_string(),
+            None,
+            false,
+        )
+        .unwrap();
+        let secondary = crate::auth_accounts::upsert_api_key_account(
+            home,
+            "sk-secondary".to_string(),
+            None,
+            false,
+        )
+        .unwrap();
+        activate_account(home, &primary.id).unwrap();
+
+        let manager = AuthManager::new(home.to_path_buf(), AuthMode::ApiKey, "test".to_string());
+        manager.set_failover_accounts(vec![primary.id.clone(), secondary.id.clone()]);
+        let api_key = |m: &AuthManager| m.auth().and_then(|a| a.api_key);
+        assert_eq!(api_key(&manager).as_deref(), Some("sk-primary"));
+
+        let now = Utc::now();
+        let switch = manager
+            .fail_over_on_rate_limit(Some(600), now)
+            .unwrap()
+            .expect("switches to secondary");
+        assert_eq!(switch.from.as_deref(), Some(primary.id.as_str()));
+        assert_eq!(switch.to, secondary.id);
+        assert_eq!(switch.reason, AccountSwitchReason::RateLimited);
+        assert_eq!(api_key(&manager).as_deref(), Some("sk-secondary"));
+
+        // Before the primary resets we stay on the secondary.
+        let before_reset = now + chrono::Duration::seconds(300);
+        assert_eq!(
+            manager.restore_preferred_account(before_reset).unwrap(),
+            None
+        );
+        assert_eq!(api_key(&manager).as_deref(), Some("sk-secondary"));
+
+        let after_reset = now + chrono::Duration::seconds(601);
+        let switch = manager
+            .restore_preferred_account(after_reset)
+            .unwrap()
+            .expect("switches back to primary");
+        assert_eq!(switch.to, primary.id);
+        assert_eq!(switch.reason, AccountSwitchReason::LimitReset);
+        assert_eq!(api_key(&manager).as_deref(), Some("sk-primary"));
+        assert_eq!(
+            crate::auth_accounts::get_active_account_id(home).unwrap(),
+            Some(primary.id)
+        );
+        assert_eq!(manager.account_switches().len(), 2);
+    }
+
+    #[test]
+    fn new_session_stays_off_a_primary_still_limited_on_disk() {
+        let dir = tempdir().unwrap();
+        let home = dir.path();
+        let primary = crate::auth_accounts::upsert_api_key_account(
+            home,
+            "sk-primary".to_string(),
+            None,
+            false,
+        )
+        .unwrap();
+        let secondary = crate::auth_accounts::upsert_api_key_account(
+            home,
+            "sk-secondary".to_string(),
+            None,
+            false,
+        )
+        .unwrap();
+        let now = Utc::now();
+        crate::account_usage::record_usage_limit_hint(home, &primary.id, None, Some(600), now)
+            .unwrap();
+        activate_account(home, &secondary.id).unwrap();
+
+        // A fresh manager has no in-memory limits, as after ConfigureSession.
+        let manager = AuthManager::new(home.to_path_buf(), AuthMode::ApiKey, "test".to_string());
+        manager.set_failover_accounts(vec![primary.id.clone(), secondary.id.clone()]);
+
+        let before_reset = now + chrono::Duration::seconds(300);
+        assert_eq!(
+            manager.restore_preferred_account(before_reset).unwrap(),
+            None
+        );
+        let after_reset = now + chrono::Duration::seconds(601);
+        let switch = manager
+            .restore_preferred_account(after_reset)
+            .unwrap()
+            .expect("switches back once the stored limit resets");
+        assert_eq!(switch.to, primary.id);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn about_to_expire_token_is_refreshed_once_under_concurrent_access() {
+        use wiremock::Mock;
+        use wiremock::MockServer;
+        use wiremock::ResponseTemplate;
+        use wiremock::matchers::method;
+        use wiremock::matchers::path;
+
+        let jwt = |claims: serde_json::Value| {
+            let b64 = |b: &[u8]| base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(b);
+            format!(
+                "{}.{}.{}",
+                b64(br#"{"alg":"none","typ":"JWT"}"#),
+                b64(&serde_json::to_vec(&claims).unwrap()),
+                b64(b"sig")
+            )
+        };
+        let expiring =
+            jwt(json!({ "exp": (Utc::now() + chrono::Duration::seconds(30)).timestamp() }));
+        let refreshed =
+            jwt(json!({ "exp": (Utc::now() + chrono::Duration::hours(1)).timestamp() }));
+        let id_token = jwt(json!({ "email": "user@example.com" }));
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(Duration::from_millis(200))
+                    .set_body_json(json!({
+                        "id_token": id_token,
+                        "access_token": refreshed,
+                        "refresh_token": "new-refresh-token",
+                    })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        // SAFETY: no other test reads or writes this variable.
+        unsafe {
+            std::env::set_var(
+                REFRESH_TOKEN_URL_OVERRIDE_ENV_VAR,
+                format!("{}/oauth/token", server.uri()),
+            );
+        }
+
+        let codex_home = tempdir().unwrap();
+        let auth_json = json!({
+            "OPENAI_API_KEY": null,
+            "tokens": {
+                "id_token": id_token,
+                "access_token": expiring,
+                "refresh_token": "old-refresh-token",
+            },
+            "last_refresh": Utc::now(),
+        });
+        std::fs::write(
+            get_auth_file(codex_home.path()),
+            serde_json::to_string(&auth_json).unwrap(),
+        )
+        .unwrap();
+
+        let manager = Arc::new(AuthManager::new(
+            codex_home.path().to_path_buf(),
+            AuthMode::ChatGPT,
+            "codex_cli_rs".to_string(),
+        ));
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let manager = Arc::clone(&manager);
+                tokio::spawn(async move { manager.fresh_auth().await })
+            })
+            .collect();
+        for task in tasks {
+            let auth = task.await.unwrap().expect("auth should be present");
+            assert_eq!(auth.get_token().await.unwrap(), refreshed);
+        }
+
+        unsafe {
+            std::env::remove_var(REFRESH_TOKEN_URL_OVERRIDE_ENV_VAR);
+        }
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn fresh_auth_leaves_api_key_untouched() {
+        let manager = AuthManager::from_auth_for_testing(CodexAuth::from_api_key("sk-test"));
+        manager.set_refresh_margin(Duration::from_secs(u64::MAX));
+        let auth = manager.fresh_auth().await.expect("auth should be present");
+        assert_eq!(auth.get_token().await.unwrap(), "sk-test");
+    }
+
     #[test]
     fn login_with_api_key_overwrites_existing_auth_json() {
         let dir = tempdir().unwrap();
@@ -775,6 +989,118 @@ pub struct AuthManager {
     codex_home: PathBuf,
     originator: String,
     inner: RwLock<CachedAuth>,
+    failover: Mutex<AccountFailover>,
+    refresh_margin: Mutex<Duration>,
+    /// Held while a proactive refresh is in flight so concurrent callers wait
+    /// for it instead of issuing their own.
+    refresh_lock: tokio::sync::Mutex<()>,
+}
+
+/// Default window before access-token expiry in which `AuthManager` refreshes
+/// the token before handing it out.
+pub const DEFAULT_TOKEN_REFRESH_MARGIN_SECS: u64 = 5 * 60;
+
+/// Back-off applied to a rate-limited account when neither the error nor a
+/// stored rate-limit snapshot says when it resets.
+const DEFAULT_RATE_LIMIT_BACKOFF_SECS: i64 = 15 * 60;
+
+/// Why the manager moved to a different stored account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountSwitchReason {
+    /// The active account hit a rate limit.
+    RateLimited,
+    /// A higher-priority account's rate limit has reset.
+    LimitReset,
+}
+
+/// A recorded move from one stored account to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountSwitch {
+    pub from: Option<String>,
+    pub to: String,
+    pub reason: AccountSwitchReason,
+    pub at: DateTime<Utc>,
+}
+
+/// Ordered stored-account ids to rotate through on rate limits, with the
+/// time each limited account becomes usable again.
+#[derive(Debug, Default)]
+struct AccountFailover {
+    order: Vec<String>,
+    limited_until: HashMap<String, DateTime<Utc>>,
+    switches: Vec<AccountSwitch>,
+}
+
+impl AccountFailover {
+    /// False while the account is rate limited, either as seen by this
+    /// session or as recorded in its stored usage by an earlier one.
+    fn is_available(&self, codex_home: &Path, account_id: &str, now: DateTime<Utc>) -> bool {
+        self.limited_until
+            .get(account_id)
+            .is_none_or(|until| *until <= now)
+            && stored_limited_until(codex_home, account_id, now).is_none()
+    }
+
+    fn position(&self, account_id: Option<&String>) -> Option<usize> {
+        account_id.and_then(|id| self.order.iter().position(|o| o == id))
+    }
+}
+
+fn stored_snapshot(
+    codex_home: &Path,
+    account_id: &str,
+) -> Option<crate::account_usage::StoredRateLimitSnapshot> {
+    crate::account_usage::list_rate_limit_snapshots(codex_home)
+        .ok()?
+        .into_iter()
+        .find(|s| s.account_id == account_id)
+}
+
+/// Reset time recorded in the account's last rate-limit snapshot for the
+/// more exhausted window, if it is still in the future.
+fn stored_reset_at(
+    codex_home: &Path,
+    account_id: &str,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    snapshot_reset_at(&stored_snapshot(codex_home, account_id)?, now)
+}
+
+/// When an account that hit its usage limit becomes usable again, according
+/// to its stored usage. `None` if it is not limited: it never hit the limit,
+/// the window has since reset, or a later snapshot shows headroom.
+fn stored_limited_until(
+    codex_home: &Path,
+    account_id: &str,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let snapshot = stored_snapshot(codex_home, account_id)?;
+    let hit_at = snapshot.last_usage_limit_hit_at?;
+    let exhausted = snapshot
+        .snapshot
+        .as_ref()
+        .is_some_and(|s| s.primary_used_percent >= 100.0 || s.secondary_used_percent >= 100.0);
+    let observed_since_hit = snapshot.observed_at.is_some_and(|at| at > hit_at);
+    if observed_since_hit && !exhausted {
+        return None;
+    }
+    snapshot_reset_at(&snapshot, now)
+}
+
+fn snapshot_reset_at(
+    snapshot: &crate::account_usage::StoredRateLimitSnapshot,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let secondary_exhausted = snapshot
+        .snapshot
+        .as_ref()
+        .is_some_and(|s| s.secondary_used_percent > s.primary_used_percent);
+    let reset_at = if secondary_exhausted {
+        snapshot.secondary_next_reset_at
+    } else {
+        snapshot.primary_next_reset_at
+    };
+    reset_at.filter(|at| *at > now)
 }
 
 impl AuthManager {
@@ -793,6 +1119,9 @@ impl AuthManager {
                 preferred_auth_mode,
                 auth,
             }),
+            failover: Mutex::new(AccountFailover::default()),
+            refresh_margin: Mutex::new(Duration::from_secs(DEFAULT_TOKEN_REFRESH_MARGIN_SECS)),
+            refresh_lock: tokio::sync::Mutex::new(()),
         }
     }
 
@@ -807,6 +1136,9 @@ impl AuthManager {
             codex_home: PathBuf::new(),
             originator: "codex_cli_rs".to_string(),
             inner: RwLock::new(cached),
+            failover: Mutex::new(AccountFailover::default()),
+            refresh_margin: Mutex::new(Duration::from_secs(DEFAULT_TOKEN_REFRESH_MARGIN_SECS)),
+            refresh_lock: tokio::sync::Mutex::new(()),
         })
     }
 
@@ -873,6 +1205,51 @@ impl AuthManager {
         }
     }
 
+    /// Set how long before access-token expiry `fresh_auth` refreshes it.
+    pub fn set_refresh_margin(&self, margin: Duration) {
+        if let Ok(mut guard) = self.refresh_margin.lock() {
+            *guard = margin;
+        }
+    }
+
+    /// Like `auth()`, but first refreshes a ChatGPT access token that expires
+    /// within the refresh margin. Concurrent callers share a single refresh.
+    /// If the refresh fails the current auth is returned unchanged so the
+    /// request can still fall back to refreshing on a 401.
+    pub async fn fresh_auth(&self) -> Option<CodexAuth> {
+        let auth = self.auth()?;
+        if !self.needs_refresh(&auth) {
+            return Some(auth);
+        }
+
+        let _guard = self.refresh_lock.lock().await;
+        // Another caller may have refreshed while we waited for the lock.
+        let auth = self.auth()?;
+        if !self.needs_refresh(&auth) {
+            return Some(auth);
+        }
+        match auth.refresh_token().await {
+            Ok(_) => {
+                self.reload();
+                self.auth()
+            }
+            Err(err) => {
+                tracing::warn!("Proactive token refresh failed: {err}");
+                Some(auth)
+            }
+        }
+    }
+
+    fn needs_refresh(&self, auth: &CodexAuth) -> bool {
+        let margin = self
+            .refresh_margin
+            .lock()
+            .map(|m| *m)
+            .unwrap_or(Duration::from_secs(DEFAULT_TOKEN_REFRESH_MARGIN_SECS));
+        let margin = chrono::Duration::from_std(margin).unwrap_or(chrono::Duration::MAX);
+        auth.expires_within(margin, Utc::now())
+    }
+
     /// Log out by deleting the on‑disk auth.json (if present). Returns Ok(true)
     /// if a file was removed, Ok(false) if no auth file existed. On success,
     /// reloads the in‑memory auth cache so callers immediately observe the
@@ -883,4 +1260,127 @@ impl AuthManager {
         self.reload();
         Ok(removed)
     }
+
+    /// Set the stored account ids to fail over between, most preferred first.
+    /// An empty list disables failover.
+    pub fn set_failover_accounts(&self, order: Vec<String>) {
+        if let Ok(mut failover) = self.failover.lock() {
+            failover.order = order;
+            failover.limited_until.clear();
+        }
+    }
+
+    /// Account switches made by failover so far, oldest first.
+    pub fn account_switches(&self) -> Vec<AccountSwitch> {
+        self.failover
+            .lock()
+            .map(|f| f.switches.clone())
+            .unwrap_or_default()
+    }
+
+    /// Mark the active account as rate limited until `resets_in_seconds`
+    /// (falling back to its stored rate-limit snapshot) and activate the next
+    /// failover account that is not limited. Returns the switch, or `None`
+    /// when failover is off or every other account is limited too.
+    pub fn fail_over_on_rate_limit(
+        &self,
+        resets_in_seconds: Option<u64>,
+        now: DateTime<Utc>,
+    ) -> std::io::Result<Option<AccountSwitch>> {
+        let mut failover = self
+            .failover
+            .lock()
+            .map_err(|_| std::io::Error::other("account failover state poisoned"))?;
+        if failover.order.is_empty() {
+            return Ok(None);
+        }
+
+        let active = crate::auth_accounts::get_active_account_id(&self.codex_home)?;
+        if let Some(active) = &active {
+            let until = resets_in_seconds
+                .map(|secs| now + chrono::Duration::seconds(secs as i64))
+                .or_else(|| stored_reset_at(&self.codex_home, active, now))
+                .unwrap_or(now + chrono::Duration::seconds(DEFAULT_RATE_LIMIT_BACKOFF_SECS));
+            failover.limited_until.insert(active.clone(), until);
+        }
+
+        let next = failover
+            .order
+            .iter()
+            .find(|id| {
+                Some(*id) != active.as_ref() && failover.is_available(&self.codex_home, id, now)
+            })
+            .cloned();
+        match next {
+            Some(next) => self
+                .switch_account(
+                    &mut failover,
+                    active,
+                    next,
+                    AccountSwitchReason::RateLimited,
+                    now,
+                )
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Move back to the most preferred failover account once its rate limit
+    /// has reset. Does nothing when the active account is not part of the
+    /// failover list, so a manual account choice is left alone.
+    pub fn restore_preferred_account(
+        &self,
+        now: DateTime<Utc>,
+    ) -> std::io::Result<Option<AccountSwitch>> {
+        let mut failover = self
+            .failover
+            .lock()
+            .map_err(|_| std::io::Error::other("account failover state poisoned"))?;
+        if failover.order.is_empty() {
+            return Ok(None);
+        }
+        failover.limited_until.retain(|_, until| *until > now);
+
+        let active = crate::auth_accounts::get_active_account_id(&self.codex_home)?;
+        let Some(active_position) = failover.position(active.as_ref()) else {
+            return Ok(None);
+        };
+        let preferred = failover.order[..active_position]
+            .iter()
+            .find(|id| failover.is_available(&self.codex_home, id, now))
+            .cloned();
+        match preferred {
+            Some(preferred) => self
+                .switch_account(
+                    &mut failover,
+                    active,
+                    preferred,
+                    AccountSwitchReason::LimitReset,
+                    now,
+                )
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn switch_account(
+        &self,
+        failover: &mut AccountFailover,
+        from: Option<String>,
+        to: String,
+        reason: AccountSwitchReason,
+        now: DateTime<Utc>,
+    ) -> std::io::Result<AccountSwitch> {
+        activate_account(&self.codex_home, &to)?;
+        self.reload();
+        tracing::info!("switched account {from:?} -> {to} ({reason:?})");
+        let switch = AccountSwitch {
+            from,
+            to,
+            reason,
+            at: now,
+        };
+        failover.switches.push(switch.clone());
+        Ok(switch)
+    }
 }
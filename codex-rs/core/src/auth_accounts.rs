@@ -7,6 +7,10 @@ use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
+use crate::account_usage::StoredRateLimitSnapshot;
+use crate::account_usage::TokenTotals;
+use crate::account_usage::list_rate_limit_snapshots;
+use crate::account_usage::load_account_usage;
 use crate::token_data::TokenData;
 
 const ACCOUNTS_FILE_NAME: &str = "auth_accounts.json";
@@ -333,6 +337,81 @@ pub fn upsert_chatgpt_account(
     Ok(stored)
 }
 
+/// Usage and rate-limit state combined across every stored account.
+#[derive(Debug, Clone, Default)]
+pub struct AggregatedUsage {
+    /// Number of stored accounts, including those with no recorded usage.
+    pub account_count: usize,
+    /// Lifetime token totals summed across accounts.
+    pub totals: TokenTotals,
+    /// Estimated USD spend across accounts for models with known pricing.
+    pub estimated_cost: f64,
+    /// Rate-limit snapshot of the account closest to its limit.
+    pub most_constrained: Option<StoredRateLimitSnapshot>,
+    /// Earliest upcoming rate-limit reset across accounts.
+    pub soonest_reset_at: Option<DateTime<Utc>>,
+}
+
+fn snapshot_used_percent(snapshot: &StoredRateLimitSnapshot) -> Option<f64> {
+    snapshot
+        .snapshot
+        .as_ref()
+        .map(|s| s.primary_used_percent.max(s.secondary_used_percent))
+}
+
+/// Combine stored usage and rate-limit snapshots for all stored accounts.
+/// Accounts without a usage file or snapshot contribute nothing.
+pub fn aggregate_usage(codex_home: &Path) -> io::Result<AggregatedUsage> {
+    let accounts = list_accounts(codex_home)?;
+    let snapshots = list_rate_limit_snapshots(codex_home)?;
+    let now = now();
+    let mut aggregated = AggregatedUsage {
+        account_count: accounts.len(),
+        ..Default::default()
+    };
+
+    for account in &accounts {
+        if let Some(summary) = load_account_usage(codex_home, &account.id)? {
+            aggregated.totals.add_totals(&summary.totals);
+            aggregated.estimated_cost += summary
+                .model_totals
+                .iter()
+                .filter_map(|(model, totals)| totals.estimated_cost(model))
+                .sum::<f64>();
+        }
+
+        let Some(snapshot) = snapshots.iter().find(|s| s.account_id == account.id) else {
+            continue;
+        };
+        for reset_at in [
+            snapshot.primary_next_reset_at,
+            snapshot.secondary_next_reset_at,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|reset_at| *reset_at > now)
+        {
+            if aggregated
+                .soonest_reset_at
+                .is_none_or(|soonest| reset_at < soonest)
+            {
+                aggregated.soonest_reset_at = Some(reset_at);
+            }
+        }
+        if let Some(used) = snapshot_used_percent(snapshot)
+            && aggregated
+                .most_constrained
+                .as_ref()
+                .and_then(snapshot_used_percent)
+                .is_none_or(|current| used > current)
+        {
+            aggregated.most_constrained = Some(snapshot.clone());
+        }
+    }
+
+    Ok(aggregated)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -445,4 +524,78 @@ mod tests {
         let active_after = get_active_account_id(home.path()).expect("active id");
         assert!(active_after.is_none());
     }
+
+    #[test]
+    fn aggregate_usage_sums_accounts_and_picks_soonest_reset() {
+        use crate::account_usage::record_rate_limit_snapshot;
+        use crate::account_usage::record_token_usage;
+        use crate::protocol::RateLimitSnapshotEvent;
+        use crate::protocol::TokenUsage;
+
+        let home = tempdir().expect("tempdir");
+        let first = upsert_api_key_account(home.path(), "sk-one".to_string(), None, true)
+            .expect("first account");
+        let second = upsert_api_key_account(home.path(), "sk-two".to_string(), None, false)
+            .expect("second account");
+        // A third account with no usage or snapshot must not break aggregation.
+        upsert_api_key_account(home.path(), "sk-three".to_string(), None, false)
+            .expect("third account");
+
+        let now = Utc::now();
+        let usage = |input_tokens, output_tokens| TokenUsage {
+            input_tokens,
+            cached_input_tokens: 0,
+            output_tokens,
+            reasoning_output_tokens: 0,
+            total_tokens: input_tokens + output_tokens,
+        };
+        record_token_usage(
+            home.path(),
+            &first.id,
+            None,
+            Some("gpt-5"),
+            &usage(400_000, 100_000),
+            now,
+        )
+        .expect("first usage");
+        record_token_usage(
+            home.path(),
+            &second.id,
+            None,
+            Some("gpt-5"),
+            &usage(600_000, 300_000),
+            now,
+        )
+        .expect("second usage");
+
+        let snapshot = |used, reset_after_seconds| RateLimitSnapshotEvent {
+            primary_used_percent: used,
+            secondary_used_percent: 10.0,
+            primary_to_secondary_ratio_percent: 50.0,
+            primary_window_minutes: 300,
+            secondary_window_minutes: 10_080,
+            primary_reset_after_seconds: Some(reset_after_seconds),
+            secondary_reset_after_seconds: Some(86_400),
+        };
+        // The first account is closer to its limit; the second resets sooner.
+        record_rate_limit_snapshot(home.path(), &first.id, None, &snapshot(85.0, 3_600), now)
+            .expect("first snapshot");
+        record_rate_limit_snapshot(home.path(), &second.id, None, &snapshot(40.0, 600), now)
+            .expect("second snapshot");
+
+        let aggregated = aggregate_usage(home.path()).expect("aggregate");
+        assert_eq!(aggregated.account_count, 3);
+        assert_eq!(aggregated.totals.input_tokens, 1_000_000);
+        assert_eq!(aggregated.totals.output_tokens, 400_000);
+        // 1M input at $1.25/M + 0.4M output at $10/M.
+        assert!((aggregated.estimated_cost - 5.25).abs() < 1e-9);
+        assert_eq!(
+            aggregated.most_constrained.map(|s| s.account_id),
+            Some(first.id)
+        );
+        assert_eq!(
+            aggregated.soonest_reset_at,
+            Some(now + chrono::Duration::seconds(600))
+        );
+    }
 }
@@ -24,8 +24,17 @@
 //!     assert!(result.mean_ms < 50.0, "Spawn took too long: {:.1}ms", result.mean_ms);
 //! }
 //! ```
+//!
+//! Results can be appended to a [`BenchmarkHistory`] (JSONL, one record per
+//! run, keyed by benchmark name and git commit) so trends survive between runs.
 
+use chrono::DateTime;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
 use std::future::Future;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 /// Configuration for a benchmark run
@@ -357,6 +366,155 @@ impl BenchmarkResult {
     }
 }
 
+/// Persisted summary of one benchmark run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    /// Benchmark name
+    pub name: String,
+    /// Git commit the run was measured at
+    pub commit: String,
+    /// When the run was recorded
+    pub recorded_at: DateTime<Utc>,
+    /// Mean execution time in milliseconds
+    pub mean_ms: f64,
+    /// 50th percentile (median) in milliseconds
+    pub p50_ms: f64,
+    /// 95th percentile in milliseconds
+    pub p95_ms: f64,
+    /// Standard deviation in milliseconds
+    pub stddev_ms: f64,
+    /// Number of successful samples collected
+    pub sample_count: usize,
+    /// Raw samples in microseconds, kept so later runs can be tested for
+    /// significance against this one
+    #[serde(default)]
+    pub samples_us: Vec<u64>,
+}
+
+impl BenchmarkRecord {
+    /// Snapshot a result for persistence, stamped with `commit` and the current time
+    pub fn from_result(result: &BenchmarkResult, commit: impl Into<String>) -> Self {
+        Self {
+            name: result.name.clone(),
+            commit: commit.into(),
+            recorded_at: Utc::now(),
+            mean_ms: result.mean_ms,
+            p50_ms: result.p50_ms,
+            p95_ms: result.p95_ms,
+            stddev_ms: result.stddev_ms,
+            sample_count: result.sample_count,
+            samples_us: result.samples_us.clone(),
+        }
+    }
+
+    /// Rebuild a [`BenchmarkResult`] from the stored samples
+    ///
+    /// Records written without samples keep their summary statistics; min,
+    /// max and p99 are unknown and left at zero.
+    pub fn to_result(&self) -> BenchmarkResult {
+        if self.samples_us.is_empty() {
+            return BenchmarkResult {
+                mean_ms: self.mean_ms,
+                stddev_ms: self.stddev_ms,
+                p50_ms: self.p50_ms,
+                p95_ms: self.p95_ms,
+                sample_count: self.sample_count,
+                ..BenchmarkResult::empty(&self.name)
+            };
+        }
+        let samples: Vec<Duration> = self
+            .samples_us
+            .iter()
+            .map(|&us| Duration::from_micros(us))
+            .collect();
+        BenchmarkResult::from_samples(&self.name, &samples)
+    }
+}
+
+/// Append-only benchmark history stored as JSON lines
+///
+/// Each line is one [`BenchmarkRecord`]; records for all benchmarks share the
+/// file and are filtered by name on load. File order is recording order.
+#[derive(Debug, Clone)]
+pub struct BenchmarkHistory {
+    path: PathBuf,
+}
+
+impl BenchmarkHistory {
+    /// Use the history file at `path` (created on first record)
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Path of the backing history file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a run's summary for `commit`
+    pub fn record(
+        &self,
+        result: &BenchmarkResult,
+        commit: impl Into<String>,
+    ) -> std::io::Result<BenchmarkRecord> {
+        let record = BenchmarkRecord::from_result(result, commit);
+        self.append(&record)?;
+        Ok(record)
+    }
+
+    /// Append an already-built record
+    pub fn append(&self, record: &BenchmarkRecord) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut line = serde_json::to_string(record).map_err(std::io::Error::other)?;
+        line.push('\n');
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())
+    }
+
+    /// Load every recorded run of `name`, oldest first
+    ///
+    /// A missing file is an empty history. Lines that fail to parse (e.g. a
+    /// torn write) are skipped with a warning.
+    pub fn load_history(&self, name: &str) -> std::io::Result<Vec<BenchmarkRecord>> {
+        let file = match std::fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut records = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<BenchmarkRecord>(&line) {
+                Ok(record) if record.name == name => records.push(record),
+                Ok(_) => {}
+                Err(e) => tracing::warn!(
+                    path = %self.path.display(),
+                    line = index + 1,
+                    error = %e,
+                    "Skipping unparseable benchmark history line"
+                ),
+            }
+        }
+        Ok(records)
+    }
+
+    /// Most recent run of `name`, if any
+    pub fn latest(&self, name: &str) -> std::io::Result<Option<BenchmarkRecord>> {
+        Ok(self.load_history(name)?.pop())
+    }
+}
+
 /// Calculate percentile from sorted samples
 fn percentile(sorted: &[u64], p: usize) -> u64 {
     if sorted.is_empty() {
@@ -549,6 +707,49 @@ mod tests {
         result.assert_mean_under(50.0); // Should not panic
     }
 
+    #[test]
+    fn test_history_records_and_loads_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = BenchmarkHistory::new(dir.path().join("bench/history.jsonl"));
+
+        for (commit, ms) in [("aaa111", 10), ("bbb222", 20), ("ccc333", 30)] {
+            let samples: Vec<Duration> = (0..4).map(|i| Duration::from_millis(ms + i)).collect();
+            let result = BenchmarkResult::from_samples("spawn", &samples);
+            history.record(&result, commit).unwrap();
+        }
+        let other = BenchmarkResult::from_samples("other", &[Duration::from_millis(1)]);
+        history.record(&other, "aaa111").unwrap();
+
+        let records = history.load_history("spawn").unwrap();
+        let commits: Vec<&str> = records.iter().map(|r| r.commit.as_str()).collect();
+        assert_eq!(commits, vec!["aaa111", "bbb222", "ccc333"]);
+
+        let last = &records[2];
+        assert!((last.mean_ms - 31.5).abs() < 0.01, "mean: {}", last.mean_ms);
+        assert!((last.p50_ms - 31.0).abs() < 0.01, "p50: {}", last.p50_ms);
+        assert!((last.p95_ms - 32.0).abs() < 0.01, "p95: {}", last.p95_ms);
+        assert!(
+            (last.stddev_ms - 1.118).abs() < 0.01,
+            "stddev: {}",
+            last.stddev_ms
+        );
+        assert_eq!(last.sample_count, 4);
+        assert!(records[0].recorded_at <= records[2].recorded_at);
+
+        let restored = last.to_result();
+        assert_eq!(restored.samples_us(), &[30_000, 31_000, 32_000, 33_000]);
+        assert_eq!(history.load_history("other").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_history_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = BenchmarkHistory::new(dir.path().join("missing.jsonl"));
+
+        assert!(history.load_history("spawn").unwrap().is_empty());
+        assert!(history.latest("spawn").unwrap().is_none());
+    }
+
     #[test]
     #[should_panic(expected = "FAILED")]
     fn test_assert_mean_under_fails() {
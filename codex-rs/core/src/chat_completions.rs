@@ -17,14 +17,19 @@ use tracing::trace;
 
 use crate::ModelProviderInfo;
 use crate::auth::AuthManager;
+use crate::client_common::DrainOnDrop;
 use crate::client_common::Prompt;
 use crate::client_common::ResponseEvent;
 use crate::client_common::ResponseStream;
+use crate::client_common::until_stream_dropped;
 use crate::debug_logger::DebugLogger;
 use crate::error::CodexErr;
 use crate::error::Result;
+use crate::http_client::retry_delay;
+use crate::http_client::send_with_timeout;
 use crate::model_family::ModelFamily;
 use crate::openai_tools::create_tools_json_for_chat_completions_api;
+use crate::protocol::TokenUsage;
 use crate::util::backoff;
 use codex_protocol::mcp_protocol::AuthMode;
 use codex_protocol::models::ContentItem;
@@ -324,6 +329,9 @@ pub(crate) async fn stream_chat_completions(
         "model": model_slug,
         "messages": messages,
         "stream": true,
+        // Ask for the trailing usage chunk; without it Chat Completions
+        // streams report no token usage at all.
+        "stream_options": { "include_usage": true },
         "tools": tools_json,
     });
 
@@ -376,7 +384,10 @@ pub(crate) async fn stream_chat_completions(
     loop {
         attempt += 1;
 
-        let auth = auth_manager.as_ref().and_then(|m| m.auth());
+        let auth = match auth_manager.as_ref() {
+            Some(manager) => manager.fresh_auth().await,
+            None => None,
+        };
         let mut req_builder = provider.create_request_builder(client, &auth).await?;
 
         if let Some(auth) = auth.as_ref()
@@ -386,11 +397,10 @@ pub(crate) async fn stream_chat_completions(
             req_builder = req_builder.header("chatgpt-account-id", account_id);
         }
 
-        let res = req_builder
+        let req_builder = req_builder
             .header(reqwest::header::ACCEPT, "text/event-stream")
-            .json(&payload)
-            .send()
-            .await;
+            .json(&payload);
+        let res = send_with_timeout(req_builder, provider.request_timeout()).await;
 
         match res {
             Ok(resp) if resp.status().is_success() => {
@@ -406,16 +416,21 @@ pub(crate) async fn stream_chat_completions(
                     );
                 }
                 let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent>>(1600);
-                let stream = resp.bytes_stream().map_err(CodexErr::Reqwest);
+                let stream = DrainOnDrop::new(resp.bytes_stream().map_err(CodexErr::Reqwest));
                 let debug_logger_clone = Arc::clone(debug_logger);
                 let request_id_clone = request_id.clone();
-                tokio::spawn(process_chat_sse(
-                    stream,
-                    tx_event,
-                    provider.stream_idle_timeout(),
-                    debug_logger_clone,
-                    request_id_clone,
-                ));
+                let idle_timeout = provider.stream_idle_timeout();
+                tokio::spawn(async move {
+                    let tx_closed = tx_event.clone();
+                    let processor = process_chat_sse(
+                        stream,
+                        tx_event,
+                        idle_timeout,
+                        debug_logger_clone,
+                        request_id_clone,
+                    );
+                    until_stream_dropped(&tx_closed, processor).await;
+                });
                 return Ok(ResponseStream { rx_event });
             }
             Ok(res) => {
@@ -441,15 +456,7 @@ pub(crate) async fn stream_chat_completions(
                     return Err(CodexErr::RetryLimit(status));
                 }
 
-                let retry_after_secs = res
-                    .headers()
-                    .get(reqwest::header::RETRY_AFTER)
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|s| s.parse::<u64>().ok());
-
-                let delay = retry_after_secs
-                    .map(|s| Duration::from_millis(s * 1_000))
-                    .unwrap_or_else(|| backoff(attempt));
+                let delay = retry_delay(res.headers(), attempt);
                 tokio::time::sleep(delay).await;
             }
             Err(e) => {
@@ -465,7 +472,7 @@ pub(crate) async fn stream_chat_completions(
                         );
                         let _ = logger.end_request_log(&request_id);
                     }
-                    return Err(e.into());
+                    return Err(e);
                 }
                 let delay = backoff(attempt);
                 tokio::time::sleep(delay).await;
@@ -505,6 +512,8 @@ async fn process_chat_sse<S>(
     let mut assistant_text = String::new();
     let mut reasoning_text = String::new();
     let mut current_item_id: Option<String> = None;
+    // Latest cumulative usage reported by the provider, if it streams any.
+    let mut token_usage: Option<TokenUsage> = None;
 
     loop {
         let sse = match timeout(idle_timeout, stream.next()).await {
@@ -531,7 +540,7 @@ async fn process_chat_sse<S>(
                 let _ = tx_event
                     .send(Ok(ResponseEvent::Completed {
                         response_id: String::new(),
-                        token_usage: None,
+                        token_usage,
                     }))
                     .await;
                 if let Ok(logger) = debug_logger.lock() {
@@ -592,7 +601,7 @@ async fn process_chat_sse<S>(
             let _ = tx_event
                 .send(Ok(ResponseEvent::Completed {
                     response_id: String::new(),
-                    token_usage: None,
+                    token_usage,
                 }))
                 .await;
             // Mark the request log as complete
@@ -638,6 +647,18 @@ async fn process_chat_sse<S>(
             current_item_id = Some(item_id.to_string());
         }
 
+        // Providers that stream usage report running totals; forward the
+        // difference so consumers can track cost as the response streams.
+        if let Some(usage) = chunk.get("usage").and_then(parse_chat_usage) {
+            let usage_delta = usage_since(token_usage.as_ref(), &usage);
+            token_usage = Some(usage);
+            if usage_delta != TokenUsage::default() {
+                let _ = tx_event
+                    .send(Ok(ResponseEvent::UsageDelta { usage_delta }))
+                    .await;
+            }
+        }
+
         let choice_opt = chunk.get("choices").and_then(|c| c.get(0));
 
         if let Some(choice) = choice_opt {
@@ -972,6 +993,9 @@ where
                 Poll::Ready(Some(Ok(ResponseEvent::RateLimits(snapshot)))) => {
                     return Poll::Ready(Some(Ok(ResponseEvent::RateLimits(snapshot))));
                 }
+                Poll::Ready(Some(Ok(ResponseEvent::UsageDelta { usage_delta }))) => {
+                    return Poll::Ready(Some(Ok(ResponseEvent::UsageDelta { usage_delta })));
+                }
                 Poll::Ready(Some(Ok(ResponseEvent::Completed {
                     response_id,
                     token_usage,
@@ -1111,6 +1135,44 @@ where
     }
 }
 
+/// Parse an OpenAI Chat Completions `usage` object. Returns `None` for
+/// `null` or malformed values.
+fn parse_chat_usage(usage: &serde_json::Value) -> Option<TokenUsage> {
+    let field = |path: &[&str]| {
+        path.iter()
+            .try_fold(usage, |v, key| v.get(key))
+            .and_then(serde_json::Value::as_u64)
+    };
+    let input_tokens = field(&["prompt_tokens"])?;
+    let output_tokens = field(&["completion_tokens"]).unwrap_or(0);
+    Some(TokenUsage {
+        input_tokens,
+        cached_input_tokens: field(&["prompt_tokens_details", "cached_tokens"]).unwrap_or(0),
+        output_tokens,
+        reasoning_output_tokens: field(&["completion_tokens_details", "reasoning_tokens"])
+            .unwrap_or(0),
+        total_tokens: field(&["total_tokens"]).unwrap_or(input_tokens + output_tokens),
+    })
+}
+
+/// Usage accrued between two cumulative readings.
+pub(crate) fn usage_since(previous: Option<&TokenUsage>, current: &TokenUsage) -> TokenUsage {
+    let Some(previous) = previous else {
+        return current.clone();
+    };
+    TokenUsage {
+        input_tokens: current.input_tokens.saturating_sub(previous.input_tokens),
+        cached_input_tokens: current
+            .cached_input_tokens
+            .saturating_sub(previous.cached_input_tokens),
+        output_tokens: current.output_tokens.saturating_sub(previous.output_tokens),
+        reasoning_output_tokens: current
+            .reasoning_output_tokens
+            .saturating_sub(previous.reasoning_output_tokens),
+        total_tokens: current.total_tokens.saturating_sub(previous.total_tokens),
+    }
+}
+
 /// Extension trait that activates aggregation on any stream of [`ResponseEvent`].
 pub(crate) trait AggregateStreamExt: Stream<Item = Result<ResponseEvent>> + Sized {
     /// Returns a new stream that emits **only** the final assistant message
@@ -333,6 +333,7 @@ impl ClaudePipesSession {
                 let _ = child.kill().await;
                 return Err(CliError::Timeout {
                     elapsed: self.config.max_response_time,
+                    partial_output: String::new(),
                 });
             }
 
@@ -270,6 +270,7 @@ impl GeminiPipesSession {
                 let _ = child.kill().await;
                 return Err(CliError::Timeout {
                     elapsed: self.config.max_response_time,
+                    partial_output: String::new(),
                 });
             }
 
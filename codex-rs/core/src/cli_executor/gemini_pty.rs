@@ -232,6 +232,7 @@ impl GeminiPtySession {
             if Instant::now() > deadline {
                 return Err(CliError::Timeout {
                     elapsed: Duration::from_secs(10),
+                    partial_output: String::new(),
                 });
             }
 
@@ -368,6 +369,7 @@ impl GeminiPtySession {
                 tracing::error!("Response timeout after {:?}", self.config.max_response_time);
                 return Err(CliError::Timeout {
                     elapsed: self.config.max_response_time,
+                    partial_output: String::new(),
                 });
             }
 
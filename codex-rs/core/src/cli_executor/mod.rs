@@ -9,6 +9,7 @@ pub mod gemini_pipes;
 pub mod gemini_pty;
 pub mod prompt_detector;
 pub mod stream;
+pub mod tool_spec;
 pub mod types;
 
 pub use claude::{ClaudeCliConfig, ClaudeCliExecutor};
@@ -20,6 +21,8 @@ pub use gemini_pty::{
     ConversationId, GeminiPtyConfig, GeminiPtyProvider, GeminiPtySession, SessionStats,
 };
 pub use prompt_detector::PromptDetector;
+pub use stream::{StreamLimits, StreamParser};
+pub use tool_spec::{CliToolExecutor, CliToolRegistry, CliToolSpec, StreamParserFactory};
 pub use types::*;
 
 /// Core trait for CLI-based model executors
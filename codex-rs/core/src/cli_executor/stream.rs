@@ -1,10 +1,163 @@
 use serde_json::Value;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::ChildStdout;
 use tokio::sync::mpsc;
+use tokio::time::{Duration, Instant};
 
 use super::types::{CliError, ResponseMetadata, StreamEvent};
 
+/// Converts a CLI tool's stdout, one line at a time, into stream events
+///
+/// A fresh parser is created per request, so implementations may keep state
+/// across lines.
+pub trait StreamParser: Send {
+    /// Events produced by one line of output. `CliError::ParseError` skips
+    /// the line (within the stream's error budget); any other error aborts.
+    fn parse_line(&mut self, line: &str) -> Result<Vec<StreamEvent>, CliError>;
+
+    /// Events to emit once output ends, before `StreamEvent::Done`
+    fn finish(&mut self) -> Vec<StreamEvent> {
+        Vec::new()
+    }
+}
+
+/// Limits applied while reading a CLI's output
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamLimits {
+    /// Limit for the whole stream
+    pub overall: Option<Duration>,
+    /// Limit between consecutive lines of output
+    pub idle: Option<Duration>,
+    /// Unparseable lines to skip before failing the stream (0 = strict)
+    pub max_parse_errors: usize,
+}
+
+/// Feed `reader` line by line through `parser`, forwarding events to `tx`
+/// and finishing with `StreamEvent::Done`
+pub async fn drive_stream_parser<R>(
+    reader: R,
+    parser: &mut dyn StreamParser,
+    tx: mpsc::Sender<StreamEvent>,
+) -> Result<(), CliError>
+where
+    R: AsyncRead + Unpin,
+{
+    drive_stream_parser_with_limits(reader, parser, tx, StreamLimits::default()).await
+}
+
+/// Like [`drive_stream_parser`], but gives up with `CliError::Timeout` once
+/// `limits.overall` has elapsed, or `CliError::IdleTimeout` when no line
+/// arrives within `limits.idle`. Either error carries the text streamed so
+/// far; `Done` is not sent.
+///
+/// Up to `limits.max_parse_errors` lines the parser rejects with
+/// `CliError::ParseError` are logged and skipped. When any were skipped, a
+/// final `StreamEvent::Metadata` (repeating the last usage seen) reports the
+/// count in `skipped_lines` before `Done`.
+pub async fn drive_stream_parser_with_limits<R>(
+    reader: R,
+    parser: &mut dyn StreamParser,
+    tx: mpsc::Sender<StreamEvent>,
+    limits: StreamLimits,
+) -> Result<(), CliError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut reader = BufReader::new(reader).lines();
+    let started = Instant::now();
+    let deadline = limits.overall.map(|overall| started + overall);
+    let mut partial_output = String::new();
+    let mut last_metadata: Option<ResponseMetadata> = None;
+    let mut skipped_lines = 0;
+
+    loop {
+        let now = Instant::now();
+        let wait = match (deadline, limits.idle) {
+            (Some(deadline), Some(idle)) => Some(idle.min(deadline.saturating_duration_since(now))),
+            (Some(deadline), None) => Some(deadline.saturating_duration_since(now)),
+            (None, idle) => idle,
+        };
+        let next = match wait {
+            Some(wait) => match tokio::time::timeout(wait, reader.next_line()).await {
+                Ok(next) => next,
+                Err(_) => {
+                    let partial_output = std::mem::take(&mut partial_output);
+                    return Err(match (deadline, limits.idle) {
+                        (Some(deadline), _) if Instant::now() >= deadline => CliError::Timeout {
+                            elapsed: started.elapsed(),
+                            partial_output,
+                        },
+                        (_, idle) => CliError::IdleTimeout {
+                            idle: idle.unwrap_or(wait),
+                            partial_output,
+                        },
+                    });
+                }
+            },
+            None => reader.next_line().await,
+        };
+        let Some(line) = next.map_err(|e| CliError::Internal {
+            message: format!("Failed to read stdout: {e}"),
+        })?
+        else {
+            break;
+        };
+
+        let events = match parser.parse_line(&line) {
+            Ok(events) => events,
+            Err(CliError::ParseError { details }) if skipped_lines < limits.max_parse_errors => {
+                skipped_lines += 1;
+                tracing::warn!("Skipping unparseable CLI output line ({details}): {line}");
+                continue;
+            }
+            Err(CliError::ParseError { details }) => {
+                return Err(CliError::ParseError {
+                    details: format!("{details} (after skipping {skipped_lines} lines)"),
+                });
+            }
+            Err(e) => return Err(e),
+        };
+        for event in events {
+            match &event {
+                StreamEvent::Delta(text) => partial_output.push_str(text),
+                StreamEvent::Metadata(metadata) => last_metadata = Some(metadata.clone()),
+                _ => {}
+            }
+            send(&tx, event).await?;
+        }
+    }
+
+    for event in parser.finish() {
+        if let StreamEvent::Metadata(metadata) = &event {
+            last_metadata = Some(metadata.clone());
+        }
+        send(&tx, event).await?;
+    }
+    if skipped_lines > 0 {
+        let metadata = last_metadata.unwrap_or_else(|| ResponseMetadata {
+            model: "unknown".to_string(),
+            input_tokens: None,
+            output_tokens: None,
+            skipped_lines: 0,
+        });
+        send(
+            &tx,
+            StreamEvent::Metadata(ResponseMetadata {
+                skipped_lines,
+                ..metadata
+            }),
+        )
+        .await?;
+    }
+    send(&tx, StreamEvent::Done).await
+}
+
+async fn send(tx: &mpsc::Sender<StreamEvent>, event: StreamEvent) -> Result<(), CliError> {
+    tx.send(event).await.map_err(|e| CliError::Internal {
+        message: format!("Channel send failed: {e}"),
+    })
+}
+
 /// Parse Claude CLI stream-json format
 ///
 /// Claude outputs newline-delimited JSON:
@@ -14,15 +167,20 @@ pub async fn parse_claude_stream(
     stdout: ChildStdout,
     tx: mpsc::Sender<StreamEvent>,
 ) -> Result<(), CliError> {
-    let mut reader = BufReader::new(stdout).lines();
+    drive_stream_parser(stdout, &mut ClaudeStreamParser, tx).await
+}
 
-    while let Some(line) = reader.next_line().await.map_err(|e| CliError::Internal {
-        message: format!("Failed to read stdout: {e}"),
-    })? {
-        let json: Value = serde_json::from_str(&line).map_err(|e| CliError::ParseError {
+/// Line parser for Claude's `--output-format stream-json`
+#[derive(Debug, Default)]
+pub struct ClaudeStreamParser;
+
+impl StreamParser for ClaudeStreamParser {
+    fn parse_line(&mut self, line: &str) -> Result<Vec<StreamEvent>, CliError> {
+        let json: Value = serde_json::from_str(line).map_err(|e| CliError::ParseError {
             details: format!("Invalid JSON: {e}"),
         })?;
 
+        let mut events = Vec::new();
         match json["type"].as_str() {
             Some("system") => {
                 // Log init metadata but don't send to user
@@ -39,45 +197,30 @@ pub async fn parse_claude_stream(
                         if item["type"] == "text"
                             && let Some(text) = item["text"].as_str()
                         {
-                            tx.send(StreamEvent::Delta(text.to_string()))
-                                .await
-                                .map_err(|e| CliError::Internal {
-                                    message: format!("Channel send failed: {e}"),
-                                })?;
+                            events.push(StreamEvent::Delta(text.to_string()));
                         }
                     }
                 }
 
                 // Extract token usage
                 if let Some(usage) = json["message"]["usage"].as_object() {
-                    let metadata = ResponseMetadata {
+                    events.push(StreamEvent::Metadata(ResponseMetadata {
                         model: json["message"]["model"]
                             .as_str()
                             .unwrap_or("unknown")
                             .to_string(),
                         input_tokens: usage["input_tokens"].as_u64().map(|n| n as usize),
                         output_tokens: usage["output_tokens"].as_u64().map(|n| n as usize),
-                    };
-                    tx.send(StreamEvent::Metadata(metadata))
-                        .await
-                        .map_err(|e| CliError::Internal {
-                            message: format!("Channel send failed: {e}"),
-                        })?;
+                        skipped_lines: 0,
+                    }));
                 }
             }
             _ => {
                 tracing::warn!("Unknown stream type: {}", json["type"]);
             }
         }
+        Ok(events)
     }
-
-    tx.send(StreamEvent::Done)
-        .await
-        .map_err(|e| CliError::Internal {
-            message: format!("Channel send failed: {e}"),
-        })?;
-
-    Ok(())
 }
 
 /// Parse Gemini CLI stream-json format
@@ -87,20 +230,27 @@ pub async fn parse_gemini_stream(
     stdout: ChildStdout,
     tx: mpsc::Sender<StreamEvent>,
 ) -> Result<(), CliError> {
-    let mut reader = BufReader::new(stdout).lines();
-    let mut accumulated_text = String::new();
+    drive_stream_parser(stdout, &mut GeminiStreamParser::default(), tx).await
+}
 
-    while let Some(line) = reader.next_line().await.map_err(|e| CliError::Internal {
-        message: format!("Failed to read stdout: {e}"),
-    })? {
+/// Line parser for Gemini's `--output-format stream-json`, tolerating the
+/// plain-text lines it prints around retries
+#[derive(Debug, Default)]
+pub struct GeminiStreamParser {
+    accumulated_text: String,
+}
+
+impl StreamParser for GeminiStreamParser {
+    fn parse_line(&mut self, line: &str) -> Result<Vec<StreamEvent>, CliError> {
         // Skip credential loading messages (not JSON)
         if line.starts_with("Loaded cached credentials") || line.starts_with("Attempt ") {
             tracing::debug!("Gemini status: {}", line);
-            continue;
+            return Ok(Vec::new());
         }
 
+        let mut events = Vec::new();
         // Try to parse as JSON first
-        if let Ok(json) = serde_json::from_str::<Value>(&line) {
+        if let Ok(json) = serde_json::from_str::<Value>(line) {
             // Handle error responses
             if let Some(error) = json.get("error") {
                 let code = error["code"].as_i64().unwrap_or(0);
@@ -120,62 +270,42 @@ pub async fn parse_gemini_stream(
                 && json["role"] == "assistant"
                 && let Some(content) = json["content"].as_str()
             {
-                accumulated_text.push_str(content);
-                tx.send(StreamEvent::Delta(content.to_string()))
-                    .await
-                    .map_err(|e| CliError::Internal {
-                        message: format!("Channel send failed: {e}"),
-                    })?;
+                self.accumulated_text.push_str(content);
+                events.push(StreamEvent::Delta(content.to_string()));
             }
 
             // Handle result/stats
             if json["type"] == "result"
                 && let Some(stats) = json["stats"].as_object()
             {
-                tx.send(StreamEvent::Metadata(ResponseMetadata {
+                events.push(StreamEvent::Metadata(ResponseMetadata {
                     model: "gemini".to_string(),
                     input_tokens: stats["input_tokens"].as_u64().map(|n| n as usize),
                     output_tokens: stats["output_tokens"].as_u64().map(|n| n as usize),
-                }))
-                .await
-                .map_err(|e| CliError::Internal {
-                    message: format!("Channel send failed: {e}"),
-                })?;
+                    skipped_lines: 0,
+                }));
             }
-        } else {
+        } else if !line.is_empty() {
             // Fallback: treat as plain text response
             // Gemini may output plain text after retries succeed
-            if !line.is_empty() {
-                accumulated_text.push_str(&line);
-                tx.send(StreamEvent::Delta(line))
-                    .await
-                    .map_err(|e| CliError::Internal {
-                        message: format!("Channel send failed: {e}"),
-                    })?;
-            }
+            self.accumulated_text.push_str(line);
+            events.push(StreamEvent::Delta(line.to_string()));
         }
+        Ok(events)
     }
 
-    // Send metadata if we got any response
-    if !accumulated_text.is_empty() {
-        tx.send(StreamEvent::Metadata(ResponseMetadata {
+    fn finish(&mut self) -> Vec<StreamEvent> {
+        // Send metadata if we got any response
+        if self.accumulated_text.is_empty() {
+            return Vec::new();
+        }
+        vec![StreamEvent::Metadata(ResponseMetadata {
             model: "gemini".to_string(),
             input_tokens: None, // Not available in text mode
             output_tokens: None,
-        }))
-        .await
-        .map_err(|e| CliError::Internal {
-            message: format!("Channel send failed: {e}"),
-        })?;
+            skipped_lines: 0,
+        })]
     }
-
-    tx.send(StreamEvent::Done)
-        .await
-        .map_err(|e| CliError::Internal {
-            message: format!("Channel send failed: {e}"),
-        })?;
-
-    Ok(())
 }
 
 #[cfg(test)]
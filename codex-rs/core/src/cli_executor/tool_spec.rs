@@ -0,0 +1,603 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tokio::time::Duration;
+
+use super::CliExecutor;
+use super::claude::ClaudeCliConfig;
+use super::context::CliContextManager;
+use super::gemini::GeminiCliConfig;
+use super::stream::{
+    ClaudeStreamParser, GeminiStreamParser, StreamLimits, StreamParser,
+    drive_stream_parser_with_limits,
+};
+use super::types::{CliError, Conversation, StreamEvent};
+
+/// Placeholder in `args_template` replaced with the request's model
+pub const MODEL_PLACEHOLDER: &str = "{model}";
+
+/// Placeholder in `args_template` replaced with the formatted prompt. When
+/// present the prompt is passed as an argument instead of on stdin.
+pub const PROMPT_PLACEHOLDER: &str = "{prompt}";
+
+/// Builds a fresh stream parser for each request
+pub type StreamParserFactory = Arc<dyn Fn() -> Box<dyn StreamParser> + Send + Sync>;
+
+/// Declarative description of a CLI tool the executor can drive
+///
+/// Registering a spec is all it takes to support a new tool: the binary is
+/// spawned with `args_template` (after placeholder substitution), the prompt
+/// is written to stdin unless the template takes `{prompt}`, and stdout is
+/// turned into `StreamEvent`s by `stream_parser`.
+#[derive(Clone)]
+pub struct CliToolSpec {
+    /// Registry key and display name (e.g., "claude")
+    pub name: String,
+    /// Binary to spawn (name on PATH or absolute path)
+    pub binary: String,
+    /// Arguments, with `{model}` / `{prompt}` placeholders
+    pub args_template: Vec<String>,
+    pub stream_parser: StreamParserFactory,
+    /// Model used for every request; falls back to the conversation's model
+    pub model: Option<String>,
+    /// Overall limit for one request
+    pub timeout_secs: u64,
+    /// Kill the process after this long without a line of output
+    pub idle_timeout_secs: Option<u64>,
+    /// Unparseable output lines skipped before the request fails
+    pub max_parse_errors: usize,
+    /// Shown when the binary is missing
+    pub install_hint: String,
+    /// Shown when the health check reports an authentication problem
+    pub auth_command: String,
+}
+
+impl fmt::Debug for CliToolSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CliToolSpec")
+            .field("name", &self.name)
+            .field("binary", &self.binary)
+            .field("args_template", &self.args_template)
+            .field("model", &self.model)
+            .field("timeout_secs", &self.timeout_secs)
+            .field("idle_timeout_secs", &self.idle_timeout_secs)
+            .field("max_parse_errors", &self.max_parse_errors)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CliToolSpec {
+    pub fn new(
+        name: impl Into<String>,
+        binary: impl Into<String>,
+        args_template: Vec<String>,
+        stream_parser: StreamParserFactory,
+    ) -> Self {
+        let name = name.into();
+        Self {
+            binary: binary.into(),
+            args_template,
+            stream_parser,
+            model: None,
+            timeout_secs: 120,
+            idle_timeout_secs: Some(60),
+            max_parse_errors: 10,
+            install_hint: format!("Install the {name} CLI and add it to PATH"),
+            auth_command: format!("{name} login"),
+            name,
+        }
+    }
+
+    /// Claude CLI with `--output-format stream-json`
+    pub fn claude(config: &ClaudeCliConfig) -> Self {
+        Self {
+            model: config.model.clone(),
+            timeout_secs: config.timeout_secs,
+            idle_timeout_secs: Some(config.idle_timeout_secs),
+            max_parse_errors: config.max_parse_errors,
+            install_hint: "Visit https://claude.ai/download".to_string(),
+            auth_command: "claude login".to_string(),
+            ..Self::new(
+                "claude",
+                config.binary_path.clone(),
+                template(&[
+                    "--print",
+                    "--output-format",
+                    "stream-json",
+                    "--model",
+                    MODEL_PLACEHOLDER,
+                ]),
+                Arc::new(|| Box::new(ClaudeStreamParser)),
+            )
+        }
+    }
+
+    /// Gemini CLI with `--output-format stream-json`
+    pub fn gemini(config: &GeminiCliConfig) -> Self {
+        Self {
+            model: config.model.clone(),
+            timeout_secs: config.timeout_secs,
+            idle_timeout_secs: Some(config.idle_timeout_secs),
+            max_parse_errors: config.max_parse_errors,
+            install_hint: "Run: npm install -g @google/gemini-cli && gemini (to authenticate)"
+                .to_string(),
+            auth_command: "gemini (then follow OAuth prompts)".to_string(),
+            ..Self::new(
+                "gemini",
+                config.binary_path.clone(),
+                template(&[
+                    "--model",
+                    MODEL_PLACEHOLDER,
+                    "--output-format",
+                    "stream-json",
+                ]),
+                Arc::new(|| Box::new(GeminiStreamParser::default())),
+            )
+        }
+    }
+
+    fn takes_prompt_arg(&self) -> bool {
+        self.args_template
+            .iter()
+            .any(|arg| arg.contains(PROMPT_PLACEHOLDER))
+    }
+
+    /// Substitute placeholders in `args_template`
+    pub fn build_args(&self, model: &str, prompt: &str) -> Vec<String> {
+        self.args_template
+            .iter()
+            .map(|arg| {
+                arg.replace(MODEL_PLACEHOLDER, model)
+                    .replace(PROMPT_PLACEHOLDER, prompt)
+            })
+            .collect()
+    }
+}
+
+fn template(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| (*arg).to_string()).collect()
+}
+
+/// Executes any tool described by a `CliToolSpec`
+#[derive(Debug, Clone)]
+pub struct CliToolExecutor {
+    spec: CliToolSpec,
+}
+
+impl CliToolExecutor {
+    pub fn new(spec: CliToolSpec) -> Self {
+        Self { spec }
+    }
+
+    pub fn spec(&self) -> &CliToolSpec {
+        &self.spec
+    }
+
+    fn spawn_error(&self, e: std::io::Error, action: &str) -> CliError {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            CliError::BinaryNotFound {
+                binary: self.spec.binary.clone(),
+                install_hint: self.spec.install_hint.clone(),
+            }
+        } else {
+            CliError::Internal {
+                message: format!("Failed to {action}: {e}"),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CliExecutor for CliToolExecutor {
+    async fn execute(
+        &self,
+        conversation: &Conversation,
+        user_message: &str,
+    ) -> Result<mpsc::Receiver<StreamEvent>, CliError> {
+        let name = self.spec.name.clone();
+
+        // Compress conversation if needed
+        let conversation = CliContextManager::compress_if_needed(conversation, user_message);
+
+        // Format history into prompt
+        let prompt = CliContextManager::format_history(&conversation, user_message);
+
+        tracing::debug!(
+            "Executing {} CLI: {} chars, ~{} tokens",
+            name,
+            prompt.len(),
+            CliContextManager::estimate_tokens(&prompt)
+        );
+
+        // Build command
+        let model = self.spec.model.as_ref().unwrap_or(&conversation.model);
+        let prompt_on_stdin = !self.spec.takes_prompt_arg();
+        let args = self.spec.build_args(model, &prompt);
+        let mut child = Command::new(&self.spec.binary)
+            .args(&args)
+            .stdin(if prompt_on_stdin {
+                std::process::Stdio::piped()
+            } else {
+                std::process::Stdio::null()
+            })
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|e| self.spawn_error(e, &format!("spawn {name} CLI")))?;
+
+        // Write prompt to stdin
+        if prompt_on_stdin && let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(prompt.as_bytes())
+                .await
+                .map_err(|e| CliError::Internal {
+                    message: format!("Failed to write to stdin: {e}"),
+                })?;
+            stdin.shutdown().await.map_err(|e| CliError::Internal {
+                message: format!("Failed to close stdin: {e}"),
+            })?;
+        }
+
+        // Create channel for streaming events
+        let (tx, rx) = mpsc::channel(100);
+
+        // Spawn task to parse stdout
+        let stdout = child.stdout.take().ok_or_else(|| CliError::Internal {
+            message: "Failed to capture stdout".to_string(),
+        })?;
+
+        let mut parser = (self.spec.stream_parser)();
+        let tx_clone = tx.clone();
+        let limits = StreamLimits {
+            overall: Some(Duration::from_secs(self.spec.timeout_secs)),
+            idle: self.spec.idle_timeout_secs.map(Duration::from_secs),
+            max_parse_errors: self.spec.max_parse_errors,
+        };
+
+        tokio::spawn(async move {
+            let parse_result =
+                drive_stream_parser_with_limits(stdout, parser.as_mut(), tx_clone, limits).await;
+
+            match parse_result {
+                Ok(()) => {
+                    tracing::debug!("{} stream parsing completed successfully", name);
+                }
+                Err(e @ (CliError::Timeout { .. } | CliError::IdleTimeout { .. })) => {
+                    // The process is still running (or hung); stop it rather
+                    // than waiting on an exit status that may never come.
+                    tracing::error!("{} request timed out: {:?}", name, e);
+                    let _ = child.kill().await;
+                    let _ = tx.send(StreamEvent::Error(e)).await;
+                    return;
+                }
+                Err(e) => {
+                    tracing::error!("{} stream parsing failed: {:?}", name, e);
+                    let _ = tx.send(StreamEvent::Error(e)).await;
+                }
+            }
+
+            // Wait for process to exit and check status
+            if let Ok(status) = child.wait().await
+                && !status.success()
+            {
+                let code = status.code().unwrap_or(-1);
+                tracing::error!("{} CLI exited with code: {}", name, code);
+
+                // Try to read stderr for error details
+                if let Some(mut stderr) = child.stderr {
+                    let mut stderr_content = String::new();
+                    if (stderr.read_to_string(&mut stderr_content).await).is_ok() {
+                        let _ = tx
+                            .send(StreamEvent::Error(CliError::ProcessFailed {
+                                code,
+                                stderr: stderr_content,
+                            }))
+                            .await;
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    async fn health_check(&self) -> Result<(), CliError> {
+        let output = Command::new(&self.spec.binary)
+            .arg("--version")
+            .output()
+            .await
+            .map_err(|e| self.spawn_error(e, "run health check"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+
+            // Check for auth errors
+            if stderr.contains("not authenticated") || stderr.contains("login") {
+                return Err(CliError::NotAuthenticated {
+                    auth_command: self.spec.auth_command.clone(),
+                });
+            }
+
+            return Err(CliError::ProcessFailed {
+                code: output.status.code().unwrap_or(-1),
+                stderr: stderr.to_string(),
+            });
+        }
+
+        let version = String::from_utf8_lossy(&output.stdout);
+        tracing::info!(
+            "{} CLI health check passed: {}",
+            self.spec.name,
+            version.trim()
+        );
+
+        Ok(())
+    }
+
+    fn estimate_tokens(&self, conversation: &Conversation) -> usize {
+        let mut total = 0;
+
+        if let Some(system) = &conversation.system_prompt {
+            total += CliContextManager::estimate_tokens(system);
+        }
+
+        for msg in &conversation.messages {
+            total += CliContextManager::estimate_tokens(&msg.content);
+        }
+
+        total
+    }
+}
+
+/// Named `CliToolSpec`s available for dispatch
+#[derive(Debug, Clone, Default)]
+pub struct CliToolRegistry {
+    specs: BTreeMap<String, CliToolSpec>,
+}
+
+impl CliToolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the built-in Claude and Gemini specs
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register(CliToolSpec::claude(&ClaudeCliConfig::default()));
+        registry.register(CliToolSpec::gemini(&GeminiCliConfig::default()));
+        registry
+    }
+
+    /// Add or replace the spec registered under `spec.name`
+    pub fn register(&mut self, spec: CliToolSpec) {
+        self.specs.insert(spec.name.clone(), spec);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&CliToolSpec> {
+        self.specs.get(name)
+    }
+
+    /// Registered tool names, sorted
+    pub fn names(&self) -> Vec<String> {
+        self.specs.keys().cloned().collect()
+    }
+
+    /// Executor for the tool registered under `name`
+    pub fn executor(&self, name: &str) -> Option<CliToolExecutor> {
+        self.get(name).cloned().map(CliToolExecutor::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli_executor::types::ResponseMetadata;
+
+    /// Parses "delta: <text>" and "tokens: <in> <out>" lines
+    struct FakeParser;
+
+    impl StreamParser for FakeParser {
+        fn parse_line(&mut self, line: &str) -> Result<Vec<StreamEvent>, CliError> {
+            if let Some(text) = line.strip_prefix("delta: ") {
+                return Ok(vec![StreamEvent::Delta(text.to_string())]);
+            }
+            if let Some(counts) = line.strip_prefix("tokens: ") {
+                let mut counts = counts.split(' ').map(|n| n.parse().ok());
+                return Ok(vec![StreamEvent::Metadata(ResponseMetadata {
+                    model: "fake".to_string(),
+                    input_tokens: counts.next().flatten(),
+                    output_tokens: counts.next().flatten(),
+                    skipped_lines: 0,
+                })]);
+            }
+            Err(CliError::ParseError {
+                details: format!("unexpected line: {line}"),
+            })
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn registered_fake_tool_output_is_parsed_into_events() {
+        let mut registry = CliToolRegistry::with_defaults();
+        registry.register(CliToolSpec::new(
+            "fake",
+            "sh",
+            template(&[
+                "-c",
+                "cat >/dev/null; echo \"delta: model=$0\"; echo 'delta: done'; echo 'tokens: 12 3'",
+                MODEL_PLACEHOLDER,
+            ]),
+            Arc::new(|| Box::new(FakeParser)),
+        ));
+        assert_eq!(registry.names(), vec!["claude", "fake", "gemini"]);
+
+        let executor = registry.executor("fake").unwrap();
+        let conversation = Conversation {
+            messages: vec![],
+            system_prompt: None,
+            model: "fake-large".to_string(),
+        };
+        let mut rx = executor.execute(&conversation, "hello").await.unwrap();
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        let rendered: Vec<String> = events
+            .iter()
+            .map(|event| match event {
+                StreamEvent::Delta(text) => format!("delta {text}"),
+                StreamEvent::Metadata(m) => {
+                    format!("usage {:?} {:?}", m.input_tokens, m.output_tokens)
+                }
+                StreamEvent::Done => "done".to_string(),
+                StreamEvent::Error(e) => format!("error {e}"),
+            })
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "delta model=fake-large",
+                "delta done",
+                "usage Some(12) Some(3)",
+                "done",
+            ]
+        );
+    }
+
+    /// Run a fake `sh -c <script>` tool and collect every event it streams
+    #[cfg(unix)]
+    async fn run_fake_tool(
+        script: &str,
+        timeout_secs: u64,
+        idle_timeout_secs: u64,
+    ) -> (Vec<StreamEvent>, std::time::Duration) {
+        let spec = CliToolSpec {
+            timeout_secs,
+            idle_timeout_secs: Some(idle_timeout_secs),
+            ..CliToolSpec::new(
+                "fake",
+                "sh",
+                template(&["-c", script]),
+                Arc::new(|| Box::new(FakeParser)),
+            )
+        };
+        let conversation = Conversation {
+            messages: vec![],
+            system_prompt: None,
+            model: "fake".to_string(),
+        };
+        let started = std::time::Instant::now();
+        let mut rx = CliToolExecutor::new(spec)
+            .execute(&conversation, "hello")
+            .await
+            .unwrap();
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        (events, started.elapsed())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn silent_tool_is_killed_by_idle_watchdog() {
+        let (events, elapsed) = run_fake_tool("cat >/dev/null; sleep 30", 30, 1).await;
+
+        assert!(elapsed < Duration::from_secs(5), "took {elapsed:?}");
+        match events.as_slice() {
+            [
+                StreamEvent::Error(CliError::IdleTimeout {
+                    idle,
+                    partial_output,
+                }),
+            ] => {
+                assert_eq!(*idle, Duration::from_secs(1));
+                assert_eq!(partial_output, "");
+            }
+            other => panic!("expected a single idle timeout, got {other:?}"),
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn long_running_tool_hits_hard_timeout_with_partial_output() {
+        let (events, elapsed) = run_fake_tool(
+            "cat >/dev/null; while true; do echo 'delta: tick'; sleep 0.2; done",
+            1,
+            10,
+        )
+        .await;
+
+        assert!(elapsed < Duration::from_secs(5), "took {elapsed:?}");
+        let Some(StreamEvent::Error(CliError::Timeout {
+            elapsed,
+            partial_output,
+        })) = events.last()
+        else {
+            panic!("expected a hard timeout, got {events:?}");
+        };
+        assert!(*elapsed >= Duration::from_secs(1));
+        assert!(partial_output.starts_with("ticktick"), "{partial_output}");
+        assert!(
+            events[..events.len() - 1]
+                .iter()
+                .all(|event| matches!(event, StreamEvent::Delta(text) if text == "tick"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn garbage_lines_are_skipped_and_counted() {
+        let (events, _) = run_fake_tool(
+            "cat >/dev/null; echo 'delta: one'; echo '{not json'; echo 'delta: two'",
+            30,
+            10,
+        )
+        .await;
+
+        match events.as_slice() {
+            [
+                StreamEvent::Delta(first),
+                StreamEvent::Delta(second),
+                StreamEvent::Metadata(metadata),
+                StreamEvent::Done,
+            ] => {
+                assert_eq!((first.as_str(), second.as_str()), ("one", "two"));
+                assert_eq!(metadata.skipped_lines, 1);
+            }
+            other => panic!("expected two deltas and a skip count, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn builtin_specs_match_previous_arguments() {
+        let claude = CliToolSpec::claude(&ClaudeCliConfig::default());
+        assert_eq!(
+            claude.build_args("claude-sonnet-4.5", "ignored"),
+            vec![
+                "--print",
+                "--output-format",
+                "stream-json",
+                "--model",
+                "claude-sonnet-4.5"
+            ]
+        );
+        let gemini = CliToolSpec::gemini(&GeminiCliConfig::default());
+        assert_eq!(
+            gemini.build_args("gemini-2.5-pro", "ignored"),
+            vec![
+                "--model",
+                "gemini-2.5-pro",
+                "--output-format",
+                "stream-json"
+            ]
+        );
+    }
+}
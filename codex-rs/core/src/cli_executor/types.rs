@@ -36,6 +36,8 @@ pub struct ResponseMetadata {
     pub model: String,
     pub input_tokens: Option<usize>,
     pub output_tokens: Option<usize>,
+    /// Output lines dropped because they could not be parsed
+    pub skipped_lines: usize,
 }
 
 #[derive(Error, Debug, Clone)]
@@ -52,8 +54,21 @@ pub enum CliError {
     #[error("CLI process failed with code {code}: {stderr}")]
     ProcessFailed { code: i32, stderr: String },
 
+    /// The request ran past its overall time limit.
     #[error("Timeout after {elapsed:?}")]
-    Timeout { elapsed: std::time::Duration },
+    Timeout {
+        elapsed: std::time::Duration,
+        /// Text streamed before the process was killed
+        partial_output: String,
+    },
+
+    /// The CLI produced no output for too long and was presumed hung.
+    #[error("No output for {idle:?}; CLI presumed hung")]
+    IdleTimeout {
+        idle: std::time::Duration,
+        /// Text streamed before the process was killed
+        partial_output: String,
+    },
 
     #[error("Parse error: {details}")]
     ParseError { details: String },
@@ -25,21 +25,25 @@ use uuid::Uuid;
 
 use crate::chat_completions::AggregateStreamExt;
 use crate::chat_completions::stream_chat_completions;
+use crate::client_common::DrainOnDrop;
 use crate::client_common::Prompt;
 use crate::client_common::ResponseEvent;
 use crate::client_common::ResponseStream;
 use crate::client_common::ResponsesApiRequest;
 use crate::client_common::create_reasoning_param_for_request;
+use crate::client_common::until_stream_dropped;
 use crate::config::Config;
 use crate::config_types::ReasoningEffort as ReasoningEffortConfig;
 use crate::config_types::ReasoningSummary as ReasoningSummaryConfig;
 use crate::config_types::TextVerbosity as TextVerbosityConfig;
 use crate::debug_logger::DebugLogger;
-use crate::default_client::create_client;
+use crate::default_client::create_client_for_provider;
 use crate::error::CodexErr;
 use crate::error::Result;
 use crate::error::UsageLimitReachedError;
 use crate::flags::CODEX_RS_SSE_FIXTURE;
+use crate::http_client::retry_delay;
+use crate::http_client::send_with_timeout;
 use crate::model_family::ModelFamily;
 use crate::model_provider_info::ModelProviderInfo;
 use crate::model_provider_info::WireApi;
@@ -96,7 +100,7 @@ impl ModelClient {
         session_id: Uuid,
         debug_logger: Arc<Mutex<DebugLogger>>,
     ) -> Self {
-        let client = create_client(&config.responses_originator_header);
+        let client = create_client_for_provider(&config.responses_originator_header, &provider);
 
         Self {
             config,
@@ -131,6 +135,10 @@ impl ModelClient {
         &self.config.codex_home
     }
 
+    pub fn monthly_budget_usd(&self) -> Option<f64> {
+        self.config.monthly_budget_usd
+    }
+
     pub fn get_auto_compact_token_limit(&self) -> Option<i64> {
         self.config.model_auto_compact_token_limit.or_else(|| {
             get_model_info(&self.config.model_family).and_then(|info| info.auto_compact_token_limit)
@@ -350,7 +358,10 @@ impl ModelClient {
             attempt += 1;
 
             // Always fetch the latest auth in case a prior attempt refreshed the token.
-            let auth = auth_manager.as_ref().and_then(|m| m.auth());
+            let auth = match auth_manager.as_ref() {
+                Some(manager) => manager.fresh_auth().await,
+                None => None,
+            };
 
             trace!(
                 "POST to {}: {}",
@@ -376,7 +387,7 @@ impl ModelClient {
                 req_builder = req_builder.header("chatgpt-account-id", account_id);
             }
 
-            let res = req_builder.send().await;
+            let res = send_with_timeout(req_builder, self.provider.request_timeout()).await;
             if let Ok(resp) = &res {
                 trace!(
                     "Response status: {}, request-id: {}",
@@ -423,7 +434,7 @@ impl ModelClient {
                     }
 
                     // spawn task to process SSE
-                    let stream = resp.bytes_stream().map_err(CodexErr::Reqwest);
+                    let stream = DrainOnDrop::new(resp.bytes_stream().map_err(CodexErr::Reqwest));
                     let debug_logger = Arc::clone(&self.debug_logger);
                     let request_id_clone = request_id.clone();
 
@@ -433,7 +444,7 @@ impl ModelClient {
                     let idle_timeout = self.provider.stream_idle_timeout();
 
                     tokio::spawn(async move {
-                        let process_result = timeout(
+                        let processor = timeout(
                             total_timeout,
                             process_sse(
                                 stream,
@@ -442,8 +453,11 @@ impl ModelClient {
                                 debug_logger,
                                 request_id_clone,
                             ),
-                        )
-                        .await;
+                        );
+                        let Some(process_result) = until_stream_dropped(&tx_event, processor).await
+                        else {
+                            return;
+                        };
 
                         if process_result.is_err() {
                             // Total timeout exceeded
@@ -474,12 +488,8 @@ impl ModelClient {
                         .and_then(|v| v.to_str().ok())
                         .map(std::string::ToString::to_string);
 
-                    // Pull out Retry‑After header if present.
-                    let retry_after_secs = res
-                        .headers()
-                        .get(reqwest::header::RETRY_AFTER)
-                        .and_then(|v| v.to_str().ok())
-                        .and_then(|s| s.parse::<u64>().ok());
+                    // Resolve the Retry-After delay before the body consumes the response.
+                    let delay = retry_delay(res.headers(), attempt);
 
                     if status == StatusCode::UNAUTHORIZED
                         && let Some(a) = auth.as_ref()
@@ -596,9 +606,6 @@ impl ModelClient {
                         return Err(CodexErr::RetryLimit(status));
                     }
 
-                    let delay = retry_after_secs
-                        .map(|s| Duration::from_millis(s * 1_000))
-                        .unwrap_or_else(|| backoff(attempt));
                     tokio::time::sleep(delay).await;
                 }
                 Err(e) => {
@@ -607,7 +614,7 @@ impl ModelClient {
                         if let Ok(logger) = self.debug_logger.lock() {
                             let _ = logger.log_error(&endpoint, &format!("Network error: {e}"));
                         }
-                        return Err(e.into());
+                        return Err(e);
                     }
                     let delay = backoff(attempt);
                     tokio::time::sleep(delay).await;
@@ -643,6 +650,11 @@ impl ModelClient {
     pub fn get_auth_manager(&self) -> Option<Arc<AuthManager>> {
         self.auth_manager.clone()
     }
+
+    /// Debug logger shared by every client writing to this session's log
+    pub fn get_debug_logger(&self) -> Arc<Mutex<DebugLogger>> {
+        Arc::clone(&self.debug_logger)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -1308,6 +1320,9 @@ mod tests {
             stream_max_retries: Some(0),
             stream_idle_timeout_ms: Some(1000),
             agent_total_timeout_ms: None,
+            connect_timeout_ms: None,
+            request_timeout_ms: None,
+            proxy: None,
             requires_openai_auth: false,
             openrouter: None,
         };
@@ -1370,6 +1385,9 @@ mod tests {
             stream_max_retries: Some(0),
             stream_idle_timeout_ms: Some(1000),
             agent_total_timeout_ms: None,
+            connect_timeout_ms: None,
+            request_timeout_ms: None,
+            proxy: None,
             requires_openai_auth: false,
             openrouter: None,
         };
@@ -1407,6 +1425,9 @@ mod tests {
             stream_max_retries: Some(0),
             stream_idle_timeout_ms: Some(1000),
             agent_total_timeout_ms: None,
+            connect_timeout_ms: None,
+            request_timeout_ms: None,
+            proxy: None,
             requires_openai_auth: false,
             openrouter: None,
         };
@@ -1515,6 +1536,9 @@ mod tests {
                 stream_max_retries: Some(0),
                 stream_idle_timeout_ms: Some(1000),
                 agent_total_timeout_ms: None,
+                connect_timeout_ms: None,
+                request_timeout_ms: None,
+                proxy: None,
                 requires_openai_auth: false,
                 openrouter: None,
             };
@@ -7,17 +7,21 @@ use crate::model_family::ModelFamily;
 use crate::openai_tools::OpenAiTool;
 use crate::protocol::RateLimitSnapshotEvent;
 use crate::protocol::TokenUsage;
+use bytes::Bytes;
 use codex_apply_patch::APPLY_PATCH_TOOL_INSTRUCTIONS;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::ResponseItem;
 use futures::Stream;
+use futures::StreamExt;
 use serde::Serialize;
 use serde_json::Value;
 use std::borrow::Cow;
+use std::future::Future;
 use std::ops::Deref;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 /// Additional prompt for Code. Can not edit Codex instructions.
@@ -258,6 +262,12 @@ pub enum ResponseEvent {
         query: Option<String>,
     },
     RateLimits(RateLimitSnapshotEvent),
+    /// Token usage added since the previous chunk, for providers that stream
+    /// cumulative `usage` (e.g. OpenAI Chat Completions). The deltas of a
+    /// response sum to the `token_usage` reported by `Completed`.
+    UsageDelta {
+        usage_delta: TokenUsage,
+    },
 }
 
 #[derive(Debug, Serialize)]
@@ -443,6 +453,103 @@ impl Stream for ResponseStream {
     }
 }
 
+/// Run a stream processor until it finishes or the consumer drops its
+/// [`ResponseStream`], whichever comes first. Returns `None` when cancelled.
+///
+/// Dropping the processor drops its body stream, which lets [`DrainOnDrop`]
+/// recycle the connection right away instead of at the next event.
+pub(crate) async fn until_stream_dropped<T>(
+    tx_event: &mpsc::Sender<Result<ResponseEvent>>,
+    processor: impl Future<Output = T>,
+) -> Option<T> {
+    tokio::select! {
+        result = processor => Some(result),
+        _ = tx_event.closed() => None,
+    }
+}
+
+/// How long a cancelled response body may be drained in the background
+/// before its connection is closed instead of returned to the pool.
+const CANCELLED_BODY_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Upper bound on bytes read while draining a cancelled response body.
+const CANCELLED_BODY_DRAIN_MAX_BYTES: usize = 4 * 1024 * 1024;
+
+/// Response body wrapper that, when dropped before the body ends, reads the
+/// remainder on a background task so the HTTP connection can be reused for
+/// keep-alive. Draining is bounded by time and size; past either limit the
+/// connection is simply closed.
+pub(crate) struct DrainOnDrop<S>
+where
+    S: Stream<Item = Result<Bytes>> + Unpin + Send + 'static,
+{
+    inner: Option<S>,
+    finished: bool,
+}
+
+impl<S> DrainOnDrop<S>
+where
+    S: Stream<Item = Result<Bytes>> + Unpin + Send + 'static,
+{
+    pub(crate) fn new(inner: S) -> Self {
+        Self {
+            inner: Some(inner),
+            finished: false,
+        }
+    }
+}
+
+impl<S> Stream for DrainOnDrop<S>
+where
+    S: Stream<Item = Result<Bytes>> + Unpin + Send + 'static,
+{
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Poll::Ready(None);
+        };
+        let poll = inner.poll_next_unpin(cx);
+        if matches!(poll, Poll::Ready(None) | Poll::Ready(Some(Err(_)))) {
+            self.finished = true;
+        }
+        poll
+    }
+}
+
+impl<S> Drop for DrainOnDrop<S>
+where
+    S: Stream<Item = Result<Bytes>> + Unpin + Send + 'static,
+{
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let Some(mut inner) = self.inner.take() else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        handle.spawn(async move {
+            let drain = async {
+                let mut drained = 0;
+                while let Some(Ok(chunk)) = inner.next().await {
+                    drained += chunk.len();
+                    if drained > CANCELLED_BODY_DRAIN_MAX_BYTES {
+                        return false;
+                    }
+                }
+                true
+            };
+            match tokio::time::timeout(CANCELLED_BODY_DRAIN_TIMEOUT, drain).await {
+                Ok(true) => tracing::trace!("drained cancelled response body"),
+                _ => tracing::debug!("closing connection for cancelled response body"),
+            }
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::model_family::find_family_for_model;
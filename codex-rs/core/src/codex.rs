@@ -464,6 +464,7 @@ use crate::agent_tool::WaitForAgentParams;
 use crate::apply_patch::convert_apply_patch_to_protocol;
 use crate::apply_patch::get_writable_roots;
 use crate::apply_patch::{self, ApplyPatchResult};
+use crate::chat_completions::usage_since;
 use crate::client::ModelClient;
 use crate::client_common::{Prompt, REVIEW_PROMPT, ResponseEvent};
 use crate::config::{Config, persist_model_selection};
@@ -491,6 +492,7 @@ use crate::openai_model_info::get_model_info;
 use crate::openai_tools::ToolsConfig;
 use crate::openai_tools::get_openai_tools;
 use crate::parse_command::parse_command;
+use crate::plan_tool::PlanItemArg;
 use crate::plan_tool::handle_update_plan;
 use crate::project_doc::get_user_instructions;
 use crate::project_features::{ProjectCommand, ProjectHook, ProjectHooks};
@@ -527,6 +529,7 @@ use crate::protocol::SessionConfiguredEvent;
 use crate::protocol::Submission;
 use crate::protocol::TaskCompleteEvent;
 use crate::protocol::TokenCountEvent;
+use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
 use crate::protocol::TurnDiffEvent;
 use crate::protocol::ValidationGroup;
@@ -784,6 +787,9 @@ struct State {
     token_usage_info: Option<TokenUsageInfo>,
     latest_rate_limits: Option<RateLimitSnapshotEvent>,
     pending_manual_compacts: VecDeque<String>,
+    /// Most recent plan accepted from `update_plan`, used to validate the
+    /// next update's status transitions.
+    last_plan: Vec<PlanItemArg>,
 }
 
 #[derive(Clone)]
@@ -1037,6 +1043,14 @@ impl Session {
         self.state.lock_unchecked().history.contents()
     }
 
+    pub(crate) fn last_plan(&self) -> Vec<PlanItemArg> {
+        self.state.lock_unchecked().last_plan.clone()
+    }
+
+    pub(crate) fn set_last_plan(&self, plan: Vec<PlanItemArg>) {
+        self.state.lock_unchecked().last_plan = plan;
+    }
+
     pub(crate) fn model_client(&self) -> &ModelClient {
         &self.client
     }
@@ -2157,7 +2171,7 @@ impl Session {
         ) {
             SafetyCheck::AutoApprove { sandbox_type } => sandbox_type,
             SafetyCheck::AskUser | SafetyCheck::Reject { .. } => {
-                crate::safety::get_platform_sandbox().unwrap_or(SandboxType::None)
+                crate::safety::get_platform_sandbox()
             }
         }
     }
@@ -2279,6 +2293,7 @@ impl Session {
             env,
             with_escalated_permissions: Some(false),
             justification: None,
+            max_output_bytes: None,
         };
 
         let exec_ctx = ExecCommandContext {
@@ -2359,6 +2374,7 @@ impl Session {
                 env: extra_env,
                 with_escalated_permissions: Some(false),
                 justification: Some("Spec Ops slash command".to_string()),
+                max_output_bytes: None,
             };
 
             (params, name.to_string())
@@ -2388,6 +2404,7 @@ impl Session {
                 env: extra_env,
                 with_escalated_permissions: Some(false),
                 justification: None,
+                max_output_bytes: None,
             };
 
             (params, command_cfg.name)
@@ -2779,6 +2796,7 @@ impl State {
             request_ordinal: self.request_ordinal,
             dry_run_guard: self.dry_run_guard.clone(),
             next_internal_sub_id: self.next_internal_sub_id,
+            last_plan: self.last_plan.clone(),
             ..Default::default()
         }
     }
@@ -3250,7 +3268,9 @@ async fn submission_loop(
 
                 // Create debug logger based on config
                 let debug_logger = match crate::debug_logger::DebugLogger::new(config.debug) {
-                    Ok(logger) => std::sync::Arc::new(std::sync::Mutex::new(logger)),
+                    Ok(logger) => std::sync::Arc::new(std::sync::Mutex::new(
+                        logger.with_limits(config.debug_log),
+                    )),
                     Err(e) => {
                         warn!("Failed to create debug logger: {}", e);
                         // Create a disabled logger as fallback
@@ -3260,6 +3280,12 @@ async fn submission_loop(
                     }
                 };
 
+                if let Some(manager) = auth_manager.as_ref() {
+                    manager.set_failover_accounts(config.account_failover.clone());
+                    manager
+                        .set_refresh_margin(Duration::from_secs(config.token_refresh_margin_secs));
+                }
+
                 // Wrap provided auth (if any) in a minimal AuthManager for client usage.
                 let client = ModelClient::new(
                     config.clone(),
@@ -3945,15 +3971,9 @@ async fn spawn_review_thread(
     }
     let review_config = Arc::new(review_config);
 
-    let review_debug_logger = match crate::debug_logger::DebugLogger::new(review_config.debug) {
-        Ok(logger) => Arc::new(Mutex::new(logger)),
-        Err(err) => {
-            warn!("failed to create review debug logger: {err}");
-            Arc::new(Mutex::new(
-                crate::debug_logger::DebugLogger::new(false).unwrap(),
-            ))
-        }
-    };
+    // Reuse the session's logger: a second logger on the same debug.log
+    // would rotate it without holding the session logger's write lock.
+    let review_debug_logger = parent_turn_context.client.get_debug_logger();
 
     let review_client = ModelClient::new(
         review_config.clone(),
@@ -4514,6 +4534,20 @@ async fn run_turn(
         agents_active,
     );
 
+    if let Some(manager) = tc.client.get_auth_manager() {
+        match manager.restore_preferred_account(Utc::now()) {
+            Ok(Some(switch)) => {
+                sess.notify_background_event(
+                    &sub_id,
+                    format!("Usage limit reset; switched back to account {}.", switch.to),
+                )
+                .await;
+            }
+            Ok(None) => {}
+            Err(err) => warn!("Failed to restore preferred account: {err}"),
+        }
+    }
+
     let mut retries = 0;
     // Ensure we only auto-compact once per turn to avoid loops
     let mut did_auto_compact = false;
@@ -4595,6 +4629,25 @@ async fn run_turn(
                         }
                     });
                 }
+                if let CodexErr::UsageLimitReached(limit_err) = &e
+                    && let Some(manager) = turn_context.client.get_auth_manager()
+                {
+                    match manager.fail_over_on_rate_limit(limit_err.resets_in_seconds, Utc::now()) {
+                        Ok(Some(switch)) => {
+                            sess.notify_background_event(
+                                &sub_id,
+                                format!(
+                                    "Usage limit reached; switched to account {} and retrying.",
+                                    switch.to
+                                ),
+                            )
+                            .await;
+                            continue;
+                        }
+                        Ok(None) => {}
+                        Err(err) => warn!("Account failover failed: {err}"),
+                    }
+                }
                 return Err(e);
             }
             Err(e) => {
@@ -4929,6 +4982,8 @@ async fn try_run_turn(
     let mut stream = client.clone().stream(&prompt).await?;
 
     let mut output = Vec::new();
+    // Usage already added to the session through `UsageDelta` events.
+    let mut streamed_usage = TokenUsage::default();
     loop {
         // Poll the next item from the model stream. We must inspect *both* Ok and Err
         // cases so that transient stream failures (e.g., dropped SSE connection before
@@ -5014,11 +5069,18 @@ async fn try_run_turn(
                 let (new_info, rate_limits, should_emit);
                 {
                     let mut state = sess.state.lock().unwrap();
-                    let info = TokenUsageInfo::new_or_append(
+                    // Only add what the streamed deltas did not already count.
+                    let remainder = token_usage
+                        .as_ref()
+                        .map(|usage| usage_since(Some(&streamed_usage), usage));
+                    let mut info = TokenUsageInfo::new_or_append(
                         &state.token_usage_info,
-                        &token_usage,
+                        &remainder,
                         sess.client.get_model_context_window(),
                     );
+                    if let (Some(info), Some(usage)) = (info.as_mut(), token_usage.as_ref()) {
+                        info.last_token_usage = usage.clone();
+                    }
                     let limits = state.latest_rate_limits.clone();
                     let emit = info.is_some() || limits.is_some();
                     state.token_usage_info = info.clone();
@@ -5044,18 +5106,36 @@ async fn try_run_turn(
                     let usage_home = ctx.codex_home.clone();
                     let usage_account = ctx.account_id.clone();
                     let usage_plan = ctx.plan;
+                    let usage_model = client.get_model();
                     let usage_clone = usage.clone();
-                    spawn_usage_task(move || {
+                    let budget = client.monthly_budget_usd();
+                    let usage_task = tokio::task::spawn_blocking(move || {
+                        let now = Utc::now();
                         if let Err(err) = account_usage::record_token_usage(
                             &usage_home,
                             &usage_account,
                             usage_plan.as_deref(),
+                            Some(&usage_model),
                             &usage_clone,
-                            Utc::now(),
+                            now,
                         ) {
                             warn!("Failed to persist token usage: {err}");
                         }
+                        budget.map(|limit| account_usage::check_budget(&usage_home, limit, now))
                     });
+                    // Without a budget there is nothing to report, so leave the
+                    // write running in the background as before.
+                    if budget.is_some() {
+                        match usage_task.await {
+                            Ok(Some(Ok(status))) => {
+                                for warning in status.warnings() {
+                                    sess.notify_background_event(sub_id, warning).await;
+                                }
+                            }
+                            Ok(Some(Err(err))) => warn!("Failed to check usage budget: {err}"),
+                            Ok(None) | Err(_) => {}
+                        }
+                    }
                 }
 
                 let unified_diff = turn_diff_tracker.get_unified_diff();
@@ -5185,6 +5265,27 @@ async fn try_run_turn(
                     });
                 }
             } // Note: ReasoningSummaryPartAdded handled above without scratchpad mutation.
+            ResponseEvent::UsageDelta { usage_delta } => {
+                streamed_usage.add_assign(&usage_delta);
+                let (info, rate_limits) = {
+                    let mut state = sess.state.lock().unwrap();
+                    let mut info = TokenUsageInfo::new_or_append(
+                        &state.token_usage_info,
+                        &Some(usage_delta),
+                        sess.client.get_model_context_window(),
+                    );
+                    if let Some(info) = info.as_mut() {
+                        info.last_token_usage = streamed_usage.clone();
+                    }
+                    state.token_usage_info = info.clone();
+                    (info, state.latest_rate_limits.clone())
+                };
+                let payload = TokenCountEvent { info, rate_limits };
+                sess.tx_event
+                    .send(sess.make_event(sub_id, EventMsg::TokenCount(payload)))
+                    .await
+                    .ok();
+            }
         }
     }
 }
@@ -6961,6 +7062,7 @@ fn to_exec_params(params: ShellToolCallParams, sess: &Session) -> ExecParams {
         env: create_env(&sess.shell_environment_policy),
         with_escalated_permissions: params.with_escalated_permissions,
         justification: params.justification,
+        max_output_bytes: None,
     }
 }
 
@@ -7048,7 +7150,14 @@ pub struct ExecInvokeArgs<'a> {
 }
 
 fn maybe_run_with_user_profile(params: ExecParams, sess: &Session) -> ExecParams {
-    if sess.shell_environment_policy.use_profile {
+    if sess.shell_environment_policy.interactive_login {
+        let maybe_command = sess
+            .user_shell
+            .format_interactive_login_invocation(params.command.clone());
+        if let Some(command) = maybe_command {
+            return ExecParams { command, ..params };
+        }
+    } else if sess.shell_environment_policy.use_profile {
         let maybe_command = sess
             .user_shell
             .format_default_shell_invocation(params.command.clone());
@@ -11881,6 +11990,9 @@ mod client_integration_tests {
             stream_max_retries: Some(0),
             stream_idle_timeout_ms: Some(5_000),
             agent_total_timeout_ms: None,
+            connect_timeout_ms: None,
+            request_timeout_ms: None,
+            proxy: None,
             requires_openai_auth: false,
             openrouter: None,
         };
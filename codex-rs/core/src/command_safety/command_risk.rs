@@ -0,0 +1,67 @@
+//! Combined risk classification for shell commands.
+//!
+//! Folds the dangerous-command heuristics and the known-safe allow-list into
+//! a single level so display code does not have to consult both.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::is_dangerous_command::command_might_be_dangerous;
+use super::is_safe_command::is_known_safe_command;
+
+/// How risky a command looks before it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandRisk {
+    /// Read-only command on the known-safe allow-list.
+    Safe,
+    /// Neither known-safe nor flagged as destructive.
+    Caution,
+    /// Matches a destructive pattern such as `rm -rf` or `git reset`.
+    Dangerous,
+}
+
+/// Classify `command`. Dangerous patterns take precedence over the allow-list.
+pub fn assess_command_risk(command: &[String]) -> CommandRisk {
+    if command_might_be_dangerous(command) {
+        CommandRisk::Dangerous
+    } else if is_known_safe_command(command) {
+        CommandRisk::Safe
+    } else {
+        CommandRisk::Caution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vec_str(args: &[&str]) -> Vec<String> {
+        args.iter().map(std::string::ToString::to_string).collect()
+    }
+
+    #[test]
+    fn rm_rf_is_dangerous() {
+        assert_eq!(
+            assess_command_risk(&vec_str(&["rm", "-rf", "/"])),
+            CommandRisk::Dangerous
+        );
+    }
+
+    #[test]
+    fn ls_is_safe() {
+        assert_eq!(assess_command_risk(&vec_str(&["ls"])), CommandRisk::Safe);
+    }
+
+    #[test]
+    fn unknown_commands_need_caution() {
+        assert_eq!(
+            assess_command_risk(&vec_str(&["cargo", "build"])),
+            CommandRisk::Caution
+        );
+        assert_eq!(
+            assess_command_risk(&vec_str(&["bash", "-lc", "ls && rm -rf target"])),
+            CommandRisk::Dangerous
+        );
+    }
+}
@@ -8,6 +8,63 @@ use crate::bash::try_parse_bash;
 use crate::bash::try_parse_word_only_commands_sequence;
 use std::path::Path;
 
+/// An extra dangerous command, matched against parsed argv.
+///
+/// The first word matches the program's basename; the remaining words must
+/// appear as whole arguments, in order, among the command's arguments. Flags
+/// in between are allowed, so `["kubectl", "delete"]` matches
+/// `kubectl -n prod delete pod web`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DangerousPattern {
+    argv: Vec<String>,
+}
+
+impl DangerousPattern {
+    pub fn new<S: Into<String>>(argv: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            argv: argv.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    fn matches(&self, command: &[String]) -> bool {
+        let (Some((program, words)), Some((cmd0, args))) =
+            (self.argv.split_first(), command.split_first())
+        else {
+            return false;
+        };
+        let basename = Path::new(cmd0)
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or(cmd0);
+        if basename != program {
+            return false;
+        }
+        let mut args = args.iter();
+        words.iter().all(|word| args.any(|arg| arg == word))
+    }
+}
+
+/// Danger rules applied on top of the built-in heuristics.
+///
+/// The default policy adds nothing, so it behaves exactly like
+/// [`command_might_be_dangerous`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandSafetyPolicy {
+    extra_dangerous: Vec<DangerousPattern>,
+}
+
+impl CommandSafetyPolicy {
+    /// Also treat commands matching `pattern` as dangerous.
+    pub fn with_dangerous_pattern(mut self, pattern: DangerousPattern) -> Self {
+        self.extra_dangerous.push(pattern);
+        self
+    }
+
+    fn flags_extra(&self, command: &[String]) -> bool {
+        self.extra_dangerous.iter().any(|p| p.matches(command))
+    }
+}
+
 /// Returns true if the command looks like a potentially dangerous operation
 /// that should prompt for user approval.
 ///
@@ -15,7 +72,13 @@ use std::path::Path;
 /// (e.g., `git reset`, `rm -rf`, etc.) even when running in a permissive
 /// sandbox mode like `DangerFullAccess`.
 pub fn command_might_be_dangerous(command: &[String]) -> bool {
-    if is_dangerous_to_call_with_exec(command) {
+    is_dangerous_command_with_policy(command, &CommandSafetyPolicy::default())
+}
+
+/// Like [`command_might_be_dangerous`], additionally applying the extra
+/// patterns in `policy`.
+pub fn is_dangerous_command_with_policy(command: &[String], policy: &CommandSafetyPolicy) -> bool {
+    if is_dangerous_to_call_with_exec(command, policy) {
         return true;
     }
 
@@ -24,7 +87,7 @@ pub fn command_might_be_dangerous(command: &[String]) -> bool {
     if let Some(all_commands) = parse_shell_lc_plain_commands(command)
         && all_commands
             .iter()
-            .any(|cmd| is_dangerous_to_call_with_exec(cmd))
+            .any(|cmd| is_dangerous_to_call_with_exec(cmd, policy))
     {
         return true;
     }
@@ -66,7 +129,11 @@ fn parse_shell_lc_plain_commands(command: &[String]) -> Option<Vec<Vec<String>>>
 }
 
 /// Returns true if directly executing this command could be dangerous.
-fn is_dangerous_to_call_with_exec(command: &[String]) -> bool {
+fn is_dangerous_to_call_with_exec(command: &[String], policy: &CommandSafetyPolicy) -> bool {
+    if policy.flags_extra(command) {
+        return true;
+    }
+
     let cmd0 = command.first().map(String::as_str);
 
     match cmd0 {
@@ -100,7 +167,7 @@ fn is_dangerous_to_call_with_exec(command: &[String]) -> bool {
         Some(cmd) if cmd.starts_with("mkfs.") => true,
 
         // For `sudo <cmd>`, check the inner command
-        Some("sudo") => is_dangerous_to_call_with_exec(&command[1..]),
+        Some("sudo") => is_dangerous_to_call_with_exec(&command[1..], policy),
 
         // Everything else is not flagged as dangerous
         _ => false,
@@ -216,4 +283,61 @@ mod tests {
             "/dev/sda1"
         ])));
     }
+
+    fn org_policy() -> CommandSafetyPolicy {
+        CommandSafetyPolicy::default()
+            .with_dangerous_pattern(DangerousPattern::new(["terraform", "destroy"]))
+            .with_dangerous_pattern(DangerousPattern::new(["kubectl", "delete"]))
+    }
+
+    #[test]
+    fn custom_policy_flags_terraform_destroy() {
+        let cmd = vec_str(&["terraform", "destroy", "-auto-approve"]);
+        assert!(!command_might_be_dangerous(&cmd));
+        assert!(is_dangerous_command_with_policy(&cmd, &org_policy()));
+    }
+
+    #[test]
+    fn custom_policy_matches_argv_not_substrings() {
+        let policy = org_policy();
+        assert!(is_dangerous_command_with_policy(
+            &vec_str(&[
+                "/usr/local/bin/kubectl",
+                "-n",
+                "prod",
+                "delete",
+                "pod",
+                "web"
+            ]),
+            &policy
+        ));
+        assert!(is_dangerous_command_with_policy(
+            &vec_str(&["bash", "-lc", "terraform init && terraform destroy"]),
+            &policy
+        ));
+        assert!(is_dangerous_command_with_policy(
+            &vec_str(&["sudo", "kubectl", "delete", "ns", "dev"]),
+            &policy
+        ));
+        assert!(!is_dangerous_command_with_policy(
+            &vec_str(&["terraform", "plan", "-destroy"]),
+            &policy
+        ));
+        assert!(!is_dangerous_command_with_policy(
+            &vec_str(&["echo", "terraform", "destroy"]),
+            &policy
+        ));
+        assert!(!is_dangerous_command_with_policy(
+            &vec_str(&["kubectl", "get", "pods"]),
+            &policy
+        ));
+    }
+
+    #[test]
+    fn custom_policy_keeps_builtin_rules() {
+        assert!(is_dangerous_command_with_policy(
+            &vec_str(&["git", "reset", "--hard"]),
+            &org_policy()
+        ));
+    }
 }
@@ -1,5 +1,8 @@
+use crate::auth::DEFAULT_TOKEN_REFRESH_MARGIN_SECS;
 use crate::codex::ApprovedCommandPattern;
+use crate::config_loader::ConfigLoader;
 use crate::config_profile::ConfigProfile;
+use crate::config_profile::resolve_profile_inheritance;
 use crate::config_types::AceConfig;
 use crate::config_types::AgentConfig;
 use crate::config_types::AllowedCommand;
@@ -8,6 +11,7 @@ use crate::config_types::BrowserConfig;
 use crate::config_types::CachedTerminalBackground;
 use crate::config_types::ClientTools;
 use crate::config_types::ConfirmGuardConfig;
+use crate::config_types::DebugLogSettings;
 use crate::config_types::GithubConfig;
 use crate::config_types::History;
 use crate::config_types::HotReloadConfig;
@@ -18,6 +22,7 @@ use crate::config_types::ProjectHookConfig;
 use crate::config_types::QualityGateConfig;
 use crate::config_types::ReasoningEffort;
 use crate::config_types::ReasoningSummary;
+use crate::config_types::RolloutSettings;
 use crate::config_types::SandboxWorkspaceWrite;
 use crate::config_types::ShellEnvironmentPolicy;
 use crate::config_types::ShellEnvironmentPolicyToml;
@@ -34,6 +39,7 @@ use crate::model_family::derive_default_model_family;
 use crate::model_family::find_family_for_model;
 use crate::model_provider_info::ModelProviderInfo;
 use crate::model_provider_info::built_in_model_providers;
+use crate::model_provider_info::built_in_provider_default_model;
 use crate::openai_model_info::get_model_info;
 use crate::project_features::{ProjectCommand, ProjectHooks, load_project_commands};
 use crate::protocol::ApprovedCommandMatchKind;
@@ -91,6 +97,17 @@ pub struct Config {
     /// Token usage threshold triggering auto-compaction of conversation history.
     pub model_auto_compact_token_limit: Option<i64>,
 
+    /// Monthly spend budget in USD. When set, warnings are shown as estimated
+    /// spend crosses 50/75/90/100% of it.
+    pub monthly_budget_usd: Option<f64>,
+
+    /// Stored account ids to rotate through when the active account hits a
+    /// usage limit, most preferred first. Empty disables failover.
+    pub account_failover: Vec<String>,
+
+    /// Refresh ChatGPT access tokens this many seconds before they expire.
+    pub token_refresh_margin_secs: u64,
+
     /// Key into the model_providers map that specifies which provider to use.
     pub model_provider_id: String,
 
@@ -213,6 +230,9 @@ pub struct Config {
     /// (Code still reads legacy `~/.codex/history.jsonl`).
     pub history: History,
 
+    /// Settings for session rollout files.
+    pub rollout: RolloutSettings,
+
     /// Optional URI-based file opener. If set, citations to files in the model
     /// output will be hyperlinked using the specified URI scheme.
     pub file_opener: UriBasedFileOpener,
@@ -262,6 +282,9 @@ pub struct Config {
     /// Enable debug logging of LLM requests and responses
     pub debug: bool,
 
+    /// Rotation limits for the debug log.
+    pub debug_log: DebugLogSettings,
+
     /// Whether we're using ChatGPT authentication (affects feature availability)
     pub using_chatgpt_auth: bool,
 
@@ -299,8 +322,8 @@ impl Config {
     /// **in between** the values parsed from `config.toml` and the
     /// strongly-typed overrides specified via [`ConfigOverrides`].
     ///
-    /// The precedence order is therefore: `config.toml` < `-c` overrides <
-    /// `ConfigOverrides`.
+    /// The precedence order is therefore: `config.toml` < project
+    /// `.codex/config.toml` < `-c` overrides < `ConfigOverrides`.
     pub fn load_with_cli_overrides(
         cli_overrides: Vec<(String, TomlValue)>,
         overrides: ConfigOverrides,
@@ -310,16 +333,23 @@ impl Config {
         // `Config` instance.
         let codex_home = find_codex_home()?;
 
-        // Step 1: parse `config.toml` into a generic JSON value.
-        let mut root_value = load_config_as_toml(&codex_home)?;
+        // Step 1: parse `config.toml` and the project config for the
+        // requested cwd into a generic TOML value.
+        let mut loader = ConfigLoader::new().with_codex_home(codex_home.clone());
+        if let Some(cwd) = &overrides.cwd {
+            loader = loader.with_cwd(cwd.clone());
+        }
+        let mut root_value = load_layered_toml(&loader)?;
 
         // Step 2: apply the `-c` overrides.
         for (path, value) in cli_overrides.into_iter() {
             apply_toml_override(&mut root_value, &path, value);
         }
 
-        // Step 3: deserialize into `ConfigToml` so that Serde can enforce the
-        // correct types.
+        // Step 3: expand profile `extends` chains, then deserialize into
+        // `ConfigToml` so that Serde can enforce the correct types.
+        resolve_profile_inheritance(&mut root_value)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
         let cfg: ConfigToml = root_value.try_into().map_err(|e| {
             tracing::error!("Failed to deserialize overridden config: {e}");
             std::io::Error::new(std::io::ErrorKind::InvalidData, e)
@@ -330,16 +360,21 @@ impl Config {
     }
 }
 
+/// Like [`Config::load_with_cli_overrides`] without the typed overrides,
+/// resolving the project config from the process working directory.
 pub fn load_config_as_toml_with_cli_overrides(
     codex_home: &Path,
     cli_overrides: Vec<(String, TomlValue)>,
 ) -> std::io::Result<ConfigToml> {
-    let mut root_value = load_config_as_toml(codex_home)?;
+    let loader = ConfigLoader::new().with_codex_home(codex_home.to_path_buf());
+    let mut root_value = load_layered_toml(&loader)?;
 
     for (path, value) in cli_overrides.into_iter() {
         apply_toml_override(&mut root_value, &path, value);
     }
 
+    resolve_profile_inheritance(&mut root_value)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
     let cfg: ConfigToml = root_value.try_into().map_err(|e| {
         tracing::error!("Failed to deserialize overridden config: {e}");
         std::io::Error::new(std::io::ErrorKind::InvalidData, e)
@@ -348,27 +383,21 @@ pub fn load_config_as_toml_with_cli_overrides(
     Ok(cfg)
 }
 
-/// Read `CODEX_HOME/config.toml` and return it as a generic TOML value. Returns
-/// an empty TOML table when the file does not exist.
+fn load_layered_toml(loader: &ConfigLoader) -> std::io::Result<TomlValue> {
+    loader.load_toml().map_err(|e| {
+        tracing::error!("Failed to load config.toml: {e}");
+        e.into()
+    })
+}
+
+/// Read `CODEX_HOME/config.toml` and return it as a generic TOML value, with
+/// `${VAR}` references expanded. Returns an empty TOML table when the file
+/// does not exist. Project configs are not applied.
 pub fn load_config_as_toml(codex_home: &Path) -> std::io::Result<TomlValue> {
-    let read_path = resolve_codex_path_for_read(codex_home, Path::new(CONFIG_TOML_FILE));
-    match std::fs::read_to_string(&read_path) {
-        Ok(contents) => match toml::from_str::<TomlValue>(&contents) {
-            Ok(val) => Ok(val),
-            Err(e) => {
-                tracing::error!("Failed to parse config.toml: {e}");
-                Err(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
-            }
-        },
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-            tracing::info!("config.toml not found, using defaults");
-            Ok(TomlValue::Table(Default::default()))
-        }
-        Err(e) => {
-            tracing::error!("Failed to read config.toml: {e}");
-            Err(e)
-        }
-    }
+    ConfigLoader::load_global_toml(codex_home).map_err(|e| {
+        tracing::error!("Failed to load config.toml: {e}");
+        e.into()
+    })
 }
 
 pub fn load_global_mcp_servers(
@@ -460,6 +489,22 @@ pub fn write_global_mcp_servers(
                 entry["tool_timeout_sec"] = toml_edit::value(timeout);
             }
 
+            if let Some(attempts) = config.reconnect_attempts {
+                entry["reconnect_attempts"] = toml_edit::value(i64::from(attempts));
+            }
+
+            if config.lazy {
+                entry["lazy"] = toml_edit::value(true);
+            }
+
+            if !config.tools.is_empty() {
+                let mut tools = TomlArray::new();
+                for tool in &config.tools {
+                    tools.push(tool.clone());
+                }
+                entry["tools"] = TomlItem::Value(tools.into());
+            }
+
             doc["mcp_servers"][name.as_str()] = TomlItem::Table(entry);
         }
     }
@@ -1234,6 +1279,23 @@ pub fn list_mcp_servers(
                     .get("tool_timeout_sec")
                     .and_then(toml_edit::Item::as_integer)
                     .map(|i| i as u64);
+                let reconnect_attempts = t
+                    .get("reconnect_attempts")
+                    .and_then(toml_edit::Item::as_integer)
+                    .map(|i| i as u32);
+                let lazy = t
+                    .get("lazy")
+                    .and_then(toml_edit::Item::as_bool)
+                    .unwrap_or(false);
+                let tools: Vec<String> = t
+                    .get("tools")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|v| v.as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .unwrap_or_default();
 
                 out.push((
                     name.to_string(),
@@ -1244,6 +1306,9 @@ pub fn list_mcp_servers(
                         startup_timeout_sec,
                         startup_timeout_ms,
                         tool_timeout_sec,
+                        reconnect_attempts,
+                        lazy,
+                        tools,
                     },
                 ));
             }
@@ -1332,6 +1397,22 @@ pub fn add_mcp_server(codex_home: &Path, name: &str, cfg: McpServerConfig) -> an
             TomlItem::Value(toml_edit::Value::from(sec as i64)),
         );
     }
+    if let Some(attempts) = cfg.reconnect_attempts {
+        server_tbl.insert(
+            "reconnect_attempts",
+            TomlItem::Value(toml_edit::Value::from(i64::from(attempts))),
+        );
+    }
+    if cfg.lazy {
+        server_tbl.insert("lazy", TomlItem::Value(toml_edit::Value::from(true)));
+    }
+    if !cfg.tools.is_empty() {
+        let mut arr = toml_edit::Array::new();
+        for tool in cfg.tools.into_iter() {
+            arr.push(toml_edit::Value::from(tool));
+        }
+        server_tbl.insert("tools", TomlItem::Value(toml_edit::Value::Array(arr)));
+    }
 
     // Write into enabled table
     tbl.insert(name, TomlItem::Table(server_tbl));
@@ -1477,6 +1558,18 @@ pub struct ConfigToml {
     /// Token usage threshold triggering auto-compaction of conversation history.
     pub model_auto_compact_token_limit: Option<i64>,
 
+    /// Monthly spend budget in USD. When set, warnings are shown as estimated
+    /// spend crosses 50/75/90/100% of it.
+    pub monthly_budget_usd: Option<f64>,
+
+    /// Stored account ids to rotate through when the active account hits a
+    /// usage limit, most preferred first.
+    #[serde(default)]
+    pub account_failover: Vec<String>,
+
+    /// Refresh ChatGPT access tokens this many seconds before they expire.
+    pub token_refresh_margin_secs: Option<u64>,
+
     /// Default approval policy for executing commands.
     pub approval_policy: Option<AskForApproval>,
 
@@ -1546,6 +1639,14 @@ pub struct ConfigToml {
     #[serde(default)]
     pub history: Option<History>,
 
+    /// Settings for session rollout files (`[rollout]`).
+    #[serde(default)]
+    pub rollout: Option<RolloutSettings>,
+
+    /// Rotation limits for the debug log (`[debug_log]`).
+    #[serde(default)]
+    pub debug_log: Option<DebugLogSettings>,
+
     /// Optional URI-based file opener. If set, citations to files in the model
     /// output will be hyperlinked using the specified URI scheme.
     pub file_opener: Option<UriBasedFileOpener>,
@@ -1692,6 +1793,33 @@ pub struct ProjectStage0Toml {
     pub notebooklm_base_url: Option<String>,
 }
 
+/// Whether `resolved_cwd` is marked `trust_level = "trusted"` in `projects`,
+/// directly, through a parent entry, or through the main checkout of the git
+/// worktree it lives in.
+pub(crate) fn is_path_trusted(
+    projects: &HashMap<String, ProjectConfig>,
+    resolved_cwd: &Path,
+) -> bool {
+    let is_override_trusted = |override_cfg: Option<&ProjectConfig>| {
+        override_cfg
+            .and_then(|p| p.trust_level.as_deref())
+            .is_some_and(|v| v == "trusted")
+    };
+
+    if is_override_trusted(resolve_project_override(projects, resolved_cwd)) {
+        return true;
+    }
+
+    // If cwd lives inside a git worktree, check whether the root git project
+    // (the primary repository working directory) is trusted. This lets
+    // worktrees inherit trust from the main project.
+    if let Some(root_project) = resolve_root_git_project_for_trust(resolved_cwd) {
+        return is_override_trusted(resolve_project_override(projects, &root_project));
+    }
+
+    false
+}
+
 fn resolve_project_override<'a>(
     projects: &'a HashMap<String, ProjectConfig>,
     resolved_cwd: &Path,
@@ -1772,25 +1900,7 @@ impl ConfigToml {
 
     pub fn is_cwd_trusted(&self, resolved_cwd: &Path) -> bool {
         let projects = self.projects.clone().unwrap_or_default();
-
-        let is_override_trusted = |override_cfg: Option<&ProjectConfig>| {
-            override_cfg
-                .and_then(|p| p.trust_level.as_deref())
-                .is_some_and(|v| v == "trusted")
-        };
-
-        if is_override_trusted(resolve_project_override(&projects, resolved_cwd)) {
-            return true;
-        }
-
-        // If cwd lives inside a git worktree, check whether the root git project
-        // (the primary repository working directory) is trusted. This lets
-        // worktrees inherit trust from the main project.
-        if let Some(root_project) = resolve_root_git_project_for_trust(resolved_cwd) {
-            return is_override_trusted(resolve_project_override(&projects, &root_project));
-        }
-
-        false
+        is_path_trusted(&projects, resolved_cwd)
     }
 
     pub fn get_config_profile(
@@ -2050,11 +2160,14 @@ impl Config {
         // Determine auth mode early so defaults like model selection can depend on it.
         let using_chatgpt_auth = Self::is_using_chatgpt_auth(&codex_home);
 
-        let default_model_slug = if using_chatgpt_auth {
-            GPT_5_CODEX_MEDIUM_MODEL
-        } else {
-            OPENAI_DEFAULT_MODEL
-        };
+        let default_model_slug =
+            if let Some(slug) = built_in_provider_default_model(&model_provider_id) {
+                slug
+            } else if using_chatgpt_auth {
+                GPT_5_CODEX_MEDIUM_MODEL
+            } else {
+                OPENAI_DEFAULT_MODEL
+            };
 
         let model = model
             .or(config_profile.model)
@@ -2124,13 +2237,18 @@ impl Config {
             .or(cfg.review_model)
             .unwrap_or_else(default_review_model);
 
-        let config = Self {
+        let mut config = Self {
             model,
             review_model,
             model_family,
             model_context_window,
             model_max_output_tokens,
             model_auto_compact_token_limit,
+            monthly_budget_usd: cfg.monthly_budget_usd,
+            account_failover: cfg.account_failover,
+            token_refresh_margin_secs: cfg
+                .token_refresh_margin_secs
+                .unwrap_or(DEFAULT_TOKEN_REFRESH_MARGIN_SECS),
             model_provider_id,
             model_provider,
             cwd: resolved_cwd,
@@ -2159,6 +2277,7 @@ impl Config {
             project_doc_max_bytes: cfg.project_doc_max_bytes.unwrap_or(PROJECT_DOC_MAX_BYTES),
             codex_home,
             history,
+            rollout: cfg.rollout.unwrap_or_default(),
             file_opener: cfg.file_opener.unwrap_or(UriBasedFileOpener::VsCode),
             tui: cfg.tui.clone().unwrap_or_default(),
             codex_linux_sandbox_exe,
@@ -2197,6 +2316,7 @@ impl Config {
             include_view_image_tool: include_view_image_tool_flag,
             responses_originator_header,
             debug: debug.unwrap_or(false),
+            debug_log: cfg.debug_log.unwrap_or_default(),
             // Already computed before moving codex_home
             using_chatgpt_auth,
             github: cfg.github.unwrap_or_default(),
@@ -2215,6 +2335,8 @@ impl Config {
                 .unwrap_or_default(),
         };
 
+        crate::project_features::apply_feature_env_overrides(&mut config);
+
         // Validate configuration and log warnings for misconfigurations
         Self::validate_config(&config);
 
@@ -2458,7 +2580,12 @@ impl Config {
             .map_err(crate::config_loader::ConfigLoadError::IoError)?;
 
         // Parse raw ConfigToml (preserves all fields including profiles)
-        let cfg: ConfigToml = toml::from_str(&contents)
+        let mut root_value: TomlValue = toml::from_str(&contents)
+            .map_err(crate::config_loader::ConfigLoadError::TomlParseError)?;
+        resolve_profile_inheritance(&mut root_value)
+            .map_err(|e| crate::config_loader::ConfigLoadError::ValidationError(e.to_string()))?;
+        let cfg: ConfigToml = root_value
+            .try_into()
             .map_err(crate::config_loader::ConfigLoadError::TomlParseError)?;
 
         // Build overrides that preserve current session state
@@ -2963,6 +3090,9 @@ exclude_slash_tmp = true
                 startup_timeout_sec: None,
                 startup_timeout_ms: None,
                 tool_timeout_sec: None,
+                reconnect_attempts: None,
+                lazy: false,
+                tools: Vec::new(),
             },
         );
 
@@ -3229,6 +3359,9 @@ model_text_verbosity = "high"
             stream_max_retries: Some(10),
             stream_idle_timeout_ms: Some(300_000),
             agent_total_timeout_ms: None,
+            connect_timeout_ms: None,
+            request_timeout_ms: None,
+            proxy: None,
             requires_openai_auth: false,
             openrouter: None,
         };
@@ -3295,6 +3428,9 @@ model_text_verbosity = "high"
                 model_context_window: Some(200_000),
                 model_max_output_tokens: Some(100_000),
                 model_auto_compact_token_limit: None,
+                monthly_budget_usd: None,
+                account_failover: Vec::new(),
+                token_refresh_margin_secs: DEFAULT_TOKEN_REFRESH_MARGIN_SECS,
                 active_profile: Some("o3".to_string()),
                 model_provider_id: "openai".to_string(),
                 model_provider: fixture.openai_provider.clone(),
@@ -3322,6 +3458,7 @@ model_text_verbosity = "high"
                 project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
                 codex_home: fixture.codex_home(),
                 history: History::default(),
+                rollout: RolloutSettings::default(),
                 file_opener: UriBasedFileOpener::VsCode,
                 tui: Tui::default(),
                 codex_linux_sandbox_exe: None,
@@ -3340,6 +3477,7 @@ model_text_verbosity = "high"
                 include_view_image_tool: true,
                 responses_originator_header: "codex_cli_rs".to_string(),
                 debug: false,
+                debug_log: DebugLogSettings::default(),
                 using_chatgpt_auth: expected_using_chatgpt_auth,
                 github: GithubConfig::default(),
                 validation: ValidationConfig::default(),
@@ -3379,6 +3517,9 @@ model_text_verbosity = "high"
             model_context_window: Some(16_385),
             model_max_output_tokens: Some(4_096),
             model_auto_compact_token_limit: None,
+            monthly_budget_usd: None,
+            account_failover: Vec::new(),
+            token_refresh_margin_secs: DEFAULT_TOKEN_REFRESH_MARGIN_SECS,
             model_provider_id: "openai-chat-completions".to_string(),
             model_provider: fixture.openai_chat_completions_provider.clone(),
             active_profile: Some("gpt3".to_string()),
@@ -3406,6 +3547,7 @@ model_text_verbosity = "high"
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
             codex_home: fixture.codex_home(),
             history: History::default(),
+            rollout: RolloutSettings::default(),
             file_opener: UriBasedFileOpener::VsCode,
             tui: Tui::default(),
             codex_linux_sandbox_exe: None,
@@ -3424,6 +3566,7 @@ model_text_verbosity = "high"
             include_view_image_tool: true,
             responses_originator_header: "codex_cli_rs".to_string(),
             debug: false,
+            debug_log: DebugLogSettings::default(),
             using_chatgpt_auth: expected_using_chatgpt_auth,
             github: GithubConfig::default(),
             validation: ValidationConfig::default(),
@@ -3478,6 +3621,9 @@ model_text_verbosity = "high"
             model_context_window: Some(200_000),
             model_max_output_tokens: Some(100_000),
             model_auto_compact_token_limit: None,
+            monthly_budget_usd: None,
+            account_failover: Vec::new(),
+            token_refresh_margin_secs: DEFAULT_TOKEN_REFRESH_MARGIN_SECS,
             model_provider_id: "openai".to_string(),
             model_provider: fixture.openai_provider.clone(),
             active_profile: Some("zdr".to_string()),
@@ -3505,6 +3651,7 @@ model_text_verbosity = "high"
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
             codex_home: fixture.codex_home(),
             history: History::default(),
+            rollout: RolloutSettings::default(),
             file_opener: UriBasedFileOpener::VsCode,
             tui: Tui::default(),
             codex_linux_sandbox_exe: None,
@@ -3523,6 +3670,7 @@ model_text_verbosity = "high"
             include_view_image_tool: true,
             responses_originator_header: "codex_cli_rs".to_string(),
             debug: false,
+            debug_log: DebugLogSettings::default(),
             using_chatgpt_auth: expected_using_chatgpt_auth,
             github: GithubConfig::default(),
             validation: ValidationConfig::default(),
@@ -3563,6 +3711,9 @@ model_text_verbosity = "high"
             model_context_window: Some(272_000), // From get_model_info() for gpt-5
             model_max_output_tokens: Some(128_000),
             model_auto_compact_token_limit: None,
+            monthly_budget_usd: None,
+            account_failover: Vec::new(),
+            token_refresh_margin_secs: DEFAULT_TOKEN_REFRESH_MARGIN_SECS,
             model_provider_id: "openai".to_string(),
             model_provider: fixture.openai_provider.clone(),
             active_profile: Some("gpt5".to_string()),
@@ -3590,6 +3741,7 @@ model_text_verbosity = "high"
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
             codex_home: fixture.codex_home(),
             history: History::default(),
+            rollout: RolloutSettings::default(),
             file_opener: UriBasedFileOpener::VsCode,
             tui: Tui::default(),
             codex_linux_sandbox_exe: None,
@@ -3608,6 +3760,7 @@ model_text_verbosity = "high"
             use_experimental_streamable_shell_tool: false,
             include_view_image_tool: true,
             debug: false,
+            debug_log: DebugLogSettings::default(),
             using_chatgpt_auth: expected_using_chatgpt_auth,
             github: GithubConfig::default(),
             validation: ValidationConfig::default(),
@@ -0,0 +1,255 @@
+//! Summarize what changed between two loaded [`Config`]s.
+//!
+//! Used after a hot reload so the UI can say "model: gpt-5 → o3,
+//! approval_policy changed" instead of a bare "config changed".
+
+use std::fmt;
+
+use crate::config::Config;
+
+/// One field that differs between two configs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Name of the `Config` field.
+    pub key: &'static str,
+    /// Old and new values, for fields with a short textual form.
+    pub values: Option<(String, String)>,
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.values {
+            Some((old, new)) => write!(f, "{}: {old} → {new}", self.key),
+            None => write!(f, "{} changed", self.key),
+        }
+    }
+}
+
+/// Fields that differ between an old and a new [`Config`], in declaration order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    pub changes: Vec<ConfigChange>,
+}
+
+impl ConfigDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Names of the changed fields.
+    pub fn keys(&self) -> Vec<&'static str> {
+        self.changes.iter().map(|change| change.key).collect()
+    }
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, change) in self.changes.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare the listed fields of two configs.
+///
+/// The field lists must name every `Config` field: the destructuring
+/// pattern has no `..`, so adding a field without deciding how to diff it
+/// fails to compile.
+/// - `shown`: values implementing `Display`, rendered as `old → new`
+/// - `optional`: `Option`s of `Display` values, `None` rendered as "unset"
+/// - `opaque`: compared only, reported as "changed"
+/// - `ignored`: derived from other fields or tied to the session
+macro_rules! diff_fields {
+    (
+        $old:expr, $new:expr;
+        shown: [$($shown:ident),* $(,)?];
+        optional: [$($optional:ident),* $(,)?];
+        opaque: [$($opaque:ident),* $(,)?];
+        ignored: [$($ignored:ident),* $(,)?] $(;)?
+    ) => {{
+        let Config {
+            $($shown: _,)*
+            $($optional: _,)*
+            $($opaque: _,)*
+            $($ignored: _,)*
+        } = $old;
+        let mut changes = Vec::new();
+        $(
+            if $old.$shown != $new.$shown {
+                changes.push(ConfigChange {
+                    key: stringify!($shown),
+                    values: Some(($old.$shown.to_string(), $new.$shown.to_string())),
+                });
+            }
+        )*
+        $(
+            if $old.$optional != $new.$optional {
+                changes.push(ConfigChange {
+                    key: stringify!($optional),
+                    values: Some((show_optional(&$old.$optional), show_optional(&$new.$optional))),
+                });
+            }
+        )*
+        $(
+            if $old.$opaque != $new.$opaque {
+                changes.push(ConfigChange {
+                    key: stringify!($opaque),
+                    values: None,
+                });
+            }
+        )*
+        changes
+    }};
+}
+
+fn show_optional<T: fmt::Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "unset".to_string(),
+    }
+}
+
+impl Config {
+    /// Describe which fields differ between `self` and `new`.
+    pub fn diff(&self, new: &Config) -> ConfigDiff {
+        let changes = diff_fields!(
+            self, new;
+            shown: [
+                model,
+                review_model,
+                model_provider_id,
+                approval_policy,
+                hide_agent_reasoning,
+                show_raw_agent_reasoning,
+                disable_response_storage,
+                auto_upgrade_enabled,
+                project_doc_max_bytes,
+                token_refresh_margin_secs,
+                model_reasoning_effort,
+                model_reasoning_summary,
+                model_text_verbosity,
+                chatgpt_base_url,
+                include_plan_tool,
+                include_apply_patch_tool,
+                tools_web_search_request,
+                use_experimental_streamable_shell_tool,
+                include_view_image_tool,
+                debug,
+            ];
+            optional: [
+                model_context_window,
+                model_max_output_tokens,
+                model_auto_compact_token_limit,
+                monthly_budget_usd,
+                active_profile,
+            ];
+            opaque: [
+                model_provider,
+                account_failover,
+                sandbox_policy,
+                always_allow_commands,
+                project_hooks,
+                project_commands,
+                stage0,
+                shell_environment_policy,
+                confirm_guard,
+                user_instructions,
+                base_instructions,
+                notify,
+                tui_notifications,
+                mcp_servers,
+                experimental_client_tools,
+                ace,
+                agents,
+                quality_gates,
+                speckit_stage_agents,
+                hot_reload,
+                model_providers,
+                history,
+                rollout,
+                debug_log,
+                file_opener,
+                tui,
+                tools_web_search_allowed_domains,
+                github,
+                validation,
+                subagent_commands,
+                output_schema,
+            ];
+            ignored: [
+                model_family,
+                cwd,
+                codex_home,
+                codex_linux_sandbox_exe,
+                responses_originator_header,
+                using_chatgpt_auth,
+                experimental_resume,
+            ];
+        );
+        ConfigDiff { changes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigOverrides;
+    use crate::config::ConfigToml;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn reload_with(config: &Config, contents: &str) -> Config {
+        std::fs::write(config.codex_home.join("config.toml"), contents).unwrap();
+        config.reload_from_file().unwrap()
+    }
+
+    #[test]
+    fn reload_with_changed_model_reports_only_model() {
+        let codex_home = TempDir::new().unwrap();
+        let base = Config::load_from_base_config_with_overrides(
+            ConfigToml::default(),
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .unwrap();
+        let old = reload_with(&base, "model = \"team-model-v1\"\n");
+        let new = reload_with(&old, "model = \"team-model-v2\"\n");
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.changes,
+            vec![ConfigChange {
+                key: "model",
+                values: Some(("team-model-v1".to_string(), "team-model-v2".to_string())),
+            }]
+        );
+        assert_eq!(diff.to_string(), "model: team-model-v1 → team-model-v2");
+        assert!(new.diff(&new).is_empty());
+    }
+
+    #[test]
+    fn opaque_fields_are_reported_without_values() {
+        let codex_home = TempDir::new().unwrap();
+        let base = Config::load_from_base_config_with_overrides(
+            ConfigToml::default(),
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .unwrap();
+        let mut new = base.clone();
+        new.notify = Some(vec!["notify-send".to_string()]);
+        new.hide_agent_reasoning = !base.hide_agent_reasoning;
+
+        assert_eq!(
+            base.diff(&new).to_string(),
+            format!(
+                "hide_agent_reasoning: {} → {}, notify changed",
+                base.hide_agent_reasoning, new.hide_agent_reasoning
+            )
+        );
+    }
+}
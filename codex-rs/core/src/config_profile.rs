@@ -1,5 +1,8 @@
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use toml::Value as TomlValue;
+use toml::value::Table as TomlTable;
 
 use crate::config_types::ReasoningEffort;
 use crate::config_types::ReasoningSummary;
@@ -10,6 +13,9 @@ use crate::protocol::AskForApproval;
 /// in `config.toml`.
 #[derive(Debug, Clone, Default, PartialEq, Deserialize)]
 pub struct ConfigProfile {
+    /// Name of another profile whose values this profile inherits. Resolved by
+    /// [`resolve_profile_inheritance`] before the config is deserialized.
+    pub extends: Option<String>,
     pub model: Option<String>,
     /// The key in the `model_providers` map identifying the
     /// [`ModelProviderInfo`] to use.
@@ -22,3 +28,214 @@ pub struct ConfigProfile {
     pub chatgpt_base_url: Option<String>,
     pub experimental_instructions_file: Option<PathBuf>,
 }
+
+/// Errors produced while resolving `extends` between profiles.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProfileInheritanceError {
+    #[error("config profile `{profile}` extends unknown profile `{parent}`")]
+    UnknownParent { profile: String, parent: String },
+
+    #[error("config profile `{profile}` has a non-string `extends` value")]
+    InvalidExtends { profile: String },
+
+    #[error("config profiles form an `extends` cycle: {}", chain.join(" -> "))]
+    Cycle { chain: Vec<String> },
+}
+
+/// Expand `extends = "<parent>"` in the `[profiles]` table of a raw config.
+///
+/// Each profile starts from its fully resolved parent: nested tables are
+/// merged key by key, while scalars and arrays set by the child replace the
+/// parent's. Configs without a `[profiles]` table are left untouched.
+pub fn resolve_profile_inheritance(root: &mut TomlValue) -> Result<(), ProfileInheritanceError> {
+    let Some(TomlValue::Table(profiles)) = root.get_mut("profiles") else {
+        return Ok(());
+    };
+
+    let mut resolved = HashMap::new();
+    let names: Vec<String> = profiles.keys().cloned().collect();
+    for name in &names {
+        resolve_profile(name, profiles, &mut resolved, &mut Vec::new())?;
+    }
+    for (name, table) in resolved {
+        profiles.insert(name, TomlValue::Table(table));
+    }
+    Ok(())
+}
+
+fn resolve_profile(
+    name: &str,
+    profiles: &TomlTable,
+    resolved: &mut HashMap<String, TomlTable>,
+    stack: &mut Vec<String>,
+) -> Result<Option<TomlTable>, ProfileInheritanceError> {
+    if let Some(table) = resolved.get(name) {
+        return Ok(Some(table.clone()));
+    }
+    if let Some(start) = stack.iter().position(|n| n == name) {
+        let mut chain = stack[start..].to_vec();
+        chain.push(name.to_string());
+        return Err(ProfileInheritanceError::Cycle { chain });
+    }
+    // Non-table profiles are left for deserialization to reject.
+    let Some(TomlValue::Table(table)) = profiles.get(name) else {
+        return Ok(None);
+    };
+
+    let table = match table.get("extends") {
+        None => table.clone(),
+        Some(TomlValue::String(parent)) => {
+            stack.push(name.to_string());
+            let base = resolve_profile(parent, profiles, resolved, stack)?;
+            stack.pop();
+            let base = base.ok_or_else(|| ProfileInheritanceError::UnknownParent {
+                profile: name.to_string(),
+                parent: parent.clone(),
+            })?;
+            merge_tables(base, table)
+        }
+        Some(_) => {
+            return Err(ProfileInheritanceError::InvalidExtends {
+                profile: name.to_string(),
+            });
+        }
+    };
+    resolved.insert(name.to_string(), table.clone());
+    Ok(Some(table))
+}
+
+/// Overlay `overlay` onto `base`, recursing into tables present in both.
+fn merge_tables(mut base: TomlTable, overlay: &TomlTable) -> TomlTable {
+    for (key, value) in overlay {
+        let merged = match (base.remove(key), value) {
+            (Some(TomlValue::Table(base_table)), TomlValue::Table(overlay_table)) => {
+                TomlValue::Table(merge_tables(base_table, overlay_table))
+            }
+            _ => value.clone(),
+        };
+        base.insert(key.clone(), merged);
+    }
+    base
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn resolve(toml: &str) -> Result<TomlValue, ProfileInheritanceError> {
+        let mut root: TomlValue = toml::from_str(toml).expect("valid toml");
+        resolve_profile_inheritance(&mut root)?;
+        Ok(root)
+    }
+
+    fn profile(root: &TomlValue, name: &str) -> ConfigProfile {
+        root["profiles"][name]
+            .clone()
+            .try_into()
+            .expect("profile should deserialize")
+    }
+
+    #[test]
+    fn child_inherits_parent_fields() {
+        let root = resolve(
+            r#"
+[profiles.base]
+model = "o3"
+model_provider = "openai"
+approval_policy = "never"
+
+[profiles.dev]
+extends = "base"
+"#,
+        )
+        .unwrap();
+
+        let dev = profile(&root, "dev");
+        assert_eq!(dev.model.as_deref(), Some("o3"));
+        assert_eq!(dev.model_provider.as_deref(), Some("openai"));
+        assert_eq!(dev.approval_policy, Some(AskForApproval::Never));
+    }
+
+    #[test]
+    fn child_values_take_precedence_and_tables_deep_merge() {
+        let root = resolve(
+            r#"
+[profiles.base]
+model = "o3"
+model_reasoning_effort = "high"
+tags = ["a", "b"]
+extra = { keep = 1, replace = 2 }
+
+[profiles.mid]
+extends = "base"
+model = "gpt-5"
+
+[profiles.dev]
+extends = "mid"
+model_reasoning_effort = "low"
+tags = ["c"]
+extra = { replace = 3 }
+"#,
+        )
+        .unwrap();
+
+        let dev = profile(&root, "dev");
+        assert_eq!(dev.model.as_deref(), Some("gpt-5"));
+        assert_eq!(dev.model_reasoning_effort, Some(ReasoningEffort::Low));
+        assert_eq!(
+            root["profiles"]["dev"]["tags"],
+            TomlValue::Array(vec![TomlValue::String("c".into())])
+        );
+        assert_eq!(
+            root["profiles"]["dev"]["extra"]["keep"].as_integer(),
+            Some(1)
+        );
+        assert_eq!(
+            root["profiles"]["dev"]["extra"]["replace"].as_integer(),
+            Some(3)
+        );
+
+        // Parents are unaffected by their children.
+        assert_eq!(profile(&root, "base").model.as_deref(), Some("o3"));
+    }
+
+    #[test]
+    fn extends_cycle_is_an_error() {
+        let err = resolve(
+            r#"
+[profiles.a]
+extends = "b"
+
+[profiles.b]
+extends = "a"
+"#,
+        )
+        .unwrap_err();
+
+        let ProfileInheritanceError::Cycle { chain } = &err else {
+            panic!("expected cycle error, got {err:?}");
+        };
+        assert_eq!(chain.first(), chain.last());
+        assert_eq!(chain.len(), 3);
+        assert!(err.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn unknown_parent_is_an_error() {
+        let err = resolve(
+            r#"
+[profiles.dev]
+extends = "missing"
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ProfileInheritanceError::UnknownParent {
+                profile: "dev".to_string(),
+                parent: "missing".to_string(),
+            }
+        );
+    }
+}
@@ -482,6 +482,21 @@ pub struct McpServerConfig {
     /// If unset, defaults to 60 seconds.
     #[serde(default)]
     pub tool_timeout_sec: Option<u64>,
+
+    /// How many times to respawn the server after its connection drops
+    /// before giving up. If unset, defaults to 5; `0` disables reconnects.
+    #[serde(default)]
+    pub reconnect_attempts: Option<u32>,
+
+    /// Defer spawning the server until the first call to one of its tools.
+    /// Only takes effect when `tools` lists the server's tool names;
+    /// otherwise the server is started at session init to discover them.
+    #[serde(default)]
+    pub lazy: bool,
+
+    /// Tool names advertised for a `lazy` server before it is spawned.
+    #[serde(default)]
+    pub tools: Vec<String>,
 }
 
 impl McpServerConfig {
@@ -543,6 +558,46 @@ pub struct History {
     pub max_bytes: Option<usize>,
 }
 
+/// Settings for session rollout files under `~/.code/sessions`.
+#[derive(Deserialize, Debug, Copy, Clone, PartialEq, Default)]
+pub struct RolloutSettings {
+    /// Write new rollouts zstd-compressed (`.jsonl.zst`). Plain and compressed
+    /// rollouts are always readable regardless of this flag.
+    #[serde(default)]
+    pub compress: bool,
+    /// Delete archived rollouts older than this many days at startup.
+    #[serde(default)]
+    pub retention_days: Option<u64>,
+}
+
+/// Size limits for the rotating debug log under `~/.code/debug_logs`.
+#[derive(Deserialize, Debug, Copy, Clone, PartialEq)]
+pub struct DebugLogSettings {
+    /// Roll over to a new file once the active one would exceed this size.
+    #[serde(default = "default_debug_log_max_file_bytes")]
+    pub max_file_bytes: u64,
+    /// Files kept, including the active one; the oldest is deleted on rollover.
+    #[serde(default = "default_debug_log_max_files")]
+    pub max_files: usize,
+}
+
+fn default_debug_log_max_file_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+
+fn default_debug_log_max_files() -> usize {
+    5
+}
+
+impl Default for DebugLogSettings {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: default_debug_log_max_file_bytes(),
+            max_files: default_debug_log_max_files(),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Copy, Clone, PartialEq, Default)]
 #[serde(rename_all = "kebab-case")]
 pub enum HistoryPersistence {
@@ -621,6 +676,11 @@ pub struct Tui {
     /// with Ctrl+T. Defaults to true.
     #[serde(default = "default_true")]
     pub alternate_screen: bool,
+
+    /// Slash command aliases, mapping a short name to a canonical command
+    /// (e.g. `sa = "speckit.auto"`). Exact command names take precedence.
+    #[serde(default)]
+    pub slash_aliases: HashMap<String, String>,
 }
 
 // Important: Provide a manual Default so that when no config file exists and we
@@ -639,6 +699,7 @@ impl Default for Tui {
             spinner: SpinnerSelection::default(),
             notifications: Notifications::default(),
             alternate_screen: true,
+            slash_aliases: HashMap::new(),
         }
     }
 }
@@ -945,6 +1006,10 @@ pub struct ShellEnvironmentPolicyToml {
     pub include_only: Option<Vec<String>>,
 
     pub experimental_use_profile: Option<bool>,
+
+    /// Run commands through an interactive login shell so rc-file aliases
+    /// and functions are available.
+    pub experimental_interactive_login: Option<bool>,
 }
 
 pub type EnvironmentVariablePattern = WildMatchPattern<'*', '?'>;
@@ -952,10 +1017,14 @@ pub type EnvironmentVariablePattern = WildMatchPattern<'*', '?'>;
 /// Deriving the `env` based on this policy works as follows:
 /// 1. Create an initial map based on the `inherit` policy.
 /// 2. If `ignore_default_excludes` is false, filter the map using the default
-///    exclude pattern(s), which are: `"*KEY*"` and `"*TOKEN*"`.
+///    exclude patterns in [`crate::exec_env::DEFAULT_EXCLUDE_PATTERNS`].
 /// 3. If `exclude` is not empty, filter the map using the provided patterns.
 /// 4. Insert any entries from `r#set` into the map.
 /// 5. If non-empty, filter the map using the `include_only` patterns.
+///
+/// `exclude` therefore acts as a denylist that wins over the `include_only`
+/// allowlist, while explicit `r#set` entries are always kept unless
+/// `include_only` filters them out.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct ShellEnvironmentPolicy {
     /// Starting point when building the environment.
@@ -976,6 +1045,10 @@ pub struct ShellEnvironmentPolicy {
 
     /// If true, the shell profile will be used to run the command.
     pub use_profile: bool,
+
+    /// If true, commands run through an interactive login shell. Takes
+    /// precedence over `use_profile`.
+    pub interactive_login: bool,
 }
 
 impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
@@ -997,6 +1070,7 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             .map(|s| EnvironmentVariablePattern::new_case_insensitive(&s))
             .collect();
         let use_profile = toml.experimental_use_profile.unwrap_or(false);
+        let interactive_login = toml.experimental_interactive_login.unwrap_or(false);
 
         Self {
             inherit,
@@ -1005,6 +1079,7 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             r#set,
             include_only,
             use_profile,
+            interactive_login,
         }
     }
 }
@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
 use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::{Receiver, TryRecvError, channel};
 use std::time::{Duration, Instant};
 
@@ -12,6 +13,8 @@ pub struct ConfigWatcher {
     debounce_duration: Duration,
     last_event_time: Option<Instant>,
     pending_changes: HashSet<PathBuf>,
+    /// Content hash last reported per path (`None` when the file was absent)
+    content_hashes: HashMap<PathBuf, Option<u64>>,
 }
 
 impl ConfigWatcher {
@@ -31,12 +34,16 @@ impl ConfigWatcher {
         )
         .context("Failed to create file watcher")?;
 
-        // Watch each path
+        // Watch each path, remembering the current contents of watched files
+        let mut content_hashes = HashMap::new();
         for path in watch_paths {
             if path.exists() {
                 watcher
                     .watch(path, RecursiveMode::NonRecursive)
                     .with_context(|| format!("Failed to watch path: {}", path.display()))?;
+                if path.is_file() {
+                    content_hashes.insert(path.clone(), content_hash(path));
+                }
             }
         }
 
@@ -46,13 +53,18 @@ impl ConfigWatcher {
             debounce_duration: Duration::from_millis(debounce_ms),
             last_event_time: None,
             pending_changes: HashSet::new(),
+            content_hashes,
         })
     }
 
     /// Check for file changes and return paths if debounce period has elapsed
     ///
+    /// All events within the debounce window are coalesced into one sorted,
+    /// deduplicated path list. Paths whose content hash matches the last one
+    /// seen (e.g. an editor touching mtime only) are dropped.
+    ///
     /// Returns:
-    /// - `Some(paths)` if changes detected and debounce period elapsed
+    /// - `Some(paths)` if content changed and debounce period elapsed
     /// - `None` if no changes or still within debounce period
     pub fn check_for_changes(&mut self) -> Option<Vec<PathBuf>> {
         let mut has_new_events = false;
@@ -94,9 +106,20 @@ impl ConfigWatcher {
             && !self.pending_changes.is_empty()
             && last_time.elapsed() >= self.debounce_duration
         {
-            // Debounce period elapsed - return changes and reset
-            let changes: Vec<PathBuf> = self.pending_changes.drain().collect();
+            // Debounce period elapsed - return content changes and reset
+            let mut changes: Vec<PathBuf> = self
+                .pending_changes
+                .drain()
+                .filter(|path| {
+                    let hash = content_hash(path);
+                    self.content_hashes.insert(path.clone(), hash) != Some(hash)
+                })
+                .collect();
             self.last_event_time = None;
+            if changes.is_empty() {
+                return None;
+            }
+            changes.sort();
             return Some(changes);
         }
 
@@ -110,6 +133,14 @@ impl ConfigWatcher {
     }
 }
 
+/// Hash of the file contents, or `None` when the file cannot be read.
+fn content_hash(path: &Path) -> Option<u64> {
+    let contents = std::fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
 /// Determine if an event should trigger a reload
 fn should_process_event(event: &Event) -> bool {
     matches!(
@@ -173,6 +204,39 @@ mod tests {
         assert!(watcher.check_for_changes().is_none());
     }
 
+    /// Poll until the watcher reports changes or a generous timeout passes.
+    fn wait_for_changes(watcher: &mut ConfigWatcher) -> Option<Vec<PathBuf>> {
+        for _ in 0..20 {
+            std::thread::sleep(Duration::from_millis(50));
+            if let Some(changes) = watcher.check_for_changes() {
+                return Some(changes);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_identical_content_writes_are_not_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        fs::write(&config_path, "model = \"gpt-5\"").unwrap();
+
+        let mut watcher = ConfigWatcher::new(std::slice::from_ref(&config_path), 50).unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+
+        // First write changes the content and is reported once
+        fs::write(&config_path, "model = \"o3\"").unwrap();
+        assert_eq!(
+            wait_for_changes(&mut watcher),
+            Some(vec![config_path.clone()])
+        );
+
+        // Two more rapid writes of the same content are swallowed
+        fs::write(&config_path, "model = \"o3\"").unwrap();
+        fs::write(&config_path, "model = \"o3\"").unwrap();
+        assert_eq!(wait_for_changes(&mut watcher), None);
+    }
+
     #[test]
     fn test_multiple_path_watching() {
         let temp_dir = TempDir::new().unwrap();
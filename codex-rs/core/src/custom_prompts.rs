@@ -1,4 +1,6 @@
 use codex_protocol::custom_prompts::CustomPrompt;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::Path;
 use std::path::PathBuf;
@@ -73,6 +75,229 @@ pub async fn discover_prompts_in_excluding(
     out
 }
 
+/// A directory of prompts whose entries are exposed as `namespace:name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptSource {
+    pub namespace: String,
+    pub dir: PathBuf,
+}
+
+impl PromptSource {
+    pub fn new(namespace: impl Into<String>, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            dir: dir.into(),
+        }
+    }
+}
+
+/// A prompt together with the source it was loaded from.
+#[derive(Debug, Clone)]
+pub struct LoadedPrompt {
+    pub prompt: CustomPrompt,
+    pub namespace: String,
+    pub source_dir: PathBuf,
+}
+
+impl LoadedPrompt {
+    /// The namespaced name, e.g. `team:review`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}:{}", self.namespace, self.prompt.name)
+    }
+}
+
+/// A bare prompt name defined by more than one source. `winner` is the later
+/// source, which shadows `shadowed` when the name is used unqualified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptCollision {
+    pub name: String,
+    pub shadowed: String,
+    pub winner: String,
+}
+
+/// Result of discovering prompts across several sources.
+#[derive(Debug, Clone, Default)]
+pub struct PromptCatalog {
+    /// Every loaded prompt, in source order and then by name.
+    pub prompts: Vec<LoadedPrompt>,
+    pub collisions: Vec<PromptCollision>,
+}
+
+impl PromptCatalog {
+    /// Look up `namespace:name` exactly, or a bare `name` in the last source
+    /// that defines it.
+    pub fn resolve(&self, name: &str) -> Option<&LoadedPrompt> {
+        match name.split_once(':') {
+            Some((namespace, bare)) => self
+                .prompts
+                .iter()
+                .find(|p| p.namespace == namespace && p.prompt.name == bare),
+            None => self.prompts.iter().rev().find(|p| p.prompt.name == name),
+        }
+    }
+}
+
+/// Discover prompts from `sources` in order. Prompts are kept under their
+/// namespace; when a bare name appears in several sources the later one
+/// shadows the earlier, and the collision is logged and recorded.
+pub async fn discover_prompts_from(sources: &[PromptSource]) -> PromptCatalog {
+    let mut catalog = PromptCatalog::default();
+    let mut owners: HashMap<String, String> = HashMap::new();
+
+    for source in sources {
+        for prompt in discover_prompts_in(&source.dir).await {
+            let loaded = LoadedPrompt {
+                prompt,
+                namespace: source.namespace.clone(),
+                source_dir: source.dir.clone(),
+            };
+            let qualified = loaded.qualified_name();
+            if let Some(previous) = owners.insert(loaded.prompt.name.clone(), qualified.clone()) {
+                tracing::warn!(
+                    "custom prompt `{qualified}` from {} shadows `{previous}`",
+                    source.dir.display()
+                );
+                catalog.collisions.push(PromptCollision {
+                    name: loaded.prompt.name.clone(),
+                    shadowed: previous,
+                    winner: qualified,
+                });
+            }
+            catalog.prompts.push(loaded);
+        }
+    }
+    catalog
+}
+
+/// A parameter declared in a prompt's frontmatter. Entries may be a bare
+/// name (required) or a `{ name, default }` mapping (optional).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum PromptParameter {
+    Required(String),
+    WithDefault {
+        name: String,
+        #[serde(default)]
+        default: Option<String>,
+    },
+}
+
+impl PromptParameter {
+    pub fn name(&self) -> &str {
+        match self {
+            PromptParameter::Required(name) | PromptParameter::WithDefault { name, .. } => name,
+        }
+    }
+
+    pub fn default_value(&self) -> Option<&str> {
+        match self {
+            PromptParameter::Required(_) => None,
+            PromptParameter::WithDefault { default, .. } => default.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PromptFrontmatter {
+    #[serde(default)]
+    params: Vec<PromptParameter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PromptRenderError {
+    #[error("missing argument `{0}` for custom prompt")]
+    MissingArgument(String),
+    #[error("invalid custom prompt frontmatter: {0}")]
+    InvalidFrontmatter(String),
+}
+
+/// Split `content` into its YAML frontmatter (between leading `---` lines)
+/// and the remaining body. Content without frontmatter is all body.
+fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return (None, content);
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, content)
+}
+
+/// Parameters declared in the prompt's frontmatter, in declaration order.
+pub fn prompt_parameters(prompt: &CustomPrompt) -> Result<Vec<PromptParameter>, PromptRenderError> {
+    let (frontmatter, _) = split_frontmatter(&prompt.content);
+    let Some(frontmatter) = frontmatter else {
+        return Ok(Vec::new());
+    };
+    if frontmatter.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_yaml::from_str::<PromptFrontmatter>(frontmatter)
+        .map(|fm| fm.params)
+        .map_err(|e| PromptRenderError::InvalidFrontmatter(e.to_string()))
+}
+
+/// Render a prompt body, substituting `{{name}}` placeholders from `args`
+/// and falling back to frontmatter defaults. A placeholder with neither an
+/// argument nor a default is an error. `{{{{` renders as a literal `{{`,
+/// and text between braces that is not a valid name is left untouched.
+pub fn render(
+    prompt: &CustomPrompt,
+    args: &HashMap<String, String>,
+) -> Result<String, PromptRenderError> {
+    let params = prompt_parameters(prompt)?;
+    let (_, body) = split_frontmatter(&prompt.content);
+
+    let mut out = String::with_capacity(body.len());
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        if let Some(escaped) = after.strip_prefix("{{") {
+            out.push_str("{{");
+            rest = escaped;
+            continue;
+        }
+        let placeholder = after
+            .find("}}")
+            .map(|end| (after[..end].trim(), end))
+            .filter(|(name, _)| is_placeholder_name(name));
+        let Some((name, end)) = placeholder else {
+            out.push_str("{{");
+            rest = after;
+            continue;
+        };
+        let value = args
+            .get(name)
+            .map(String::as_str)
+            .or_else(|| {
+                params
+                    .iter()
+                    .find(|p| p.name() == name)
+                    .and_then(PromptParameter::default_value)
+            })
+            .ok_or_else(|| PromptRenderError::MissingArgument(name.to_string()))?;
+        out.push_str(value);
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +349,97 @@ mod tests {
         let names: Vec<String> = found.into_iter().map(|e| e.name).collect();
         assert_eq!(names, vec!["good"]);
     }
+
+    fn prompt(content: &str) -> CustomPrompt {
+        CustomPrompt {
+            name: "review".to_string(),
+            path: PathBuf::from("review.md"),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn render_substitutes_arguments_and_defaults() {
+        let p = prompt(
+            "---\nparams:\n  - file\n  - name: focus\n    default: correctness\n---\nReview {{file}} for {{ focus }}.\n",
+        );
+        assert_eq!(
+            prompt_parameters(&p).unwrap(),
+            vec![
+                PromptParameter::Required("file".to_string()),
+                PromptParameter::WithDefault {
+                    name: "focus".to_string(),
+                    default: Some("correctness".to_string()),
+                },
+            ]
+        );
+        let args = HashMap::from([("file".to_string(), "src/lib.rs".to_string())]);
+        assert_eq!(
+            render(&p, &args).unwrap(),
+            "Review src/lib.rs for correctness.\n"
+        );
+    }
+
+    #[test]
+    fn render_errors_on_missing_argument() {
+        let p = prompt("---\nparams: [file]\n---\nReview {{file}}\n");
+        assert_eq!(
+            render(&p, &HashMap::new()),
+            Err(PromptRenderError::MissingArgument("file".to_string()))
+        );
+    }
+
+    #[test]
+    fn render_keeps_escaped_braces_literal() {
+        let p = prompt("Use {{{{file}} for {{file}}, and {{ not a name }}.");
+        let args = HashMap::from([("file".to_string(), "a.rs".to_string())]);
+        assert_eq!(
+            render(&p, &args).unwrap(),
+            "Use {{file}} for a.rs, and {{ not a name }}."
+        );
+    }
+
+    #[tokio::test]
+    async fn later_source_shadows_earlier_with_recorded_collision() {
+        let tmp = tempdir().expect("create TempDir");
+        let team = tmp.path().join("team");
+        let user = tmp.path().join("user");
+        fs::create_dir(&team).unwrap();
+        fs::create_dir(&user).unwrap();
+        fs::write(team.join("review.md"), b"team review").unwrap();
+        fs::write(team.join("deploy.md"), b"team deploy").unwrap();
+        fs::write(user.join("review.md"), b"my review").unwrap();
+
+        let catalog = discover_prompts_from(&[
+            PromptSource::new("team", &team),
+            PromptSource::new("user", &user),
+        ])
+        .await;
+
+        let names: Vec<String> = catalog
+            .prompts
+            .iter()
+            .map(LoadedPrompt::qualified_name)
+            .collect();
+        assert_eq!(names, vec!["team:deploy", "team:review", "user:review"]);
+        assert_eq!(
+            catalog.collisions,
+            vec![PromptCollision {
+                name: "review".to_string(),
+                shadowed: "team:review".to_string(),
+                winner: "user:review".to_string(),
+            }]
+        );
+
+        let bare = catalog.resolve("review").expect("review resolves");
+        assert_eq!(bare.prompt.content, "my review");
+        assert_eq!(bare.source_dir, user);
+        let qualified = catalog.resolve("team:review").expect("team:review");
+        assert_eq!(qualified.prompt.content, "team review");
+        assert_eq!(qualified.source_dir, team);
+        assert_eq!(
+            catalog.resolve("deploy").map(|p| p.namespace.as_str()),
+            Some("team")
+        );
+    }
 }
@@ -0,0 +1,149 @@
+//! WAL checkpointing and size management
+//!
+//! SQLite only truncates the `-wal` file on a `TRUNCATE` checkpoint, and
+//! automatic (passive) checkpoints stall while readers are active, so on
+//! long sessions the WAL keeps growing. Call [`maybe_checkpoint`] from idle
+//! points to fold it back into the main database once it gets too large.
+
+use rusqlite::Connection;
+use std::path::PathBuf;
+
+use super::{DbError, Result};
+
+/// Pages written before SQLite attempts a passive checkpoint on commit
+pub const WAL_AUTOCHECKPOINT_PAGES: i64 = 1000;
+
+/// WAL size above which [`maybe_checkpoint`] truncates it (64MB)
+pub const DEFAULT_WAL_CHECKPOINT_THRESHOLD_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Outcome of a `wal_checkpoint(TRUNCATE)`
+#[derive(Debug)]
+pub struct CheckpointStats {
+    /// A reader or writer prevented the checkpoint from completing
+    pub busy: bool,
+    /// Frames in the WAL when the checkpoint started
+    pub wal_pages: i64,
+    /// Frames copied back into the database
+    pub checkpointed_pages: i64,
+    pub wal_size_before: u64,
+    pub wal_size_after: u64,
+}
+
+/// Current size of the connection's `-wal` file (0 when there is none)
+pub fn wal_size(conn: &Connection) -> Result<u64> {
+    let Some(wal_path) = wal_path(conn) else {
+        return Ok(0);
+    };
+    match std::fs::metadata(&wal_path) {
+        Ok(metadata) => Ok(metadata.len()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(DbError::Io(format!(
+            "Failed to stat {}: {e}",
+            wal_path.display()
+        ))),
+    }
+}
+
+/// Checkpoint and truncate the WAL unconditionally
+pub fn checkpoint(conn: &Connection) -> Result<CheckpointStats> {
+    let wal_size_before = wal_size(conn)?;
+    // A successful TRUNCATE resets the log before reporting, so its frame
+    // counts read 0; a PASSIVE pass first does the copying and reports it.
+    let (_, wal_pages, checkpointed_pages) = run_checkpoint(conn, "PASSIVE")?;
+    let (busy, _, _) = run_checkpoint(conn, "TRUNCATE")?;
+    let wal_size_after = wal_size(conn)?;
+
+    tracing::info!(
+        "WAL checkpoint: {} of {} pages ({} → {} bytes){}",
+        checkpointed_pages,
+        wal_pages,
+        wal_size_before,
+        wal_size_after,
+        if busy != 0 { ", busy" } else { "" }
+    );
+
+    Ok(CheckpointStats {
+        busy: busy != 0,
+        wal_pages,
+        checkpointed_pages,
+        wal_size_before,
+        wal_size_after,
+    })
+}
+
+/// Checkpoint only when the WAL has grown past `threshold_bytes`
+///
+/// Returns `None` when the WAL was small enough to leave alone.
+pub fn maybe_checkpoint(
+    conn: &Connection,
+    threshold_bytes: u64,
+) -> Result<Option<CheckpointStats>> {
+    if wal_size(conn)? <= threshold_bytes {
+        return Ok(None);
+    }
+    checkpoint(conn).map(Some)
+}
+
+/// `(busy, log frames, checkpointed frames)` from `wal_checkpoint(mode)`
+fn run_checkpoint(conn: &Connection, mode: &str) -> Result<(i64, i64, i64)> {
+    let sql = format!("PRAGMA wal_checkpoint({mode})");
+    let counts = conn.query_row(&sql, [], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+    Ok(counts)
+}
+
+fn wal_path(conn: &Connection) -> Option<PathBuf> {
+    conn.path()
+        .filter(|path| !path.is_empty())
+        .map(|path| PathBuf::from(format!("{path}-wal")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_wal_db(path: &std::path::Path) -> Connection {
+        let conn = Connection::open(path).unwrap();
+        // Disable automatic checkpoints so the WAL grows for the test.
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA wal_autocheckpoint = 0;
+             CREATE TABLE blobs (id INTEGER PRIMARY KEY, data BLOB NOT NULL);",
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_maybe_checkpoint_truncates_large_wal() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let conn = open_wal_db(&temp_dir.path().join("wal.db"));
+        for _ in 0..200 {
+            conn.execute("INSERT INTO blobs (data) VALUES (zeroblob(8192))", [])
+                .unwrap();
+        }
+        let grown = wal_size(&conn).unwrap();
+        assert!(grown > 1024 * 1024, "WAL only grew to {grown} bytes");
+
+        assert!(maybe_checkpoint(&conn, grown).unwrap().is_none());
+
+        let stats = maybe_checkpoint(&conn, 1024 * 1024).unwrap().unwrap();
+        assert!(!stats.busy);
+        assert!(stats.checkpointed_pages > 0);
+        assert_eq!(stats.checkpointed_pages, stats.wal_pages);
+        assert_eq!(stats.wal_size_before, grown);
+        assert_eq!(stats.wal_size_after, 0);
+        assert_eq!(wal_size(&conn).unwrap(), 0);
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM blobs", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 200);
+    }
+
+    #[test]
+    fn test_in_memory_database_has_no_wal() {
+        let conn = Connection::open_in_memory().unwrap();
+        assert_eq!(wal_size(&conn).unwrap(), 0);
+        assert!(maybe_checkpoint(&conn, 0).unwrap().is_none());
+    }
+}
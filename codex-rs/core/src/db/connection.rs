@@ -6,9 +6,47 @@ use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
 use rusqlite::Connection;
 use std::path::Path;
+use std::time::Duration;
 
 use super::{DbError, Result};
 
+/// Default maximum number of pooled connections
+pub const DEFAULT_POOL_SIZE: u32 = 10;
+
+/// Default time a caller waits for a free connection before erroring
+pub const DEFAULT_CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Sizing and checkout behaviour for [`initialize_pool_with_config`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// Maximum number of open connections
+    pub max_size: u32,
+    /// Warm connections kept open (clamped to `max_size`)
+    pub min_idle: u32,
+    /// How long `pool.get()` blocks before returning a pool error
+    pub connection_timeout: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_POOL_SIZE,
+            min_idle: 2,
+            connection_timeout: DEFAULT_CONNECTION_TIMEOUT,
+        }
+    }
+}
+
+impl PoolConfig {
+    /// Default config with the given maximum pool size
+    pub fn with_max_size(max_size: u32) -> Self {
+        Self {
+            max_size,
+            ..Self::default()
+        }
+    }
+}
+
 /// Initialize connection pool with optimal pragmas
 ///
 /// # SPEC-945B Requirements:
@@ -31,17 +69,38 @@ use super::{DbError, Result};
 /// - `auto_vacuum = INCREMENTAL`: Prevent unbounded growth
 /// - `mmap_size = 1073741824`: 1GB memory-mapped I/O
 /// - `busy_timeout = 5000`: 5s deadlock wait
+/// - `wal_autocheckpoint = 1000`: Passive checkpoint every 1000 pages
+///   (see `checkpoint::maybe_checkpoint` for truncating the WAL)
 ///
 /// # Performance Impact (per SPEC-945B Section 1.3)
 /// - Before: 850µs/read, 2.1ms/write, 78ms/100-read batch
 /// - After: 129µs/read, 0.9ms/write, 12ms/100-read batch
 /// - Overall: 6.6× read improvement, 2.3× write improvement
 pub fn initialize_pool(db_path: &Path, pool_size: u32) -> Result<Pool<SqliteConnectionManager>> {
+    initialize_pool_with_config(db_path, PoolConfig::with_max_size(pool_size))
+}
+
+/// Initialize connection pool with explicit sizing and checkout timeout
+///
+/// Same pragmas as [`initialize_pool`]; use this when the caller needs a
+/// pool size or checkout timeout other than the defaults. Async callers
+/// should hand the pool to `async_wrapper::with_connection`.
+pub fn initialize_pool_with_config(
+    db_path: &Path,
+    config: PoolConfig,
+) -> Result<Pool<SqliteConnectionManager>> {
+    if config.max_size == 0 {
+        return Err(DbError::Pool(
+            "Pool max_size must be at least 1".to_string(),
+        ));
+    }
+
     let manager = SqliteConnectionManager::file(db_path);
 
     let pool = Pool::builder()
-        .max_size(pool_size)
-        .min_idle(Some(pool_size.min(2))) // Keep up to 2 warm connections, but not more than pool_size
+        .max_size(config.max_size)
+        .min_idle(Some(config.min_idle.min(config.max_size))) // Warm connections, but not more than max_size
+        .connection_timeout(config.connection_timeout)
         .connection_customizer(Box::new(ConnectionCustomizer))
         .test_on_check_out(true) // Health check before returning
         .build(manager)
@@ -67,6 +126,7 @@ impl r2d2::CustomizeConnection<Connection, rusqlite::Error> for ConnectionCustom
         // - cache_size = -32000: 32MB page cache
         // - mmap_size = 1073741824: 1GB memory-mapped I/O
         // - busy_timeout = 5000: 5s deadlock wait
+        // - wal_autocheckpoint = 1000: checkpoint::WAL_AUTOCHECKPOINT_PAGES
         conn.execute_batch(
             "PRAGMA journal_mode = WAL;
              PRAGMA synchronous = NORMAL;
@@ -75,7 +135,8 @@ impl r2d2::CustomizeConnection<Connection, rusqlite::Error> for ConnectionCustom
              PRAGMA temp_store = MEMORY;
              PRAGMA auto_vacuum = INCREMENTAL;
              PRAGMA mmap_size = 1073741824;
-             PRAGMA busy_timeout = 5000;",
+             PRAGMA busy_timeout = 5000;
+             PRAGMA wal_autocheckpoint = 1000;",
         )
     }
 }
@@ -175,6 +236,15 @@ mod tests {
             .query_row("PRAGMA busy_timeout", [], |row| row.get(0))
             .unwrap();
         assert_eq!(busy_timeout, 5000);
+
+        // Verify WAL auto-checkpoint interval
+        let wal_autocheckpoint: i64 = conn
+            .query_row("PRAGMA wal_autocheckpoint", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(
+            wal_autocheckpoint,
+            crate::db::checkpoint::WAL_AUTOCHECKPOINT_PAGES
+        );
     }
 
     #[test]
@@ -219,6 +289,91 @@ mod tests {
         assert_eq!(count, 10);
     }
 
+    #[test]
+    fn test_pool_config_applied() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let config = PoolConfig {
+            max_size: 3,
+            min_idle: 8,
+            connection_timeout: Duration::from_secs(2),
+        };
+        let pool = initialize_pool_with_config(&db_path, config).unwrap();
+
+        assert_eq!(pool.max_size(), 3);
+        assert_eq!(pool.min_idle(), Some(3));
+        assert_eq!(pool.connection_timeout(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_pool_config_rejects_zero_size() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let result = initialize_pool_with_config(&db_path, PoolConfig::with_max_size(0));
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_async_reads_and_writes() {
+        use crate::db::async_wrapper::with_connection;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let pool = initialize_pool_with_config(&db_path, PoolConfig::with_max_size(4)).unwrap();
+
+        with_connection(&pool, |conn| {
+            conn.execute(
+                "CREATE TABLE items (id INTEGER PRIMARY KEY, value TEXT NOT NULL)",
+                [],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        // Interleave writers and readers, more tasks than pooled connections
+        let handles: Vec<_> = (0..40)
+            .map(|i| {
+                let pool = pool.clone();
+                tokio::spawn(async move {
+                    if i % 2 == 0 {
+                        with_connection(&pool, move |conn| {
+                            conn.execute(
+                                "INSERT INTO items (value) VALUES (?1)",
+                                [format!("value_{i}")],
+                            )?;
+                            Ok(())
+                        })
+                        .await
+                    } else {
+                        with_connection(&pool, |conn| {
+                            conn.query_row("SELECT COUNT(*) FROM items", [], |row| {
+                                row.get::<_, i64>(0)
+                            })?;
+                            Ok(())
+                        })
+                        .await
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            if let Err(e) = handle.await.unwrap() {
+                panic!("concurrent pool operation failed: {e}");
+            }
+        }
+
+        let count: i64 = with_connection(&pool, |conn| {
+            Ok(conn.query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))?)
+        })
+        .await
+        .unwrap();
+        assert_eq!(count, 20);
+    }
+
     #[test]
     fn test_verify_pragmas_detects_bad_config() {
         let temp_dir = tempfile::tempdir().unwrap();
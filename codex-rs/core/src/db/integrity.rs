@@ -0,0 +1,410 @@
+//! Corruption detection and recovery
+//!
+//! A crash or a full disk can leave the SQLite file damaged, after which
+//! every query fails with an opaque "database disk image is malformed".
+//! `check_integrity` turns that into a report the TUI can show, and
+//! `attempt_repair` salvages what it can into a fresh file.
+
+use rusqlite::types::Value;
+use rusqlite::{Connection, OpenFlags, params_from_iter};
+use std::path::{Path, PathBuf};
+
+use super::{DbError, Result};
+
+/// Foreign key violation found by `PRAGMA foreign_key_check`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKeyViolation {
+    /// Table holding the dangling reference
+    pub table: String,
+    pub rowid: Option<i64>,
+    /// Table the reference points at
+    pub parent: String,
+}
+
+/// Result of [`check_integrity`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Problems from `PRAGMA integrity_check`, or the error that stopped it
+    pub integrity_errors: Vec<String>,
+    pub foreign_key_violations: Vec<ForeignKeyViolation>,
+}
+
+impl IntegrityReport {
+    pub fn is_ok(&self) -> bool {
+        self.integrity_errors.is_empty() && self.foreign_key_violations.is_empty()
+    }
+
+    /// One-line description suitable for a user prompt
+    pub fn summary(&self) -> String {
+        if self.is_ok() {
+            return "Database integrity check passed".to_string();
+        }
+        let mut parts = Vec::new();
+        if let Some(first) = self.integrity_errors.first() {
+            parts.push(format!(
+                "{} integrity error(s), first: {first}",
+                self.integrity_errors.len()
+            ));
+        }
+        if !self.foreign_key_violations.is_empty() {
+            parts.push(format!(
+                "{} foreign key violation(s)",
+                self.foreign_key_violations.len()
+            ));
+        }
+        format!("Database is damaged: {}", parts.join("; "))
+    }
+}
+
+/// Run `PRAGMA integrity_check` and `PRAGMA foreign_key_check`
+///
+/// Errors raised by the checks themselves (e.g. "file is not a database")
+/// are reported as integrity errors rather than returned.
+pub fn check_integrity(conn: &Connection) -> IntegrityReport {
+    let mut report = IntegrityReport::default();
+
+    match query_strings(conn, "PRAGMA integrity_check") {
+        Ok(rows) => report
+            .integrity_errors
+            .extend(rows.into_iter().filter(|row| row != "ok")),
+        Err(e) => report.integrity_errors.push(e.to_string()),
+    }
+
+    let violations = conn
+        .prepare("PRAGMA foreign_key_check")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| {
+                Ok(ForeignKeyViolation {
+                    table: row.get(0)?,
+                    rowid: row.get(1)?,
+                    parent: row.get(2)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+        });
+    match violations {
+        Ok(violations) => report.foreign_key_violations = violations,
+        Err(e) => report.integrity_errors.push(e.to_string()),
+    }
+
+    report
+}
+
+/// [`check_integrity`] on the database at `db_path`, reporting a failure to
+/// open it as an integrity error
+pub fn check_integrity_at(db_path: &Path) -> IntegrityReport {
+    match Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) {
+        Ok(conn) => check_integrity(&conn),
+        Err(e) => IntegrityReport {
+            integrity_errors: vec![e.to_string()],
+            ..Default::default()
+        },
+    }
+}
+
+/// What [`attempt_repair`] managed to recover
+#[derive(Debug)]
+pub struct RepairOutcome {
+    /// Where the damaged file was moved
+    pub backup_path: PathBuf,
+    pub rows_recovered: usize,
+    /// Tables (or indexes, views, triggers) that could not be fully restored
+    pub incomplete: Vec<String>,
+    /// Integrity of the rebuilt database
+    pub report: IntegrityReport,
+}
+
+/// Rebuild the database at `db_path` by copying its schema and every
+/// readable row into a fresh file
+///
+/// The damaged file (and its WAL/SHM sidecars) is kept next to it with a
+/// `.corrupt-<timestamp>` suffix. Rows past the first unreadable page of a
+/// table are lost. Callers must close all connections to `db_path` first.
+pub fn attempt_repair(db_path: &Path) -> Result<RepairOutcome> {
+    let recovered_path = sibling(db_path, ".recovering");
+    remove_if_exists(&recovered_path)?;
+
+    let (rows_recovered, incomplete) = match copy_into(db_path, &recovered_path) {
+        Ok(copied) => copied,
+        Err(e) => {
+            let _ = std::fs::remove_file(&recovered_path);
+            return Err(e);
+        }
+    };
+
+    let backup_path = sibling(
+        db_path,
+        &format!(".corrupt-{}", chrono::Utc::now().format("%Y%m%d%H%M%S")),
+    );
+    rename(db_path, &backup_path)?;
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = sibling(db_path, suffix);
+        if sidecar.exists() {
+            rename(&sidecar, &sibling(&backup_path, suffix))?;
+        }
+    }
+    rename(&recovered_path, db_path)?;
+
+    let report = check_integrity_at(db_path);
+    tracing::info!(
+        "Database repaired: {} rows recovered, {} incomplete, backup at {}",
+        rows_recovered,
+        incomplete.len(),
+        backup_path.display()
+    );
+
+    Ok(RepairOutcome {
+        backup_path,
+        rows_recovered,
+        incomplete,
+        report,
+    })
+}
+
+/// Copy schema and rows from `source` into a new database at `dest`,
+/// returning the number of rows copied and the objects left incomplete
+fn copy_into(source: &Path, dest: &Path) -> Result<(usize, Vec<String>)> {
+    let conn = Connection::open(dest)?;
+    // Salvaged rows may reference parents that were lost.
+    conn.execute_batch("PRAGMA foreign_keys = OFF")?;
+    conn.execute(
+        "ATTACH DATABASE ?1 AS old",
+        [source.to_string_lossy().as_ref()],
+    )?;
+
+    // Tables first so rows can be loaded before indexes and triggers exist.
+    let mut schema: Vec<(String, String, String)> = conn
+        .prepare(
+            "SELECT type, name, sql FROM old.sqlite_master
+             WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%'",
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(|e| DbError::Integrity(format!("Schema is unreadable: {e}")))?;
+    schema.sort_by_key(|(kind, _, _)| kind != "table");
+
+    let user_version: i64 = conn
+        .query_row("PRAGMA old.user_version", [], |row| row.get(0))
+        .unwrap_or(0);
+
+    let mut rows_recovered = 0;
+    let mut incomplete = Vec::new();
+    conn.execute_batch("BEGIN")?;
+    for (kind, name, sql) in &schema {
+        if let Err(e) = conn.execute_batch(sql) {
+            tracing::warn!("Could not recreate {kind} {name}: {e}");
+            incomplete.push(name.clone());
+            continue;
+        }
+        if kind == "table" {
+            let (copied, complete) = copy_rows(&conn, name)?;
+            rows_recovered += copied;
+            if !complete {
+                incomplete.push(name.clone());
+            }
+        }
+    }
+    conn.execute_batch(&format!(
+        "PRAGMA main.user_version = {user_version}; COMMIT"
+    ))?;
+    conn.execute_batch("DETACH DATABASE old")?;
+
+    Ok((rows_recovered, incomplete))
+}
+
+/// Copy rows of `table` until the first unreadable one; returns the number
+/// copied and whether the whole table was read
+fn copy_rows(conn: &Connection, table: &str) -> Result<(usize, bool)> {
+    let quoted = format!("\"{}\"", table.replace('"', "\"\""));
+    let mut select = match conn.prepare(&format!("SELECT * FROM old.{quoted}")) {
+        Ok(select) => select,
+        Err(e) => {
+            tracing::warn!("Could not read table {table}: {e}");
+            return Ok((0, false));
+        }
+    };
+    let columns = select.column_count();
+    let placeholders = vec!["?"; columns].join(", ");
+    let mut insert = conn.prepare(&format!(
+        "INSERT INTO main.{quoted} VALUES ({placeholders})"
+    ))?;
+
+    let mut rows = select.query([])?;
+    let mut copied = 0;
+    loop {
+        let row = match rows.next() {
+            Ok(Some(row)) => row,
+            Ok(None) => return Ok((copied, true)),
+            Err(e) => {
+                tracing::warn!("Stopped reading table {table} after {copied} rows: {e}");
+                return Ok((copied, false));
+            }
+        };
+        let values = (0..columns)
+            .map(|i| row.get::<_, Value>(i))
+            .collect::<rusqlite::Result<Vec<_>>>();
+        match values {
+            Ok(values) => {
+                insert.execute(params_from_iter(values))?;
+                copied += 1;
+            }
+            Err(e) => {
+                tracing::warn!("Stopped reading table {table} after {copied} rows: {e}");
+                return Ok((copied, false));
+            }
+        }
+    }
+}
+
+fn query_strings(conn: &Connection, sql: &str) -> rusqlite::Result<Vec<String>> {
+    conn.prepare(sql)?
+        .query_map([], |row| row.get(0))?
+        .collect()
+}
+
+/// `path` with `suffix` appended to its file name
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(DbError::Integrity(format!(
+            "Failed to remove {}: {e}",
+            path.display()
+        ))),
+    }
+}
+
+fn rename(from: &Path, to: &Path) -> Result<()> {
+    std::fs::rename(from, to).map_err(|e| {
+        DbError::Integrity(format!(
+            "Failed to move {} to {}: {e}",
+            from.display(),
+            to.display()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Seek, SeekFrom, Write};
+
+    /// Database with a table, an index and enough rows to span many pages
+    fn create_db(path: &Path) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE notes (id INTEGER PRIMARY KEY, body TEXT NOT NULL);
+             CREATE INDEX notes_body ON notes(body);
+             PRAGMA user_version = 7;",
+        )
+        .unwrap();
+        for i in 0..500 {
+            conn.execute(
+                "INSERT INTO notes (body) VALUES (?1)",
+                [format!("note {i} {}", "x".repeat(40))],
+            )
+            .unwrap();
+        }
+    }
+
+    /// Overwrite the root page of `name` with garbage
+    fn corrupt_root_page(path: &Path, name: &str) {
+        let (root, page_size): (i64, i64) = {
+            let conn = Connection::open(path).unwrap();
+            let root = conn
+                .query_row(
+                    "SELECT rootpage FROM sqlite_master WHERE name = ?1",
+                    [name],
+                    |row| row.get(0),
+                )
+                .unwrap();
+            let page_size = conn
+                .query_row("PRAGMA page_size", [], |row| row.get(0))
+                .unwrap();
+            (root, page_size)
+        };
+        let mut file = std::fs::OpenOptions::new().write(true).open(path).unwrap();
+        file.seek(SeekFrom::Start(((root - 1) * page_size) as u64))
+            .unwrap();
+        file.write_all(&vec![0xA5; page_size as usize]).unwrap();
+    }
+
+    #[test]
+    fn test_healthy_database_passes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("healthy.db");
+        create_db(&db_path);
+
+        let report = check_integrity_at(&db_path);
+        assert!(report.is_ok(), "{report:?}");
+        assert_eq!(report.summary(), "Database integrity check passed");
+    }
+
+    #[test]
+    fn test_corrupted_database_is_detected() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("corrupt.db");
+        create_db(&db_path);
+        corrupt_root_page(&db_path, "notes");
+
+        let report = check_integrity_at(&db_path);
+        assert!(!report.is_ok());
+        assert!(!report.integrity_errors.is_empty());
+        assert!(report.summary().starts_with("Database is damaged"));
+    }
+
+    #[test]
+    fn test_foreign_key_violations_are_reported() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "PRAGMA foreign_keys = OFF;
+             CREATE TABLE runs (id INTEGER PRIMARY KEY);
+             CREATE TABLE outputs (id INTEGER PRIMARY KEY, run_id INTEGER REFERENCES runs(id));
+             INSERT INTO outputs (run_id) VALUES (42);",
+        )
+        .unwrap();
+
+        let report = check_integrity(&conn);
+        assert!(report.integrity_errors.is_empty());
+        assert_eq!(
+            report.foreign_key_violations,
+            vec![ForeignKeyViolation {
+                table: "outputs".to_string(),
+                rowid: Some(1),
+                parent: "runs".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_repair_rebuilds_damaged_index_and_keeps_backup() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("index.db");
+        create_db(&db_path);
+        corrupt_root_page(&db_path, "notes_body");
+        assert!(!check_integrity_at(&db_path).is_ok());
+
+        let outcome = attempt_repair(&db_path).unwrap();
+
+        assert!(outcome.report.is_ok(), "{:?}", outcome.report);
+        assert_eq!(outcome.rows_recovered, 500);
+        assert!(outcome.incomplete.is_empty(), "{:?}", outcome.incomplete);
+        assert!(outcome.backup_path.exists());
+
+        let conn = Connection::open(&db_path).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM notes", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 500);
+        let version: i64 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, 7);
+    }
+}
@@ -9,6 +9,33 @@ use tracing::info;
 /// Current schema version
 pub const SCHEMA_VERSION: i32 = 2;
 
+/// A single forward-only schema migration
+///
+/// `version` is the `PRAGMA user_version` value the database has once
+/// `apply` has run. Migrations in a set must be numbered 1, 2, 3, ... with
+/// no gaps, and `apply` should be idempotent (`IF NOT EXISTS`, etc.) so a
+/// re-run after a crash cannot fail.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub version: i32,
+    pub description: &'static str,
+    pub apply: fn(&Connection) -> Result<()>,
+}
+
+/// Migrations for the consensus database, in application order
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "create consensus_runs and agent_outputs",
+        apply: migration_v1,
+    },
+    Migration {
+        version: 2,
+        description: "drop dual-schema consensus_artifacts/consensus_synthesis",
+        apply: migration_v2,
+    },
+];
+
 /// Apply all migrations to bring DB to current version
 ///
 /// # SPEC-945B Requirements:
@@ -20,23 +47,39 @@ pub const SCHEMA_VERSION: i32 = 2;
 ///
 /// # Implementation: Week 1, Day 3-4
 pub fn migrate_to_latest(conn: &mut Connection) -> Result<()> {
-    let current_version = get_schema_version(conn)?;
+    run_migrations(conn, MIGRATIONS).map(|_| ())
+}
 
-    if current_version == SCHEMA_VERSION {
-        info!("Schema already at version {}", SCHEMA_VERSION);
-        return Ok(());
-    }
+/// Apply every migration in `migrations` newer than the DB's `user_version`
+///
+/// Pending migrations run in order inside one exclusive transaction, and
+/// `user_version` is bumped in the same transaction, so a failure leaves
+/// the DB at its previous version. A DB whose `user_version` is greater
+/// than the last known migration is rejected rather than opened.
+///
+/// Returns the versions that were applied (empty if already current).
+/// Intended to be shared by any SQLite store with its own migration set.
+pub fn run_migrations(conn: &mut Connection, migrations: &[Migration]) -> Result<Vec<i32>> {
+    validate_migrations(migrations)?;
+
+    let target_version = migrations.last().map_or(0, |m| m.version);
+    let current_version = get_schema_version(conn)?;
 
-    if current_version > SCHEMA_VERSION {
+    if current_version > target_version {
         return Err(DbError::Migration(format!(
-            "Database schema version {current_version} is newer than application version {SCHEMA_VERSION}. \
+            "Database schema version {current_version} is newer than application version {target_version}. \
              Please update the application."
         )));
     }
 
+    if current_version == target_version {
+        info!("Schema already at version {}", target_version);
+        return Ok(Vec::new());
+    }
+
     info!(
         "Migrating schema from version {} to {}",
-        current_version, SCHEMA_VERSION
+        current_version, target_version
     );
 
     // Apply migrations sequentially within a transaction
@@ -44,26 +87,45 @@ pub fn migrate_to_latest(conn: &mut Connection) -> Result<()> {
         .transaction_with_behavior(TransactionBehavior::Exclusive)
         .map_err(|e| DbError::Migration(format!("Failed to begin migration transaction: {e}")))?;
 
-    for version in (current_version + 1)..=SCHEMA_VERSION {
-        info!("Applying migration to version {}", version);
-        apply_migration(&tx, version)?;
+    let mut applied = Vec::new();
+    for migration in migrations.iter().filter(|m| m.version > current_version) {
+        info!(
+            "Applying migration to version {}: {}",
+            migration.version, migration.description
+        );
+        (migration.apply)(&tx)?;
+        applied.push(migration.version);
     }
 
     // Update schema version
-    tx.execute(&format!("PRAGMA user_version = {SCHEMA_VERSION}"), [])
+    tx.execute(&format!("PRAGMA user_version = {target_version}"), [])
         .map_err(|e| DbError::Migration(format!("Failed to update schema version: {e}")))?;
 
     tx.commit()
         .map_err(|e| DbError::Migration(format!("Failed to commit migration: {e}")))?;
 
-    info!("Schema migration complete: version {}", SCHEMA_VERSION);
+    info!("Schema migration complete: version {}", target_version);
+    Ok(applied)
+}
+
+/// Ensure migrations are numbered 1..=N in order with no gaps
+fn validate_migrations(migrations: &[Migration]) -> Result<()> {
+    for (index, migration) in migrations.iter().enumerate() {
+        let expected = index as i32 + 1;
+        if migration.version != expected {
+            return Err(DbError::Migration(format!(
+                "Migration list out of order: expected version {expected}, found {} ({})",
+                migration.version, migration.description
+            )));
+        }
+    }
     Ok(())
 }
 
 /// Get current schema version
 ///
 /// # Implementation: Week 1, Day 3
-fn get_schema_version(conn: &Connection) -> Result<i32> {
+pub fn get_schema_version(conn: &Connection) -> Result<i32> {
     let version: i32 = conn
         .query_row("PRAGMA user_version", [], |row| row.get(0))
         .map_err(|e| DbError::Migration(format!("Failed to query schema version: {e}")))?;
@@ -71,19 +133,6 @@ fn get_schema_version(conn: &Connection) -> Result<i32> {
     Ok(version)
 }
 
-/// Apply specific migration version
-///
-/// # Implementation: Week 1, Day 3-4
-fn apply_migration(conn: &Connection, version: i32) -> Result<()> {
-    match version {
-        1 => migration_v1(conn),
-        2 => migration_v2(conn),
-        _ => Err(DbError::Migration(format!(
-            "Unknown migration version: {version}"
-        ))),
-    }
-}
-
 /// Migration V1: Create new normalized schema
 ///
 /// Creates:
@@ -311,6 +360,146 @@ mod tests {
         );
     }
 
+    fn record_step(conn: &Connection, step: i32) -> Result<()> {
+        conn.execute_batch("CREATE TABLE IF NOT EXISTS applied_steps (step INTEGER NOT NULL)")?;
+        conn.execute("INSERT INTO applied_steps (step) VALUES (?1)", [step])?;
+        Ok(())
+    }
+
+    fn step_1(conn: &Connection) -> Result<()> {
+        record_step(conn, 1)
+    }
+
+    fn step_2(conn: &Connection) -> Result<()> {
+        record_step(conn, 2)
+    }
+
+    fn step_3(conn: &Connection) -> Result<()> {
+        record_step(conn, 3)
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            description: "step 1",
+            apply: step_1,
+        },
+        Migration {
+            version: 2,
+            description: "step 2",
+            apply: step_2,
+        },
+        Migration {
+            version: 3,
+            description: "step 3",
+            apply: step_3,
+        },
+    ];
+
+    fn applied_steps(conn: &Connection) -> Vec<i32> {
+        let mut stmt = conn
+            .prepare("SELECT step FROM applied_steps ORDER BY rowid")
+            .expect("Failed to prepare query");
+        stmt.query_map([], |row| row.get(0))
+            .expect("Failed to query steps")
+            .collect::<std::result::Result<Vec<i32>, _>>()
+            .expect("Failed to collect steps")
+    }
+
+    #[test]
+    fn test_schema_version_matches_migrations() {
+        assert_eq!(MIGRATIONS.last().map(|m| m.version), Some(SCHEMA_VERSION));
+        validate_migrations(MIGRATIONS).expect("MIGRATIONS should be contiguous");
+    }
+
+    #[test]
+    fn test_run_migrations_fresh_db_runs_all() {
+        let mut conn = create_test_db();
+
+        let applied = run_migrations(&mut conn, TEST_MIGRATIONS).expect("Migration failed");
+
+        assert_eq!(applied, vec![1, 2, 3]);
+        assert_eq!(applied_steps(&conn), vec![1, 2, 3]);
+        assert_eq!(get_schema_version(&conn).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_run_migrations_partial_db_runs_remaining() {
+        let mut conn = create_test_db();
+
+        run_migrations(&mut conn, &TEST_MIGRATIONS[..1]).expect("Initial migration failed");
+        assert_eq!(get_schema_version(&conn).unwrap(), 1);
+
+        let applied = run_migrations(&mut conn, TEST_MIGRATIONS).expect("Migration failed");
+
+        assert_eq!(applied, vec![2, 3]);
+        assert_eq!(applied_steps(&conn), vec![1, 2, 3]);
+        assert_eq!(get_schema_version(&conn).unwrap(), 3);
+
+        let applied = run_migrations(&mut conn, TEST_MIGRATIONS).expect("Re-run failed");
+        assert!(
+            applied.is_empty(),
+            "Current DB should not re-run migrations"
+        );
+    }
+
+    #[test]
+    fn test_run_migrations_rejects_future_version() {
+        let mut conn = create_test_db();
+        conn.execute("PRAGMA user_version = 4", [])
+            .expect("Failed to set version");
+
+        let error_msg = run_migrations(&mut conn, TEST_MIGRATIONS)
+            .unwrap_err()
+            .to_string();
+
+        assert!(
+            error_msg.contains("version 4 is newer than application version 3"),
+            "unexpected error: {error_msg}"
+        );
+        assert_eq!(get_schema_version(&conn).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_run_migrations_rolls_back_on_failure() {
+        fn failing(_conn: &Connection) -> Result<()> {
+            Err(DbError::Migration("boom".to_string()))
+        }
+        let migrations = [
+            TEST_MIGRATIONS[0],
+            Migration {
+                version: 2,
+                description: "fails",
+                apply: failing,
+            },
+        ];
+        let mut conn = create_test_db();
+
+        assert!(run_migrations(&mut conn, &migrations).is_err());
+
+        assert_eq!(get_schema_version(&conn).unwrap(), 0);
+        let count: i32 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE name = 'applied_steps'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 0, "Failed migration batch should be rolled back");
+    }
+
+    #[test]
+    fn test_run_migrations_rejects_gaps() {
+        let migrations = [TEST_MIGRATIONS[0], TEST_MIGRATIONS[2]];
+        let mut conn = create_test_db();
+
+        let error_msg = run_migrations(&mut conn, &migrations)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error_msg.contains("expected version 2, found 3"));
+    }
+
     #[test]
     fn test_foreign_key_constraint() {
         let mut conn = create_test_db();
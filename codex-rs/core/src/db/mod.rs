@@ -5,20 +5,29 @@
 //! This module provides:
 //! - Connection pooling (r2d2-sqlite)
 //! - ACID transaction helpers
-//! - Schema migrations
+//! - Schema migrations (reusable `run_migrations` runner)
 //! - Auto-vacuum scheduling
+//! - WAL checkpointing
+//! - Integrity checks and corruption repair
 //! - WAL mode + performance pragmas
 //! - Async wrappers for Tokio runtime integration
 
 pub mod async_wrapper;
+pub mod checkpoint;
 pub mod connection;
 pub mod integration_examples;
+pub mod integrity;
 pub mod migrations;
 pub mod transactions;
 pub mod vacuum;
 
 // Sync API
-pub use connection::initialize_pool;
+pub use checkpoint::{CheckpointStats, checkpoint, maybe_checkpoint, wal_size};
+pub use connection::{PoolConfig, initialize_pool, initialize_pool_with_config};
+pub use integrity::{
+    IntegrityReport, RepairOutcome, attempt_repair, check_integrity, check_integrity_at,
+};
+pub use migrations::{Migration, migrate_to_latest, run_migrations};
 pub use transactions::{batch_insert, execute_in_transaction, upsert_consensus_run};
 pub use vacuum::{
     VacuumStats, estimate_vacuum_savings, get_freelist_size, run_vacuum_cycle, spawn_vacuum_daemon,
@@ -49,4 +58,10 @@ pub enum DbError {
 
     #[error("Migration error: {0}")]
     Migration(String),
+
+    #[error("Integrity error: {0}")]
+    Integrity(String),
+
+    #[error("I/O error: {0}")]
+    Io(String),
 }
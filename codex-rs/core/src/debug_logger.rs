@@ -1,33 +1,162 @@
+use crate::config_types::DebugLogSettings;
 use chrono::Local;
+use regex_lite::Captures;
+use regex_lite::Regex;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use uuid::Uuid;
 
+/// Object keys whose string values are always treated as credentials.
+const DEFAULT_SENSITIVE_KEYS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "api_key",
+    "openai_api_key",
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_secret",
+];
+
+/// Patterns for credentials embedded in free text. The first capture group,
+/// when present, is the secret; otherwise the whole match is.
+const DEFAULT_SECRET_PATTERNS: &[&str] = &[
+    r"(?i)\bbearer\s+([A-Za-z0-9._~+/=-]+)",
+    r"\b(sk-[A-Za-z0-9_-]{8,})",
+];
+
+/// Replacement for a secret that keeps only its last four characters, so the
+/// same credential can still be recognised across log files.
+pub fn fingerprint(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() <= 8 {
+        return "[REDACTED]".to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("[REDACTED ...{tail}]")
+}
+
+/// Masks credentials in debug log entries before they are written.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    sensitive_keys: Vec<String>,
+    patterns: Vec<Regex>,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self {
+            sensitive_keys: DEFAULT_SENSITIVE_KEYS
+                .iter()
+                .map(|k| (*k).to_string())
+                .collect(),
+            patterns: DEFAULT_SECRET_PATTERNS
+                .iter()
+                .filter_map(|p| Regex::new(p).ok())
+                .collect(),
+        }
+    }
+}
+
+impl Redactor {
+    /// Treat string values under `key` (case-insensitive) as secrets.
+    pub fn add_sensitive_key(&mut self, key: impl Into<String>) {
+        self.sensitive_keys.push(key.into());
+    }
+
+    /// Mask matches of `pattern` in every logged string. The first capture
+    /// group, when present, selects the secret within the match.
+    pub fn add_pattern(&mut self, pattern: &str) -> Result<(), regex_lite::Error> {
+        self.patterns.push(Regex::new(pattern)?);
+        Ok(())
+    }
+
+    pub fn redact_str(&self, text: &str) -> String {
+        let mut redacted = text.to_string();
+        for pattern in &self.patterns {
+            redacted = pattern
+                .replace_all(&redacted, |caps: &Captures<'_>| {
+                    let Some(whole) = caps.get(0) else {
+                        return String::new();
+                    };
+                    let secret = caps.get(1).unwrap_or(whole);
+                    let start = secret.start() - whole.start();
+                    let end = secret.end() - whole.start();
+                    let matched = whole.as_str();
+                    format!(
+                        "{}{}{}",
+                        &matched[..start],
+                        fingerprint(secret.as_str()),
+                        &matched[end..]
+                    )
+                })
+                .into_owned();
+        }
+        redacted
+    }
+
+    pub fn redact_value(&self, value: &Value) -> Value {
+        match value {
+            Value::String(text) => Value::String(self.redact_str(text)),
+            Value::Array(items) => {
+                Value::Array(items.iter().map(|v| self.redact_value(v)).collect())
+            }
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(key, v)| {
+                        let redacted = match v {
+                            Value::String(secret) if self.is_sensitive_key(key) => {
+                                Value::String(fingerprint(secret))
+                            }
+                            _ => self.redact_value(v),
+                        };
+                        (key.clone(), redacted)
+                    })
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    fn is_sensitive_key(&self, key: &str) -> bool {
+        self.sensitive_keys
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(key))
+    }
+}
+
+/// Name of the active debug log; rotated copies get a `.1`, `.2`, ... suffix
+/// with `.1` being the most recent.
+const DEBUG_LOG_FILE: &str = "debug.log";
+
 #[derive(Debug)]
 struct StreamInfo {
-    response_file: PathBuf,
     events: Vec<Value>,
 }
 
+/// Appends JSON lines to `debug.log`, rolling over to a fresh file once the
+/// size cap would be exceeded and deleting the oldest rotated file.
 #[derive(Debug)]
 pub struct DebugLogger {
     enabled: bool,
     log_dir: PathBuf,
     // Maps request_id to stream info for collecting events
     active_streams: Mutex<HashMap<String, StreamInfo>>,
+    redactor: Redactor,
+    limits: DebugLogSettings,
+    // Serializes appends so a rollover never interleaves with a write
+    write_lock: Mutex<()>,
 }
 
 impl DebugLogger {
     pub fn new(enabled: bool) -> Result<Self, std::io::Error> {
         if !enabled {
-            return Ok(Self {
-                enabled: false,
-                log_dir: PathBuf::new(),
-                active_streams: Mutex::new(HashMap::new()),
-            });
+            return Ok(Self::with_log_dir(false, PathBuf::new()));
         }
 
         let mut log_dir = crate::config::find_codex_home()?;
@@ -35,14 +164,81 @@ impl DebugLogger {
 
         fs::create_dir_all(&log_dir)?;
 
-        Ok(Self {
+        Ok(Self::with_log_dir(enabled, log_dir))
+    }
+
+    fn with_log_dir(enabled: bool, log_dir: PathBuf) -> Self {
+        Self {
             enabled,
             log_dir,
             active_streams: Mutex::new(HashMap::new()),
-        })
+            redactor: Redactor::default(),
+            limits: DebugLogSettings::default(),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Apply the configured rotation limits.
+    pub fn with_limits(mut self, limits: DebugLogSettings) -> Self {
+        self.limits = limits;
+        self
     }
 
-    /// Start a new request/response log file and return the request ID
+    /// Redaction applied to every entry before it is written; extend it with
+    /// additional keys or patterns as needed.
+    pub fn redactor_mut(&mut self) -> &mut Redactor {
+        &mut self.redactor
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        self.log_dir.join(format!("{DEBUG_LOG_FILE}.{index}"))
+    }
+
+    /// Shift `debug.log.N` to `debug.log.N+1` (dropping the oldest) and move
+    /// the active file to `debug.log.1`. Each step is a rename, so a crash
+    /// mid-rollover loses at most the oldest file, never a partial one.
+    fn rotate(&self) -> Result<(), std::io::Error> {
+        let keep = self.limits.max_files.max(1);
+        let oldest = self.rotated_path(keep - 1);
+        if keep == 1 {
+            return match fs::remove_file(self.log_dir.join(DEBUG_LOG_FILE)) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            };
+        }
+        match fs::remove_file(&oldest) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+        for index in (1..keep - 1).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(index + 1))?;
+            }
+        }
+        fs::rename(self.log_dir.join(DEBUG_LOG_FILE), self.rotated_path(1))
+    }
+
+    /// Redact `entry` and append it as one line, rotating first if it would
+    /// push the active file past `max_file_bytes`.
+    fn append_entry(&self, entry: &Value) -> Result<(), std::io::Error> {
+        let mut line = serde_json::to_string(&self.redactor.redact_value(entry))?;
+        line.push('\n');
+
+        let _guard = self
+            .write_lock
+            .lock()
+            .map_err(|_| std::io::Error::other("debug log lock poisoned"))?;
+        let path = self.log_dir.join(DEBUG_LOG_FILE);
+        let current = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        if current > 0 && current + line.len() as u64 > self.limits.max_file_bytes {
+            self.rotate()?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        file.write_all(line.as_bytes())
+    }
+
+    /// Log the request and return an ID for collecting its response events
     pub fn start_request_log(
         &self,
         endpoint: &str,
@@ -52,47 +248,18 @@ impl DebugLogger {
             return Ok(String::new());
         }
 
-        let timestamp = Local::now();
         let request_id = Uuid::new_v4().to_string();
-        let request_id_short = &request_id[..8]; // Use first 8 chars of UUID for brevity
-
-        // Create request file with pretty-printed JSON
-        let request_filename = format!(
-            "{}_{}_request.json",
-            timestamp.format("%Y%m%d_%H%M%S%.3f"),
-            request_id_short
-        );
-        let request_file_path = self.log_dir.join(request_filename);
-
-        // Create request object with metadata
-        let request_entry = serde_json::json!({
-            "timestamp": timestamp.to_rfc3339(),
+        self.append_entry(&serde_json::json!({
+            "timestamp": Local::now().to_rfc3339(),
+            "type": "request",
             "request_id": request_id,
             "endpoint": endpoint,
             "payload": payload
-        });
-
-        // Write pretty-printed JSON to request file
-        let formatted_request = serde_json::to_string_pretty(&request_entry)?;
-        fs::write(&request_file_path, formatted_request)?;
-
-        // Prepare response file path
-        let response_filename = format!(
-            "{}_{}_response.json",
-            timestamp.format("%Y%m%d_%H%M%S%.3f"),
-            request_id_short
-        );
-        let response_file_path = self.log_dir.join(response_filename);
+        }))?;
 
         // Store the stream info for this request_id
         if let Ok(mut streams) = self.active_streams.lock() {
-            streams.insert(
-                request_id.clone(),
-                StreamInfo {
-                    response_file: response_file_path,
-                    events: Vec::new(),
-                },
-            );
+            streams.insert(request_id.clone(), StreamInfo { events: Vec::new() });
         }
 
         Ok(request_id)
@@ -124,146 +291,153 @@ impl DebugLogger {
         Ok(())
     }
 
-    /// Mark a stream as completed and write all collected events to response file
+    /// Mark a stream as completed and log all collected events as one entry
     pub fn end_request_log(&self, request_id: &str) -> Result<(), std::io::Error> {
         if !self.enabled || request_id.is_empty() {
             return Ok(());
         }
 
-        if let Ok(mut streams) = self.active_streams.lock()
-            && let Some(stream_info) = streams.remove(request_id)
-        {
-            // Create the response object with all events as an array
-            let response_data = serde_json::json!({
+        let stream_info = match self.active_streams.lock() {
+            Ok(mut streams) => streams.remove(request_id),
+            Err(_) => None,
+        };
+        if let Some(stream_info) = stream_info {
+            self.append_entry(&serde_json::json!({
+                "timestamp": Local::now().to_rfc3339(),
+                "type": "response",
                 "request_id": request_id,
-                "completed_at": Local::now().to_rfc3339(),
                 "events": stream_info.events
-            });
-
-            // Write pretty-printed JSON to response file
-            let formatted_response = serde_json::to_string_pretty(&response_data)?;
-            fs::write(&stream_info.response_file, formatted_response)?;
+            }))?;
         }
 
         Ok(())
     }
 
-    // Legacy methods for backward compatibility - they now create standalone files
+    // Legacy methods for backward compatibility - they log standalone entries
     pub fn log_request(&self, endpoint: &str, payload: &Value) -> Result<(), std::io::Error> {
-        if !self.enabled {
-            return Ok(());
-        }
+        self.log_standalone("request", endpoint, "payload", payload.clone())
+    }
 
-        let timestamp = Local::now();
-        let filename = format!("{}_request.json", timestamp.format("%Y%m%d_%H%M%S%.3f"));
+    pub fn log_response(&self, endpoint: &str, response: &Value) -> Result<(), std::io::Error> {
+        self.log_standalone("response", endpoint, "response", response.clone())
+    }
 
-        let file_path = self.log_dir.join(filename);
+    pub fn log_stream_chunk(&self, endpoint: &str, chunk: &str) -> Result<(), std::io::Error> {
+        self.log_standalone("stream_chunk", endpoint, "chunk", Value::from(chunk))
+    }
 
-        let log_entry = serde_json::json!({
-            "timestamp": timestamp.to_rfc3339(),
-            "type": "request",
-            "endpoint": endpoint,
-            "payload": payload
-        });
+    pub fn log_error(&self, endpoint: &str, error: &str) -> Result<(), std::io::Error> {
+        self.log_standalone("error", endpoint, "error", Value::from(error))
+    }
 
-        let formatted = serde_json::to_string_pretty(&log_entry)?;
-        fs::write(file_path, formatted)?;
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
 
-        Ok(())
+    pub fn log_sse_event(&self, endpoint: &str, event_data: &Value) -> Result<(), std::io::Error> {
+        self.log_standalone("sse_event", endpoint, "event", event_data.clone())
     }
 
-    pub fn log_response(&self, endpoint: &str, response: &Value) -> Result<(), std::io::Error> {
+    fn log_standalone(
+        &self,
+        entry_type: &str,
+        endpoint: &str,
+        field: &str,
+        value: Value,
+    ) -> Result<(), std::io::Error> {
         if !self.enabled {
             return Ok(());
         }
 
-        let timestamp = Local::now();
-        let filename = format!("{}_response.json", timestamp.format("%Y%m%d_%H%M%S%.3f"));
-
-        let file_path = self.log_dir.join(filename);
-
-        let log_entry = serde_json::json!({
-            "timestamp": timestamp.to_rfc3339(),
-            "type": "response",
+        let mut entry = serde_json::json!({
+            "timestamp": Local::now().to_rfc3339(),
+            "type": entry_type,
             "endpoint": endpoint,
-            "response": response
         });
+        entry[field] = value;
+        self.append_entry(&entry)
+    }
+}
 
-        let formatted = serde_json::to_string_pretty(&log_entry)?;
-        fs::write(file_path, formatted)?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
 
-        Ok(())
+    fn logger_in(dir: &TempDir) -> DebugLogger {
+        DebugLogger::with_log_dir(true, dir.path().to_path_buf())
     }
 
-    pub fn log_stream_chunk(&self, endpoint: &str, chunk: &str) -> Result<(), std::io::Error> {
-        if !self.enabled {
-            return Ok(());
-        }
-
-        let timestamp = Local::now();
-        let filename = format!("{}_stream.txt", timestamp.format("%Y%m%d_%H%M%S%.3f"));
+    #[test]
+    fn authorization_header_is_masked_in_request_log() {
+        let dir = TempDir::new().unwrap();
+        let logger = logger_in(&dir);
+        let token = "eyJhbGciOiJIUzI1NiJ9.payload.signature-wxyz";
+        let payload = serde_json::json!({
+            "headers": { "Authorization": format!("Bearer {token}") },
+            "input": format!("retrying with Bearer {token}"),
+        });
 
-        let file_path = self.log_dir.join(filename);
+        logger
+            .log_request("https://api.example.com/v1/responses", &payload)
+            .unwrap();
 
-        let log_entry = format!(
-            "=== Stream Chunk at {} ===\nEndpoint: {}\n\n{}\n",
-            timestamp.to_rfc3339(),
-            endpoint,
-            chunk
+        let written = fs::read_to_string(dir.path().join(DEBUG_LOG_FILE)).unwrap();
+        assert!(!written.contains(token), "token leaked: {written}");
+        let logged: Value = serde_json::from_str(&written).unwrap();
+        assert_eq!(
+            logged["payload"]["headers"]["Authorization"],
+            "[REDACTED ...wxyz]"
+        );
+        assert_eq!(
+            logged["payload"]["input"],
+            "retrying with Bearer [REDACTED ...wxyz]"
         );
-
-        fs::write(file_path, log_entry)?;
-
-        Ok(())
     }
 
-    pub fn log_error(&self, endpoint: &str, error: &str) -> Result<(), std::io::Error> {
-        if !self.enabled {
-            return Ok(());
-        }
+    #[test]
+    fn custom_patterns_extend_redaction() {
+        let mut redactor = Redactor::default();
+        redactor.add_pattern(r"session=(\w+)").unwrap();
+        redactor.add_sensitive_key("x-api-key");
 
-        let timestamp = Local::now();
-        let filename = format!("{}_error.txt", timestamp.format("%Y%m%d_%H%M%S%.3f"));
-
-        let file_path = self.log_dir.join(filename);
-
-        let log_entry = format!(
-            "=== Error at {} ===\nEndpoint: {}\n\n{}\n",
-            timestamp.to_rfc3339(),
-            endpoint,
-            error
+        assert_eq!(
+            redactor.redact_str("cookie session=abcdef123456"),
+            "cookie session=[REDACTED ...3456]"
+        );
+        assert_eq!(
+            redactor.redact_value(&serde_json::json!({ "X-Api-Key": "short" })),
+            serde_json::json!({ "X-Api-Key": "[REDACTED]" })
         );
-
-        fs::write(file_path, log_entry)?;
-
-        Ok(())
-    }
-
-    pub fn is_enabled(&self) -> bool {
-        self.enabled
     }
 
-    pub fn log_sse_event(&self, endpoint: &str, event_data: &Value) -> Result<(), std::io::Error> {
-        // Legacy method - now creates standalone files
-        if !self.enabled {
-            return Ok(());
-        }
-
-        let timestamp = Local::now();
-        let filename = format!("{}_sse.json", timestamp.format("%Y%m%d_%H%M%S%.3f"));
-
-        let file_path = self.log_dir.join(filename);
-
-        let log_entry = serde_json::json!({
-            "timestamp": timestamp.to_rfc3339(),
-            "type": "sse_event",
-            "endpoint": endpoint,
-            "event": event_data
+    #[test]
+    fn writing_past_size_cap_rotates_and_drops_oldest() {
+        let dir = TempDir::new().unwrap();
+        let logger = logger_in(&dir).with_limits(DebugLogSettings {
+            max_file_bytes: 512,
+            max_files: 3,
         });
+        let chunk = "x".repeat(300);
+        for i in 0..6 {
+            logger
+                .log_stream_chunk("endpoint", &format!("{i}:{chunk}"))
+                .unwrap();
+        }
 
-        let formatted = serde_json::to_string_pretty(&log_entry)?;
-        fs::write(file_path, formatted)?;
-
-        Ok(())
+        let mut names: Vec<String> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["debug.log", "debug.log.1", "debug.log.2"]);
+
+        // Each entry fills a file, so the three survivors hold chunks 3..=5.
+        let read = |name: &str| fs::read_to_string(dir.path().join(name)).unwrap();
+        assert!(read("debug.log").contains("\"5:x"));
+        assert!(read("debug.log.1").contains("\"4:x"));
+        assert!(read("debug.log.2").contains("\"3:x"));
+        assert!(!names.iter().any(|n| n == "debug.log.3"));
     }
 }
@@ -1,3 +1,6 @@
+use crate::http_client::ProxyConfig;
+use crate::http_client::apply_proxy;
+use crate::model_provider_info::ModelProviderInfo;
 use reqwest::header::HeaderValue;
 use std::sync::LazyLock;
 use std::sync::Mutex;
@@ -70,6 +73,25 @@ fn sanitize_user_agent(candidate: String, fallback: &str) -> String {
 
 /// Create a reqwest client with default `originator` and `User-Agent` headers set.
 pub fn create_client(originator: &str) -> reqwest::Client {
+    client_builder(originator)
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new())
+}
+
+/// Like [`create_client`], but applies the connect timeout and proxy
+/// settings of `provider`.
+pub fn create_client_for_provider(
+    originator: &str,
+    provider: &ModelProviderInfo,
+) -> reqwest::Client {
+    let proxy = ProxyConfig::from_env().with_override(provider.proxy.as_deref());
+    apply_proxy(client_builder(originator), proxy)
+        .connect_timeout(provider.connect_timeout())
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new())
+}
+
+fn client_builder(originator: &str) -> reqwest::ClientBuilder {
     use reqwest::header::HeaderMap;
     use reqwest::header::HeaderValue;
 
@@ -79,15 +101,10 @@ pub fn create_client(originator: &str) -> reqwest::Client {
     headers.insert("originator", originator_value);
     let ua = get_codex_user_agent(Some(originator));
 
-    match reqwest::Client::builder()
+    reqwest::Client::builder()
         // Set UA via dedicated helper to avoid header validation pitfalls
         .user_agent(ua)
         .default_headers(headers)
-        .build()
-    {
-        Ok(client) => client,
-        Err(_) => reqwest::Client::new(),
-    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use crate::exec::ExecToolCallOutput;
+use crate::http_client::HttpError;
 use reqwest::StatusCode;
 use serde_json;
 use std::io;
@@ -119,6 +120,9 @@ pub enum CodexErr {
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
 
+    #[error(transparent)]
+    Http(#[from] HttpError),
+
     #[error(transparent)]
     Json(#[from] serde_json::Error),
 
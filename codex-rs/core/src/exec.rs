@@ -2,6 +2,7 @@
 use std::os::unix::process::ExitStatusExt;
 
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::io;
 use std::path::Path;
 use std::path::PathBuf;
@@ -11,6 +12,8 @@ use std::time::Duration;
 use std::time::Instant;
 
 use async_channel::Sender;
+use codex_utils_string::take_bytes_at_char_boundary;
+use codex_utils_string::take_last_bytes_at_char_boundary;
 use tokio::io::AsyncRead;
 use tokio::io::AsyncReadExt;
 use tokio::io::BufReader;
@@ -45,14 +48,20 @@ const TIMEOUT_CODE: i32 = 64;
 const EXIT_CODE_SIGNAL_BASE: i32 = 128; // conventional shell: 128 + signal
 const EXEC_TIMEOUT_EXIT_CODE: i32 = 124; // conventional timeout exit code
 
+/// How long to wait for a killed child to be reaped after a timeout.
+const KILL_REAP_GRACE: Duration = Duration::from_millis(500);
+
 // I/O buffer sizing
 const READ_CHUNK_SIZE: usize = 8192; // bytes per read
 const AGGREGATE_BUFFER_INITIAL_CAPACITY: usize = 8 * 1024; // 8 KiB
 
 /// Limit the number of ExecCommandOutputDelta events emitted per exec call.
-/// Aggregation still collects full output; only the live event stream is capped.
+/// Aggregation is capped separately by `ExecParams::max_output_bytes`.
 pub(crate) const MAX_EXEC_OUTPUT_DELTAS_PER_CALL: usize = 10_000;
 
+/// Default cap on the aggregated output kept for one exec call.
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 10 * 1024 * 1024; // 10 MiB
+
 #[derive(Clone, Debug)]
 pub struct ExecParams {
     pub command: Vec<String>,
@@ -61,6 +70,10 @@ pub struct ExecParams {
     pub env: HashMap<String, String>,
     pub with_escalated_permissions: Option<bool>,
     pub justification: Option<String>,
+    /// Cap on the aggregated output; `None` uses `DEFAULT_MAX_OUTPUT_BYTES`.
+    /// stdout and stderr are also kept separately, up to a quarter of the
+    /// cap each.
+    pub max_output_bytes: Option<usize>,
 }
 
 impl ExecParams {
@@ -69,6 +82,14 @@ impl ExecParams {
     pub fn maybe_timeout_duration(&self) -> Option<Duration> {
         self.timeout_ms.map(Duration::from_millis)
     }
+
+    /// Maximum bytes of aggregated output kept for the call. stdout and
+    /// stderr each keep up to a quarter of it on top, so a call holds at
+    /// most 1.5x the cap. Output beyond a buffer's cap keeps its head and
+    /// tail with a truncation marker in between.
+    pub fn output_cap(&self) -> usize {
+        self.max_output_bytes.unwrap_or(DEFAULT_MAX_OUTPUT_BYTES)
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -82,6 +103,43 @@ pub enum SandboxType {
     LinuxSeccomp,
 }
 
+/// What a [`SandboxType`] can restrict, so callers can tell ahead of time
+/// which parts of a [`SandboxPolicy`] will actually be enforced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SandboxCapabilities {
+    /// Restricts which paths may be read or written.
+    pub filesystem: bool,
+    /// Restricts outbound and inbound network access.
+    pub network: bool,
+    /// Restricts signalling and inspecting processes outside the sandbox.
+    pub process: bool,
+}
+
+impl SandboxType {
+    pub fn capabilities(self) -> SandboxCapabilities {
+        match self {
+            SandboxType::None => SandboxCapabilities::default(),
+            SandboxType::MacosSeatbelt => SandboxCapabilities {
+                filesystem: true,
+                network: true,
+                process: true,
+            },
+            // Landlock covers the filesystem and seccomp covers sockets; there
+            // is no process isolation beyond blocking `ptrace`.
+            SandboxType::LinuxSeccomp => SandboxCapabilities {
+                filesystem: true,
+                network: true,
+                process: false,
+            },
+        }
+    }
+
+    /// Whether this sandbox enforces anything at all.
+    pub fn is_available(self) -> bool {
+        self != SandboxType::None
+    }
+}
+
 #[derive(Clone)]
 pub struct StdoutStream {
     pub sub_id: String,
@@ -107,6 +165,7 @@ pub async fn process_exec_tool_call(
     let start = Instant::now();
 
     let timeout_duration = params.maybe_timeout_duration();
+    let output_cap = params.output_cap();
 
     let raw_output_result: std::result::Result<RawExecToolCallOutput, CodexErr> = match sandbox_type
     {
@@ -127,7 +186,8 @@ pub async fn process_exec_tool_call(
                 env,
             )
             .await?;
-            consume_truncated_output(child, timeout_duration, stdout_stream.clone()).await
+            consume_truncated_output(child, timeout_duration, output_cap, stdout_stream.clone())
+                .await
         }
         SandboxType::LinuxSeccomp => {
             let ExecParams {
@@ -151,7 +211,7 @@ pub async fn process_exec_tool_call(
             )
             .await?;
 
-            consume_truncated_output(child, timeout_duration, stdout_stream).await
+            consume_truncated_output(child, timeout_duration, output_cap, stdout_stream).await
         }
     };
     let duration = start.elapsed();
@@ -279,6 +339,7 @@ async fn exec(
     stdout_stream: Option<StdoutStream>,
 ) -> Result<RawExecToolCallOutput> {
     let timeout = params.maybe_timeout_duration();
+    let output_cap = params.output_cap();
     let ExecParams {
         command, cwd, env, ..
     } = params;
@@ -300,7 +361,7 @@ async fn exec(
         env,
     )
     .await?;
-    consume_truncated_output(child, timeout, stdout_stream).await
+    consume_truncated_output(child, timeout, output_cap, stdout_stream).await
 }
 
 /// Consumes the output of a child process, truncating it so it is suitable for
@@ -308,6 +369,7 @@ async fn exec(
 async fn consume_truncated_output(
     child: Child,
     timeout: Option<Duration>,
+    output_cap: usize,
     stdout_stream: Option<StdoutStream>,
 ) -> Result<RawExecToolCallOutput> {
     // Both stdout and stderr were configured with `Stdio::piped()`
@@ -327,21 +389,40 @@ async fn consume_truncated_output(
         ))
     })?;
 
-    let (agg_tx, agg_rx) = async_channel::unbounded::<Vec<u8>>();
+    let (agg_tx, agg_rx) = async_channel::unbounded::<(bool, Vec<u8>)>();
 
     let stdout_handle = tokio::spawn(read_capped(
         BufReader::new(stdout_reader),
         stdout_stream.clone(),
         false,
-        Some(agg_tx.clone()),
+        agg_tx.clone(),
     ));
     let stderr_handle = tokio::spawn(read_capped(
         BufReader::new(stderr_reader),
         stdout_stream.clone(),
         true,
-        Some(agg_tx.clone()),
+        agg_tx.clone(),
     ));
 
+    // Drain the aggregate channel while the child runs so memory stays bounded.
+    // This is the only copy of the output: the aggregate gets the whole cap
+    // and each stream keeps a quarter of it on the side.
+    let aggregator = tokio::spawn(async move {
+        let stream_cap = output_cap / 4;
+        let mut combined = HeadTailBuffer::new(output_cap);
+        let mut stdout = HeadTailBuffer::new(stream_cap);
+        let mut stderr = HeadTailBuffer::new(stream_cap);
+        while let Ok((is_stderr, chunk)) = agg_rx.recv().await {
+            combined.push(&chunk);
+            if is_stderr {
+                stderr.push(&chunk);
+            } else {
+                stdout.push(&chunk);
+            }
+        }
+        (combined, stdout, stderr)
+    });
+
     let (exit_status, timed_out) = match timeout {
         Some(timeout) => {
             tokio::select! {
@@ -361,7 +442,8 @@ async fn consume_truncated_output(
                                 }
                             }
                             killer.as_mut().start_kill()?;
-                            // Debatable whether `child.wait().await` should be called here.
+                            // Reap the killed child so it does not linger as a zombie.
+                            let _ = tokio::time::timeout(KILL_REAP_GRACE, killer.as_mut().wait()).await;
                             (synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + TIMEOUT_CODE), true)
                         }
                     }
@@ -391,42 +473,26 @@ async fn consume_truncated_output(
     // avoid re-sending a kill signal during Drop.
     killer.disarm();
 
-    // If we timed out, abort the readers after a short grace to prevent hanging when pipes
-    // remain open due to orphaned grandchildren.
-    let (stdout, stderr) = if timed_out {
-        // Abort reader tasks to avoid hanging if pipes remain open.
+    // If we timed out, abort the readers to prevent hanging when pipes remain
+    // open due to orphaned grandchildren; the aggregator keeps the output
+    // captured so far.
+    if timed_out {
         stdout_handle.abort();
         stderr_handle.abort();
-        (
-            StreamOutput {
-                text: Vec::new(),
-                truncated_after_lines: None,
-            },
-            StreamOutput {
-                text: Vec::new(),
-                truncated_after_lines: None,
-            },
-        )
     } else {
-        (stdout_handle.await??, stderr_handle.await??)
-    };
+        stdout_handle.await??;
+        stderr_handle.await??;
+    }
 
     drop(agg_tx);
 
-    let mut combined_buf = Vec::with_capacity(AGGREGATE_BUFFER_INITIAL_CAPACITY);
-    while let Ok(chunk) = agg_rx.recv().await {
-        append_all(&mut combined_buf, &chunk);
-    }
-    let aggregated_output = StreamOutput {
-        text: combined_buf,
-        truncated_after_lines: None,
-    };
+    let (combined, stdout, stderr) = aggregator.await?;
 
     Ok(RawExecToolCallOutput {
         exit_status,
-        stdout,
-        stderr,
-        aggregated_output,
+        stdout: stdout.finish(),
+        stderr: stderr.finish(),
+        aggregated_output: combined.finish(),
         timed_out,
     })
 }
@@ -435,14 +501,11 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
     mut reader: R,
     stream: Option<StdoutStream>,
     is_stderr: bool,
-    aggregate_tx: Option<Sender<Vec<u8>>>,
-) -> io::Result<StreamOutput<Vec<u8>>> {
-    let mut buf = Vec::with_capacity(AGGREGATE_BUFFER_INITIAL_CAPACITY);
+    aggregate_tx: Sender<(bool, Vec<u8>)>,
+) -> io::Result<()> {
     let mut tmp = [0u8; READ_CHUNK_SIZE];
     let mut emitted_deltas: usize = 0;
 
-    // No caps: append all bytes
-
     loop {
         let n = reader.read(&mut tmp).await?;
         if n == 0 {
@@ -490,18 +553,87 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
             }
         }
 
-        if let Some(tx) = &aggregate_tx {
-            let _ = tx.send(tmp[..n].to_vec()).await;
+        let _ = aggregate_tx.send((is_stderr, tmp[..n].to_vec())).await;
+        // Continue reading to EOF to avoid back-pressure
+    }
+
+    Ok(())
+}
+
+/// Bounded output capture that keeps the first and last halves of the cap and
+/// counts the bytes dropped in between.
+struct HeadTailBuffer {
+    head: Vec<u8>,
+    tail: VecDeque<u8>,
+    head_cap: usize,
+    tail_cap: usize,
+    dropped: usize,
+}
+
+impl HeadTailBuffer {
+    fn new(cap: usize) -> Self {
+        let head_cap = cap / 2;
+        Self {
+            head: Vec::with_capacity(head_cap.min(AGGREGATE_BUFFER_INITIAL_CAPACITY)),
+            tail: VecDeque::new(),
+            head_cap,
+            tail_cap: cap - head_cap,
+            dropped: 0,
         }
+    }
 
-        append_all(&mut buf, &tmp[..n]);
-        // Continue reading to EOF to avoid back-pressure
+    fn push(&mut self, mut bytes: &[u8]) {
+        let head_room = self.head_cap - self.head.len();
+        if head_room > 0 {
+            let take = head_room.min(bytes.len());
+            append_all(&mut self.head, &bytes[..take]);
+            bytes = &bytes[take..];
+        }
+        if bytes.len() >= self.tail_cap {
+            self.dropped += self.tail.len() + bytes.len() - self.tail_cap;
+            self.tail.clear();
+            bytes = &bytes[bytes.len() - self.tail_cap..];
+        } else {
+            let overflow = (self.tail.len() + bytes.len()).saturating_sub(self.tail_cap);
+            self.dropped += overflow;
+            self.tail.drain(..overflow);
+        }
+        self.tail.extend(bytes);
     }
 
-    Ok(StreamOutput {
-        text: buf,
-        truncated_after_lines: None,
-    })
+    /// Join head and tail, trimming both to UTF-8 boundaries and inserting a
+    /// truncation marker when anything was dropped.
+    fn finish(self) -> StreamOutput<Vec<u8>> {
+        let mut tail = Vec::from(self.tail);
+        if self.dropped == 0 {
+            let mut text = self.head;
+            text.append(&mut tail);
+            return StreamOutput {
+                text,
+                truncated_after_lines: None,
+            };
+        }
+
+        let head_lossy = String::from_utf8_lossy(&self.head);
+        let head = take_bytes_at_char_boundary(&head_lossy, self.head.len());
+        let tail_lossy = String::from_utf8_lossy(&tail);
+        let tail_kept = take_last_bytes_at_char_boundary(&tail_lossy, tail.len());
+        let truncated = self.dropped
+            + self.head.len().saturating_sub(head.len())
+            + tail.len().saturating_sub(tail_kept.len());
+        let tail = tail_kept;
+        let mut text = Vec::with_capacity(head.len() + tail.len() + 48);
+        append_all(&mut text, head.as_bytes());
+        append_all(
+            &mut text,
+            format!("\n[... {truncated} bytes truncated ...]\n").as_bytes(),
+        );
+        append_all(&mut text, tail.as_bytes());
+        StreamOutput {
+            text,
+            truncated_after_lines: None,
+        }
+    }
 }
 
 #[cfg(unix)]
@@ -18,6 +18,14 @@ pub struct ExecCommandParams {
 
     #[serde(default = "default_login")]
     pub(crate) login: bool,
+
+    /// Initial PTY width in columns.
+    #[serde(default = "default_cols")]
+    pub(crate) cols: u16,
+
+    /// Initial PTY height in rows.
+    #[serde(default = "default_rows")]
+    pub(crate) rows: u16,
 }
 
 fn default_yield_time() -> u64 {
@@ -36,6 +44,14 @@ fn default_shell() -> String {
     "/bin/bash".to_string()
 }
 
+fn default_cols() -> u16 {
+    80
+}
+
+fn default_rows() -> u16 {
+    24
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct WriteStdinParams {
     pub(crate) session_id: SessionId,
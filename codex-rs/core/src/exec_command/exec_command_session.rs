@@ -1,10 +1,12 @@
 use std::sync::Mutex as StdMutex;
 
+use portable_pty::MasterPty;
+use portable_pty::PtySize;
+
 use tokio::sync::broadcast;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-#[derive(Debug)]
 pub(crate) struct ExecCommandSession {
     /// Queue for writing bytes to the process stdin (PTY master write side).
     writer_tx: mpsc::Sender<Vec<u8>>,
@@ -12,6 +14,9 @@ pub(crate) struct ExecCommandSession {
     /// receive only chunks emitted after they subscribe.
     output_tx: broadcast::Sender<Vec<u8>>,
 
+    /// PTY master, kept so the terminal size can be changed after spawn.
+    master: StdMutex<Box<dyn MasterPty + Send>>,
+
     /// Child killer handle for termination on drop (can signal independently
     /// of a thread blocked in `.wait()`).
     killer: StdMutex<Option<Box<dyn portable_pty::ChildKiller + Send + Sync>>>,
@@ -29,10 +34,23 @@ pub(crate) struct ExecCommandSession {
     exit_status: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
+// `MasterPty` does not implement `Debug`, so it is left out here.
+impl std::fmt::Debug for ExecCommandSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecCommandSession")
+            .field("writer_tx", &self.writer_tx)
+            .field("output_tx", &self.output_tx)
+            .field("killer", &self.killer)
+            .field("exit_status", &self.exit_status)
+            .finish_non_exhaustive()
+    }
+}
+
 impl ExecCommandSession {
     pub(crate) fn new(
         writer_tx: mpsc::Sender<Vec<u8>>,
         output_tx: broadcast::Sender<Vec<u8>>,
+        master: Box<dyn MasterPty + Send>,
         killer: Box<dyn portable_pty::ChildKiller + Send + Sync>,
         reader_handle: JoinHandle<()>,
         writer_handle: JoinHandle<()>,
@@ -44,6 +62,7 @@ impl ExecCommandSession {
             Self {
                 writer_tx,
                 output_tx,
+                master: StdMutex::new(master),
                 killer: StdMutex::new(Some(killer)),
                 reader_handle: StdMutex::new(Some(reader_handle)),
                 writer_handle: StdMutex::new(Some(writer_handle)),
@@ -65,6 +84,22 @@ impl ExecCommandSession {
     pub(crate) fn has_exited(&self) -> bool {
         self.exit_status.load(std::sync::atomic::Ordering::SeqCst)
     }
+
+    /// Resizes the PTY. On Unix this issues `TIOCSWINSZ`, and the kernel
+    /// delivers `SIGWINCH` to the foreground process group so full-screen
+    /// programs redraw at the new size.
+    pub(crate) fn resize(&self, cols: u16, rows: u16) -> anyhow::Result<()> {
+        let master = self
+            .master
+            .lock()
+            .map_err(|_| anyhow::anyhow!("PTY master lock poisoned"))?;
+        master.resize(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+    }
 }
 
 impl Drop for ExecCommandSession {
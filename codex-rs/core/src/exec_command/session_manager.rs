@@ -242,6 +242,22 @@ impl SessionManager {
         })
     }
 
+    /// Propagate a new terminal size to a running session's PTY.
+    pub async fn resize_session(
+        &self,
+        session_id: SessionId,
+        cols: u16,
+        rows: u16,
+    ) -> Result<(), String> {
+        let sessions = self.sessions.lock().await;
+        let session = sessions
+            .get(&session_id)
+            .ok_or_else(|| format!("unknown session id {}", session_id.0))?;
+        session
+            .resize(cols, rows)
+            .map_err(|err| format!("failed to resize session id {}: {err}", session_id.0))
+    }
+
     /// Kill all running exec sessions by dropping their session objects.
     /// This is invoked on user interrupts to ensure no child processes remain.
     pub async fn kill_all(&self) {
@@ -264,6 +280,8 @@ async fn create_exec_command_session(
         max_output_tokens: _,
         shell,
         login,
+        cols,
+        rows,
     } = params;
 
     // Use the native pty implementation for the system
@@ -271,8 +289,8 @@ async fn create_exec_command_session(
 
     // Create a new pty
     let pair = pty_system.openpty(PtySize {
-        rows: 24,
-        cols: 80,
+        rows,
+        cols,
         pixel_width: 0,
         pixel_height: 0,
     })?;
@@ -357,6 +375,7 @@ async fn create_exec_command_session(
     let (session, initial_output_rx) = ExecCommandSession::new(
         writer_tx,
         output_tx,
+        pair.master,
         killer,
         reader_handle,
         writer_handle,
@@ -510,6 +529,8 @@ PY"#
             max_output_tokens: 1_000, // large enough to avoid truncation here
             shell: "/bin/bash".to_string(),
             login: false,
+            cols: 80,
+            rows: 24,
         };
         let initial_output = match session_manager
             .handle_exec_command_request(params.clone())
@@ -585,6 +606,76 @@ PY"#
         );
     }
 
+    /// Spawns a process with an initial PTY size, resizes it, and checks the
+    /// child observes both sizes through `stty size` from a `SIGWINCH` trap.
+    #[cfg(unix)]
+    #[allow(clippy::print_stderr)]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn session_manager_resize_reaches_child() {
+        use crate::exec_command::exec_command_params::ExecCommandParams;
+        use crate::exec_command::exec_command_params::WriteStdinParams;
+
+        let session_manager = SessionManager::default();
+        let cmd = r#"trap 'echo "resized $(stty size)"' WINCH
+echo "initial $(stty size)"
+while true; do sleep 0.05; done"#
+            .to_string();
+        let params = ExecCommandParams {
+            cmd,
+            yield_time_ms: 500,
+            max_output_tokens: 1_000,
+            shell: "/bin/bash".to_string(),
+            login: false,
+            cols: 100,
+            rows: 30,
+        };
+        let initial = match session_manager.handle_exec_command_request(params).await {
+            Ok(v) => v,
+            Err(e) => {
+                // PTY may be restricted in some sandboxes; skip in that case.
+                if e.contains("openpty") || e.contains("Operation not permitted") {
+                    eprintln!("skipping test due to restricted PTY: {e}");
+                    return;
+                }
+                panic!("exec request failed unexpectedly: {e}");
+            }
+        };
+        assert!(
+            initial.output.contains("initial 30 100"),
+            "unexpected initial output: {:?}",
+            initial.output
+        );
+        let ExitStatus::Ongoing(session_id) = initial.exit_status else {
+            panic!("expected ongoing session");
+        };
+
+        session_manager
+            .resize_session(session_id, 120, 40)
+            .await
+            .expect("resize should succeed");
+        let resized = session_manager
+            .handle_write_stdin_request(WriteStdinParams {
+                session_id,
+                chars: String::new(),
+                yield_time_ms: 500,
+                max_output_tokens: 1_000,
+            })
+            .await
+            .expect("write stdin should succeed");
+        assert!(
+            resized.output.contains("resized 40 120"),
+            "unexpected output after resize: {:?}",
+            resized.output
+        );
+
+        assert!(
+            session_manager
+                .resize_session(SessionId(u32::MAX), 80, 24)
+                .await
+                .is_err()
+        );
+    }
+
     #[cfg(unix)]
     fn extract_monotonic_numbers(s: &str) -> Vec<i64> {
         s.lines()
@@ -4,6 +4,11 @@ use crate::config_types::ShellEnvironmentPolicyInherit;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+/// Name patterns for obvious secrets, removed from the inherited environment
+/// unless `ignore_default_excludes` is set.
+pub const DEFAULT_EXCLUDE_PATTERNS: &[&str] =
+    &["*KEY*", "*SECRET*", "*TOKEN*", "*PASSWORD*", "*CREDENTIAL*"];
+
 /// Construct an environment map based on the rules in the specified policy. The
 /// resulting map can be passed directly to `Command::envs()` after calling
 /// `env_clear()` to ensure no unintended variables are leaked to the spawned
@@ -42,11 +47,10 @@ where
 
     // Step 2 – Apply the default exclude if not disabled.
     if !policy.ignore_default_excludes {
-        let default_excludes = vec![
-            EnvironmentVariablePattern::new_case_insensitive("*KEY*"),
-            EnvironmentVariablePattern::new_case_insensitive("*SECRET*"),
-            EnvironmentVariablePattern::new_case_insensitive("*TOKEN*"),
-        ];
+        let default_excludes: Vec<EnvironmentVariablePattern> = DEFAULT_EXCLUDE_PATTERNS
+            .iter()
+            .map(|pattern| EnvironmentVariablePattern::new_case_insensitive(pattern))
+            .collect();
         env_map.retain(|k, _| !matches_any(k, &default_excludes));
     }
 
@@ -61,6 +65,8 @@ where
     }
 
     // Step 5 – If include_only is non-empty, keep *only* the matching vars.
+    // This can only remove entries, so anything excluded above stays excluded
+    // even when it also matches an include_only pattern.
     if !policy.include_only.is_empty() {
         env_map.retain(|k, _| matches_any(k, &policy.include_only));
     }
@@ -172,6 +178,35 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_denylist_wins_over_allowlist() {
+        let vars = make_vars(&[
+            ("PATH", "/usr/bin"),
+            ("AWS_REGION", "us-east-1"),
+            ("AWS_SECRET_ACCESS_KEY", "shh"),
+            ("AWS_PROFILE", "prod"),
+            ("DB_PASSWORD", "hunter2"),
+        ]);
+
+        let policy = ShellEnvironmentPolicy {
+            inherit: ShellEnvironmentPolicyInherit::All,
+            exclude: vec![EnvironmentVariablePattern::new_case_insensitive(
+                "AWS_PROFILE",
+            )],
+            include_only: vec![
+                EnvironmentVariablePattern::new_case_insensitive("AWS_*"),
+                EnvironmentVariablePattern::new_case_insensitive("DB_*"),
+            ],
+            ..Default::default()
+        };
+
+        let result = populate_env(vars, &policy);
+        let expected: HashMap<String, String> = hashmap! {
+            "AWS_REGION".to_string() => "us-east-1".to_string(),
+        };
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_inherit_none() {
         let vars = make_vars(&[("PATH", "/usr/bin"), ("HOME", "/home")]);
@@ -573,6 +573,78 @@ pub async fn current_branch_name(cwd: &Path) -> Option<String> {
         .filter(|name| !name.is_empty())
 }
 
+/// Structured summary of a working tree's state, as reported by `git status`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct GitWorktreeStatus {
+    /// Checked out branch; `None` when HEAD is detached.
+    pub branch: Option<String>,
+    /// Commits on the branch not yet on its upstream.
+    pub ahead: u32,
+    /// Commits on the upstream not yet on the branch.
+    pub behind: u32,
+    /// Paths with changes staged in the index (including unmerged paths).
+    pub staged: usize,
+    /// Tracked paths with unstaged changes in the working tree.
+    pub unstaged: usize,
+    /// Untracked paths.
+    pub untracked: usize,
+}
+
+impl GitWorktreeStatus {
+    /// True when nothing is staged, modified, or untracked.
+    pub fn is_clean(&self) -> bool {
+        self.staged == 0 && self.unstaged == 0 && self.untracked == 0
+    }
+}
+
+/// Returns a structured status summary for the working tree containing `cwd`.
+/// Works for linked worktrees (`git worktree add`) as well as the main checkout.
+/// Returns None if `cwd` is not inside a git work tree or on error/timeout.
+pub async fn worktree_status(cwd: &Path) -> Option<GitWorktreeStatus> {
+    let out = run_git_command_with_timeout(&["status", "--porcelain=v2", "--branch"], cwd).await?;
+    if !out.status.success() {
+        return None;
+    }
+    Some(parse_porcelain_v2_status(&String::from_utf8_lossy(
+        &out.stdout,
+    )))
+}
+
+/// Parse `git status --porcelain=v2 --branch` output into a summary.
+fn parse_porcelain_v2_status(output: &str) -> GitWorktreeStatus {
+    let mut status = GitWorktreeStatus::default();
+    for line in output.lines() {
+        if let Some(head) = line.strip_prefix("# branch.head ") {
+            if head != "(detached)" {
+                status.branch = Some(head.to_string());
+            }
+        } else if let Some(ab) = line.strip_prefix("# branch.ab ") {
+            for part in ab.split_whitespace() {
+                if let Some(n) = part.strip_prefix('+') {
+                    status.ahead = n.parse().unwrap_or(0);
+                } else if let Some(n) = part.strip_prefix('-') {
+                    status.behind = n.parse().unwrap_or(0);
+                }
+            }
+        } else if line.starts_with("1 ") || line.starts_with("2 ") {
+            // Ordinary or renamed entry: "<kind> <XY> ..." where X is the index
+            // state and Y the working tree state; '.' means unchanged.
+            let mut xy = line[2..].chars();
+            if xy.next().is_some_and(|x| x != '.') {
+                status.staged += 1;
+            }
+            if xy.next().is_some_and(|y| y != '.') {
+                status.unstaged += 1;
+            }
+        } else if line.starts_with("u ") {
+            status.staged += 1;
+        } else if line.starts_with("? ") {
+            status.untracked += 1;
+        }
+    }
+    status
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1081,6 +1153,98 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_worktree_status_non_git_directory() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        assert_eq!(worktree_status(temp_dir.path()).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_worktree_status_counts_staged_and_untracked() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo_path = create_test_git_repo(&temp_dir).await;
+
+        let clean = worktree_status(&repo_path)
+            .await
+            .expect("Should get status for clean repo");
+        assert!(clean.is_clean());
+        assert!(clean.branch.is_some());
+
+        // One staged new file, one staged modification, one unstaged
+        // modification on the same file, and two untracked files.
+        fs::write(repo_path.join("staged.txt"), "staged").unwrap();
+        fs::write(repo_path.join("test.txt"), "changed").unwrap();
+        Command::new("git")
+            .args(["add", "staged.txt", "test.txt"])
+            .current_dir(&repo_path)
+            .output()
+            .await
+            .expect("Failed to stage files");
+        fs::write(repo_path.join("test.txt"), "changed again").unwrap();
+        fs::write(repo_path.join("untracked1.txt"), "u1").unwrap();
+        fs::write(repo_path.join("untracked2.txt"), "u2").unwrap();
+
+        let status = worktree_status(&repo_path)
+            .await
+            .expect("Should get status for dirty repo");
+        assert_eq!(status.staged, 2);
+        assert_eq!(status.unstaged, 1);
+        assert_eq!(status.untracked, 2);
+        assert_eq!((status.ahead, status.behind), (0, 0));
+        assert!(!status.is_clean());
+    }
+
+    #[tokio::test]
+    async fn test_worktree_status_in_linked_worktree() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo_path = create_test_git_repo(&temp_dir).await;
+        let worktree_path = temp_dir.path().join("wt");
+
+        Command::new("git")
+            .args(["worktree", "add", "-b", "feature"])
+            .arg(&worktree_path)
+            .current_dir(&repo_path)
+            .output()
+            .await
+            .expect("Failed to add worktree");
+        fs::write(worktree_path.join("new.txt"), "new").unwrap();
+
+        let status = worktree_status(&worktree_path)
+            .await
+            .expect("Should get status for linked worktree");
+        assert_eq!(status.branch.as_deref(), Some("feature"));
+        assert_eq!(status.untracked, 1);
+        assert_eq!(status.staged, 0);
+    }
+
+    #[test]
+    fn test_parse_porcelain_v2_status() {
+        let output = "\
+# branch.oid 0123456789abcdef0123456789abcdef01234567
+# branch.head (detached)
+# branch.upstream origin/main
+# branch.ab +3 -1
+1 M. N... 100644 100644 100644 aaaa bbbb a.txt
+1 .M N... 100644 100644 100644 aaaa bbbb b.txt
+2 R. N... 100644 100644 100644 aaaa bbbb R100 c.txt\told.txt
+u UU N... 100644 100644 100644 100644 aaaa bbbb cccc d.txt
+? e.txt
+! ignored.txt
+";
+        let status = parse_porcelain_v2_status(output);
+        assert_eq!(
+            status,
+            GitWorktreeStatus {
+                branch: None,
+                ahead: 3,
+                behind: 1,
+                staged: 3,
+                unstaged: 1,
+                untracked: 1,
+            }
+        );
+    }
+
     #[test]
     fn test_git_info_serialization_with_nones() {
         let git_info = GitInfo {
@@ -101,23 +101,9 @@ pub async fn setup_worktree(git_root: &Path, branch_id: &str) -> Result<(PathBuf
         return Ok((worktree_path, effective_branch));
     }
 
-    let output = Command::new("git")
-        .current_dir(git_root)
-        .args([
-            "worktree",
-            "add",
-            "-b",
-            &effective_branch,
-            worktree_path.to_string_lossy().as_ref(),
-        ])
-        .output()
-        .await
-        .map_err(|e| format!("Failed to create git worktree: {e}"))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
+    if let Err(err) = create_worktree_checked(git_root, &effective_branch, &worktree_path).await {
         // If the branch already exists, generate a unique name and retry once.
-        if stderr.contains("already exists") {
+        if err.contains("already exists") {
             effective_branch = format!(
                 "{}-{}",
                 effective_branch,
@@ -135,25 +121,9 @@ pub async fn setup_worktree(git_root: &Path, branch_id: &str) -> Result<(PathBuf
                     .output()
                     .await;
             }
-            let retry = Command::new("git")
-                .current_dir(git_root)
-                .args([
-                    "worktree",
-                    "add",
-                    "-b",
-                    &effective_branch,
-                    worktree_path.to_string_lossy().as_ref(),
-                ])
-                .output()
-                .await
-                .map_err(|e| format!("Failed to create git worktree (retry): {e}"))?;
-            if !retry.status.success() {
-                let retry_err = String::from_utf8_lossy(&retry.stderr);
-                return Err(format!("Failed to create worktree: {retry_err}"));
-            }
-            record_worktree_in_session(git_root, &worktree_path).await;
+            create_worktree_checked(git_root, &effective_branch, &worktree_path).await?;
         } else {
-            return Err(format!("Failed to create worktree: {stderr}"));
+            return Err(err);
         }
     }
 
@@ -165,6 +135,200 @@ pub async fn setup_worktree(git_root: &Path, branch_id: &str) -> Result<(PathBuf
     Ok((worktree_path, effective_branch))
 }
 
+/// Create `worktree_path` on a new branch `branch`, undoing partial work on failure.
+///
+/// If any step fails, the worktree registration, its directory and the branch
+/// (when this call created them) are removed before the original error is returned.
+pub async fn create_worktree_checked(
+    git_root: &Path,
+    branch: &str,
+    worktree_path: &Path,
+) -> Result<PathBuf, String> {
+    let path_existed = worktree_path.exists();
+    let branch_existed = branch_exists(git_root, branch).await;
+
+    let result = add_worktree(git_root, branch, worktree_path).await;
+    if result.is_err() {
+        rollback_worktree(
+            git_root,
+            branch,
+            worktree_path,
+            path_existed,
+            branch_existed,
+        )
+        .await;
+    }
+    result.map(|()| worktree_path.to_path_buf())
+}
+
+async fn add_worktree(git_root: &Path, branch: &str, worktree_path: &Path) -> Result<(), String> {
+    let output = Command::new("git")
+        .current_dir(git_root)
+        .args([
+            "worktree",
+            "add",
+            "-b",
+            branch,
+            worktree_path.to_string_lossy().as_ref(),
+        ])
+        .output()
+        .await
+        .map_err(|e| format!("Failed to create git worktree: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to create worktree: {stderr}"));
+    }
+
+    // Confirm the checkout is usable before handing it out.
+    let check = Command::new("git")
+        .current_dir(worktree_path)
+        .args(["rev-parse", "--verify", "--quiet", "HEAD"])
+        .output()
+        .await
+        .map_err(|e| format!("Failed to verify git worktree: {e}"))?;
+    if !check.status.success() {
+        return Err(format!(
+            "Worktree {} has no checked out commit",
+            worktree_path.display()
+        ));
+    }
+    Ok(())
+}
+
+async fn branch_exists(git_root: &Path, branch: &str) -> bool {
+    Command::new("git")
+        .current_dir(git_root)
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{branch}"),
+        ])
+        .output()
+        .await
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Best-effort removal of whatever a failed `create_worktree_checked` left behind.
+async fn rollback_worktree(
+    git_root: &Path,
+    branch: &str,
+    worktree_path: &Path,
+    path_existed: bool,
+    branch_existed: bool,
+) {
+    if !path_existed {
+        let _ = Command::new("git")
+            .current_dir(git_root)
+            .args(["worktree", "remove", "--force"])
+            .arg(worktree_path)
+            .output()
+            .await;
+        if worktree_path.exists() {
+            let _ = tokio::fs::remove_dir_all(worktree_path).await;
+        }
+        let _ = Command::new("git")
+            .current_dir(git_root)
+            .args(["worktree", "prune"])
+            .output()
+            .await;
+    }
+    if !branch_existed {
+        let _ = Command::new("git")
+            .current_dir(git_root)
+            .args(["branch", "-D", branch])
+            .output()
+            .await;
+    }
+}
+
+/// A worktree registered with a repository, as reported by `git worktree list`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeInfo {
+    pub path: PathBuf,
+    /// Checked out branch without the `refs/heads/` prefix; `None` when detached or bare.
+    pub branch: Option<String>,
+    /// Commit checked out in the worktree; `None` for bare repositories.
+    pub head: Option<String>,
+    pub locked: bool,
+}
+
+/// List the worktrees registered with the repository at `git_root`, main worktree first.
+pub async fn list_worktrees(git_root: &Path) -> Result<Vec<WorktreeInfo>, String> {
+    let output = Command::new("git")
+        .current_dir(git_root)
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .await
+        .map_err(|e| format!("Failed to list git worktrees: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git worktree list failed: {stderr}"));
+    }
+    Ok(parse_worktree_list(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+fn parse_worktree_list(text: &str) -> Vec<WorktreeInfo> {
+    let mut worktrees = Vec::new();
+    let mut current: Option<WorktreeInfo> = None;
+    for line in text.lines() {
+        if let Some(path) = line.strip_prefix("worktree ") {
+            worktrees.extend(current.take());
+            current = Some(WorktreeInfo {
+                path: PathBuf::from(path),
+                branch: None,
+                head: None,
+                locked: false,
+            });
+            continue;
+        }
+        let Some(info) = current.as_mut() else {
+            continue;
+        };
+        if let Some(head) = line.strip_prefix("HEAD ") {
+            info.head = Some(head.to_string());
+        } else if let Some(branch) = line.strip_prefix("branch ") {
+            let branch = branch.strip_prefix("refs/heads/").unwrap_or(branch);
+            info.branch = Some(branch.to_string());
+        } else if line == "locked" || line.starts_with("locked ") {
+            info.locked = true;
+        }
+    }
+    worktrees.extend(current);
+    worktrees
+}
+
+/// Prune worktrees whose directories no longer exist and return their paths.
+///
+/// The main worktree and locked worktrees are never pruned.
+pub async fn prune_worktrees(git_root: &Path) -> Result<Vec<PathBuf>, String> {
+    let stale: Vec<PathBuf> = list_worktrees(git_root)
+        .await?
+        .into_iter()
+        .skip(1)
+        .filter(|wt| !wt.locked && !wt.path.exists())
+        .map(|wt| wt.path)
+        .collect();
+    if stale.is_empty() {
+        return Ok(stale);
+    }
+
+    let output = Command::new("git")
+        .current_dir(git_root)
+        .args(["worktree", "prune"])
+        .output()
+        .await
+        .map_err(|e| format!("Failed to prune git worktrees: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git worktree prune failed: {stderr}"));
+    }
+    Ok(stale)
+}
+
 /// Append the created worktree to a per-process session file so the TUI can
 /// clean it up on exit without touching worktrees from other processes.
 async fn record_worktree_in_session(git_root: &Path, worktree_path: &Path) {
@@ -397,3 +561,155 @@ pub async fn detect_default_branch(cwd: &Path) -> Option<String> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    async fn git(cwd: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .envs([
+                ("GIT_CONFIG_GLOBAL", "/dev/null"),
+                ("GIT_CONFIG_NOSYSTEM", "1"),
+            ])
+            .args(args)
+            .current_dir(cwd)
+            .output()
+            .await
+            .expect("run git");
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    async fn create_repo(temp_dir: &TempDir) -> PathBuf {
+        let repo = temp_dir.path().join("repo");
+        std::fs::create_dir(&repo).expect("create repo dir");
+        git(&repo, &["init"]).await;
+        git(&repo, &["config", "user.name", "Test User"]).await;
+        git(&repo, &["config", "user.email", "test@example.com"]).await;
+        std::fs::write(repo.join("README.md"), "hello").expect("write file");
+        git(&repo, &["add", "."]).await;
+        git(&repo, &["commit", "-m", "Initial commit"]).await;
+        repo
+    }
+
+    #[tokio::test]
+    async fn list_and_prune_worktrees() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let repo = create_repo(&temp_dir).await;
+        let keep = temp_dir.path().join("wt-keep");
+        let gone = temp_dir.path().join("wt-gone");
+        git(
+            &repo,
+            &["worktree", "add", "-b", "keep", keep.to_str().unwrap()],
+        )
+        .await;
+        git(
+            &repo,
+            &["worktree", "add", "-b", "gone", gone.to_str().unwrap()],
+        )
+        .await;
+
+        let worktrees = list_worktrees(&repo).await.expect("list worktrees");
+        assert_eq!(worktrees.len(), 3);
+        let mut branches: Vec<Option<&str>> =
+            worktrees.iter().map(|wt| wt.branch.as_deref()).collect();
+        branches[1..].sort();
+        assert_eq!(branches[1..], [Some("gone"), Some("keep")]);
+        assert!(worktrees.iter().all(|wt| wt.head.is_some() && !wt.locked));
+
+        std::fs::remove_dir_all(&gone).expect("remove worktree dir");
+        let pruned = prune_worktrees(&repo).await.expect("prune worktrees");
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].file_name(), gone.file_name());
+
+        let remaining = list_worktrees(&repo).await.expect("list worktrees");
+        assert_eq!(remaining.len(), 2);
+        assert_eq!(remaining[1].branch.as_deref(), Some("keep"));
+        assert!(
+            prune_worktrees(&repo)
+                .await
+                .expect("prune again")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn create_worktree_checked_cleans_up_on_failure() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let repo = create_repo(&temp_dir).await;
+        git(&repo, &["branch", "taken"]).await;
+        let path = temp_dir.path().join("wt-taken");
+
+        let err = create_worktree_checked(&repo, "taken", &path)
+            .await
+            .expect_err("conflicting branch name should fail");
+        assert!(err.contains("already exists"), "unexpected error: {err}");
+        assert!(!path.exists());
+        let worktrees = list_worktrees(&repo).await.expect("list worktrees");
+        assert_eq!(worktrees.len(), 1);
+        // The pre-existing branch is left alone.
+        assert!(branch_exists(&repo, "taken").await);
+
+        let created = create_worktree_checked(&repo, "fresh", &path)
+            .await
+            .expect("create worktree");
+        assert_eq!(created, path);
+        assert!(path.join("README.md").exists());
+    }
+
+    #[tokio::test]
+    async fn create_worktree_checked_removes_new_branch_on_failure() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let repo = create_repo(&temp_dir).await;
+        // A non-empty target directory makes `worktree add` fail after the
+        // branch name has been validated.
+        let path = temp_dir.path().join("occupied");
+        std::fs::create_dir(&path).expect("create dir");
+        std::fs::write(path.join("keep.txt"), "keep").expect("write file");
+
+        create_worktree_checked(&repo, "orphan", &path)
+            .await
+            .expect_err("occupied path should fail");
+        assert!(!branch_exists(&repo, "orphan").await);
+        assert!(path.join("keep.txt").exists());
+        assert_eq!(list_worktrees(&repo).await.expect("list").len(), 1);
+    }
+
+    #[test]
+    fn parse_worktree_list_handles_detached_and_locked() {
+        let text = "\
+worktree /repo
+HEAD 1111111111111111111111111111111111111111
+branch refs/heads/main
+
+worktree /wt/detached
+HEAD 2222222222222222222222222222222222222222
+detached
+locked in use
+
+";
+        let worktrees = parse_worktree_list(text);
+        assert_eq!(
+            worktrees,
+            vec![
+                WorktreeInfo {
+                    path: PathBuf::from("/repo"),
+                    branch: Some("main".to_string()),
+                    head: Some("1111111111111111111111111111111111111111".to_string()),
+                    locked: false,
+                },
+                WorktreeInfo {
+                    path: PathBuf::from("/wt/detached"),
+                    branch: None,
+                    head: Some("2222222222222222222222222222222222222222".to_string()),
+                    locked: true,
+                },
+            ]
+        );
+    }
+}
@@ -1,18 +1,357 @@
 use anyhow::Context;
+use chrono::DateTime;
+use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
 use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
 
 use crate::config::resolve_codex_path_for_read;
 
 pub(crate) const INTERNAL_STORAGE_FILE: &str = "internal_storage.json";
 
+/// Directory under `CODEX_HOME` that holds [`FileStorage`] keys, kept apart
+/// from `auth.json`, `config.toml` and the other files in the home directory
+pub(crate) const INTERNAL_STORAGE_DIR: &str = "internal_storage";
+
+/// Key/value store behind [`InternalStorage`]
+///
+/// Keys are plain names (no path separators); values are opaque strings.
+pub trait StorageBackend: Debug + Send + Sync {
+    /// Value stored under `key`, or `None` if it was never set
+    fn get(&self, key: &str) -> anyhow::Result<Option<String>>;
+
+    fn set(&self, key: &str, value: &str) -> anyhow::Result<()>;
+
+    /// Remove `key`; removing a missing key is not an error
+    fn delete(&self, key: &str) -> anyhow::Result<()>;
+
+    /// All stored keys, sorted
+    fn list(&self) -> anyhow::Result<Vec<String>>;
+
+    /// Stored keys starting with `prefix`, sorted
+    fn list_prefix(&self, prefix: &str) -> anyhow::Result<Vec<String>> {
+        let mut keys = self.list()?;
+        keys.retain(|key| key.starts_with(prefix));
+        Ok(keys)
+    }
+}
+
+/// Separator between a namespace and its keys in the underlying backend
+const NAMESPACE_SEPARATOR: char = '.';
+
+/// View of a backend in which every key is prefixed with `<name>.`, so
+/// subsystems sharing one store cannot read or clobber each other's keys
+///
+/// Keys passed to and returned from the view are the un-prefixed ones.
+#[derive(Debug, Clone)]
+pub struct NamespacedStorage {
+    backend: Arc<dyn StorageBackend>,
+    prefix: String,
+}
+
+impl NamespacedStorage {
+    /// Namespace names must be non-empty and must not contain `.`, so one
+    /// namespace can never be a prefix of another.
+    pub fn new(backend: Arc<dyn StorageBackend>, name: &str) -> anyhow::Result<Self> {
+        if name.is_empty() || name.contains(NAMESPACE_SEPARATOR) {
+            anyhow::bail!("invalid storage namespace: {name:?}");
+        }
+        Ok(Self {
+            backend,
+            prefix: format!("{name}{NAMESPACE_SEPARATOR}"),
+        })
+    }
+
+    fn full_key(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+}
+
+impl StorageBackend for NamespacedStorage {
+    fn get(&self, key: &str) -> anyhow::Result<Option<String>> {
+        self.backend.get(&self.full_key(key))
+    }
+
+    fn set(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        self.backend.set(&self.full_key(key), value)
+    }
+
+    fn delete(&self, key: &str) -> anyhow::Result<()> {
+        self.backend.delete(&self.full_key(key))
+    }
+
+    fn list(&self) -> anyhow::Result<Vec<String>> {
+        self.list_prefix("")
+    }
+
+    fn list_prefix(&self, prefix: &str) -> anyhow::Result<Vec<String>> {
+        Ok(self
+            .backend
+            .list_prefix(&self.full_key(prefix))?
+            .into_iter()
+            .filter_map(|key| key.strip_prefix(&self.prefix).map(str::to_string))
+            .collect())
+    }
+}
+
+/// One file per key inside a dedicated directory
+///
+/// The directory must belong to the store alone: [`StorageBackend::list`]
+/// returns every file in it.
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    root: PathBuf,
+}
+
+impl FileStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> anyhow::Result<PathBuf> {
+        if key.is_empty() || key == "." || key == ".." || key.contains(['/', '\\']) {
+            anyhow::bail!("invalid storage key: {key:?}");
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+impl StorageBackend for FileStorage {
+    fn get(&self, key: &str) -> anyhow::Result<Option<String>> {
+        read_optional(&self.path_for(key)?)
+    }
+
+    fn set(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        let path = self.path_for(key)?;
+        std::fs::create_dir_all(&self.root).with_context(|| {
+            format!(
+                "failed to create storage directory at {}",
+                self.root.display()
+            )
+        })?;
+        std::fs::write(&path, value).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    fn delete(&self, key: &str) -> anyhow::Result<()> {
+        let path = self.path_for(key)?;
+        match std::fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+            Err(error) => {
+                Err(error).with_context(|| format!("failed to delete {}", path.display()))
+            }
+        }
+    }
+
+    fn list(&self) -> anyhow::Result<Vec<String>> {
+        let entries = match std::fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => {
+                return Err(error)
+                    .with_context(|| format!("failed to list {}", self.root.display()));
+            }
+        };
+        let mut keys = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_file()
+                && let Some(name) = entry.file_name().to_str()
+            {
+                keys.push(name.to_string());
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+}
+
+fn read_optional(path: &Path) -> anyhow::Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Process-local storage for tests and ephemeral environments
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: Mutex<BTreeMap<String, String>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, String>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn get(&self, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(self.entries().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        self.entries().insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> anyhow::Result<()> {
+        self.entries().remove(key);
+        Ok(())
+    }
+
+    fn list(&self) -> anyhow::Result<Vec<String>> {
+        Ok(self.entries().keys().cloned().collect())
+    }
+}
+
+/// Source of the current time for [`ExpiringStorage`]
+pub type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+
+/// On-disk form of an [`ExpiringStorage`] value
+///
+/// Unknown fields are rejected so that unrelated JSON values which happen to
+/// contain a `value` field are read back verbatim.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpiringEntry {
+    value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+/// Wrapper whose entries may carry an expiry time
+///
+/// The expiry is stored with the value, so it survives restarts. Expired
+/// entries read as absent until [`ExpiringStorage::purge_expired`] removes
+/// them. Values not written through this wrapper are treated as
+/// non-expiring, so it is best used on a dedicated [`NamespacedStorage`].
+#[derive(Clone)]
+pub struct ExpiringStorage {
+    inner: Arc<dyn StorageBackend>,
+    clock: Clock,
+}
+
+impl Debug for ExpiringStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExpiringStorage")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExpiringStorage {
+    pub fn new(inner: Arc<dyn StorageBackend>) -> Self {
+        Self::with_clock(inner, Arc::new(Utc::now))
+    }
+
+    pub fn with_clock(inner: Arc<dyn StorageBackend>, clock: Clock) -> Self {
+        Self { inner, clock }
+    }
+
+    /// Store `value` so that it reads as absent once `ttl` has elapsed
+    pub fn set_with_ttl(&self, key: &str, value: &str, ttl: Duration) -> anyhow::Result<()> {
+        let ttl = chrono::Duration::from_std(ttl).context("TTL out of range")?;
+        self.write(key, value, Some((self.clock)() + ttl))
+    }
+
+    /// Delete every expired entry, returning how many were removed
+    pub fn purge_expired(&self) -> anyhow::Result<usize> {
+        let mut purged = 0;
+        for key in self.inner.list()? {
+            if let Some(entry) = self.read(&key)?
+                && self.is_expired(&entry)
+            {
+                self.inner.delete(&key)?;
+                purged += 1;
+            }
+        }
+        Ok(purged)
+    }
+
+    fn write(
+        &self,
+        key: &str,
+        value: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let entry = ExpiringEntry {
+            value: value.to_string(),
+            expires_at,
+        };
+        self.inner.set(key, &serde_json::to_string(&entry)?)
+    }
+
+    fn read(&self, key: &str) -> anyhow::Result<Option<ExpiringEntry>> {
+        Ok(self.inner.get(key)?.map(|raw| {
+            serde_json::from_str(&raw).unwrap_or(ExpiringEntry {
+                value: raw,
+                expires_at: None,
+            })
+        }))
+    }
+
+    fn is_expired(&self, entry: &ExpiringEntry) -> bool {
+        entry
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= (self.clock)())
+    }
+}
+
+impl StorageBackend for ExpiringStorage {
+    fn get(&self, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(self
+            .read(key)?
+            .filter(|entry| !self.is_expired(entry))
+            .map(|entry| entry.value))
+    }
+
+    /// Stores a value that never expires
+    fn set(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        self.write(key, value, None)
+    }
+
+    fn delete(&self, key: &str) -> anyhow::Result<()> {
+        self.inner.delete(key)
+    }
+
+    fn list(&self) -> anyhow::Result<Vec<String>> {
+        self.list_prefix("")
+    }
+
+    fn list_prefix(&self, prefix: &str) -> anyhow::Result<Vec<String>> {
+        let mut live = Vec::new();
+        for key in self.inner.list_prefix(prefix)? {
+            if self.get(&key)?.is_some() {
+                live.push(key);
+            }
+        }
+        Ok(live)
+    }
+}
+
+fn default_backend() -> Arc<dyn StorageBackend> {
+    Arc::new(MemoryStorage::new())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InternalStorage {
-    #[serde(skip)]
-    storage_path: PathBuf,
+    #[serde(skip, default = "default_backend")]
+    backend: Arc<dyn StorageBackend>,
     #[serde(default = "default_gpt_5_codex_model_prompt_seen")]
     pub gpt_5_codex_model_prompt_seen: bool,
 }
@@ -24,7 +363,7 @@ const fn default_gpt_5_codex_model_prompt_seen() -> bool {
 impl Default for InternalStorage {
     fn default() -> Self {
         Self {
-            storage_path: PathBuf::new(),
+            backend: default_backend(),
             gpt_5_codex_model_prompt_seen: default_gpt_5_codex_model_prompt_seen(),
         }
     }
@@ -32,61 +371,293 @@ impl Default for InternalStorage {
 
 // TODO(jif) generalise all the file writers and build proper async channel inserters.
 impl InternalStorage {
+    /// Load from the `internal_storage/` directory in `codex_home`, falling
+    /// back to the legacy `codex_home/internal_storage.json` until the first
+    /// `persist`
     pub fn load(codex_home: &Path) -> Self {
-        let storage_path = codex_home.join(INTERNAL_STORAGE_FILE);
-        let read_path = resolve_codex_path_for_read(codex_home, Path::new(INTERNAL_STORAGE_FILE));
+        let backend: Arc<dyn StorageBackend> =
+            Arc::new(FileStorage::new(codex_home.join(INTERNAL_STORAGE_DIR)));
+        let serialized = match backend.get(INTERNAL_STORAGE_FILE) {
+            Ok(None) => read_optional(&resolve_codex_path_for_read(
+                codex_home,
+                Path::new(INTERNAL_STORAGE_FILE),
+            )),
+            other => other,
+        };
+        Self::from_serialized(backend, serialized)
+    }
+
+    /// Load from `backend`; `persist` writes back to the same backend
+    pub fn load_from(backend: Arc<dyn StorageBackend>) -> Self {
+        let serialized = backend.get(INTERNAL_STORAGE_FILE);
+        Self::from_serialized(backend, serialized)
+    }
 
-        match std::fs::read_to_string(&read_path) {
-            Ok(serialized) => match serde_json::from_str::<Self>(&serialized) {
+    fn from_serialized(
+        backend: Arc<dyn StorageBackend>,
+        serialized: anyhow::Result<Option<String>>,
+    ) -> Self {
+        match serialized {
+            Ok(Some(serialized)) => match serde_json::from_str::<Self>(&serialized) {
                 Ok(mut storage) => {
-                    storage.storage_path = storage_path;
+                    storage.backend = backend;
                     storage
                 }
                 Err(error) => {
                     tracing::warn!("failed to parse internal storage: {error:?}");
-                    Self::empty(storage_path)
+                    Self::empty(backend)
                 }
             },
+            Ok(None) => {
+                tracing::debug!("internal storage not found in {backend:?}; initializing defaults");
+                Self::empty(backend)
+            }
             Err(error) => {
-                if error.kind() == ErrorKind::NotFound {
-                    tracing::debug!(
-                        "internal storage not found at {}; initializing defaults",
-                        storage_path.display()
-                    );
-                } else {
-                    tracing::warn!("failed to read internal storage: {error:?}");
-                }
-                Self::empty(storage_path)
+                tracing::warn!("failed to read internal storage: {error:?}");
+                Self::empty(backend)
             }
         }
     }
 
-    fn empty(storage_path: PathBuf) -> Self {
+    /// Keys private to one subsystem, stored in the same backend
+    ///
+    /// The namespace that would contain `internal_storage.json` is reserved.
+    pub fn namespace(&self, name: &str) -> anyhow::Result<NamespacedStorage> {
+        if INTERNAL_STORAGE_FILE
+            .strip_prefix(name)
+            .is_some_and(|rest| rest.starts_with(NAMESPACE_SEPARATOR))
+        {
+            anyhow::bail!("storage namespace {name:?} is reserved");
+        }
+        NamespacedStorage::new(self.backend.clone(), name)
+    }
+
+    fn empty(backend: Arc<dyn StorageBackend>) -> Self {
         Self {
-            storage_path,
+            backend,
             ..Default::default()
         }
     }
 
     pub async fn persist(&self) -> anyhow::Result<()> {
         let serialized = serde_json::to_string_pretty(self)?;
+        let backend = self.backend.clone();
+        tokio::task::spawn_blocking(move || backend.set(INTERNAL_STORAGE_FILE, &serialized))
+            .await
+            .context("internal storage writer task failed")?
+            .context("failed to persist internal storage")
+    }
+}
 
-        if let Some(parent) = self.storage_path.parent() {
-            tokio::fs::create_dir_all(parent).await.with_context(|| {
-                format!(
-                    "failed to create internal storage directory at {}",
-                    parent.display()
-                )
-            })?;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
 
-        tokio::fs::write(&self.storage_path, serialized)
-            .await
-            .with_context(|| {
-                format!(
-                    "failed to persist internal storage at {}",
-                    self.storage_path.display()
-                )
-            })
+    /// Behaviour every backend must share
+    fn exercise_backend(backend: &dyn StorageBackend) {
+        assert_eq!(backend.get("alpha").unwrap(), None);
+        assert_eq!(backend.list().unwrap(), Vec::<String>::new());
+
+        backend.set("beta", "2").unwrap();
+        backend.set("alpha", "1").unwrap();
+        backend.set("alpha", "one").unwrap();
+        assert_eq!(backend.get("alpha").unwrap().as_deref(), Some("one"));
+        assert_eq!(backend.list().unwrap(), vec!["alpha", "beta"]);
+
+        backend.delete("alpha").unwrap();
+        backend.delete("missing").unwrap();
+        assert_eq!(backend.get("alpha").unwrap(), None);
+        assert_eq!(backend.list().unwrap(), vec!["beta"]);
+    }
+
+    async fn exercise_internal_storage(backend: Arc<dyn StorageBackend>) {
+        let mut storage = InternalStorage::load_from(backend.clone());
+        assert!(storage.gpt_5_codex_model_prompt_seen);
+
+        storage.gpt_5_codex_model_prompt_seen = false;
+        storage.persist().await.unwrap();
+        assert_eq!(backend.list().unwrap(), vec![INTERNAL_STORAGE_FILE]);
+
+        let reloaded = InternalStorage::load_from(backend.clone());
+        assert!(!reloaded.gpt_5_codex_model_prompt_seen);
+
+        backend.set(INTERNAL_STORAGE_FILE, "not json").unwrap();
+        assert!(InternalStorage::load_from(backend).gpt_5_codex_model_prompt_seen);
+    }
+
+    #[test]
+    fn file_storage_satisfies_backend_contract() {
+        let dir = TempDir::new().unwrap();
+        exercise_backend(&FileStorage::new(dir.path().join("home")));
+    }
+
+    #[test]
+    fn memory_storage_satisfies_backend_contract() {
+        exercise_backend(&MemoryStorage::new());
+    }
+
+    #[tokio::test]
+    async fn internal_storage_round_trips_through_file_storage() {
+        let dir = TempDir::new().unwrap();
+        exercise_internal_storage(Arc::new(FileStorage::new(dir.path()))).await;
+        assert!(dir.path().join(INTERNAL_STORAGE_FILE).exists());
+    }
+
+    #[tokio::test]
+    async fn load_reads_legacy_file_and_persists_into_storage_dir() {
+        let home = TempDir::new().unwrap();
+        std::fs::write(home.path().join("auth.json"), "{}").unwrap();
+        std::fs::write(
+            home.path().join(INTERNAL_STORAGE_FILE),
+            r#"{"gpt_5_codex_model_prompt_seen":false}"#,
+        )
+        .unwrap();
+
+        let storage = InternalStorage::load(home.path());
+        assert!(!storage.gpt_5_codex_model_prompt_seen);
+        assert_eq!(storage.backend.list().unwrap(), Vec::<String>::new());
+
+        storage.persist().await.unwrap();
+        let storage_dir = home.path().join(INTERNAL_STORAGE_DIR);
+        assert!(storage_dir.join(INTERNAL_STORAGE_FILE).exists());
+        assert_eq!(storage.backend.list().unwrap(), vec![INTERNAL_STORAGE_FILE]);
+        assert!(!InternalStorage::load(home.path()).gpt_5_codex_model_prompt_seen);
+    }
+
+    #[tokio::test]
+    async fn internal_storage_round_trips_through_memory_storage() {
+        exercise_internal_storage(Arc::new(MemoryStorage::new())).await;
+    }
+
+    #[test]
+    fn file_storage_rejects_path_keys() {
+        let dir = TempDir::new().unwrap();
+        let backend = FileStorage::new(dir.path());
+        assert!(backend.set("../escape", "x").is_err());
+        assert!(backend.get("nested/key").is_err());
+    }
+
+    fn exercise_namespaces(backend: Arc<dyn StorageBackend>) {
+        let storage = InternalStorage::load_from(backend.clone());
+        let agents = storage.namespace("agents").unwrap();
+        let auth = storage.namespace("auth").unwrap();
+
+        agents.set("last_run", "agents").unwrap();
+        agents.set("last_model", "gpt").unwrap();
+        auth.set("last_run", "auth").unwrap();
+        backend.set("last_run", "global").unwrap();
+
+        assert_eq!(agents.get("last_run").unwrap().as_deref(), Some("agents"));
+        assert_eq!(auth.get("last_run").unwrap().as_deref(), Some("auth"));
+        assert_eq!(backend.get("last_run").unwrap().as_deref(), Some("global"));
+
+        assert_eq!(agents.list().unwrap(), vec!["last_model", "last_run"]);
+        assert_eq!(agents.list_prefix("last_r").unwrap(), vec!["last_run"]);
+        assert_eq!(auth.list_prefix("last").unwrap(), vec!["last_run"]);
+        assert_eq!(
+            backend.list_prefix("a").unwrap(),
+            vec!["agents.last_model", "agents.last_run", "auth.last_run"]
+        );
+
+        auth.delete("last_run").unwrap();
+        assert_eq!(agents.get("last_run").unwrap().as_deref(), Some("agents"));
+        assert_eq!(auth.list().unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn namespaces_do_not_collide_in_file_storage() {
+        let dir = TempDir::new().unwrap();
+        exercise_namespaces(Arc::new(FileStorage::new(dir.path())));
+    }
+
+    #[test]
+    fn namespaces_do_not_collide_in_memory_storage() {
+        exercise_namespaces(Arc::new(MemoryStorage::new()));
+    }
+
+    #[test]
+    fn namespace_names_cannot_overlap() {
+        let storage = InternalStorage::default();
+        assert!(storage.namespace("").is_err());
+        assert!(storage.namespace("agents.v2").is_err());
+        assert!(storage.namespace("internal_storage").is_err());
+    }
+
+    #[tokio::test]
+    async fn namespaces_cannot_overwrite_codex_home_files() {
+        let home = TempDir::new().unwrap();
+        std::fs::write(home.path().join("auth.json"), "auth").unwrap();
+        std::fs::write(home.path().join("config.toml"), "config").unwrap();
+
+        let storage = InternalStorage::load(home.path());
+        storage.persist().await.unwrap();
+        storage.namespace("auth").unwrap().set("json", "x").unwrap();
+        storage
+            .namespace("config")
+            .unwrap()
+            .set("toml", "x")
+            .unwrap();
+
+        let read = |name: &str| std::fs::read_to_string(home.path().join(name)).unwrap();
+        assert_eq!(read("auth.json"), "auth");
+        assert_eq!(read("config.toml"), "config");
+        assert!(InternalStorage::load(home.path()).gpt_5_codex_model_prompt_seen);
+    }
+
+    /// Clock that only moves when the test advances it
+    fn manual_clock() -> (Clock, Arc<Mutex<DateTime<Utc>>>) {
+        let now = Arc::new(Mutex::new(Utc::now()));
+        let handle = now.clone();
+        (Arc::new(move || *handle.lock().unwrap()), now)
+    }
+
+    #[test]
+    fn entries_expire_after_their_ttl() {
+        let dir = TempDir::new().unwrap();
+        let (clock, now) = manual_clock();
+        let backend: Arc<dyn StorageBackend> = Arc::new(FileStorage::new(dir.path()));
+        let cache = ExpiringStorage::with_clock(backend.clone(), clock.clone());
+
+        cache
+            .set_with_ttl("token", "abc", Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(cache.get("token").unwrap().as_deref(), Some("abc"));
+
+        // The expiry is persisted, so a fresh wrapper sees it too.
+        *now.lock().unwrap() += chrono::Duration::seconds(61);
+        let reopened = ExpiringStorage::with_clock(backend.clone(), clock);
+        assert_eq!(reopened.get("token").unwrap(), None);
+        assert_eq!(reopened.list().unwrap(), Vec::<String>::new());
+        assert!(backend.get("token").unwrap().is_some());
+    }
+
+    #[test]
+    fn purge_expired_removes_only_expired_entries() {
+        let (clock, now) = manual_clock();
+        let backend: Arc<dyn StorageBackend> = Arc::new(MemoryStorage::new());
+        let cache = ExpiringStorage::with_clock(backend.clone(), clock);
+
+        cache
+            .set_with_ttl("short", "1", Duration::from_secs(10))
+            .unwrap();
+        cache
+            .set_with_ttl("long", "2", Duration::from_secs(3600))
+            .unwrap();
+        cache.set("forever", "3").unwrap();
+        backend.set("raw", "4").unwrap();
+        let foreign = r#"{"value":"5","expires_at":"2000-01-01T00:00:00Z","kind":"other"}"#;
+        backend.set("foreign", foreign).unwrap();
+
+        *now.lock().unwrap() += chrono::Duration::seconds(11);
+        assert_eq!(cache.purge_expired().unwrap(), 1);
+        assert_eq!(
+            backend.list().unwrap(),
+            vec!["foreign", "forever", "long", "raw"]
+        );
+        assert_eq!(cache.get("long").unwrap().as_deref(), Some("2"));
+        assert_eq!(cache.get("raw").unwrap().as_deref(), Some("4"));
+        assert_eq!(cache.get("foreign").unwrap().as_deref(), Some(foreign));
     }
 }
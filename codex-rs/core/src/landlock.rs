@@ -25,6 +25,7 @@ pub async fn spawn_command_under_linux_sandbox<P>(
 where
     P: AsRef<Path>,
 {
+    warn_if_landlock_degraded(sandbox_policy);
     let args = create_linux_sandbox_command_args(command, sandbox_policy, sandbox_policy_cwd);
     let arg0 = Some("codex-linux-sandbox");
     spawn_child_async(
@@ -68,3 +69,67 @@ fn create_linux_sandbox_command_args(
 
     linux_cmd
 }
+
+/// Filesystem features used by the sandbox helper's Landlock ruleset
+/// (written against `ABI::V5`), paired with the ABI version that introduced
+/// them.
+pub const LANDLOCK_FILESYSTEM_FEATURES: &[(u32, &str)] = &[
+    (1, "filesystem access control"),
+    (2, "file reparenting (refer)"),
+    (3, "file truncation"),
+    (5, "device ioctl"),
+];
+
+/// Filesystem features a kernel with Landlock ABI `abi_version` cannot
+/// enforce.
+pub fn unsupported_landlock_features(abi_version: u32) -> Vec<&'static str> {
+    LANDLOCK_FILESYSTEM_FEATURES
+        .iter()
+        .filter(|(introduced_in, _)| *introduced_in > abi_version)
+        .map(|(_, feature)| *feature)
+        .collect()
+}
+
+/// Asks the kernel for the highest Landlock ABI version it supports,
+/// returning `0` when Landlock is not available.
+#[cfg(target_os = "linux")]
+pub fn probe_landlock_abi() -> u32 {
+    const LANDLOCK_CREATE_RULESET_VERSION: libc::c_uint = 1;
+
+    // SAFETY: with a null attribute pointer and the VERSION flag the syscall
+    // only reports the ABI version and does not create a ruleset.
+    let version = unsafe {
+        libc::syscall(
+            libc::SYS_landlock_create_ruleset,
+            std::ptr::null::<libc::c_void>(),
+            0usize,
+            LANDLOCK_CREATE_RULESET_VERSION,
+        )
+    };
+    u32::try_from(version).unwrap_or(0)
+}
+
+/// Warns once per process when the kernel cannot enforce every filesystem
+/// restriction of a sandboxed command. The helper is spawned per command, so
+/// reporting it there would repeat the warning on every tool call.
+fn warn_if_landlock_degraded(sandbox_policy: &SandboxPolicy) {
+    #[cfg(target_os = "linux")]
+    {
+        static CHECKED: std::sync::Once = std::sync::Once::new();
+        if sandbox_policy.has_full_disk_write_access() {
+            return;
+        }
+        CHECKED.call_once(|| {
+            let abi_version = probe_landlock_abi();
+            let unsupported = unsupported_landlock_features(abi_version);
+            if !unsupported.is_empty() {
+                tracing::warn!(
+                    "Landlock ABI v{abi_version} cannot enforce {}; sandbox is degraded",
+                    unsupported.join(", ")
+                );
+            }
+        });
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = sandbox_policy;
+}
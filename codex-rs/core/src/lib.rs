@@ -30,6 +30,7 @@ pub mod architect;
 pub mod async_agent_executor; // SPEC-936: Async agent execution without tmux
 mod command_safety;
 pub mod config;
+pub mod config_diff;
 pub mod config_edit;
 pub mod config_loader;
 pub mod config_profile;
@@ -61,7 +62,11 @@ pub mod schema_validator;
 pub mod slash_commands;
 mod truncate;
 mod user_instructions;
+pub use model_provider_info::BUILT_IN_DEEPSEEK_MODEL_PROVIDER_ID;
+pub use model_provider_info::BUILT_IN_MISTRAL_MODEL_PROVIDER_ID;
 pub use model_provider_info::BUILT_IN_OSS_MODEL_PROVIDER_ID;
+pub use model_provider_info::ModelInfo;
+pub use model_provider_info::ModelPricing;
 pub use model_provider_info::ModelProviderInfo;
 pub use model_provider_info::OpenRouterConfig;
 pub use model_provider_info::OpenRouterProviderConfig;
@@ -81,6 +86,7 @@ pub use auth::CodexAuth;
 pub mod benchmarks; // SPEC-940: Benchmark harness with statistical analysis
 pub mod default_client;
 pub mod model_family;
+pub mod model_prices;
 mod openai_model_info;
 mod openai_tools;
 mod patch_harness;
@@ -105,15 +111,19 @@ pub use rollout::ARCHIVED_SESSIONS_SUBDIR;
 pub use rollout::RolloutRecorder;
 pub use rollout::SESSIONS_SUBDIR;
 pub use rollout::SessionMeta;
+pub use rollout::export_markdown;
 pub use rollout::find_conversation_path_by_id_str;
 pub use rollout::list::ConversationItem;
 pub use rollout::list::ConversationsPage;
 pub use rollout::list::Cursor;
+pub use rollout::list::search_conversations;
+pub use rollout::open_rollout_reader;
 mod function_tool;
 mod user_notification;
 pub mod util;
 
 pub use apply_patch::CODEX_APPLY_PATCH_ARG1;
+pub use command_safety::command_risk;
 pub use command_safety::is_dangerous_command;
 pub use command_safety::is_safe_command;
 pub use safety::get_platform_sandbox;
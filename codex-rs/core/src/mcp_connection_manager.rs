@@ -6,18 +6,22 @@
 //! in a single aggregated map using the fully-qualified tool name
 //! `"<server><MCP_TOOL_NAME_DELIMITER><tool>"` as the key.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ffi::OsString;
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Context;
 use anyhow::Result;
 use anyhow::anyhow;
 use codex_mcp_client::McpClient;
+use codex_mcp_client::McpRequestTimeout;
 use mcp_types::ClientCapabilities;
 use mcp_types::Implementation;
 use mcp_types::Tool;
+use mcp_types::ToolInputSchema;
 
 use serde_json::json;
 use sha1::Digest;
@@ -27,6 +31,7 @@ use tracing::info;
 use tracing::warn;
 
 use crate::config_types::McpServerConfig;
+use crate::util::backoff;
 
 /// Delimiter used to separate the server name from the tool name in a fully
 /// qualified tool name.
@@ -42,6 +47,10 @@ const DEFAULT_STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
 /// Timeout for an individual tool call.
 const DEFAULT_TOOL_CALL_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// Reconnect attempts after a server's connection drops, unless overridden
+/// per server with `reconnect_attempts`.
+const DEFAULT_RECONNECT_ATTEMPTS: u32 = 5;
+
 /// Map that holds a startup error for every MCP server that could **not** be
 /// spawned successfully.
 pub type ClientStartErrors = HashMap<String, anyhow::Error>;
@@ -84,14 +93,219 @@ struct ToolInfo {
     tool: Tool,
 }
 
+/// A tool in the aggregated catalog, see [`McpConnectionManager::tool_catalog`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolEntry {
+    /// Server the tool comes from.
+    pub server: String,
+    /// Tool name as reported by the server.
+    pub name: String,
+    /// Fully-qualified `server__tool` name used for dispatch.
+    pub namespaced_name: String,
+    pub schema: ToolInputSchema,
+}
+
+/// Returned (inside [`anyhow::Error`]) when a tool call exceeds its timeout.
+#[derive(Debug, thiserror::Error)]
+#[error("tool call `{server}/{tool}` timed out after {timeout:?}")]
+pub struct McpToolCallTimeout {
+    pub server: String,
+    pub tool: String,
+    pub timeout: Duration,
+}
+
+/// Connection status of a configured MCP server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McpServerState {
+    /// A `lazy` server that has not been spawned yet.
+    NotStarted,
+    Connected,
+    /// The connection dropped and the server is being respawned. `attempt`
+    /// counts from 1.
+    Reconnecting {
+        attempt: u32,
+    },
+    /// Every reconnect attempt failed; the server stays unavailable until the
+    /// session restarts.
+    Failed,
+}
+
+/// A live client (absent until a lazy server is first used) plus what is
+/// needed to spawn it again.
+struct ServerConnection {
+    client: std::sync::RwLock<Option<Arc<McpClient>>>,
+    state: std::sync::Mutex<McpServerState>,
+    /// Serializes the first spawn of a lazy server.
+    start_lock: tokio::sync::Mutex<()>,
+    config: McpServerConfig,
+    startup_timeout: Duration,
+}
+
+impl ServerConnection {
+    fn new(
+        client: Option<Arc<McpClient>>,
+        config: McpServerConfig,
+        startup_timeout: Duration,
+    ) -> Self {
+        let state = if client.is_some() {
+            McpServerState::Connected
+        } else {
+            McpServerState::NotStarted
+        };
+        Self {
+            client: std::sync::RwLock::new(client),
+            state: std::sync::Mutex::new(state),
+            start_lock: tokio::sync::Mutex::new(()),
+            config,
+            startup_timeout,
+        }
+    }
+
+    fn client(&self) -> Option<Arc<McpClient>> {
+        match self.client.read() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    fn set_client(&self, client: McpClient) {
+        let client = Some(Arc::new(client));
+        match self.client.write() {
+            Ok(mut guard) => *guard = client,
+            Err(poisoned) => *poisoned.into_inner() = client,
+        }
+    }
+
+    /// Return the running client, spawning a lazy server on first use.
+    async fn ensure_started(&self, server_name: &str) -> Result<Arc<McpClient>> {
+        if let Some(client) = self.client() {
+            return Ok(client);
+        }
+        let _guard = self.start_lock.lock().await;
+        if let Some(client) = self.client() {
+            return Ok(client);
+        }
+        let client = start_client(self.config.clone(), self.startup_timeout)
+            .await
+            .with_context(|| format!("failed to start MCP server '{server_name}'"))?;
+        self.set_client(client);
+        self.set_state(McpServerState::Connected);
+        info!("started lazy MCP server '{server_name}'");
+        self.client()
+            .ok_or_else(|| anyhow!("MCP server '{server_name}' stopped during startup"))
+    }
+
+    fn state(&self) -> McpServerState {
+        match self.state.lock() {
+            Ok(guard) => *guard,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    fn set_state(&self, state: McpServerState) {
+        match self.state.lock() {
+            Ok(mut guard) => *guard = state,
+            Err(poisoned) => *poisoned.into_inner() = state,
+        }
+    }
+
+    /// Start reconnecting in the background unless a reconnect is already
+    /// running or has given up.
+    fn spawn_reconnect(self: &Arc<Self>, server_name: &str) {
+        {
+            let mut state = match self.state.lock() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            if *state != McpServerState::Connected {
+                return;
+            }
+            *state = McpServerState::Reconnecting { attempt: 1 };
+        }
+
+        let conn = Arc::clone(self);
+        let server_name = server_name.to_string();
+        tokio::spawn(async move { conn.reconnect(server_name).await });
+    }
+
+    async fn reconnect(&self, server_name: String) {
+        let max_attempts = self
+            .config
+            .reconnect_attempts
+            .unwrap_or(DEFAULT_RECONNECT_ATTEMPTS);
+        for attempt in 1..=max_attempts {
+            self.set_state(McpServerState::Reconnecting { attempt });
+            tokio::time::sleep(backoff(u64::from(attempt))).await;
+            match start_client(self.config.clone(), self.startup_timeout).await {
+                Ok(client) => {
+                    self.set_client(client);
+                    self.set_state(McpServerState::Connected);
+                    info!("reconnected to MCP server '{server_name}' (attempt {attempt})");
+                    return;
+                }
+                Err(e) => {
+                    warn!(
+                        "reconnect attempt {attempt} to MCP server '{server_name}' failed: {e:#}"
+                    );
+                }
+            }
+        }
+        warn!("giving up on MCP server '{server_name}' after {max_attempts} reconnect attempts");
+        self.set_state(McpServerState::Failed);
+    }
+}
+
+/// Spawn the server described by `cfg` and run the `initialize` handshake.
+async fn start_client(cfg: McpServerConfig, startup_timeout: Duration) -> Result<McpClient> {
+    let McpServerConfig {
+        command, args, env, ..
+    } = cfg;
+    let client = McpClient::new_stdio_client(
+        command.into(),
+        args.into_iter().map(OsString::from).collect(),
+        env,
+    )
+    .await?;
+
+    let params = mcp_types::InitializeRequestParams {
+        capabilities: ClientCapabilities {
+            experimental: None,
+            roots: None,
+            sampling: None,
+            // https://modelcontextprotocol.io/specification/2025-06-18/client/elicitation#capabilities
+            // indicates this should be an empty object.
+            elicitation: Some(json!({})),
+        },
+        client_info: Implementation {
+            name: "codex-mcp-client".to_owned(),
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            title: Some("Codex".into()),
+            // This field is used by Codex when it is an MCP
+            // server: it should not be used when Codex is
+            // an MCP client.
+            user_agent: None,
+        },
+        protocol_version: mcp_types::MCP_SCHEMA_VERSION.to_owned(),
+    };
+    let initialize_notification_params = None;
+    client
+        .initialize(
+            params,
+            initialize_notification_params,
+            Some(startup_timeout),
+        )
+        .await?;
+    Ok(client)
+}
+
 /// A thin wrapper around a set of running [`McpClient`] instances.
 #[derive(Default)]
 pub struct McpConnectionManager {
-    /// Server-name -> client instance.
+    /// Server-name -> connection.
     ///
     /// The server name originates from the keys of the `mcp_servers` map in
     /// the user configuration.
-    clients: HashMap<String, std::sync::Arc<McpClient>>,
+    clients: HashMap<String, Arc<ServerConnection>>,
 
     /// Fully qualified tool name -> tool instance.
     tools: HashMap<String, ToolInfo>,
@@ -126,6 +340,9 @@ impl McpConnectionManager {
         // the initial `tools/list` step below.
         let mut per_server_timeout: HashMap<String, Duration> = HashMap::new();
         let mut tool_timeouts: HashMap<String, Duration> = HashMap::new();
+        let mut configs: HashMap<String, McpServerConfig> = HashMap::new();
+        let mut lazy_servers: Vec<String> = Vec::new();
+        let mut lazy_tools: Vec<ToolInfo> = Vec::new();
 
         for (server_name, cfg) in mcp_servers {
             // Validate server name before spawning
@@ -149,64 +366,36 @@ impl McpConnectionManager {
                 .map(Duration::from_millis)
                 .unwrap_or(DEFAULT_TOOL_CALL_TIMEOUT);
             tool_timeouts.insert(server_name.clone(), tool_timeout);
+            configs.insert(server_name.clone(), cfg.clone());
 
-            join_set.spawn(async move {
-                let McpServerConfig {
-                    command, args, env, ..
-                } = cfg;
-                let client_res = McpClient::new_stdio_client(
-                    command.into(),
-                    args.into_iter().map(OsString::from).collect(),
-                    env,
-                )
-                .await;
-                match client_res {
-                    Ok(client) => {
-                        // Initialize the client.
-                        let params = mcp_types::InitializeRequestParams {
-                            capabilities: ClientCapabilities {
-                                experimental: None,
-                                roots: None,
-                                sampling: None,
-                                // https://modelcontextprotocol.io/specification/2025-06-18/client/elicitation#capabilities
-                                // indicates this should be an empty object.
-                                elicitation: Some(json!({})),
-                            },
-                            client_info: Implementation {
-                                name: "codex-mcp-client".to_owned(),
-                                version: env!("CARGO_PKG_VERSION").to_owned(),
-                                title: Some("Codex".into()),
-                                // This field is used by Codex when it is an MCP
-                                // server: it should not be used when Codex is
-                                // an MCP client.
-                                user_agent: None,
-                            },
-                            protocol_version: mcp_types::MCP_SCHEMA_VERSION.to_owned(),
-                        };
-                        let initialize_notification_params = None;
-                        let timeout = Some(startup_timeout);
-                        match client
-                            .initialize(params, initialize_notification_params, timeout)
-                            .await
-                        {
-                            Ok(_response) => (server_name, Ok(client)),
-                            Err(e) => (server_name, Err(e)),
-                        }
+            // Lazy servers with declared tools are spawned on first use.
+            if cfg.lazy && !cfg.tools.is_empty() {
+                for tool_name in &cfg.tools {
+                    if !excluded_tools.contains(&(server_name.clone(), tool_name.clone())) {
+                        lazy_tools.push(declared_tool(&server_name, tool_name));
                     }
-                    Err(e) => (server_name, Err(e.into())),
                 }
+                lazy_servers.push(server_name);
+                continue;
+            }
+            if cfg.lazy {
+                info!("MCP server '{server_name}' is lazy but declares no tools; starting it now");
+            }
+
+            join_set.spawn(async move {
+                let client_res = start_client(cfg, startup_timeout).await;
+                (server_name, client_res)
             });
         }
 
-        let mut clients: HashMap<String, std::sync::Arc<McpClient>> =
-            HashMap::with_capacity(join_set.len());
+        let mut clients: HashMap<String, Arc<McpClient>> = HashMap::with_capacity(join_set.len());
 
         while let Some(res) = join_set.join_next().await {
             let (server_name, client_res) = res?; // JoinError propagation
 
             match client_res {
                 Ok(client) => {
-                    clients.insert(server_name, std::sync::Arc::new(client));
+                    clients.insert(server_name, Arc::new(client));
                 }
                 Err(e) => {
                     errors.insert(server_name, e);
@@ -217,8 +406,9 @@ impl McpConnectionManager {
         // Query tools from each server. Do not fail the entire manager if a
         // server fails to list tools within its startup timeout; instead,
         // record the error and continue.
-        let (all_tools, list_errors) =
+        let (mut all_tools, list_errors) =
             list_all_tools(&clients, &per_server_timeout, &excluded_tools).await;
+        all_tools.extend(lazy_tools);
 
         // Remove clients that failed to list tools so they are not used later.
         for (server_name, err) in list_errors {
@@ -228,14 +418,35 @@ impl McpConnectionManager {
 
         let tools = qualify_tools(all_tools);
 
-        Ok((
-            Self {
-                clients,
-                tools,
-                tool_timeouts,
-            },
-            errors,
-        ))
+        let clients = clients
+            .into_iter()
+            .map(|(server_name, client)| (server_name, Some(client)))
+            .chain(
+                lazy_servers
+                    .into_iter()
+                    .map(|server_name| (server_name, None)),
+            )
+            .filter_map(|(server_name, client)| {
+                let config = configs.remove(&server_name)?;
+                let startup_timeout = per_server_timeout
+                    .get(&server_name)
+                    .copied()
+                    .unwrap_or(DEFAULT_STARTUP_TIMEOUT);
+                let conn = ServerConnection::new(client, config, startup_timeout);
+                Some((server_name, Arc::new(conn)))
+            })
+            .collect();
+
+        let manager = Self {
+            clients,
+            tools,
+            tool_timeouts,
+        };
+        for warning in manager.tool_collisions() {
+            warn!("{warning}");
+        }
+
+        Ok((manager, errors))
     }
 
     /// Returns a single map that contains **all** tools. Each key is the
@@ -247,7 +458,17 @@ impl McpConnectionManager {
             .collect()
     }
 
+    /// Current connection state of `server`, or `None` if it is not
+    /// configured or failed to start.
+    pub fn server_state(&self, server: &str) -> Option<McpServerState> {
+        self.clients.get(server).map(|conn| conn.state())
+    }
+
     /// Invoke the tool indicated by the (server, tool) pair.
+    ///
+    /// A lazy server is spawned by its first call. If the server's connection
+    /// has dropped, a reconnect is started in the background and calls fail
+    /// fast until it completes.
     pub async fn call_tool(
         &self,
         server: &str,
@@ -255,18 +476,51 @@ impl McpConnectionManager {
         arguments: Option<serde_json::Value>,
         timeout: Option<Duration>,
     ) -> Result<mcp_types::CallToolResult> {
-        let client = self
+        let conn = self
             .clients
             .get(server)
-            .ok_or_else(|| anyhow!("unknown MCP server '{server}'"))?
-            .clone();
+            .ok_or_else(|| anyhow!("unknown MCP server '{server}'"))?;
 
-        let timeout = timeout.or_else(|| self.tool_timeouts.get(server).copied());
+        let client = conn.ensure_started(server).await?;
+        if client.is_closed() {
+            conn.spawn_reconnect(server);
+        }
+        match conn.state() {
+            McpServerState::NotStarted | McpServerState::Connected => {}
+            McpServerState::Reconnecting { attempt } => {
+                return Err(anyhow!(
+                    "MCP server '{server}' disconnected; reconnecting (attempt {attempt})"
+                ));
+            }
+            McpServerState::Failed => {
+                return Err(anyhow!(
+                    "MCP server '{server}' disconnected and could not be restarted"
+                ));
+            }
+        }
 
-        client
-            .call_tool(tool.to_string(), arguments, timeout)
-            .await
-            .with_context(|| format!("tool call failed for `{server}/{tool}`"))
+        let timeout = timeout.unwrap_or_else(|| self.get_tool_timeout(server));
+
+        // Let the client enforce the timeout so it drops the pending request
+        // entry; a reply that arrives afterwards is discarded and the
+        // connection stays usable.
+        let result = client
+            .call_tool(tool.to_string(), arguments, Some(timeout))
+            .await;
+        if let Err(err) = &result
+            && let Some(McpRequestTimeout { timeout }) = err.downcast_ref::<McpRequestTimeout>()
+        {
+            return Err(McpToolCallTimeout {
+                server: server.to_string(),
+                tool: tool.to_string(),
+                timeout: *timeout,
+            }
+            .into());
+        }
+        if result.is_err() && client.is_closed() {
+            conn.spawn_reconnect(server);
+        }
+        result.with_context(|| format!("tool call failed for `{server}/{tool}`"))
     }
 
     pub fn parse_tool_name(&self, tool_name: &str) -> Option<(String, String)> {
@@ -275,6 +529,87 @@ impl McpConnectionManager {
             .map(|tool| (tool.server_name.clone(), tool.tool_name.clone()))
     }
 
+    /// Every tool with its originating server, sorted by namespaced name.
+    pub fn tool_catalog(&self) -> Vec<ToolEntry> {
+        let mut entries: Vec<ToolEntry> = self
+            .tools
+            .iter()
+            .map(|(namespaced_name, info)| ToolEntry {
+                server: info.server_name.clone(),
+                name: info.tool_name.clone(),
+                namespaced_name: namespaced_name.clone(),
+                schema: info.tool.input_schema.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.namespaced_name.cmp(&b.namespaced_name));
+        entries
+    }
+
+    /// One warning per tool name exposed by more than one server. Such tools
+    /// must be addressed by their namespaced name.
+    pub fn tool_collisions(&self) -> Vec<String> {
+        let mut servers_by_name: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for info in self.tools.values() {
+            servers_by_name
+                .entry(info.tool_name.as_str())
+                .or_default()
+                .push(info.server_name.as_str());
+        }
+        servers_by_name
+            .into_iter()
+            .filter(|(_, servers)| servers.len() > 1)
+            .map(|(name, mut servers)| {
+                servers.sort_unstable();
+                format!(
+                    "tool `{name}` is provided by multiple MCP servers ({}); use the namespaced name to pick one",
+                    servers.join(", ")
+                )
+            })
+            .collect()
+    }
+
+    /// Resolve a namespaced (`server__tool`) or bare tool name to its
+    /// (server, tool) pair. Bare names must be unique across servers.
+    pub fn resolve_tool(&self, tool_name: &str) -> Result<(String, String)> {
+        if let Some(resolved) = self.parse_tool_name(tool_name) {
+            return Ok(resolved);
+        }
+        let mut matches: Vec<&ToolInfo> = self
+            .tools
+            .values()
+            .filter(|info| info.tool_name == tool_name)
+            .collect();
+        match matches.len() {
+            0 => Err(anyhow!("unknown MCP tool '{tool_name}'")),
+            1 => {
+                let info = matches.remove(0);
+                Ok((info.server_name.clone(), info.tool_name.clone()))
+            }
+            _ => {
+                let mut servers: Vec<&str> = matches
+                    .iter()
+                    .map(|info| info.server_name.as_str())
+                    .collect();
+                servers.sort_unstable();
+                Err(anyhow!(
+                    "MCP tool '{tool_name}' is ambiguous; it is provided by {}",
+                    servers.join(", ")
+                ))
+            }
+        }
+    }
+
+    /// Invoke a tool by namespaced or unambiguous bare name.
+    pub async fn call_tool_by_name(
+        &self,
+        tool_name: &str,
+        arguments: Option<serde_json::Value>,
+        timeout: Option<Duration>,
+    ) -> Result<mcp_types::CallToolResult> {
+        let (server, tool) = self.resolve_tool(tool_name)?;
+        self.call_tool(&server, &tool, arguments, timeout).await
+    }
+
     pub fn get_tool_timeout(&self, server: &str) -> Duration {
         self.tool_timeouts
             .get(server)
@@ -286,7 +621,7 @@ impl McpConnectionManager {
 /// Query every server for its available tools and return a single map that
 /// contains **all** tools. Each key is the fully-qualified name for the tool.
 async fn list_all_tools(
-    clients: &HashMap<String, std::sync::Arc<McpClient>>,
+    clients: &HashMap<String, Arc<McpClient>>,
     timeouts: &HashMap<String, Duration>,
     excluded_tools: &HashSet<(String, String)>,
 ) -> (Vec<ToolInfo>, HashMap<String, anyhow::Error>) {
@@ -346,6 +681,27 @@ async fn list_all_tools(
     (aggregated, errors)
 }
 
+/// Tool advertised for a lazy server before it runs. The real schema is only
+/// known once the server is spawned, so accept any object.
+fn declared_tool(server_name: &str, tool_name: &str) -> ToolInfo {
+    ToolInfo {
+        server_name: server_name.to_string(),
+        tool_name: tool_name.to_string(),
+        tool: Tool {
+            annotations: None,
+            description: None,
+            input_schema: ToolInputSchema {
+                properties: None,
+                required: None,
+                r#type: "object".to_string(),
+            },
+            name: tool_name.to_string(),
+            output_schema: None,
+            title: None,
+        },
+    }
+}
+
 fn is_valid_mcp_server_name(server_name: &str) -> bool {
     !server_name.is_empty()
         && server_name
@@ -356,7 +712,6 @@ fn is_valid_mcp_server_name(server_name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mcp_types::ToolInputSchema;
 
     fn create_test_tool(server_name: &str, tool_name: &str) -> ToolInfo {
         ToolInfo {
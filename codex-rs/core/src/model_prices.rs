@@ -0,0 +1,177 @@
+//! Published model list prices, used to estimate spend from recorded usage.
+//!
+//! This is the one pricing table for every provider the CLI drives (OpenAI,
+//! Anthropic, Google). Slugs are matched exactly so that e.g. `o3-mini` is
+//! never billed as `o3`; the only normalization is dropping a dated snapshot
+//! suffix (`gpt-4o-2024-08-06`, `claude-sonnet-4-5-20250929`). Models not
+//! listed here have no known price.
+//!
+//! Sources: platform.openai.com/docs/pricing, claude.com/pricing,
+//! ai.google.dev/pricing. Prices drift, so treat results as estimates.
+
+/// List prices in USD per million tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPrices {
+    pub input_per_million: f64,
+    pub cached_input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+impl ModelPrices {
+    const fn new(input: f64, cached_input: f64, output: f64) -> Self {
+        Self {
+            input_per_million: input,
+            cached_input_per_million: cached_input,
+            output_per_million: output,
+        }
+    }
+
+    /// Prices for models without a published cache discount; cached input
+    /// is billed at the regular input rate.
+    const fn without_cache_discount(input: f64, output: f64) -> Self {
+        Self::new(input, input, output)
+    }
+
+    /// Estimated USD cost. `cached_input_tokens` is the cached portion of
+    /// `input_tokens` and is billed at the cached rate.
+    pub fn estimate_cost(
+        &self,
+        input_tokens: u64,
+        cached_input_tokens: u64,
+        output_tokens: u64,
+    ) -> f64 {
+        let cached = cached_input_tokens.min(input_tokens);
+        let uncached = input_tokens - cached;
+        (uncached as f64 * self.input_per_million
+            + cached as f64 * self.cached_input_per_million
+            + output_tokens as f64 * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// List prices for `model`, matched by exact slug (or a known alias), with
+/// or without a dated snapshot suffix
+pub fn model_prices(model: &str) -> Option<ModelPrices> {
+    listed_prices(model).or_else(|| listed_prices(strip_snapshot_date(model)?))
+}
+
+/// `gpt-4o-2024-08-06` -> `gpt-4o`, `claude-sonnet-4-5-20250929` -> `claude-sonnet-4-5`
+fn strip_snapshot_date(model: &str) -> Option<&str> {
+    let is_digits = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+
+    if let Some((base, date)) = model.rsplit_once('-')
+        && is_digits(date, 8)
+    {
+        return Some(base);
+    }
+    let mut parts = model.rsplitn(4, '-');
+    let (day, month, year, base) = (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+    (is_digits(year, 4) && is_digits(month, 2) && is_digits(day, 2)).then_some(base)
+}
+
+fn listed_prices(model: &str) -> Option<ModelPrices> {
+    let prices = match model {
+        // OpenAI
+        "gpt-5" | "gpt-5.1" | "gpt5_1" | "gpt5_1_minimal" | "gpt-5.1-minimal"
+        | "gpt-5.1-instant" => ModelPrices::new(1.25, 0.125, 10.00),
+        "gpt-5-codex" | "gpt5_1_codex" | "gpt-5.1-codex" => ModelPrices::new(1.25, 0.125, 10.00),
+        "gpt-5-mini" | "gpt5_1_mini" => ModelPrices::new(0.25, 0.025, 2.00),
+        "gpt-5-nano" => ModelPrices::new(0.05, 0.005, 0.40),
+        "gpt-4.1" => ModelPrices::new(2.00, 0.50, 8.00),
+        "gpt-4.1-mini" => ModelPrices::new(0.40, 0.10, 1.60),
+        "gpt-4.1-nano" => ModelPrices::new(0.10, 0.025, 0.40),
+        "gpt-4o" => ModelPrices::new(2.50, 1.25, 10.00),
+        "gpt-4o-mini" => ModelPrices::new(0.15, 0.075, 0.60),
+        "gpt-4-turbo" | "gpt-4" => ModelPrices::without_cache_discount(10.00, 30.00),
+        "gpt-3.5-turbo" => ModelPrices::without_cache_discount(0.50, 1.50),
+        "o3" => ModelPrices::new(2.00, 0.50, 8.00),
+        "o3-mini" => ModelPrices::new(1.10, 0.55, 4.40),
+        "o3-pro" => ModelPrices::without_cache_discount(20.00, 80.00),
+        "o4-mini" => ModelPrices::new(1.10, 0.275, 4.40),
+        "codex-mini-latest" => ModelPrices::new(1.50, 0.375, 6.00),
+
+        // Anthropic (cache reads at 10% of input)
+        "claude-haiku" | "claude-haiku-4.5" | "claude-haiku-4-5" | "claude-haiku-3.5"
+        | "claude-3-5-haiku" | "haiku" => ModelPrices::new(1.00, 0.10, 5.00),
+        "claude-sonnet" | "claude-sonnet-4.5" | "claude-sonnet-4-5" | "claude-sonnet-4"
+        | "sonnet" => ModelPrices::new(3.00, 0.30, 15.00),
+        "claude-opus" | "claude-opus-4.5" | "claude-opus-4.1" | "claude-opus-4-1"
+        | "claude-opus-4" | "opus" => ModelPrices::new(15.00, 1.50, 75.00),
+
+        // Google (standard pricing, prompts up to 200k tokens)
+        "gemini-3-pro" | "gemini-3.0-pro" => ModelPrices::without_cache_discount(2.00, 12.00),
+        "gemini-2.5-pro" | "gemini-pro-2.5" => ModelPrices::without_cache_discount(1.25, 10.00),
+        "gemini-1.5-pro" | "gemini-pro-1.5" | "gemini-pro" => {
+            ModelPrices::without_cache_discount(1.25, 10.00)
+        }
+        "gemini-2.5-flash" | "gemini-flash-2.5" | "flash-2.5" => {
+            ModelPrices::without_cache_discount(0.30, 2.50)
+        }
+        "gemini-2.0-flash" | "flash-2.0" => ModelPrices::without_cache_discount(0.30, 2.50),
+        "gemini-1.5-flash" | "flash-1.5" | "flash" => {
+            ModelPrices::without_cache_discount(0.075, 0.30)
+        }
+        "gemini-2.5-flash-lite" | "flash-lite" => ModelPrices::without_cache_discount(0.05, 0.20),
+
+        _ => return None,
+    };
+    Some(prices)
+}
+
+/// Estimated USD cost of token usage on `model`, or `None` when the model
+/// has no known price
+pub fn estimated_cost(
+    model: &str,
+    input_tokens: u64,
+    cached_input_tokens: u64,
+    output_tokens: u64,
+) -> Option<f64> {
+    model_prices(model)
+        .map(|prices| prices.estimate_cost(input_tokens, cached_input_tokens, output_tokens))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn o3_variants_are_priced_separately() {
+        assert_eq!(
+            model_prices("o3-mini").map(|p| p.input_per_million),
+            Some(1.10)
+        );
+        assert_eq!(
+            model_prices("o3-pro").map(|p| p.input_per_million),
+            Some(20.00)
+        );
+        assert_eq!(model_prices("o3").map(|p| p.input_per_million), Some(2.00));
+        assert_eq!(model_prices("o3-deep-research-preview"), None);
+    }
+
+    #[test]
+    fn dated_snapshots_use_their_base_model_price() {
+        assert_eq!(model_prices("gpt-4o-2024-08-06"), model_prices("gpt-4o"));
+        assert_eq!(model_prices("o3-mini-2025-01-31"), model_prices("o3-mini"));
+        assert_eq!(
+            model_prices("claude-sonnet-4-5-20250929"),
+            model_prices("claude-sonnet-4.5")
+        );
+        assert_eq!(model_prices("gpt-5-mini-2025"), None);
+    }
+
+    #[test]
+    fn covers_every_provider() {
+        assert!(model_prices("gpt-5").is_some());
+        assert!(model_prices("claude-sonnet-4.5").is_some());
+        assert!(model_prices("gemini-2.5-flash").is_some());
+        assert_eq!(model_prices("unknown-model"), None);
+    }
+
+    #[test]
+    fn cached_input_is_billed_at_the_cached_rate() {
+        // 1M input of which 400k cached, 100k output on gpt-5:
+        // 600k * 1.25 + 400k * 0.125 + 100k * 10.00 = 0.75 + 0.05 + 1.00
+        let cost = estimated_cost("gpt-5", 1_000_000, 400_000, 100_000).expect("priced");
+        assert!((cost - 1.80).abs() < 1e-9, "unexpected cost {cost}");
+    }
+}
@@ -1,5 +1,7 @@
 use crate::bash::try_parse_bash;
 use crate::bash::try_parse_word_only_commands_sequence;
+use crate::command_safety::command_risk::CommandRisk;
+use crate::command_safety::command_risk::assess_command_risk;
 use serde::Deserialize;
 use serde::Serialize;
 use shlex::split as shlex_split;
@@ -88,6 +90,22 @@ pub fn parse_command(command: &[String]) -> Vec<ParsedCommand> {
     deduped
 }
 
+/// Parsed summaries of a command together with its overall risk level.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ParsedCommandLine {
+    pub commands: Vec<ParsedCommand>,
+    pub risk: CommandRisk,
+}
+
+/// Like [`parse_command`], but also classifies the command with
+/// [`assess_command_risk`] so consumers get both in one call.
+pub fn parse_command_with_risk(command: &[String]) -> ParsedCommandLine {
+    ParsedCommandLine {
+        commands: parse_command(command),
+        risk: assess_command_risk(command),
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::items_after_test_module)]
 /// Tests are at the top to encourage using TDD + Codex to fix the implementation.
@@ -111,6 +129,16 @@ mod tests {
         assert_eq!(out, expected);
     }
 
+    #[test]
+    fn parse_with_risk_flags_rm_rf_as_dangerous() {
+        let parsed = parse_command_with_risk(&vec_str(&["rm", "-rf", "/"]));
+        assert_eq!(parsed.risk, CommandRisk::Dangerous);
+        assert_eq!(
+            parsed.commands,
+            parse_command(&vec_str(&["rm", "-rf", "/"]))
+        );
+    }
+
     #[test]
     fn git_status_is_read_command() {
         assert_parsed(
@@ -1,4 +1,6 @@
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use crate::codex::{Session, ToolCallCtx};
@@ -23,7 +25,7 @@ pub(crate) static PLAN_TOOL: LazyLock<OpenAiTool> = LazyLock::new(|| {
     plan_item_props.insert(
         "status".to_string(),
         JsonSchema::String {
-            description: Some("One of: pending, in_progress, completed".to_string()),
+            description: Some("One of: pending, in_progress, completed, failed".to_string()),
         },
     );
 
@@ -52,6 +54,7 @@ pub(crate) static PLAN_TOOL: LazyLock<OpenAiTool> = LazyLock::new(|| {
         description: r#"Updates the task plan.
 Provide an optional name and a list of plan items, each with a step and status.
 At most one step can be in_progress at a time.
+Steps move from pending to in_progress, then to completed or failed.
 "#
         .to_string(),
         strict: false,
@@ -74,6 +77,18 @@ pub(crate) async fn handle_update_plan(
     match parse_update_plan_arguments(arguments, &ctx.call_id) {
         Ok(mut args) => {
             args.name = normalize_plan_name(args.name.take());
+            if let Err(err) =
+                validate_plan_transitions(&session.last_plan(), &args.plan, PLAN_VALIDATION_MODE)
+            {
+                return ResponseInputItem::FunctionCallOutput {
+                    call_id: ctx.call_id.clone(),
+                    output: FunctionCallOutputPayload {
+                        content: err.to_string(),
+                        success: Some(false),
+                    },
+                };
+            }
+            session.set_last_plan(args.plan.clone());
             let output = ResponseInputItem::FunctionCallOutput {
                 call_id: ctx.call_id.clone(),
                 output: FunctionCallOutputPayload {
@@ -90,6 +105,70 @@ pub(crate) async fn handle_update_plan(
     }
 }
 
+/// How `update_plan` treats illegal status transitions. A rejected update is
+/// returned to the model as a failed call naming the offending step, so it
+/// can resend the plan with the intermediate status.
+const PLAN_VALIDATION_MODE: PlanValidationMode = PlanValidationMode::Strict;
+
+/// Status transitions a step may make between two plan updates. Keeping the
+/// same status is always allowed.
+const ALLOWED_TRANSITIONS: &[(StepStatus, StepStatus)] = &[
+    (StepStatus::Pending, StepStatus::InProgress),
+    (StepStatus::InProgress, StepStatus::Completed),
+    (StepStatus::InProgress, StepStatus::Failed),
+    (StepStatus::Failed, StepStatus::InProgress),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanValidationMode {
+    /// Reject the update on the first illegal transition.
+    Strict,
+    /// Log illegal transitions and accept the update.
+    Lenient,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PlanValidationError {
+    #[error("plan step `{step}` cannot move from {from:?} to {to:?}")]
+    IllegalTransition {
+        step: String,
+        from: StepStatus,
+        to: StepStatus,
+    },
+}
+
+pub fn is_allowed_transition(from: &StepStatus, to: &StepStatus) -> bool {
+    from == to
+        || ALLOWED_TRANSITIONS
+            .iter()
+            .any(|(allowed_from, allowed_to)| allowed_from == from && allowed_to == to)
+}
+
+/// Checks every status change between `old` and `new` against
+/// [`ALLOWED_TRANSITIONS`]. Steps that are new in `new` may start in any
+/// status.
+pub fn validate_plan_transitions(
+    old: &[PlanItemArg],
+    new: &[PlanItemArg],
+    mode: PlanValidationMode,
+) -> Result<(), PlanValidationError> {
+    for change in diff_plans(old, new).status_changed {
+        if is_allowed_transition(&change.from, &change.to) {
+            continue;
+        }
+        let err = PlanValidationError::IllegalTransition {
+            step: change.step,
+            from: change.from,
+            to: change.to,
+        };
+        match mode {
+            PlanValidationMode::Strict => return Err(err),
+            PlanValidationMode::Lenient => tracing::warn!("{err}"),
+        }
+    }
+    Ok(())
+}
+
 fn parse_update_plan_arguments(
     arguments: String,
     call_id: &str,
@@ -215,9 +294,282 @@ fn format_plan_word(word: &str) -> String {
     formatted
 }
 
+/// What changed between two successive plans, so clients can animate only
+/// the affected steps. Steps are matched by their text; repeated texts are
+/// matched in order of appearance.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlanDiff {
+    /// Steps only present in the new plan, with their index in it.
+    pub added: Vec<(usize, String)>,
+    /// Steps only present in the old plan, with their index in it.
+    pub removed: Vec<(usize, String)>,
+    /// Steps present in both plans whose position relative to the other
+    /// retained steps changed, in new-plan order.
+    pub reordered: Vec<String>,
+    /// Steps present in both plans whose status changed, in new-plan order.
+    pub status_changed: Vec<StepStatusChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepStatusChange {
+    pub step: String,
+    pub from: StepStatus,
+    pub to: StepStatus,
+}
+
+impl PlanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.reordered.is_empty()
+            && self.status_changed.is_empty()
+    }
+}
+
+pub fn diff_plans(old: &[PlanItemArg], new: &[PlanItemArg]) -> PlanDiff {
+    let old_keys = step_keys(old);
+    let new_keys = step_keys(new);
+    let old_index: HashMap<(&str, usize), usize> = old_keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (*key, index))
+        .collect();
+    let new_index: HashMap<(&str, usize), usize> = new_keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (*key, index))
+        .collect();
+
+    let mut diff = PlanDiff::default();
+    for (index, key) in old_keys.iter().enumerate() {
+        if !new_index.contains_key(key) {
+            diff.removed.push((index, key.0.to_string()));
+        }
+    }
+
+    // Steps kept in both plans, listed in each plan's order. Those outside the
+    // longest common subsequence are the ones that moved.
+    let kept_in_old: Vec<(&str, usize)> = old_keys
+        .iter()
+        .filter(|key| new_index.contains_key(*key))
+        .copied()
+        .collect();
+    let kept_in_new: Vec<(&str, usize)> = new_keys
+        .iter()
+        .filter(|key| old_index.contains_key(*key))
+        .copied()
+        .collect();
+    let stable = longest_common_subsequence(&kept_in_old, &kept_in_new);
+
+    for (index, key) in new_keys.iter().enumerate() {
+        let Some(&old_position) = old_index.get(key) else {
+            diff.added.push((index, key.0.to_string()));
+            continue;
+        };
+        if !stable.contains(key) {
+            diff.reordered.push(key.0.to_string());
+        }
+        let (from, to) = (&old[old_position].status, &new[index].status);
+        if from != to {
+            diff.status_changed.push(StepStatusChange {
+                step: key.0.to_string(),
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+    }
+
+    diff
+}
+
+/// Keys each step by its text and how many earlier steps share that text.
+fn step_keys(plan: &[PlanItemArg]) -> Vec<(&str, usize)> {
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    plan.iter()
+        .map(|item| {
+            let occurrence = seen.entry(item.step.as_str()).or_default();
+            let key = (item.step.as_str(), *occurrence);
+            *occurrence += 1;
+            key
+        })
+        .collect()
+}
+
+fn longest_common_subsequence<'a>(
+    a: &[(&'a str, usize)],
+    b: &[(&'a str, usize)],
+) -> HashSet<(&'a str, usize)> {
+    let mut lengths = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if a[i] == b[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut common = HashSet::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            common.insert(a[i]);
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] > lengths[i][j + 1] {
+            // On ties, skip the new-plan step so the step that moved in the
+            // new plan is reported rather than the one it jumped over.
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    common
+}
+
 #[cfg(test)]
 mod tests {
+    use super::PlanDiff;
+    use super::PlanItemArg;
+    use super::PlanValidationError;
+    use super::PlanValidationMode;
+    use super::StepStatus;
+    use super::StepStatusChange;
+    use super::diff_plans;
     use super::normalize_plan_name;
+    use super::validate_plan_transitions;
+
+    fn plan(items: &[(&str, StepStatus)]) -> Vec<PlanItemArg> {
+        items
+            .iter()
+            .map(|(step, status)| PlanItemArg {
+                step: step.to_string(),
+                status: status.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diff_reports_status_flip() {
+        let old = plan(&[
+            ("Write tests", StepStatus::Pending),
+            ("Ship", StepStatus::Pending),
+        ]);
+        let new = plan(&[
+            ("Write tests", StepStatus::Completed),
+            ("Ship", StepStatus::Pending),
+        ]);
+
+        assert_eq!(
+            diff_plans(&old, &new),
+            PlanDiff {
+                status_changed: vec![StepStatusChange {
+                    step: "Write tests".to_string(),
+                    from: StepStatus::Pending,
+                    to: StepStatus::Completed,
+                }],
+                ..Default::default()
+            }
+        );
+        assert!(diff_plans(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn diff_reports_inserted_step_without_reordering_others() {
+        let old = plan(&[
+            ("Investigate", StepStatus::Completed),
+            ("Ship", StepStatus::Pending),
+        ]);
+        let new = plan(&[
+            ("Investigate", StepStatus::Completed),
+            ("Fix bug", StepStatus::InProgress),
+            ("Ship", StepStatus::Pending),
+        ]);
+
+        assert_eq!(
+            diff_plans(&old, &new),
+            PlanDiff {
+                added: vec![(1, "Fix bug".to_string())],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn diff_reports_reordered_and_removed_steps() {
+        let old = plan(&[
+            ("A", StepStatus::Pending),
+            ("B", StepStatus::Pending),
+            ("C", StepStatus::Pending),
+            ("D", StepStatus::Pending),
+        ]);
+        let new = plan(&[
+            ("A", StepStatus::Pending),
+            ("C", StepStatus::Pending),
+            ("B", StepStatus::Pending),
+        ]);
+
+        assert_eq!(
+            diff_plans(&old, &new),
+            PlanDiff {
+                removed: vec![(3, "D".to_string())],
+                reordered: vec!["C".to_string()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn legal_transitions_are_accepted() {
+        let legal = [
+            (StepStatus::Pending, StepStatus::InProgress),
+            (StepStatus::InProgress, StepStatus::Completed),
+            (StepStatus::InProgress, StepStatus::Failed),
+            (StepStatus::Failed, StepStatus::InProgress),
+            (StepStatus::Completed, StepStatus::Completed),
+        ];
+        for (from, to) in legal {
+            let old = plan(&[("Step", from.clone())]);
+            let new = plan(&[("Step", to.clone())]);
+            assert_eq!(
+                validate_plan_transitions(&old, &new, PlanValidationMode::Strict),
+                Ok(()),
+                "{from:?} -> {to:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn illegal_transitions_are_rejected_in_strict_mode_only() {
+        let old = plan(&[
+            ("Build", StepStatus::Completed),
+            ("Deploy", StepStatus::Pending),
+        ]);
+        let new = plan(&[
+            ("Build", StepStatus::Pending),
+            ("Deploy", StepStatus::Pending),
+        ]);
+
+        assert_eq!(
+            validate_plan_transitions(&old, &new, PlanValidationMode::Strict),
+            Err(PlanValidationError::IllegalTransition {
+                step: "Build".to_string(),
+                from: StepStatus::Completed,
+                to: StepStatus::Pending,
+            })
+        );
+        assert_eq!(
+            validate_plan_transitions(&old, &new, PlanValidationMode::Lenient),
+            Ok(())
+        );
+
+        let skipped = plan(&[
+            ("Build", StepStatus::Completed),
+            ("Deploy", StepStatus::Completed),
+        ]);
+        assert!(validate_plan_transitions(&old, &skipped, PlanValidationMode::Strict).is_err());
+    }
 
     #[test]
     fn drops_empty_names() {
@@ -1,24 +1,37 @@
 //! Project-level documentation discovery.
 //!
-//! Project-level documentation can be stored in files named `AGENTS.md`.
-//! We include the concatenation of all files found along the path from the
-//! repository root to the current working directory as follows:
+//! Project-level documentation can be stored in files named `AGENTS.md` or
+//! `CODEX.md`. We include the concatenation of all files found along the path
+//! from the repository root to the current working directory as follows:
 //!
 //! 1.  Determine the Git repository root by walking upwards from the current
 //!     working directory until a `.git` directory or file is found. If no Git
 //!     root is found, only the current working directory is considered.
-//! 2.  Collect every `AGENTS.md` found from the repository root down to the
-//!     current working directory (inclusive) and concatenate their contents in
-//!     that order.
-//! 3.  We do **not** walk past the Git root.
+//! 2.  Collect every `AGENTS.md` and `CODEX.md` found from the repository root
+//!     down to the current working directory (inclusive) and concatenate their
+//!     contents in that order. When more than one doc is found, each is
+//!     preceded by a header naming its path relative to the root.
+//! 3.  Directories and docs matched by a `.gitignore` along the way (e.g.
+//!     vendored trees) are skipped, along with everything beneath them.
+//! 4.  We do **not** walk past the Git root.
+//!
+//! The combined text is capped at `Config::project_doc_max_bytes`. When the
+//! docs exceed the cap we keep whole leading sections, cutting at the last
+//! Markdown heading that fits, and append a truncation notice.
 
 use crate::config::Config;
+use codex_utils_string::take_bytes_at_char_boundary;
+use ignore::Match;
+use ignore::gitignore::Gitignore;
+use std::collections::HashSet;
+use std::path::Path;
 use std::path::PathBuf;
 use tokio::io::AsyncReadExt;
 use tracing::error;
 
-/// Currently, we only match the filename `AGENTS.md` exactly.
-const CANDIDATE_FILENAMES: &[&str] = &["AGENTS.md"];
+/// Doc filenames, matched exactly. A directory containing both contributes
+/// both, in this order.
+const CANDIDATE_FILENAMES: &[&str] = &["AGENTS.md", "CODEX.md"];
 
 /// When both `Config::instructions` and the project doc are present, they will
 /// be concatenated with the following separator.
@@ -55,16 +68,18 @@ pub async fn read_project_docs(config: &Config) -> std::io::Result<Option<String
         return Ok(None);
     }
 
-    let paths = discover_project_doc_paths(config)?;
+    let (base, paths) = discover_project_docs(config)?;
     if paths.is_empty() {
         return Ok(None);
     }
 
     let mut remaining: u64 = max_total as u64;
-    let mut parts: Vec<String> = Vec::new();
+    let mut parts: Vec<(PathBuf, String)> = Vec::new();
+    let mut truncated = false;
 
     for p in paths {
         if remaining == 0 {
+            truncated = true;
             break;
         }
 
@@ -80,6 +95,7 @@ pub async fn read_project_docs(config: &Config) -> std::io::Result<Option<String
         reader.read_to_end(&mut data).await?;
 
         if size > remaining {
+            truncated = true;
             tracing::warn!(
                 "Project doc `{}` exceeds remaining budget ({} bytes) - truncating.",
                 p.display(),
@@ -89,24 +105,95 @@ pub async fn read_project_docs(config: &Config) -> std::io::Result<Option<String
 
         let text = String::from_utf8_lossy(&data).to_string();
         if !text.trim().is_empty() {
-            parts.push(text);
+            parts.push((p, text));
             remaining = remaining.saturating_sub(data.len() as u64);
         }
     }
 
-    if parts.is_empty() {
-        Ok(None)
+    let joined = match parts.len() {
+        0 => return Ok(None),
+        1 => parts.remove(0).1,
+        _ => parts
+            .iter()
+            .map(|(path, text)| format!("{}\n\n{text}", source_header(&base, path)))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    };
+    if truncated || joined.len() > max_total {
+        Ok(Some(truncate_at_heading_boundary(&joined, max_total)))
     } else {
-        Ok(Some(parts.join("\n\n")))
+        Ok(Some(joined))
+    }
+}
+
+/// Header naming the file a doc came from, relative to the search root.
+fn source_header(base: &Path, path: &Path) -> String {
+    let display = path.strip_prefix(base).unwrap_or(path);
+    format!("--- project-doc: {} ---", display.display())
+}
+
+/// Marker appended to project docs that were cut to fit `max_bytes`.
+fn truncation_notice(max_bytes: usize) -> String {
+    format!("\n\n[project doc truncated: exceeded {max_bytes} byte limit]")
+}
+
+/// Shorten `text` to at most `max_bytes`, including the truncation notice.
+///
+/// The cut is placed at the start of the last Markdown heading (outside code
+/// fences) that fits, so only whole sections are kept. Text without a usable
+/// heading falls back to the last line break, then to a UTF-8 char boundary.
+fn truncate_at_heading_boundary(text: &str, max_bytes: usize) -> String {
+    let notice = truncation_notice(max_bytes);
+    if notice.len() >= max_bytes {
+        return take_bytes_at_char_boundary(text, max_bytes).to_string();
+    }
+
+    let prefix = take_bytes_at_char_boundary(text, max_bytes - notice.len());
+    let cut = last_heading_start(prefix)
+        .or_else(|| prefix.rfind('\n'))
+        .unwrap_or(prefix.len());
+
+    format!("{}{notice}", prefix[..cut].trim_end())
+}
+
+/// Byte offset of the last heading line in `text`, ignoring the first line
+/// (cutting there would drop everything) and headings inside ``` fences.
+fn last_heading_start(text: &str) -> Option<usize> {
+    let mut in_fence = false;
+    let mut last = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence && offset > 0 && is_atx_heading(trimmed) && line.ends_with('\n') {
+            last = Some(offset);
+        }
+        offset += line.len();
     }
+    last
 }
 
-/// Discover the list of AGENTS.md files using the same search rules as
+/// Whether `line` is a Markdown ATX heading: 1-6 `#` followed by a space or
+/// the end of the line (so `#!/bin/sh` and `#tag` are not headings).
+fn is_atx_heading(line: &str) -> bool {
+    let hashes = line.bytes().take_while(|&b| b == b'#').count();
+    let rest = line[hashes..].trim_end_matches(['\r', '\n']);
+    (1..=6).contains(&hashes) && (rest.is_empty() || rest.starts_with([' ', '\t']))
+}
+
+/// Discover the list of project doc files using the same search rules as
 /// `read_project_docs`, but return the file paths instead of concatenated
 /// contents. The list is ordered from repository root to the current working
 /// directory (inclusive). Symlinks are allowed. When `project_doc_max_bytes`
 /// is zero, returns an empty list.
 pub fn discover_project_doc_paths(config: &Config) -> std::io::Result<Vec<PathBuf>> {
+    discover_project_docs(config).map(|(_, paths)| paths)
+}
+
+/// Returns the search root (Git root, or cwd outside a repo) together with
+/// the discovered doc paths.
+fn discover_project_docs(config: &Config) -> std::io::Result<(PathBuf, Vec<PathBuf>)> {
     let mut dir = config.cwd.clone();
     if let Ok(canon) = dir.canonicalize() {
         dir = canon;
@@ -133,6 +220,8 @@ pub fn discover_project_doc_paths(config: &Config) -> std::io::Result<Vec<PathBu
         cursor = parent.to_path_buf();
     }
 
+    // Use the canonical cwd so doc paths (built from it) strip cleanly.
+    let base = git_root.clone().unwrap_or_else(|| chain[0].clone());
     let search_dirs: Vec<PathBuf> = if let Some(root) = git_root {
         let mut dirs: Vec<PathBuf> = Vec::new();
         let mut saw_root = false;
@@ -148,20 +237,44 @@ pub fn discover_project_doc_paths(config: &Config) -> std::io::Result<Vec<PathBu
         }
         dirs
     } else {
-        vec![config.cwd.clone()]
+        vec![chain[0].clone()]
     };
 
     let mut found: Vec<PathBuf> = Vec::new();
+    // Resolved targets of `found`, so e.g. a CODEX.md symlinked to
+    // AGENTS.md is only included once.
+    let mut resolved: HashSet<PathBuf> = HashSet::new();
+    let mut ignores: Vec<Gitignore> = Vec::new();
     for d in search_dirs {
+        // Anything below an ignored directory (e.g. `vendor/`) is ignored too.
+        if is_gitignored(&ignores, &d, true) {
+            break;
+        }
+
+        let gitignore_path = d.join(".gitignore");
+        if gitignore_path.is_file() {
+            let (matcher, err) = Gitignore::new(&gitignore_path);
+            if let Some(err) = err {
+                tracing::warn!("Failed to parse `{}`: {err}", gitignore_path.display());
+            }
+            ignores.push(matcher);
+        }
+
         for name in CANDIDATE_FILENAMES {
             let candidate = d.join(name);
             match std::fs::symlink_metadata(&candidate) {
                 Ok(md) => {
                     let ft = md.file_type();
                     // Allow regular files and symlinks; opening will later fail for dangling links.
-                    if ft.is_file() || ft.is_symlink() {
-                        found.push(candidate);
-                        break;
+                    if (ft.is_file() || ft.is_symlink())
+                        && !is_gitignored(&ignores, &candidate, false)
+                    {
+                        let target = candidate
+                            .canonicalize()
+                            .unwrap_or_else(|_| candidate.clone());
+                        if resolved.insert(target) {
+                            found.push(candidate);
+                        }
                     }
                 }
                 Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
@@ -170,7 +283,20 @@ pub fn discover_project_doc_paths(config: &Config) -> std::io::Result<Vec<PathBu
         }
     }
 
-    Ok(found)
+    Ok((base, found))
+}
+
+/// Whether `path` is ignored by the `.gitignore` files collected so far.
+/// Deeper files take precedence, so a nested `!pattern` can re-include.
+fn is_gitignored(ignores: &[Gitignore], path: &Path, is_dir: bool) -> bool {
+    for matcher in ignores.iter().rev() {
+        match matcher.matched_path_or_any_parents(path, is_dir) {
+            Match::Ignore(_) => return true,
+            Match::Whitelist(_) => return false,
+            Match::None => {}
+        }
+    }
+    false
 }
 
 #[cfg(test)]
@@ -231,7 +357,7 @@ mod tests {
         );
     }
 
-    /// Oversize file is truncated to `project_doc_max_bytes`.
+    /// Oversize file is truncated to `project_doc_max_bytes`, notice included.
     #[tokio::test]
     async fn doc_larger_than_limit_is_truncated() {
         const LIMIT: usize = 1024;
@@ -244,8 +370,48 @@ mod tests {
             .await
             .expect("doc expected");
 
-        assert_eq!(res.len(), LIMIT, "doc should be truncated to LIMIT bytes");
-        assert_eq!(res, huge[..LIMIT]);
+        let notice = truncation_notice(LIMIT);
+        assert!(res.len() <= LIMIT, "doc should fit within LIMIT bytes");
+        assert!(res.ends_with(&notice));
+        assert_eq!(res, format!("{}{notice}", &huge[..LIMIT - notice.len()]));
+    }
+
+    /// Oversize Markdown is cut at a heading so only whole sections remain.
+    #[tokio::test]
+    async fn oversized_doc_truncates_at_heading_boundary() {
+        const LIMIT: usize = 512;
+        let tmp = tempfile::tempdir().expect("tempdir");
+
+        let section = |n: usize| format!("## Section {n}\n\n{}\n\n", "body text ".repeat(10));
+        let doc: String = (1..=10).map(section).collect();
+        fs::write(tmp.path().join("AGENTS.md"), &doc).unwrap();
+
+        let res = get_user_instructions(&make_config(&tmp, LIMIT, None))
+            .await
+            .expect("doc expected");
+
+        let notice = truncation_notice(LIMIT);
+        assert!(res.len() <= LIMIT, "{} > {LIMIT}", res.len());
+        let body = res.strip_suffix(&notice).expect("truncation notice");
+        assert!(body.starts_with("## Section 1\n"));
+        // The kept text is a whole number of sections, minus trailing blank lines.
+        let kept_sections = body.matches("## Section").count();
+        assert!(kept_sections >= 1);
+        let expected: String = (1..=kept_sections).map(section).collect();
+        assert_eq!(body, expected.trim_end());
+        assert!(
+            expected.len() < doc.len(),
+            "some sections should be dropped"
+        );
+    }
+
+    #[test]
+    fn heading_inside_code_fence_is_not_a_boundary() {
+        let text = "# Intro\nintro\n```\n# not a heading\n```\nmore";
+        assert_eq!(last_heading_start(text), None);
+
+        let text = "# Intro\nintro\n## Next\nbody";
+        assert_eq!(last_heading_start(text), Some("# Intro\nintro\n".len()));
     }
 
     /// When `cwd` is nested inside a repo, the search should locate AGENTS.md
@@ -345,6 +511,116 @@ mod tests {
         cfg.cwd = nested;
 
         let res = get_user_instructions(&cfg).await.expect("doc expected");
-        assert_eq!(res, "root doc\n\ncrate doc");
+        assert_eq!(
+            res,
+            "--- project-doc: AGENTS.md ---\n\nroot doc\n\n\
+             --- project-doc: workspace/crate_a/AGENTS.md ---\n\ncrate doc"
+        );
+    }
+
+    /// AGENTS.md and CODEX.md at every level are included root-first, each
+    /// under a header naming its source file.
+    #[tokio::test]
+    async fn includes_all_nested_docs_in_order_with_headers() {
+        let repo = tempfile::tempdir().expect("tempdir");
+        std::fs::create_dir(repo.path().join(".git")).unwrap();
+
+        fs::write(repo.path().join("CODEX.md"), "root codex").unwrap();
+        let pkg = repo.path().join("packages/pkg");
+        let app = pkg.join("app");
+        std::fs::create_dir_all(&app).unwrap();
+        fs::write(pkg.join("AGENTS.md"), "pkg agents").unwrap();
+        fs::write(app.join("AGENTS.md"), "app agents").unwrap();
+        fs::write(app.join("CODEX.md"), "app codex").unwrap();
+
+        let mut cfg = make_config(&repo, 4096, None);
+        cfg.cwd = app;
+
+        let res = get_user_instructions(&cfg).await.expect("doc expected");
+        let expected = [
+            ("CODEX.md", "root codex"),
+            ("packages/pkg/AGENTS.md", "pkg agents"),
+            ("packages/pkg/app/AGENTS.md", "app agents"),
+            ("packages/pkg/app/CODEX.md", "app codex"),
+        ]
+        .iter()
+        .map(|(path, text)| format!("--- project-doc: {path} ---\n\n{text}"))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn only_atx_headings_are_cut_points() {
+        assert!(is_atx_heading("# Title\n"));
+        assert!(is_atx_heading("###### Deep\n"));
+        assert!(is_atx_heading("##\n"));
+        assert!(!is_atx_heading("#!/bin/sh\n"));
+        assert!(!is_atx_heading("#tag\n"));
+        assert!(!is_atx_heading("####### Seven\n"));
+
+        let text = "# Intro\nbody\n#!/bin/sh\n#tag\nmore\n";
+        assert_eq!(last_heading_start(text), None);
+        let text = "# Intro\nbody\n## Next\n#tag\n";
+        assert_eq!(last_heading_start(text), Some("# Intro\nbody\n".len()));
+    }
+
+    /// A CODEX.md symlinked to AGENTS.md is included once.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn symlinked_doc_is_included_once() {
+        let repo = tempfile::tempdir().expect("tempdir");
+        std::fs::create_dir(repo.path().join(".git")).unwrap();
+        fs::write(repo.path().join("AGENTS.md"), "root doc").unwrap();
+        std::os::unix::fs::symlink("AGENTS.md", repo.path().join("CODEX.md")).unwrap();
+
+        let cfg = make_config(&repo, 4096, None);
+
+        let res = get_user_instructions(&cfg).await.expect("doc expected");
+        assert_eq!(res, "root doc");
+    }
+
+    /// Outside a git repo, paths are relative to the canonical cwd even when
+    /// it was reached through a symlink.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn doc_headers_are_relative_outside_git_through_symlinked_cwd() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let real = tmp.path().join("real");
+        std::fs::create_dir(&real).unwrap();
+        fs::write(real.join("AGENTS.md"), "agents").unwrap();
+        fs::write(real.join("CODEX.md"), "codex").unwrap();
+        let link = tmp.path().join("link");
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+
+        let mut cfg = make_config(&tmp, 4096, None);
+        cfg.cwd = link;
+
+        let res = get_user_instructions(&cfg).await.expect("doc expected");
+        assert_eq!(
+            res,
+            "--- project-doc: AGENTS.md ---\n\nagents\n\n\
+             --- project-doc: CODEX.md ---\n\ncodex"
+        );
+    }
+
+    /// Docs inside gitignored directories (e.g. vendored code) are skipped.
+    #[tokio::test]
+    async fn skips_docs_in_gitignored_dirs() {
+        let repo = tempfile::tempdir().expect("tempdir");
+        std::fs::create_dir(repo.path().join(".git")).unwrap();
+        fs::write(repo.path().join(".gitignore"), "vendor/\nCODEX.md\n").unwrap();
+        fs::write(repo.path().join("AGENTS.md"), "root doc").unwrap();
+        fs::write(repo.path().join("CODEX.md"), "ignored codex").unwrap();
+
+        let vendored = repo.path().join("vendor/lib");
+        std::fs::create_dir_all(&vendored).unwrap();
+        fs::write(vendored.join("AGENTS.md"), "vendored doc").unwrap();
+
+        let mut cfg = make_config(&repo, 4096, None);
+        cfg.cwd = vendored;
+
+        let res = get_user_instructions(&cfg).await.expect("doc expected");
+        assert_eq!(res, "root doc");
     }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::config::Config;
 use crate::config_types::{ProjectCommandConfig, ProjectHookConfig, ProjectHookEvent};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -126,3 +127,226 @@ fn resolve_optional_path(raw: &Option<String>, project_root: &Path) -> Option<Pa
         Some(project_root.join(path))
     }
 }
+
+/// Where the effective value of a feature flag came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureSource {
+    /// Set in `config.toml` or a CLI override.
+    Config,
+    /// Forced by a `CODE_FEATURE_*` environment variable.
+    Env,
+    /// Neither configured nor overridden.
+    Default,
+}
+
+impl std::fmt::Display for FeatureSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FeatureSource::Config => "config",
+            FeatureSource::Env => "env",
+            FeatureSource::Default => "default",
+        })
+    }
+}
+
+/// Effective state of one optional feature, as reported by [`active_features`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureState {
+    pub name: &'static str,
+    pub enabled: bool,
+    pub source: FeatureSource,
+}
+
+/// A toggleable feature backed by a boolean on [`Config`].
+struct FeatureFlag {
+    name: &'static str,
+    env_var: &'static str,
+    default: bool,
+    get: fn(&Config) -> bool,
+    set: fn(&mut Config, bool),
+}
+
+const FEATURE_FLAGS: &[FeatureFlag] = &[
+    FeatureFlag {
+        name: "plan_tool",
+        env_var: "CODE_FEATURE_PLAN_TOOL",
+        default: false,
+        get: |c| c.include_plan_tool,
+        set: |c, v| c.include_plan_tool = v,
+    },
+    FeatureFlag {
+        name: "apply_patch_tool",
+        env_var: "CODE_FEATURE_APPLY_PATCH_TOOL",
+        default: false,
+        get: |c| c.include_apply_patch_tool,
+        set: |c, v| c.include_apply_patch_tool = v,
+    },
+    FeatureFlag {
+        name: "view_image_tool",
+        env_var: "CODE_FEATURE_VIEW_IMAGE_TOOL",
+        default: true,
+        get: |c| c.include_view_image_tool,
+        set: |c, v| c.include_view_image_tool = v,
+    },
+    FeatureFlag {
+        name: "web_search",
+        env_var: "CODE_FEATURE_WEB_SEARCH",
+        default: false,
+        get: |c| c.tools_web_search_request,
+        set: |c, v| c.tools_web_search_request = v,
+    },
+    FeatureFlag {
+        name: "streamable_shell_tool",
+        env_var: "CODE_FEATURE_STREAMABLE_SHELL_TOOL",
+        default: false,
+        get: |c| c.use_experimental_streamable_shell_tool,
+        set: |c, v| c.use_experimental_streamable_shell_tool = v,
+    },
+    FeatureFlag {
+        name: "auto_upgrade",
+        env_var: "CODE_FEATURE_AUTO_UPGRADE",
+        default: false,
+        get: |c| c.auto_upgrade_enabled,
+        set: |c, v| c.auto_upgrade_enabled = v,
+    },
+];
+
+/// List every known feature with its effective value and where it came from.
+///
+/// Precedence is env > config > default. A configured value equal to the
+/// default is reported as `Default`, since the two are indistinguishable
+/// once the config is resolved.
+pub fn active_features(config: &Config) -> Vec<FeatureState> {
+    active_features_with_env(config, |key| std::env::var(key).ok())
+}
+
+fn active_features_with_env(
+    config: &Config,
+    env: impl Fn(&str) -> Option<String>,
+) -> Vec<FeatureState> {
+    FEATURE_FLAGS
+        .iter()
+        .map(|flag| {
+            let (enabled, source) = match env(flag.env_var).as_deref().and_then(parse_flag) {
+                Some(value) => (value, FeatureSource::Env),
+                None => {
+                    let value = (flag.get)(config);
+                    let source = if value == flag.default {
+                        FeatureSource::Default
+                    } else {
+                        FeatureSource::Config
+                    };
+                    (value, source)
+                }
+            };
+            FeatureState {
+                name: flag.name,
+                enabled,
+                source,
+            }
+        })
+        .collect()
+}
+
+/// Apply `CODE_FEATURE_*` overrides so the resolved config matches what
+/// [`active_features`] reports. Called once while loading [`Config`].
+pub(crate) fn apply_feature_env_overrides(config: &mut Config) {
+    for flag in FEATURE_FLAGS {
+        if let Some(value) = std::env::var(flag.env_var)
+            .ok()
+            .as_deref()
+            .and_then(parse_flag)
+        {
+            (flag.set)(config, value);
+        }
+    }
+}
+
+/// Parse an env flag value; unrecognised values are ignored.
+fn parse_flag(raw: &str) -> Option<bool> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigOverrides;
+    use crate::config::ConfigToml;
+
+    fn default_config() -> Config {
+        let codex_home = tempfile::TempDir::new().unwrap();
+        Config::load_from_base_config_with_overrides(
+            ConfigToml::default(),
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect("defaults for test should always succeed")
+    }
+
+    fn state<'a>(states: &'a [FeatureState], name: &str) -> &'a FeatureState {
+        states
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("feature {name} missing"))
+    }
+
+    #[test]
+    fn reports_env_config_and_default_sources() {
+        let mut config = default_config();
+        config.tools_web_search_request = true;
+
+        let states = active_features_with_env(&config, |key| {
+            (key == "CODE_FEATURE_PLAN_TOOL").then(|| "true".to_string())
+        });
+
+        assert_eq!(
+            state(&states, "plan_tool"),
+            &FeatureState {
+                name: "plan_tool",
+                enabled: true,
+                source: FeatureSource::Env,
+            }
+        );
+        assert_eq!(
+            state(&states, "web_search"),
+            &FeatureState {
+                name: "web_search",
+                enabled: true,
+                source: FeatureSource::Config,
+            }
+        );
+        assert_eq!(
+            state(&states, "view_image_tool"),
+            &FeatureState {
+                name: "view_image_tool",
+                enabled: true,
+                source: FeatureSource::Default,
+            }
+        );
+        assert_eq!(states.len(), FEATURE_FLAGS.len());
+    }
+
+    #[test]
+    fn env_can_disable_and_bad_values_are_ignored() {
+        let mut config = default_config();
+        config.include_apply_patch_tool = true;
+
+        let states = active_features_with_env(&config, |key| match key {
+            "CODE_FEATURE_APPLY_PATCH_TOOL" => Some("maybe".to_string()),
+            "CODE_FEATURE_VIEW_IMAGE_TOOL" => Some("off".to_string()),
+            _ => None,
+        });
+
+        let apply_patch = state(&states, "apply_patch_tool");
+        assert!(apply_patch.enabled);
+        assert_eq!(apply_patch.source, FeatureSource::Config);
+
+        let view_image = state(&states, "view_image_tool");
+        assert!(!view_image.enabled);
+        assert_eq!(view_image.source, FeatureSource::Env);
+    }
+}
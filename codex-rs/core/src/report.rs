@@ -3,6 +3,20 @@
 //! Provides statistical reporting with Markdown table generation,
 //! baseline comparison, and regression detection.
 //!
+//! Each comparison is classified as [`Verdict::Improved`], [`Verdict::Stable`]
+//! or [`Verdict::Regressed`] using a per-benchmark [`RegressionThreshold`]
+//! from a [`RegressionPolicy`]. The baseline is either the previous run in a
+//! [`BenchmarkHistory`] or a named baseline pinned in [`PinnedBaselines`].
+//!
+//! A change only leaves `Stable` when Welch's t-test on the raw sample
+//! distributions rejects "same mean" at the policy's `alpha`, so noisy
+//! benchmarks whose point estimate moved are not flagged. The p-value is
+//! reported with every verdict.
+//!
+//! Comparison reports can be exported for CI: [`ComparisonReport::export_json`]
+//! writes structured data and [`ComparisonReport::export_junit`] writes JUnit
+//! XML where every regressed benchmark is a failing test case.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -14,8 +28,173 @@
 //! println!("{}", report.to_markdown());
 //! ```
 
+use crate::benchmarks::BenchmarkHistory;
+use crate::benchmarks::BenchmarkRecord;
 use crate::benchmarks::BenchmarkResult;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// How much slower (or faster) a benchmark must get before it counts
+///
+/// A change must exceed *both* limits: `relative_percent` of the baseline
+/// mean and `absolute_ms`. The absolute floor keeps sub-millisecond
+/// benchmarks from flapping on tiny relative swings.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RegressionThreshold {
+    /// Minimum change as a percentage of the baseline mean
+    pub relative_percent: f64,
+    /// Minimum change in milliseconds
+    pub absolute_ms: f64,
+}
+
+impl Default for RegressionThreshold {
+    fn default() -> Self {
+        Self {
+            relative_percent: 20.0,
+            absolute_ms: 0.0,
+        }
+    }
+}
+
+impl RegressionThreshold {
+    /// Threshold with the given relative limit and no absolute floor
+    pub fn relative(percent: f64) -> Self {
+        Self {
+            relative_percent: percent,
+            absolute_ms: 0.0,
+        }
+    }
+
+    /// Set the absolute floor in milliseconds
+    pub fn with_absolute_ms(mut self, ms: f64) -> Self {
+        self.absolute_ms = ms;
+        self
+    }
+}
+
+/// Significance level used when a policy does not set one
+pub const DEFAULT_ALPHA: f64 = 0.05;
+
+fn default_alpha() -> f64 {
+    DEFAULT_ALPHA
+}
+
+/// Default threshold plus per-benchmark overrides
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegressionPolicy {
+    /// Threshold for benchmarks without an override
+    #[serde(default)]
+    pub default: RegressionThreshold,
+    /// Overrides keyed by benchmark name
+    #[serde(default)]
+    pub per_benchmark: HashMap<String, RegressionThreshold>,
+    /// Significance level: changes with `p_value >= alpha` are `Stable`
+    #[serde(default = "default_alpha")]
+    pub alpha: f64,
+}
+
+impl Default for RegressionPolicy {
+    fn default() -> Self {
+        Self::new(RegressionThreshold::default())
+    }
+}
+
+impl RegressionPolicy {
+    /// Policy applying `default` to every benchmark
+    pub fn new(default: RegressionThreshold) -> Self {
+        Self {
+            default,
+            per_benchmark: HashMap::new(),
+            alpha: DEFAULT_ALPHA,
+        }
+    }
+
+    /// Set the significance level (e.g. 0.01 for stricter gating)
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Override the threshold for one benchmark (e.g. a high-variance one)
+    pub fn with_threshold(
+        mut self,
+        name: impl Into<String>,
+        threshold: RegressionThreshold,
+    ) -> Self {
+        self.per_benchmark.insert(name.into(), threshold);
+        self
+    }
+
+    /// Threshold that applies to `name`
+    pub fn threshold_for(&self, name: &str) -> RegressionThreshold {
+        self.per_benchmark
+            .get(name)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// Classification of a benchmark against its baseline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    Improved,
+    Stable,
+    Regressed,
+}
+
+/// Which earlier run a report is compared against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaselineSelector {
+    /// The most recent run recorded in the history
+    PreviousRun,
+    /// A baseline pinned under this label
+    Pinned(String),
+}
+
+/// Named baselines pinned from benchmark runs
+///
+/// Stored as a JSON object mapping each label to the records that make up
+/// that baseline, so a release or known-good commit can be kept as the
+/// reference while the history keeps growing.
+#[derive(Debug, Clone)]
+pub struct PinnedBaselines {
+    path: PathBuf,
+}
+
+impl PinnedBaselines {
+    /// Use the baselines file at `path` (created on first pin)
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Pin `records` under `label`, replacing any previous pin with that label
+    pub fn pin(&self, label: &str, records: Vec<BenchmarkRecord>) -> std::io::Result<()> {
+        let mut all = self.load_all()?;
+        all.insert(label.to_string(), records);
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&all).map_err(std::io::Error::other)?;
+        std::fs::write(&self.path, json)
+    }
+
+    /// Records pinned under `label`, if any
+    pub fn get(&self, label: &str) -> std::io::Result<Option<Vec<BenchmarkRecord>>> {
+        Ok(self.load_all()?.remove(label))
+    }
+
+    fn load_all(&self) -> std::io::Result<BTreeMap<String, Vec<BenchmarkRecord>>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+}
 
 /// A collection of benchmark results for reporting
 #[derive(Debug, Default)]
@@ -57,6 +236,48 @@ impl PerformanceReport {
         self.order.iter().filter_map(|name| self.results.get(name))
     }
 
+    /// Build a report from persisted records (later records win on name clashes)
+    pub fn from_records(title: impl Into<String>, records: &[BenchmarkRecord]) -> Self {
+        let mut report = Self::new(title);
+        for record in records {
+            report.add_result(record.to_result());
+        }
+        report
+    }
+
+    /// Load the baseline this report should be compared against
+    ///
+    /// `PreviousRun` takes the latest history entry for each benchmark in
+    /// this report, so call it before recording the current run. A missing
+    /// pinned label is a `NotFound` error rather than an empty baseline.
+    pub fn load_baseline(
+        &self,
+        history: &BenchmarkHistory,
+        pins: &PinnedBaselines,
+        selector: &BaselineSelector,
+    ) -> std::io::Result<PerformanceReport> {
+        match selector {
+            BaselineSelector::PreviousRun => {
+                let mut records = Vec::new();
+                for name in &self.order {
+                    if let Some(record) = history.latest(name)? {
+                        records.push(record);
+                    }
+                }
+                Ok(Self::from_records("previous run", &records))
+            }
+            BaselineSelector::Pinned(label) => {
+                let records = pins.get(label)?.ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!("no pinned baseline named `{label}`"),
+                    )
+                })?;
+                Ok(Self::from_records(label.clone(), &records))
+            }
+        }
+    }
+
     /// Generate Markdown table report
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
@@ -85,11 +306,25 @@ impl PerformanceReport {
 
     /// Generate comparison report between current and baseline
     pub fn compare_to_baseline(&self, baseline: &PerformanceReport) -> ComparisonReport {
+        self.compare_with_policy(baseline, &RegressionPolicy::default())
+    }
+
+    /// Compare against `baseline` using per-benchmark thresholds from `policy`
+    pub fn compare_with_policy(
+        &self,
+        baseline: &PerformanceReport,
+        policy: &RegressionPolicy,
+    ) -> ComparisonReport {
         let mut comparisons = Vec::new();
 
         for result in self.results() {
             if let Some(baseline_result) = baseline.get_result(&result.name) {
-                let comparison = ComparisonResult::compare(result, baseline_result);
+                let comparison = ComparisonResult::compare_with_significance(
+                    result,
+                    baseline_result,
+                    policy.threshold_for(&result.name),
+                    policy.alpha,
+                );
                 comparisons.push(comparison);
             }
         }
@@ -99,7 +334,7 @@ impl PerformanceReport {
 }
 
 /// Result of comparing two benchmark results
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ComparisonResult {
     /// Benchmark name
     pub name: String,
@@ -111,9 +346,11 @@ pub struct ComparisonResult {
     pub speedup: f64,
     /// Percentage change (positive = regression, negative = improvement)
     pub change_percent: f64,
-    /// Whether the change is statistically significant (p < 0.05)
+    /// Whether the change is statistically significant (p < alpha)
     pub significant: bool,
-    /// Whether this represents a regression (>20% slower)
+    /// Classification against the applied threshold
+    pub verdict: Verdict,
+    /// Whether this represents a regression (`verdict == Regressed`)
     pub is_regression: bool,
     /// T-statistic from Welch's t-test
     pub t_statistic: f64,
@@ -122,8 +359,28 @@ pub struct ComparisonResult {
 }
 
 impl ComparisonResult {
-    /// Compare two benchmark results
+    /// Compare two benchmark results using the default threshold
     pub fn compare(current: &BenchmarkResult, baseline: &BenchmarkResult) -> Self {
+        Self::compare_with_threshold(current, baseline, RegressionThreshold::default())
+    }
+
+    /// Compare two benchmark results against an explicit threshold
+    pub fn compare_with_threshold(
+        current: &BenchmarkResult,
+        baseline: &BenchmarkResult,
+        threshold: RegressionThreshold,
+    ) -> Self {
+        Self::compare_with_significance(current, baseline, threshold, DEFAULT_ALPHA)
+    }
+
+    /// Compare two benchmark results, requiring `p_value < alpha` for any
+    /// verdict other than `Stable`
+    pub fn compare_with_significance(
+        current: &BenchmarkResult,
+        baseline: &BenchmarkResult,
+        threshold: RegressionThreshold,
+        alpha: f64,
+    ) -> Self {
         let speedup = if current.mean_ms > 0.0 {
             baseline.mean_ms / current.mean_ms
         } else {
@@ -138,10 +395,21 @@ impl ComparisonResult {
 
         // Welch's t-test for statistical significance
         let (t_statistic, p_value) = welch_t_test(current, baseline);
-        let significant = p_value < 0.05;
-
-        // Regression if >20% slower with statistical significance
-        let is_regression = change_percent > 20.0 && significant;
+        let significant = p_value < alpha;
+
+        // Only significant changes past both limits leave `Stable`
+        let delta_ms = current.mean_ms - baseline.mean_ms;
+        let verdict = if !significant {
+            Verdict::Stable
+        } else if change_percent > threshold.relative_percent && delta_ms > threshold.absolute_ms {
+            Verdict::Regressed
+        } else if -change_percent > threshold.relative_percent && -delta_ms > threshold.absolute_ms
+        {
+            Verdict::Improved
+        } else {
+            Verdict::Stable
+        };
+        let is_regression = verdict == Verdict::Regressed;
 
         Self {
             name: current.name.clone(),
@@ -150,6 +418,7 @@ impl ComparisonResult {
             speedup,
             change_percent,
             significant,
+            verdict,
             is_regression,
             t_statistic,
             p_value,
@@ -173,19 +442,20 @@ impl ComparisonResult {
         };
 
         format!(
-            "{}: {:.2}ms → {:.2}ms ({:.1}% {}){}",
+            "{}: {:.2}ms → {:.2}ms ({:.1}% {}, p={:.3}){}",
             self.name,
             self.baseline_mean_ms,
             self.current_mean_ms,
             self.change_percent.abs(),
             direction,
+            self.p_value,
             significance
         )
     }
 }
 
 /// Report comparing multiple benchmarks to baselines
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ComparisonReport {
     /// Individual comparisons
     pub comparisons: Vec<ComparisonResult>,
@@ -205,11 +475,11 @@ impl ComparisonReport {
             .collect()
     }
 
-    /// Get all improvements (>20% faster, significant)
+    /// Get all improvements (significant and past the threshold)
     pub fn improvements(&self) -> Vec<&ComparisonResult> {
         self.comparisons
             .iter()
-            .filter(|c| c.change_percent < -20.0 && c.significant)
+            .filter(|c| c.verdict == Verdict::Improved)
             .collect()
     }
 
@@ -218,27 +488,28 @@ impl ComparisonReport {
         let mut output = String::new();
 
         output.push_str("# Performance Comparison Report\n\n");
-        output
-            .push_str("| Operation | Baseline (ms) | Current (ms) | Change | Speedup | Status |\n");
-        output
-            .push_str("|-----------|---------------|--------------|--------|---------|--------|\n");
+        output.push_str(
+            "| Operation | Baseline (ms) | Current (ms) | Change | Speedup | p-value | Status |\n",
+        );
+        output.push_str(
+            "|-----------|---------------|--------------|--------|---------|---------|--------|\n",
+        );
 
         for comparison in &self.comparisons {
-            let status = if comparison.is_regression {
-                "⚠️ REGRESSION"
-            } else if comparison.change_percent < -20.0 && comparison.significant {
-                "✅ IMPROVED"
-            } else {
-                "➡️ OK"
+            let status = match comparison.verdict {
+                Verdict::Regressed => "⚠️ REGRESSION",
+                Verdict::Improved => "✅ IMPROVED",
+                Verdict::Stable => "➡️ OK",
             };
 
             output.push_str(&format!(
-                "| {} | {:.2} | {:.2} | {:+.1}% | {:.2}x | {} |\n",
+                "| {} | {:.2} | {:.2} | {:+.1}% | {:.2}x | {:.3} | {} |\n",
                 comparison.name,
                 comparison.baseline_mean_ms,
                 comparison.current_mean_ms,
                 comparison.change_percent,
                 comparison.speedup,
+                comparison.p_value,
                 status
             ));
         }
@@ -263,24 +534,132 @@ impl ComparisonReport {
             );
         }
     }
+
+    /// Serialize as pretty JSON: counts plus one entry per comparison
+    pub fn to_json(&self) -> String {
+        let export = JsonExport {
+            total: self.comparisons.len(),
+            regressions: self.regressions().len(),
+            improvements: self.improvements().len(),
+            comparisons: &self.comparisons,
+        };
+        // Only plain numbers/strings are serialized, so this cannot fail
+        serde_json::to_string_pretty(&export).unwrap_or_default()
+    }
+
+    /// Write [`Self::to_json`] to `path`
+    pub fn export_json(&self, path: &Path) -> std::io::Result<()> {
+        write_export(path, &self.to_json())
+    }
+
+    /// Render as a JUnit XML test suite
+    ///
+    /// Each comparison is a `<testcase>` whose `time` is the current mean in
+    /// seconds; regressed benchmarks carry a `<failure>` with the timings.
+    pub fn to_junit_xml(&self) -> String {
+        let failures = self.regressions().len();
+        let total_secs: f64 = self
+            .comparisons
+            .iter()
+            .map(|c| c.current_mean_ms / 1000.0)
+            .sum();
+
+        let mut output = String::new();
+        output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str(&format!(
+            "<testsuite name=\"benchmarks\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" time=\"{total_secs:.6}\">\n",
+            self.comparisons.len()
+        ));
+
+        for comparison in &self.comparisons {
+            let name = escape_xml(&comparison.name);
+            let time = comparison.current_mean_ms / 1000.0;
+            if comparison.verdict == Verdict::Regressed {
+                let message = escape_xml(&format!(
+                    "regressed: {:.2}ms -> {:.2}ms ({:+.1}%, p={:.3})",
+                    comparison.baseline_mean_ms,
+                    comparison.current_mean_ms,
+                    comparison.change_percent,
+                    comparison.p_value
+                ));
+                output.push_str(&format!(
+                    "  <testcase classname=\"benchmarks\" name=\"{name}\" time=\"{time:.6}\">\n"
+                ));
+                output.push_str(&format!(
+                    "    <failure type=\"regression\" message=\"{message}\">{}</failure>\n",
+                    escape_xml(&comparison.summary())
+                ));
+                output.push_str("  </testcase>\n");
+            } else {
+                output.push_str(&format!(
+                    "  <testcase classname=\"benchmarks\" name=\"{name}\" time=\"{time:.6}\"/>\n"
+                ));
+            }
+        }
+
+        output.push_str("</testsuite>\n");
+        output
+    }
+
+    /// Write [`Self::to_junit_xml`] to `path`
+    pub fn export_junit(&self, path: &Path) -> std::io::Result<()> {
+        write_export(path, &self.to_junit_xml())
+    }
+}
+
+/// Top-level shape of [`ComparisonReport::to_json`]
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    total: usize,
+    regressions: usize,
+    improvements: usize,
+    comparisons: &'a [ComparisonResult],
+}
+
+/// Write an export file, creating parent directories as needed
+fn write_export(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)
+}
+
+/// Escape XML special characters, replacing characters XML 1.0 does not
+/// allow (control characters other than tab/newline/CR, U+FFFE, U+FFFF)
+/// with U+FFFD.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => escaped.push('\u{fffd}'),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 /// Perform Welch's t-test between two benchmark results
 ///
-/// Returns (t-statistic, p-value)
+/// Uses the raw samples (with the unbiased n-1 variance) when available and
+/// falls back to the summary mean/stddev for results rebuilt without them.
+///
+/// Returns (t-statistic, two-tailed p-value)
 fn welch_t_test(a: &BenchmarkResult, b: &BenchmarkResult) -> (f64, f64) {
-    let n1 = a.sample_count as f64;
-    let n2 = b.sample_count as f64;
+    let (mean1, var1, n1) = sample_stats(a);
+    let (mean2, var2, n2) = sample_stats(b);
 
     if n1 < 2.0 || n2 < 2.0 {
         return (0.0, 1.0); // Not enough samples for significance
     }
 
-    let mean1 = a.mean_ms;
-    let mean2 = b.mean_ms;
-    let var1 = a.stddev_ms.powi(2);
-    let var2 = b.stddev_ms.powi(2);
-
     // Welch's t-statistic
     let se = ((var1 / n1) + (var2 / n2)).sqrt();
     if se == 0.0 {
@@ -298,37 +677,151 @@ fn welch_t_test(a: &BenchmarkResult, b: &BenchmarkResult) -> (f64, f64) {
         n1 + n2 - 2.0 // Fall back to pooled df
     };
 
-    // Approximate p-value using Student's t distribution
-    // For simplicity, use a lookup table for common significance levels
-    let p_value = approximate_t_p_value(t.abs(), df);
+    (t, student_t_two_tailed_p(t, df))
+}
+
+/// (mean, variance, n) in milliseconds for a benchmark result
+fn sample_stats(result: &BenchmarkResult) -> (f64, f64, f64) {
+    let samples = result.samples_us();
+    if samples.len() < 2 {
+        return (
+            result.mean_ms,
+            result.stddev_ms.powi(2),
+            result.sample_count as f64,
+        );
+    }
 
-    (t, p_value)
+    let n = samples.len() as f64;
+    let mean = samples.iter().map(|&us| us as f64 / 1000.0).sum::<f64>() / n;
+    let var = samples
+        .iter()
+        .map(|&us| (us as f64 / 1000.0 - mean).powi(2))
+        .sum::<f64>()
+        / (n - 1.0);
+    (mean, var, n)
 }
 
-/// Approximate p-value for t-distribution
+/// Two-tailed p-value of Student's t distribution with `df` degrees of freedom
 ///
-/// Uses critical values for common thresholds. Returns approximate p-value.
-fn approximate_t_p_value(t: f64, df: f64) -> f64 {
-    // Critical values for two-tailed test at various significance levels
-    // These are approximate for df >= 10
-    let crit_001 = 3.169 + (0.1 / df.max(1.0)); // p < 0.01
-    let crit_005 = 2.228 + (0.1 / df.max(1.0)); // p < 0.05
-    let crit_010 = 1.812 + (0.1 / df.max(1.0)); // p < 0.10
-
-    if t >= crit_001 {
-        0.005 // p < 0.01
-    } else if t >= crit_005 {
-        0.025 // 0.01 < p < 0.05
-    } else if t >= crit_010 {
-        0.075 // 0.05 < p < 0.10
+/// P(|T| >= |t|) = I_x(df/2, 1/2) with x = df / (df + t²).
+fn student_t_two_tailed_p(t: f64, df: f64) -> f64 {
+    if !t.is_finite() {
+        return 0.0;
+    }
+    if df <= 0.0 {
+        return 1.0;
+    }
+    let x = df / (df + t * t);
+    regularized_incomplete_beta(x, df / 2.0, 0.5).clamp(0.0, 1.0)
+}
+
+/// Regularized incomplete beta function I_x(a, b)
+fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    let front = ln_front.exp();
+
+    // The continued fraction converges quickly on this side of the mean
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(x, a, b) / a
     } else {
-        0.5 // p > 0.10
+        1.0 - front * beta_continued_fraction(1.0 - x, b, a) / b
     }
 }
 
+/// Continued fraction for the incomplete beta function (modified Lentz)
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 300;
+    const EPSILON: f64 = 3e-14;
+    const TINY: f64 = 1e-300;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    h
+}
+
+/// Natural log of the gamma function (Lanczos approximation, g = 7)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection formula
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let mut sum = COEFFICIENTS[0];
+    for (i, &coefficient) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += coefficient / (x + i as f64);
+    }
+    let t = x + 7.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use quick_xml::events::Event;
     use std::time::Duration;
 
     #[test]
@@ -388,6 +881,7 @@ mod tests {
                 speedup: 2.0,
                 change_percent: -50.0,
                 significant: true,
+                verdict: Verdict::Improved,
                 is_regression: false,
                 t_statistic: 0.0,
                 p_value: 0.5,
@@ -414,6 +908,320 @@ mod tests {
         assert!(t.abs() > 2.0, "t-statistic {t} should be > 2.0");
     }
 
+    /// Samples around `mean_ms` with ±1ms of deterministic jitter
+    fn noisy(name: &str, mean_ms: u64) -> BenchmarkResult {
+        let samples: Vec<Duration> = (0..21)
+            .map(|i| Duration::from_millis(mean_ms - 1 + (i % 3)))
+            .collect();
+        BenchmarkResult::from_samples(name, &samples)
+    }
+
+    #[test]
+    fn test_small_slowdown_under_threshold_is_stable() {
+        let baseline = noisy("noisy", 100);
+        let current = noisy("noisy", 102);
+
+        let comparison = ComparisonResult::compare_with_threshold(
+            &current,
+            &baseline,
+            RegressionThreshold::relative(5.0),
+        );
+
+        assert!((comparison.change_percent - 2.0).abs() < 0.01);
+        assert!(comparison.significant, "p = {}", comparison.p_value);
+        assert_eq!(comparison.verdict, Verdict::Stable);
+        assert!(!comparison.is_regression);
+    }
+
+    #[test]
+    fn test_large_slowdown_over_threshold_is_regressed() {
+        let baseline = noisy("noisy", 100);
+        let current = noisy("noisy", 120);
+
+        let comparison = ComparisonResult::compare_with_threshold(
+            &current,
+            &baseline,
+            RegressionThreshold::relative(5.0),
+        );
+
+        assert_eq!(comparison.verdict, Verdict::Regressed);
+        assert!(comparison.is_regression);
+    }
+
+    #[test]
+    fn test_absolute_floor_suppresses_regression() {
+        let baseline = noisy("tiny", 100);
+        let current = noisy("tiny", 120);
+        let threshold = RegressionThreshold::relative(5.0).with_absolute_ms(50.0);
+
+        let comparison = ComparisonResult::compare_with_threshold(&current, &baseline, threshold);
+
+        assert_eq!(comparison.verdict, Verdict::Stable);
+    }
+
+    #[test]
+    fn test_policy_uses_per_benchmark_threshold() {
+        let mut baseline = PerformanceReport::new("baseline");
+        baseline.add_result(noisy("steady", 100));
+        baseline.add_result(noisy("jittery", 100));
+        let mut current = PerformanceReport::new("current");
+        current.add_result(noisy("steady", 110));
+        current.add_result(noisy("jittery", 110));
+
+        let policy = RegressionPolicy::new(RegressionThreshold::relative(5.0))
+            .with_threshold("jittery", RegressionThreshold::relative(25.0));
+        let report = current.compare_with_policy(&baseline, &policy);
+
+        let verdicts: Vec<(&str, Verdict)> = report
+            .comparisons
+            .iter()
+            .map(|c| (c.name.as_str(), c.verdict))
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![("steady", Verdict::Regressed), ("jittery", Verdict::Stable)]
+        );
+    }
+
+    #[test]
+    fn test_pinned_baseline_differs_from_previous_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = BenchmarkHistory::new(dir.path().join("history.jsonl"));
+        let pins = PinnedBaselines::new(dir.path().join("baselines.json"));
+
+        // v1.0 ran at 100ms and was pinned; a later run had already slowed to 118ms.
+        let release = history.record(&noisy("spawn", 100), "v1.0").unwrap();
+        pins.pin("release", vec![release]).unwrap();
+        history.record(&noisy("spawn", 118), "later").unwrap();
+
+        let mut current = PerformanceReport::new("current");
+        current.add_result(noisy("spawn", 120));
+        let policy = RegressionPolicy::new(RegressionThreshold::relative(5.0));
+
+        let previous = current
+            .load_baseline(&history, &pins, &BaselineSelector::PreviousRun)
+            .unwrap();
+        let vs_previous = current.compare_with_policy(&previous, &policy);
+        assert_eq!(vs_previous.comparisons[0].verdict, Verdict::Stable);
+
+        let pinned = current
+            .load_baseline(&history, &pins, &BaselineSelector::Pinned("release".into()))
+            .unwrap();
+        let vs_pinned = current.compare_with_policy(&pinned, &policy);
+        assert_eq!(vs_pinned.comparisons[0].verdict, Verdict::Regressed);
+
+        let missing =
+            current.load_baseline(&history, &pins, &BaselineSelector::Pinned("nope".into()));
+        assert_eq!(missing.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+    }
+
+    /// 21 samples spread evenly over `center_ms ± 10ms` in shuffled order
+    fn spread(name: &str, center_ms: u64) -> BenchmarkResult {
+        let samples: Vec<Duration> = (0..21u64)
+            .map(|i| Duration::from_millis(center_ms - 10 + (i * 8) % 21))
+            .collect();
+        BenchmarkResult::from_samples(name, &samples)
+    }
+
+    #[test]
+    fn test_student_t_p_value_matches_tables() {
+        // Two-tailed critical values: t(10) = 2.228 at 0.05, t(30) = 2.750 at 0.01
+        assert!((student_t_two_tailed_p(2.228, 10.0) - 0.05).abs() < 1e-3);
+        assert!((student_t_two_tailed_p(2.750, 30.0) - 0.01).abs() < 1e-3);
+        assert!((student_t_two_tailed_p(0.0, 5.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_overlapping_noisy_distributions_not_significant() {
+        let baseline = spread("noisy", 100);
+        let current = spread("noisy", 101);
+
+        // The mean moved past a tight 0.5% threshold, but only by noise.
+        let comparison = ComparisonResult::compare_with_significance(
+            &current,
+            &baseline,
+            RegressionThreshold::relative(0.5),
+            0.05,
+        );
+
+        assert!((comparison.change_percent - 1.0).abs() < 0.01);
+        assert!(comparison.p_value > 0.5, "p = {}", comparison.p_value);
+        assert!(!comparison.significant);
+        assert_eq!(comparison.verdict, Verdict::Stable);
+        assert!(comparison.summary().contains("p=0."));
+    }
+
+    #[test]
+    fn test_separated_distributions_significant() {
+        let baseline = spread("slow", 100);
+        let current = spread("slow", 130);
+
+        let comparison = ComparisonResult::compare_with_significance(
+            &current,
+            &baseline,
+            RegressionThreshold::relative(5.0),
+            0.01,
+        );
+
+        assert!(comparison.p_value < 1e-6, "p = {}", comparison.p_value);
+        assert!(comparison.significant);
+        assert_eq!(comparison.verdict, Verdict::Regressed);
+    }
+
+    #[test]
+    fn test_policy_alpha_gates_borderline_change() {
+        // t ≈ 2.09 on ~40 df: p ≈ 0.04, significant at 0.05 but not at 0.01
+        let mut baseline = PerformanceReport::new("baseline");
+        baseline.add_result(spread("borderline", 100));
+        let mut current = PerformanceReport::new("current");
+        current.add_result(spread("borderline", 104));
+        let threshold = RegressionThreshold::relative(1.0);
+
+        let lenient = current.compare_with_policy(&baseline, &RegressionPolicy::new(threshold));
+        let strict = current.compare_with_policy(
+            &baseline,
+            &RegressionPolicy::new(threshold).with_alpha(0.01),
+        );
+
+        let p = lenient.comparisons[0].p_value;
+        assert!(p > 0.01 && p < 0.05, "p = {p}");
+        assert_eq!(lenient.comparisons[0].verdict, Verdict::Regressed);
+        assert_eq!(strict.comparisons[0].verdict, Verdict::Stable);
+    }
+
+    fn export_fixture() -> ComparisonReport {
+        let baseline = spread("a<b", 100);
+        let mut current = PerformanceReport::new("current");
+        current.add_result(spread("stable", 100));
+        current.add_result(spread("a<b", 130));
+        let mut base = PerformanceReport::new("baseline");
+        base.add_result(spread("stable", 100));
+        base.add_result(baseline);
+        current.compare_with_policy(
+            &base,
+            &RegressionPolicy::new(RegressionThreshold::relative(5.0)),
+        )
+    }
+
+    #[test]
+    fn test_export_json_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out/report.json");
+        let report = export_fixture();
+
+        report.export_json(&path).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["total"], 2);
+        assert_eq!(json["regressions"], 1);
+        assert_eq!(json["improvements"], 0);
+        let regressed = &json["comparisons"][1];
+        assert_eq!(regressed["name"], "a<b");
+        assert_eq!(regressed["verdict"], "Regressed");
+        assert_eq!(regressed["is_regression"], true);
+        for field in [
+            "baseline_mean_ms",
+            "current_mean_ms",
+            "change_percent",
+            "speedup",
+            "p_value",
+            "t_statistic",
+        ] {
+            assert!(regressed[field].is_number(), "{field} should be a number");
+        }
+        assert_eq!(json["comparisons"][0]["verdict"], "Stable");
+    }
+
+    #[test]
+    fn test_export_junit_marks_regression_as_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("junit.xml");
+        let report = export_fixture();
+
+        report.export_junit(&path).unwrap();
+        let xml = std::fs::read_to_string(&path).unwrap();
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+
+        // (name, time, failure message) per testcase, read with a real parser
+        // so mismatched or unclosed tags fail the test.
+        let mut reader = quick_xml::Reader::from_str(&xml);
+        let mut suite = None;
+        let mut testcases: Vec<(String, String, Option<String>)> = Vec::new();
+        let mut open_tags = 0usize;
+        loop {
+            let event = reader.read_event().expect("well-formed XML");
+            match &event {
+                Event::Start(e) | Event::Empty(e) => {
+                    if matches!(event, Event::Start(_)) {
+                        open_tags += 1;
+                    }
+                    let attr = |key: &str| xml_attr(e, key);
+                    match e.name().as_ref() {
+                        b"testsuite" => {
+                            suite = Some((attr("name"), attr("tests"), attr("failures")));
+                        }
+                        b"testcase" => {
+                            assert_eq!(attr("classname").as_deref(), Some("benchmarks"));
+                            testcases.push((attr("name").unwrap(), attr("time").unwrap(), None));
+                        }
+                        b"failure" => {
+                            testcases.last_mut().expect("failure inside a testcase").2 =
+                                attr("message");
+                        }
+                        other => panic!("unexpected element {}", String::from_utf8_lossy(other)),
+                    }
+                }
+                Event::End(_) => open_tags -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        assert_eq!(open_tags, 0, "every element should be closed");
+
+        assert_eq!(
+            suite,
+            Some((
+                Some("benchmarks".to_string()),
+                Some("2".to_string()),
+                Some("1".to_string())
+            ))
+        );
+        assert_eq!(
+            testcases,
+            vec![
+                ("stable".to_string(), "0.100000".to_string(), None),
+                (
+                    "a<b".to_string(),
+                    "0.130000".to_string(),
+                    Some("regressed: 100.00ms -> 130.00ms (+30.0%, p=0.000)".to_string())
+                ),
+            ]
+        );
+    }
+
+    /// Unescaped value of attribute `key` on `element`
+    fn xml_attr(element: &quick_xml::events::BytesStart<'_>, key: &str) -> Option<String> {
+        element
+            .attributes()
+            .map(|attr| attr.expect("valid attribute"))
+            .find(|attr| attr.key.as_ref() == key.as_bytes())
+            .map(|attr| {
+                let raw = std::str::from_utf8(&attr.value).expect("UTF-8 attribute");
+                quick_xml::escape::unescape(raw)
+                    .expect("valid escapes")
+                    .into_owned()
+            })
+    }
+
+    #[test]
+    fn test_escape_xml_replaces_characters_invalid_in_xml() {
+        assert_eq!(
+            escape_xml("a\u{1b}[31m\tb\u{0}c\u{fffe}<d>"),
+            "a\u{fffd}[31m\tb\u{fffd}c\u{fffd}&lt;d&gt;"
+        );
+    }
+
     #[test]
     fn test_comparison_report_markdown() {
         let report = ComparisonReport {
@@ -425,6 +1233,7 @@ mod tests {
                     speedup: 2.0,
                     change_percent: -50.0,
                     significant: true,
+                    verdict: Verdict::Improved,
                     is_regression: false,
                     t_statistic: 0.0,
                     p_value: 0.01,
@@ -436,6 +1245,7 @@ mod tests {
                     speedup: 0.2,
                     change_percent: 400.0,
                     significant: true,
+                    verdict: Verdict::Regressed,
                     is_regression: true,
                     t_statistic: 0.0,
                     p_value: 0.01,
@@ -444,6 +1254,8 @@ mod tests {
         };
 
         let markdown = report.to_markdown();
+        assert!(markdown.contains("p-value"));
+        assert!(markdown.contains("| 0.010 |"));
         assert!(markdown.contains("REGRESSION"));
         assert!(markdown.contains("IMPROVED"));
         assert!(markdown.contains("regression"));
@@ -0,0 +1,103 @@
+//! Transparent zstd compression for rollout files.
+//!
+//! Compressed rollouts use the `.jsonl.zst` extension and are written as a
+//! sequence of independent zstd frames, one per batch of lines, so appending
+//! on resume and recovering from a crash mid-session both keep working.
+
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Cursor;
+use std::io::Read;
+use std::path::Path;
+
+use tracing::warn;
+
+/// Extension for plain rollout files.
+pub(crate) const PLAIN_EXTENSION: &str = ".jsonl";
+
+/// Extension for zstd-compressed rollout files.
+pub(crate) const COMPRESSED_EXTENSION: &str = ".jsonl.zst";
+
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// True if `path` names a compressed rollout file.
+pub(crate) fn is_compressed(path: &Path) -> bool {
+    path.to_str()
+        .is_some_and(|p| p.ends_with(COMPRESSED_EXTENSION))
+}
+
+/// Strip either rollout extension from a file name.
+pub(crate) fn strip_rollout_extension(name: &str) -> Option<&str> {
+    name.strip_suffix(COMPRESSED_EXTENSION)
+        .or_else(|| name.strip_suffix(PLAIN_EXTENSION))
+}
+
+/// Compress one batch of JSONL bytes into a self-contained zstd frame.
+pub(crate) fn compress_frame(bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    zstd::stream::encode_all(bytes, COMPRESSION_LEVEL)
+}
+
+/// Decompress a rollout written as consecutive zstd frames. A torn last
+/// frame (e.g. from a crash mid-write) is dropped so that everything before
+/// it stays readable.
+pub(crate) fn decode_frames(bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    let complete = complete_frames_len(bytes);
+    if complete < bytes.len() {
+        warn!(
+            "ignoring {} bytes of truncated zstd frame at end of rollout",
+            bytes.len() - complete
+        );
+    }
+
+    let mut decoded = Vec::new();
+    let mut rest = &bytes[..complete];
+    while let Some(len) = frame_len(rest) {
+        let (frame, tail) = rest.split_at(len);
+        decoded.extend(zstd::stream::decode_all(frame)?);
+        rest = tail;
+    }
+    Ok(decoded)
+}
+
+/// Length of the complete frames at the start of `bytes`, i.e. the offset at
+/// which a torn last frame starts, or `bytes.len()` if there is none.
+pub(crate) fn complete_frames_len(bytes: &[u8]) -> usize {
+    let mut offset = 0;
+    while let Some(len) = frame_len(&bytes[offset..]) {
+        offset += len;
+    }
+    offset
+}
+
+/// Size of the complete frame at the start of `bytes`, if there is one.
+fn frame_len(bytes: &[u8]) -> Option<usize> {
+    zstd::zstd_safe::find_frame_compressed_size(bytes)
+        .ok()
+        .filter(|&len| len > 0 && len <= bytes.len())
+}
+
+/// Open a rollout file for line-by-line reading, decompressing when needed.
+/// Blocking; call from `spawn_blocking` in async contexts.
+pub fn open_rollout_reader(path: &Path) -> std::io::Result<Box<dyn BufRead + Send>> {
+    let mut file = File::open(path)?;
+    if is_compressed(path) {
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        Ok(Box::new(Cursor::new(decode_frames(&bytes)?)))
+    } else {
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Read a whole rollout file as text, decompressing when needed.
+pub(crate) async fn read_rollout_text(path: &Path) -> std::io::Result<String> {
+    if !is_compressed(path) {
+        return tokio::fs::read_to_string(path).await;
+    }
+    let bytes = tokio::fs::read(path).await?;
+    let decoded = tokio::task::spawn_blocking(move || decode_frames(&bytes))
+        .await
+        .map_err(std::io::Error::other)??;
+    String::from_utf8(decoded).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
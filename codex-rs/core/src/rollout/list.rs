@@ -1,10 +1,13 @@
 #![allow(dead_code)] // Conversation listing utilities for rollout sessions
 use std::cmp::Reverse;
+use std::io::BufRead;
 use std::io::{self};
 use std::path::Path;
 use std::path::PathBuf;
 
 use codex_file_search as file_search;
+use codex_utils_string::take_bytes_at_char_boundary;
+use codex_utils_string::take_last_bytes_at_char_boundary;
 use std::num::NonZero;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -15,7 +18,13 @@ use time::macros::format_description;
 use uuid::Uuid;
 
 use super::SESSIONS_SUBDIR;
+use super::compression::is_compressed;
+use super::compression::open_rollout_reader;
+use super::compression::read_rollout_text;
+use super::compression::strip_rollout_extension;
 use crate::config::resolve_codex_path_for_read;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::RolloutLine;
 
@@ -39,11 +48,15 @@ pub struct ConversationItem {
     pub path: PathBuf,
     /// First up to 5 JSONL records parsed as JSON (includes meta line).
     pub head: Vec<serde_json::Value>,
+    /// Text around the first match when returned by [`search_conversations`].
+    pub snippet: Option<String>,
 }
 
 /// Hard cap to bound worst‑case work per request.
 const MAX_SCAN_FILES: usize = 100;
 const HEAD_RECORD_LIMIT: usize = 10;
+/// Bytes of context kept on each side of a search match.
+const SNIPPET_CONTEXT_BYTES: usize = 60;
 
 /// Pagination cursor identifying a file by timestamp and UUID.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -112,13 +125,13 @@ pub(crate) async fn get_conversations(
 /// Returns the entire file contents as a String.
 #[allow(dead_code)]
 pub(crate) async fn get_conversation(path: &Path) -> io::Result<String> {
-    tokio::fs::read_to_string(path).await
+    read_rollout_text(path).await
 }
 
 /// Load conversation file paths from disk using directory traversal.
 ///
 /// Directory layout: `~/.code/sessions/YYYY/MM/DD/rollout-YYYY-MM-DDThh-mm-ss-<uuid>.jsonl`
-/// (Code still reads legacy `~/.codex/sessions/...`).
+/// (or `.jsonl.zst` when compressed; Code still reads legacy `~/.codex/sessions/...`).
 /// Returned newest (latest) first.
 async fn traverse_directories_for_paths(
     root: PathBuf,
@@ -150,7 +163,7 @@ async fn traverse_directories_for_paths(
                     break 'outer;
                 }
                 let mut day_files = collect_files(day_path, |name_str, path| {
-                    if !name_str.starts_with("rollout-") || !name_str.ends_with(".jsonl") {
+                    if !name_str.starts_with("rollout-") {
                         return None;
                     }
 
@@ -183,7 +196,11 @@ async fn traverse_directories_for_paths(
                             .unwrap_or((Vec::new(), false, false));
                     // Apply filters: must have session meta and at least one user message event
                     if saw_session_meta && saw_user_event {
-                        items.push(ConversationItem { path, head });
+                        items.push(ConversationItem {
+                            path,
+                            head,
+                            snippet: None,
+                        });
                     }
                 }
             }
@@ -199,6 +216,114 @@ async fn traverse_directories_for_paths(
     })
 }
 
+/// Search recorded conversations for `query` in user and assistant message
+/// text (ASCII case-insensitive). Returns up to `limit` matches, newest first,
+/// each with a snippet around its first match. Files are read line by line.
+pub async fn search_conversations(
+    codex_home: &Path,
+    query: &str,
+    limit: usize,
+) -> io::Result<Vec<ConversationItem>> {
+    let query = query.trim();
+    let root = resolve_codex_path_for_read(codex_home, Path::new(SESSIONS_SUBDIR));
+    if query.is_empty() || limit == 0 || !root.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut items = Vec::new();
+    for (_year, year_path) in collect_dirs_desc(&root, |s| s.parse::<u16>().ok()).await? {
+        for (_month, month_path) in collect_dirs_desc(&year_path, |s| s.parse::<u8>().ok()).await? {
+            for (_day, day_path) in collect_dirs_desc(&month_path, |s| s.parse::<u8>().ok()).await?
+            {
+                let mut day_files = collect_files(&day_path, |name_str, path| {
+                    if !name_str.starts_with("rollout-") {
+                        return None;
+                    }
+                    parse_timestamp_uuid_from_filename(name_str)
+                        .map(|(ts, id)| (ts, id, path.to_path_buf()))
+                })
+                .await?;
+                day_files.sort_by_key(|(ts, sid, _path)| (Reverse(*ts), Reverse(*sid)));
+                for (_ts, _sid, path) in day_files {
+                    let needle = query.to_ascii_lowercase();
+                    let scan_path = path.clone();
+                    let found =
+                        tokio::task::spawn_blocking(move || search_file(&scan_path, &needle))
+                            .await
+                            .map_err(io::Error::other)?;
+                    // Unreadable files are skipped rather than failing the search.
+                    if let Ok(Some((head, snippet))) = found {
+                        items.push(ConversationItem {
+                            path,
+                            head,
+                            snippet: Some(snippet),
+                        });
+                        if items.len() >= limit {
+                            return Ok(items);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(items)
+}
+
+/// Stream `path` looking for `needle` (already lowercased) in message text.
+/// Returns the head records read so far and the match snippet on the first hit.
+fn search_file(path: &Path, needle: &str) -> io::Result<Option<(Vec<serde_json::Value>, String)>> {
+    let reader = open_rollout_reader(path)?;
+    let mut scan = HeadScan::default();
+    for line in reader.lines() {
+        let line = line?;
+        if scan.head.len() < HEAD_RECORD_LIMIT {
+            scan.push_line(&line);
+        }
+        let Ok(rollout_line) = serde_json::from_str::<RolloutLine>(line.trim()) else {
+            continue;
+        };
+        let RolloutItem::ResponseItem(ResponseItem::Message { role, content, .. }) =
+            rollout_line.item
+        else {
+            continue;
+        };
+        if role != "user" && role != "assistant" {
+            continue;
+        }
+        for item in content {
+            let (ContentItem::InputText { text } | ContentItem::OutputText { text }) = item else {
+                continue;
+            };
+            if let Some(snippet) = match_snippet(&text, needle) {
+                return Ok(Some((scan.head, snippet)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Extract whitespace-collapsed context around the first case-insensitive
+/// occurrence of `needle` in `text`.
+fn match_snippet(text: &str, needle: &str) -> Option<String> {
+    // ASCII lowercasing preserves byte offsets, so indices map back to `text`.
+    let start = text.to_ascii_lowercase().find(needle)?;
+    let end = start + needle.len();
+    let before = take_last_bytes_at_char_boundary(&text[..start], SNIPPET_CONTEXT_BYTES);
+    let after = take_bytes_at_char_boundary(&text[end..], SNIPPET_CONTEXT_BYTES);
+
+    let mut snippet = String::new();
+    if before.len() < start {
+        snippet.push('…');
+    }
+    snippet.push_str(before);
+    snippet.push_str(&text[start..end]);
+    snippet.push_str(after);
+    if end + after.len() < text.len() {
+        snippet.push('…');
+    }
+    Some(snippet.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
 /// Pagination cursor token format: "<file_ts>|<uuid>" where `file_ts` matches the
 /// filename timestamp portion (YYYY-MM-DDThh-mm-ss) used in rollout filenames.
 /// The cursor orders files by timestamp desc, then UUID desc.
@@ -271,8 +396,8 @@ where
 }
 
 fn parse_timestamp_uuid_from_filename(name: &str) -> Option<(OffsetDateTime, Uuid)> {
-    // Expected: rollout-YYYY-MM-DDThh-mm-ss-<uuid>.jsonl
-    let core = name.strip_prefix("rollout-")?.strip_suffix(".jsonl")?;
+    // Expected: rollout-YYYY-MM-DDThh-mm-ss-<uuid>.jsonl (or .jsonl.zst)
+    let core = strip_rollout_extension(name.strip_prefix("rollout-")?)?;
 
     // Scan from the right for a '-' such that the suffix parses as a UUID.
     let (sep_idx, uuid) = core
@@ -293,41 +418,72 @@ async fn read_head_and_flags(
 ) -> io::Result<(Vec<serde_json::Value>, bool, bool)> {
     use tokio::io::AsyncBufReadExt;
 
+    let mut scan = HeadScan::default();
+
+    if is_compressed(path) {
+        let path = path.to_path_buf();
+        return tokio::task::spawn_blocking(move || {
+            let reader = open_rollout_reader(&path)?;
+            for line in reader.lines() {
+                if scan.head.len() >= max_records {
+                    break;
+                }
+                scan.push_line(&line?);
+            }
+            Ok(scan.finish())
+        })
+        .await
+        .map_err(io::Error::other)?;
+    }
+
     let file = tokio::fs::File::open(path).await?;
     let reader = tokio::io::BufReader::new(file);
     let mut lines = reader.lines();
-    let mut head: Vec<serde_json::Value> = Vec::new();
-    let mut saw_session_meta = false;
-    let mut saw_user_event = false;
 
-    while head.len() < max_records {
+    while scan.head.len() < max_records {
         let line_opt = lines.next_line().await?;
         let Some(line) = line_opt else { break };
+        scan.push_line(&line);
+    }
+
+    Ok(scan.finish())
+}
+
+/// Accumulates list-summary data from the first records of a rollout.
+#[derive(Default)]
+struct HeadScan {
+    head: Vec<serde_json::Value>,
+    saw_session_meta: bool,
+    saw_user_event: bool,
+}
+
+impl HeadScan {
+    fn push_line(&mut self, line: &str) {
         let trimmed = line.trim();
         if trimmed.is_empty() {
-            continue;
+            return;
         }
 
         let parsed: Result<RolloutLine, _> = serde_json::from_str(trimmed);
-        let Ok(rollout_line) = parsed else { continue };
+        let Ok(rollout_line) = parsed else { return };
 
         match rollout_line.item {
             RolloutItem::SessionMeta(session_meta_line) => {
                 if let Ok(val) = serde_json::to_value(session_meta_line) {
-                    head.push(val);
-                    saw_session_meta = true;
+                    self.head.push(val);
+                    self.saw_session_meta = true;
                 }
             }
             RolloutItem::ResponseItem(item) => {
                 if let Ok(val) = serde_json::to_value(item) {
-                    head.push(val);
+                    self.head.push(val);
                 }
             }
             RolloutItem::Event(event) => {
                 if let Some(msg) = crate::protocol::event_msg_from_protocol(&event.msg)
                     && matches!(msg, crate::protocol::EventMsg::AgentMessage(_))
                 {
-                    saw_user_event = true;
+                    self.saw_user_event = true;
                 }
             }
             // Skip variants not displayed in list summaries.
@@ -335,7 +491,9 @@ async fn read_head_and_flags(
         }
     }
 
-    Ok((head, saw_session_meta, saw_user_event))
+    fn finish(self) -> (Vec<serde_json::Value>, bool, bool) {
+        (self.head, self.saw_session_meta, self.saw_user_event)
+    }
 }
 
 /// Locate a recorded conversation rollout file by its UUID string using the existing
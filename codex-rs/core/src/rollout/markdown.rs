@@ -0,0 +1,158 @@
+//! Render rollout sessions as shareable Markdown transcripts.
+
+use std::io;
+use std::path::Path;
+
+use codex_protocol::mcp_protocol::ConversationId;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ReasoningItemReasoningSummary;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::models::WebSearchAction;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use tracing::warn;
+
+use super::compression::read_rollout_text;
+use super::list::find_conversation_path_by_id_str;
+use crate::util::strip_bash_lc_and_escape;
+
+/// Export the session `conversation_id` under `codex_home` to `out_path` as
+/// Markdown. Returns `NotFound` when no rollout exists for the id.
+pub async fn export_markdown(
+    codex_home: &Path,
+    conversation_id: ConversationId,
+    out_path: &Path,
+) -> io::Result<()> {
+    let id_str = conversation_id.to_string();
+    let Some(path) = find_conversation_path_by_id_str(codex_home, &id_str).await? else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no rollout found for conversation {id_str}"),
+        ));
+    };
+    let text = read_rollout_text(&path).await?;
+    tokio::fs::write(out_path, render_markdown(&text)).await
+}
+
+/// Render the JSONL contents of a rollout file as a Markdown transcript.
+pub fn render_markdown(rollout_text: &str) -> String {
+    let mut out = String::new();
+    for line in rollout_text.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let rollout_line = match serde_json::from_str::<RolloutLine>(line) {
+            Ok(rollout_line) => rollout_line,
+            Err(e) => {
+                warn!("skipping unparsable rollout line in export: {e}");
+                continue;
+            }
+        };
+        let ts = rollout_line.timestamp.as_str();
+        match rollout_line.item {
+            RolloutItem::SessionMeta(session_meta_line) => {
+                let meta = session_meta_line.meta;
+                out.push_str(&format!("# Codex session {}\n\n", meta.id));
+                out.push_str(&format!("- Started: {}\n", meta.timestamp));
+                out.push_str(&format!("- Working directory: `{}`\n", meta.cwd.display()));
+                if let Some(branch) = session_meta_line.git.and_then(|git| git.branch) {
+                    out.push_str(&format!("- Branch: `{branch}`\n"));
+                }
+                out.push('\n');
+            }
+            RolloutItem::ResponseItem(item) => render_response_item(&mut out, &item, ts),
+            RolloutItem::Compacted(compacted) => {
+                out.push_str(&format!("## Summary ({ts})\n\n{}\n\n", compacted.message));
+            }
+            RolloutItem::TurnContext(_) | RolloutItem::Event(_) => {}
+        }
+    }
+    out
+}
+
+fn render_response_item(out: &mut String, item: &ResponseItem, ts: &str) {
+    match item {
+        ResponseItem::Message { role, content, .. } => {
+            out.push_str(&format!("## {} ({ts})\n\n", role_header(role)));
+            for content_item in content {
+                match content_item {
+                    ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                        out.push_str(text.trim_end());
+                        out.push_str("\n\n");
+                    }
+                    ContentItem::InputImage { image_url } => {
+                        out.push_str(&format!("![image]({image_url})\n\n"));
+                    }
+                }
+            }
+        }
+        ResponseItem::Reasoning { summary, .. } => {
+            if summary.is_empty() {
+                return;
+            }
+            out.push_str(&format!("### Reasoning ({ts})\n\n"));
+            for ReasoningItemReasoningSummary::SummaryText { text } in summary {
+                out.push_str(&format!("> {}\n\n", text.trim().replace('\n', "\n> ")));
+            }
+        }
+        ResponseItem::LocalShellCall { action, .. } => {
+            let LocalShellAction::Exec(exec) = action;
+            out.push_str(&format!("### Shell ({ts})\n\n"));
+            push_fenced(out, "sh", &strip_bash_lc_and_escape(&exec.command));
+        }
+        ResponseItem::FunctionCall {
+            name, arguments, ..
+        } => {
+            out.push_str(&format!("### Tool call: `{name}` ({ts})\n\n"));
+            push_fenced(out, "json", arguments);
+        }
+        ResponseItem::FunctionCallOutput { output, .. } => {
+            let status = match output.success {
+                Some(false) => " (failed)",
+                _ => "",
+            };
+            out.push_str(&format!("### Tool output{status} ({ts})\n\n"));
+            push_fenced(out, "text", &output.content);
+        }
+        ResponseItem::CustomToolCall { name, input, .. } => {
+            out.push_str(&format!("### Tool call: `{name}` ({ts})\n\n"));
+            push_fenced(out, "text", input);
+        }
+        ResponseItem::CustomToolCallOutput { output, .. } => {
+            out.push_str(&format!("### Tool output ({ts})\n\n"));
+            push_fenced(out, "text", output);
+        }
+        ResponseItem::WebSearchCall { action, .. } => {
+            if let WebSearchAction::Search { query } = action {
+                out.push_str(&format!("### Web search ({ts})\n\n`{query}`\n\n"));
+            }
+        }
+        ResponseItem::Other => {}
+    }
+}
+
+fn role_header(role: &str) -> String {
+    let mut chars = role.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Unknown".to_string(),
+    }
+}
+
+/// Append `body` in a code fence long enough that backticks inside it cannot
+/// close the block early.
+fn push_fenced(out: &mut String, lang: &str, body: &str) {
+    let mut longest_run = 0;
+    let mut run = 0;
+    for c in body.chars() {
+        if c == '`' {
+            run += 1;
+            longest_run = longest_run.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    let fence = "`".repeat((longest_run + 1).max(3));
+    out.push_str(&format!("{fence}{lang}\n{}\n{fence}\n\n", body.trim_end()));
+}
@@ -1,17 +1,20 @@
 //! Rollout module: persistence and discovery of session rollout files.
 
 pub const SESSIONS_SUBDIR: &str = "sessions";
-#[allow(dead_code)]
 pub const ARCHIVED_SESSIONS_SUBDIR: &str = "archived_sessions";
 
+pub(crate) mod compression;
 pub mod list;
+pub mod markdown;
 pub(crate) mod policy;
 pub mod recorder;
 
 #[allow(unused_imports)]
 pub use codex_protocol::protocol::SessionMeta;
+pub use compression::open_rollout_reader;
 #[allow(unused_imports)]
 pub use list::find_conversation_path_by_id_str;
+pub use markdown::export_markdown;
 pub use recorder::RolloutRecorder;
 #[allow(unused_imports)]
 pub use recorder::RolloutRecorderParams;
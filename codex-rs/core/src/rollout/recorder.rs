@@ -5,6 +5,8 @@ use std::fs::{self};
 use std::io::Error as IoError;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
 
 use codex_protocol::mcp_protocol::ConversationId;
 use serde::{Deserialize, Serialize};
@@ -19,7 +21,15 @@ use tokio::sync::oneshot;
 use tracing::info;
 use tracing::warn;
 
+use super::ARCHIVED_SESSIONS_SUBDIR;
 use super::SESSIONS_SUBDIR;
+use super::compression::COMPRESSED_EXTENSION;
+use super::compression::PLAIN_EXTENSION;
+use super::compression::complete_frames_len;
+use super::compression::compress_frame;
+use super::compression::is_compressed;
+use super::compression::read_rollout_text;
+use super::compression::strip_rollout_extension;
 use super::list::ConversationsPage;
 use super::list::Cursor;
 use super::list::get_conversations;
@@ -58,6 +68,9 @@ pub struct SavedSession {
 /// $ jq -C . ~/.code/sessions/rollout-2025-05-07T17-24-21-5973b6c0-94b8-487b-a530-2aeb6098ae0e.jsonl
 /// $ fx ~/.code/sessions/rollout-2025-05-07T17-24-21-5973b6c0-94b8-487b-a530-2aeb6098ae0e.jsonl
 /// ```
+///
+/// With `rollout.compress = true` new rollouts are written as `.jsonl.zst`
+/// instead (`zstdcat <file> | jq -C .`).
 #[derive(Clone)]
 pub struct RolloutRecorder {
     tx: Sender<RolloutCmd>,
@@ -140,18 +153,28 @@ impl RolloutRecorder {
                     }),
                 )
             }
-            RolloutRecorderParams::Resume { path } => (
-                tokio::fs::OpenOptions::new()
+            RolloutRecorderParams::Resume { path } => {
+                let file = tokio::fs::OpenOptions::new()
                     .append(true)
                     .open(&path)
-                    .await?,
-                path,
-                None,
-            ),
+                    .await?;
+                if is_compressed(&path) {
+                    // Cut a frame torn by a crash so new frames don't land
+                    // behind it, where readers would never reach them.
+                    let bytes = tokio::fs::read(&path).await?;
+                    let complete = complete_frames_len(&bytes);
+                    if complete < bytes.len() {
+                        file.set_len(complete as u64).await?;
+                    }
+                }
+                (file, path, None)
+            }
         };
 
         // Clone the cwd for the spawned task to collect git info asynchronously
         let cwd = config.cwd.clone();
+        // Resumed rollouts keep the format they were created with.
+        let compress = is_compressed(&rollout_path);
 
         // A reasonably-sized bounded channel. If the buffer fills up the send
         // future will yield, which is fine – we only need to ensure we do not
@@ -161,7 +184,7 @@ impl RolloutRecorder {
         // Spawn a Tokio task that owns the file handle and performs async
         // writes. Using `tokio::fs::File` keeps everything on the async I/O
         // driver instead of blocking the runtime.
-        tokio::task::spawn(rollout_writer(file, rx, meta, cwd));
+        tokio::task::spawn(rollout_writer(file, rx, meta, cwd, compress));
 
         Ok(Self { tx, rollout_path })
     }
@@ -266,7 +289,7 @@ impl RolloutRecorder {
 
     pub(crate) async fn get_rollout_history(path: &Path) -> std::io::Result<InitialHistory> {
         info!("Resuming rollout from {path:?}");
-        let text = tokio::fs::read_to_string(path).await?;
+        let text = read_rollout_text(path).await?;
         if text.trim().is_empty() {
             return Err(IoError::other("empty session file"));
         }
@@ -336,6 +359,35 @@ impl RolloutRecorder {
         }))
     }
 
+    /// Delete archived rollouts last modified more than `older_than` ago.
+    /// Returns how many files were removed.
+    pub async fn prune_archived(codex_home: &Path, older_than: Duration) -> std::io::Result<usize> {
+        let archived = archived_rollouts(codex_home).await?;
+        let now = SystemTime::now();
+        let expired = archived
+            .into_iter()
+            .filter(|(_, modified)| {
+                now.duration_since(*modified)
+                    .is_ok_and(|age| age > older_than)
+            })
+            .map(|(path, _)| path)
+            .collect();
+        remove_rollouts(expired).await
+    }
+
+    /// Delete all but the `keep` most recently modified archived rollouts.
+    /// Returns how many files were removed.
+    pub async fn prune_archived_keeping(codex_home: &Path, keep: usize) -> std::io::Result<usize> {
+        let mut archived = archived_rollouts(codex_home).await?;
+        archived.sort_by(|a, b| b.1.cmp(&a.1));
+        let excess = archived
+            .into_iter()
+            .skip(keep)
+            .map(|(path, _)| path)
+            .collect();
+        remove_rollouts(excess).await
+    }
+
     pub async fn shutdown(&self) -> std::io::Result<()> {
         let (tx_done, rx_done) = oneshot::channel();
         match self.tx.send(RolloutCmd::Shutdown { ack: tx_done }).await {
@@ -352,6 +404,45 @@ impl RolloutRecorder {
     }
 }
 
+/// Rollout files directly under the archive directory with their mtimes.
+async fn archived_rollouts(codex_home: &Path) -> std::io::Result<Vec<(PathBuf, SystemTime)>> {
+    let dir = codex_home.join(ARCHIVED_SESSIONS_SUBDIR);
+    let mut entries = match tokio::fs::read_dir(&dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut rollouts = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let is_rollout = entry
+            .file_name()
+            .to_str()
+            .and_then(strip_rollout_extension)
+            .is_some_and(|stem| stem.starts_with("rollout-"));
+        if !is_rollout {
+            continue;
+        }
+        let metadata = entry.metadata().await?;
+        if metadata.is_file() {
+            rollouts.push((entry.path(), metadata.modified()?));
+        }
+    }
+    Ok(rollouts)
+}
+
+async fn remove_rollouts(paths: Vec<PathBuf>) -> std::io::Result<usize> {
+    let mut removed = 0;
+    for path in paths {
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => removed += 1,
+            // Another process may have pruned it first.
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("failed to prune archived rollout {path:?}: {e}"),
+        }
+    }
+    Ok(removed)
+}
+
 struct LogFileInfo {
     /// Opened file handle to the rollout file.
     file: File,
@@ -389,7 +480,12 @@ fn create_log_file(
         .format(format)
         .map_err(|e| IoError::other(format!("failed to format timestamp: {e}")))?;
 
-    let filename = format!("rollout-{date_str}-{conversation_id}.jsonl");
+    let extension = if config.rollout.compress {
+        COMPRESSED_EXTENSION
+    } else {
+        PLAIN_EXTENSION
+    };
+    let filename = format!("rollout-{date_str}-{conversation_id}{extension}");
 
     let path = dir.join(filename);
     let file = std::fs::OpenOptions::new()
@@ -410,8 +506,13 @@ async fn rollout_writer(
     mut rx: mpsc::Receiver<RolloutCmd>,
     mut meta: Option<SessionMeta>,
     cwd: std::path::PathBuf,
+    compress: bool,
 ) -> std::io::Result<()> {
-    let mut writer = JsonlWriter { file };
+    let mut writer = JsonlWriter {
+        file,
+        compress,
+        pending: String::new(),
+    };
 
     // If we have a meta, collect git info asynchronously and write meta first
     if let Some(session_meta) = meta.take() {
@@ -425,6 +526,7 @@ async fn rollout_writer(
         writer
             .write_rollout_item(RolloutItem::SessionMeta(session_meta_line))
             .await?;
+        writer.flush_pending().await?;
     }
 
     // Process rollout commands
@@ -436,6 +538,7 @@ async fn rollout_writer(
                         writer.write_rollout_item(item).await?;
                     }
                 }
+                writer.flush_pending().await?;
             }
             RolloutCmd::Shutdown { ack } => {
                 let _ = ack.send(());
@@ -448,6 +551,10 @@ async fn rollout_writer(
 
 struct JsonlWriter {
     file: tokio::fs::File,
+    /// Write each batch of lines as one zstd frame instead of plain text.
+    compress: bool,
+    /// Lines waiting to be compressed into the next frame.
+    pending: String,
 }
 
 impl JsonlWriter {
@@ -468,8 +575,24 @@ impl JsonlWriter {
     async fn write_line(&mut self, item: &impl serde::Serialize) -> std::io::Result<()> {
         let mut json = serde_json::to_string(item)?;
         json.push('\n');
+        if self.compress {
+            self.pending.push_str(&json);
+            return Ok(());
+        }
         self.file.write_all(json.as_bytes()).await?;
         self.file.flush().await?;
         Ok(())
     }
+
+    /// Write buffered lines as a single compressed frame. No-op for plain rollouts.
+    async fn flush_pending(&mut self) -> std::io::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let frame = compress_frame(self.pending.as_bytes())?;
+        self.pending.clear();
+        self.file.write_all(&frame).await?;
+        self.file.flush().await?;
+        Ok(())
+    }
 }
@@ -12,6 +12,9 @@ use time::format_description::FormatItem;
 use time::macros::format_description;
 use uuid::Uuid;
 
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::RolloutItem;
+
 // ConversationItem and ConversationsPage are referenced in test assertions below
 #[allow(unused_imports)]
 use crate::rollout::list::ConversationItem;
@@ -384,3 +387,295 @@ async fn test_stable_ordering_same_second_pagination() {
     assert_eq!(page2.num_scanned_files, 3);
     assert!(!page2.reached_scan_cap);
 }
+
+fn message(role: &str, text: &str) -> codex_protocol::models::ResponseItem {
+    use codex_protocol::models::ContentItem;
+    use codex_protocol::models::ResponseItem;
+
+    let content = if role == "user" {
+        ContentItem::InputText {
+            text: text.to_string(),
+        }
+    } else {
+        ContentItem::OutputText {
+            text: text.to_string(),
+        }
+    };
+    ResponseItem::Message {
+        id: None,
+        role: role.to_string(),
+        content: vec![content],
+    }
+}
+
+fn response_items(history: &codex_protocol::protocol::InitialHistory) -> Vec<ResponseItem> {
+    let codex_protocol::protocol::InitialHistory::Resumed(resumed) = history else {
+        panic!("expected resumed history");
+    };
+    resumed
+        .history
+        .iter()
+        .filter_map(|item| match item {
+            RolloutItem::ResponseItem(item) => Some(item.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_compressed_rollout_round_trip() {
+    use crate::config::Config;
+    use crate::config::ConfigOverrides;
+    use crate::config::ConfigToml;
+    use crate::config_types::RolloutSettings;
+    use crate::rollout::RolloutRecorder;
+    use crate::rollout::RolloutRecorderParams;
+    use crate::rollout::find_conversation_path_by_id_str;
+    use codex_protocol::mcp_protocol::ConversationId;
+
+    let temp = TempDir::new().unwrap();
+    let cfg = ConfigToml {
+        rollout: Some(RolloutSettings {
+            compress: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides {
+            cwd: Some(temp.path().to_path_buf()),
+            ..Default::default()
+        },
+        temp.path().to_path_buf(),
+    )
+    .unwrap();
+
+    let conversation_id = ConversationId::new();
+    let recorder = RolloutRecorder::new(&config, RolloutRecorderParams::new(conversation_id, None))
+        .await
+        .unwrap();
+    let path = recorder.rollout_path.clone();
+    let first = vec![
+        message("user", "hello compressed world"),
+        message("assistant", "hi there"),
+    ];
+    recorder.record_response_items(&first).await.unwrap();
+    recorder.shutdown().await.unwrap();
+
+    assert!(path.to_string_lossy().ends_with(".jsonl.zst"), "{path:?}");
+    let bytes = fs::read(&path).unwrap();
+    assert_eq!(bytes[..4], [0x28, 0xb5, 0x2f, 0xfd], "zstd frame magic");
+
+    let found = find_conversation_path_by_id_str(temp.path(), &conversation_id.to_string())
+        .await
+        .unwrap();
+    assert_eq!(found.as_deref(), Some(path.as_path()));
+
+    // Resume appends a new frame to the same file.
+    let (resumed, saved) = RolloutRecorder::resume(&config, &path).await.unwrap();
+    assert_eq!(saved.session_id, uuid::Uuid::from(conversation_id));
+    let followup = message("user", "and again");
+    resumed
+        .record_response_items(std::slice::from_ref(&followup))
+        .await
+        .unwrap();
+    resumed.shutdown().await.unwrap();
+
+    let history = RolloutRecorder::get_rollout_history(&path).await.unwrap();
+    let mut expected = first;
+    expected.push(followup);
+    assert_eq!(response_items(&history), expected);
+    assert!(
+        get_conversation(&path)
+            .await
+            .unwrap()
+            .contains("hello compressed world")
+    );
+}
+
+#[tokio::test]
+async fn test_compressed_rollout_survives_torn_last_frame() {
+    use crate::rollout::compression::compress_frame;
+    use crate::rollout::compression::read_rollout_text;
+
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rollout-torn.jsonl.zst");
+    let mut bytes = compress_frame(b"{\"line\":1}\n").unwrap();
+    bytes.extend(compress_frame(b"{\"line\":2}\n").unwrap());
+    let torn = compress_frame(b"{\"line\":3}\n").unwrap();
+    bytes.extend(&torn[..torn.len() / 2]);
+    fs::write(&path, &bytes).unwrap();
+
+    let text = read_rollout_text(&path).await.unwrap();
+    assert_eq!(text, "{\"line\":1}\n{\"line\":2}\n");
+}
+
+#[tokio::test]
+async fn test_search_conversations_returns_matching_session() {
+    use crate::config::Config;
+    use crate::config::ConfigOverrides;
+    use crate::config::ConfigToml;
+    use crate::rollout::RolloutRecorder;
+    use crate::rollout::RolloutRecorderParams;
+    use crate::rollout::list::search_conversations;
+    use codex_protocol::mcp_protocol::ConversationId;
+
+    let temp = TempDir::new().unwrap();
+    let config = Config::load_from_base_config_with_overrides(
+        ConfigToml::default(),
+        ConfigOverrides {
+            cwd: Some(temp.path().to_path_buf()),
+            ..Default::default()
+        },
+        temp.path().to_path_buf(),
+    )
+    .unwrap();
+
+    let mut paths = Vec::new();
+    for items in [
+        vec![
+            message("user", "How do I configure the Landlock sandbox on Linux?"),
+            message("assistant", "Set sandbox_mode in config.toml."),
+        ],
+        vec![
+            message("user", "Rename this function please"),
+            message("assistant", "Done, it is now called parse_args."),
+        ],
+    ] {
+        let recorder = RolloutRecorder::new(
+            &config,
+            RolloutRecorderParams::new(ConversationId::new(), None),
+        )
+        .await
+        .unwrap();
+        recorder.record_response_items(&items).await.unwrap();
+        paths.push(recorder.rollout_path.clone());
+        recorder.shutdown().await.unwrap();
+    }
+
+    let results = search_conversations(temp.path(), "landlock sandbox", 10)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1, "{results:?}");
+    assert_eq!(results[0].path, paths[0]);
+    let snippet = results[0].snippet.as_deref().unwrap();
+    assert!(snippet.contains("Landlock sandbox"), "{snippet}");
+
+    let none = search_conversations(temp.path(), "no such phrase", 10)
+        .await
+        .unwrap();
+    assert!(none.is_empty());
+}
+
+#[tokio::test]
+async fn test_export_markdown_transcript() {
+    use crate::config::Config;
+    use crate::config::ConfigOverrides;
+    use crate::config::ConfigToml;
+    use crate::rollout::RolloutRecorder;
+    use crate::rollout::RolloutRecorderParams;
+    use crate::rollout::export_markdown;
+    use codex_protocol::mcp_protocol::ConversationId;
+    use codex_protocol::models::FunctionCallOutputPayload;
+
+    let temp = TempDir::new().unwrap();
+    let config = Config::load_from_base_config_with_overrides(
+        ConfigToml::default(),
+        ConfigOverrides {
+            cwd: Some(temp.path().to_path_buf()),
+            ..Default::default()
+        },
+        temp.path().to_path_buf(),
+    )
+    .unwrap();
+
+    let conversation_id = ConversationId::new();
+    let recorder = RolloutRecorder::new(&config, RolloutRecorderParams::new(conversation_id, None))
+        .await
+        .unwrap();
+    recorder
+        .record_response_items(&[
+            message("user", "List the files here"),
+            ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload {
+                    content: "Cargo.toml\nsrc".to_string(),
+                    success: Some(true),
+                },
+            },
+            message("assistant", "There is a Cargo.toml and a src directory."),
+        ])
+        .await
+        .unwrap();
+    recorder.shutdown().await.unwrap();
+
+    let out_path = temp.path().join("transcript.md");
+    export_markdown(temp.path(), conversation_id, &out_path)
+        .await
+        .unwrap();
+    let markdown = fs::read_to_string(&out_path).unwrap();
+
+    assert!(markdown.starts_with(&format!("# Codex session {conversation_id}\n")));
+    let user = markdown.find("## User (").expect("user header");
+    let prompt = markdown.find("List the files here").unwrap();
+    let output = markdown.find("```text\nCargo.toml\nsrc\n```").unwrap();
+    let assistant = markdown.find("## Assistant (").expect("assistant header");
+    let reply = markdown
+        .find("There is a Cargo.toml and a src directory.")
+        .unwrap();
+    assert!(user < prompt && prompt < output && output < assistant && assistant < reply);
+
+    let missing = export_markdown(temp.path(), ConversationId::new(), &out_path).await;
+    assert_eq!(missing.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+}
+
+fn write_archived_rollout(dir: &Path, name: &str, age: std::time::Duration) -> std::path::PathBuf {
+    let path = dir.join(name);
+    let file = File::create(&path).unwrap();
+    file.set_modified(std::time::SystemTime::now() - age)
+        .unwrap();
+    path
+}
+
+#[tokio::test]
+async fn test_prune_archived_by_age_and_count() {
+    use crate::rollout::ARCHIVED_SESSIONS_SUBDIR;
+    use crate::rollout::RolloutRecorder;
+    use std::time::Duration;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    let temp = TempDir::new().unwrap();
+    let archive = temp.path().join(ARCHIVED_SESSIONS_SUBDIR);
+    fs::create_dir_all(&archive).unwrap();
+    let fresh = write_archived_rollout(&archive, "rollout-fresh.jsonl", DAY);
+    let recent = write_archived_rollout(&archive, "rollout-recent.jsonl.zst", 5 * DAY);
+    let old = write_archived_rollout(&archive, "rollout-old.jsonl", 40 * DAY);
+    let older = write_archived_rollout(&archive, "rollout-older.jsonl.zst", 90 * DAY);
+    // Unrelated files are never touched.
+    let notes = write_archived_rollout(&archive, "notes.txt", 365 * DAY);
+
+    let removed = RolloutRecorder::prune_archived(temp.path(), 30 * DAY)
+        .await
+        .unwrap();
+    assert_eq!(removed, 2);
+    assert!(fresh.exists() && recent.exists() && notes.exists());
+    assert!(!old.exists() && !older.exists());
+
+    let removed = RolloutRecorder::prune_archived_keeping(temp.path(), 1)
+        .await
+        .unwrap();
+    assert_eq!(removed, 1);
+    assert!(fresh.exists() && notes.exists());
+    assert!(!recent.exists());
+
+    // A missing archive directory is not an error.
+    let empty = TempDir::new().unwrap();
+    assert_eq!(
+        RolloutRecorder::prune_archived_keeping(empty.path(), 0)
+            .await
+            .unwrap(),
+        0
+    );
+}
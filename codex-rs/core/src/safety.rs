@@ -64,7 +64,7 @@ pub fn assess_patch_safety(
         // Only auto‑approve when we can actually enforce a sandbox. Otherwise
         // fall back to asking the user because the patch may touch arbitrary
         // paths outside the project.
-        match get_platform_sandbox() {
+        match available_platform_sandbox() {
             Some(sandbox_type) => SafetyCheck::AutoApprove { sandbox_type },
             None if sandbox_policy == &SandboxPolicy::DangerFullAccess => {
                 // If the user has explicitly requested DangerFullAccess, then
@@ -158,7 +158,7 @@ pub(crate) fn assess_safety_for_untrusted_command(
             if with_escalated_permissions {
                 SafetyCheck::AskUser
             } else {
-                match get_platform_sandbox() {
+                match available_platform_sandbox() {
                     Some(sandbox_type) => SafetyCheck::AutoApprove { sandbox_type },
                     // Fall back to asking since the command is untrusted and
                     // we do not have a sandbox available
@@ -170,7 +170,7 @@ pub(crate) fn assess_safety_for_untrusted_command(
         | (Never, WorkspaceWrite { .. })
         | (OnFailure, ReadOnly)
         | (OnFailure, WorkspaceWrite { .. }) => {
-            match get_platform_sandbox() {
+            match available_platform_sandbox() {
                 Some(sandbox_type) => SafetyCheck::AutoApprove { sandbox_type },
                 None => {
                     if matches!(approval_policy, OnFailure) {
@@ -193,16 +193,23 @@ pub(crate) fn assess_safety_for_untrusted_command(
     }
 }
 
-pub fn get_platform_sandbox() -> Option<SandboxType> {
+/// Returns the sandbox for the current platform. On platforms without one
+/// this is [`SandboxType::None`], whose capabilities are all `false`.
+pub fn get_platform_sandbox() -> SandboxType {
     if cfg!(target_os = "macos") {
-        Some(SandboxType::MacosSeatbelt)
+        SandboxType::MacosSeatbelt
     } else if cfg!(target_os = "linux") {
-        Some(SandboxType::LinuxSeccomp)
+        SandboxType::LinuxSeccomp
     } else {
-        None
+        SandboxType::None
     }
 }
 
+fn available_platform_sandbox() -> Option<SandboxType> {
+    let sandbox_type = get_platform_sandbox();
+    sandbox_type.is_available().then_some(sandbox_type)
+}
+
 fn is_write_patch_constrained_to_writable_paths(
     action: &ApplyPatchAction,
     sandbox_policy: &SandboxPolicy,
@@ -280,6 +287,7 @@ fn is_write_patch_constrained_to_writable_paths(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exec::SandboxCapabilities;
     use tempfile::TempDir;
 
     #[test]
@@ -376,6 +384,27 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn linux_platform_sandbox_restricts_filesystem_and_network() {
+        let capabilities = get_platform_sandbox().capabilities();
+        assert!(capabilities.filesystem);
+        assert!(capabilities.network);
+    }
+
+    #[test]
+    fn missing_sandbox_reports_no_capabilities() {
+        assert!(!SandboxType::None.is_available());
+        assert_eq!(
+            SandboxType::None.capabilities(),
+            SandboxCapabilities {
+                filesystem: false,
+                network: false,
+                process: false,
+            }
+        );
+    }
+
     #[test]
     fn test_request_escalated_privileges() {
         // Should not be a trusted command
@@ -412,7 +441,7 @@ mod tests {
             request_escalated_privileges,
         );
 
-        let expected = match get_platform_sandbox() {
+        let expected = match available_platform_sandbox() {
             Some(sandbox_type) => SafetyCheck::AutoApprove { sandbox_type },
             None => SafetyCheck::AskUser,
         };
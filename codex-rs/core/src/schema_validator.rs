@@ -3,7 +3,11 @@
 //! Provides runtime validation of TOML configuration against a JSON Schema.
 //! This module supports both embedded default schemas and external schema files.
 
+use crate::config::ConfigToml;
+use jsonschema::error::{TypeKind, ValidationErrorKind};
+use jsonschema::primitive_type::PrimitiveType;
 use jsonschema::{Draft, JSONSchema};
+use serde::Deserialize;
 use serde_json::Value;
 use std::path::Path;
 
@@ -12,7 +16,6 @@ use std::path::Path;
 /// Validates TOML configuration files against JSON Schema Draft 7 specifications.
 /// Can load schemas from external files or use an embedded default schema.
 pub struct SchemaValidator {
-    #[allow(dead_code)]
     schema: Value,
     compiled: JSONSchema,
 }
@@ -111,6 +114,89 @@ impl SchemaValidator {
         Ok(())
     }
 
+    /// Check a parsed configuration and describe every problem found.
+    ///
+    /// Unlike [`SchemaValidator::validate`], this reports unknown top-level
+    /// keys (with a "did you mean" suggestion when a known key is close) in
+    /// addition to schema violations, and classifies type mismatches so they
+    /// can be shown to the user as-is.
+    pub fn check(&self, config: &Value) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        if let (Value::Object(table), Some(Value::Object(properties))) =
+            (config, self.schema.get("properties"))
+        {
+            let known: Vec<&str> = config_toml_keys()
+                .iter()
+                .copied()
+                .chain(properties.keys().map(String::as_str))
+                .collect();
+            for key in table.keys() {
+                if !known.contains(&key.as_str()) {
+                    issues.push(ConfigIssue::UnknownKey {
+                        path: key.clone(),
+                        suggestion: suggest_key(key, known.iter().copied()),
+                    });
+                }
+            }
+        }
+
+        if let Err(errors) = self.compiled.validate(config) {
+            for error in errors {
+                let path = error.instance_path.clone().into_vec().join(".");
+                match &error.kind {
+                    ValidationErrorKind::AdditionalProperties { unexpected } => {
+                        let schema_path = error.schema_path.clone().into_vec();
+                        let parent = &schema_path[..schema_path.len().saturating_sub(1)];
+                        let candidates = self.property_names_at(parent);
+                        for key in unexpected {
+                            issues.push(ConfigIssue::UnknownKey {
+                                path: join_path(&path, key),
+                                suggestion: suggest_key(key, candidates.iter().map(String::as_str)),
+                            });
+                        }
+                    }
+                    ValidationErrorKind::Type { kind } => {
+                        let expected = match kind {
+                            TypeKind::Single(ty) => ty.to_string(),
+                            TypeKind::Multiple(types) => types
+                                .into_iter()
+                                .map(|ty| ty.to_string())
+                                .collect::<Vec<_>>()
+                                .join(" or "),
+                        };
+                        issues.push(ConfigIssue::TypeMismatch {
+                            path,
+                            expected,
+                            found: PrimitiveType::from(error.instance.as_ref()).to_string(),
+                        });
+                    }
+                    _ => issues.push(ConfigIssue::Invalid {
+                        path,
+                        message: error.to_string(),
+                    }),
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Property names declared by the schema object at `schema_path`.
+    fn property_names_at(&self, schema_path: &[String]) -> Vec<String> {
+        let mut node = &self.schema;
+        for chunk in schema_path {
+            match node.get(chunk) {
+                Some(next) => node = next,
+                None => return Vec::new(),
+            }
+        }
+        node.get("properties")
+            .and_then(Value::as_object)
+            .map(|properties| properties.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
     /// Returns the embedded default JSON Schema.
     ///
     /// Provides a comprehensive JSON Schema Draft 7 schema for configuration validation.
@@ -219,6 +305,148 @@ impl SchemaValidator {
     }
 }
 
+/// A single problem found by [`SchemaValidator::check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigIssue {
+    /// A key that no part of the configuration recognizes.
+    UnknownKey {
+        path: String,
+        suggestion: Option<String>,
+    },
+
+    /// A value whose type does not match the schema.
+    TypeMismatch {
+        path: String,
+        expected: String,
+        found: String,
+    },
+
+    /// Any other schema violation (ranges, lengths, required fields, ...).
+    Invalid { path: String, message: String },
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigIssue::UnknownKey {
+                path,
+                suggestion: Some(suggestion),
+            } => write!(f, "unknown key `{path}`; did you mean `{suggestion}`?"),
+            ConfigIssue::UnknownKey {
+                path,
+                suggestion: None,
+            } => write!(f, "unknown key `{path}`"),
+            ConfigIssue::TypeMismatch {
+                path,
+                expected,
+                found,
+            } => write!(f, "`{path}` should be {expected}, found {found}"),
+            ConfigIssue::Invalid { path, message } if path.is_empty() => write!(f, "{message}"),
+            ConfigIssue::Invalid { path, message } => write!(f, "`{path}`: {message}"),
+        }
+    }
+}
+
+fn join_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+/// Pick the candidate closest to `key`, if any is close enough to be a typo.
+fn suggest_key<'a>(key: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let max_distance = (key.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent
+/// transpositions, so `modle` is one edit away from `model`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dist = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        dist[0][j] = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1)
+                .min(dist[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(dist[i - 2][j - 2] + 1);
+            }
+            dist[i][j] = best;
+        }
+    }
+    dist[a.len()][b.len()]
+}
+
+/// Top-level keys accepted by [`ConfigToml`].
+///
+/// Read from the serde derive by asking it to deserialize from a
+/// deserializer that records the struct's field list and then bails out.
+fn config_toml_keys() -> &'static [&'static str] {
+    use serde::de::Visitor;
+
+    #[derive(Debug)]
+    struct Fields(&'static [&'static str]);
+
+    impl std::fmt::Display for Fields {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} fields", self.0.len())
+        }
+    }
+
+    impl std::error::Error for Fields {}
+
+    impl serde::de::Error for Fields {
+        fn custom<T: std::fmt::Display>(_msg: T) -> Self {
+            Fields(&[])
+        }
+    }
+
+    struct FieldRecorder;
+
+    impl<'de> serde::Deserializer<'de> for FieldRecorder {
+        type Error = Fields;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Fields> {
+            Err(Fields(&[]))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Fields> {
+            Err(Fields(fields))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    match ConfigToml::deserialize(FieldRecorder) {
+        Ok(_) => &[],
+        Err(Fields(fields)) => fields,
+    }
+}
+
 /// Errors that can occur during schema validation.
 #[derive(Debug)]
 pub enum SchemaValidationError {
@@ -437,6 +665,74 @@ audit = ["gemini"]
         }
     }
 
+    fn check(config_toml: &str) -> Vec<ConfigIssue> {
+        let validator = SchemaValidator::new(None).unwrap();
+        validator.check(&toml::from_str(config_toml).unwrap())
+    }
+
+    #[test]
+    fn test_unknown_key_suggests_closest_known_key() {
+        let issues = check(
+            r#"
+modle = "gpt-5"
+approval_policy = "never"
+            "#,
+        );
+
+        assert_eq!(
+            issues,
+            vec![ConfigIssue::UnknownKey {
+                path: "modle".to_string(),
+                suggestion: Some("model".to_string()),
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "unknown key `modle`; did you mean `model`?"
+        );
+    }
+
+    #[test]
+    fn test_unknown_nested_key_uses_section_properties() {
+        let issues = check(
+            r#"
+[hot_reload]
+enabeld = true
+            "#,
+        );
+
+        assert_eq!(
+            issues,
+            vec![ConfigIssue::UnknownKey {
+                path: "hot_reload.enabeld".to_string(),
+                suggestion: Some("enabled".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_type_mismatch_reports_expected_and_found_types() {
+        let issues = check(
+            r#"
+[hot_reload]
+debounce_ms = "2000"
+            "#,
+        );
+
+        assert_eq!(
+            issues,
+            vec![ConfigIssue::TypeMismatch {
+                path: "hot_reload.debounce_ms".to_string(),
+                expected: "integer".to_string(),
+                found: "string".to_string(),
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "`hot_reload.debounce_ms` should be integer, found string"
+        );
+    }
+
     #[test]
     fn test_full_spec939_config_passes() {
         let validator = SchemaValidator::new(None).unwrap();
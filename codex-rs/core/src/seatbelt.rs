@@ -39,11 +39,40 @@ pub async fn spawn_command_under_seatbelt(
     .await
 }
 
+/// Builds the Seatbelt profile `sandbox-exec` would be given for
+/// `sandbox_policy`. Writable roots are referenced through `WRITABLE_ROOT_*`
+/// parameters, which are passed separately as `-D` arguments.
+pub fn build_profile(sandbox_policy: &SandboxPolicy, sandbox_policy_cwd: &Path) -> String {
+    build_profile_with_params(sandbox_policy, sandbox_policy_cwd).0
+}
+
 fn create_seatbelt_command_args(
     command: Vec<String>,
     sandbox_policy: &SandboxPolicy,
     sandbox_policy_cwd: &Path,
 ) -> Vec<String> {
+    let (full_policy, extra_cli_args) =
+        build_profile_with_params(sandbox_policy, sandbox_policy_cwd);
+
+    if std::env::var("CODEX_DEBUG_PRINT_SEATBELT").is_ok() {
+        tracing::debug!(
+            "--- Codex Seatbelt Policy ---\n{full_policy}\n------------------------------"
+        );
+    }
+
+    let mut seatbelt_args: Vec<String> = vec!["-p".to_string(), full_policy];
+    seatbelt_args.extend(extra_cli_args);
+    seatbelt_args.push("--".to_string());
+    seatbelt_args.extend(command);
+    seatbelt_args
+}
+
+/// Returns the profile text together with the `-D` parameter definitions it
+/// refers to.
+fn build_profile_with_params(
+    sandbox_policy: &SandboxPolicy,
+    sandbox_policy_cwd: &Path,
+) -> (String, Vec<String>) {
     let (file_write_policy, extra_cli_args) = {
         if sandbox_policy.has_full_disk_write_access() {
             // Allegedly, this is more permissive than `(allow file-write*)`.
@@ -117,23 +146,185 @@ fn create_seatbelt_command_args(
         "{MACOS_SEATBELT_BASE_POLICY}\n{file_read_policy}\n{file_write_policy}\n{network_policy}"
     );
 
-    if std::env::var("CODEX_DEBUG_PRINT_SEATBELT").is_ok() {
-        tracing::debug!(
-            "--- Codex Seatbelt Policy ---\n{full_policy}\n------------------------------"
-        );
+    (full_policy, extra_cli_args)
+}
+
+/// Top-level forms a Seatbelt profile may contain.
+const PROFILE_DIRECTIVES: &[&str] = &["version", "allow", "deny", "import", "define", "debug"];
+
+/// A problem found by [`validate_profile`], with the 1-based line it was
+/// found on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
     }
+}
 
-    let mut seatbelt_args: Vec<String> = vec!["-p".to_string(), full_policy];
-    seatbelt_args.extend(extra_cli_args);
-    seatbelt_args.push("--".to_string());
-    seatbelt_args.extend(command);
-    seatbelt_args
+/// An open list while scanning a profile.
+struct OpenForm {
+    line: usize,
+    head: Option<String>,
+    args: usize,
+}
+
+/// Performs a lightweight syntax check of a Seatbelt profile: balanced
+/// parentheses, terminated strings, a leading `(version 1)`, and known
+/// top-level directives with an operation for every `allow`/`deny`. This does
+/// not check operation or filter names, which only `sandbox-exec` knows.
+pub fn validate_profile(profile: &str) -> Result<(), Vec<ProfileError>> {
+    let mut errors = Vec::new();
+    let mut stack: Vec<OpenForm> = Vec::new();
+    let mut top_level_forms: Vec<(usize, Option<String>)> = Vec::new();
+    let mut line = 1;
+    let mut chars = profile.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            ';' => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            '(' => stack.push(OpenForm {
+                line,
+                head: None,
+                args: 0,
+            }),
+            ')' => match stack.pop() {
+                Some(form) => {
+                    if stack.is_empty() {
+                        top_level_forms.push((form.line, form.head.clone()));
+                        check_directive(&form, &mut errors);
+                    } else {
+                        push_element(&mut stack, &mut errors, line, None, ")");
+                    }
+                }
+                None => errors.push(ProfileError {
+                    line,
+                    message: "unexpected `)` with no matching `(`".to_string(),
+                }),
+            },
+            '"' | '#' if c == '"' || chars.peek() == Some(&'"') => {
+                if c == '#' {
+                    chars.next();
+                }
+                let start_line = line;
+                let mut terminated = false;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            if chars.next() == Some('\n') {
+                                line += 1;
+                            }
+                        }
+                        '\n' => line += 1,
+                        '"' => {
+                            terminated = true;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                if terminated {
+                    push_element(&mut stack, &mut errors, start_line, None, "\"");
+                } else {
+                    errors.push(ProfileError {
+                        line: start_line,
+                        message: "unterminated string".to_string(),
+                    });
+                }
+            }
+            _ => {
+                let mut atom = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '(' | ')' | '"' | ';') {
+                        break;
+                    }
+                    atom.push(next);
+                    chars.next();
+                }
+                push_element(&mut stack, &mut errors, line, Some(atom.clone()), &atom);
+            }
+        }
+    }
+
+    for form in stack {
+        errors.push(ProfileError {
+            line: form.line,
+            message: "unclosed `(`".to_string(),
+        });
+    }
+
+    match top_level_forms.first() {
+        Some((_, Some(head))) if head == "version" => {}
+        first => errors.push(ProfileError {
+            line: first.map_or(1, |(line, _)| *line),
+            message: "profile must start with `(version 1)`".to_string(),
+        }),
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        errors.sort_by_key(|error| error.line);
+        Err(errors)
+    }
+}
+
+/// Records a finished atom, string or list as an element of the
+/// innermost open list.
+fn push_element(
+    stack: &mut [OpenForm],
+    errors: &mut Vec<ProfileError>,
+    line: usize,
+    symbol: Option<String>,
+    text: &str,
+) {
+    match stack.last_mut() {
+        Some(form) if form.head.is_none() && form.args == 0 => match symbol {
+            Some(symbol) => form.head = Some(symbol),
+            None => form.args += 1,
+        },
+        Some(form) => form.args += 1,
+        None => errors.push(ProfileError {
+            line,
+            message: format!("unexpected `{text}` outside of a form"),
+        }),
+    }
+}
+
+fn check_directive(form: &OpenForm, errors: &mut Vec<ProfileError>) {
+    let message = match form.head.as_deref() {
+        None => "empty or malformed top-level form".to_string(),
+        Some(head) if !PROFILE_DIRECTIVES.contains(&head) => {
+            format!("unknown directive `{head}`")
+        }
+        Some(head @ ("allow" | "deny")) if form.args == 0 => {
+            format!("`{head}` requires an operation")
+        }
+        Some(_) => return,
+    };
+    errors.push(ProfileError {
+        line: form.line,
+        message,
+    });
 }
 
 #[cfg(test)]
 mod tests {
     use super::MACOS_SEATBELT_BASE_POLICY;
+    use super::ProfileError;
+    use super::build_profile;
     use super::create_seatbelt_command_args;
+    use super::validate_profile;
     use crate::protocol::SandboxPolicy;
     use pretty_assertions::assert_eq;
     use std::fs;
@@ -314,6 +505,48 @@ mod tests {
         assert_eq!(expected_args, args);
     }
 
+    #[test]
+    fn generated_profiles_pass_validation() {
+        let tmp = TempDir::new().expect("tempdir");
+        let policies = [
+            SandboxPolicy::new_read_only_policy(),
+            SandboxPolicy::new_workspace_write_policy(),
+            SandboxPolicy::DangerFullAccess,
+        ];
+        for policy in policies {
+            let profile = build_profile(&policy, tmp.path());
+            assert_eq!(validate_profile(&profile), Ok(()), "{policy:?}");
+        }
+    }
+
+    #[test]
+    fn validate_profile_reports_offending_directive() {
+        let profile = r#"(version 1)
+(deny default)
+(allw file-read*)
+(allow)
+(allow file-write* (subpath "/tmp")
+"#;
+
+        assert_eq!(
+            validate_profile(profile),
+            Err(vec![
+                ProfileError {
+                    line: 3,
+                    message: "unknown directive `allw`".to_string(),
+                },
+                ProfileError {
+                    line: 4,
+                    message: "`allow` requires an operation".to_string(),
+                },
+                ProfileError {
+                    line: 5,
+                    message: "unclosed `(`".to_string(),
+                },
+            ])
+        );
+    }
+
     struct PopulatedTmp {
         root_with_git: PathBuf,
         root_without_git: PathBuf,
@@ -16,6 +16,12 @@ pub struct BashShell {
     pub(crate) bashrc_path: String,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct FishShell {
+    pub(crate) shell_path: String,
+    pub(crate) config_path: String,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct PowerShellConfig {
     pub(crate) exe: String, // Executable name or path, e.g. "pwsh" or "powershell.exe".
@@ -26,6 +32,7 @@ pub struct PowerShellConfig {
 pub enum Shell {
     Zsh(ZshShell),
     Bash(BashShell),
+    Fish(FishShell),
     PowerShell(PowerShellConfig),
     Unknown,
 }
@@ -43,6 +50,20 @@ impl Shell {
                 &bash.shell_path,
                 &bash.bashrc_path,
             ),
+            // A `bash -lc` script uses bash syntax, so it must not be handed
+            // to fish.
+            Shell::Fish(_) if strip_bash_lc(command.as_slice()).is_some() => Some(command),
+            // fish reads config.fish on every start, so there is nothing to
+            // source explicitly.
+            Shell::Fish(fish) => {
+                let joined = shlex::try_join(command.iter().map(String::as_str)).ok()?;
+                Some(vec![
+                    fish.shell_path.clone(),
+                    "-l".to_string(),
+                    "-c".to_string(),
+                    joined,
+                ])
+            }
             Shell::PowerShell(ps) => {
                 // If model generated a bash command, prefer a detected bash fallback
                 if let Some(script) = strip_bash_lc(command.as_slice()) {
@@ -92,6 +113,30 @@ impl Shell {
         }
     }
 
+    /// Runs `command` through an interactive login shell so that the rc
+    /// files, aliases and functions the user has in their terminal are
+    /// available. This is slower than [`Self::format_default_shell_invocation`]
+    /// and rc files may print output or prompt, so it is only used when
+    /// `shell_environment_policy.experimental_interactive_login` is set.
+    /// fish cannot run bash syntax, so a `bash -lc` command is returned
+    /// unchanged for fish; other commands are interpreted with fish syntax.
+    pub fn format_interactive_login_invocation(&self, command: Vec<String>) -> Option<Vec<String>> {
+        let (shell_path, flags): (&str, &[&str]) = match self {
+            Shell::Zsh(zsh) => (&zsh.shell_path, &["-i", "-l", "-c"]),
+            Shell::Bash(bash) => (&bash.shell_path, &["-i", "-l", "-c"]),
+            Shell::Fish(_) if strip_bash_lc(command.as_slice()).is_some() => return Some(command),
+            Shell::Fish(fish) => (&fish.shell_path, &["--interactive", "--login", "-c"]),
+            Shell::PowerShell(_) => return self.format_default_shell_invocation(command),
+            Shell::Unknown => return None,
+        };
+        let joined = join_shell_command(command.as_slice())?;
+
+        let mut invocation = vec![shell_path.to_string()];
+        invocation.extend(flags.iter().map(ToString::to_string));
+        invocation.push(joined);
+        Some(invocation)
+    }
+
     pub fn name(&self) -> Option<String> {
         match self {
             Shell::Zsh(zsh) => std::path::Path::new(&zsh.shell_path)
@@ -100,6 +145,9 @@ impl Shell {
             Shell::Bash(bash) => std::path::Path::new(&bash.shell_path)
                 .file_name()
                 .map(|s| s.to_string_lossy().to_string()),
+            Shell::Fish(fish) => std::path::Path::new(&fish.shell_path)
+                .file_name()
+                .map(|s| s.to_string_lossy().to_string()),
             Shell::PowerShell(ps) => Some(ps.exe.clone()),
             Shell::Unknown => None,
         }
@@ -111,8 +159,7 @@ fn format_shell_invocation_with_rc(
     shell_path: &str,
     rc_path: &str,
 ) -> Option<Vec<String>> {
-    let joined = strip_bash_lc(command)
-        .or_else(|| shlex::try_join(command.iter().map(String::as_str)).ok())?;
+    let joined = join_shell_command(command)?;
 
     let rc_command = if std::path::Path::new(rc_path).exists() {
         format!("source {rc_path} && ({joined})")
@@ -123,6 +170,12 @@ fn format_shell_invocation_with_rc(
     Some(vec![shell_path.to_string(), "-lc".to_string(), rc_command])
 }
 
+/// Unwraps a `bash -lc <script>` command to its script, or quotes any other
+/// command into a single shell string.
+fn join_shell_command(command: &[String]) -> Option<String> {
+    strip_bash_lc(command).or_else(|| shlex::try_join(command.iter().map(String::as_str)).ok())
+}
+
 fn strip_bash_lc(command: &[String]) -> Option<String> {
     match command {
         // exactly three items
@@ -164,24 +217,35 @@ fn detect_default_user_shell() -> Shell {
                 .into_owned();
             let home_path = CStr::from_ptr((*pw).pw_dir).to_string_lossy().into_owned();
 
-            if shell_path.ends_with("/zsh") {
-                return Shell::Zsh(ZshShell {
-                    shell_path,
-                    zshrc_path: format!("{home_path}/.zshrc"),
-                });
-            }
-
-            if shell_path.ends_with("/bash") {
-                return Shell::Bash(BashShell {
-                    shell_path,
-                    bashrc_path: format!("{home_path}/.bashrc"),
-                });
-            }
+            return shell_from_path(shell_path, &home_path);
         }
     }
     Shell::Unknown
 }
 
+/// Maps a login shell path (as found in the passwd database) to a [`Shell`].
+#[cfg(unix)]
+fn shell_from_path(shell_path: String, home_path: &str) -> Shell {
+    if shell_path.ends_with("/zsh") {
+        Shell::Zsh(ZshShell {
+            shell_path,
+            zshrc_path: format!("{home_path}/.zshrc"),
+        })
+    } else if shell_path.ends_with("/bash") {
+        Shell::Bash(BashShell {
+            shell_path,
+            bashrc_path: format!("{home_path}/.bashrc"),
+        })
+    } else if shell_path.ends_with("/fish") {
+        Shell::Fish(FishShell {
+            shell_path,
+            config_path: format!("{home_path}/.config/fish/config.fish"),
+        })
+    } else {
+        Shell::Unknown
+    }
+}
+
 #[cfg(unix)]
 pub async fn default_user_shell() -> Shell {
     detect_default_user_shell()
@@ -260,6 +324,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_shell_from_path_detects_zsh_bash_and_fish() {
+        assert_eq!(
+            shell_from_path("/usr/bin/fish".to_string(), "/home/u"),
+            Shell::Fish(FishShell {
+                shell_path: "/usr/bin/fish".to_string(),
+                config_path: "/home/u/.config/fish/config.fish".to_string(),
+            })
+        );
+        assert!(matches!(
+            shell_from_path("/bin/zsh".to_string(), "/home/u"),
+            Shell::Zsh(_)
+        ));
+        assert!(matches!(
+            shell_from_path("/bin/bash".to_string(), "/home/u"),
+            Shell::Bash(_)
+        ));
+        assert_eq!(
+            shell_from_path("/bin/tcsh".to_string(), "/home/u"),
+            Shell::Unknown
+        );
+    }
+
+    #[test]
+    fn test_interactive_login_invocation_flags_per_shell() {
+        let command = vec!["bash".to_string(), "-lc".to_string(), "ll src".to_string()];
+        let cases = [
+            (
+                Shell::Zsh(ZshShell {
+                    shell_path: "/bin/zsh".to_string(),
+                    zshrc_path: "/does/not/exist/.zshrc".to_string(),
+                }),
+                vec!["/bin/zsh", "-i", "-l", "-c", "ll src"],
+            ),
+            (
+                Shell::Bash(BashShell {
+                    shell_path: "/bin/bash".to_string(),
+                    bashrc_path: "/does/not/exist/.bashrc".to_string(),
+                }),
+                vec!["/bin/bash", "-i", "-l", "-c", "ll src"],
+            ),
+            (
+                Shell::Fish(FishShell {
+                    shell_path: "/usr/bin/fish".to_string(),
+                    config_path: "/does/not/exist/config.fish".to_string(),
+                }),
+                vec!["bash", "-lc", "ll src"],
+            ),
+        ];
+
+        for (shell, expected) in cases {
+            assert_eq!(
+                shell.format_interactive_login_invocation(command.clone()),
+                Some(expected.into_iter().map(ToString::to_string).collect())
+            );
+        }
+        assert_eq!(
+            Shell::Unknown.format_interactive_login_invocation(command),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fish_default_invocation_uses_login_flag() {
+        let shell = Shell::Fish(FishShell {
+            shell_path: "/usr/bin/fish".to_string(),
+            config_path: "/does/not/exist/config.fish".to_string(),
+        });
+        assert_eq!(
+            shell.format_default_shell_invocation(vec!["myecho".to_string(), "a b".to_string()]),
+            Some(vec![
+                "/usr/bin/fish".to_string(),
+                "-l".to_string(),
+                "-c".to_string(),
+                "myecho 'a b'".to_string(),
+            ])
+        );
+
+        let bash_command = vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "for f in *; do echo \"$f\"; done".to_string(),
+        ];
+        assert_eq!(
+            shell.format_default_shell_invocation(bash_command.clone()),
+            Some(bash_command)
+        );
+    }
+
     #[tokio::test]
     async fn test_run_with_profile_zshrc_not_exists() {
         let shell = Shell::Zsh(ZshShell {
@@ -360,6 +513,7 @@ mod tests {
                     )]),
                     with_escalated_permissions: None,
                     justification: None,
+                    max_output_bytes: None,
                 },
                 SandboxType::None,
                 &SandboxPolicy::DangerFullAccess,
@@ -471,6 +625,7 @@ mod macos_tests {
                     )]),
                     with_escalated_permissions: None,
                     justification: None,
+                    max_output_bytes: None,
                 },
                 SandboxType::None,
                 &SandboxPolicy::DangerFullAccess,
@@ -1,5 +1,6 @@
 use crate::config_types::AgentConfig;
 use crate::config_types::SubagentCommandConfig;
+use std::collections::HashMap;
 
 // NOTE: SPEC-KIT-963 removed the built-in prompt-expanding commands (/plan, /solve, /code).
 // This fork (theturtlecsz/code) uses /speckit.* namespace exclusively.
@@ -116,6 +117,152 @@ pub fn format_subagent_command(
 // This fork uses /speckit.* namespace via the command registry instead.
 // format_subagent_command() retained for custom [[subagents.commands]] in config.
 
+/// A command known to the resolver. Deprecated commands still resolve, but
+/// to their replacement, with a [`Deprecation`] notice attached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlashCommandDef {
+    pub name: &'static str,
+    pub deprecated_by: Option<&'static str>,
+}
+
+impl SlashCommandDef {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            deprecated_by: None,
+        }
+    }
+
+    pub const fn deprecated(name: &'static str, replacement: &'static str) -> Self {
+        Self {
+            name,
+            deprecated_by: Some(replacement),
+        }
+    }
+}
+
+/// How a typed command name was matched to a canonical command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlashMatchKind {
+    Exact,
+    Alias,
+    Prefix,
+}
+
+/// A deprecated command that was run as its replacement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    pub old: String,
+    pub new: String,
+}
+
+impl Deprecation {
+    /// User-facing notice, e.g. "/spec-auto is deprecated; use /speckit.auto instead."
+    pub fn notice(&self) -> String {
+        format!("/{} is deprecated; use /{} instead.", self.old, self.new)
+    }
+}
+
+/// A typed command name resolved to one of the known commands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlashResolution {
+    pub command: String,
+    pub kind: SlashMatchKind,
+    pub deprecation: Option<Deprecation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SlashResolveError {
+    #[error("unknown command: /{0}")]
+    Unknown(String),
+    #[error("ambiguous command /{input}; did you mean {}?", slash_list(candidates))]
+    Ambiguous {
+        input: String,
+        candidates: Vec<String>,
+    },
+}
+
+/// Resolve a command name typed without its leading slash against the known
+/// `commands`. Exact names win, then configured `aliases` (alias -> command),
+/// then an unambiguous prefix. Prefixes are matched per `.`-separated
+/// segment, so `spec.imp` matches `speckit.implement`, and never select a
+/// deprecated command. Matching ignores case.
+pub fn resolve_slash_command(
+    input: &str,
+    commands: &[SlashCommandDef],
+    aliases: &HashMap<String, String>,
+) -> Result<SlashResolution, SlashResolveError> {
+    let find_exact = |name: &str, kind: SlashMatchKind| {
+        let def = commands
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))?;
+        Some(match def.deprecated_by {
+            Some(replacement) => SlashResolution {
+                command: replacement.to_string(),
+                kind,
+                deprecation: Some(Deprecation {
+                    old: def.name.to_string(),
+                    new: replacement.to_string(),
+                }),
+            },
+            None => SlashResolution {
+                command: def.name.to_string(),
+                kind,
+                deprecation: None,
+            },
+        })
+    };
+
+    if let Some(resolution) = find_exact(input, SlashMatchKind::Exact) {
+        return Ok(resolution);
+    }
+
+    if let Some(target) = aliases
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(input))
+        .map(|(_, target)| target.trim_start_matches('/'))
+    {
+        return find_exact(target, SlashMatchKind::Alias)
+            .ok_or_else(|| SlashResolveError::Unknown(target.to_string()));
+    }
+
+    let mut candidates: Vec<String> = commands
+        .iter()
+        .filter(|c| c.deprecated_by.is_none() && is_segment_prefix(input, c.name))
+        .map(|c| c.name.to_string())
+        .collect();
+    match candidates.len() {
+        0 => Err(SlashResolveError::Unknown(input.to_string())),
+        1 => Ok(SlashResolution {
+            command: candidates.remove(0),
+            kind: SlashMatchKind::Prefix,
+            deprecation: None,
+        }),
+        _ => Err(SlashResolveError::Ambiguous {
+            input: input.to_string(),
+            candidates,
+        }),
+    }
+}
+
+fn slash_list(commands: &[String]) -> String {
+    commands
+        .iter()
+        .map(|c| format!("/{c}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn is_segment_prefix(input: &str, command: &str) -> bool {
+    let typed: Vec<&str> = input.split('.').collect();
+    let full: Vec<&str> = command.split('.').collect();
+    typed.len() == full.len()
+        && !input.is_empty()
+        && typed.iter().zip(&full).all(|(t, f)| {
+            f.len() >= t.len() && f.as_bytes()[..t.len()].eq_ignore_ascii_case(t.as_bytes())
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +292,84 @@ mod tests {
         assert!(default_instructions_for("code").is_none());
         assert!(default_instructions_for("custom").is_none());
     }
+
+    const COMMANDS: &[SlashCommandDef] = &[
+        SlashCommandDef::new("review"),
+        SlashCommandDef::new("speckit.auto"),
+        SlashCommandDef::new("speckit.audit"),
+        SlashCommandDef::new("speckit.implement"),
+        SlashCommandDef::deprecated("spec-auto", "speckit.auto"),
+    ];
+
+    #[test]
+    fn resolves_configured_alias() {
+        let aliases = HashMap::from([("sa".to_string(), "speckit.auto".to_string())]);
+        assert_eq!(
+            resolve_slash_command("sa", COMMANDS, &aliases),
+            Ok(SlashResolution {
+                command: "speckit.auto".to_string(),
+                kind: SlashMatchKind::Alias,
+                deprecation: None,
+            })
+        );
+        // Exact command names take precedence over an alias of the same name.
+        let shadowing = HashMap::from([("review".to_string(), "speckit.audit".to_string())]);
+        assert_eq!(
+            resolve_slash_command("review", COMMANDS, &shadowing).map(|r| r.kind),
+            Ok(SlashMatchKind::Exact)
+        );
+    }
+
+    #[test]
+    fn resolves_unique_segment_prefix() {
+        assert_eq!(
+            resolve_slash_command("spec.imp", COMMANDS, &HashMap::new()),
+            Ok(SlashResolution {
+                command: "speckit.implement".to_string(),
+                kind: SlashMatchKind::Prefix,
+                deprecation: None,
+            })
+        );
+    }
+
+    #[test]
+    fn ambiguous_prefix_lists_candidates() {
+        let err = resolve_slash_command("spec.a", COMMANDS, &HashMap::new()).unwrap_err();
+        assert_eq!(
+            err,
+            SlashResolveError::Ambiguous {
+                input: "spec.a".to_string(),
+                candidates: vec!["speckit.auto".to_string(), "speckit.audit".to_string()],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "ambiguous command /spec.a; did you mean /speckit.auto, /speckit.audit?"
+        );
+    }
+
+    #[test]
+    fn deprecated_command_resolves_to_replacement_with_notice() {
+        let resolution = resolve_slash_command("spec-auto", COMMANDS, &HashMap::new()).unwrap();
+        assert_eq!(
+            resolution,
+            SlashResolution {
+                command: "speckit.auto".to_string(),
+                kind: SlashMatchKind::Exact,
+                deprecation: Some(Deprecation {
+                    old: "spec-auto".to_string(),
+                    new: "speckit.auto".to_string(),
+                }),
+            }
+        );
+        assert_eq!(
+            resolution.deprecation.unwrap().notice(),
+            "/spec-auto is deprecated; use /speckit.auto instead."
+        );
+        // Deprecated names are not offered as prefix candidates.
+        assert_eq!(
+            resolve_slash_command("spec-a", COMMANDS, &HashMap::new()),
+            Err(SlashResolveError::Unknown("spec-a".to_string()))
+        );
+    }
 }
@@ -1,4 +1,6 @@
 use base64::Engine;
+use chrono::DateTime;
+use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
@@ -28,6 +30,21 @@ impl TokenData {
             .as_deref()
             .is_some_and(|email| email.trim().to_ascii_lowercase().ends_with("@openai.com"))
     }
+
+    /// Expiry from the access token's `exp` claim, if it is a JWT carrying one.
+    pub fn access_token_expires_at(&self) -> Option<DateTime<Utc>> {
+        #[derive(Deserialize)]
+        struct ExpClaim {
+            exp: Option<i64>,
+        }
+
+        let payload_b64 = self.access_token.split('.').nth(1)?;
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(payload_b64)
+            .ok()?;
+        let claims: ExpClaim = serde_json::from_slice(&payload).ok()?;
+        DateTime::from_timestamp(claims.exp?, 0)
+    }
 }
 
 /// Flat subset of useful claims in id_token from auth.json.
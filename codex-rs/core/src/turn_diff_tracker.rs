@@ -1,8 +1,10 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
+use std::time::SystemTime;
 
 use anyhow::Context;
 use anyhow::Result;
@@ -29,6 +31,9 @@ struct BaselineFileInfo {
 /// 2. Keep a stable internal filename (uuid) per external path for rename tracking.
 /// 3. To compute the aggregated unified diff, compare each baseline snapshot to the current file on disk entirely in-memory
 ///    using the `similar` crate and emit unified diffs with rewritten external paths.
+/// 4. Per-file diffs are cached together with the file's on-disk state (size, mtime, mode) and only recomputed for files
+///    touched by a change or whose on-disk state moved since the last snapshot, so the cost of each snapshot is a stat
+///    per tracked file plus a diff per changed one rather than a diff of everything accumulated in the turn.
 #[derive(Default)]
 pub struct TurnDiffTracker {
     /// Map external path -> internal filename (uuid).
@@ -40,6 +45,39 @@ pub struct TurnDiffTracker {
     temp_name_to_current_path: HashMap<String, PathBuf>,
     /// Cache of known git worktree roots to avoid repeated filesystem walks.
     git_root_cache: Vec<PathBuf>,
+    /// Internal filename -> rendered diff as of the last snapshot.
+    file_diffs: HashMap<String, CachedFileDiff>,
+    /// Internal filenames touched since the last snapshot.
+    dirty: HashSet<String>,
+    /// Aggregated diff from the last snapshot; `None` when it must be rebuilt.
+    cached_snapshot: Option<Option<String>>,
+}
+
+struct CachedFileDiff {
+    sort_key: String,
+    diff: String,
+    /// State of the current path when `diff` was rendered.
+    disk_state: Option<DiskState>,
+}
+
+/// Cheap fingerprint of a file on disk, used to notice edits made without a
+/// corresponding [`TurnDiffTracker::push_change`].
+#[derive(Clone, PartialEq, Eq)]
+struct DiskState {
+    len: u64,
+    modified: Option<SystemTime>,
+    mode: Option<FileMode>,
+}
+
+impl DiskState {
+    fn of(path: &Path) -> Option<Self> {
+        let meta = fs::symlink_metadata(path).ok()?;
+        Some(Self {
+            len: meta.len(),
+            modified: meta.modified().ok(),
+            mode: file_mode_for_path(path),
+        })
+    }
 }
 
 impl TurnDiffTracker {
@@ -53,78 +91,95 @@ impl TurnDiffTracker {
     /// - Also updates internal mappings for move/rename events.
     pub fn on_patch_begin(&mut self, changes: &HashMap<PathBuf, FileChange>) {
         for (path, change) in changes.iter() {
-            // Ensure a stable internal filename exists for this external path.
-            if !self.external_to_temp_name.contains_key(path) {
-                let internal = Uuid::new_v4().to_string();
-                self.external_to_temp_name
-                    .insert(path.clone(), internal.clone());
-                self.temp_name_to_current_path
-                    .insert(internal.clone(), path.clone());
-
-                // If the file exists on disk now, snapshot as baseline; else leave missing to represent /dev/null.
-                let baseline_file_info = if path.exists() {
-                    let mode = file_mode_for_path(path);
-                    let mode_val = mode.unwrap_or(FileMode::Regular);
-                    let content = blob_bytes(path, mode_val).unwrap_or_default();
-                    let oid = if mode == Some(FileMode::Symlink) {
-                        format!("{:x}", git_blob_sha1_hex_bytes(&content))
-                    } else {
-                        self.git_blob_oid_for_path(path)
-                            .unwrap_or_else(|| format!("{:x}", git_blob_sha1_hex_bytes(&content)))
-                    };
-                    Some(BaselineFileInfo {
-                        path: path.clone(),
-                        content,
-                        mode: mode_val,
-                        oid,
-                    })
+            self.push_change(path, change);
+        }
+    }
+
+    /// Records a single file change that is about to be applied and marks
+    /// that file for re-diffing on the next [`Self::snapshot`].
+    pub fn push_change(&mut self, path: &Path, change: &FileChange) {
+        // Ensure a stable internal filename exists for this external path.
+        if !self.external_to_temp_name.contains_key(path) {
+            let internal = Uuid::new_v4().to_string();
+            self.external_to_temp_name
+                .insert(path.to_path_buf(), internal.clone());
+            self.temp_name_to_current_path
+                .insert(internal.clone(), path.to_path_buf());
+
+            // If the file exists on disk now, snapshot as baseline; else leave missing to represent /dev/null.
+            let baseline_file_info = if path.exists() {
+                let mode = file_mode_for_path(path);
+                let mode_val = mode.unwrap_or(FileMode::Regular);
+                let content = blob_bytes(path, mode_val).unwrap_or_default();
+                let oid = if mode == Some(FileMode::Symlink) {
+                    format!("{:x}", git_blob_sha1_hex_bytes(&content))
                 } else {
-                    Some(BaselineFileInfo {
-                        path: path.clone(),
-                        content: vec![],
-                        mode: FileMode::Regular,
-                        oid: ZERO_OID.to_string(),
-                    })
+                    self.git_blob_oid_for_path(path)
+                        .unwrap_or_else(|| format!("{:x}", git_blob_sha1_hex_bytes(&content)))
                 };
+                Some(BaselineFileInfo {
+                    path: path.to_path_buf(),
+                    content,
+                    mode: mode_val,
+                    oid,
+                })
+            } else {
+                Some(BaselineFileInfo {
+                    path: path.to_path_buf(),
+                    content: vec![],
+                    mode: FileMode::Regular,
+                    oid: ZERO_OID.to_string(),
+                })
+            };
 
-                if let Some(baseline_file_info) = baseline_file_info {
-                    self.baseline_file_info
-                        .insert(internal.clone(), baseline_file_info);
-                }
+            if let Some(baseline_file_info) = baseline_file_info {
+                self.baseline_file_info.insert(internal, baseline_file_info);
             }
+        }
+
+        // Track rename/move in current mapping if provided in an Update.
+        if let FileChange::Update {
+            move_path: Some(dest),
+            ..
+        } = change
+        {
+            let uuid_filename = match self.external_to_temp_name.get(path) {
+                Some(i) => i.clone(),
+                None => {
+                    // This should be rare, but if we haven't mapped the source, create it with no baseline.
+                    let i = Uuid::new_v4().to_string();
+                    self.baseline_file_info.insert(
+                        i.clone(),
+                        BaselineFileInfo {
+                            path: path.to_path_buf(),
+                            content: vec![],
+                            mode: FileMode::Regular,
+                            oid: ZERO_OID.to_string(),
+                        },
+                    );
+                    i
+                }
+            };
+            // Update current external mapping for temp file name.
+            self.temp_name_to_current_path
+                .insert(uuid_filename.clone(), dest.clone());
+            // Update forward file_mapping: external current -> internal name.
+            self.external_to_temp_name.remove(path);
+            self.external_to_temp_name
+                .insert(dest.clone(), uuid_filename);
+        };
 
-            // Track rename/move in current mapping if provided in an Update.
-            if let FileChange::Update {
+        let current = match change {
+            FileChange::Update {
                 move_path: Some(dest),
                 ..
-            } = change
-            {
-                let uuid_filename = match self.external_to_temp_name.get(path) {
-                    Some(i) => i.clone(),
-                    None => {
-                        // This should be rare, but if we haven't mapped the source, create it with no baseline.
-                        let i = Uuid::new_v4().to_string();
-                        self.baseline_file_info.insert(
-                            i.clone(),
-                            BaselineFileInfo {
-                                path: path.clone(),
-                                content: vec![],
-                                mode: FileMode::Regular,
-                                oid: ZERO_OID.to_string(),
-                            },
-                        );
-                        i
-                    }
-                };
-                // Update current external mapping for temp file name.
-                self.temp_name_to_current_path
-                    .insert(uuid_filename.clone(), dest.clone());
-                // Update forward file_mapping: external current -> internal name.
-                self.external_to_temp_name.remove(path);
-                self.external_to_temp_name
-                    .insert(dest.clone(), uuid_filename);
-            };
+            } => dest.as_path(),
+            _ => path,
+        };
+        if let Some(internal) = self.external_to_temp_name.get(current) {
+            self.dirty.insert(internal.clone());
         }
+        self.cached_snapshot = None;
     }
 
     fn get_path_for_internal(&self, internal: &str) -> Option<PathBuf> {
@@ -219,34 +274,161 @@ impl TurnDiffTracker {
         if s.len() == 40 { Some(s) } else { None }
     }
 
-    /// Recompute the aggregated unified diff by comparing all of the in-memory snapshots that were
-    /// collected before the first time they were touched by apply_patch during this turn with
-    /// the current repo state.
+    /// The aggregated unified diff comparing the in-memory snapshots that were collected before the
+    /// first time each file was touched by apply_patch during this turn with the repo state; see
+    /// [`Self::snapshot`].
     pub fn get_unified_diff(&mut self) -> Result<Option<String>> {
-        let mut aggregated = String::new();
+        Ok(self.snapshot())
+    }
+
+    /// The aggregated unified diff for the tracked files, or `None` when
+    /// nothing changed. Only files touched by a change or modified on disk
+    /// since the previous snapshot are re-diffed; otherwise the cached result
+    /// is returned.
+    pub fn snapshot(&mut self) -> Option<String> {
+        let stale: Vec<String> = self
+            .baseline_file_info
+            .keys()
+            .filter(|internal| {
+                let current = self.get_path_for_internal(internal);
+                match (self.file_diffs.get(*internal), current) {
+                    (Some(cached), Some(path)) => cached.disk_state != DiskState::of(&path),
+                    (None, Some(_)) => true,
+                    (_, None) => false,
+                }
+            })
+            .cloned()
+            .collect();
+        self.dirty.extend(stale);
+
+        if let Some(cached) = &self.cached_snapshot
+            && self.dirty.is_empty()
+        {
+            return cached.clone();
+        }
+
+        for internal in std::mem::take(&mut self.dirty) {
+            let current = self.get_path_for_internal(&internal);
+            // Read the state before diffing so a write racing with the diff
+            // is picked up by the next snapshot.
+            let disk_state = current.as_deref().and_then(DiskState::of);
+            let sort_key = current
+                .map(|p| self.relative_to_git_root_str(&p))
+                .unwrap_or_default();
+            let diff = self.get_file_diff(&internal);
+            self.file_diffs.insert(
+                internal,
+                CachedFileDiff {
+                    sort_key,
+                    diff,
+                    disk_state,
+                },
+            );
+        }
 
-        // Compute diffs per tracked internal file in a stable order by external path.
+        // Sort lexicographically by full repo-relative path to match git behavior.
+        let mut entries: Vec<&CachedFileDiff> = self.file_diffs.values().collect();
+        entries.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+        let aggregated = aggregate_file_diffs(entries.into_iter().map(|entry| entry.diff.as_str()));
+
+        self.cached_snapshot = Some(aggregated.clone());
+        aggregated
+    }
+
+    /// Recomputes the aggregated diff from scratch, ignoring the per-file
+    /// cache. Used to check that incremental snapshots stay equivalent.
+    #[cfg(test)]
+    fn recompute_unified_diff(&mut self) -> Option<String> {
         let mut baseline_file_names: Vec<String> =
             self.baseline_file_info.keys().cloned().collect();
-        // Sort lexicographically by full repo-relative path to match git behavior.
         baseline_file_names.sort_by_key(|internal| {
             self.get_path_for_internal(internal)
                 .map(|p| self.relative_to_git_root_str(&p))
                 .unwrap_or_default()
         });
+        let diffs: Vec<String> = baseline_file_names
+            .iter()
+            .map(|internal| self.get_file_diff(internal))
+            .collect();
+        aggregate_file_diffs(diffs.iter().map(String::as_str))
+    }
+
+    /// The aggregated unified diff for the turn, or an empty string when
+    /// nothing changed.
+    pub fn as_unified_diff(&mut self) -> String {
+        self.get_unified_diff().ok().flatten().unwrap_or_default()
+    }
 
-        for internal in baseline_file_names {
-            aggregated.push_str(self.get_file_diff(&internal).as_str());
-            if !aggregated.ends_with('\n') {
-                aggregated.push('\n');
+    /// The turn's net effect on each touched file, sorted by path. Additions
+    /// are keyed by their final path; deletions and updates by the path the
+    /// file had when first seen, with renames reported through `move_path`.
+    ///
+    /// [`FileChange`] can only carry text, so changes involving non-UTF-8
+    /// content are always reported as [`FileChange::Update`] with empty
+    /// contents and a git-style `Binary files differ` section (including any
+    /// `new file`/`deleted file` header) as the `unified_diff`.
+    pub fn as_file_changes(&mut self) -> Vec<(PathBuf, FileChange)> {
+        let internal_names: Vec<String> = self.baseline_file_info.keys().cloned().collect();
+        let mut changes = Vec::new();
+
+        for internal in internal_names {
+            let Some(current_path) = self.get_path_for_internal(&internal) else {
+                continue;
+            };
+            let Some(baseline) = self.baseline_file_info.get(&internal) else {
+                continue;
+            };
+            let baseline_path = baseline.path.clone();
+            let left_bytes = (baseline.oid != ZERO_OID).then(|| baseline.content.clone());
+            let current_mode = file_mode_for_path(&current_path).unwrap_or(FileMode::Regular);
+            let right_bytes = blob_bytes(&current_path, current_mode);
+            let moved = current_path != baseline_path;
+
+            if left_bytes == right_bytes && !(moved && right_bytes.is_some()) {
+                continue;
             }
-        }
 
-        if aggregated.trim().is_empty() {
-            Ok(None)
-        } else {
-            Ok(Some(aggregated))
+            let left_text = left_bytes
+                .as_deref()
+                .map(|bytes| std::str::from_utf8(bytes).ok());
+            let right_text = right_bytes
+                .as_deref()
+                .map(|bytes| std::str::from_utf8(bytes).ok());
+
+            let change = match (left_text, right_text) {
+                (None, None) => continue,
+                (None, Some(Some(content))) => {
+                    changes.push((
+                        current_path,
+                        FileChange::Add {
+                            content: content.to_string(),
+                        },
+                    ));
+                    continue;
+                }
+                (Some(Some(_)), None) => FileChange::Delete,
+                (Some(Some(original)), Some(Some(new))) => FileChange::Update {
+                    unified_diff: similar::TextDiff::from_lines(original, new)
+                        .unified_diff()
+                        .context_radius(3)
+                        .to_string(),
+                    move_path: moved.then(|| current_path.clone()),
+                    original_content: original.to_string(),
+                    new_content: new.to_string(),
+                },
+                // At least one side is binary.
+                _ => FileChange::Update {
+                    unified_diff: self.get_file_diff(&internal),
+                    move_path: (moved && right_bytes.is_some()).then(|| current_path.clone()),
+                    original_content: String::new(),
+                    new_content: String::new(),
+                },
+            };
+            changes.push((baseline_path, change));
         }
+
+        changes.sort_by(|(a, _), (b, _)| a.cmp(b));
+        changes
     }
 
     fn get_file_diff(&mut self, internal_file_name: &str) -> String {
@@ -404,6 +586,22 @@ impl std::fmt::Display for FileMode {
     }
 }
 
+/// Joins per-file diffs in order, ensuring each ends with a newline.
+fn aggregate_file_diffs<'a>(diffs: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut aggregated = String::new();
+    for diff in diffs {
+        aggregated.push_str(diff);
+        if !aggregated.ends_with('\n') {
+            aggregated.push('\n');
+        }
+    }
+    if aggregated.trim().is_empty() {
+        None
+    } else {
+        Some(aggregated)
+    }
+}
+
 #[cfg(unix)]
 fn file_mode_for_path(path: &Path) -> Option<FileMode> {
     use std::os::unix::fs::PermissionsExt;
@@ -784,6 +982,212 @@ index {left_oid_b}..{ZERO_OID}
         assert_eq!(combined, expected);
     }
 
+    #[test]
+    fn file_changes_cover_add_update_move_delete_and_binary() {
+        let dir = tempdir().unwrap();
+        let added = dir.path().join("added.txt");
+        let edited = dir.path().join("edited.txt");
+        let src = dir.path().join("src.txt");
+        let dest = dir.path().join("dest.txt");
+        let deleted = dir.path().join("deleted.txt");
+        let binary = dir.path().join("image.bin");
+        fs::write(&edited, "one\ntwo\n").unwrap();
+        fs::write(&src, "keep\n").unwrap();
+        fs::write(&deleted, "bye\n").unwrap();
+        fs::write(&binary, [0xff, 0xfe, 0x00]).unwrap();
+
+        let update = |move_path: Option<PathBuf>| FileChange::Update {
+            unified_diff: String::new(),
+            move_path,
+            original_content: String::new(),
+            new_content: String::new(),
+        };
+        let mut acc = TurnDiffTracker::new();
+        acc.on_patch_begin(&HashMap::from([
+            (
+                added.clone(),
+                FileChange::Add {
+                    content: "new\n".to_string(),
+                },
+            ),
+            (edited.clone(), update(None)),
+            (src.clone(), update(Some(dest.clone()))),
+            (deleted.clone(), FileChange::Delete),
+        ]));
+        fs::write(&added, "new\n").unwrap();
+        fs::write(&edited, "one\n2\n").unwrap();
+        fs::rename(&src, &dest).unwrap();
+        fs::remove_file(&deleted).unwrap();
+
+        // A second edit to the same file within the turn is folded in.
+        acc.on_patch_begin(&HashMap::from([
+            (edited.clone(), update(None)),
+            (binary.clone(), update(None)),
+        ]));
+        fs::write(&edited, "one\n2\nthree\n").unwrap();
+        fs::write(&binary, [0x00, 0x01]).unwrap();
+
+        let changes = acc.as_file_changes();
+        let binary_diff = match &changes[3] {
+            (path, FileChange::Update { unified_diff, .. }) if path == &binary => {
+                unified_diff.clone()
+            }
+            other => panic!("expected binary update, got {other:?}"),
+        };
+        assert!(binary_diff.contains("Binary files differ"));
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    added,
+                    FileChange::Add {
+                        content: "new\n".to_string(),
+                    },
+                ),
+                (deleted, FileChange::Delete),
+                (
+                    edited,
+                    FileChange::Update {
+                        unified_diff: "@@ -1,2 +1,3 @@\n one\n-two\n+2\n+three\n".to_string(),
+                        move_path: None,
+                        original_content: "one\ntwo\n".to_string(),
+                        new_content: "one\n2\nthree\n".to_string(),
+                    },
+                ),
+                (
+                    binary,
+                    FileChange::Update {
+                        unified_diff: binary_diff,
+                        move_path: None,
+                        original_content: String::new(),
+                        new_content: String::new(),
+                    },
+                ),
+                (
+                    src,
+                    FileChange::Update {
+                        unified_diff: String::new(),
+                        move_path: Some(dest),
+                        original_content: "keep\n".to_string(),
+                        new_content: "keep\n".to_string(),
+                    },
+                ),
+            ]
+        );
+        assert!(acc.as_unified_diff().contains("diff --git"));
+    }
+
+    #[test]
+    fn incremental_snapshot_matches_full_recompute() {
+        let dir = tempdir().unwrap();
+        let added = dir.path().join("added.txt");
+        let edited = dir.path().join("edited.txt");
+        let src = dir.path().join("src.txt");
+        let dest = dir.path().join("dest.txt");
+        let deleted = dir.path().join("deleted.txt");
+        let binary = dir.path().join("image.bin");
+        fs::write(&edited, "one\ntwo\n").unwrap();
+        fs::write(&src, "keep\n").unwrap();
+        fs::write(&deleted, "bye\n").unwrap();
+        fs::write(&binary, [0xff, 0xfe, 0x00]).unwrap();
+
+        let update = |move_path: Option<PathBuf>| FileChange::Update {
+            unified_diff: String::new(),
+            move_path,
+            original_content: String::new(),
+            new_content: String::new(),
+        };
+        type Step<'a> = (&'a Path, FileChange, Box<dyn Fn() + 'a>);
+        let steps: Vec<Step> = vec![
+            (
+                &added,
+                FileChange::Add {
+                    content: "new\n".to_string(),
+                },
+                Box::new(|| fs::write(&added, "new\n").unwrap()),
+            ),
+            (
+                &edited,
+                update(None),
+                Box::new(|| fs::write(&edited, "one\n2\n").unwrap()),
+            ),
+            (
+                &src,
+                update(Some(dest.clone())),
+                Box::new(|| fs::rename(&src, &dest).unwrap()),
+            ),
+            (
+                &deleted,
+                FileChange::Delete,
+                Box::new(|| fs::remove_file(&deleted).unwrap()),
+            ),
+            (
+                &dest,
+                update(None),
+                Box::new(|| fs::write(&dest, "keep\nmore\n").unwrap()),
+            ),
+            (
+                &binary,
+                update(None),
+                Box::new(|| fs::write(&binary, [0x00, 0x01]).unwrap()),
+            ),
+            (
+                &edited,
+                update(None),
+                Box::new(|| fs::write(&edited, "one\n2\nthree\n").unwrap()),
+            ),
+            (
+                &added,
+                FileChange::Delete,
+                Box::new(|| fs::remove_file(&added).unwrap()),
+            ),
+        ];
+
+        let mut acc = TurnDiffTracker::new();
+        assert_eq!(acc.snapshot(), None);
+        for (path, change, apply) in steps {
+            acc.push_change(path, &change);
+            apply();
+            let incremental = acc.snapshot();
+            assert_eq!(incremental, acc.recompute_unified_diff());
+            // Without new changes the cached snapshot is returned as-is.
+            assert_eq!(acc.snapshot(), incremental);
+        }
+        assert!(acc.snapshot().is_some());
+    }
+
+    #[test]
+    fn snapshot_picks_up_edits_made_outside_push_change() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("file.txt");
+        fs::write(&file, "one\n").unwrap();
+
+        let mut acc = TurnDiffTracker::new();
+        acc.push_change(
+            &file,
+            &FileChange::Update {
+                unified_diff: String::new(),
+                move_path: None,
+                original_content: String::new(),
+                new_content: String::new(),
+            },
+        );
+        fs::write(&file, "two\n").unwrap();
+        let first = acc.snapshot();
+        assert_eq!(first, acc.recompute_unified_diff());
+
+        // A different length guarantees a new fingerprint even on
+        // filesystems with coarse mtimes.
+        fs::write(&file, "three\n").unwrap();
+        let second = acc.snapshot();
+        assert_ne!(second, first);
+        assert_eq!(second, acc.recompute_unified_diff());
+
+        fs::remove_file(&file).unwrap();
+        assert_eq!(acc.snapshot(), acc.recompute_unified_diff());
+    }
+
     #[test]
     fn binary_files_differ_update() {
         let dir = tempdir().unwrap();
@@ -70,6 +70,9 @@ where
         stream_max_retries: Some(0),
         stream_idle_timeout_ms: Some(5_000),
         agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
         requires_openai_auth: false,
         openrouter: None,
     };
@@ -207,6 +210,7 @@ async fn omits_reasoning_when_none_present() {
 
     assert_eq!(assistant["content"], Value::String("a1".into()));
     assert!(assistant.get("reasoning").is_none());
+    assert_eq!(body["stream_options"], json!({ "include_usage": true }));
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
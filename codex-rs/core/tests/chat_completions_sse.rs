@@ -11,6 +11,7 @@ use codex_core::ResponseEvent;
 use codex_core::ResponseItem;
 use codex_core::WireApi;
 use codex_core::debug_logger::DebugLogger;
+use codex_core::protocol::TokenUsage;
 use codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR;
 use core_test_support::load_default_config_for_test;
 use futures::StreamExt;
@@ -53,6 +54,9 @@ async fn run_stream(sse_body: &str) -> Vec<ResponseEvent> {
         stream_max_retries: Some(0),
         stream_idle_timeout_ms: Some(5_000),
         agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
         requires_openai_auth: false,
         openrouter: None,
     };
@@ -332,3 +336,58 @@ async fn streams_reasoning_before_tool_call() {
 
     assert!(matches!(events[3], ResponseEvent::Completed { .. }));
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn streams_usage_deltas_that_sum_to_final_usage() {
+    if network_disabled() {
+        println!(
+            "Skipping test because it cannot execute when network is disabled in a Codex sandbox."
+        );
+        return;
+    }
+
+    let sse = concat!(
+        "data: {\"choices\":[{\"delta\":{\"content\":\"he\"}}],\"usage\":{\"prompt_tokens\":10,\"completion_tokens\":1,\"total_tokens\":11}}\n\n",
+        "data: {\"choices\":[{\"delta\":{\"content\":\"llo\"}}],\"usage\":{\"prompt_tokens\":10,\"completion_tokens\":3,\"total_tokens\":13}}\n\n",
+        "data: {\"choices\":[{\"delta\":{}}],\"usage\":null}\n\n",
+        "data: {\"choices\":[],\"usage\":{\"prompt_tokens\":10,\"completion_tokens\":4,\"total_tokens\":14,\"prompt_tokens_details\":{\"cached_tokens\":6},\"completion_tokens_details\":{\"reasoning_tokens\":2}}}\n\n",
+        "data: [DONE]\n\n",
+    );
+
+    let events = run_stream(sse).await;
+
+    let deltas: Vec<&TokenUsage> = events
+        .iter()
+        .filter_map(|ev| match ev {
+            ResponseEvent::UsageDelta { usage_delta } => Some(usage_delta),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(deltas.len(), 3, "unexpected events: {events:?}");
+    assert_eq!(deltas[1].output_tokens, 2);
+    assert_eq!(deltas[1].input_tokens, 0);
+
+    let summed = deltas.iter().fold(TokenUsage::default(), |mut acc, d| {
+        acc.input_tokens += d.input_tokens;
+        acc.cached_input_tokens += d.cached_input_tokens;
+        acc.output_tokens += d.output_tokens;
+        acc.reasoning_output_tokens += d.reasoning_output_tokens;
+        acc.total_tokens += d.total_tokens;
+        acc
+    });
+
+    let Some(ResponseEvent::Completed { token_usage, .. }) = events.last() else {
+        panic!("expected Completed last, got {events:?}");
+    };
+    assert_eq!(
+        token_usage.as_ref(),
+        Some(&TokenUsage {
+            input_tokens: 10,
+            cached_input_tokens: 6,
+            output_tokens: 4,
+            reasoning_output_tokens: 2,
+            total_tokens: 14,
+        })
+    );
+    assert_eq!(Some(&summed), token_usage.as_ref());
+}
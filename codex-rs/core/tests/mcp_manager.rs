@@ -5,9 +5,12 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::Once;
+use std::time::Duration;
 
 use codex_core::config_types::McpServerConfig;
 use codex_core::mcp_connection_manager::McpConnectionManager;
+use codex_core::mcp_connection_manager::McpServerState;
+use codex_core::mcp_connection_manager::McpToolCallTimeout;
 
 static BUILD_TEST_SERVER: Once = Once::new();
 
@@ -35,11 +38,9 @@ fn ensure_test_server() -> PathBuf {
     let root = workspace_root();
     let server = server_bin_path();
 
+    // Always invoke cargo (a no-op when up to date) so a stale binary from an
+    // older checkout is never used.
     BUILD_TEST_SERVER.call_once(|| {
-        if server.exists() {
-            return;
-        }
-
         let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
         let status = Command::new(cargo)
             .current_dir(root)
@@ -72,6 +73,9 @@ async fn mcp_manager_skips_slow_server_on_timeout() {
         startup_timeout_sec: None,
         startup_timeout_ms: Some(100),
         tool_timeout_sec: None,
+        reconnect_attempts: None,
+        lazy: false,
+        tools: Vec::new(),
     };
     // Fast server responds immediately
     let fast_cfg = McpServerConfig {
@@ -81,6 +85,9 @@ async fn mcp_manager_skips_slow_server_on_timeout() {
         startup_timeout_sec: None,
         startup_timeout_ms: Some(500),
         tool_timeout_sec: None,
+        reconnect_attempts: None,
+        lazy: false,
+        tools: Vec::new(),
     };
 
     let mut servers = HashMap::new();
@@ -116,6 +123,9 @@ async fn mcp_manager_respects_extended_startup_timeout() {
         startup_timeout_sec: None,
         startup_timeout_ms: Some(500),
         tool_timeout_sec: None,
+        reconnect_attempts: None,
+        lazy: false,
+        tools: Vec::new(),
     };
     let mut servers = HashMap::new();
     servers.insert("slow_ok".to_string(), slow_ok);
@@ -128,3 +138,238 @@ async fn mcp_manager_respects_extended_startup_timeout() {
     let tools = mgr.list_all_tools();
     assert!(tools.keys().any(|k| k.starts_with("slow_ok__")));
 }
+
+#[tokio::test]
+async fn mcp_manager_reconnects_after_server_exits() {
+    let server = ensure_test_server();
+    let temp = tempfile::TempDir::new().unwrap();
+    let marker = temp.path().join("crashed");
+
+    let cfg = McpServerConfig {
+        command: server.to_string_lossy().to_string(),
+        args: vec![],
+        env: Some(HashMap::from([(
+            "CRASH_ONCE_FILE".to_string(),
+            marker.to_string_lossy().to_string(),
+        )])),
+        startup_timeout_sec: None,
+        startup_timeout_ms: Some(2_000),
+        tool_timeout_sec: None,
+        reconnect_attempts: Some(3),
+        lazy: false,
+        tools: Vec::new(),
+    };
+    let mut servers = HashMap::new();
+    servers.insert("flaky".to_string(), cfg);
+
+    let (mgr, errs) = McpConnectionManager::new(servers, std::collections::HashSet::new())
+        .await
+        .expect("manager creation should not fail");
+    assert!(errs.is_empty(), "no errors expected, got: {errs:?}");
+    assert_eq!(mgr.server_state("flaky"), Some(McpServerState::Connected));
+
+    let args = Some(serde_json::json!({ "text": "hello" }));
+
+    // The server exits while handling this call.
+    let err = mgr
+        .call_tool("flaky", "echo", args.clone(), None)
+        .await
+        .expect_err("call should fail when the server exits");
+    assert!(marker.exists(), "server should have crashed: {err:#}");
+
+    // Calls during the reconnect window fail fast.
+    let err = mgr
+        .call_tool("flaky", "echo", args.clone(), None)
+        .await
+        .expect_err("call should fail while reconnecting");
+    assert!(format!("{err:#}").contains("reconnecting"), "{err:#}");
+
+    let mut state = mgr.server_state("flaky");
+    for _ in 0..100 {
+        if state == Some(McpServerState::Connected) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        state = mgr.server_state("flaky");
+    }
+    assert_eq!(state, Some(McpServerState::Connected));
+
+    let result = mgr
+        .call_tool("flaky", "echo", args, None)
+        .await
+        .expect("call should succeed after reconnecting");
+    let text = serde_json::to_value(&result.content).unwrap();
+    assert_eq!(text[0]["text"], "hello");
+}
+
+#[tokio::test]
+async fn mcp_manager_times_out_slow_tool_call_and_stays_usable() {
+    let server = ensure_test_server();
+
+    let cfg = McpServerConfig {
+        command: server.to_string_lossy().to_string(),
+        args: vec![],
+        env: None,
+        startup_timeout_sec: None,
+        startup_timeout_ms: Some(2_000),
+        tool_timeout_sec: None,
+        reconnect_attempts: None,
+        lazy: false,
+        tools: Vec::new(),
+    };
+    let mut servers = HashMap::new();
+    servers.insert("slowtool".to_string(), cfg);
+
+    let (mgr, errs) = McpConnectionManager::new(servers, std::collections::HashSet::new())
+        .await
+        .expect("manager creation should not fail");
+    assert!(errs.is_empty(), "no errors expected, got: {errs:?}");
+
+    let err = mgr
+        .call_tool(
+            "slowtool",
+            "echo",
+            Some(serde_json::json!({ "text": "late", "delay_ms": 500 })),
+            Some(Duration::from_millis(100)),
+        )
+        .await
+        .expect_err("slow call should time out");
+    let timeout = err
+        .downcast_ref::<McpToolCallTimeout>()
+        .unwrap_or_else(|| panic!("expected a timeout error, got: {err:#}"));
+    assert_eq!(timeout.server, "slowtool");
+    assert_eq!(timeout.timeout, Duration::from_millis(100));
+
+    // The late reply is discarded and the next call gets its own answer.
+    let result = mgr
+        .call_tool(
+            "slowtool",
+            "echo",
+            Some(serde_json::json!({ "text": "fast" })),
+            None,
+        )
+        .await
+        .expect("fast call should succeed");
+    let content = serde_json::to_value(&result.content).unwrap();
+    assert_eq!(content[0]["text"], "fast");
+    assert_eq!(
+        mgr.server_state("slowtool"),
+        Some(McpServerState::Connected)
+    );
+}
+
+#[tokio::test]
+async fn mcp_manager_defers_lazy_server_until_first_call() {
+    let server = ensure_test_server();
+    let temp = tempfile::TempDir::new().unwrap();
+    let marker = temp.path().join("started");
+
+    let cfg = McpServerConfig {
+        command: server.to_string_lossy().to_string(),
+        args: vec![],
+        env: Some(HashMap::from([(
+            "STARTED_MARKER_FILE".to_string(),
+            marker.to_string_lossy().to_string(),
+        )])),
+        startup_timeout_sec: None,
+        startup_timeout_ms: Some(2_000),
+        tool_timeout_sec: None,
+        reconnect_attempts: None,
+        lazy: true,
+        tools: vec!["echo".to_string()],
+    };
+    let mut servers = HashMap::new();
+    servers.insert("lazy".to_string(), cfg);
+
+    let (mgr, errs) = McpConnectionManager::new(servers, std::collections::HashSet::new())
+        .await
+        .expect("manager creation should not fail");
+    assert!(errs.is_empty(), "no errors expected, got: {errs:?}");
+
+    // Declared tools are advertised without spawning the server.
+    assert!(mgr.list_all_tools().contains_key("lazy__echo"));
+    assert_eq!(mgr.server_state("lazy"), Some(McpServerState::NotStarted));
+    assert!(!marker.exists(), "lazy server spawned at startup");
+
+    let result = mgr
+        .call_tool(
+            "lazy",
+            "echo",
+            Some(serde_json::json!({ "text": "wake up" })),
+            None,
+        )
+        .await
+        .expect("first call should start the server");
+    let content = serde_json::to_value(&result.content).unwrap();
+    assert_eq!(content[0]["text"], "wake up");
+    assert!(marker.exists());
+    assert_eq!(mgr.server_state("lazy"), Some(McpServerState::Connected));
+}
+
+#[tokio::test]
+async fn mcp_manager_reports_tool_collisions_and_dispatches_namespaced() {
+    let server = ensure_test_server();
+
+    let cfg = |prefix: &str| McpServerConfig {
+        command: server.to_string_lossy().to_string(),
+        args: vec![],
+        env: Some(HashMap::from([(
+            "ECHO_PREFIX".to_string(),
+            prefix.to_string(),
+        )])),
+        startup_timeout_sec: None,
+        startup_timeout_ms: Some(2_000),
+        tool_timeout_sec: None,
+        reconnect_attempts: None,
+        lazy: false,
+        tools: Vec::new(),
+    };
+    let mut servers = HashMap::new();
+    servers.insert("alpha".to_string(), cfg("alpha:"));
+    servers.insert("beta".to_string(), cfg("beta:"));
+
+    let (mgr, errs) = McpConnectionManager::new(servers, std::collections::HashSet::new())
+        .await
+        .expect("manager creation should not fail");
+    assert!(errs.is_empty(), "no errors expected, got: {errs:?}");
+
+    let catalog: Vec<(String, String, String)> = mgr
+        .tool_catalog()
+        .into_iter()
+        .map(|entry| (entry.server, entry.name, entry.namespaced_name))
+        .collect();
+    assert_eq!(
+        catalog,
+        vec![
+            (
+                "alpha".to_string(),
+                "echo".to_string(),
+                "alpha__echo".to_string()
+            ),
+            (
+                "beta".to_string(),
+                "echo".to_string(),
+                "beta__echo".to_string()
+            ),
+        ]
+    );
+
+    let collisions = mgr.tool_collisions();
+    assert_eq!(collisions.len(), 1, "{collisions:?}");
+    assert!(collisions[0].contains("`echo`"), "{collisions:?}");
+    assert!(collisions[0].contains("alpha, beta"), "{collisions:?}");
+
+    let args = Some(serde_json::json!({ "text": "hi" }));
+    let result = mgr
+        .call_tool_by_name("beta__echo", args.clone(), None)
+        .await
+        .expect("namespaced call should succeed");
+    let content = serde_json::to_value(&result.content).unwrap();
+    assert_eq!(content[0]["text"], "beta:hi");
+
+    let err = mgr
+        .call_tool_by_name("echo", args, None)
+        .await
+        .expect_err("bare name is ambiguous");
+    assert!(format!("{err:#}").contains("ambiguous"), "{err:#}");
+}
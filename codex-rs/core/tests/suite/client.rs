@@ -10,6 +10,8 @@ use codex_core::Prompt;
 use codex_core::ResponseItem;
 use codex_core::WireApi;
 use codex_core::built_in_model_providers;
+use codex_core::error::CodexErr;
+use codex_core::http_client::HttpError;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::InputItem;
 use codex_core::protocol::Op;
@@ -22,6 +24,7 @@ use serde_json::Value;
 use serde_json::json;
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tempfile::TempDir;
 use uuid::Uuid;
 use wiremock::Mock;
@@ -537,6 +540,9 @@ async fn azure_overrides_assign_properties_used_for_responses_url() {
         stream_max_retries: None,
         stream_idle_timeout_ms: None,
         agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
         requires_openai_auth: false,
         openrouter: None,
     };
@@ -615,6 +621,9 @@ async fn env_var_overrides_loaded_auth() {
         stream_max_retries: None,
         stream_idle_timeout_ms: None,
         agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
         requires_openai_auth: false,
         openrouter: None,
     };
@@ -820,6 +829,9 @@ async fn openrouter_metadata_is_forwarded_in_responses_payload() {
         stream_max_retries: Some(0),
         stream_idle_timeout_ms: Some(5_000),
         agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
         requires_openai_auth: false,
         openrouter: Some(OpenRouterConfig {
             provider: Some(OpenRouterProviderConfig {
@@ -884,3 +896,239 @@ async fn openrouter_metadata_is_forwarded_in_responses_payload() {
     assert_eq!(request_body["route"], json!({ "strategy": "balanced" }));
     assert_eq!(request_body["dry_run"], Value::Bool(true));
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn stream_request_times_out_when_server_never_responds() {
+    non_sandbox_test!();
+
+    // Accept connections but never write a response.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((stream, _)) = listener.accept().await {
+            held.push(stream);
+        }
+    });
+
+    let provider = ModelProviderInfo {
+        name: "hanging".into(),
+        base_url: Some(format!("http://{addr}/v1")),
+        env_key: None,
+        env_key_instructions: None,
+        wire_api: WireApi::Responses,
+        query_params: None,
+        http_headers: None,
+        env_http_headers: None,
+        request_max_retries: Some(0),
+        stream_max_retries: Some(0),
+        stream_idle_timeout_ms: Some(5_000),
+        agent_total_timeout_ms: None,
+        connect_timeout_ms: Some(1_000),
+        request_timeout_ms: Some(200),
+        proxy: None,
+        requires_openai_auth: false,
+        openrouter: None,
+    };
+
+    let codex_home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&codex_home);
+    config.model_provider_id = provider.name.clone();
+    config.model_provider = provider.clone();
+    let effort = config.model_reasoning_effort;
+    let summary = config.model_reasoning_summary;
+    let verbosity = config.model_text_verbosity;
+    let config = Arc::new(config);
+
+    let client = ModelClient::new(
+        Arc::clone(&config),
+        None,
+        provider,
+        effort,
+        summary,
+        verbosity,
+        Uuid::new_v4(),
+        Arc::new(std::sync::Mutex::new(
+            codex_core::debug_logger::DebugLogger::new(false).unwrap(),
+        )),
+    );
+
+    let mut prompt = Prompt::default();
+    prompt.input.push(ResponseItem::Message {
+        id: None,
+        role: "user".to_string(),
+        content: vec![ContentItem::InputText {
+            text: "hello".to_string(),
+        }],
+    });
+
+    let started = std::time::Instant::now();
+    let err = match client.stream(&prompt).await {
+        Ok(_) => panic!("expected the request to time out"),
+        Err(e) => e,
+    };
+    assert!(
+        matches!(
+            err,
+            CodexErr::Http(HttpError::Timeout { timeout }) if timeout == Duration::from_millis(200)
+        ),
+        "unexpected error: {err:?}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+/// Serve every request on a kept-alive connection with a chunked SSE body
+/// whose `response.completed` event arrives after a delay. Returns the base
+/// URL and a counter of accepted connections.
+#[expect(clippy::unwrap_used)]
+async fn slow_sse_server() -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let connections = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let accepted = Arc::clone(&connections);
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            accepted.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            tokio::spawn(async move {
+                let mut buf = Vec::new();
+                loop {
+                    // Read one request: headers, then a Content-Length body.
+                    let header_end = loop {
+                        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                            break pos + 4;
+                        }
+                        let mut chunk = [0u8; 4096];
+                        match socket.read(&mut chunk).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                        }
+                    };
+                    let headers = String::from_utf8_lossy(&buf[..header_end]).to_lowercase();
+                    let content_length = headers
+                        .lines()
+                        .find_map(|line| line.strip_prefix("content-length:"))
+                        .and_then(|v| v.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    while buf.len() < header_end + content_length {
+                        let mut chunk = [0u8; 4096];
+                        match socket.read(&mut chunk).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                        }
+                    }
+                    buf.drain(..header_end + content_length);
+
+                    let item = "data: {\"type\":\"response.output_item.done\",\"item\":{\"type\":\"message\",\"role\":\"assistant\",\"content\":[{\"type\":\"output_text\",\"text\":\"hi\"}]}}\n\n";
+                    let completed = "data: {\"type\":\"response.completed\",\"response\":{\"id\":\"resp1\"}}\n\n";
+                    let head = "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ntransfer-encoding: chunked\r\n\r\n";
+                    let chunk = |data: &str| format!("{:x}\r\n{data}\r\n", data.len());
+                    if socket
+                        .write_all(format!("{head}{}", chunk(item)).as_bytes())
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                    if socket
+                        .write_all(format!("{}0\r\n\r\n", chunk(completed)).as_bytes())
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    (format!("http://{addr}/v1"), connections)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancelled_stream_is_drained_and_connection_reused() {
+    non_sandbox_test!();
+
+    let (base_url, connections) = slow_sse_server().await;
+    let provider = ModelProviderInfo {
+        name: "slow".into(),
+        base_url: Some(base_url),
+        env_key: None,
+        env_key_instructions: None,
+        wire_api: WireApi::Responses,
+        query_params: None,
+        http_headers: None,
+        env_http_headers: None,
+        request_max_retries: Some(0),
+        stream_max_retries: Some(0),
+        stream_idle_timeout_ms: Some(5_000),
+        agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
+        requires_openai_auth: false,
+        openrouter: None,
+    };
+
+    let codex_home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&codex_home);
+    config.model_provider_id = provider.name.clone();
+    config.model_provider = provider.clone();
+    let effort = config.model_reasoning_effort;
+    let summary = config.model_reasoning_summary;
+    let verbosity = config.model_text_verbosity;
+    let config = Arc::new(config);
+
+    let client = ModelClient::new(
+        Arc::clone(&config),
+        None,
+        provider,
+        effort,
+        summary,
+        verbosity,
+        Uuid::new_v4(),
+        Arc::new(std::sync::Mutex::new(
+            codex_core::debug_logger::DebugLogger::new(false).unwrap(),
+        )),
+    );
+
+    let mut prompt = Prompt::default();
+    prompt.input.push(ResponseItem::Message {
+        id: None,
+        role: "user".to_string(),
+        content: vec![ContentItem::InputText {
+            text: "hello".to_string(),
+        }],
+    });
+
+    // Cancel after the first event, while the rest of the body is pending.
+    let mut stream = client.stream(&prompt).await.expect("first stream");
+    stream
+        .next()
+        .await
+        .expect("first event")
+        .expect("first event ok");
+    drop(stream);
+
+    // Give the background drain time to read the remaining body.
+    tokio::time::sleep(Duration::from_millis(800)).await;
+
+    let mut stream = client.stream(&prompt).await.expect("second stream");
+    let mut completed = false;
+    while let Some(event) = stream.next().await {
+        if matches!(
+            event.expect("second stream event"),
+            codex_core::ResponseEvent::Completed { .. }
+        ) {
+            completed = true;
+        }
+    }
+    assert!(completed, "second stream did not complete");
+    assert_eq!(
+        connections.load(std::sync::atomic::Ordering::SeqCst),
+        1,
+        "second request should reuse the drained connection"
+    );
+}
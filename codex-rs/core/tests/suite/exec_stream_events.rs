@@ -52,6 +52,7 @@ async fn test_aggregated_output_interleaves_in_order() {
         env: HashMap::new(),
         with_escalated_permissions: None,
         justification: None,
+        max_output_bytes: None,
     };
 
     let policy = SandboxPolicy::new_read_only_policy();
@@ -74,9 +75,7 @@ async fn test_aggregated_output_interleaves_in_order() {
     assert_eq!(result.aggregated_output.truncated_after_lines, None);
 }
 
-/// SPEC-957: ExecStream struct fields (sender/stdout_stream) are now private.
 #[tokio::test]
-#[ignore = "SPEC-957: ExecStream private fields prevent direct event collection"]
 async fn test_exec_timeout_returns_partial_output() {
     let cmd = vec![
         "/bin/sh".to_string(),
@@ -92,6 +91,7 @@ async fn test_exec_timeout_returns_partial_output() {
         env: HashMap::new(),
         with_escalated_permissions: None,
         justification: None,
+        max_output_bytes: None,
     };
 
     let policy = SandboxPolicy::new_read_only_policy();
@@ -117,3 +117,132 @@ async fn test_exec_timeout_returns_partial_output() {
     assert!(output.duration >= Duration::from_millis(200));
     assert!(output.timed_out);
 }
+
+#[tokio::test]
+async fn test_exec_timeout_kills_long_running_command() {
+    let cmd = vec!["sleep".to_string(), "10".to_string()];
+
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let params = ExecParams {
+        command: cmd,
+        cwd: cwd.clone(),
+        timeout_ms: Some(200),
+        env: HashMap::new(),
+        with_escalated_permissions: None,
+        justification: None,
+        max_output_bytes: None,
+    };
+
+    let policy = SandboxPolicy::new_read_only_policy();
+
+    let start = std::time::Instant::now();
+    let result = process_exec_tool_call(
+        params,
+        SandboxType::None,
+        &policy,
+        cwd.as_path(),
+        &None,
+        None,
+    )
+    .await;
+
+    assert!(
+        start.elapsed() < Duration::from_secs(3),
+        "timed out command was not killed promptly: {:?}",
+        start.elapsed()
+    );
+    let Err(CodexErr::Sandbox(SandboxErr::Timeout { output })) = result else {
+        panic!("expected timeout error");
+    };
+    assert!(output.timed_out);
+    assert_eq!(output.exit_code, 124);
+}
+
+#[tokio::test]
+async fn test_exec_output_is_capped_with_truncation_marker() {
+    const CAP: usize = 64 * 1024;
+    // ~2 MiB of output, far beyond the cap.
+    let cmd = vec![
+        "/bin/sh".to_string(),
+        "-c".to_string(),
+        "printf 'START\\n'; yes 0123456789abcdef | head -c 2000000; printf 'END\\n'".to_string(),
+    ];
+
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let params = ExecParams {
+        command: cmd,
+        cwd: cwd.clone(),
+        timeout_ms: Some(10_000),
+        env: HashMap::new(),
+        with_escalated_permissions: None,
+        justification: None,
+        max_output_bytes: Some(CAP),
+    };
+
+    let policy = SandboxPolicy::new_read_only_policy();
+
+    let result = process_exec_tool_call(
+        params,
+        SandboxType::None,
+        &policy,
+        cwd.as_path(),
+        &None,
+        None,
+    )
+    .await
+    .expect("process_exec_tool_call");
+
+    assert_eq!(result.exit_code, 0);
+    // The aggregate keeps the whole cap and each stream a quarter of it.
+    for (text, share) in [
+        (&result.stdout.text, CAP / 4),
+        (&result.aggregated_output.text, CAP),
+    ] {
+        assert!(text.len() < share + 64, "captured {} bytes", text.len());
+        assert!(text.starts_with("START\n"));
+        assert!(text.ends_with("END\n"));
+        assert!(text.contains(" bytes truncated ...]"));
+    }
+}
+
+#[tokio::test]
+async fn test_exec_aggregate_below_cap_is_not_truncated() {
+    const CAP: usize = 64 * 1024;
+    // Between half the cap and the cap: fits the aggregate whole but not
+    // the per-stream buffers.
+    const LEN: usize = 48 * 1024;
+    let cmd = vec![
+        "/bin/sh".to_string(),
+        "-c".to_string(),
+        format!("yes 0123456789abcdef | head -c {LEN}"),
+    ];
+
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let params = ExecParams {
+        command: cmd,
+        cwd: cwd.clone(),
+        timeout_ms: Some(10_000),
+        env: HashMap::new(),
+        with_escalated_permissions: None,
+        justification: None,
+        max_output_bytes: Some(CAP),
+    };
+
+    let policy = SandboxPolicy::new_read_only_policy();
+
+    let result = process_exec_tool_call(
+        params,
+        SandboxType::None,
+        &policy,
+        cwd.as_path(),
+        &None,
+        None,
+    )
+    .await
+    .expect("process_exec_tool_call");
+
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(result.aggregated_output.text.len(), LEN);
+    assert!(!result.aggregated_output.text.contains("bytes truncated"));
+    assert!(result.stdout.text.contains(" bytes truncated ...]"));
+}
@@ -74,6 +74,9 @@ async fn continue_after_stream_error() {
         stream_max_retries: Some(1),
         stream_idle_timeout_ms: Some(2_000),
         agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
         requires_openai_auth: false,
         openrouter: None,
     };
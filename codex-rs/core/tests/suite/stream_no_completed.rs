@@ -82,6 +82,9 @@ async fn retries_on_early_close() {
         stream_max_retries: Some(1),
         stream_idle_timeout_ms: Some(2000),
         agent_total_timeout_ms: None,
+        connect_timeout_ms: None,
+        request_timeout_ms: None,
+        proxy: None,
         requires_openai_auth: false,
         openrouter: None,
     };
@@ -4,8 +4,11 @@ use std::path::PathBuf;
 
 use codex_core::error::Result;
 use codex_core::error::SandboxErr;
+use codex_core::landlock::unsupported_landlock_features;
 use codex_core::protocol::SandboxPolicy;
 
+pub use codex_core::landlock::probe_landlock_abi;
+
 use landlock::ABI;
 use landlock::Access;
 use landlock::AccessFs;
@@ -24,38 +27,171 @@ use seccompiler::SeccompRule;
 use seccompiler::TargetArch;
 use seccompiler::apply_filter;
 
+/// Paths that stay writable under every restricted policy so that common
+/// shell idioms such as `> /dev/null` keep working. They are not listed in
+/// [`FilesystemRules::writable_paths`].
+const ALWAYS_WRITABLE_PATHS: &[&str] = &["/dev/null"];
+
+/// The sandbox rules computed for a [`SandboxPolicy`], before anything is
+/// installed on the current thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LandlockRuleset {
+    /// `None` when the policy grants full disk write access, in which case no
+    /// Landlock rules are installed at all.
+    pub filesystem: Option<FilesystemRules>,
+    pub network: NetworkRules,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilesystemRules {
+    /// Paths (and everything beneath them) the sandboxed process may read.
+    pub readable_paths: Vec<PathBuf>,
+    /// Paths (and everything beneath them) the sandboxed process may write.
+    pub writable_paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkRules {
+    /// No seccomp filter is installed.
+    Unrestricted,
+    /// Socket syscalls are denied except for `AF_UNIX` domain sockets.
+    UnixSocketsOnly,
+}
+
+impl LandlockRuleset {
+    /// Renders the ruleset as a short, human-readable summary suitable for
+    /// logging.
+    pub fn describe(&self) -> String {
+        let filesystem = match &self.filesystem {
+            None => "filesystem: unrestricted".to_string(),
+            Some(rules) => {
+                let writable = ALWAYS_WRITABLE_PATHS
+                    .iter()
+                    .map(PathBuf::from)
+                    .chain(rules.writable_paths.iter().cloned())
+                    .collect::<Vec<_>>();
+                format!(
+                    "filesystem: read [{}], write [{}]",
+                    join_paths(&rules.readable_paths),
+                    join_paths(&writable)
+                )
+            }
+        };
+        let network = match self.network {
+            NetworkRules::Unrestricted => "network: unrestricted",
+            NetworkRules::UnixSocketsOnly => "network: AF_UNIX sockets only",
+        };
+        format!("{filesystem}\n{network}")
+    }
+}
+
+fn join_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Computes the rules [`apply_sandbox_policy_to_current_thread`] would
+/// install for `sandbox_policy`, without applying them. `cwd` resolves the
+/// workspace root for [`SandboxPolicy::WorkspaceWrite`].
+pub fn compute_ruleset(sandbox_policy: &SandboxPolicy, cwd: &Path) -> LandlockRuleset {
+    let network = if sandbox_policy.has_full_network_access() {
+        NetworkRules::Unrestricted
+    } else {
+        NetworkRules::UnixSocketsOnly
+    };
+
+    // TODO(ragona): Add appropriate restrictions if
+    // `sandbox_policy.has_full_disk_read_access()` is `false`.
+    let filesystem = (!sandbox_policy.has_full_disk_write_access()).then(|| FilesystemRules {
+        readable_paths: vec![PathBuf::from("/")],
+        writable_paths: sandbox_policy
+            .get_writable_roots_with_cwd(cwd)
+            .into_iter()
+            .map(|writable_root| writable_root.root)
+            .collect(),
+    });
+
+    LandlockRuleset {
+        filesystem,
+        network,
+    }
+}
+
+/// How much of a [`LandlockRuleset`] the running kernel can enforce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnforcedLandlock {
+    /// Landlock ABI version reported by the kernel; `0` when Landlock is
+    /// unavailable.
+    pub abi_version: u32,
+    /// `true` when some part of the ruleset could not be enforced.
+    pub degraded: bool,
+    /// Features the ruleset needs that the kernel does not support.
+    pub unsupported_features: Vec<String>,
+}
+
+/// Compares what `ruleset` needs against the ABI version returned by
+/// `probe_abi`. Use [`probe_landlock_abi`] to query the running kernel.
+pub fn check_landlock_support(
+    ruleset: &LandlockRuleset,
+    probe_abi: impl FnOnce() -> u32,
+) -> EnforcedLandlock {
+    let abi_version = probe_abi();
+    let unsupported_features: Vec<String> = if ruleset.filesystem.is_some() {
+        unsupported_landlock_features(abi_version)
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    EnforcedLandlock {
+        abi_version,
+        degraded: !unsupported_features.is_empty(),
+        unsupported_features,
+    }
+}
+
 /// Apply sandbox policies inside this thread so only the child inherits
 /// them, not the entire CLI process.
 pub(crate) fn apply_sandbox_policy_to_current_thread(
     sandbox_policy: &SandboxPolicy,
     cwd: &Path,
-) -> Result<()> {
-    if !sandbox_policy.has_full_network_access() {
+) -> Result<EnforcedLandlock> {
+    let ruleset = compute_ruleset(sandbox_policy, cwd);
+    let mut enforced = check_landlock_support(&ruleset, probe_landlock_abi);
+
+    if ruleset.network == NetworkRules::UnixSocketsOnly {
         install_network_seccomp_filter_on_current_thread()?;
     }
 
-    if !sandbox_policy.has_full_disk_write_access() {
-        let writable_roots = sandbox_policy
-            .get_writable_roots_with_cwd(cwd)
-            .into_iter()
-            .map(|writable_root| writable_root.root)
-            .collect();
-        install_filesystem_landlock_rules_on_current_thread(writable_roots)?;
+    if let Some(filesystem) = &ruleset.filesystem {
+        let fully_enforced = install_filesystem_landlock_rules_on_current_thread(filesystem)?;
+        // Some environments (including our test harness) may lack Landlock
+        // support even though the CLI still links against the sandbox helper.
+        // Rather than aborting entirely, degrade gracefully so guardrail
+        // commands can proceed; the caller warns about it once per session.
+        if !fully_enforced && !enforced.degraded {
+            enforced.degraded = true;
+            enforced
+                .unsupported_features
+                .push("filesystem access control".to_string());
+        }
     }
 
-    // TODO(ragona): Add appropriate restrictions if
-    // `sandbox_policy.has_full_disk_read_access()` is `false`.
-
-    Ok(())
+    Ok(enforced)
 }
 
 /// Installs Landlock file-system rules on the current thread allowing read
-/// access to the entire file-system while restricting write access to
-/// `/dev/null` and the provided list of `writable_roots`.
+/// access to `readable_paths` while restricting write access to `/dev/null`
+/// and `writable_paths`. Returns whether the kernel fully enforced them.
 ///
 /// # Errors
 /// Returns [`CodexErr::Sandbox`] variants when the ruleset fails to apply.
-fn install_filesystem_landlock_rules_on_current_thread(writable_roots: Vec<PathBuf>) -> Result<()> {
+fn install_filesystem_landlock_rules_on_current_thread(rules: &FilesystemRules) -> Result<bool> {
     let abi = ABI::V5;
     let access_rw = AccessFs::from_all(abi);
     let access_ro = AccessFs::from_read(abi);
@@ -64,29 +200,26 @@ fn install_filesystem_landlock_rules_on_current_thread(writable_roots: Vec<PathB
         .set_compatibility(CompatLevel::BestEffort)
         .handle_access(access_rw)?
         .create()?
-        .add_rules(landlock::path_beneath_rules(&["/"], access_ro))?
-        .add_rules(landlock::path_beneath_rules(&["/dev/null"], access_rw))?
+        .add_rules(landlock::path_beneath_rules(
+            &rules.readable_paths,
+            access_ro,
+        ))?
+        .add_rules(landlock::path_beneath_rules(
+            ALWAYS_WRITABLE_PATHS,
+            access_rw,
+        ))?
         .set_no_new_privs(true);
 
-    if !writable_roots.is_empty() {
-        ruleset = ruleset.add_rules(landlock::path_beneath_rules(&writable_roots, access_rw))?;
+    if !rules.writable_paths.is_empty() {
+        ruleset = ruleset.add_rules(landlock::path_beneath_rules(
+            &rules.writable_paths,
+            access_rw,
+        ))?;
     }
 
     let status = ruleset.restrict_self()?;
 
-    if status.ruleset == landlock::RulesetStatus::NotEnforced {
-        // Some environments (including our test harness) may lack Landlock
-        // support even though the CLI still links against the sandbox helper.
-        // Rather than aborting entirely, degrade gracefully so guardrail
-        // commands can proceed. These environments are already isolated by the
-        // surrounding harness, so the reduced protection is acceptable.
-        eprintln!(
-            "codex-linux-sandbox: Landlock not enforced, continuing without filesystem restrictions"
-        );
-        return Ok(());
-    }
-
-    Ok(())
+    Ok(status.ruleset == landlock::RulesetStatus::FullyEnforced)
 }
 
 /// Installs a seccomp filter that blocks outbound network access except for
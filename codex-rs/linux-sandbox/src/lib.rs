@@ -1,5 +1,5 @@
 #[cfg(target_os = "linux")]
-mod landlock;
+pub mod landlock;
 #[cfg(target_os = "linux")]
 mod linux_run_main;
 
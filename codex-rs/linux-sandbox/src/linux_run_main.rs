@@ -24,6 +24,8 @@ pub fn run_main() -> ! {
         command,
     } = LandlockCommand::parse();
 
+    // A degraded sandbox is reported once by the spawning process
+    // (`codex_core::landlock`), not on every sandboxed command.
     if let Err(e) = apply_sandbox_policy_to_current_thread(&sandbox_policy, &sandbox_policy_cwd) {
         panic!("error running landlock: {e:?}");
     }
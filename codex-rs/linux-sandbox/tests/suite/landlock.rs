@@ -7,6 +7,11 @@ use codex_core::exec::SandboxType;
 use codex_core::exec::process_exec_tool_call;
 use codex_core::exec_env::create_env;
 use codex_core::protocol::SandboxPolicy;
+use codex_linux_sandbox::landlock::EnforcedLandlock;
+use codex_linux_sandbox::landlock::FilesystemRules;
+use codex_linux_sandbox::landlock::NetworkRules;
+use codex_linux_sandbox::landlock::check_landlock_support;
+use codex_linux_sandbox::landlock::compute_ruleset;
 use landlock::{
     ABI, AccessFs, CompatLevel, Compatible, Ruleset, RulesetAttr, RulesetCreatedAttr, RulesetStatus,
 };
@@ -69,6 +74,7 @@ async fn run_cmd(cmd: &[&str], writable_roots: &[PathBuf], timeout_ms: u64) {
         env: create_env_from_core_vars(),
         with_escalated_permissions: None,
         justification: None,
+        max_output_bytes: None,
     };
 
     let sandbox_policy = SandboxPolicy::WorkspaceWrite {
@@ -157,6 +163,48 @@ async fn test_writable_root() {
     .await;
 }
 
+#[test]
+fn read_only_policy_computes_read_paths_without_write_paths() {
+    let cwd = tempfile::tempdir().unwrap();
+    let ruleset = compute_ruleset(&SandboxPolicy::new_read_only_policy(), cwd.path());
+
+    assert_eq!(
+        ruleset.filesystem,
+        Some(FilesystemRules {
+            readable_paths: vec![PathBuf::from("/")],
+            writable_paths: Vec::new(),
+        })
+    );
+    assert_eq!(ruleset.network, NetworkRules::UnixSocketsOnly);
+    assert_eq!(
+        ruleset.describe(),
+        "filesystem: read [/], write [/dev/null]\nnetwork: AF_UNIX sockets only"
+    );
+}
+
+#[test]
+fn older_landlock_abi_reports_degraded_features() {
+    let cwd = tempfile::tempdir().unwrap();
+    let ruleset = compute_ruleset(&SandboxPolicy::new_read_only_policy(), cwd.path());
+
+    let enforced = check_landlock_support(&ruleset, || 2);
+    assert_eq!(
+        enforced,
+        EnforcedLandlock {
+            abi_version: 2,
+            degraded: true,
+            unsupported_features: vec!["file truncation".to_string(), "device ioctl".to_string()],
+        }
+    );
+
+    let enforced = check_landlock_support(&ruleset, || 5);
+    assert!(!enforced.degraded);
+    assert!(enforced.unsupported_features.is_empty());
+
+    let unrestricted = compute_ruleset(&SandboxPolicy::DangerFullAccess, cwd.path());
+    assert!(!check_landlock_support(&unrestricted, || 0).degraded);
+}
+
 #[tokio::test]
 #[should_panic(expected = "Sandbox(Timeout")]
 async fn test_timeout() {
@@ -180,6 +228,7 @@ async fn assert_network_blocked(cmd: &[&str]) {
         env: create_env_from_core_vars(),
         with_escalated_permissions: None,
         justification: None,
+        max_output_bytes: None,
     };
 
     let sandbox_policy = SandboxPolicy::new_read_only_policy();
@@ -5,16 +5,23 @@
 //! Implements OAuth 2.0 Device Authorization Grant for:
 //! - OpenAI (ChatGPT authentication for non-browser environments)
 //! - Google (Gemini API authentication)
+//! - Azure OpenAI (Microsoft identity platform)
 //!
 //! Flow overview:
 //! 1. Request device code from authorization server
 //! 2. Display user code and verification URL to user
-//! 3. Poll token endpoint until user completes authorization
-//! 4. Store and refresh tokens as needed
+//! 3. Poll token endpoint until user completes authorization (see `poll_with_progress`)
+//! 4. Store and refresh tokens as needed (see `spawn_refresh_task`)
 
+use crate::device_code_poll::{PollProgressStream, run_poll_loop};
+use crate::device_code_refresh::{RefreshTaskOptions, run_refresh_loop};
+use crate::device_code_storage::DeviceCodeTokenStorage;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 
 /// Response from device authorization endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -169,6 +176,7 @@ pub enum DeviceCodeProvider {
     OpenAI,
     Google,
     Anthropic,
+    Azure,
 }
 
 impl DeviceCodeProvider {
@@ -177,6 +185,7 @@ impl DeviceCodeProvider {
             Self::OpenAI => "openai",
             Self::Google => "google",
             Self::Anthropic => "anthropic",
+            Self::Azure => "azure",
         }
     }
 
@@ -186,6 +195,7 @@ impl DeviceCodeProvider {
             Self::OpenAI => "OpenAI",
             Self::Google => "Gemini",
             Self::Anthropic => "Claude",
+            Self::Azure => "Azure OpenAI",
         }
     }
 }
@@ -225,11 +235,56 @@ pub trait DeviceCodeAuth: Send + Sync {
     /// requiring user interaction.
     async fn refresh_token(&self, refresh_token: &str) -> Result<TokenResponse, RefreshError>;
 
+    /// Revoke a stored token with the provider (RFC 7009)
+    ///
+    /// Providers without a revocation endpoint keep the default no-op.
+    async fn revoke(&self, _token: &StoredToken) -> Result<(), RefreshError> {
+        Ok(())
+    }
+
     /// Get the scopes requested for this provider
     fn scopes(&self) -> &[&str];
 
     /// Get human-readable provider name for UI display
     fn display_name(&self) -> &str;
+
+    /// Poll until authorization completes, reporting progress along the way
+    ///
+    /// Returns a stream of `PollProgress` events (time left before the device
+    /// code expires, attempts so far) and a handle resolving to the token.
+    /// The stream ends once polling finishes.
+    fn poll_with_progress(
+        self: Arc<Self>,
+        authorization: DeviceAuthorizationResponse,
+    ) -> (
+        PollProgressStream,
+        JoinHandle<Result<TokenResponse, PollError>>,
+    )
+    where
+        Self: Sized + 'static,
+    {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let handle = tokio::spawn(run_poll_loop(self, authorization, tx));
+        (PollProgressStream::new(rx), handle)
+    }
+
+    /// Spawn a background task that refreshes this provider's stored token
+    ///
+    /// The token is refreshed `options.margin` (default 5 minutes) before
+    /// `expires_at` and written back to `storage`. Providers without a stored
+    /// token are skipped until one appears. The task exits when `shutdown` is
+    /// cancelled or the provider rejects the refresh token.
+    fn spawn_refresh_task(
+        self: Arc<Self>,
+        storage: Arc<DeviceCodeTokenStorage>,
+        options: RefreshTaskOptions,
+        shutdown: CancellationToken,
+    ) -> JoinHandle<()>
+    where
+        Self: Sized + 'static,
+    {
+        tokio::spawn(run_refresh_loop(self, storage, options, shutdown))
+    }
 }
 
 /// Stored token data for persistence
@@ -281,6 +336,17 @@ impl StoredToken {
     pub fn can_refresh(&self) -> bool {
         self.refresh_token.is_some()
     }
+
+    /// Token to send for revocation with its RFC 7009 type hint
+    ///
+    /// Prefers the refresh token, since revoking it also invalidates
+    /// access tokens issued from it.
+    pub fn revocation_target(&self) -> (&str, &'static str) {
+        match self.refresh_token.as_deref() {
+            Some(refresh) => (refresh, "refresh_token"),
+            None => (&self.access_token, "access_token"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -346,6 +412,7 @@ mod tests {
         assert_eq!(DeviceCodeProvider::OpenAI.as_str(), "openai");
         assert_eq!(DeviceCodeProvider::Google.as_str(), "google");
         assert_eq!(DeviceCodeProvider::Anthropic.as_str(), "anthropic");
+        assert_eq!(DeviceCodeProvider::Azure.as_str(), "azure");
         assert_eq!(format!("{}", DeviceCodeProvider::OpenAI), "openai");
         assert_eq!(format!("{}", DeviceCodeProvider::Anthropic), "anthropic");
     }
@@ -223,6 +223,8 @@ impl DeviceCodeAuth for AnthropicDeviceCode {
             .map_err(|e| RefreshError::Parse(e.to_string()))
     }
 
+    // No public revocation endpoint; `revoke` keeps the trait's no-op default.
+
     fn scopes(&self) -> &[&str] {
         ANTHROPIC_SCOPES
     }
@@ -0,0 +1,393 @@
+//! Azure OpenAI Device Code Authorization
+//!
+//! FORK-SPECIFIC (just-every/code): P6-SYNC Phase 5
+//!
+//! Implements device code OAuth 2.0 flow against the Microsoft identity
+//! platform for Azure OpenAI access. Suited to organisations whose Azure AD
+//! (Entra ID) tenant fronts their model deployments.
+//!
+//! Microsoft Identity Device Code Flow:
+//! 1. POST to /{tenant}/oauth2/v2.0/devicecode with client_id and scope
+//! 2. Display user_code and verification_uri to user
+//! 3. Poll /{tenant}/oauth2/v2.0/token with device_code until success
+//! 4. Store access_token and refresh_token
+//!
+//! Note: Requires an app registration with "Allow public client flows" enabled.
+
+use crate::device_code::{
+    DeviceAuthError, DeviceAuthorizationResponse, DeviceCodeAuth, DeviceCodeProvider, PollError,
+    RefreshError, TokenResponse,
+};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Microsoft identity platform authority
+const AZURE_AUTHORITY: &str = "https://login.microsoftonline.com";
+const DEVICE_AUTH_ENDPOINT: &str = "/oauth2/v2.0/devicecode";
+const TOKEN_ENDPOINT: &str = "/oauth2/v2.0/token";
+
+/// Tenant used when AZURE_TENANT_ID is not set (any work/school account)
+const DEFAULT_TENANT: &str = "organizations";
+
+/// Azure OAuth scopes for Azure OpenAI (Cognitive Services)
+const AZURE_SCOPES: &[&str] = &[
+    "https://cognitiveservices.azure.com/.default",
+    "offline_access",
+    "openid",
+    "profile",
+];
+
+/// Azure device code authorization client
+pub struct AzureDeviceCode {
+    client: reqwest::Client,
+    tenant_id: String,
+    client_id: String,
+    authority: String,
+}
+
+impl AzureDeviceCode {
+    /// Create with configuration from environment
+    ///
+    /// Requires AZURE_OAUTH_CLIENT_ID environment variable.
+    /// Uses AZURE_TENANT_ID if set, otherwise the `organizations` tenant.
+    pub fn from_env() -> Result<Self, DeviceAuthError> {
+        let client_id = std::env::var("AZURE_OAUTH_CLIENT_ID").map_err(|_| {
+            DeviceAuthError::Config(
+                "AZURE_OAUTH_CLIENT_ID environment variable not set. \
+                 Register an app at https://portal.azure.com (Entra ID > App registrations)"
+                    .to_string(),
+            )
+        })?;
+
+        let tenant_id =
+            std::env::var("AZURE_TENANT_ID").unwrap_or_else(|_| DEFAULT_TENANT.to_string());
+
+        Ok(Self::new(tenant_id, client_id))
+    }
+
+    /// Create with explicit tenant and client ID
+    pub fn new(tenant_id: String, client_id: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            tenant_id,
+            client_id,
+            authority: AZURE_AUTHORITY.to_string(),
+        }
+    }
+
+    /// Override the identity authority (sovereign clouds, tests)
+    pub fn with_authority(mut self, authority: impl Into<String>) -> Self {
+        self.authority = authority.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Check if client is configured
+    pub fn is_configured(&self) -> bool {
+        !self.client_id.is_empty() && !self.tenant_id.is_empty()
+    }
+
+    /// Build URL for an endpoint under the configured tenant
+    fn endpoint_url(&self, path: &str) -> String {
+        format!("{}/{}{}", self.authority, self.tenant_id, path)
+    }
+}
+
+/// OAuth error response from the Microsoft identity platform
+#[derive(Debug, Deserialize)]
+struct AzureOAuthError {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// Device authorization request body
+#[derive(Debug, Serialize)]
+struct AzureDeviceAuthRequest {
+    client_id: String,
+    scope: String,
+}
+
+/// Token request body for device code grant
+#[derive(Debug, Serialize)]
+struct AzureDeviceTokenRequest {
+    client_id: String,
+    device_code: String,
+    grant_type: String,
+}
+
+/// Token request body for refresh grant
+#[derive(Debug, Serialize)]
+struct AzureRefreshTokenRequest {
+    client_id: String,
+    refresh_token: String,
+    grant_type: String,
+    scope: String,
+}
+
+#[async_trait]
+impl DeviceCodeAuth for AzureDeviceCode {
+    fn provider(&self) -> DeviceCodeProvider {
+        DeviceCodeProvider::Azure
+    }
+
+    async fn start_device_authorization(
+        &self,
+    ) -> Result<DeviceAuthorizationResponse, DeviceAuthError> {
+        if !self.is_configured() {
+            return Err(DeviceAuthError::Config(
+                "Azure OAuth not configured. Set AZURE_OAUTH_CLIENT_ID environment variable."
+                    .to_string(),
+            ));
+        }
+
+        let request = AzureDeviceAuthRequest {
+            client_id: self.client_id.clone(),
+            scope: AZURE_SCOPES.join(" "),
+        };
+
+        let response = self
+            .client
+            .post(self.endpoint_url(DEVICE_AUTH_ENDPOINT))
+            .form(&request)
+            .send()
+            .await
+            .map_err(|e| DeviceAuthError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+
+            if let Ok(oauth_err) = serde_json::from_str::<AzureOAuthError>(&body) {
+                return Err(DeviceAuthError::Server(format!(
+                    "{}: {}",
+                    oauth_err.error,
+                    oauth_err.error_description.unwrap_or_default()
+                )));
+            }
+
+            return Err(DeviceAuthError::Server(format!("HTTP {status}: {body}")));
+        }
+
+        // Microsoft returns standard RFC 8628 fields (plus a `message` we ignore)
+        response
+            .json::<DeviceAuthorizationResponse>()
+            .await
+            .map_err(|e| DeviceAuthError::Parse(e.to_string()))
+    }
+
+    async fn poll_for_token(&self, device_code: &str) -> Result<TokenResponse, PollError> {
+        let request = AzureDeviceTokenRequest {
+            client_id: self.client_id.clone(),
+            device_code: device_code.to_string(),
+            grant_type: "urn:ietf:params:oauth:grant-type:device_code".to_string(),
+        };
+
+        let response = self
+            .client
+            .post(self.endpoint_url(TOKEN_ENDPOINT))
+            .form(&request)
+            .send()
+            .await
+            .map_err(|e| PollError::Network(e.to_string()))?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+
+        if status.is_success() {
+            return serde_json::from_str::<TokenResponse>(&body)
+                .map_err(|e| PollError::Parse(e.to_string()));
+        }
+
+        // Parse OAuth error to determine poll status
+        let oauth_err: AzureOAuthError = serde_json::from_str(&body)
+            .map_err(|e| PollError::Parse(format!("Failed to parse error: {e}")))?;
+
+        match oauth_err.error.as_str() {
+            "authorization_pending" => Err(PollError::AuthorizationPending),
+            "slow_down" => Err(PollError::SlowDown),
+            // Microsoft reports user denial as authorization_declined
+            "authorization_declined" | "access_denied" => Err(PollError::AccessDenied),
+            "expired_token" => Err(PollError::ExpiredToken),
+            _ => Err(PollError::Server(format!(
+                "{}: {}",
+                oauth_err.error,
+                oauth_err.error_description.unwrap_or_default()
+            ))),
+        }
+    }
+
+    async fn refresh_token(&self, refresh_token: &str) -> Result<TokenResponse, RefreshError> {
+        let request = AzureRefreshTokenRequest {
+            client_id: self.client_id.clone(),
+            refresh_token: refresh_token.to_string(),
+            grant_type: "refresh_token".to_string(),
+            scope: AZURE_SCOPES.join(" "),
+        };
+
+        let response = self
+            .client
+            .post(self.endpoint_url(TOKEN_ENDPOINT))
+            .form(&request)
+            .send()
+            .await
+            .map_err(|e| RefreshError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+
+            if let Ok(oauth_err) = serde_json::from_str::<AzureOAuthError>(&body) {
+                if oauth_err.error == "invalid_grant" {
+                    return Err(RefreshError::InvalidGrant);
+                }
+                return Err(RefreshError::Server(format!(
+                    "{}: {}",
+                    oauth_err.error,
+                    oauth_err.error_description.unwrap_or_default()
+                )));
+            }
+
+            return Err(RefreshError::Server(body));
+        }
+
+        response
+            .json::<TokenResponse>()
+            .await
+            .map_err(|e| RefreshError::Parse(e.to_string()))
+    }
+
+    fn scopes(&self) -> &[&str] {
+        AZURE_SCOPES
+    }
+
+    fn display_name(&self) -> &str {
+        "Azure OpenAI"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device_code::StoredToken;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn token_body(access_token: &str) -> serde_json::Value {
+        serde_json::json!({
+            "access_token": access_token,
+            "token_type": "Bearer",
+            "expires_in": 3600,
+            "refresh_token": "azure-refresh",
+            "scope": "https://cognitiveservices.azure.com/.default"
+        })
+    }
+
+    #[test]
+    fn test_azure_device_code_creation() {
+        let client = AzureDeviceCode::new("contoso".to_string(), "client-123".to_string());
+        assert_eq!(client.provider(), DeviceCodeProvider::Azure);
+        assert_eq!(client.display_name(), "Azure OpenAI");
+        assert!(client.is_configured());
+        assert!(client.scopes().contains(&"offline_access"));
+        assert_eq!(
+            client.endpoint_url(TOKEN_ENDPOINT),
+            "https://login.microsoftonline.com/contoso/oauth2/v2.0/token"
+        );
+    }
+
+    #[test]
+    fn test_empty_client_id_not_configured() {
+        let client = AzureDeviceCode::new("contoso".to_string(), String::new());
+        assert!(!client.is_configured());
+    }
+
+    #[tokio::test]
+    async fn test_poll_pending_then_authorized() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "authorization_pending",
+                "error_description": "AADSTS70016: pending"
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .and(body_string_contains("device_code=dev-abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(token_body("azure-access")))
+            .mount(&server)
+            .await;
+
+        let client = AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+            .with_authority(server.uri());
+
+        assert!(matches!(
+            client.poll_for_token("dev-abc").await,
+            Err(PollError::AuthorizationPending)
+        ));
+
+        let response = client.poll_for_token("dev-abc").await.expect("authorized");
+        let stored = StoredToken::from_response(client.provider(), response);
+        assert_eq!(stored.provider, DeviceCodeProvider::Azure);
+        assert_eq!(stored.access_token, "azure-access");
+        assert_eq!(stored.refresh_token.as_deref(), Some("azure-refresh"));
+        assert!(!stored.is_expired());
+    }
+
+    #[tokio::test]
+    async fn test_declined_maps_to_access_denied() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "authorization_declined"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+            .with_authority(server.uri());
+        assert!(matches!(
+            client.poll_for_token("dev-abc").await,
+            Err(PollError::AccessDenied)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .and(body_string_contains("refresh_token=old-refresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(token_body("refreshed")))
+            .mount(&server)
+            .await;
+
+        let client = AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+            .with_authority(server.uri());
+
+        let response = client.refresh_token("old-refresh").await.expect("refresh");
+        assert_eq!(response.access_token, "refreshed");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_invalid_grant() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "invalid_grant"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+            .with_authority(server.uri());
+        assert!(matches!(
+            client.refresh_token("revoked").await,
+            Err(RefreshError::InvalidGrant)
+        ));
+    }
+}
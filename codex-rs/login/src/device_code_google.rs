@@ -17,7 +17,7 @@
 
 use crate::device_code::{
     DeviceAuthError, DeviceAuthorizationResponse, DeviceCodeAuth, DeviceCodeProvider, PollError,
-    RefreshError, TokenResponse,
+    RefreshError, StoredToken, TokenResponse,
 };
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -25,6 +25,7 @@ use serde::{Deserialize, Serialize};
 /// Google OAuth2 endpoints
 const GOOGLE_DEVICE_AUTH_URL: &str = "https://oauth2.googleapis.com/device/code";
 const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+const GOOGLE_REVOKE_URL: &str = "https://oauth2.googleapis.com/revoke";
 
 /// Default client ID for Google OAuth (Gemini API access)
 /// Users should configure their own client ID via environment variable
@@ -280,6 +281,38 @@ impl DeviceCodeAuth for GoogleDeviceCode {
             .map_err(|e| RefreshError::Parse(e.to_string()))
     }
 
+    async fn revoke(&self, token: &StoredToken) -> Result<(), RefreshError> {
+        let (token, _) = token.revocation_target();
+
+        let response = self
+            .client
+            .post(GOOGLE_REVOKE_URL)
+            .form(&[("token", token)])
+            .send()
+            .await
+            .map_err(|e| RefreshError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+
+            if let Ok(oauth_err) = serde_json::from_str::<GoogleOAuthError>(&body) {
+                // Already revoked or expired: nothing left to invalidate
+                if oauth_err.error == "invalid_token" {
+                    return Ok(());
+                }
+                return Err(RefreshError::Server(format!(
+                    "{}: {}",
+                    oauth_err.error,
+                    oauth_err.error_description.unwrap_or_default()
+                )));
+            }
+
+            return Err(RefreshError::Server(body));
+        }
+
+        Ok(())
+    }
+
     fn scopes(&self) -> &[&str] {
         GOOGLE_SCOPES
     }
@@ -14,7 +14,7 @@
 
 use crate::device_code::{
     DeviceAuthError, DeviceAuthorizationResponse, DeviceCodeAuth, DeviceCodeProvider, PollError,
-    RefreshError, TokenResponse,
+    RefreshError, StoredToken, TokenResponse,
 };
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,7 @@ use serde::{Deserialize, Serialize};
 const OPENAI_AUTH_BASE: &str = "https://auth.openai.com";
 const DEVICE_AUTH_ENDPOINT: &str = "/oauth/device";
 const TOKEN_ENDPOINT: &str = "/oauth/token";
+const REVOKE_ENDPOINT: &str = "/oauth/revoke";
 
 /// Default client ID for OpenAI OAuth
 /// Can be overridden with OPENAI_OAUTH_CLIENT_ID environment variable
@@ -63,6 +64,12 @@ impl OpenAIDeviceCode {
         }
     }
 
+    /// Override the auth server base URL (tests, proxies)
+    pub fn with_auth_base(mut self, auth_base: impl Into<String>) -> Self {
+        self.auth_base = auth_base.into().trim_end_matches('/').to_string();
+        self
+    }
+
     /// Get client ID from environment or use default
     fn get_client_id() -> String {
         std::env::var("OPENAI_OAUTH_CLIENT_ID").unwrap_or_else(|_| DEFAULT_CLIENT_ID.to_string())
@@ -105,6 +112,14 @@ struct RefreshTokenRequest {
     grant_type: String,
 }
 
+/// Revocation request body (RFC 7009)
+#[derive(Debug, Serialize)]
+struct RevokeTokenRequest<'a> {
+    client_id: &'a str,
+    token: &'a str,
+    token_type_hint: &'a str,
+}
+
 #[async_trait]
 impl DeviceCodeAuth for OpenAIDeviceCode {
     fn provider(&self) -> DeviceCodeProvider {
@@ -234,6 +249,32 @@ impl DeviceCodeAuth for OpenAIDeviceCode {
             .map_err(|e| RefreshError::Parse(e.to_string()))
     }
 
+    async fn revoke(&self, token: &StoredToken) -> Result<(), RefreshError> {
+        let url = self.endpoint_url(REVOKE_ENDPOINT);
+        let (token, token_type_hint) = token.revocation_target();
+
+        let request = RevokeTokenRequest {
+            client_id: &self.client_id,
+            token,
+            token_type_hint,
+        };
+
+        let response = self
+            .client
+            .post(&url)
+            .form(&request)
+            .send()
+            .await
+            .map_err(|e| RefreshError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RefreshError::Server(body));
+        }
+
+        Ok(())
+    }
+
     fn scopes(&self) -> &[&str] {
         OPENAI_SCOPES
     }
@@ -279,4 +320,33 @@ mod tests {
         let client = OpenAIDeviceCode::new();
         assert!(client.scopes().contains(&"offline_access"));
     }
+
+    #[tokio::test]
+    async fn test_revoke_sends_refresh_token() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/revoke"))
+            .and(body_string_contains("token=refresh-abc"))
+            .and(body_string_contains("token_type_hint=refresh_token"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            OpenAIDeviceCode::with_client_id("custom-id".to_string()).with_auth_base(server.uri());
+        let token = StoredToken {
+            provider: DeviceCodeProvider::OpenAI,
+            access_token: "access-abc".to_string(),
+            refresh_token: Some("refresh-abc".to_string()),
+            expires_at: None,
+            scope: None,
+            stored_at: 0,
+        };
+
+        client.revoke(&token).await.expect("revoke");
+    }
 }
@@ -0,0 +1,152 @@
+//! Interactive Polling for Device Code Authorization
+//!
+//! FORK-SPECIFIC (just-every/code): P6-SYNC Phase 5
+//!
+//! Drives the RFC 8628 polling loop to completion while reporting progress,
+//! so interactive callers can render a countdown to code expiry and keep the
+//! user code on screen. Non-interactive callers can keep calling
+//! `DeviceCodeAuth::poll_for_token` directly.
+
+use crate::device_code::{DeviceAuthorizationResponse, DeviceCodeAuth, PollError, TokenResponse};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// Extra delay added to the interval on `slow_down` (RFC 8628 §3.5)
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+/// Progress update emitted before each poll wait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollProgress {
+    /// Whole seconds until the device code expires
+    pub seconds_remaining: u64,
+    /// Token requests made so far
+    pub attempts: u32,
+}
+
+/// Stream of progress events; ends when polling finishes
+pub type PollProgressStream = UnboundedReceiverStream<PollProgress>;
+
+/// Poll until the user authorizes, the code expires, or a terminal error occurs
+///
+/// Progress is sent before every wait; a dropped receiver does not stop polling.
+pub(crate) async fn run_poll_loop<A: DeviceCodeAuth + ?Sized>(
+    auth: Arc<A>,
+    authorization: DeviceAuthorizationResponse,
+    progress: mpsc::UnboundedSender<PollProgress>,
+) -> Result<TokenResponse, PollError> {
+    let deadline = Instant::now() + authorization.expires_duration();
+    let mut interval = authorization.poll_interval();
+    let mut attempts = 0u32;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(PollError::ExpiredToken);
+        }
+
+        let _ = progress.send(PollProgress {
+            seconds_remaining: remaining.as_secs(),
+            attempts,
+        });
+
+        tokio::time::sleep(interval.min(remaining)).await;
+
+        attempts += 1;
+        match auth.poll_for_token(&authorization.device_code).await {
+            Ok(token) => return Ok(token),
+            Err(PollError::AuthorizationPending) => {}
+            Err(PollError::SlowDown) => interval += SLOW_DOWN_INCREMENT,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device_code_azure::AzureDeviceCode;
+    use tokio_stream::StreamExt;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn authorization(expires_in: u64) -> DeviceAuthorizationResponse {
+        DeviceAuthorizationResponse {
+            device_code: "dev-abc".to_string(),
+            user_code: "ABCD-EFGH".to_string(),
+            verification_uri: "https://example.com/device".to_string(),
+            verification_uri_complete: None,
+            expires_in,
+            interval: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_progress_counts_down_until_authorized() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "authorization_pending"
+            })))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "authorized",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            })))
+            .mount(&server)
+            .await;
+
+        let auth = Arc::new(
+            AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+                .with_authority(server.uri()),
+        );
+        let (progress, result) = auth.poll_with_progress(authorization(60));
+
+        let events: Vec<PollProgress> = progress.collect().await;
+        let token = result.await.unwrap().expect("authorized");
+        assert_eq!(token.access_token, "authorized");
+
+        assert_eq!(
+            events.iter().map(|e| e.attempts).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!(
+            events
+                .windows(2)
+                .all(|w| w[1].seconds_remaining < w[0].seconds_remaining)
+        );
+        assert!(events[0].seconds_remaining <= 60);
+    }
+
+    #[tokio::test]
+    async fn test_expired_code_ends_polling() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "authorization_pending"
+            })))
+            .mount(&server)
+            .await;
+
+        let auth = Arc::new(
+            AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+                .with_authority(server.uri()),
+        );
+        let (progress, result) = auth.poll_with_progress(authorization(1));
+
+        assert!(matches!(
+            result.await.unwrap(),
+            Err(PollError::ExpiredToken)
+        ));
+        let events: Vec<PollProgress> = progress.collect().await;
+        assert_eq!(events.first().map(|e| e.attempts), Some(0));
+    }
+}
@@ -0,0 +1,277 @@
+//! Background Token Refresh for Device Code Authorization
+//!
+//! FORK-SPECIFIC (just-every/code): P6-SYNC Phase 5
+//!
+//! Keeps a provider's stored token fresh by refreshing it a configurable
+//! margin before `expires_at`, so the first request after expiry does not
+//! fail. The task runs until its shutdown token is cancelled or the refresh
+//! token is rejected by the provider.
+
+use crate::device_code::{DeviceCodeAuth, RefreshError, StoredToken};
+use crate::device_code_storage::{DeviceCodeTokenStorage, StorageError};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// Default margin before expiry at which tokens are refreshed
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// Default interval between storage checks
+pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Source of the current Unix timestamp (seconds)
+pub type Clock = Arc<dyn Fn() -> i64 + Send + Sync>;
+
+/// Configuration for the background refresh task
+#[derive(Clone)]
+pub struct RefreshTaskOptions {
+    /// Refresh once the token is within this margin of `expires_at`
+    pub margin: Duration,
+    /// How often the task re-reads storage and checks the clock
+    pub check_interval: Duration,
+    /// Clock used to decide when a token is due (injectable for tests)
+    pub clock: Clock,
+}
+
+impl Default for RefreshTaskOptions {
+    fn default() -> Self {
+        Self {
+            margin: DEFAULT_REFRESH_MARGIN,
+            check_interval: DEFAULT_CHECK_INTERVAL,
+            clock: Arc::new(|| chrono::Utc::now().timestamp()),
+        }
+    }
+}
+
+impl std::fmt::Debug for RefreshTaskOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshTaskOptions")
+            .field("margin", &self.margin)
+            .field("check_interval", &self.check_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether `token` should be refreshed at `now` given `margin`
+fn refresh_due(token: &StoredToken, now: i64, margin: Duration) -> bool {
+    match token.expires_at {
+        Some(exp) => token.can_refresh() && now >= exp - margin.as_secs() as i64,
+        None => false, // No expiry means nothing to refresh
+    }
+}
+
+/// Run the refresh loop for `auth.provider()` until `shutdown` fires
+pub(crate) async fn run_refresh_loop<A: DeviceCodeAuth + ?Sized>(
+    auth: Arc<A>,
+    storage: Arc<DeviceCodeTokenStorage>,
+    options: RefreshTaskOptions,
+    shutdown: CancellationToken,
+) {
+    let provider = auth.provider();
+
+    loop {
+        match storage.get_token(provider) {
+            Ok(token) if refresh_due(&token, (options.clock)(), options.margin) => {
+                // refresh_due guarantees a refresh token is present
+                let refresh_token = token.refresh_token.unwrap_or_default();
+                let result = tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    result = auth.refresh_token(&refresh_token) => result,
+                };
+                match result {
+                    Ok(response) => {
+                        let now = (options.clock)();
+                        match storage.update_access_token_at(provider, response, now) {
+                            Ok(()) => info!("Refreshed device code token for {}", provider),
+                            Err(e) => {
+                                warn!("Failed to store refreshed token for {}: {}", provider, e)
+                            }
+                        }
+                    }
+                    Err(RefreshError::InvalidGrant) => {
+                        warn!(
+                            "Refresh token for {} was rejected; stopping auto-refresh",
+                            provider
+                        );
+                        break;
+                    }
+                    Err(e) => warn!("Token refresh failed for {}: {}", provider, e),
+                }
+            }
+            Ok(_) => {}
+            // NotAuthenticated: nothing to refresh until the user logs in
+            Err(StorageError::NotFound(_)) => {
+                debug!("No stored token for {}, skipping refresh", provider);
+            }
+            Err(e) => warn!("Failed to read token for {}: {}", provider, e),
+        }
+
+        tokio::select! {
+            _ = shutdown.cancelled() => break,
+            _ = tokio::time::sleep(options.check_interval) => {}
+        }
+    }
+
+    debug!("Auto-refresh task for {} stopped", provider);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device_code::{DeviceCodeProvider, TokenResponse};
+    use crate::device_code_azure::AzureDeviceCode;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use tempfile::TempDir;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn token_response(access_token: &str, expires_in: u64) -> TokenResponse {
+        TokenResponse {
+            access_token: access_token.to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: Some(expires_in),
+            refresh_token: Some("refresh-1".to_string()),
+            scope: None,
+            id_token: None,
+        }
+    }
+
+    #[test]
+    fn test_refresh_due() {
+        let token = StoredToken {
+            provider: DeviceCodeProvider::Azure,
+            access_token: "a".to_string(),
+            refresh_token: Some("r".to_string()),
+            expires_at: Some(1_000),
+            scope: None,
+            stored_at: 0,
+        };
+        let margin = Duration::from_secs(300);
+        assert!(!refresh_due(&token, 699, margin));
+        assert!(refresh_due(&token, 700, margin));
+
+        let no_refresh = StoredToken {
+            refresh_token: None,
+            ..token.clone()
+        };
+        assert!(!refresh_due(&no_refresh, 900, margin));
+
+        let no_expiry = StoredToken {
+            expires_at: None,
+            ..token
+        };
+        assert!(!refresh_due(&no_expiry, i64::MAX, margin));
+    }
+
+    #[tokio::test]
+    async fn test_refreshes_before_expiry_with_fake_clock() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/contoso/oauth2/v2.0/token"))
+            .and(body_string_contains("refresh_token=refresh-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "refreshed",
+                "token_type": "Bearer",
+                "expires_in": 3600,
+                "refresh_token": "refresh-2"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = TempDir::new().unwrap();
+        let storage = Arc::new(DeviceCodeTokenStorage::with_path_and_keyring(
+            dir.path().join("tokens.json"),
+            None,
+        ));
+        let start = chrono::Utc::now().timestamp();
+        storage
+            .update_access_token_at(
+                DeviceCodeProvider::Azure,
+                token_response("original", 600),
+                start,
+            )
+            .unwrap();
+        let original_expiry = start + 600;
+
+        let now = Arc::new(AtomicI64::new(start));
+        let clock_now = Arc::clone(&now);
+        let options = RefreshTaskOptions {
+            check_interval: Duration::from_millis(10),
+            clock: Arc::new(move || clock_now.load(Ordering::SeqCst)),
+            ..Default::default()
+        };
+
+        let auth = Arc::new(
+            AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+                .with_authority(server.uri()),
+        );
+        let shutdown = CancellationToken::new();
+        let handle = auth.spawn_refresh_task(Arc::clone(&storage), options, shutdown.clone());
+
+        // Well outside the margin: nothing happens
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(
+            storage
+                .get_token(DeviceCodeProvider::Azure)
+                .unwrap()
+                .access_token,
+            "original"
+        );
+
+        // Inside the 5 minute margin but before expiry
+        now.store(start + 301, Ordering::SeqCst);
+        let mut refreshed = None;
+        for _ in 0..200 {
+            let token = storage.get_token(DeviceCodeProvider::Azure).unwrap();
+            if token.access_token == "refreshed" {
+                refreshed = Some(token);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let token = refreshed.expect("token refreshed before expiry");
+        assert!(now.load(Ordering::SeqCst) < original_expiry);
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh-2"));
+        assert_eq!(token.expires_at, Some(start + 301 + 3600));
+
+        shutdown.cancel();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_skips_not_authenticated_and_stops_on_shutdown() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let dir = TempDir::new().unwrap();
+        let storage = Arc::new(DeviceCodeTokenStorage::with_path_and_keyring(
+            dir.path().join("tokens.json"),
+            None,
+        ));
+        let options = RefreshTaskOptions {
+            check_interval: Duration::from_millis(10),
+            clock: Arc::new(|| i64::MAX),
+            ..Default::default()
+        };
+
+        let auth = Arc::new(
+            AzureDeviceCode::new("contoso".to_string(), "client-123".to_string())
+                .with_authority(server.uri()),
+        );
+        let shutdown = CancellationToken::new();
+        let handle = auth.spawn_refresh_task(storage, options, shutdown.clone());
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        shutdown.cancel();
+        tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("task stops on shutdown")
+            .unwrap();
+    }
+}
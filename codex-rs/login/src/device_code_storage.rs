@@ -12,7 +12,7 @@
 //! - Fallback: ~/.codex/device_tokens.json (file-based, chmod 600)
 //! - Migration: File tokens auto-migrate to keyring on first load
 
-use crate::device_code::{DeviceCodeProvider, StoredToken, TokenResponse};
+use crate::device_code::{DeviceCodeAuth, DeviceCodeProvider, StoredToken, TokenResponse};
 use codex_keyring_store::{CredentialStoreError, DefaultKeyringStore, KeyringStore};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -32,6 +32,9 @@ const KEYRING_SERVICE: &str = "codex-cli";
 /// Keyring account prefix for device code tokens
 const KEYRING_ACCOUNT_PREFIX: &str = "device-token-";
 
+/// Separator between provider and account label in storage keys
+const ACCOUNT_SEPARATOR: char = ':';
+
 /// Storage errors
 #[derive(Debug, Error)]
 pub enum StorageError {
@@ -51,7 +54,7 @@ pub enum StorageError {
 /// Token storage file format
 #[derive(Debug, Default, Serialize, Deserialize)]
 struct TokenStore {
-    /// Map of provider name to stored token
+    /// Map of storage key (provider name, or `provider:label`) to stored token
     tokens: HashMap<String, StoredToken>,
     /// Schema version for migration
     #[serde(default = "default_version")]
@@ -142,57 +145,86 @@ impl DeviceCodeTokenStorage {
         self.keyring.is_some()
     }
 
-    /// Get keyring account name for a provider
-    fn keyring_account(provider: DeviceCodeProvider) -> String {
-        format!("{}{}", KEYRING_ACCOUNT_PREFIX, provider.as_str())
+    /// Get keyring account name for a storage key
+    fn keyring_account(key: &str) -> String {
+        format!("{KEYRING_ACCOUNT_PREFIX}{key}")
+    }
+
+    /// Storage key for a provider account
+    ///
+    /// The default (unlabeled) account keeps the bare provider name so tokens
+    /// stored before multi-account support still resolve. Labels are trimmed,
+    /// and a blank label means the default account.
+    fn store_key(provider: DeviceCodeProvider, account_label: Option<&str>) -> String {
+        match account_label.map(str::trim) {
+            Some(label) if !label.is_empty() => {
+                format!("{}{}{}", provider.as_str(), ACCOUNT_SEPARATOR, label)
+            }
+            _ => provider.as_str().to_string(),
+        }
+    }
+
+    /// Split a storage key back into provider and account label
+    fn parse_store_key(key: &str) -> Option<(DeviceCodeProvider, Option<String>)> {
+        let (name, label) = match key.split_once(ACCOUNT_SEPARATOR) {
+            Some((name, label)) if !label.trim().is_empty() => {
+                (name, Some(label.trim().to_string()))
+            }
+            Some((name, _)) => (name, None),
+            None => (key, None),
+        };
+        let provider = match name {
+            "openai" => DeviceCodeProvider::OpenAI,
+            "google" => DeviceCodeProvider::Google,
+            "anthropic" => DeviceCodeProvider::Anthropic,
+            "azure" => DeviceCodeProvider::Azure,
+            _ => return None,
+        };
+        Some((provider, label))
     }
 
     /// Load token from keyring (P53-SYNC)
-    fn load_from_keyring(&self, provider: DeviceCodeProvider) -> Option<StoredToken> {
+    fn load_from_keyring(&self, key: &str) -> Option<StoredToken> {
         let keyring = self.keyring.as_ref()?;
-        let account = Self::keyring_account(provider);
+        let account = Self::keyring_account(key);
 
         match keyring.load(KEYRING_SERVICE, &account) {
             Ok(Some(json)) => {
-                trace!("keyring.load success for {}", provider.as_str());
+                trace!("keyring.load success for {}", key);
                 match serde_json::from_str(&json) {
                     Ok(token) => Some(token),
                     Err(e) => {
-                        warn!(
-                            "Failed to parse keyring token for {}: {}",
-                            provider.as_str(),
-                            e
-                        );
+                        warn!("Failed to parse keyring token for {}: {}", key, e);
                         None
                     }
                 }
             }
             Ok(None) => {
-                trace!("keyring.load no entry for {}", provider.as_str());
+                trace!("keyring.load no entry for {}", key);
                 None
             }
             Err(e) => {
-                warn!("keyring.load error for {}: {}", provider.as_str(), e);
+                warn!("keyring.load error for {}: {}", key, e);
                 None
             }
         }
     }
 
     /// Save token to keyring (P53-SYNC)
-    fn save_to_keyring(&self, provider: DeviceCodeProvider, token: &StoredToken) -> bool {
+    fn save_to_keyring(&self, key: &str, token: &StoredToken) -> bool {
         let Some(keyring) = self.keyring.as_ref() else {
             return false;
         };
-        let account = Self::keyring_account(provider);
+        let account = Self::keyring_account(key);
 
         match serde_json::to_string(token) {
             Ok(json) => match keyring.save(KEYRING_SERVICE, &account, &json) {
                 Ok(()) => {
-                    trace!("keyring.save success for {}", provider.as_str());
+                    trace!("keyring.save success for {}", key);
                     true
                 }
                 Err(e) => {
-                    warn!("keyring.save error for {}: {}", provider.as_str(), e);
+                    warn!("keyring.save error for {}: {}", key, e);
                     false
                 }
             },
@@ -204,23 +236,19 @@ impl DeviceCodeTokenStorage {
     }
 
     /// Delete token from keyring (P53-SYNC)
-    fn delete_from_keyring(&self, provider: DeviceCodeProvider) -> bool {
+    fn delete_from_keyring(&self, key: &str) -> bool {
         let Some(keyring) = self.keyring.as_ref() else {
             return false;
         };
-        let account = Self::keyring_account(provider);
+        let account = Self::keyring_account(key);
 
         match keyring.delete(KEYRING_SERVICE, &account) {
             Ok(existed) => {
-                trace!(
-                    "keyring.delete success for {} (existed: {})",
-                    provider.as_str(),
-                    existed
-                );
+                trace!("keyring.delete success for {} (existed: {})", key, existed);
                 true
             }
             Err(e) => {
-                warn!("keyring.delete error for {}: {}", provider.as_str(), e);
+                warn!("keyring.delete error for {}: {}", key, e);
                 false
             }
         }
@@ -278,24 +306,34 @@ impl DeviceCodeTokenStorage {
         provider: DeviceCodeProvider,
         response: TokenResponse,
     ) -> Result<(), StorageError> {
+        self.store_account_token(provider, None, response)
+    }
+
+    /// Store a token for a labeled account of a provider
+    ///
+    /// `None` (or an empty label) targets the provider's default account.
+    pub fn store_account_token(
+        &self,
+        provider: DeviceCodeProvider,
+        account_label: Option<&str>,
+        response: TokenResponse,
+    ) -> Result<(), StorageError> {
+        let key = Self::store_key(provider, account_label);
         let token = StoredToken::from_response(provider, response);
 
         // P53-SYNC: Save to keyring first (primary storage)
-        let keyring_saved = self.save_to_keyring(provider, &token);
+        let keyring_saved = self.save_to_keyring(&key, &token);
         if keyring_saved {
-            debug!("Token saved to keyring for {}", provider.as_str());
+            debug!("Token saved to keyring for {}", key);
         }
 
         // Always save to file as backup (or as primary if keyring unavailable)
         let mut store = self.read_store()?;
-        store.tokens.insert(provider.as_str().to_string(), token);
+        store.tokens.insert(key.clone(), token);
         self.write_store(&store)?;
 
         if !keyring_saved && self.keyring.is_some() {
-            warn!(
-                "Token saved to file only (keyring save failed) for {}",
-                provider.as_str()
-            );
+            warn!("Token saved to file only (keyring save failed) for {}", key);
         }
 
         Ok(())
@@ -305,9 +343,20 @@ impl DeviceCodeTokenStorage {
     ///
     /// P53-SYNC: Tries keyring first, falls back to file, migrates if found in file only.
     pub fn get_token(&self, provider: DeviceCodeProvider) -> Result<StoredToken, StorageError> {
+        self.get_account_token(provider, None)
+    }
+
+    /// Get a stored token for a labeled account of a provider
+    pub fn get_account_token(
+        &self,
+        provider: DeviceCodeProvider,
+        account_label: Option<&str>,
+    ) -> Result<StoredToken, StorageError> {
+        let key = Self::store_key(provider, account_label);
+
         // P53-SYNC: Try keyring first (primary storage)
-        if let Some(token) = self.load_from_keyring(provider) {
-            trace!("Token loaded from keyring for {}", provider.as_str());
+        if let Some(token) = self.load_from_keyring(&key) {
+            trace!("Token loaded from keyring for {}", key);
             return Ok(token);
         }
 
@@ -315,16 +364,13 @@ impl DeviceCodeTokenStorage {
         let store = self.read_store()?;
         let token = store
             .tokens
-            .get(provider.as_str())
+            .get(&key)
             .cloned()
-            .ok_or_else(|| StorageError::NotFound(provider.to_string()))?;
+            .ok_or_else(|| StorageError::NotFound(key.clone()))?;
 
         // P53-SYNC: Migrate file token to keyring
-        if self.keyring.is_some() && self.save_to_keyring(provider, &token) {
-            debug!(
-                "Migrated token from file to keyring for {}",
-                provider.as_str()
-            );
+        if self.keyring.is_some() && self.save_to_keyring(&key, &token) {
+            debug!("Migrated token from file to keyring for {}", key);
         }
 
         Ok(token)
@@ -348,12 +394,47 @@ impl DeviceCodeTokenStorage {
         &self,
         provider: DeviceCodeProvider,
         response: TokenResponse,
+    ) -> Result<(), StorageError> {
+        self.update_access_token_at(provider, response, chrono::Utc::now().timestamp())
+    }
+
+    /// Update the access token, computing expiry relative to `now` (Unix timestamp)
+    pub(crate) fn update_access_token_at(
+        &self,
+        provider: DeviceCodeProvider,
+        response: TokenResponse,
+        now: i64,
+    ) -> Result<(), StorageError> {
+        self.update_account_access_token_at(provider, None, response, now)
+    }
+
+    /// Update the access token of a labeled account after a refresh
+    pub fn update_account_access_token(
+        &self,
+        provider: DeviceCodeProvider,
+        account_label: Option<&str>,
+        response: TokenResponse,
+    ) -> Result<(), StorageError> {
+        self.update_account_access_token_at(
+            provider,
+            account_label,
+            response,
+            chrono::Utc::now().timestamp(),
+        )
+    }
+
+    /// Update a labeled account's access token, computing expiry relative to `now`
+    pub(crate) fn update_account_access_token_at(
+        &self,
+        provider: DeviceCodeProvider,
+        account_label: Option<&str>,
+        response: TokenResponse,
+        now: i64,
     ) -> Result<(), StorageError> {
         let mut store = self.read_store()?;
-        let key = provider.as_str().to_string();
+        let key = Self::store_key(provider, account_label);
 
         if let Some(existing) = store.tokens.get_mut(&key) {
-            let now = chrono::Utc::now().timestamp();
             existing.access_token = response.access_token;
             existing.expires_at = response.expires_in.map(|secs| now + secs as i64);
 
@@ -363,10 +444,13 @@ impl DeviceCodeTokenStorage {
             }
 
             existing.stored_at = now;
+
+            // Keep keyring in sync, otherwise get_token keeps serving the stale token
+            self.save_to_keyring(&key, existing);
             self.write_store(&store)
         } else {
             // No existing token, store as new
-            self.store_token(provider, response)
+            self.store_account_token(provider, account_label, response)
         }
     }
 
@@ -374,55 +458,115 @@ impl DeviceCodeTokenStorage {
     ///
     /// P53-SYNC: Removes from both keyring and file storage.
     pub fn remove_token(&self, provider: DeviceCodeProvider) -> Result<(), StorageError> {
+        self.remove_account_token(provider, None)
+    }
+
+    /// Remove the token for a labeled account of a provider
+    pub fn remove_account_token(
+        &self,
+        provider: DeviceCodeProvider,
+        account_label: Option<&str>,
+    ) -> Result<(), StorageError> {
+        let key = Self::store_key(provider, account_label);
+
         // P53-SYNC: Remove from keyring
-        self.delete_from_keyring(provider);
+        self.delete_from_keyring(&key);
 
         // Remove from file storage
         let mut store = self.read_store()?;
-        store.tokens.remove(provider.as_str());
+        store.tokens.remove(&key);
         self.write_store(&store)
     }
 
+    /// Log out of a provider: revoke its token remotely, then delete it locally
+    ///
+    /// Revocation is best effort; the local token is removed even if the
+    /// provider rejects or cannot be reached for the revoke call.
+    pub async fn logout(&self, auth: &dyn DeviceCodeAuth) -> Result<(), StorageError> {
+        self.logout_account(auth, None).await
+    }
+
+    /// Log out of a labeled account of a provider
+    pub async fn logout_account(
+        &self,
+        auth: &dyn DeviceCodeAuth,
+        account_label: Option<&str>,
+    ) -> Result<(), StorageError> {
+        let provider = auth.provider();
+        let key = Self::store_key(provider, account_label);
+
+        match self.get_account_token(provider, account_label) {
+            Ok(token) => {
+                if let Err(e) = auth.revoke(&token).await {
+                    warn!("Token revocation failed for {}: {}", key, e);
+                }
+            }
+            Err(StorageError::NotFound(_)) => {}
+            Err(e) => warn!("Failed to read token for {}: {}", key, e),
+        }
+
+        self.remove_account_token(provider, account_label)
+    }
+
     /// List all providers with stored tokens
     pub fn list_providers(&self) -> Result<Vec<DeviceCodeProvider>, StorageError> {
+        let mut providers = Vec::new();
+        for (provider, _) in self.list_accounts()? {
+            if !providers.contains(&provider) {
+                providers.push(provider);
+            }
+        }
+        Ok(providers)
+    }
+
+    /// List all stored accounts as (provider, account label) pairs
+    ///
+    /// The default account of each provider has no label.
+    pub fn list_accounts(&self) -> Result<Vec<(DeviceCodeProvider, Option<String>)>, StorageError> {
         let store = self.read_store()?;
-        Ok(store
+        let mut accounts: Vec<_> = store
             .tokens
             .keys()
-            .filter_map(|k| match k.as_str() {
-                "openai" => Some(DeviceCodeProvider::OpenAI),
-                "google" => Some(DeviceCodeProvider::Google),
-                "anthropic" => Some(DeviceCodeProvider::Anthropic),
-                _ => None,
-            })
-            .collect())
+            .filter_map(|k| Self::parse_store_key(k))
+            .collect();
+        accounts.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()).then_with(|| a.1.cmp(&b.1)));
+        Ok(accounts)
     }
 
     /// Get status summary for all providers
-    pub fn status_summary(&self) -> Result<Vec<(DeviceCodeProvider, TokenStatus)>, StorageError> {
+    ///
+    /// Each provider's default account is always listed (possibly as
+    /// `NotAuthenticated`), followed by its labeled accounts in label order.
+    pub fn status_summary(
+        &self,
+    ) -> Result<Vec<(DeviceCodeProvider, Option<String>, TokenStatus)>, StorageError> {
         let store = self.read_store()?;
-        let mut results = Vec::new();
+        let mut labeled: Vec<_> = store
+            .tokens
+            .iter()
+            .filter_map(|(key, token)| match Self::parse_store_key(key) {
+                Some((provider, Some(label))) => Some((provider, label, token)),
+                _ => None,
+            })
+            .collect();
+        labeled.sort_by(|a, b| a.1.cmp(&b.1));
 
+        let mut results = Vec::new();
         for provider in [
             DeviceCodeProvider::OpenAI,
             DeviceCodeProvider::Google,
             DeviceCodeProvider::Anthropic,
+            DeviceCodeProvider::Azure,
         ] {
             let status = match store.tokens.get(provider.as_str()) {
-                Some(token) => {
-                    if token.is_expired() {
-                        if token.can_refresh() {
-                            TokenStatus::NeedsRefresh
-                        } else {
-                            TokenStatus::Expired
-                        }
-                    } else {
-                        TokenStatus::Valid
-                    }
-                }
+                Some(token) => TokenStatus::of(token),
                 None => TokenStatus::NotAuthenticated,
             };
-            results.push((provider, status));
+            results.push((provider, None, status));
+
+            for (_, label, token) in labeled.iter().filter(|(p, _, _)| *p == provider) {
+                results.push((provider, Some(label.clone()), TokenStatus::of(token)));
+            }
         }
 
         Ok(results)
@@ -452,6 +596,19 @@ pub enum TokenStatus {
 }
 
 impl TokenStatus {
+    /// Status of a stored token
+    fn of(token: &StoredToken) -> Self {
+        if token.is_expired() {
+            if token.can_refresh() {
+                Self::NeedsRefresh
+            } else {
+                Self::Expired
+            }
+        } else {
+            Self::Valid
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::NotAuthenticated => "not authenticated",
@@ -570,6 +727,32 @@ mod tests {
         assert!(!storage.has_token(DeviceCodeProvider::OpenAI));
     }
 
+    #[tokio::test]
+    async fn test_logout_removes_token_when_revoke_fails() {
+        use crate::device_code_openai::OpenAIDeviceCode;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/revoke"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let (_dir, storage) = test_storage();
+        storage
+            .store_token(DeviceCodeProvider::OpenAI, make_token_response())
+            .unwrap();
+
+        let auth =
+            OpenAIDeviceCode::with_client_id("client".to_string()).with_auth_base(server.uri());
+        storage.logout(&auth).await.unwrap();
+
+        assert!(!storage.has_token(DeviceCodeProvider::OpenAI));
+    }
+
     #[test]
     fn test_list_providers() {
         let (_dir, storage) = test_storage();
@@ -596,30 +779,159 @@ mod tests {
             .unwrap();
 
         let summary = storage.status_summary().unwrap();
-        assert_eq!(summary.len(), 3); // OpenAI, Google, Anthropic
+        assert_eq!(summary.len(), 4); // OpenAI, Google, Anthropic, Azure
 
         let openai_status = summary
             .iter()
-            .find(|(p, _)| *p == DeviceCodeProvider::OpenAI)
-            .map(|(_, s)| *s)
+            .find(|(p, _, _)| *p == DeviceCodeProvider::OpenAI)
+            .map(|(_, _, s)| *s)
             .unwrap();
         assert_eq!(openai_status, TokenStatus::Valid);
 
         let google_status = summary
             .iter()
-            .find(|(p, _)| *p == DeviceCodeProvider::Google)
-            .map(|(_, s)| *s)
+            .find(|(p, _, _)| *p == DeviceCodeProvider::Google)
+            .map(|(_, _, s)| *s)
             .unwrap();
         assert_eq!(google_status, TokenStatus::NotAuthenticated);
 
         let anthropic_status = summary
             .iter()
-            .find(|(p, _)| *p == DeviceCodeProvider::Anthropic)
-            .map(|(_, s)| *s)
+            .find(|(p, _, _)| *p == DeviceCodeProvider::Anthropic)
+            .map(|(_, _, s)| *s)
             .unwrap();
         assert_eq!(anthropic_status, TokenStatus::NotAuthenticated);
     }
 
+    #[test]
+    fn test_multiple_labeled_accounts() {
+        let (_dir, storage) = test_storage();
+
+        let mut work = make_token_response();
+        work.access_token = "work-token".to_string();
+        let mut personal = make_token_response();
+        personal.access_token = "personal-token".to_string();
+
+        storage
+            .store_account_token(DeviceCodeProvider::Google, Some("work"), work)
+            .unwrap();
+        storage
+            .store_account_token(DeviceCodeProvider::Google, Some("personal"), personal)
+            .unwrap();
+
+        let summary = storage.status_summary().unwrap();
+        assert_eq!(summary.len(), 6); // 4 default accounts + 2 labeled Google accounts
+        let google: Vec<_> = summary
+            .iter()
+            .filter(|(p, _, _)| *p == DeviceCodeProvider::Google)
+            .map(|(_, label, status)| (label.as_deref(), *status))
+            .collect();
+        assert_eq!(
+            google,
+            vec![
+                (None, TokenStatus::NotAuthenticated),
+                (Some("personal"), TokenStatus::Valid),
+                (Some("work"), TokenStatus::Valid),
+            ]
+        );
+
+        let work = storage
+            .get_account_token(DeviceCodeProvider::Google, Some("work"))
+            .unwrap();
+        assert_eq!(work.access_token, "work-token");
+        let personal = storage
+            .get_account_token(DeviceCodeProvider::Google, Some("personal"))
+            .unwrap();
+        assert_eq!(personal.access_token, "personal-token");
+        assert!(!storage.has_token(DeviceCodeProvider::Google));
+        assert_eq!(
+            storage.list_providers().unwrap(),
+            vec![DeviceCodeProvider::Google]
+        );
+
+        storage
+            .remove_account_token(DeviceCodeProvider::Google, Some("work"))
+            .unwrap();
+        assert!(
+            storage
+                .get_account_token(DeviceCodeProvider::Google, Some("personal"))
+                .is_ok()
+        );
+        assert_eq!(
+            storage.list_accounts().unwrap(),
+            vec![(DeviceCodeProvider::Google, Some("personal".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_account_labels_are_normalized() {
+        let (_dir, storage) = test_storage();
+
+        storage
+            .store_account_token(
+                DeviceCodeProvider::Google,
+                Some("  "),
+                make_token_response(),
+            )
+            .unwrap();
+        assert!(storage.has_token(DeviceCodeProvider::Google));
+
+        storage
+            .store_account_token(
+                DeviceCodeProvider::Google,
+                Some(" work "),
+                make_token_response(),
+            )
+            .unwrap();
+        assert!(
+            storage
+                .get_account_token(DeviceCodeProvider::Google, Some("work"))
+                .is_ok()
+        );
+        assert_eq!(
+            storage.list_accounts().unwrap(),
+            vec![
+                (DeviceCodeProvider::Google, None),
+                (DeviceCodeProvider::Google, Some("work".to_string())),
+            ]
+        );
+        assert_eq!(
+            DeviceCodeTokenStorage::parse_store_key("google:"),
+            Some((DeviceCodeProvider::Google, None))
+        );
+    }
+
+    #[test]
+    fn test_update_labeled_account_access_token() {
+        let (_dir, storage) = test_storage();
+
+        storage
+            .store_token(DeviceCodeProvider::Google, make_token_response())
+            .unwrap();
+        storage
+            .store_account_token(
+                DeviceCodeProvider::Google,
+                Some("work"),
+                make_token_response(),
+            )
+            .unwrap();
+
+        let mut refreshed = make_token_response();
+        refreshed.access_token = "refreshed-work-token".to_string();
+        refreshed.refresh_token = None;
+        storage
+            .update_account_access_token(DeviceCodeProvider::Google, Some("work"), refreshed)
+            .unwrap();
+
+        let work = storage
+            .get_account_token(DeviceCodeProvider::Google, Some("work"))
+            .unwrap();
+        assert_eq!(work.access_token, "refreshed-work-token");
+        assert_eq!(work.refresh_token, Some("test-refresh-token".to_string()));
+        let default = storage.get_token(DeviceCodeProvider::Google).unwrap();
+        assert_eq!(default.access_token, "test-access-token");
+    }
+
     #[test]
     fn test_update_access_token() {
         let (_dir, storage) = test_storage();
@@ -669,7 +981,7 @@ mod tests {
             .unwrap();
 
         // Verify it was saved to keyring
-        let account = DeviceCodeTokenStorage::keyring_account(DeviceCodeProvider::OpenAI);
+        let account = DeviceCodeTokenStorage::keyring_account(DeviceCodeProvider::OpenAI.as_str());
         assert!(
             keyring.saved_value(&account).is_some(),
             "Token should be saved to keyring"
@@ -697,7 +1009,7 @@ mod tests {
             DeviceCodeTokenStorage::with_path_and_keyring(path, Some(keyring.clone()));
 
         // Keyring should be empty initially
-        let account = DeviceCodeTokenStorage::keyring_account(DeviceCodeProvider::OpenAI);
+        let account = DeviceCodeTokenStorage::keyring_account(DeviceCodeProvider::OpenAI.as_str());
         assert!(keyring.saved_value(&account).is_none());
 
         // Step 3: Get token - should migrate from file to keyring
@@ -721,7 +1033,7 @@ mod tests {
         storage
             .store_token(DeviceCodeProvider::OpenAI, make_token_response())
             .unwrap();
-        let account = DeviceCodeTokenStorage::keyring_account(DeviceCodeProvider::OpenAI);
+        let account = DeviceCodeTokenStorage::keyring_account(DeviceCodeProvider::OpenAI.as_str());
         assert!(keyring.saved_value(&account).is_some());
 
         storage.remove_token(DeviceCodeProvider::OpenAI).unwrap();
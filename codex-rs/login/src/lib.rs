@@ -4,8 +4,11 @@ mod server;
 // P6-SYNC Phase 5: Device Code Authorization Flow
 pub mod device_code;
 pub mod device_code_anthropic;
+pub mod device_code_azure;
 pub mod device_code_google;
 pub mod device_code_openai;
+pub mod device_code_poll;
+pub mod device_code_refresh;
 pub mod device_code_storage;
 
 pub use server::LoginServer;
@@ -19,8 +22,11 @@ pub use device_code::{
     RefreshError, StoredToken, TokenResponse,
 };
 pub use device_code_anthropic::AnthropicDeviceCode;
+pub use device_code_azure::AzureDeviceCode;
 pub use device_code_google::GoogleDeviceCode;
 pub use device_code_openai::OpenAIDeviceCode;
+pub use device_code_poll::{PollProgress, PollProgressStream};
+pub use device_code_refresh::RefreshTaskOptions;
 pub use device_code_storage::{DeviceCodeTokenStorage, TokenStatus};
 
 // Re-export commonly used auth types and helpers from codex-core for compatibility
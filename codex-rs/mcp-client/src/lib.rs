@@ -1,3 +1,4 @@
 mod mcp_client;
 
 pub use mcp_client::McpClient;
+pub use mcp_client::McpRequestTimeout;
@@ -14,6 +14,7 @@
 use std::collections::HashMap;
 use std::ffi::OsString;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
@@ -59,6 +60,21 @@ const CHANNEL_CAPACITY: usize = 128;
 /// Internal representation of a pending request sender.
 type PendingSender = oneshot::Sender<JSONRPCMessage>;
 
+/// Error returned when a request does not receive a response within the
+/// timeout passed to [`McpClient::send_request`].
+#[derive(Debug, Clone, Copy)]
+pub struct McpRequestTimeout {
+    pub timeout: Duration,
+}
+
+impl std::fmt::Display for McpRequestTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "request timed out after {:?}", self.timeout)
+    }
+}
+
+impl std::error::Error for McpRequestTimeout {}
+
 /// A running MCP client instance.
 pub struct McpClient {
     /// Retain this child process until the client is dropped. The Tokio runtime
@@ -76,6 +92,10 @@ pub struct McpClient {
 
     /// Monotonically increasing counter used to generate request IDs.
     id_counter: AtomicI64,
+
+    /// Set once the server's stdout closes or its stdin can no longer be
+    /// written, i.e. the transport is gone for good.
+    closed: Arc<AtomicBool>,
 }
 
 impl McpClient {
@@ -111,11 +131,13 @@ impl McpClient {
 
         let (outgoing_tx, mut outgoing_rx) = mpsc::channel::<JSONRPCMessage>(CHANNEL_CAPACITY);
         let pending: Arc<Mutex<HashMap<i64, PendingSender>>> = Arc::new(Mutex::new(HashMap::new()));
+        let closed = Arc::new(AtomicBool::new(false));
 
         // Spawn writer task. It listens on the `outgoing_rx` channel and
         // writes messages to the child's STDIN.
         let writer_handle = {
             let mut stdin = stdin;
+            let closed = closed.clone();
             tokio::spawn(async move {
                 while let Some(msg) = outgoing_rx.recv().await {
                     match serde_json::to_string(&msg) {
@@ -123,10 +145,12 @@ impl McpClient {
                             debug!("MCP message to server: {json}");
                             if stdin.write_all(json.as_bytes()).await.is_err() {
                                 error!("failed to write message to child stdin");
+                                closed.store(true, Ordering::SeqCst);
                                 break;
                             }
                             if stdin.write_all(b"\n").await.is_err() {
                                 error!("failed to write newline to child stdin");
+                                closed.store(true, Ordering::SeqCst);
                                 break;
                             }
                             // No explicit flush needed on a pipe; write_all is sufficient.
@@ -141,6 +165,7 @@ impl McpClient {
         // STDOUT and dispatches responses to the pending map.
         let reader_handle = {
             let pending = pending.clone();
+            let closed = closed.clone();
             // Use a larger buffer size (1MB) to handle large tool responses
             let mut lines = BufReader::with_capacity(1024 * 1024, stdout).lines();
 
@@ -168,6 +193,12 @@ impl McpClient {
                         }
                     }
                 }
+
+                // The server exited or closed stdout. Fail every outstanding
+                // request now instead of leaving callers waiting for a reply
+                // that can never arrive.
+                closed.store(true, Ordering::SeqCst);
+                pending.lock().await.clear();
             })
         };
 
@@ -182,14 +213,21 @@ impl McpClient {
             outgoing_tx,
             pending,
             id_counter: AtomicI64::new(1),
+            closed,
         })
     }
 
+    /// Returns `true` once the connection to the server has been lost. A
+    /// closed client never recovers; spawn a new one to reconnect.
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
     /// Send an arbitrary MCP request and await the typed result.
     ///
     /// If `timeout` is `None` the call waits indefinitely. If `Some(duration)`
     /// is supplied and no response is received within the given period, a
-    /// timeout error is returned.
+    /// [`McpRequestTimeout`] error is returned and the pending entry is removed.
     pub async fn send_request<R>(
         &self,
         params: R::Params,
@@ -232,6 +270,13 @@ impl McpClient {
             guard.insert(id, tx);
         }
 
+        // Checked after registering so a concurrent close either sees our
+        // entry and drops it, or we see the flag here.
+        if self.is_closed() {
+            self.pending.lock().await.remove(&id);
+            return Err(anyhow!("connection to MCP server closed"));
+        }
+
         // Send to writer task.
         if self.outgoing_tx.send(message).await.is_err() {
             return Err(anyhow!(
@@ -256,7 +301,7 @@ impl McpClient {
                         // Timed out. Remove the pending entry so we don't leak.
                         let mut guard = self.pending.lock().await;
                         guard.remove(&id);
-                        return Err(anyhow!("request timed out"));
+                        return Err(McpRequestTimeout { timeout: duration }.into());
                     }
                 }
             }
@@ -1419,6 +1419,9 @@ fn convert_mcp_servers(
                         startup_timeout_sec: None,
                         startup_timeout_ms: None,
                         tool_timeout_sec: None,
+                        reconnect_attempts: None,
+                        lazy: false,
+                        tools: Vec::new(),
                     },
                 );
             }
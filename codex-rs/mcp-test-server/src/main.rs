@@ -16,7 +16,6 @@ enum Id {
 struct JsonRpcReq {
     jsonrpc: String,
     method: String,
-    #[allow(dead_code)]
     params: Option<serde_json::Value>,
     id: Option<Id>,
 }
@@ -41,6 +40,9 @@ fn sleep_env_ms(var: &str) {
 }
 
 fn main() {
+    if let Ok(marker) = env::var("STARTED_MARKER_FILE") {
+        let _ = std::fs::write(marker, b"started");
+    }
     let stdin = io::stdin();
     let mut stdout = io::stdout();
     for line in stdin.lock().lines() {
@@ -97,6 +99,42 @@ fn main() {
                 let _ = writeln!(stdout, "{}", serde_json::to_string(&resp).unwrap());
                 let _ = stdout.flush();
             }
+            "tools/call" => {
+                // Simulate a crash mid-session: the first call exits without
+                // replying and leaves a marker so the respawned server answers.
+                if let Ok(marker) = env::var("CRASH_ONCE_FILE")
+                    && std::fs::metadata(&marker).is_err()
+                {
+                    let _ = std::fs::write(&marker, b"crashed");
+                    std::process::exit(1);
+                }
+                // `delay_ms` simulates a slow tool.
+                if let Some(ms) = req
+                    .params
+                    .as_ref()
+                    .and_then(|p| p.pointer("/arguments/delay_ms"))
+                    .and_then(serde_json::Value::as_u64)
+                {
+                    thread::sleep(Duration::from_millis(ms));
+                }
+                let text = req
+                    .params
+                    .as_ref()
+                    .and_then(|p| p.pointer("/arguments/text"))
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default();
+                // `ECHO_PREFIX` tells otherwise identical servers apart.
+                let text = format!("{}{text}", env::var("ECHO_PREFIX").unwrap_or_default());
+                let result = json!({ "content": [{ "type": "text", "text": text }] });
+                let resp = JsonRpcResp {
+                    jsonrpc: "2.0".into(),
+                    id: req.id,
+                    result: Some(result),
+                    error: None,
+                };
+                let _ = writeln!(stdout, "{}", serde_json::to_string(&resp).unwrap());
+                let _ = stdout.flush();
+            }
             _ => {
                 // Unknown method -> echo minimal error structure
                 let err = json!({ "code": -32601, "message": "Method not found" });
@@ -26,6 +26,10 @@ use codex_core::pm::artifacts::{
 };
 use codex_core::pm::bot::{BotKind, BotWriteMode};
 use tokio::sync::Mutex;
+use tokio::sync::mpsc;
+
+/// Receives human-readable output lines as an engine progresses.
+pub type OutputSink = mpsc::UnboundedSender<String>;
 
 /// Result of an engine execution.
 pub struct EngineResult {
@@ -61,6 +65,8 @@ pub struct ResearchContext {
     /// If true, engine runs in degraded mode when NotebookLM unavailable
     /// instead of blocking. Default: true.
     pub allow_degraded: bool,
+    /// Optional sink for live output lines.
+    pub output: Option<OutputSink>,
 }
 
 impl ResearchContext {
@@ -71,6 +77,7 @@ impl ResearchContext {
             work_item_id: work_item_id.to_string(),
             notebooklm_health_url: None,
             allow_degraded: true,
+            output: None,
         }
     }
 }
@@ -81,15 +88,17 @@ struct CheckpointAccum {
     work_item_id: String,
     seq: u32,
     checkpoints: Vec<BotRunCheckpoint>,
+    output: Option<OutputSink>,
 }
 
 impl CheckpointAccum {
-    fn new(run_id: &str, work_item_id: &str) -> Self {
+    fn new(run_id: &str, work_item_id: &str, output: Option<OutputSink>) -> Self {
         Self {
             run_id: run_id.to_string(),
             work_item_id: work_item_id.to_string(),
             seq: 0,
             checkpoints: Vec::new(),
+            output,
         }
     }
 
@@ -107,6 +116,11 @@ impl CheckpointAccum {
         };
         self.checkpoints.push(cp);
         self.seq += 1;
+
+        if let Some(output) = &self.output {
+            // Subscribers are optional; a closed sink is not an error
+            let _ = output.send(format!("[{phase}] {summary}"));
+        }
     }
 }
 
@@ -234,6 +248,7 @@ pub async fn run_research(ctx: &ResearchContext) -> EngineResult {
     let cps = Arc::new(Mutex::new(CheckpointAccum::new(
         &ctx.run_id,
         &ctx.work_item_id,
+        ctx.output.clone(),
     )));
 
     // ── Phase 1: Dependency check ───────────────────────────────────────
@@ -501,6 +516,8 @@ pub struct ReviewContext {
     pub work_item_id: String,
     pub write_mode: BotWriteMode,
     pub rebase_target: Option<String>,
+    /// Optional sink for live output lines.
+    pub output: Option<OutputSink>,
 }
 
 /// Git subcommands that the review engine is permitted to execute.
@@ -551,6 +568,88 @@ fn run_git_in(dir: &Path, args: &[&str]) -> Result<std::process::Output, String>
         .map_err(|e| format!("git {subcommand}: {e}"))
 }
 
+/// Run an allowlisted git command in `dir`, streaming its stdout and stderr
+/// to the run's output sink while it runs.
+fn run_git_streamed(
+    ctx: &ReviewContext,
+    dir: &Path,
+    args: &[&str],
+) -> Result<std::process::Output, String> {
+    let Some(sink) = &ctx.output else {
+        return run_git_in(dir, args);
+    };
+    let subcommand = args.first().ok_or_else(|| "empty git args".to_string())?;
+    if !ALLOWED_GIT_SUBCOMMANDS.contains(subcommand) {
+        return Err(format!(
+            "git subcommand '{subcommand}' is not in the review engine allowlist"
+        ));
+    }
+
+    let mut cmd = std::process::Command::new("git");
+    cmd.args(args).current_dir(dir);
+    run_streamed(cmd, &format!("git {subcommand}"), sink)
+        .map_err(|e| format!("git {subcommand}: {e}"))
+}
+
+/// Run `cmd` to completion, sending each stdout and stderr line to `sink` as
+/// soon as it is read, tagged with `label` and the stream.
+fn run_streamed(
+    mut cmd: std::process::Command,
+    label: &str,
+    sink: &OutputSink,
+) -> std::io::Result<std::process::Output> {
+    let mut child = cmd
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()?;
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    // Drain both pipes concurrently so neither can fill and stall the child
+    let (stdout, stderr) = std::thread::scope(|scope| {
+        let stdout = scope.spawn(move || forward_lines(stdout, sink, label, "stdout"));
+        let stderr = scope.spawn(move || forward_lines(stderr, sink, label, "stderr"));
+        (
+            stdout.join().unwrap_or_default(),
+            stderr.join().unwrap_or_default(),
+        )
+    });
+    let status = child.wait()?;
+    Ok(std::process::Output {
+        status,
+        stdout,
+        stderr,
+    })
+}
+
+/// Read `pipe` to EOF, forwarding each line to `sink`; returns the raw bytes.
+fn forward_lines(
+    pipe: Option<impl std::io::Read>,
+    sink: &OutputSink,
+    label: &str,
+    stream: &str,
+) -> Vec<u8> {
+    use std::io::BufRead;
+
+    let mut captured = Vec::new();
+    let Some(pipe) = pipe else {
+        return captured;
+    };
+    let mut reader = std::io::BufReader::new(pipe);
+    let mut line = Vec::new();
+    while reader.read_until(b'\n', &mut line).unwrap_or(0) > 0 {
+        let text = String::from_utf8_lossy(&line);
+        // Subscribers are optional; a closed sink is not an error
+        let _ = sink.send(format!(
+            "[{label} {stream}] {}",
+            text.trim_end_matches(['\n', '\r'])
+        ));
+        captured.extend_from_slice(&line);
+        line.clear();
+    }
+    captured
+}
+
 /// RAII guard that removes a git worktree on drop.
 struct WorktreeGuard {
     workspace_path: PathBuf,
@@ -670,6 +769,7 @@ pub async fn run_review(ctx: &ReviewContext) -> EngineResult {
     let cps = Arc::new(Mutex::new(CheckpointAccum::new(
         &ctx.run_id,
         &ctx.work_item_id,
+        ctx.output.clone(),
     )));
 
     // ── Phase 1: Context gathering ──────────────────────────────────────
@@ -791,17 +891,19 @@ pub async fn run_review(ctx: &ReviewContext) -> EngineResult {
             cp.emit("worktree", "Creating bot worktree", Some(45));
         }
 
+        let workspace_dir = Path::new(&ctx.workspace_path);
         let branch_name = format!("bot/review/{}", ctx.run_id);
         let worktree_dir = std::env::temp_dir().join(format!("review-{}", ctx.run_id));
 
         // Create bot branch at HEAD
-        if let Err(e) = run_git(&ctx.workspace_path, &["branch", &branch_name, base]) {
+        if let Err(e) = run_git_streamed(ctx, workspace_dir, &["branch", &branch_name, base]) {
             tracing::warn!("Failed to create branch: {e}");
         }
 
         // Create worktree
-        match run_git(
-            &ctx.workspace_path,
+        match run_git_streamed(
+            ctx,
+            workspace_dir,
             &[
                 "worktree",
                 "add",
@@ -876,12 +978,12 @@ pub async fn run_review(ctx: &ReviewContext) -> EngineResult {
 
         if !files_changed.is_empty() {
             // Stage and commit in worktree
-            let _ = run_git_in(&worktree_dir, &["add", "-A"]);
+            let _ = run_git_streamed(ctx, &worktree_dir, &["add", "-A"]);
             let commit_msg = format!("bot: review fixes for {}", ctx.work_item_id);
-            let _ = run_git_in(&worktree_dir, &["commit", "-m", &commit_msg]);
+            let _ = run_git_streamed(ctx, &worktree_dir, &["commit", "-m", &commit_msg]);
 
             // Get unified diff
-            if let Ok(output) = run_git_in(&worktree_dir, &["diff", "HEAD~1..HEAD"])
+            if let Ok(output) = run_git_streamed(ctx, &worktree_dir, &["diff", "HEAD~1..HEAD"])
                 && output.status.success()
             {
                 patch_diff = String::from_utf8_lossy(&output.stdout).to_string();
@@ -896,11 +998,12 @@ pub async fn run_review(ctx: &ReviewContext) -> EngineResult {
                     cp.emit("rebase", &format!("Rebasing onto {target}"), Some(70));
                 }
 
-                match run_git_in(&worktree_dir, &["rebase", target]) {
+                match run_git_streamed(ctx, &worktree_dir, &["rebase", target]) {
                     Ok(output) if output.status.success() => {
                         rebase_status = RebaseStatus::Clean;
                         // Update diff after rebase
-                        if let Ok(diff_out) = run_git_in(&worktree_dir, &["diff", "HEAD~1..HEAD"])
+                        if let Ok(diff_out) =
+                            run_git_streamed(ctx, &worktree_dir, &["diff", "HEAD~1..HEAD"])
                             && diff_out.status.success()
                         {
                             patch_diff = String::from_utf8_lossy(&diff_out.stdout).to_string();
@@ -915,7 +1018,7 @@ pub async fn run_review(ctx: &ReviewContext) -> EngineResult {
                         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
 
                         // Abort the failed rebase
-                        let _ = run_git_in(&worktree_dir, &["rebase", "--abort"]);
+                        let _ = run_git_streamed(ctx, &worktree_dir, &["rebase", "--abort"]);
 
                         // Parse conflicting files from stderr
                         let conflicting_files: Vec<String> = stderr
@@ -1070,6 +1173,8 @@ pub struct EngineParams {
     pub allow_degraded: Option<bool>,
     /// Override health URL for testing/debug.
     pub notebooklm_health_url: Option<String>,
+    /// Optional sink for live output lines.
+    pub output: Option<OutputSink>,
 }
 
 /// Dispatch to the appropriate engine.
@@ -1086,6 +1191,7 @@ pub async fn run_engine(params: EngineParams) -> EngineResult {
             if let Some(url) = params.notebooklm_health_url {
                 ctx.notebooklm_health_url = Some(url);
             }
+            ctx.output = params.output;
             run_research(&ctx).await
         }
         BotKind::Review => {
@@ -1095,6 +1201,7 @@ pub async fn run_engine(params: EngineParams) -> EngineResult {
                 work_item_id: params.work_item_id,
                 write_mode: params.write_mode,
                 rebase_target: params.rebase_target,
+                output: params.output,
             };
             run_review(&ctx).await
         }
@@ -1114,6 +1221,7 @@ mod tests {
             // Point to a URL that won't respond
             notebooklm_health_url: Some("http://127.0.0.1:1/nonexistent".to_string()),
             allow_degraded: false,
+            output: None,
         };
 
         let result = run_research(&ctx).await;
@@ -1124,6 +1232,28 @@ mod tests {
         assert!(!result.checkpoints.is_empty());
     }
 
+    #[tokio::test]
+    async fn research_streams_checkpoints_to_output_sink() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut ctx = ResearchContext::new("/tmp/nonexistent-workspace", "test-out", "SPEC-OUT");
+        ctx.notebooklm_health_url = Some("http://127.0.0.1:1/nonexistent".to_string());
+        ctx.allow_degraded = false;
+        ctx.output = Some(tx);
+
+        let result = run_research(&ctx).await;
+        drop(ctx);
+
+        let mut lines = Vec::new();
+        while let Some(line) = rx.recv().await {
+            lines.push(line);
+        }
+        assert_eq!(lines.len(), result.checkpoints.len());
+        assert_eq!(
+            lines[0],
+            "[dependency_check] Checking NotebookLM availability"
+        );
+    }
+
     #[tokio::test]
     async fn research_degrades_when_notebooklm_unavailable() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -1133,6 +1263,7 @@ mod tests {
             work_item_id: "SPEC-DEGRADED".to_string(),
             notebooklm_health_url: Some("http://127.0.0.1:1/nonexistent".to_string()),
             allow_degraded: true,
+            output: None,
         };
 
         let result = run_research(&ctx).await;
@@ -1178,6 +1309,7 @@ mod tests {
             work_item_id: "SPEC-WS-001".to_string(),
             notebooklm_health_url: Some("http://127.0.0.1:1/nonexistent".to_string()),
             allow_degraded: true,
+            output: None,
         };
 
         let result = run_research(&ctx).await;
@@ -1205,6 +1337,7 @@ mod tests {
             work_item_id: "SPEC-REVIEW-RO".to_string(),
             write_mode: BotWriteMode::None,
             rebase_target: None,
+            output: None,
         };
 
         let result = run_review(&ctx).await;
@@ -1220,6 +1353,58 @@ mod tests {
         assert!(!result.checkpoints.is_empty());
     }
 
+    #[test]
+    fn process_output_is_forwarded_line_by_line() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut cmd = std::process::Command::new("sh");
+        cmd.args([
+            "-c",
+            "printf 'one\\ntwo\\n'; printf 'warning: careful\\n' >&2",
+        ]);
+        let output = run_streamed(cmd, "git rebase", &tx).expect("run sh");
+        drop(tx);
+        assert_eq!(output.stdout, b"one\ntwo\n");
+        assert_eq!(output.stderr, b"warning: careful\n");
+
+        let mut lines = Vec::new();
+        while let Ok(line) = rx.try_recv() {
+            lines.push(line);
+        }
+        // The streams are read concurrently, so only per-stream order is fixed
+        lines.sort();
+        assert_eq!(
+            lines,
+            vec![
+                "[git rebase stderr] warning: careful",
+                "[git rebase stdout] one",
+                "[git rebase stdout] two",
+            ]
+        );
+    }
+
+    #[test]
+    fn streamed_output_arrives_before_slow_child_exits() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut cmd = std::process::Command::new("sh");
+        cmd.args(["-c", "echo first; sleep 2; echo second"]);
+        let run = std::thread::spawn(move || run_streamed(cmd, "git log", &tx));
+
+        let first = rx.blocking_recv().expect("first line");
+        assert_eq!(first, "[git log stdout] first");
+        assert!(
+            !run.is_finished(),
+            "first line was only observed after the child exited"
+        );
+
+        let output = run.join().unwrap().expect("run sh");
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"first\nsecond\n");
+        assert_eq!(
+            rx.blocking_recv().as_deref(),
+            Some("[git log stdout] second")
+        );
+    }
+
     #[tokio::test]
     async fn review_allowlist_blocks_disallowed_commands() {
         let result = run_git("/tmp", &["push", "origin", "main"]);
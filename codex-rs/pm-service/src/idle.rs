@@ -0,0 +1,129 @@
+//! Optional exit-when-idle watchdog (D135, D136).
+//!
+//! By default the service stays running while the user is logged in (D142).
+//! Setting `CODEX_PM_IDLE_TIMEOUT_SECS` opts into exiting once no runs are
+//! active, no clients are connected, and no request has arrived for that
+//! long; socket activation restarts the service on the next connection.
+//! `CODEX_PM_IDLE_POLL_SECS` controls how often idleness is checked.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::manager::BotRunManager;
+
+/// Environment variable enabling idle exit after N seconds without activity.
+pub const IDLE_TIMEOUT_ENV: &str = "CODEX_PM_IDLE_TIMEOUT_SECS";
+
+/// Environment variable overriding the idle check interval in seconds.
+pub const IDLE_POLL_ENV: &str = "CODEX_PM_IDLE_POLL_SECS";
+
+/// Default interval between idle checks.
+pub const DEFAULT_IDLE_POLL_INTERVAL_SECS: u64 = 10;
+
+/// Effective idle-exit settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleConfig {
+    /// Exit after this much inactivity; `None` keeps the service running.
+    pub timeout: Option<Duration>,
+    /// How often to check for idleness.
+    pub poll_interval: Duration,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            poll_interval: Duration::from_secs(DEFAULT_IDLE_POLL_INTERVAL_SECS),
+        }
+    }
+}
+
+impl IdleConfig {
+    /// Read settings from `CODEX_PM_IDLE_TIMEOUT_SECS` / `CODEX_PM_IDLE_POLL_SECS`.
+    pub fn from_env() -> Self {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Read settings through `lookup`; invalid or zero values fall back to defaults.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let defaults = Self::default();
+        Self {
+            timeout: parse_secs(IDLE_TIMEOUT_ENV, lookup(IDLE_TIMEOUT_ENV)),
+            poll_interval: parse_secs(IDLE_POLL_ENV, lookup(IDLE_POLL_ENV))
+                .unwrap_or(defaults.poll_interval),
+        }
+    }
+}
+
+/// Parse a positive number of seconds, warning about unusable values.
+fn parse_secs(name: &str, value: Option<String>) -> Option<Duration> {
+    let value = value?;
+    match value.trim().parse::<u64>() {
+        Ok(0) => {
+            tracing::warn!("{name}=0 is not allowed; using the default");
+            None
+        }
+        Ok(secs) => Some(Duration::from_secs(secs)),
+        Err(e) => {
+            tracing::warn!("Ignoring invalid {name}={value:?}: {e}");
+            None
+        }
+    }
+}
+
+/// Signal shutdown once the service has been idle for `config.timeout`.
+///
+/// Returns immediately when idle exit is disabled.
+pub async fn watch_idle(
+    manager: Arc<BotRunManager>,
+    config: IdleConfig,
+    shutdown_tx: tokio::sync::watch::Sender<bool>,
+) {
+    let Some(timeout) = config.timeout else {
+        return;
+    };
+
+    let mut ticker = tokio::time::interval(config.poll_interval);
+    loop {
+        ticker.tick().await;
+        if manager.active_run_count().await == 0
+            && manager.connection_count() == 0
+            && manager.last_activity_elapsed().await >= timeout
+        {
+            tracing::info!("Idle for {}s, shutting down", timeout.as_secs());
+            let _ = shutdown_tx.send(true);
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(timeout: Option<&str>, poll: Option<&str>) -> IdleConfig {
+        IdleConfig::from_lookup(|name| match name {
+            IDLE_TIMEOUT_ENV => timeout.map(str::to_string),
+            IDLE_POLL_ENV => poll.map(str::to_string),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn unset_keeps_service_running() {
+        assert_eq!(config(None, None), IdleConfig::default());
+    }
+
+    #[test]
+    fn parses_configured_values() {
+        let cfg = config(Some("300"), Some(" 2 "));
+        assert_eq!(cfg.timeout, Some(Duration::from_secs(300)));
+        assert_eq!(cfg.poll_interval, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn zero_and_garbage_fall_back_to_defaults() {
+        assert_eq!(config(Some("0"), Some("0")), IdleConfig::default());
+        assert_eq!(config(Some("soon"), Some("-1")), IdleConfig::default());
+    }
+}
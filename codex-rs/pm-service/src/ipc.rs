@@ -1,7 +1,7 @@
 //! Unix domain socket IPC listener (PM-D7, PM-D8, PM-D9).
 //!
-//! Listens on the socket path and dispatches JSON-RPC-lite messages
-//! to the BotRunManager. Supports push notifications for --wait (PM-D24).
+//! Listens on the socket path (or a TCP address) and dispatches JSON-RPC-lite
+//! messages to the BotRunManager. Supports push notifications for --wait (PM-D24).
 
 use std::sync::Arc;
 
@@ -9,31 +9,97 @@ use codex_app_server_protocol::{
     JSONRPCError, JSONRPCErrorError, JSONRPCNotification, JSONRPCRequest, JSONRPCResponse,
     RequestId,
 };
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
-use tokio::net::{UnixListener, UnixStream};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream, UnixListener, UnixStream};
+use tokio::sync::broadcast;
 
-use crate::manager::BotRunManager;
+use crate::manager::{BotRunManager, OutputSubscription};
 use crate::protocol::*;
-use crate::{PROTOCOL_VERSION, default_socket_path};
+use crate::{PROTOCOL_VERSION, default_socket_path, listen_addr};
+
+/// Transport the IPC server accepts connections on.
+///
+/// The JSON-RPC-lite protocol is identical over both transports.
+pub enum IpcListener {
+    /// Unix domain socket (default, PM-D7)
+    Unix(UnixListener),
+    /// TCP socket, for clients that cannot share a Unix socket (e.g. containers)
+    Tcp(TcpListener),
+}
+
+impl From<UnixListener> for IpcListener {
+    fn from(listener: UnixListener) -> Self {
+        Self::Unix(listener)
+    }
+}
+
+impl From<TcpListener> for IpcListener {
+    fn from(listener: TcpListener) -> Self {
+        Self::Tcp(listener)
+    }
+}
+
+/// An accepted client connection.
+enum IpcStream {
+    Unix(UnixStream),
+    Tcp(TcpStream),
+}
+
+impl IpcListener {
+    async fn accept(&self) -> std::io::Result<IpcStream> {
+        match self {
+            Self::Unix(listener) => listener.accept().await.map(|(s, _)| IpcStream::Unix(s)),
+            Self::Tcp(listener) => listener.accept().await.map(|(s, _)| IpcStream::Tcp(s)),
+        }
+    }
+}
 
 /// Start the IPC listener on a pre-bound listener with shutdown support.
 ///
-/// Accepts connections, dispatches to the manager, tracks connections,
-/// and exits when `shutdown_rx` signals.
+/// Starts the manager's run dispatcher, accepts connections, dispatches to
+/// the manager, tracks connections, and exits when `shutdown_rx` signals.
 pub async fn serve(
     manager: Arc<BotRunManager>,
-    listener: UnixListener,
+    listener: impl Into<IpcListener>,
+    shutdown_rx: tokio::sync::watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    serve_with_auth(manager, listener, shutdown_rx, None).await
+}
+
+/// Like [`serve`], but requires clients to present `auth_token` in `hello`.
+///
+/// With `Some(secret)`, every connection must open with a `hello` whose
+/// `token` param matches; otherwise it receives a JSON-RPC error and is
+/// closed. `None` accepts all clients (backward compatible).
+pub async fn serve_with_auth(
+    manager: Arc<BotRunManager>,
+    listener: impl Into<IpcListener>,
     mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
+    auth_token: Option<String>,
 ) -> std::io::Result<()> {
+    let listener = listener.into();
+    let auth_token: Option<Arc<str>> = auth_token.map(Arc::from);
+    manager.start_dispatcher();
     loop {
         tokio::select! {
             accept_result = listener.accept() => {
                 match accept_result {
-                    Ok((stream, _addr)) => {
+                    Ok(stream) => {
                         let mgr = Arc::clone(&manager);
+                        let token = auth_token.clone();
                         mgr.inc_connections();
                         tokio::spawn(async move {
-                            if let Err(e) = handle_connection(Arc::clone(&mgr), stream).await {
+                            let result = match stream {
+                                IpcStream::Unix(stream) => {
+                                    let (reader, writer) = stream.into_split();
+                                    handle_connection(Arc::clone(&mgr), reader, writer, token).await
+                                }
+                                IpcStream::Tcp(stream) => {
+                                    let (reader, writer) = stream.into_split();
+                                    handle_connection(Arc::clone(&mgr), reader, writer, token).await
+                                }
+                            };
+                            if let Err(e) = result {
                                 tracing::warn!("Connection error: {e}");
                             }
                             mgr.dec_connections();
@@ -87,10 +153,21 @@ pub async fn serve_path(
 ///
 /// Reads newline-delimited JSON-RPC messages, sends responses,
 /// and supports push notifications for subscribed bot.run requests.
-async fn handle_connection(manager: Arc<BotRunManager>, stream: UnixStream) -> std::io::Result<()> {
-    let (reader, mut writer) = stream.into_split();
+/// When `auth_token` is set, the connection is closed unless it first
+/// completes a `hello` carrying that token.
+async fn handle_connection<R, W>(
+    manager: Arc<BotRunManager>,
+    reader: R,
+    mut writer: W,
+    auth_token: Option<Arc<str>>,
+) -> std::io::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
     let mut reader = BufReader::new(reader);
     let mut line = String::new();
+    let mut authenticated = auth_token.is_none();
 
     // Track active subscription for --wait support
     let mut pending_subscription: Option<(
@@ -150,17 +227,42 @@ async fn handle_connection(manager: Arc<BotRunManager>, stream: UnixStream) -> s
             continue;
         }
 
+        if !authenticated && let Some(expected) = auth_token.as_deref() {
+            if let Err(rejection) = authorize(trimmed, expected) {
+                tracing::warn!("Rejected unauthenticated PM client");
+                let mut bytes = serde_json::to_vec(&rejection).unwrap_or_else(|_| b"{}".to_vec());
+                bytes.push(b'\n');
+                writer.write_all(&bytes).await?;
+                writer.flush().await?;
+                break;
+            }
+            authenticated = true;
+        }
+
         manager.touch_activity().await;
 
         // Check if this is a bot.run with subscribe: true
         let wants_subscribe = check_subscribe(trimmed);
 
+        // bot.subscribe: take the subscription before responding so no output is missed
+        let output_subscription = match output_subscribe_run_id(trimmed) {
+            Some(run_id) => manager.subscribe_output(&run_id).await.ok(),
+            None => None,
+        };
+
         let response = dispatch_message(&manager, trimmed).await;
         let mut response_bytes = serde_json::to_vec(&response).unwrap_or_else(|_| b"{}".to_vec());
         response_bytes.push(b'\n');
         writer.write_all(&response_bytes).await?;
         writer.flush().await?;
 
+        if let Some(subscription) = output_subscription
+            && response.get("result").is_some()
+        {
+            forward_output(&manager, &mut writer, subscription).await?;
+            continue;
+        }
+
         // If this was a subscribed bot.run, set up push notification
         if wants_subscribe
             && let Some(result) = response.get("result")
@@ -220,6 +322,48 @@ async fn handle_connection(manager: Arc<BotRunManager>, stream: UnixStream) -> s
     Ok(())
 }
 
+/// Check that an unauthenticated connection's message is a `hello` with the right token.
+///
+/// Returns the JSON-RPC error to send before closing the connection otherwise.
+fn authorize(raw: &str, expected: &str) -> Result<(), serde_json::Value> {
+    let request = serde_json::from_str::<serde_json::Value>(raw).unwrap_or_default();
+    let id = request
+        .get("id")
+        .and_then(|id| serde_json::from_value::<RequestId>(id.clone()).ok())
+        .unwrap_or(RequestId::Integer(0));
+
+    let message = if request.get("method").and_then(|m| m.as_str()) != Some("hello") {
+        "Authentication required: send hello with a token first"
+    } else {
+        let token = request
+            .get("params")
+            .and_then(|p| p.get("token"))
+            .and_then(|t| t.as_str());
+        match token {
+            Some(token) if constant_time_eq(token.as_bytes(), expected.as_bytes()) => {
+                return Ok(());
+            }
+            Some(_) => "Invalid token",
+            None => "Missing token",
+        }
+    };
+
+    Err(serde_json::to_value(JSONRPCError {
+        id,
+        error: JSONRPCErrorError {
+            code: ERR_UNAUTHORIZED,
+            message: message.to_string(),
+            data: None,
+        },
+    })
+    .unwrap_or_default())
+}
+
+/// Compare secrets without short-circuiting on the first differing byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// Check if a raw JSON-RPC message is a bot.run with subscribe: true.
 fn check_subscribe(raw: &str) -> bool {
     if let Ok(v) = serde_json::from_str::<serde_json::Value>(raw) {
@@ -233,6 +377,75 @@ fn check_subscribe(raw: &str) -> bool {
     }
 }
 
+/// Return the run ID if a raw JSON-RPC message is a `bot.subscribe`.
+fn output_subscribe_run_id(raw: &str) -> Option<String> {
+    let v = serde_json::from_str::<serde_json::Value>(raw).ok()?;
+    if v.get("method").and_then(|m| m.as_str()) != Some("bot.subscribe") {
+        return None;
+    }
+    v.get("params")?.get("run_id")?.as_str().map(str::to_string)
+}
+
+/// Stream a run's output as `bot.output` notifications: buffered lines
+/// first, then live ones, ending with the run's `bot.terminal`.
+async fn forward_output<W: AsyncWrite + Unpin>(
+    manager: &BotRunManager,
+    writer: &mut W,
+    mut subscription: OutputSubscription,
+) -> std::io::Result<()> {
+    let run_id = subscription.run_id.clone();
+    for line in subscription.buffered.drain(..) {
+        let notif = BotOutputNotification {
+            run_id: run_id.clone(),
+            line,
+        };
+        write_notification(writer, "bot.output", &notif).await?;
+    }
+
+    let mut finished = subscription.finished.take();
+    while finished.is_none() {
+        tokio::select! {
+            // Drain pending output before honoring the terminal event
+            biased;
+            output = subscription.live.recv() => match output {
+                Ok(notif) if notif.run_id == run_id => {
+                    write_notification(writer, "bot.output", &notif).await?;
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("bot.subscribe for {run_id} skipped {skipped} output line(s)");
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            terminal = subscription.terminal.recv() => match terminal {
+                Ok(notif) if notif.run_id == run_id => finished = Some(notif),
+                Ok(_) => {}
+                // Our terminal event may have been dropped; fall back to the record
+                Err(broadcast::error::RecvError::Lagged(_)) => {
+                    finished = manager.finished_notification(&run_id).await;
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+        }
+    }
+
+    if let Some(notif) = finished {
+        write_notification(writer, "bot.terminal", &notif).await?;
+    }
+    Ok(())
+}
+
+/// Serialize and write a single notification line.
+async fn write_notification<W: AsyncWrite + Unpin, T: serde::Serialize>(
+    writer: &mut W,
+    method: &str,
+    params: &T,
+) -> std::io::Result<()> {
+    let bytes = encode_notification(method, serde_json::to_value(params).unwrap_or_default());
+    writer.write_all(&bytes).await?;
+    writer.flush().await
+}
+
 /// Parse and dispatch a single JSON-RPC message.
 async fn dispatch_message(manager: &BotRunManager, raw: &str) -> serde_json::Value {
     // Parse the request
@@ -284,7 +497,9 @@ async fn dispatch_method(
         "bot.runs" => handle_bot_runs(manager, params).await,
         "bot.cancel" => handle_bot_cancel(manager, params).await,
         "bot.resume" => handle_bot_resume(manager, params).await,
+        "bot.subscribe" => handle_bot_subscribe(manager, params).await,
         "service.status" => handle_service_status(manager).await,
+        "service.metrics" => handle_service_metrics(manager).await,
         "service.doctor" => handle_service_doctor(params),
         _ => Err((ERR_METHOD_NOT_FOUND, format!("Unknown method: {method}"))),
     }
@@ -320,7 +535,9 @@ fn handle_hello(params: Option<serde_json::Value>) -> Result<serde_json::Value,
             "bot.runs".to_string(),
             "bot.cancel".to_string(),
             "bot.resume".to_string(),
+            "bot.subscribe".to_string(),
             "service.status".to_string(),
+            "service.metrics".to_string(),
             "service.doctor".to_string(),
         ],
     };
@@ -428,16 +645,12 @@ async fn handle_bot_cancel(
             })
         })?;
 
-    let state = manager
+    let result = manager
         .cancel(&params.workspace_path, &params.work_item_id, &params.run_id)
         .await
         .map_err(|e| manager_error_to_rpc(&e))?;
 
-    serde_json::to_value(serde_json::json!({
-        "run_id": params.run_id,
-        "status": state,
-    }))
-    .map_err(|e| (ERR_INFRA, format!("Serialize error: {e}")))
+    serde_json::to_value(result).map_err(|e| (ERR_INFRA, format!("Serialize error: {e}")))
 }
 
 /// Handle `bot.resume`.
@@ -464,6 +677,37 @@ async fn handle_bot_resume(
     serde_json::to_value(result).map_err(|e| (ERR_INFRA, format!("Serialize error: {e}")))
 }
 
+/// Handle `bot.subscribe`.
+///
+/// Only validates the run and reports its state; streaming is driven by
+/// the connection loop.
+async fn handle_bot_subscribe(
+    manager: &BotRunManager,
+    params: Option<serde_json::Value>,
+) -> Result<serde_json::Value, (i64, String)> {
+    let params: BotSubscribeParams = params
+        .ok_or_else(|| (ERR_INVALID_PARAMS, "Missing params".to_string()))
+        .and_then(|v| {
+            serde_json::from_value(v).map_err(|e| {
+                (
+                    ERR_INVALID_PARAMS,
+                    format!("Invalid bot.subscribe params: {e}"),
+                )
+            })
+        })?;
+
+    let show = manager
+        .show(&params.run_id)
+        .await
+        .map_err(|e| manager_error_to_rpc(&e))?;
+
+    let result = BotSubscribeResult {
+        run_id: params.run_id,
+        status: show.status,
+    };
+    serde_json::to_value(result).map_err(|e| (ERR_INFRA, format!("Serialize error: {e}")))
+}
+
 /// Handle `service.status`.
 async fn handle_service_status(
     manager: &BotRunManager,
@@ -477,6 +721,14 @@ async fn handle_service_status(
     serde_json::to_value(result).map_err(|e| (ERR_INFRA, format!("Serialize error: {e}")))
 }
 
+/// Handle `service.metrics`.
+async fn handle_service_metrics(
+    manager: &BotRunManager,
+) -> Result<serde_json::Value, (i64, String)> {
+    let result = manager.metrics().await;
+    serde_json::to_value(result).map_err(|e| (ERR_INFRA, format!("Serialize error: {e}")))
+}
+
 /// Handle `service.doctor`.
 fn handle_service_doctor(
     _params: Option<serde_json::Value>,
@@ -490,7 +742,10 @@ fn handle_service_doctor(
         DoctorCheck {
             name: "socket".to_string(),
             status: "ok".to_string(),
-            detail: Some(format!("Listening on {}", default_socket_path().display())),
+            detail: Some(match listen_addr() {
+                Some(addr) => format!("Listening on tcp://{addr}"),
+                None => format!("Listening on {}", default_socket_path().display()),
+            }),
         },
     ];
 
@@ -529,12 +784,14 @@ mod tests {
     use super::*;
     use crate::persistence::PersistenceStore;
 
-    fn test_manager() -> BotRunManager {
+    fn test_manager() -> Arc<BotRunManager> {
         let tmp = tempfile::TempDir::new().unwrap();
         let path = tmp.path().to_path_buf();
         std::mem::forget(tmp);
         let store = Arc::new(PersistenceStore::with_base_dir(path).unwrap());
-        BotRunManager::new(store)
+        let manager = Arc::new(BotRunManager::new(store));
+        manager.start_dispatcher();
+        manager
     }
 
     #[tokio::test]
@@ -616,6 +873,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn dispatch_bot_run_accepts_priority() {
+        let manager = test_manager();
+        let msg = serde_json::json!({
+            "id": 3,
+            "method": "bot.run",
+            "params": {
+                "workspace_path": "/tmp/test",
+                "work_item_id": "SPEC-TEST-001",
+                "kind": "research",
+                "capture_mode": "prompts_only",
+                "priority": 7
+            }
+        });
+        let result = dispatch_message(&manager, &msg.to_string()).await;
+        assert_eq!(result["result"]["status"].as_str().unwrap(), "succeeded");
+
+        // Priorities are a u8; out-of-range values are invalid params
+        let msg = serde_json::json!({
+            "id": 4,
+            "method": "bot.run",
+            "params": {
+                "workspace_path": "/tmp/test",
+                "work_item_id": "SPEC-TEST-002",
+                "kind": "research",
+                "capture_mode": "prompts_only",
+                "priority": 256
+            }
+        });
+        let result = dispatch_message(&manager, &msg.to_string()).await;
+        assert_eq!(
+            result["error"]["code"].as_i64().unwrap(),
+            ERR_INVALID_PARAMS
+        );
+    }
+
     #[tokio::test]
     async fn dispatch_bot_show() {
         let manager = test_manager();
@@ -2,7 +2,7 @@
 //!
 //! Lightweight persistent service that manages bot runs for work items.
 //! Listens on a Unix domain socket at `$XDG_RUNTIME_DIR/codex-pm.sock`
-//! and speaks JSON-RPC-lite (PM-D8).
+//! (or a TCP address from `CODEX_PM_LISTEN_ADDR`) and speaks JSON-RPC-lite (PM-D8).
 //!
 //! ## Decision References
 //! - D135: Service-first bot job management
@@ -14,6 +14,7 @@
 //! - PM-D21: Crate split
 
 pub mod engine;
+pub mod idle;
 pub mod ipc;
 pub mod manager;
 pub mod persistence;
@@ -25,6 +26,29 @@ pub const PROTOCOL_VERSION: &str = "1.0";
 /// Default socket filename.
 pub const SOCKET_FILENAME: &str = "codex-pm.sock";
 
+/// Environment variable selecting a TCP listen address (e.g. `127.0.0.1:7777`).
+///
+/// When set, the service binds and `--ping` dials TCP instead of the Unix socket.
+pub const LISTEN_ADDR_ENV: &str = "CODEX_PM_LISTEN_ADDR";
+
+/// Environment variable holding the shared secret clients must send in `hello`.
+pub const TOKEN_ENV: &str = "CODEX_PM_TOKEN";
+
+/// Shared secret from `CODEX_PM_TOKEN`, if set and non-empty.
+pub fn auth_token() -> Option<String> {
+    std::env::var(TOKEN_ENV)
+        .ok()
+        .filter(|token| !token.is_empty())
+}
+
+/// TCP listen address from `CODEX_PM_LISTEN_ADDR`, if set and non-empty.
+pub fn listen_addr() -> Option<String> {
+    std::env::var(LISTEN_ADDR_ENV)
+        .ok()
+        .map(|addr| addr.trim().to_string())
+        .filter(|addr| !addr.is_empty())
+}
+
 /// Get the default socket path using XDG_RUNTIME_DIR.
 ///
 /// Falls back to `/tmp/codex-pm-<username>.sock` if XDG_RUNTIME_DIR is not set.
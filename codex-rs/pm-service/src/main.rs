@@ -11,37 +11,67 @@
 //! - **Service mode** (default): start the IPC server and resume incomplete runs on startup.
 //! - **`--ping`**: connect to the running service socket, send a hello
 //!   handshake, verify the response, then exit. Useful for diagnostics.
+//!
+//! Setting `CODEX_PM_LISTEN_ADDR` (e.g. `127.0.0.1:7777`) switches both modes
+//! from the Unix socket to TCP. Setting `CODEX_PM_TOKEN` requires clients to
+//! present that secret in `hello`; `--ping` sends it automatically.
+//! Setting `CODEX_PM_IDLE_TIMEOUT_SECS` opts into exiting after that long
+//! without activity (see [`codex_pm_service::idle`]).
 
-use std::io::{BufRead, Write};
+use std::io::{BufRead, Read, Write};
 use std::os::unix::io::FromRawFd;
 use std::sync::Arc;
+use std::time::Duration;
 
+use codex_pm_service::idle::IdleConfig;
+use codex_pm_service::ipc::IpcListener;
 use codex_pm_service::manager::BotRunManager;
 use codex_pm_service::persistence::PersistenceStore;
-use tokio::net::UnixListener;
+use tokio::net::{TcpListener, UnixListener};
 
 /// Connect to the service socket, send a hello handshake, verify the response, then exit.
 ///
 /// This is a simple liveness/diagnostics probe.
 fn ping() -> std::io::Result<()> {
+    const READ_TIMEOUT: Duration = Duration::from_secs(10);
+    const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    if let Some(addr) = codex_pm_service::listen_addr() {
+        let stream = std::net::TcpStream::connect(&addr).map_err(|e| {
+            std::io::Error::other(format!("ping: cannot connect to tcp://{addr}: {e}"))
+        })?;
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        return ping_stream(stream);
+    }
+
     let path = codex_pm_service::default_socket_path();
-    let mut stream = std::os::unix::net::UnixStream::connect(&path).map_err(|e| {
+    let stream = std::os::unix::net::UnixStream::connect(&path).map_err(|e| {
         std::io::Error::other(format!("ping: cannot connect to {}: {e}", path.display()))
     })?;
-    stream.set_read_timeout(Some(std::time::Duration::from_secs(10)))?;
-    stream.set_write_timeout(Some(std::time::Duration::from_secs(5)))?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    ping_stream(stream)
+}
 
+/// Send a hello handshake over a connected stream and verify the response.
+fn ping_stream<S: Read + Write>(mut stream: S) -> std::io::Result<()> {
     // Send hello JSON-RPC (newline-delimited)
-    let hello = format!(
-        r#"{{"id":0,"method":"hello","params":{{"protocol_version":"{}","client_version":"ping"}}}}"#,
-        codex_pm_service::PROTOCOL_VERSION,
-    );
-    stream.write_all(hello.as_bytes())?;
+    let hello = serde_json::json!({
+        "id": 0,
+        "method": "hello",
+        "params": {
+            "protocol_version": codex_pm_service::PROTOCOL_VERSION,
+            "client_version": "ping",
+            "token": codex_pm_service::auth_token(),
+        }
+    });
+    stream.write_all(hello.to_string().as_bytes())?;
     stream.write_all(b"\n")?;
     stream.flush()?;
 
     // Read one response line
-    let mut reader = std::io::BufReader::new(&stream);
+    let mut reader = std::io::BufReader::new(stream);
     let mut line = String::new();
     reader.read_line(&mut line)?;
 
@@ -61,11 +91,11 @@ fn ping() -> std::io::Result<()> {
     }
 }
 
-/// Create a Unix listener, preferring systemd socket activation (D136).
+/// Create the IPC listener, preferring systemd socket activation (D136).
 ///
 /// If `LISTEN_FDS` is set to >= 1, uses fd 3 (the first passed fd).
-/// Otherwise, binds at the default socket path.
-fn create_listener() -> std::io::Result<UnixListener> {
+/// Otherwise, binds TCP at `CODEX_PM_LISTEN_ADDR` if set, or the default socket path.
+async fn create_listener() -> std::io::Result<IpcListener> {
     // Check for systemd socket activation (LISTEN_FDS)
     if let Ok(val) = std::env::var("LISTEN_FDS")
         && let Ok(n) = val.parse::<u32>()
@@ -76,7 +106,13 @@ fn create_listener() -> std::io::Result<UnixListener> {
         // SAFETY: fd 3 is guaranteed by systemd socket activation protocol
         let std_listener = unsafe { std::os::unix::net::UnixListener::from_raw_fd(3) };
         std_listener.set_nonblocking(true)?;
-        return UnixListener::from_std(std_listener);
+        return UnixListener::from_std(std_listener).map(IpcListener::from);
+    }
+
+    if let Some(addr) = codex_pm_service::listen_addr() {
+        let listener = TcpListener::bind(&addr).await?;
+        tracing::info!("PM service listening on tcp://{}", listener.local_addr()?);
+        return Ok(listener.into());
     }
 
     // No socket activation: bind at default path
@@ -94,7 +130,7 @@ fn create_listener() -> std::io::Result<UnixListener> {
 
     let listener = UnixListener::bind(&path)?;
     tracing::info!("PM service listening on {}", path.display());
-    Ok(listener)
+    Ok(listener.into())
 }
 
 fn main() -> std::io::Result<()> {
@@ -130,7 +166,7 @@ async fn run_service() -> std::io::Result<()> {
     // Resume incomplete runs before accepting connections
     manager.resume_incomplete().await;
 
-    let listener = create_listener()?;
+    let listener = create_listener().await?;
 
     // Shutdown coordination via watch channel
     let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
@@ -147,8 +183,28 @@ async fn run_service() -> std::io::Result<()> {
         let _ = shutdown_tx_signal.send(true);
     });
 
+    let idle = IdleConfig::from_env();
+    match idle.timeout {
+        Some(timeout) => tracing::info!(
+            "Idle exit after {}s (checked every {}s)",
+            timeout.as_secs(),
+            idle.poll_interval.as_secs()
+        ),
+        None => tracing::info!("Idle exit disabled; service stays running"),
+    }
+    tokio::spawn(codex_pm_service::idle::watch_idle(
+        Arc::clone(&manager),
+        idle,
+        shutdown_tx.clone(),
+    ));
+
+    let auth_token = codex_pm_service::auth_token();
+    if auth_token.is_some() {
+        tracing::info!("Client authentication enabled (CODEX_PM_TOKEN)");
+    }
+
     // Start IPC listener (blocks until shutdown)
-    codex_pm_service::ipc::serve(manager, listener, shutdown_rx).await?;
+    codex_pm_service::ipc::serve_with_auth(manager, listener, shutdown_rx, auth_token).await?;
 
     tracing::info!("codex-pm-service exiting cleanly");
     Ok(())
@@ -8,26 +8,53 @@
 //! - PM-D11: Capsule-backed run queue
 //! - PM-D14: Reject duplicate (work_item_id, kind)
 //! - PM-D16: Reject capture=none
-
-use std::collections::HashMap;
+//! - PM-D13: Cancel interrupts the engine via a per-run `CancellationToken`
+//!
+//! ## Priority Queue
+//!
+//! Runs accepted via [`BotRunManager::submit`] wait in a priority queue
+//! until the dispatcher loop (see [`BotRunManager::start_dispatcher`])
+//! executes them, at most [`DEFAULT_MAX_CONCURRENT_RUNS`] at a time. When
+//! every slot is busy, higher priorities dispatch first; runs with equal
+//! priority dispatch in submission order. Priorities are persisted with the
+//! request so resume honors them too.
+
+use std::collections::{BinaryHeap, HashMap, VecDeque};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::time::Instant;
 
 use codex_core::pm::artifacts::BotRunState;
 use codex_core::pm::bot::{BotCaptureMode, BotKind, BotRunRequest};
-use tokio::sync::{Mutex, broadcast};
+use tokio::sync::{Mutex, Notify, Semaphore, broadcast};
+use tokio_util::sync::CancellationToken;
 
 use crate::engine;
-use crate::persistence::{CapsulePersistence, PersistenceStore};
+use crate::persistence::{CapsulePersistence, DEFAULT_PRIORITY, IncompleteRun, PersistenceStore};
 use crate::protocol::{
-    BotRunParams, BotRunResult, BotRunsResult, BotShowResult, BotStatusResult,
-    BotTerminalNotification, RunSummary,
+    BotCancelResult, BotOutputNotification, BotRunParams, BotRunResult, BotRunsResult,
+    BotShowResult, BotStatusResult, BotTerminalNotification, RunSummary, ServiceMetricsResult,
 };
 
 /// Key for the active run index: (workspace_path, work_item_id, bot_kind).
 type ActiveRunKey = (String, String, BotKind);
 
+/// Exit code recorded for cancelled runs.
+const CANCELLED_EXIT_CODE: i32 = 2;
+
+/// Summary recorded for cancelled runs.
+const CANCELLED_SUMMARY: &str = "Cancelled by user";
+
+/// Exit code recorded for runs that exhausted their attempts.
+const EXHAUSTED_EXIT_CODE: i32 = 3;
+
+/// Recent output lines kept per run for late `bot.subscribe` clients.
+pub const OUTPUT_BUFFER_LINES: usize = 256;
+
+/// Runs the dispatcher executes at once unless overridden with
+/// [`BotRunManager::with_max_concurrent_runs`].
+pub const DEFAULT_MAX_CONCURRENT_RUNS: usize = 4;
+
 /// Error type for manager operations.
 #[derive(Debug, thiserror::Error)]
 pub enum ManagerError {
@@ -62,6 +89,54 @@ struct RunRecord {
     report_json: Option<String>,
     /// Cached artifact URIs (capsule `mv2://` or local `pm://`).
     cached_artifact_uris: Vec<String>,
+    /// Exit code once the run is terminal.
+    exit_code: Option<i32>,
+}
+
+/// A live view of one run's output, returned by `subscribe_output`.
+pub struct OutputSubscription {
+    pub run_id: String,
+    /// Recent lines emitted before subscribing, oldest first.
+    pub buffered: Vec<String>,
+    /// Lines emitted after subscribing (all runs; filter by `run_id`).
+    pub live: broadcast::Receiver<BotOutputNotification>,
+    /// Terminal notifications (all runs; filter by `run_id`).
+    pub terminal: broadcast::Receiver<BotTerminalNotification>,
+    /// Set when the run had already finished at subscribe time.
+    pub finished: Option<BotTerminalNotification>,
+}
+
+/// A run waiting in the dispatch queue.
+#[derive(Debug)]
+struct QueuedRun {
+    priority: u8,
+    /// Submission sequence number; lower values were enqueued earlier.
+    seq: u64,
+    request: BotRunRequest,
+    params: BotRunParams,
+}
+
+impl PartialEq for QueuedRun {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.seq == other.seq
+    }
+}
+
+impl Eq for QueuedRun {}
+
+impl PartialOrd for QueuedRun {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedRun {
+    /// Max-heap order: higher priority first, then earlier submission (FIFO).
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
 }
 
 /// Manages bot runs for all workspaces served by this service instance.
@@ -84,6 +159,29 @@ pub struct BotRunManager {
     /// Capsule persistence (SoR, Phase-2 D114). Optional — absent when
     /// no workspace capsule is available.
     capsule: Arc<Mutex<Option<CapsulePersistence>>>,
+    /// Runs accepted by `enqueue` awaiting dispatch.
+    queue: Arc<Mutex<BinaryHeap<QueuedRun>>>,
+    /// Next queue sequence number (FIFO tie-break within a priority).
+    queue_seq: AtomicU64,
+    /// Wakes the dispatcher when a run is queued.
+    dispatch_wake: Arc<Notify>,
+    /// Execution slots; the dispatcher holds one per executing run.
+    dispatch_slots: Arc<Semaphore>,
+    /// Set once the dispatcher loop has been spawned.
+    dispatcher_started: AtomicBool,
+    /// Cancellation tokens for runs whose engine is currently executing.
+    cancel_tokens: Arc<Mutex<HashMap<String, CancellationToken>>>,
+    /// Broadcast channel for live output lines (`bot.subscribe`).
+    output_tx: broadcast::Sender<BotOutputNotification>,
+    /// Recent output lines per executing run, capped at `OUTPUT_BUFFER_LINES`
+    /// and dropped when the engine returns.
+    output_buffers: Arc<Mutex<HashMap<String, VecDeque<String>>>>,
+    /// Runs admitted or resumed since service start.
+    total_runs: AtomicU64,
+    /// Runs that reached a terminal state other than `Failed`.
+    completed_runs: AtomicU64,
+    /// Runs that terminated `Failed`.
+    failed_runs: AtomicU64,
 }
 
 impl BotRunManager {
@@ -99,6 +197,17 @@ impl BotRunManager {
             connection_count: Arc::new(AtomicU32::new(0)),
             store,
             capsule: Arc::new(Mutex::new(None)),
+            queue: Arc::new(Mutex::new(BinaryHeap::new())),
+            queue_seq: AtomicU64::new(0),
+            dispatch_wake: Arc::new(Notify::new()),
+            dispatch_slots: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_RUNS)),
+            dispatcher_started: AtomicBool::new(false),
+            cancel_tokens: Arc::new(Mutex::new(HashMap::new())),
+            output_tx: broadcast::channel(256).0,
+            output_buffers: Arc::new(Mutex::new(HashMap::new())),
+            total_runs: AtomicU64::new(0),
+            completed_runs: AtomicU64::new(0),
+            failed_runs: AtomicU64::new(0),
         }
     }
 
@@ -115,9 +224,26 @@ impl BotRunManager {
             connection_count: Arc::new(AtomicU32::new(0)),
             store,
             capsule: Arc::new(Mutex::new(Some(capsule))),
+            queue: Arc::new(Mutex::new(BinaryHeap::new())),
+            queue_seq: AtomicU64::new(0),
+            dispatch_wake: Arc::new(Notify::new()),
+            dispatch_slots: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_RUNS)),
+            dispatcher_started: AtomicBool::new(false),
+            cancel_tokens: Arc::new(Mutex::new(HashMap::new())),
+            output_tx: broadcast::channel(256).0,
+            output_buffers: Arc::new(Mutex::new(HashMap::new())),
+            total_runs: AtomicU64::new(0),
+            completed_runs: AtomicU64::new(0),
+            failed_runs: AtomicU64::new(0),
         }
     }
 
+    /// Limit how many runs the dispatcher executes at once.
+    pub fn with_max_concurrent_runs(mut self, limit: usize) -> Self {
+        self.dispatch_slots = Arc::new(Semaphore::new(limit));
+        self
+    }
+
     /// Subscribe to terminal notifications for --wait support.
     pub fn subscribe_terminal(&self) -> broadcast::Receiver<BotTerminalNotification> {
         self.terminal_tx.subscribe()
@@ -169,6 +295,28 @@ impl BotRunManager {
         self.connection_count.load(Ordering::Relaxed)
     }
 
+    /// Snapshot of run counters for monitoring.
+    pub async fn metrics(&self) -> ServiceMetricsResult {
+        ServiceMetricsResult {
+            active_runs: self.active_run_count().await,
+            total_runs: self.total_runs.load(Ordering::Relaxed),
+            completed_runs: self.completed_runs.load(Ordering::Relaxed),
+            failed_runs: self.failed_runs.load(Ordering::Relaxed),
+            uptime_secs: self.uptime_s(),
+            connection_count: self.connection_count(),
+        }
+    }
+
+    /// Count a run reaching `state` in the metrics counters.
+    fn record_terminal(&self, state: BotRunState) {
+        let counter = if state == BotRunState::Failed {
+            &self.failed_runs
+        } else {
+            &self.completed_runs
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Try to open a capsule for a workspace and cache it.
     ///
     /// Called lazily on first write to a workspace. If the capsule can't be
@@ -231,11 +379,124 @@ impl BotRunManager {
         }
     }
 
-    /// Submit a new bot run (PM-D11).
+    /// Number of runs waiting in the dispatch queue.
+    pub async fn queued_run_count(&self) -> usize {
+        self.queue.lock().await.len()
+    }
+
+    /// Submit a new bot run (PM-D11) and wait for it to finish.
     ///
     /// Validates the request, checks for duplicates (PM-D14), rejects
-    /// capture=none (PM-D16), persists to disk, then executes the stub engine.
+    /// capture=none (PM-D16), persists to disk, then queues the run at
+    /// `params.priority` until the dispatcher executes it. Runs cancelled
+    /// while queued return without executing.
     pub async fn submit(&self, params: BotRunParams) -> Result<BotRunResult, ManagerError> {
+        // Subscribe before queueing so the terminal notification can't be missed
+        let mut terminal_rx = self.terminal_tx.subscribe();
+        let work_item_id = params.work_item_id.clone();
+        let kind = params.kind;
+        let priority = params.priority.unwrap_or(DEFAULT_PRIORITY);
+        let run_id = self.enqueue(params, priority).await?;
+
+        let notification = loop {
+            match terminal_rx.recv().await {
+                Ok(notification) if notification.run_id == run_id => break notification,
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(_)) => {
+                    if let Some(notification) = self.finished_notification(&run_id).await {
+                        break notification;
+                    }
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    return Err(ManagerError::Infra(
+                        "terminal notification channel closed".to_string(),
+                    ));
+                }
+            }
+        };
+
+        Ok(BotRunResult {
+            run_id,
+            status: notification.status,
+            work_item_id,
+            kind,
+            exit_code: notification.exit_code,
+            summary: Some(notification.summary),
+            artifact_uris: notification.artifact_uris,
+        })
+    }
+
+    /// Accept a bot run into the priority queue and wake the dispatcher.
+    ///
+    /// Returns the run ID; the run stays `Queued` until dispatched.
+    async fn enqueue(&self, params: BotRunParams, priority: u8) -> Result<String, ManagerError> {
+        let request = self.admit(&params, priority).await?;
+        let run_id = request.run_id.clone();
+        let seq = self.queue_seq.fetch_add(1, Ordering::Relaxed);
+        self.queue.lock().await.push(QueuedRun {
+            priority,
+            seq,
+            request,
+            params,
+        });
+        self.dispatch_wake.notify_one();
+        Ok(run_id)
+    }
+
+    /// Spawn the dispatcher loop that executes queued runs.
+    ///
+    /// Called by the IPC server on startup; calling it again is a no-op.
+    pub fn start_dispatcher(self: &Arc<Self>) {
+        if self.dispatcher_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        tokio::spawn(Arc::clone(self).dispatch_loop());
+    }
+
+    /// Wait for a free execution slot, then execute the highest-priority
+    /// queued run in the background, forever.
+    async fn dispatch_loop(self: Arc<Self>) {
+        loop {
+            let Ok(slot) = Arc::clone(&self.dispatch_slots).acquire_owned().await else {
+                return;
+            };
+            let queued = loop {
+                if let Some(queued) = self.next_queued().await {
+                    break queued;
+                }
+                self.dispatch_wake.notified().await;
+            };
+            let manager = Arc::clone(&self);
+            tokio::spawn(async move {
+                manager.execute(queued.request, queued.params).await;
+                drop(slot);
+            });
+        }
+    }
+
+    /// Pop the highest-priority queued run, dropping runs cancelled while
+    /// queued.
+    async fn next_queued(&self) -> Option<QueuedRun> {
+        loop {
+            let queued = self.queue.lock().await.pop()?;
+            let cancelled = self
+                .runs
+                .lock()
+                .await
+                .get(&queued.request.run_id)
+                .is_some_and(|r| r.state.is_terminal());
+            if !cancelled {
+                return Some(queued);
+            }
+        }
+    }
+
+    /// Validate, deduplicate, and persist a new run, recording it as queued.
+    async fn admit(
+        &self,
+        params: &BotRunParams,
+        priority: u8,
+    ) -> Result<BotRunRequest, ManagerError> {
         // PM-D16: reject capture=none
         if params.capture_mode == BotCaptureMode::None {
             return Err(ManagerError::CaptureNoneRejected);
@@ -262,7 +523,7 @@ impl BotRunManager {
             let key = (workspace.clone(), params.work_item_id.clone(), params.kind);
             if index.contains_key(&key) {
                 return Err(ManagerError::DuplicateRun {
-                    work_item_id: params.work_item_id,
+                    work_item_id: params.work_item_id.clone(),
                     kind: params.kind,
                 });
             }
@@ -270,7 +531,7 @@ impl BotRunManager {
 
         // Persist request BEFORE engine execution
         self.store
-            .write_request(&request, &workspace)
+            .write_request_with_priority(&request, &workspace, priority)
             .map_err(|e| ManagerError::Infra(format!("persist request: {e}")))?;
 
         // Record the run as queued
@@ -283,6 +544,7 @@ impl BotRunManager {
             summary: None,
             report_json: None,
             cached_artifact_uris: vec![],
+            exit_code: None,
         };
 
         {
@@ -291,22 +553,39 @@ impl BotRunManager {
         }
         {
             let mut index = self.active_index.lock().await;
-            let key = (workspace.clone(), params.work_item_id.clone(), params.kind);
-            index.insert(key, run_id.clone());
+            let key = (workspace, params.work_item_id.clone(), params.kind);
+            index.insert(key, run_id);
         }
+        self.total_runs.fetch_add(1, Ordering::Relaxed);
+
+        Ok(request)
+    }
+
+    /// Execute an admitted run and persist its terminal artifacts.
+    async fn execute(&self, request: BotRunRequest, params: BotRunParams) -> BotRunResult {
+        let run_id = request.run_id.clone();
+        let workspace = params.workspace_path.clone();
 
         // Execute the real engine
-        let engine_result = engine::run_engine(engine::EngineParams {
-            kind: params.kind,
-            run_id: run_id.clone(),
-            work_item_id: params.work_item_id.clone(),
-            workspace_path: workspace.clone(),
-            write_mode: params.write_mode,
-            rebase_target: params.rebase_target.clone(),
-            allow_degraded: params.allow_degraded,
-            notebooklm_health_url: params.notebooklm_health_url.clone(),
-        })
-        .await;
+        let Some(engine_result) = self
+            .run_engine_cancellable(
+                &run_id,
+                engine::EngineParams {
+                    kind: params.kind,
+                    run_id: run_id.clone(),
+                    work_item_id: params.work_item_id.clone(),
+                    workspace_path: workspace.clone(),
+                    write_mode: params.write_mode,
+                    rebase_target: params.rebase_target.clone(),
+                    allow_degraded: params.allow_degraded,
+                    notebooklm_health_url: params.notebooklm_health_url.clone(),
+                    output: None, // attached by run_engine_cancellable
+                },
+            )
+            .await
+        else {
+            return self.finish_cancelled(&request);
+        };
 
         // Persist terminal artifacts (local cache)
         let _ = self.store.write_log(&engine_result.log);
@@ -378,9 +657,12 @@ impl BotRunManager {
                 record.summary = Some(engine_result.summary.clone());
                 record.report_json = Some(engine_result.report_json.clone());
                 record.cached_artifact_uris = artifact_uris.clone();
+                record.exit_code = Some(engine_result.exit_code);
             }
         }
 
+        self.record_terminal(engine_result.state);
+
         // Remove from active index (run is now terminal)
         {
             let mut index = self.active_index.lock().await;
@@ -399,7 +681,7 @@ impl BotRunManager {
         // Ignore error if no subscribers
         let _ = self.terminal_tx.send(notification);
 
-        Ok(BotRunResult {
+        BotRunResult {
             run_id,
             status: engine_result.state,
             work_item_id: params.work_item_id,
@@ -407,7 +689,7 @@ impl BotRunManager {
             exit_code: engine_result.exit_code,
             summary: Some(engine_result.summary),
             artifact_uris,
-        })
+        }
     }
 
     /// Query run status (PM-D14).
@@ -504,13 +786,161 @@ impl BotRunManager {
         BotRunsResult { runs: page, total }
     }
 
+    /// Run the engine, racing it against a cancellation token registered
+    /// for `run_id`. Returns `None` if the run was cancelled.
+    ///
+    /// The outcome is decided under the `runs` lock, which `cancel` also
+    /// holds while it checks and records the state, so a cancel racing with
+    /// completion either wins outright or sees the run already finished.
+    async fn run_engine_cancellable(
+        &self,
+        run_id: &str,
+        params: engine::EngineParams,
+    ) -> Option<engine::EngineResult> {
+        let token = CancellationToken::new();
+        self.cancel_tokens
+            .lock()
+            .await
+            .insert(run_id.to_string(), token.clone());
+        if let Some(record) = self.runs.lock().await.get_mut(run_id)
+            && !record.state.is_terminal()
+        {
+            record.state = BotRunState::Running;
+            record.started_at = Some(chrono::Utc::now().to_rfc3339());
+        }
+        if let Err(e) = self.store.record_attempt(run_id) {
+            tracing::warn!("Failed to record attempt for run {run_id}: {e}");
+        }
+
+        let (output_tx, mut output_rx) = tokio::sync::mpsc::unbounded_channel();
+        let engine = engine::run_engine(engine::EngineParams {
+            output: Some(output_tx),
+            ..params
+        });
+        tokio::pin!(engine);
+
+        let result = loop {
+            tokio::select! {
+                result = &mut engine => break Some(result),
+                () = token.cancelled() => break None,
+                Some(line) = output_rx.recv() => self.push_output(run_id, line).await,
+            }
+        };
+        // Lines emitted just before the engine returned
+        while let Ok(line) = output_rx.try_recv() {
+            self.push_output(run_id, line).await;
+        }
+
+        self.cancel_tokens.lock().await.remove(run_id);
+        // Live subscribers already have every line; drop the replay buffer
+        self.output_buffers.lock().await.remove(run_id);
+
+        let mut runs = self.runs.lock().await;
+        match runs.get_mut(run_id) {
+            // `cancel` got there first and has recorded the run as cancelled
+            Some(record) if record.state.is_terminal() => None,
+            Some(record) => {
+                let result = result?;
+                // Claim the terminal state so a later cancel is a no-op
+                record.state = result.state;
+                record.finished_at = Some(result.log.finished_at.clone());
+                record.summary = Some(result.summary.clone());
+                record.exit_code = Some(result.exit_code);
+                Some(result)
+            }
+            None => result,
+        }
+    }
+
+    /// Buffer an output line for `run_id` and broadcast it to subscribers.
+    async fn push_output(&self, run_id: &str, line: String) {
+        // Broadcast under the buffer lock so subscribers see no gap or duplicate
+        let mut buffers = self.output_buffers.lock().await;
+        let buffer = buffers.entry(run_id.to_string()).or_default();
+        if buffer.len() == OUTPUT_BUFFER_LINES {
+            buffer.pop_front();
+        }
+        buffer.push_back(line.clone());
+        let _ = self.output_tx.send(BotOutputNotification {
+            run_id: run_id.to_string(),
+            line,
+        });
+    }
+
+    /// Subscribe to a run's output: recent buffered lines plus live ones.
+    pub async fn subscribe_output(&self, run_id: &str) -> Result<OutputSubscription, ManagerError> {
+        // Subscribe before checking state so the terminal event cannot be missed
+        let terminal = self.terminal_tx.subscribe();
+        let finished = match self.runs.lock().await.get(run_id) {
+            None => {
+                return Err(ManagerError::RunNotFound {
+                    run_id: run_id.to_string(),
+                });
+            }
+            Some(record) => terminal_notification(run_id, record),
+        };
+
+        let buffers = self.output_buffers.lock().await;
+        let buffered = buffers
+            .get(run_id)
+            .map(|b| b.iter().cloned().collect())
+            .unwrap_or_default();
+        let live = self.output_tx.subscribe();
+
+        Ok(OutputSubscription {
+            run_id: run_id.to_string(),
+            buffered,
+            live,
+            terminal,
+            finished,
+        })
+    }
+
+    /// Terminal notification for `run_id` if it has finished.
+    pub async fn finished_notification(&self, run_id: &str) -> Option<BotTerminalNotification> {
+        let runs = self.runs.lock().await;
+        runs.get(run_id)
+            .and_then(|record| terminal_notification(run_id, record))
+    }
+
+    /// Finish a run whose engine was interrupted by `cancel`.
+    ///
+    /// `cancel` has already recorded the terminal state and log and freed
+    /// the active slot; this only notifies waiters.
+    fn finish_cancelled(&self, request: &BotRunRequest) -> BotRunResult {
+        let run_id = request.run_id.clone();
+        let artifact_uris = self.store.artifact_uris(&run_id);
+        let notification = BotTerminalNotification {
+            run_id: run_id.clone(),
+            status: BotRunState::Cancelled,
+            exit_code: CANCELLED_EXIT_CODE,
+            summary: CANCELLED_SUMMARY.to_string(),
+            artifact_uris: artifact_uris.clone(),
+        };
+        let _ = self.terminal_tx.send(notification);
+
+        BotRunResult {
+            run_id,
+            status: BotRunState::Cancelled,
+            work_item_id: request.work_item_id.clone(),
+            kind: request.kind,
+            exit_code: CANCELLED_EXIT_CODE,
+            summary: Some(CANCELLED_SUMMARY.to_string()),
+            artifact_uris,
+        }
+    }
+
     /// Cancel an active run (PM-D13).
+    ///
+    /// Queued runs are dropped before dispatch; executing runs have their
+    /// engine interrupted. Cancelling a terminal run is a no-op that
+    /// reports its final state.
     pub async fn cancel(
         &self,
         _workspace: &str,
         _work_item_id: &str,
         run_id: &str,
-    ) -> Result<BotRunState, ManagerError> {
+    ) -> Result<BotCancelResult, ManagerError> {
         let mut runs = self.runs.lock().await;
         let record = runs
             .get_mut(run_id)
@@ -520,13 +950,18 @@ impl BotRunManager {
 
         if record.state.is_terminal() {
             // Already done, return current state
-            return Ok(record.state);
+            return Ok(BotCancelResult {
+                run_id: run_id.to_string(),
+                status: record.state,
+                cancelled: false,
+            });
         }
 
         let now = chrono::Utc::now().to_rfc3339();
         record.state = BotRunState::Cancelled;
         record.finished_at = Some(now.clone());
-        record.summary = Some("Cancelled by user".to_string());
+        record.summary = Some(CANCELLED_SUMMARY.to_string());
+        record.exit_code = Some(CANCELLED_EXIT_CODE);
 
         // Persist cancellation log
         let log = codex_core::pm::artifacts::BotRunLog {
@@ -537,15 +972,40 @@ impl BotRunManager {
             started_at: record.started_at.clone().unwrap_or_else(|| now.clone()),
             finished_at: now,
             duration_s: 0,
-            exit_code: 2,
-            summary: "Cancelled by user".to_string(),
+            exit_code: CANCELLED_EXIT_CODE,
+            summary: CANCELLED_SUMMARY.to_string(),
             partial: true,
             checkpoint_count: 0,
             error: None,
         };
         let _ = self.store.write_log(&log);
 
-        Ok(BotRunState::Cancelled)
+        // Free the (workspace, work_item, kind) slot for new submissions
+        {
+            let mut index = self.active_index.lock().await;
+            let key = (
+                record.workspace_path.clone(),
+                record.request.work_item_id.clone(),
+                record.request.kind,
+            );
+            index.remove(&key);
+        }
+
+        self.record_terminal(BotRunState::Cancelled);
+
+        // Interrupt the engine if it is executing; otherwise the run was
+        // still queued and its submitter is waiting on this notification
+        if let Some(token) = self.cancel_tokens.lock().await.remove(run_id) {
+            token.cancel();
+        } else if let Some(notification) = terminal_notification(run_id, record) {
+            let _ = self.terminal_tx.send(notification);
+        }
+
+        Ok(BotCancelResult {
+            run_id: run_id.to_string(),
+            status: BotRunState::Cancelled,
+            cancelled: true,
+        })
     }
 
     /// Resume an incomplete run from persistence.
@@ -586,6 +1046,7 @@ impl BotRunManager {
             summary: None,
             report_json: None,
             cached_artifact_uris: vec![],
+            exit_code: None,
         };
 
         {
@@ -601,19 +1062,28 @@ impl BotRunManager {
             );
             index.insert(key, run_id.to_string());
         }
+        self.total_runs.fetch_add(1, Ordering::Relaxed);
 
         // Re-execute the real engine
-        let engine_result = engine::run_engine(engine::EngineParams {
-            kind: request.kind,
-            run_id: run_id.to_string(),
-            work_item_id: request.work_item_id.clone(),
-            workspace_path: workspace.clone(),
-            write_mode: request.write_mode,
-            rebase_target: None,         // resume does not specify rebase target
-            allow_degraded: None,        // resume uses default allow_degraded
-            notebooklm_health_url: None, // resume uses default health URL
-        })
-        .await;
+        let Some(engine_result) = self
+            .run_engine_cancellable(
+                run_id,
+                engine::EngineParams {
+                    kind: request.kind,
+                    run_id: run_id.to_string(),
+                    work_item_id: request.work_item_id.clone(),
+                    workspace_path: workspace.clone(),
+                    write_mode: request.write_mode,
+                    rebase_target: None,  // resume does not specify rebase target
+                    allow_degraded: None, // resume uses default allow_degraded
+                    notebooklm_health_url: None, // resume uses default health URL
+                    output: None,
+                },
+            )
+            .await
+        else {
+            return Ok(self.finish_cancelled(&request));
+        };
 
         // Persist terminal artifacts (local cache)
         let _ = self.store.write_log(&engine_result.log);
@@ -685,9 +1155,12 @@ impl BotRunManager {
                 record.summary = Some(engine_result.summary.clone());
                 record.report_json = Some(engine_result.report_json.clone());
                 record.cached_artifact_uris = artifact_uris.clone();
+                record.exit_code = Some(engine_result.exit_code);
             }
         }
 
+        self.record_terminal(engine_result.state);
+
         // Remove from active index
         {
             let mut index = self.active_index.lock().await;
@@ -716,7 +1189,7 @@ impl BotRunManager {
         })
     }
 
-    /// Resume all incomplete runs found on disk.
+    /// Resume all incomplete runs found on disk, highest priority first.
     ///
     /// Called at startup before accepting connections.
     pub async fn resume_incomplete(&self) {
@@ -736,7 +1209,23 @@ impl BotRunManager {
         tracing::info!("Resuming {} incomplete run(s)", incomplete.len());
 
         for run in incomplete {
-            tracing::info!("Resuming run {} ({})", run.run_id, run.request.work_item_id);
+            if run.attempts_exhausted() {
+                tracing::warn!(
+                    "Run {} exhausted {} of {} attempts; marking failed",
+                    run.run_id,
+                    run.attempt_count,
+                    run.max_attempts
+                );
+                self.fail_exhausted(&run).await;
+                continue;
+            }
+
+            tracing::info!(
+                "Resuming run {} ({}, priority {})",
+                run.run_id,
+                run.request.work_item_id,
+                run.priority
+            );
             match self.resume(&run.run_id, &run.workspace_path).await {
                 Ok(result) => {
                     tracing::info!("Resumed run {}: {:?}", run.run_id, result.status);
@@ -747,6 +1236,71 @@ impl BotRunManager {
             }
         }
     }
+
+    /// Terminate an incomplete run that has used up its attempts instead
+    /// of resuming it again.
+    async fn fail_exhausted(&self, run: &IncompleteRun) {
+        let now = chrono::Utc::now().to_rfc3339();
+        let summary = format!(
+            "Failed: gave up after {} of {} attempts",
+            run.attempt_count, run.max_attempts
+        );
+
+        let log = codex_core::pm::artifacts::BotRunLog {
+            schema_version: codex_core::pm::artifacts::BotRunLog::SCHEMA_VERSION.to_string(),
+            run_id: run.run_id.clone(),
+            work_item_id: run.request.work_item_id.clone(),
+            state: BotRunState::Failed,
+            started_at: now.clone(),
+            finished_at: now.clone(),
+            duration_s: 0,
+            exit_code: EXHAUSTED_EXIT_CODE,
+            summary: summary.clone(),
+            partial: true,
+            checkpoint_count: run.last_checkpoint.as_ref().map_or(0, |cp| cp.seq + 1),
+            error: None,
+        };
+        if let Err(e) = self.store.write_log(&log) {
+            tracing::warn!("Failed to persist log for run {}: {e}", run.run_id);
+        }
+
+        let artifact_uris = self.store.artifact_uris(&run.run_id);
+        let record = RunRecord {
+            request: run.request.clone(),
+            workspace_path: run.workspace_path.clone(),
+            state: BotRunState::Failed,
+            started_at: None,
+            finished_at: Some(now),
+            summary: Some(summary.clone()),
+            report_json: None,
+            cached_artifact_uris: artifact_uris.clone(),
+            exit_code: Some(EXHAUSTED_EXIT_CODE),
+        };
+        self.runs.lock().await.insert(run.run_id.clone(), record);
+        self.record_terminal(BotRunState::Failed);
+
+        let _ = self.terminal_tx.send(BotTerminalNotification {
+            run_id: run.run_id.clone(),
+            status: BotRunState::Failed,
+            exit_code: EXHAUSTED_EXIT_CODE,
+            summary,
+            artifact_uris,
+        });
+    }
+}
+
+/// Rebuild the terminal notification for a finished run record.
+fn terminal_notification(run_id: &str, record: &RunRecord) -> Option<BotTerminalNotification> {
+    if !record.state.is_terminal() {
+        return None;
+    }
+    Some(BotTerminalNotification {
+        run_id: run_id.to_string(),
+        status: record.state,
+        exit_code: record.exit_code.unwrap_or_default(),
+        summary: record.summary.clone().unwrap_or_default(),
+        artifact_uris: record.cached_artifact_uris.clone(),
+    })
 }
 
 #[cfg(test)]
@@ -761,6 +1315,13 @@ mod tests {
         Arc::new(PersistenceStore::with_base_dir(path).unwrap())
     }
 
+    /// A manager with its dispatcher running, so `submit` executes runs.
+    fn test_manager(store: Arc<PersistenceStore>) -> Arc<BotRunManager> {
+        let mgr = Arc::new(BotRunManager::new(store));
+        mgr.start_dispatcher();
+        mgr
+    }
+
     fn test_params(work_item_id: &str, kind: BotKind) -> BotRunParams {
         BotRunParams {
             workspace_path: "/tmp/test-workspace".to_string(),
@@ -770,6 +1331,7 @@ mod tests {
             write_mode: codex_core::pm::bot::BotWriteMode::None,
             intensity: None,
             rebase_target: None,
+            priority: None,
             subscribe: false,
             allow_degraded: None,
             notebooklm_health_url: None,
@@ -778,7 +1340,7 @@ mod tests {
 
     #[tokio::test]
     async fn submit_and_status() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
         let result = mgr
             .submit(test_params("SPEC-TEST-001", BotKind::Research))
             .await;
@@ -795,9 +1357,68 @@ mod tests {
         assert_eq!(status.runs[0].status, BotRunState::Succeeded);
     }
 
+    #[tokio::test]
+    async fn submit_dispatches_by_priority_then_fifo() {
+        let mgr = Arc::new(BotRunManager::new(test_store()).with_max_concurrent_runs(1));
+        let mut terminal_rx = mgr.subscribe_terminal();
+
+        // Queue all three before the dispatcher starts so they contend
+        let mut submissions = Vec::new();
+        for (work_item_id, priority) in [("SPEC-LOW-A", 1), ("SPEC-HIGH", 9), ("SPEC-LOW-B", 1)] {
+            let mut params = test_params(work_item_id, BotKind::Research);
+            params.priority = Some(priority);
+            let submitter = Arc::clone(&mgr);
+            submissions.push(tokio::spawn(async move { submitter.submit(params).await }));
+            while mgr.queued_run_count().await < submissions.len() {
+                tokio::task::yield_now().await;
+            }
+        }
+        mgr.start_dispatcher();
+
+        let mut run_ids = Vec::new();
+        for submission in submissions {
+            let result = submission.await.unwrap().unwrap();
+            assert_eq!(result.status, BotRunState::Succeeded);
+            run_ids.push(result.run_id);
+        }
+        let [low_a, high, low_b] = run_ids.try_into().unwrap();
+
+        let mut order = Vec::new();
+        for _ in 0..3 {
+            order.push(terminal_rx.recv().await.unwrap().run_id);
+        }
+        assert_eq!(order, vec![high, low_a, low_b]);
+        assert_eq!(mgr.queued_run_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn cancel_while_queued_completes_submit_without_executing() {
+        // No dispatcher: the run stays queued until cancelled
+        let mgr = Arc::new(BotRunManager::new(test_store()));
+        let submitter = Arc::clone(&mgr);
+        let submission = tokio::spawn(async move {
+            submitter
+                .submit(test_params("SPEC-CANCEL", BotKind::Research))
+                .await
+        });
+        while mgr.queued_run_count().await == 0 {
+            tokio::task::yield_now().await;
+        }
+        let status = mgr.status("/tmp/test-workspace", "SPEC-CANCEL", None).await;
+        let run_id = status.runs[0].run_id.clone();
+        mgr.cancel("/tmp/test-workspace", "SPEC-CANCEL", &run_id)
+            .await
+            .unwrap();
+
+        let result = submission.await.unwrap().unwrap();
+        assert_eq!(result.status, BotRunState::Cancelled);
+        assert!(mgr.next_queued().await.is_none());
+        assert_eq!(mgr.active_run_count().await, 0);
+    }
+
     #[tokio::test]
     async fn reject_capture_none() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
         let mut params = test_params("SPEC-TEST-001", BotKind::Research);
         params.capture_mode = BotCaptureMode::None;
 
@@ -813,7 +1434,7 @@ mod tests {
 
     #[tokio::test]
     async fn cross_kind_allowed() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
 
         let r1 = mgr
             .submit(test_params("SPEC-TEST-001", BotKind::Research))
@@ -828,14 +1449,37 @@ mod tests {
 
     #[tokio::test]
     async fn uptime_and_active_count() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
         assert!(mgr.uptime_s() < 2);
         assert_eq!(mgr.active_run_count().await, 0);
     }
 
+    #[tokio::test]
+    async fn metrics_count_terminal_outcomes() {
+        let mgr = Arc::new(BotRunManager::new(test_store()));
+        let queued = mgr
+            .enqueue(test_params("SPEC-METRICS-002", BotKind::Research), 0)
+            .await
+            .unwrap();
+        mgr.cancel("/tmp/test-workspace", "SPEC-METRICS-002", &queued)
+            .await
+            .unwrap();
+        mgr.start_dispatcher();
+        mgr.submit(test_params("SPEC-METRICS-001", BotKind::Research))
+            .await
+            .unwrap();
+
+        let metrics = mgr.metrics().await;
+        assert_eq!(metrics.active_runs, 0);
+        assert_eq!(metrics.total_runs, 2);
+        assert_eq!(metrics.completed_runs, 2);
+        assert_eq!(metrics.failed_runs, 0);
+        assert_eq!(metrics.connection_count, 0);
+    }
+
     #[tokio::test]
     async fn test_show_returns_record() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
         let result = mgr
             .submit(test_params("SPEC-TEST-001", BotKind::Research))
             .await
@@ -852,7 +1496,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_show_not_found() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
         let result = mgr.show("nonexistent-run-id").await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not found"));
@@ -860,7 +1504,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_runs_filters_workspace() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
 
         // Submit to workspace A
         let mut params_a = test_params("SPEC-TEST-001", BotKind::Research);
@@ -889,7 +1533,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_runs_pagination() {
-        let mgr = BotRunManager::new(test_store());
+        let mgr = test_manager(test_store());
 
         mgr.submit(test_params("SPEC-TEST-001", BotKind::Research))
             .await
@@ -918,7 +1562,7 @@ mod tests {
     #[tokio::test]
     async fn submit_persists_request_on_disk() {
         let store = test_store();
-        let mgr = BotRunManager::new(Arc::clone(&store));
+        let mgr = test_manager(Arc::clone(&store));
 
         let result = mgr
             .submit(test_params("SPEC-TEST-P01", BotKind::Research))
@@ -934,7 +1578,7 @@ mod tests {
     #[tokio::test]
     async fn submit_persists_terminal_artifacts() {
         let store = test_store();
-        let mgr = BotRunManager::new(Arc::clone(&store));
+        let mgr = test_manager(Arc::clone(&store));
 
         let result = mgr
             .submit(test_params("SPEC-TEST-P02", BotKind::Research))
@@ -953,7 +1597,7 @@ mod tests {
     #[tokio::test]
     async fn show_returns_artifact_uris() {
         let store = test_store();
-        let mgr = BotRunManager::new(Arc::clone(&store));
+        let mgr = test_manager(Arc::clone(&store));
 
         let result = mgr
             .submit(test_params("SPEC-TEST-P03", BotKind::Research))
@@ -985,7 +1629,7 @@ mod tests {
         store.write_request(&request, "/tmp/ws").unwrap();
 
         // No log.json → incomplete
-        let mgr = BotRunManager::new(Arc::clone(&store));
+        let mgr = test_manager(Arc::clone(&store));
         let result = mgr.resume("resume-test-001", "/tmp/ws").await.unwrap();
 
         assert_eq!(result.status, BotRunState::Succeeded);
@@ -999,10 +1643,52 @@ mod tests {
         assert!(report.is_some());
     }
 
+    #[tokio::test]
+    async fn resume_incomplete_fails_runs_at_max_attempts() {
+        let store = test_store();
+        let make_request = |run_id: &str, work_item_id: &str| BotRunRequest {
+            schema_version: BotRunRequest::SCHEMA_VERSION.to_string(),
+            run_id: run_id.to_string(),
+            work_item_id: work_item_id.to_string(),
+            kind: BotKind::Research,
+            capture_mode: BotCaptureMode::PromptsOnly,
+            write_mode: codex_core::pm::bot::BotWriteMode::None,
+            requested_at: "2026-02-09T12:00:00Z".to_string(),
+            trigger: None,
+        };
+
+        // Crashed on every one of its attempts so far
+        let flapping = make_request("flapping-001", "SPEC-FLAP-001");
+        store.write_request(&flapping, "/tmp/ws").unwrap();
+        store.set_max_attempts("flapping-001", 2).unwrap();
+        store.record_attempt("flapping-001").unwrap();
+        store.record_attempt("flapping-001").unwrap();
+
+        // Crashed once, still has budget
+        let fresh = make_request("fresh-001", "SPEC-FRESH-001");
+        store.write_request(&fresh, "/tmp/ws").unwrap();
+        store.record_attempt("fresh-001").unwrap();
+
+        let mgr = test_manager(Arc::clone(&store));
+        mgr.resume_incomplete().await;
+
+        let log = store.read_log("flapping-001").unwrap().unwrap();
+        assert_eq!(log.state, BotRunState::Failed);
+        assert!(log.summary.contains("2 of 2 attempts"), "{}", log.summary);
+        assert!(store.read_report("flapping-001").unwrap().is_none());
+        let shown = mgr.show("flapping-001").await.unwrap();
+        assert_eq!(shown.status, BotRunState::Failed);
+
+        let log = store.read_log("fresh-001").unwrap().unwrap();
+        assert_eq!(log.state, BotRunState::Succeeded);
+        assert!(store.scan_incomplete().unwrap().is_empty());
+        assert_eq!(mgr.metrics().await.failed_runs, 1);
+    }
+
     #[tokio::test]
     async fn resume_fails_for_terminal_run() {
         let store = test_store();
-        let mgr = BotRunManager::new(Arc::clone(&store));
+        let mgr = test_manager(Arc::clone(&store));
 
         // Submit a run (which completes immediately)
         let result = mgr
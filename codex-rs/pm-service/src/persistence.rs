@@ -9,7 +9,7 @@
 //! ```text
 //! ~/.local/share/codex-pm/runs/{run_id}/
 //!   request.json          BotRunRequest
-//!   meta.json             { workspace_path }
+//!   meta.json             { workspace_path, priority, attempt_count, max_attempts }
 //!   checkpoint-{seq}.json BotRunCheckpoint
 //!   log.json              BotRunLog (terminal record)
 //!   report.json           BotRunResult (serialized report)
@@ -50,19 +50,47 @@ pub enum PersistenceError {
     NotFound { run_id: String },
 }
 
+/// Priority assigned to runs submitted without one. Higher values dispatch first.
+pub const DEFAULT_PRIORITY: u8 = 0;
+
+/// Engine executions allowed per run (first attempt plus resumes).
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
 /// An incomplete run discovered during `scan_incomplete`.
 #[derive(Debug, Clone)]
 pub struct IncompleteRun {
     pub run_id: String,
     pub request: BotRunRequest,
     pub workspace_path: String,
+    pub priority: u8,
+    /// Engine executions started so far.
+    pub attempt_count: u32,
+    pub max_attempts: u32,
     pub last_checkpoint: Option<BotRunCheckpoint>,
 }
 
+impl IncompleteRun {
+    /// Whether another execution would exceed `max_attempts`.
+    pub fn attempts_exhausted(&self) -> bool {
+        self.attempt_count >= self.max_attempts
+    }
+}
+
 /// Metadata stored alongside the request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct RunMeta {
     workspace_path: String,
+    /// Absent in metadata written before priorities existed.
+    #[serde(default)]
+    priority: u8,
+    #[serde(default)]
+    attempt_count: u32,
+    #[serde(default = "default_max_attempts")]
+    max_attempts: u32,
+}
+
+fn default_max_attempts() -> u32 {
+    DEFAULT_MAX_ATTEMPTS
 }
 
 /// Persistent store for bot run artifacts.
@@ -117,6 +145,16 @@ impl PersistenceStore {
         &self,
         request: &BotRunRequest,
         workspace_path: &str,
+    ) -> Result<String, PersistenceError> {
+        self.write_request_with_priority(request, workspace_path, DEFAULT_PRIORITY)
+    }
+
+    /// Persist a `BotRunRequest` with its dispatch priority. Returns the request URI.
+    pub fn write_request_with_priority(
+        &self,
+        request: &BotRunRequest,
+        workspace_path: &str,
+        priority: u8,
     ) -> Result<String, PersistenceError> {
         let dir = self.run_dir(&request.run_id);
         std::fs::create_dir_all(&dir)?;
@@ -126,13 +164,53 @@ impl PersistenceStore {
 
         let meta = RunMeta {
             workspace_path: workspace_path.to_string(),
+            priority,
+            attempt_count: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
         };
-        let meta_json = serde_json::to_string_pretty(&meta)?;
-        self.atomic_write(&dir.join("meta.json"), meta_json.as_bytes())?;
+        self.write_meta(&request.run_id, &meta)?;
 
         Ok(format!("pm://runs/{}/request", request.run_id))
     }
 
+    /// Count a new engine execution for a run. Returns the updated attempt count.
+    pub fn record_attempt(&self, run_id: &str) -> Result<u32, PersistenceError> {
+        let mut meta = self.read_meta(run_id)?;
+        meta.attempt_count += 1;
+        self.write_meta(run_id, &meta)?;
+        Ok(meta.attempt_count)
+    }
+
+    /// Override the attempt budget for a run.
+    pub fn set_max_attempts(
+        &self,
+        run_id: &str,
+        max_attempts: u32,
+    ) -> Result<(), PersistenceError> {
+        let mut meta = self.read_meta(run_id)?;
+        meta.max_attempts = max_attempts;
+        self.write_meta(run_id, &meta)
+    }
+
+    fn write_meta(&self, run_id: &str, meta: &RunMeta) -> Result<(), PersistenceError> {
+        let meta_json = serde_json::to_string_pretty(meta)?;
+        self.atomic_write(
+            &self.run_dir(run_id).join("meta.json"),
+            meta_json.as_bytes(),
+        )
+    }
+
+    fn read_meta(&self, run_id: &str) -> Result<RunMeta, PersistenceError> {
+        let path = self.run_dir(run_id).join("meta.json");
+        let data = std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => PersistenceError::NotFound {
+                run_id: run_id.to_string(),
+            },
+            _ => e.into(),
+        })?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
     /// Persist a checkpoint. Returns the checkpoint URI.
     pub fn write_checkpoint(
         &self,
@@ -245,6 +323,9 @@ impl PersistenceStore {
 
     /// Scan for incomplete runs: directories with `request.json` but no
     /// terminal `log.json` (absent or with a non-terminal state).
+    ///
+    /// Results are in dispatch order: highest priority first, then oldest
+    /// `requested_at` first within a priority level.
     pub fn scan_incomplete(&self) -> Result<Vec<IncompleteRun>, PersistenceError> {
         let mut incomplete = Vec::new();
 
@@ -285,12 +366,16 @@ impl PersistenceStore {
             let request: BotRunRequest = serde_json::from_str(&req_data)?;
 
             let meta_path = dir.join("meta.json");
-            let workspace_path = if meta_path.exists() {
+            let meta = if meta_path.exists() {
                 let meta_data = std::fs::read_to_string(&meta_path)?;
-                let meta: RunMeta = serde_json::from_str(&meta_data)?;
-                meta.workspace_path
+                serde_json::from_str(&meta_data)?
             } else {
-                String::new()
+                RunMeta {
+                    workspace_path: String::new(),
+                    priority: DEFAULT_PRIORITY,
+                    attempt_count: 0,
+                    max_attempts: DEFAULT_MAX_ATTEMPTS,
+                }
             };
 
             // Find the latest checkpoint
@@ -299,11 +384,21 @@ impl PersistenceStore {
             incomplete.push(IncompleteRun {
                 run_id,
                 request,
-                workspace_path,
+                workspace_path: meta.workspace_path,
+                priority: meta.priority,
+                attempt_count: meta.attempt_count,
+                max_attempts: meta.max_attempts,
                 last_checkpoint,
             });
         }
 
+        incomplete.sort_by(|a, b| {
+            b.priority
+                .cmp(&a.priority)
+                .then_with(|| a.request.requested_at.cmp(&b.request.requested_at))
+                .then_with(|| a.run_id.cmp(&b.run_id))
+        });
+
         Ok(incomplete)
     }
 
@@ -705,6 +800,57 @@ mod tests {
         assert_eq!(incomplete[0].run_id, "run-partial");
     }
 
+    #[test]
+    fn scan_incomplete_orders_by_priority_then_age() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = PersistenceStore::with_base_dir(tmp.path().to_path_buf()).unwrap();
+
+        let mut old_low = make_request("run-old-low", "SPEC-TEST-001");
+        old_low.requested_at = "2026-02-09T11:00:00Z".to_string();
+        let high = make_request("run-high", "SPEC-TEST-002");
+        let new_low = make_request("run-new-low", "SPEC-TEST-003");
+        store
+            .write_request_with_priority(&new_low, "/tmp/ws", 1)
+            .unwrap();
+        store
+            .write_request_with_priority(&high, "/tmp/ws", 9)
+            .unwrap();
+        store
+            .write_request_with_priority(&old_low, "/tmp/ws", 1)
+            .unwrap();
+
+        let incomplete = store.scan_incomplete().unwrap();
+        let order: Vec<_> = incomplete.iter().map(|r| r.run_id.as_str()).collect();
+        assert_eq!(order, vec!["run-high", "run-old-low", "run-new-low"]);
+        assert_eq!(incomplete[0].priority, 9);
+    }
+
+    #[test]
+    fn meta_without_priority_uses_default() {
+        let meta: RunMeta = serde_json::from_str(r#"{"workspace_path":"/tmp/ws"}"#).unwrap();
+        assert_eq!(meta.priority, DEFAULT_PRIORITY);
+        assert_eq!(meta.attempt_count, 0);
+        assert_eq!(meta.max_attempts, DEFAULT_MAX_ATTEMPTS);
+    }
+
+    #[test]
+    fn record_attempt_survives_rescan() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = PersistenceStore::with_base_dir(tmp.path().to_path_buf()).unwrap();
+
+        let req = make_request("run-retry", "SPEC-TEST-001");
+        store.write_request(&req, "/tmp/ws").unwrap();
+        assert_eq!(store.record_attempt("run-retry").unwrap(), 1);
+        assert_eq!(store.record_attempt("run-retry").unwrap(), 2);
+        store.set_max_attempts("run-retry", 2).unwrap();
+
+        let incomplete = store.scan_incomplete().unwrap();
+        assert_eq!(incomplete[0].attempt_count, 2);
+        assert_eq!(incomplete[0].max_attempts, 2);
+        assert!(incomplete[0].attempts_exhausted());
+        assert!(store.record_attempt("missing").is_err());
+    }
+
     #[test]
     fn artifact_uris_returns_correct_uris() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -25,6 +25,7 @@ pub const ERR_INVARIANT: i64 = 13;
 pub const ERR_DUPLICATE_RUN: i64 = 100;
 pub const ERR_CAPSULE: i64 = 200;
 pub const ERR_INFRA: i64 = 300;
+pub const ERR_UNAUTHORIZED: i64 = 400;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Handshake (PM-D9)
@@ -34,6 +35,9 @@ pub const ERR_INFRA: i64 = 300;
 pub struct HelloParams {
     pub protocol_version: String,
     pub client_version: String,
+    /// Shared secret; required when the service has `CODEX_PM_TOKEN` set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,6 +63,9 @@ pub struct BotRunParams {
     pub intensity: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rebase_target: Option<String>,
+    /// Queue priority; higher values dispatch first. Default: 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
     /// If true, client wants push notifications (PM-D24).
     #[serde(default)]
     pub subscribe: bool,
@@ -175,13 +182,44 @@ pub struct BotRunsResult {
 // bot.cancel
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Only `run_id` is required; the other fields are accepted for
+/// symmetry with the rest of the `bot.*` methods.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BotCancelParams {
+    #[serde(default)]
     pub workspace_path: String,
+    #[serde(default)]
     pub work_item_id: String,
     pub run_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BotCancelResult {
+    pub run_id: String,
+    /// Final state of the run.
+    pub status: BotRunState,
+    /// `false` when the run had already finished (no-op).
+    pub cancelled: bool,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// bot.subscribe
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Follow a run's output. After the response the connection receives
+/// `bot.output` notifications, then a final `bot.terminal`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BotSubscribeParams {
+    pub run_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BotSubscribeResult {
+    pub run_id: String,
+    /// State at subscribe time.
+    pub status: BotRunState,
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // bot.resume
 // ─────────────────────────────────────────────────────────────────────────────
@@ -204,6 +242,24 @@ pub struct ServiceStatusResult {
     pub workspaces: Vec<String>,
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// service.metrics
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceMetricsResult {
+    /// Runs queued or executing right now.
+    pub active_runs: usize,
+    /// Runs admitted or resumed since service start.
+    pub total_runs: u64,
+    /// Runs that finished in any terminal state other than `failed`.
+    pub completed_runs: u64,
+    /// Runs that finished `failed`.
+    pub failed_runs: u64,
+    pub uptime_secs: u64,
+    pub connection_count: u32,
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // service.doctor
 // ─────────────────────────────────────────────────────────────────────────────
@@ -242,6 +298,13 @@ pub struct BotProgressNotification {
     pub checkpoint_uri: Option<String>,
 }
 
+/// One line of run output (`bot.output`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotOutputNotification {
+    pub run_id: String,
+    pub line: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BotTerminalNotification {
     pub run_id: String,
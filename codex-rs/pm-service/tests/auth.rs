@@ -0,0 +1,132 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+//! Shared-secret authentication test (`CODEX_PM_TOKEN`).
+//!
+//! Verifies that a configured token gates the `hello` handshake, that a
+//! wrong token is rejected and the connection closed, and that services
+//! without a token accept any client.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_pm_service::PROTOCOL_VERSION;
+use codex_pm_service::manager::BotRunManager;
+use codex_pm_service::persistence::PersistenceStore;
+use codex_pm_service::protocol::ERR_UNAUTHORIZED;
+
+/// Start a service on a temp socket; returns the shutdown sender.
+async fn start_service(dir: &Path, auth_token: Option<&str>) -> tokio::sync::watch::Sender<bool> {
+    let store = Arc::new(PersistenceStore::with_base_dir(dir.join("data")).unwrap());
+    let manager = Arc::new(BotRunManager::new(store));
+    let listener = tokio::net::UnixListener::bind(dir.join("pm.sock")).unwrap();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+    tokio::spawn(codex_pm_service::ipc::serve_with_auth(
+        manager,
+        listener,
+        shutdown_rx,
+        auth_token.map(str::to_string),
+    ));
+    shutdown_tx
+}
+
+/// Send messages in order over one connection; returns each response line
+/// (empty string once the server has closed the connection).
+async fn exchange(socket: &Path, messages: Vec<serde_json::Value>) -> Vec<String> {
+    let socket = socket.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let mut stream = UnixStream::connect(socket).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        messages
+            .into_iter()
+            .map(|msg| {
+                let mut bytes = serde_json::to_vec(&msg).unwrap();
+                bytes.push(b'\n');
+                // Writing after the server hung up may fail; the read reports EOF
+                let _ = stream.write_all(&bytes);
+                let mut line = String::new();
+                let _ = reader.read_line(&mut line);
+                line
+            })
+            .collect()
+    })
+    .await
+    .unwrap()
+}
+
+fn hello(token: Option<&str>) -> serde_json::Value {
+    serde_json::json!({
+        "id": 0,
+        "method": "hello",
+        "params": {
+            "protocol_version": PROTOCOL_VERSION,
+            "client_version": "auth-test",
+            "token": token,
+        }
+    })
+}
+
+fn service_status() -> serde_json::Value {
+    serde_json::json!({ "id": 1, "method": "service.status" })
+}
+
+fn parse(line: &str) -> serde_json::Value {
+    serde_json::from_str(line).unwrap_or_else(|e| panic!("parse response: {e}\nraw: {line}"))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn matching_token_is_accepted() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let _shutdown = start_service(dir.path(), Some("s3cret")).await;
+
+    let lines = exchange(
+        &dir.path().join("pm.sock"),
+        vec![hello(Some("s3cret")), service_status()],
+    )
+    .await;
+
+    assert!(parse(&lines[0]).get("result").is_some(), "{}", lines[0]);
+    assert!(parse(&lines[1]).get("result").is_some(), "{}", lines[1]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn wrong_token_is_rejected() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let _shutdown = start_service(dir.path(), Some("s3cret")).await;
+
+    let lines = exchange(
+        &dir.path().join("pm.sock"),
+        vec![hello(Some("guess")), service_status()],
+    )
+    .await;
+
+    let resp = parse(&lines[0]);
+    assert_eq!(resp["error"]["code"].as_i64(), Some(ERR_UNAUTHORIZED));
+    assert!(lines[1].is_empty(), "connection should be closed");
+
+    // Skipping the handshake is rejected the same way
+    let lines = exchange(&dir.path().join("pm.sock"), vec![service_status()]).await;
+    assert_eq!(
+        parse(&lines[0])["error"]["code"].as_i64(),
+        Some(ERR_UNAUTHORIZED)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn no_token_configured_accepts_any_client() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let _shutdown = start_service(dir.path(), None).await;
+
+    let lines = exchange(
+        &dir.path().join("pm.sock"),
+        vec![hello(None), service_status()],
+    )
+    .await;
+
+    assert!(parse(&lines[0]).get("result").is_some(), "{}", lines[0]);
+    assert!(parse(&lines[1]).get("result").is_some(), "{}", lines[1]);
+}
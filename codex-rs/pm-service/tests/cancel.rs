@@ -0,0 +1,116 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+//! Graceful cancellation test (`bot.cancel`).
+//!
+//! Starts a research run whose NotebookLM health check hangs, cancels it
+//! from a second connection, and verifies the run ends `cancelled` without
+//! waiting for the engine. Cancelling again is a no-op.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_pm_service::manager::BotRunManager;
+use codex_pm_service::persistence::PersistenceStore;
+
+mod common;
+use common::{TestClient, hanging_health_url};
+
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    tokio::task::spawn_blocking(f).await.unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cancel_interrupts_running_bot() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let socket: PathBuf = temp_dir.path().join("pm.sock");
+    let workspace = temp_dir.path().to_string_lossy().to_string();
+
+    let store = Arc::new(PersistenceStore::with_base_dir(temp_dir.path().join("data")).unwrap());
+    let manager = Arc::new(BotRunManager::new(store));
+    let listener = tokio::net::UnixListener::bind(&socket).unwrap();
+    let (_shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+    tokio::spawn(codex_pm_service::ipc::serve(
+        Arc::clone(&manager),
+        listener,
+        shutdown_rx,
+    ));
+
+    let health_url = hanging_health_url().await;
+
+    // Long-running job on its own connection
+    let run_params = serde_json::json!({
+        "workspace_path": workspace,
+        "work_item_id": "SPEC-CANCEL-001",
+        "kind": "research",
+        "capture_mode": "prompts_only",
+        "notebooklm_health_url": health_url,
+    });
+    let run_socket = socket.clone();
+    let run = tokio::task::spawn_blocking(move || {
+        TestClient::connect(&run_socket).rpc("bot.run", run_params)
+    });
+
+    // Find the run ID once the engine is executing
+    let status_socket = socket.clone();
+    let status_ws = workspace.clone();
+    let run_id = blocking(move || {
+        let mut client = TestClient::connect(&status_socket);
+        for _ in 0..200 {
+            let resp = client.rpc(
+                "bot.status",
+                serde_json::json!({
+                    "workspace_path": status_ws,
+                    "work_item_id": "SPEC-CANCEL-001",
+                }),
+            );
+            if let Some(run) = resp["result"]["runs"].as_array().and_then(|r| r.first())
+                && run["status"] == "running"
+            {
+                return run["run_id"].as_str().unwrap().to_string();
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("run never started");
+    })
+    .await;
+
+    let cancel_socket = socket.clone();
+    let cancel_id = run_id.clone();
+    let cancel = blocking(move || {
+        TestClient::connect(&cancel_socket)
+            .rpc("bot.cancel", serde_json::json!({ "run_id": cancel_id }))
+    })
+    .await;
+    assert_eq!(cancel["result"]["status"], "cancelled", "{cancel}");
+    assert_eq!(cancel["result"]["cancelled"], true);
+
+    // The blocked bot.run returns promptly instead of waiting out the health check
+    let run_resp = tokio::time::timeout(Duration::from_secs(3), run)
+        .await
+        .expect("bot.run returns after cancel")
+        .unwrap();
+    assert_eq!(run_resp["result"]["status"], "cancelled", "{run_resp}");
+    assert_eq!(run_resp["result"]["run_id"], run_id.as_str());
+
+    // Terminal state is visible and a second cancel is a no-op
+    let show_socket = socket.clone();
+    let show_ws = workspace.clone();
+    let (show, again) = blocking(move || {
+        let mut client = TestClient::connect(&show_socket);
+        let show = client.rpc(
+            "bot.show",
+            serde_json::json!({
+                "workspace_path": show_ws,
+                "work_item_id": "SPEC-CANCEL-001",
+                "run_id": run_id,
+            }),
+        );
+        let again = client.rpc("bot.cancel", serde_json::json!({ "run_id": run_id }));
+        (show, again)
+    })
+    .await;
+    assert_eq!(show["result"]["status"], "cancelled", "{show}");
+    assert_eq!(again["result"]["status"], "cancelled");
+    assert_eq!(again["result"]["cancelled"], false);
+    assert_eq!(manager.active_run_count().await, 0);
+}
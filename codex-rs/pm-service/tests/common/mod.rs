@@ -0,0 +1,92 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, dead_code)]
+//! Helpers shared by the PM service integration tests.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::time::Duration;
+
+use codex_pm_service::PROTOCOL_VERSION;
+
+/// Blocking client over one connection, handshaken on connect.
+pub struct TestClient {
+    writer: UnixStream,
+    reader: BufReader<UnixStream>,
+}
+
+impl TestClient {
+    pub fn connect(socket_path: &Path) -> Self {
+        let stream = UnixStream::connect(socket_path).expect("connect to PM service");
+        stream
+            .set_read_timeout(Some(Duration::from_secs(30)))
+            .unwrap();
+        let writer = stream.try_clone().expect("clone stream");
+        let mut client = Self {
+            writer,
+            reader: BufReader::new(stream),
+        };
+        let resp = client.rpc(
+            "hello",
+            serde_json::json!({
+                "protocol_version": PROTOCOL_VERSION,
+                "client_version": "pm-service-test"
+            }),
+        );
+        assert!(resp.get("result").is_some(), "hello failed: {resp}");
+        client
+    }
+
+    pub fn rpc(&mut self, method: &str, params: serde_json::Value) -> serde_json::Value {
+        let msg = serde_json::json!({ "id": 1, "method": method, "params": params });
+        let mut bytes = serde_json::to_vec(&msg).unwrap();
+        bytes.push(b'\n');
+        self.writer.write_all(&bytes).unwrap();
+        self.writer.flush().unwrap();
+        self.read_message()
+    }
+
+    pub fn read_message(&mut self) -> serde_json::Value {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap_or_else(|e| panic!("parse message: {e}\nraw: {line}"))
+    }
+
+    pub fn metrics(&mut self) -> serde_json::Value {
+        let resp = self.rpc("service.metrics", serde_json::Value::Null);
+        resp.get("result")
+            .cloned()
+            .unwrap_or_else(|| panic!("service.metrics failed: {resp}"))
+    }
+
+    /// Subscribe and collect notifications through `bot.terminal`.
+    pub fn follow(&mut self, run_id: &str) -> (Vec<String>, serde_json::Value) {
+        let resp = self.rpc("bot.subscribe", serde_json::json!({ "run_id": run_id }));
+        assert!(resp.get("result").is_some(), "subscribe failed: {resp}");
+
+        let mut lines = Vec::new();
+        loop {
+            let notif = self.read_message();
+            match notif["method"].as_str() {
+                Some("bot.output") => {
+                    assert_eq!(notif["params"]["run_id"], run_id);
+                    lines.push(notif["params"]["line"].as_str().unwrap().to_string());
+                }
+                Some("bot.terminal") => return (lines, notif["params"].clone()),
+                other => panic!("unexpected message {other:?}: {notif}"),
+            }
+        }
+    }
+}
+
+/// A health endpoint that accepts connections and never answers.
+pub async fn hanging_health_url() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((stream, _)) = listener.accept().await {
+            held.push(stream);
+        }
+    });
+    format!("http://{addr}/health/ready")
+}
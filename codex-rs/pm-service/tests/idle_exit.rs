@@ -0,0 +1,39 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+//! Idle exit test (`CODEX_PM_IDLE_TIMEOUT_SECS`).
+//!
+//! Launches the service binary with a one-second idle timeout and verifies
+//! it exits cleanly on its own when no client connects.
+
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+#[test]
+fn service_exits_after_configured_idle_timeout() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_codex-pm-service"))
+        .env("XDG_RUNTIME_DIR", temp_dir.path())
+        .env("XDG_DATA_HOME", temp_dir.path().join("data"))
+        .env("CODEX_PM_IDLE_TIMEOUT_SECS", "1")
+        .env("CODEX_PM_IDLE_POLL_SECS", "1")
+        .env_remove("CODEX_PM_LISTEN_ADDR")
+        .env_remove("LISTEN_FDS")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn codex-pm-service");
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if started.elapsed() > Duration::from_secs(15) {
+            child.kill().unwrap();
+            panic!("service did not exit while idle");
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    assert!(status.success(), "unexpected exit status: {status}");
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
@@ -0,0 +1,76 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+//! Metrics snapshot test (`service.metrics`).
+//!
+//! Starts a research run whose NotebookLM health check hangs until the
+//! engine's client timeout, scrapes metrics while it is active, then again
+//! after it finishes.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_pm_service::manager::BotRunManager;
+use codex_pm_service::persistence::PersistenceStore;
+
+mod common;
+use common::{TestClient, hanging_health_url};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn metrics_track_active_and_completed_runs() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let socket = temp_dir.path().join("pm.sock");
+    let workspace = temp_dir.path().to_string_lossy().to_string();
+
+    let store = Arc::new(PersistenceStore::with_base_dir(temp_dir.path().join("data")).unwrap());
+    let manager = Arc::new(BotRunManager::new(store));
+    let listener = tokio::net::UnixListener::bind(&socket).unwrap();
+    let (_shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+    tokio::spawn(codex_pm_service::ipc::serve(
+        Arc::clone(&manager),
+        listener,
+        shutdown_rx,
+    ));
+
+    let health_url = hanging_health_url().await;
+    let run_params = serde_json::json!({
+        "workspace_path": workspace,
+        "work_item_id": "SPEC-METRICS-001",
+        "kind": "research",
+        "capture_mode": "prompts_only",
+        "notebooklm_health_url": health_url,
+    });
+    let run_socket = socket.clone();
+    let run = tokio::task::spawn_blocking(move || {
+        TestClient::connect(&run_socket).rpc("bot.run", run_params)
+    });
+
+    let metrics_socket = socket.clone();
+    let during = tokio::task::spawn_blocking(move || {
+        let mut client = TestClient::connect(&metrics_socket);
+        for _ in 0..200 {
+            let metrics = client.metrics();
+            if metrics["active_runs"] == 1 {
+                return metrics;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("run never became active");
+    })
+    .await
+    .unwrap();
+    assert_eq!(during["active_runs"], 1, "{during}");
+    assert_eq!(during["total_runs"], 1);
+    assert_eq!(during["completed_runs"], 0);
+    assert!(during["connection_count"].as_u64().unwrap() >= 2);
+
+    let resp = run.await.unwrap();
+    assert_eq!(resp["result"]["status"], "succeeded", "{resp}");
+
+    let after = tokio::task::spawn_blocking(move || TestClient::connect(&socket).metrics())
+        .await
+        .unwrap();
+    assert_eq!(after["active_runs"], 0, "{after}");
+    assert_eq!(after["total_runs"], 1);
+    assert_eq!(after["completed_runs"], 1);
+    assert_eq!(after["failed_runs"], 0);
+    assert!(after["uptime_secs"].is_u64());
+}
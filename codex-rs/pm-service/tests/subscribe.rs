@@ -0,0 +1,93 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+//! Run output streaming test (`bot.subscribe`).
+//!
+//! Subscribes to a research run while its NotebookLM health check hangs,
+//! and verifies buffered and live `bot.output` lines arrive before the
+//! `bot.terminal` marker. The buffer is dropped once the run finishes, so a
+//! late subscriber gets only the terminal marker.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_pm_service::manager::BotRunManager;
+use codex_pm_service::persistence::PersistenceStore;
+
+mod common;
+use common::{TestClient, hanging_health_url};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn subscribe_streams_output_then_terminal() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let socket = temp_dir.path().join("pm.sock");
+    let workspace = temp_dir.path().to_string_lossy().to_string();
+
+    let store = Arc::new(PersistenceStore::with_base_dir(temp_dir.path().join("data")).unwrap());
+    let manager = Arc::new(BotRunManager::new(store));
+    let listener = tokio::net::UnixListener::bind(&socket).unwrap();
+    let (_shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+    tokio::spawn(codex_pm_service::ipc::serve(
+        Arc::clone(&manager),
+        listener,
+        shutdown_rx,
+    ));
+
+    let health_url = hanging_health_url().await;
+    let run_params = serde_json::json!({
+        "workspace_path": workspace,
+        "work_item_id": "SPEC-STREAM-001",
+        "kind": "research",
+        "capture_mode": "prompts_only",
+        "notebooklm_health_url": health_url,
+    });
+    let run_socket = socket.clone();
+    let run = tokio::task::spawn_blocking(move || {
+        TestClient::connect(&run_socket).rpc("bot.run", run_params)
+    });
+
+    let follow_socket = socket.clone();
+    let status_ws = workspace.clone();
+    let (run_id, lines, terminal) = tokio::task::spawn_blocking(move || {
+        let mut client = TestClient::connect(&follow_socket);
+        let run_id = (0..200)
+            .find_map(|_| {
+                let resp = client.rpc(
+                    "bot.status",
+                    serde_json::json!({
+                        "workspace_path": status_ws,
+                        "work_item_id": "SPEC-STREAM-001",
+                    }),
+                );
+                let run = resp["result"]["runs"].as_array()?.first()?.clone();
+                if run["status"] == "running" {
+                    return run["run_id"].as_str().map(str::to_string);
+                }
+                std::thread::sleep(Duration::from_millis(10));
+                None
+            })
+            .expect("run never started");
+        let (lines, terminal) = client.follow(&run_id);
+        (run_id, lines, terminal)
+    })
+    .await
+    .unwrap();
+
+    // Buffered dependency check, then live lines after the health check times out
+    assert_eq!(
+        lines.first().map(String::as_str),
+        Some("[dependency_check] Checking NotebookLM availability")
+    );
+    assert!(lines.len() > 1, "expected live lines, got {lines:?}");
+    assert_eq!(terminal["run_id"], run_id.as_str());
+    assert_eq!(terminal["status"], "succeeded");
+
+    let run_resp = run.await.unwrap();
+    assert_eq!(run_resp["result"]["status"], "succeeded", "{run_resp}");
+
+    // A late subscriber gets the terminal marker immediately, with no replay
+    let (replayed, late_terminal) =
+        tokio::task::spawn_blocking(move || TestClient::connect(&socket).follow(&run_id))
+            .await
+            .unwrap();
+    assert_eq!(replayed, Vec::<String>::new());
+    assert_eq!(late_terminal["status"], "succeeded");
+}
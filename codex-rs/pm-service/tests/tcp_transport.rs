@@ -0,0 +1,73 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+//! TCP transport test (`CODEX_PM_LISTEN_ADDR`).
+//!
+//! Binds the IPC server on an ephemeral TCP port and verifies the
+//! JSON-RPC-lite hello handshake works unchanged over the stream.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_pm_service::PROTOCOL_VERSION;
+use codex_pm_service::manager::BotRunManager;
+use codex_pm_service::persistence::PersistenceStore;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tcp_listener_hello_handshake() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let store = Arc::new(PersistenceStore::with_base_dir(temp_dir.path().join("data")).unwrap());
+    let manager = Arc::new(BotRunManager::new(store));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+    let server = tokio::spawn(codex_pm_service::ipc::serve(
+        Arc::clone(&manager),
+        listener,
+        shutdown_rx,
+    ));
+
+    let resp = tokio::task::spawn_blocking(move || {
+        let mut stream = TcpStream::connect(addr).expect("connect over TCP");
+        stream
+            .set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+
+        let hello = serde_json::json!({
+            "id": 0,
+            "method": "hello",
+            "params": {
+                "protocol_version": PROTOCOL_VERSION,
+                "client_version": "tcp-test"
+            }
+        });
+        let mut bytes = serde_json::to_vec(&hello).unwrap();
+        bytes.push(b'\n');
+        stream.write_all(&bytes).unwrap();
+        stream.flush().unwrap();
+
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        serde_json::from_str::<serde_json::Value>(&line).unwrap()
+    })
+    .await
+    .unwrap();
+
+    let result = resp.get("result").expect("hello should succeed");
+    assert_eq!(result["protocol_version"].as_str(), Some(PROTOCOL_VERSION));
+    assert!(
+        result["capabilities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c == "bot.run")
+    );
+
+    shutdown_tx.send(true).unwrap();
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server stops on shutdown")
+        .unwrap()
+        .unwrap();
+}
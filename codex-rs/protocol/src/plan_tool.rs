@@ -10,6 +10,14 @@ pub enum StepStatus {
     Pending,
     InProgress,
     Completed,
+    Failed,
+}
+
+impl StepStatus {
+    /// True once a step has finished, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, StepStatus::Completed | StepStatus::Failed)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, JsonSchema)]
@@ -76,6 +76,13 @@ pub enum SpecKitError {
     #[error("Evidence repository error: {0}")]
     EvidenceRepository(String),
 
+    #[error("Budget exhausted for {role:?}: spent ${spent:.4} of ${limit:.4}")]
+    BudgetExhausted {
+        role: crate::gate_policy::Role,
+        spent: f64,
+        limit: f64,
+    },
+
     #[error("{0}")]
     Other(String),
 }
@@ -156,6 +163,14 @@ impl RetryClassifiable for SpecKitError {
                 }
             }
 
+            // Budget exhaustion: more spend won't fix itself
+            SpecKitError::BudgetExhausted { .. } => {
+                ErrorClass::Permanent(PermanentError::InvalidInput {
+                    field: "budget".to_string(),
+                    reason: self.to_string(),
+                })
+            }
+
             // Generic errors: parse message for clues
             SpecKitError::Other(msg) => classify_generic_error(msg),
         }
@@ -164,9 +179,7 @@ impl RetryClassifiable for SpecKitError {
     fn suggested_backoff(&self) -> Option<Duration> {
         match self.classify() {
             ErrorClass::Retryable(ref err) => match err {
-                RetryableError::RateLimitExceeded { retry_after } => {
-                    Some(Duration::from_secs(*retry_after))
-                }
+                RetryableError::RateLimitExceeded { retry_after } => *retry_after,
                 RetryableError::DatabaseLocked => {
                     // SQLite lock contention: short backoff (100-500ms)
                     Some(Duration::from_millis(200))
@@ -176,8 +189,8 @@ impl RetryClassifiable for SpecKitError {
                     None
                 }
                 RetryableError::ServiceUnavailable => {
-                    // Service unavailable: moderate backoff
-                    Some(Duration::from_secs(5))
+                    // Service unavailable: use default exponential backoff
+                    None
                 }
             },
             ErrorClass::Permanent(_) => None, // Don't retry
@@ -230,8 +243,9 @@ fn classify_mcp_error(msg: &str) -> ErrorClass {
         || msg_lower.contains("429")
         || msg_lower.contains("too many requests")
     {
-        // Try to parse Retry-After from message (e.g., "Retry-After: 60")
-        let retry_after = parse_retry_after(msg).unwrap_or(60);
+        // Honor a Retry-After in the message (e.g., "Retry-After: 60");
+        // without one the retry loop falls back to jittered backoff
+        let retry_after = parse_retry_after(msg).map(Duration::from_secs);
         return ErrorClass::Retryable(RetryableError::RateLimitExceeded { retry_after });
     }
 
@@ -394,7 +408,9 @@ mod tests {
         assert!(err.is_retryable());
         assert!(matches!(
             err.classify(),
-            ErrorClass::Retryable(RetryableError::RateLimitExceeded { retry_after: 45 })
+            ErrorClass::Retryable(RetryableError::RateLimitExceeded {
+                retry_after: Some(d)
+            }) if d == Duration::from_secs(45)
         ));
         assert_eq!(err.suggested_backoff(), Some(Duration::from_secs(45)));
     }
@@ -405,9 +421,9 @@ mod tests {
         assert!(err.is_retryable());
         assert!(matches!(
             err.classify(),
-            ErrorClass::Retryable(RetryableError::RateLimitExceeded { retry_after: 60 })
+            ErrorClass::Retryable(RetryableError::RateLimitExceeded { retry_after: None })
         ));
-        assert_eq!(err.suggested_backoff(), Some(Duration::from_secs(60)));
+        assert_eq!(err.suggested_backoff(), None);
     }
 
     #[test]
@@ -8,7 +8,9 @@
 //! See `docs/spec-kit/GATE_POLICY.md` for full specification.
 
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 use std::path::PathBuf;
+use std::time::Duration;
 
 // ============================================================================
 // Core Domain Enums
@@ -473,6 +475,153 @@ impl GateVerdict {
     }
 }
 
+/// A project-specific gate rule, evaluated before the built-in [`DecisionRule`].
+///
+/// Returns `Some(verdict)` to decide the gate, or `None` to defer to the next
+/// rule. Custom rules must be deterministic functions of the signals.
+pub type CustomGateRule = Box<dyn Fn(&[Signal]) -> Option<Verdict> + Send + Sync>;
+
+impl GateContext {
+    /// Evaluate the gate for this context.
+    ///
+    /// `custom_rules` run first, in order; the first one returning a verdict
+    /// short-circuits. Otherwise the built-in `decision_rule` decides:
+    /// failed tool-truth, blocking counter-signals, advisory signals (when
+    /// disallowed) and low owner confidence each force escalation.
+    pub fn evaluate(
+        &self,
+        decision_rule: &DecisionRule,
+        signals: Vec<Signal>,
+        custom_rules: &[CustomGateRule],
+    ) -> GateVerdict {
+        let verdict = custom_rules
+            .iter()
+            .find_map(|rule| rule(&signals))
+            .unwrap_or_else(|| apply_decision_rule(decision_rule, &signals));
+
+        let counter_signals = signals
+            .iter()
+            .filter_map(|s| match s {
+                Signal::CounterSignal(cs) => Some(cs.clone()),
+                _ => None,
+            })
+            .collect();
+
+        GateVerdict {
+            context: self.clone(),
+            decision_rule: decision_rule.clone(),
+            verdict,
+            signals,
+            counter_signals,
+            timestamp: chrono::Utc::now(),
+        }
+    }
+}
+
+/// Built-in deterministic evaluation of a [`DecisionRule`] over signals.
+fn apply_decision_rule(rule: &DecisionRule, signals: &[Signal]) -> Verdict {
+    let effective_confidence = signals
+        .iter()
+        .rev()
+        .find_map(|s| match s {
+            Signal::OwnerConfidence { value, .. } => Some(*value),
+            _ => None,
+        })
+        .unwrap_or(0.0);
+    let confidence_level = ConfidenceLevel::from_value(effective_confidence);
+
+    let escalate = |target, reason: String| Verdict::Escalate {
+        target,
+        effective_confidence,
+        confidence_level,
+        reason,
+    };
+
+    if rule.escalate_on_tool_failure
+        && let Some(failed) = signals.iter().find_map(|s| match s {
+            Signal::ToolTruth(tt) if !tt.passed => Some(tt),
+            _ => None,
+        })
+    {
+        return escalate(
+            EscalationTarget::ImplementerFallback,
+            format!("Tool-truth failed: {:?}", failed.kind),
+        );
+    }
+
+    let counter_signals = signals.iter().filter_map(|s| match s {
+        Signal::CounterSignal(cs) => Some(cs),
+        _ => None,
+    });
+    let mut has_advisory = false;
+    for cs in counter_signals {
+        match cs.severity {
+            SignalSeverity::Block => {
+                return escalate(
+                    EscalationTarget::Human,
+                    format!("Block signal from {:?}: {}", cs.source_role, cs.message),
+                );
+            }
+            SignalSeverity::Advisory => has_advisory = true,
+        }
+    }
+    if has_advisory && !rule.allow_advisory_auto_apply {
+        return escalate(
+            EscalationTarget::Human,
+            "Advisory signals present and advisory auto-apply disabled".into(),
+        );
+    }
+
+    if effective_confidence < rule.min_confidence_for_auto_apply {
+        return escalate(
+            EscalationTarget::Human,
+            format!(
+                "Confidence {effective_confidence:.2} below threshold {:.2}",
+                rule.min_confidence_for_auto_apply
+            ),
+        );
+    }
+
+    Verdict::AutoApply {
+        effective_confidence,
+        confidence_level,
+        reason: "All signals pass".into(),
+    }
+}
+
+/// Collect signals from sidecars concurrently, each bounded by `timeout`.
+///
+/// Sidecars are non-authoritative, so a slow one must not block the gate: any
+/// sidecar that exceeds `timeout` (or panics) is dropped with a warning and the
+/// gate evaluates whatever signals arrived. Signals are returned in sidecar
+/// order regardless of completion order.
+pub async fn collect_sidecar_signals<F>(sidecars: Vec<(Role, F)>, timeout: Duration) -> Vec<Signal>
+where
+    F: Future<Output = Vec<Signal>> + Send + 'static,
+{
+    let mut tasks = tokio::task::JoinSet::new();
+    for (idx, (role, sidecar)) in sidecars.into_iter().enumerate() {
+        tasks.spawn(async move { (idx, role, tokio::time::timeout(timeout, sidecar).await) });
+    }
+
+    let mut collected: Vec<(usize, Vec<Signal>)> = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((idx, _, Ok(signals))) => collected.push((idx, signals)),
+            Ok((_, role, Err(_))) => {
+                tracing::warn!(?role, ?timeout, "Sidecar timed out; dropping its signals");
+            }
+            Err(e) => tracing::warn!("Sidecar task failed; dropping its signals: {e}"),
+        }
+    }
+
+    collected.sort_by_key(|(idx, _)| *idx);
+    collected
+        .into_iter()
+        .flat_map(|(_, signals)| signals)
+        .collect()
+}
+
 // ============================================================================
 // Role Assignment (Gate Policy → Orchestrator interface)
 // ============================================================================
@@ -684,4 +833,145 @@ mod tests {
         assert!(rule.allow_advisory_auto_apply);
         assert!(rule.escalate_on_tool_failure);
     }
+
+    fn test_gate_context() -> GateContext {
+        GateContext {
+            spec_id: "SPEC-TEST-001".into(),
+            stage: Stage::Implement,
+            checkpoint: Checkpoint::AfterImplement,
+            artifact_paths: vec![],
+            is_high_risk: false,
+            retry_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_builtin_rule() {
+        let ctx = test_gate_context();
+        let rule = DecisionRule::default();
+
+        let confident = vec![Signal::OwnerConfidence {
+            value: 0.9,
+            rationale: None,
+        }];
+        assert!(ctx.evaluate(&rule, confident, &[]).passed());
+
+        let tool_failure = vec![
+            Signal::OwnerConfidence {
+                value: 0.9,
+                rationale: None,
+            },
+            Signal::ToolTruth(ToolTruth::fail(ToolTruthKind::Compile, "error[E0308]")),
+        ];
+        let verdict = ctx.evaluate(&rule, tool_failure, &[]);
+        assert!(!verdict.passed());
+        assert!(matches!(
+            verdict.verdict,
+            Verdict::Escalate {
+                target: EscalationTarget::ImplementerFallback,
+                ..
+            }
+        ));
+
+        let low_confidence = vec![Signal::OwnerConfidence {
+            value: 0.3,
+            rationale: None,
+        }];
+        assert!(!ctx.evaluate(&rule, low_confidence, &[]).passed());
+    }
+
+    #[test]
+    fn test_evaluate_custom_rule_requires_two_tool_truths() {
+        let require_two_tool_truths: CustomGateRule = Box::new(|signals| {
+            let passing = signals
+                .iter()
+                .filter(|s| matches!(s, Signal::ToolTruth(tt) if tt.passed))
+                .count();
+            (passing < 2).then(|| Verdict::Escalate {
+                target: EscalationTarget::Human,
+                effective_confidence: 0.0,
+                confidence_level: ConfidenceLevel::Low,
+                reason: format!("Need 2 agreeing tool-truth signals, got {passing}"),
+            })
+        });
+        let custom_rules = vec![require_two_tool_truths];
+
+        let ctx = test_gate_context();
+        let rule = DecisionRule::default();
+        let confidence = Signal::OwnerConfidence {
+            value: 0.9,
+            rationale: None,
+        };
+
+        let one = vec![
+            confidence.clone(),
+            Signal::ToolTruth(ToolTruth::pass(ToolTruthKind::Compile)),
+        ];
+        // Built-in rule alone would pass; the custom rule blocks first
+        assert!(ctx.evaluate(&rule, one.clone(), &[]).passed());
+        assert!(!ctx.evaluate(&rule, one, &custom_rules).passed());
+
+        let two = vec![
+            confidence,
+            Signal::ToolTruth(ToolTruth::pass(ToolTruthKind::Compile)),
+            Signal::ToolTruth(ToolTruth::pass(ToolTruthKind::UnitTests)),
+        ];
+        assert!(ctx.evaluate(&rule, two, &custom_rules).passed());
+    }
+
+    #[tokio::test]
+    async fn test_collect_sidecar_signals_drops_hung_sidecar() {
+        type SidecarFuture = std::pin::Pin<Box<dyn Future<Output = Vec<Signal>> + Send>>;
+
+        let critic: SidecarFuture = Box::pin(async {
+            vec![Signal::OwnerConfidence {
+                value: 0.9,
+                rationale: Some("critic agrees".into()),
+            }]
+        });
+        let hung: SidecarFuture = Box::pin(async {
+            tokio::time::sleep(Duration::from_secs(30)).await;
+            vec![Signal::CounterSignal(CounterSignal {
+                severity: SignalSeverity::Block,
+                kind: CounterSignalKind::PerformanceRisk,
+                source_role: Role::PerformanceReviewer,
+                message: "never arrives".into(),
+                evidence_ref: None,
+            })]
+        });
+        let security: SidecarFuture = Box::pin(async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            vec![Signal::CounterSignal(CounterSignal {
+                severity: SignalSeverity::Advisory,
+                kind: CounterSignalKind::SecurityRisk,
+                source_role: Role::SecurityReviewer,
+                message: "consider input validation".into(),
+                evidence_ref: None,
+            })]
+        });
+
+        let start = std::time::Instant::now();
+        let signals = collect_sidecar_signals(
+            vec![
+                (Role::SidecarCritic, critic),
+                (Role::PerformanceReviewer, hung),
+                (Role::SecurityReviewer, security),
+            ],
+            Duration::from_millis(200),
+        )
+        .await;
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        assert_eq!(signals.len(), 2);
+        assert!(matches!(signals[0], Signal::OwnerConfidence { .. }));
+        assert!(matches!(
+            &signals[1],
+            Signal::CounterSignal(cs) if cs.source_role == Role::SecurityReviewer
+        ));
+
+        // The gate evaluates the signals that arrived; the hung Block never lands
+        let verdict = test_gate_context().evaluate(&DecisionRule::default(), signals, &[]);
+        assert!(verdict.passed());
+        assert_eq!(verdict.counter_signals.len(), 1);
+    }
 }
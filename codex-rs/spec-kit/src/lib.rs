@@ -48,18 +48,19 @@ pub use types::{SpecAgent, SpecStage};
 
 // PR1: Gate Policy canonical vocabulary
 pub use gate_policy::{
-    Checkpoint, ConfidenceLevel, CounterSignal, CounterSignalKind, DecisionRule, EscalationTarget,
-    GateContext, GateVerdict, PolicyToggles, RiskLevel, Role, RoleAssignment, Signal,
-    SignalSeverity, Stage, StageContext, ToolTruth, ToolTruthKind, Verdict,
+    Checkpoint, ConfidenceLevel, CounterSignal, CounterSignalKind, CustomGateRule, DecisionRule,
+    EscalationTarget, GateContext, GateVerdict, PolicyToggles, RiskLevel, Role, RoleAssignment,
+    Signal, SignalSeverity, Stage, StageContext, ToolTruth, ToolTruthKind, Verdict,
 };
 
 // PR1: Router interface
 pub use router::{
-    Budget, DefaultRouter, Router, RoutingContext, ToolPermissions, WorkerKind, WorkerSpec,
+    Budget, BudgetExhaustedCallback, DefaultRouter, Router, RoutingContext, ToolPermissions,
+    WorkerKind, WorkerSpec,
 };
 
 // SPEC-940: Re-export timing macros for convenience
-pub use timing::Timer;
+pub use timing::{SpanGuard, SpanReport, Timer};
 
 /// Spec-Kit version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -23,8 +23,9 @@ pub enum RetryableError {
     #[error("Network timeout after {0}s")]
     NetworkTimeout(u64),
 
-    #[error("Rate limit exceeded, retry after {retry_after}s")]
-    RateLimitExceeded { retry_after: u64 },
+    /// HTTP 429; `retry_after` carries the server's `Retry-After` when given
+    #[error("Rate limit exceeded (HTTP 429), retry after {retry_after:?}")]
+    RateLimitExceeded { retry_after: Option<Duration> },
 
     #[error("Service unavailable (HTTP 503)")]
     ServiceUnavailable,
@@ -36,6 +37,16 @@ pub enum RetryableError {
     ConnectionRefused,
 }
 
+impl RetryableError {
+    /// Server-mandated wait that overrides computed backoff (429 `Retry-After`)
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimitExceeded { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
 /// Permanent errors (do NOT retry)
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum PermanentError {
@@ -70,4 +81,60 @@ pub trait RetryClassifiable {
     fn suggested_backoff(&self) -> Option<Duration>;
 }
 
+/// Classify an HTTP status code for retry decisions
+///
+/// 429 becomes `RateLimitExceeded`, carrying `retry_after_header` when it is
+/// a delay in seconds (HTTP-date values are ignored). 503 becomes
+/// `ServiceUnavailable`, which retries with exponential backoff.
+/// Returns `None` for statuses without a retry-specific classification.
+pub fn classify_http_status(status: u16, retry_after_header: Option<&str>) -> Option<ErrorClass> {
+    match status {
+        429 => {
+            let retry_after = retry_after_header
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            Some(ErrorClass::Retryable(RetryableError::RateLimitExceeded {
+                retry_after,
+            }))
+        }
+        503 => Some(ErrorClass::Retryable(RetryableError::ServiceUnavailable)),
+        _ => None,
+    }
+}
+
 // TODO: Implement RetryClassifiable for codex_spec_kit::error::SpecKitError in Week 2-3, Day 3
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_http_429_with_retry_after() {
+        let class = classify_http_status(429, Some("5")).expect("429 classified");
+        assert_eq!(
+            class,
+            ErrorClass::Retryable(RetryableError::RateLimitExceeded {
+                retry_after: Some(Duration::from_secs(5)),
+            })
+        );
+
+        // HTTP-date form is not a delay in seconds
+        let class = classify_http_status(429, Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(
+            class,
+            Some(ErrorClass::Retryable(RetryableError::RateLimitExceeded {
+                retry_after: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_classify_http_503_and_others() {
+        assert_eq!(
+            classify_http_status(503, None),
+            Some(ErrorClass::Retryable(RetryableError::ServiceUnavailable))
+        );
+        assert_eq!(RetryableError::ServiceUnavailable.retry_after(), None);
+        assert_eq!(classify_http_status(200, None), None);
+    }
+}
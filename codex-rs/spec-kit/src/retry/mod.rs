@@ -16,9 +16,10 @@ pub mod circuit_breaker;
 pub mod classifier;
 pub mod strategy;
 
-pub use classifier::{ErrorClass, RetryClassifiable};
+pub use classifier::{ErrorClass, RetryClassifiable, classify_http_status};
 pub use strategy::{
-    RetryConfig, RetryStatus, execute_with_backoff, execute_with_backoff_cancellable,
+    Jitter, RetryConfig, RetryStatus, backoff_delay, execute_with_backoff,
+    execute_with_backoff_cancellable, retry_delay,
 };
 
 use std::time::Duration;
@@ -6,12 +6,30 @@
 //! - Cancellation support via tokio CancellationToken
 //! - Status callbacks for progress reporting
 
-use backon::{ExponentialBuilder, Retryable};
 use codex_async_utils::OrCancelExt;
 use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use std::time::{Duration, Instant};
 use tokio_util::sync::CancellationToken;
 
+/// Jitter strategy applied to the computed backoff delay
+///
+/// Randomizing delays keeps workers that failed together from retrying in
+/// lockstep against the same provider (thundering herd).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Jitter {
+    /// Use the computed backoff as-is
+    None,
+    /// Uniform in `backoff × (1 ± jitter_factor)`
+    Proportional,
+    /// Uniform in `[0, backoff]`
+    Full,
+    /// `backoff / 2` plus uniform in `[0, backoff / 2]`
+    #[default]
+    Equal,
+}
+
 /// Retry configuration
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
@@ -19,7 +37,14 @@ pub struct RetryConfig {
     pub initial_backoff_ms: u64,
     pub max_backoff_ms: u64,
     pub backoff_multiplier: f64,
+    /// Range for `Jitter::Proportional` (0.5 → ±50%)
     pub jitter_factor: f64,
+    /// How backoff delays are randomized
+    pub jitter: Jitter,
+    /// Seed for the jitter RNG (None = seeded from OS entropy)
+    ///
+    /// Set in tests to make jittered delays reproducible.
+    pub jitter_seed: Option<u64>,
     /// Total elapsed timeout (Auto Drive pattern)
     /// If set, retries stop after this duration regardless of remaining attempts.
     /// Default: None (use max_attempts only)
@@ -34,6 +59,8 @@ impl Default for RetryConfig {
             max_backoff_ms: 10_000,
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
+            jitter: Jitter::default(),
+            jitter_seed: None,
             max_elapsed_ms: None,
         }
     }
@@ -58,22 +85,22 @@ pub struct RetryStatus {
     pub is_rate_limit: bool,
 }
 
-/// Execute operation with exponential backoff retry (using backon crate)
+/// Execute operation with exponential backoff retry
 ///
 /// # SPEC-945C Requirements:
 /// - Exponential backoff: 100ms → 200ms → 400ms → 800ms → 1600ms
-/// - Jitter: ±25-50% randomness
+/// - Jitter: randomized per `config.jitter`
 /// - Error classification: Only retry retryable errors
 /// - Max 3-5 attempts
 ///
 /// # Implementation Notes:
-/// - Uses backon crate for exponential backoff
-/// - Adds jitter to prevent thundering herd
+/// - Every sleep comes from `retry_delay`, so jitter and `Retry-After` apply
 /// - Only retries errors classified as `Retryable` via `RetryClassifiable` trait
+/// - `max_attempts` counts retries: 3 means 1 initial call + 3 retries
 /// - Returns `MaxAttemptsExceeded` if retries exhausted
 /// - Returns `PermanentError` for non-retryable errors
 pub async fn execute_with_backoff<F, Fut, T, E>(
-    operation: F,
+    mut operation: F,
     config: &RetryConfig,
 ) -> super::Result<T>
 where
@@ -81,42 +108,25 @@ where
     Fut: std::future::Future<Output = Result<T, E>> + Send,
     E: std::error::Error + super::classifier::RetryClassifiable + Send + Sync + 'static,
 {
-    // Build exponential backoff strategy
-    let max_attempts = config.max_attempts;
-    let _jitter_factor = config.jitter_factor; // Reserved for future use
-
-    let backoff = ExponentialBuilder::default()
-        .with_min_delay(Duration::from_millis(config.initial_backoff_ms))
-        .with_max_delay(Duration::from_millis(config.max_backoff_ms))
-        .with_factor(config.backoff_multiplier as f32)
-        .with_max_times(max_attempts);
-
-    // Execute with retry using backon
-    let result = operation
-        .retry(backoff)
-        .when(|err: &E| {
-            // Check if error is retryable
-            if !err.is_retryable() {
-                return false; // Don't retry permanent errors
-            }
+    let mut attempts = 0;
+    let mut rng = jitter_rng(config);
 
-            // Apply additional jitter to suggested backoff if provided
-            // Note: This runs synchronously in the retry condition check
-            // For now, we rely on backon's built-in backoff
-            true
-        })
-        .await;
+    loop {
+        attempts += 1;
 
-    // Map backon result to our Result type
-    match result {
-        Ok(value) => Ok(value),
-        Err(err) => {
-            // Check if it's a permanent error or max attempts exceeded
-            if !err.is_retryable() {
-                Err(super::RetryError::PermanentError(err.to_string()))
-            } else {
-                // Max attempts exhausted (retryable but no more retries)
-                Err(super::RetryError::MaxAttemptsExceeded(max_attempts))
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                if !err.is_retryable() {
+                    return Err(super::RetryError::PermanentError(err.to_string()));
+                }
+
+                if attempts > config.max_attempts {
+                    return Err(super::RetryError::MaxAttemptsExceeded(config.max_attempts));
+                }
+
+                let (delay, _) = retry_delay(&err, config, attempts as u32, &mut rng);
+                tokio::time::sleep(delay).await;
             }
         }
     }
@@ -143,7 +153,7 @@ where
     E: std::error::Error + super::classifier::RetryClassifiable + Send + Sync + 'static,
 {
     let mut attempts = 0;
-    let mut backoff_ms = config.initial_backoff_ms;
+    let mut rng = jitter_rng(config);
 
     loop {
         attempts += 1;
@@ -162,13 +172,9 @@ where
                     return Err(super::RetryError::MaxAttemptsExceeded(config.max_attempts));
                 }
 
-                // Apply backoff with jitter before next retry
-                let backoff_duration = Duration::from_millis(backoff_ms.min(config.max_backoff_ms));
-                let jittered = apply_jitter(backoff_duration, config.jitter_factor);
-                std::thread::sleep(jittered);
-
-                // Increase backoff for next iteration (exponential)
-                backoff_ms = (backoff_ms as f64 * config.backoff_multiplier) as u64;
+                // Apply backoff with jitter before next retry (429 Retry-After wins)
+                let (delay, _) = retry_delay(&err, config, attempts as u32, &mut rng);
+                std::thread::sleep(delay);
             }
         }
     }
@@ -213,7 +219,7 @@ where
 {
     let start_time = Instant::now();
     let mut attempt: u32 = 0;
-    let mut backoff_ms = config.initial_backoff_ms;
+    let mut rng = jitter_rng(config);
     let max_elapsed = config.max_elapsed_ms.map(Duration::from_millis);
 
     loop {
@@ -250,17 +256,8 @@ where
                     return Err(super::RetryError::MaxAttemptsExceeded(config.max_attempts));
                 }
 
-                // Check for rate limit with suggested backoff
-                let (sleep_duration, is_rate_limit) =
-                    if let Some(suggested) = err.suggested_backoff() {
-                        // Use server-suggested backoff for rate limits
-                        (suggested, true)
-                    } else {
-                        // Use exponential backoff with jitter
-                        let backoff_duration =
-                            Duration::from_millis(backoff_ms.min(config.max_backoff_ms));
-                        (apply_jitter(backoff_duration, config.jitter_factor), false)
-                    };
+                // Honor Retry-After for rate limits, otherwise back off
+                let (sleep_duration, is_rate_limit) = retry_delay(&err, config, attempt, &mut rng);
 
                 let resume_at = Instant::now() + sleep_duration;
                 let reason = if is_rate_limit {
@@ -287,25 +284,83 @@ where
                 {
                     return Err(super::RetryError::Aborted);
                 }
-
-                // Increase backoff for next iteration (exponential)
-                backoff_ms = (backoff_ms as f64 * config.backoff_multiplier) as u64;
             }
         }
     }
 }
 
+/// Compute the jittered backoff delay before retry number `attempt` (1-indexed)
+///
+/// The base delay grows as `initial_backoff_ms × multiplier^(attempt-1)`,
+/// capped at `max_backoff_ms`, then `config.jitter` randomizes it.
+pub fn backoff_delay(config: &RetryConfig, attempt: u32, rng: &mut impl Rng) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+    let base_ms = (config.initial_backoff_ms as f64 * config.backoff_multiplier.powi(exponent))
+        .min(config.max_backoff_ms as f64);
+    apply_jitter(
+        Duration::from_millis(base_ms as u64),
+        config.jitter,
+        config.jitter_factor,
+        rng,
+    )
+}
+
+/// Decide how long to wait before retry number `attempt` (1-indexed) after `err`
+///
+/// Rate limits (429) wait for the server's `Retry-After` when present.
+/// Every other wait is the jittered [`backoff_delay`]; fixed
+/// `suggested_backoff()` values are not used, since unjittered waits would
+/// put failing workers back in lockstep.
+///
+/// Returns the delay and whether it is a rate-limit wait.
+pub fn retry_delay<E: super::classifier::RetryClassifiable>(
+    err: &E,
+    config: &RetryConfig,
+    attempt: u32,
+    rng: &mut impl Rng,
+) -> (Duration, bool) {
+    use super::classifier::{ErrorClass, RetryableError};
+
+    match err.classify() {
+        ErrorClass::Retryable(rate_limit @ RetryableError::RateLimitExceeded { .. }) => {
+            let delay = rate_limit
+                .retry_after()
+                .unwrap_or_else(|| backoff_delay(config, attempt, rng));
+            (delay, true)
+        }
+        _ => (backoff_delay(config, attempt, rng), false),
+    }
+}
+
+/// Build the jitter RNG, honoring `jitter_seed` when set
+fn jitter_rng(config: &RetryConfig) -> StdRng {
+    match config.jitter_seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    }
+}
+
 /// Apply jitter to a duration
 ///
-/// Adds random variation (±jitter_factor) to prevent thundering herd.
-/// Example: 100ms with 0.5 jitter → 50ms to 150ms
-fn apply_jitter(duration: Duration, jitter_factor: f64) -> Duration {
-    use rand::rng;
-    let mut rng_instance = rng();
-    let jitter = rng_instance.random_range(-jitter_factor..=jitter_factor);
-    let multiplier = 1.0 + jitter;
-    let jittered_ms = (duration.as_millis() as f64 * multiplier).max(0.0) as u64;
-    Duration::from_millis(jittered_ms)
+/// Randomizes the delay within the window selected by `jitter`.
+/// Example: 100ms with `Equal` → 50ms to 100ms
+fn apply_jitter(
+    duration: Duration,
+    jitter: Jitter,
+    jitter_factor: f64,
+    rng: &mut impl Rng,
+) -> Duration {
+    let ms = duration.as_millis() as f64;
+    let jittered_ms = match jitter {
+        Jitter::None => ms,
+        Jitter::Proportional => {
+            let jitter = rng.random_range(-jitter_factor..=jitter_factor);
+            ms * (1.0 + jitter)
+        }
+        Jitter::Full => rng.random_range(0.0..=ms),
+        Jitter::Equal => ms / 2.0 + rng.random_range(0.0..=ms / 2.0),
+    };
+    Duration::from_millis(jittered_ms.max(0.0) as u64)
 }
 
 #[cfg(test)]
@@ -343,6 +398,93 @@ mod tests {
         }
     }
 
+    /// Error carrying an HTTP status and optional Retry-After header
+    #[derive(Debug, thiserror::Error)]
+    #[error("HTTP {status}")]
+    struct HttpError {
+        status: u16,
+        retry_after: Option<&'static str>,
+    }
+
+    impl RetryClassifiable for HttpError {
+        fn classify(&self) -> ErrorClass {
+            crate::retry::classify_http_status(self.status, self.retry_after).unwrap_or(
+                ErrorClass::Permanent(PermanentError::InvalidInput {
+                    field: "status".to_string(),
+                    reason: self.status.to_string(),
+                }),
+            )
+        }
+
+        fn suggested_backoff(&self) -> Option<Duration> {
+            // Implementors may suggest a fixed wait; the jittered backoff wins
+            Some(Duration::from_secs(30))
+        }
+    }
+
+    #[test]
+    fn test_retry_delay_honors_retry_after_on_429() {
+        let config = RetryConfig {
+            jitter: Jitter::None,
+            ..Default::default()
+        };
+        let err = HttpError {
+            status: 429,
+            retry_after: Some("5"),
+        };
+
+        let (delay, is_rate_limit) = retry_delay(&err, &config, 1, &mut rand::rng());
+        assert_eq!(delay, Duration::from_secs(5));
+        assert!(is_rate_limit);
+    }
+
+    #[test]
+    fn test_retry_delay_uses_backoff_on_503() {
+        let config = RetryConfig {
+            initial_backoff_ms: 100,
+            backoff_multiplier: 2.0,
+            jitter: Jitter::None,
+            ..Default::default()
+        };
+        let err = HttpError {
+            status: 503,
+            retry_after: None,
+        };
+
+        let mut rng = rand::rng();
+        let (first, is_rate_limit) = retry_delay(&err, &config, 1, &mut rng);
+        let (second, _) = retry_delay(&err, &config, 2, &mut rng);
+        assert_eq!(first, Duration::from_millis(100));
+        assert_eq!(second, Duration::from_millis(200));
+        assert!(!is_rate_limit);
+
+        // A 429 without Retry-After also falls back to computed backoff
+        let err = HttpError {
+            status: 429,
+            retry_after: None,
+        };
+        let (delay, is_rate_limit) = retry_delay(&err, &config, 3, &mut rng);
+        assert_eq!(delay, Duration::from_millis(400));
+        assert!(is_rate_limit);
+    }
+
+    #[test]
+    fn test_retry_delay_ignores_fixed_suggested_backoff() {
+        let config = RetryConfig {
+            initial_backoff_ms: 1_000,
+            jitter: Jitter::Full,
+            jitter_seed: Some(7),
+            ..Default::default()
+        };
+        // TestError suggests a fixed 50ms; the wait must still be jittered
+        let err = TestError::Transient("timeout".to_string());
+
+        let (delay, is_rate_limit) = retry_delay(&err, &config, 1, &mut jitter_rng(&config));
+        let expected = backoff_delay(&config, 1, &mut jitter_rng(&config));
+        assert_eq!(delay, expected);
+        assert!(!is_rate_limit);
+    }
+
     #[tokio::test]
     async fn test_immediate_success() {
         // Operation succeeds on first try - no retries
@@ -411,7 +553,7 @@ mod tests {
     #[tokio::test]
     async fn test_max_attempts() {
         // Transient error exhausts max attempts
-        // Note: backon's with_max_times(N) means "N retries" (so N+1 total calls)
+        // max_attempts counts retries (so N+1 total calls)
         use std::sync::Arc;
         use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -439,7 +581,7 @@ mod tests {
             result.unwrap_err(),
             super::super::RetryError::MaxAttemptsExceeded(3)
         );
-        // backon with_max_times(3) = 1 initial + 3 retries = 4 total calls
+        // max_attempts = 3 → 1 initial + 3 retries = 4 total calls
         assert_eq!(
             call_count.load(Ordering::SeqCst),
             4,
@@ -493,7 +635,12 @@ mod tests {
 
         // Run multiple times to verify randomness
         for _ in 0..100 {
-            let jittered = apply_jitter(duration, jitter_factor);
+            let jittered = apply_jitter(
+                duration,
+                Jitter::Proportional,
+                jitter_factor,
+                &mut rand::rng(),
+            );
             let jittered_ms = jittered.as_millis() as f64;
 
             // Should be between 500ms and 1500ms (±50%)
@@ -516,6 +663,46 @@ mod tests {
         );
         assert_eq!(config.backoff_multiplier, 2.0, "Multiplier should be 2.0");
         assert_eq!(config.jitter_factor, 0.5, "Jitter should be 0.5 (±50%)");
+        assert_eq!(config.jitter, Jitter::Equal, "Equal jitter by default");
+    }
+
+    #[test]
+    fn test_seeded_jitter_within_bounds() {
+        for (jitter, lower_fraction) in [(Jitter::Equal, 0.5), (Jitter::Full, 0.0)] {
+            let config = RetryConfig {
+                max_attempts: 6,
+                initial_backoff_ms: 100,
+                max_backoff_ms: 1_000,
+                backoff_multiplier: 2.0,
+                jitter,
+                jitter_seed: Some(42),
+                ..Default::default()
+            };
+
+            let mut rng = jitter_rng(&config);
+            let delays: Vec<Duration> = (1..=6)
+                .map(|attempt| backoff_delay(&config, attempt, &mut rng))
+                .collect();
+
+            // 100, 200, 400, 800, then capped at 1000
+            let bases = [100.0, 200.0, 400.0, 800.0, 1_000.0, 1_000.0];
+            for (attempt, (delay, base)) in delays.iter().zip(bases).enumerate() {
+                let ms = delay.as_millis() as f64;
+                assert!(
+                    (base * lower_fraction - 1.0..=base).contains(&ms),
+                    "{jitter:?} attempt {}: {ms}ms outside [{}, {base}]",
+                    attempt + 1,
+                    base * lower_fraction
+                );
+            }
+
+            // Same seed → same delays
+            let mut rng = jitter_rng(&config);
+            let replay: Vec<Duration> = (1..=6)
+                .map(|attempt| backoff_delay(&config, attempt, &mut rng))
+                .collect();
+            assert_eq!(delays, replay);
+        }
     }
 
     #[tokio::test]
@@ -536,7 +723,9 @@ mod tests {
             initial_backoff_ms: 50, // Use smaller delays for faster tests
             max_backoff_ms: 1000,
             backoff_multiplier: 2.0,
-            jitter_factor: 0.0, // Disable jitter for predictable timing
+            jitter_factor: 0.0,
+            jitter: Jitter::None, // Disable jitter for predictable timing
+            jitter_seed: None,
             max_elapsed_ms: None,
         };
 
@@ -578,7 +767,7 @@ mod tests {
         assert!(times[0].as_millis() < 10, "First call should be immediate");
 
         // Subsequent calls should follow exponential backoff pattern
-        // Note: backon adds delays between attempts, so we check intervals
+        // Delays fall between attempts, so we check intervals
         if times.len() >= 2 {
             let interval1 = (times[1] - times[0]).as_millis();
             // First retry after ~50ms backoff
@@ -717,7 +906,9 @@ mod tests {
             initial_backoff_ms: 20, // Shorter for faster tests
             max_backoff_ms: 1000,
             backoff_multiplier: 2.0,
-            jitter_factor: 0.0, // Disable jitter for predictable timing
+            jitter_factor: 0.0,
+            jitter: Jitter::None, // Disable jitter for predictable timing
+            jitter_seed: None,
             max_elapsed_ms: None,
         };
 
@@ -858,6 +1049,8 @@ mod tests {
             max_backoff_ms: 100,
             backoff_multiplier: 1.5,
             jitter_factor: 0.0,
+            jitter: Jitter::None,
+            jitter_seed: None,
             max_elapsed_ms: Some(100), // 100ms timeout
         };
 
@@ -901,6 +1094,8 @@ mod tests {
             max_backoff_ms: 100,
             backoff_multiplier: 2.0,
             jitter_factor: 0.0,
+            jitter: Jitter::None,
+            jitter_seed: None,
             max_elapsed_ms: None,
         };
 
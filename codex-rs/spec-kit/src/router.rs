@@ -8,8 +8,11 @@
 //!
 //! See `docs/spec-kit/GATE_POLICY.md` and `docs/MODEL-POLICY.md` for policy details.
 
+use crate::error::{Result, SpecKitError};
 use crate::gate_policy::{Role, Stage, StageContext};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 // ============================================================================
 // Worker Specification
@@ -227,10 +230,33 @@ pub trait Router: Send + Sync {
 ///
 /// The hardcoded provider/model values here are examples only and may
 /// diverge from the actual MODEL-POLICY.md routing tables.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Default)]
 pub struct DefaultRouter {
     /// If true, prefer local models when available
     pub prefer_local: bool,
+    /// Per-role budgets replacing the built-in defaults
+    budget_overrides: HashMap<Role, Budget>,
+    /// Accumulated spend (USD) per role, shared across clones
+    spent_usd: Arc<Mutex<HashMap<Role, f64>>>,
+    /// Invoked when a role's spend exceeds its `max_cost_usd`
+    on_budget_exhausted: Option<BudgetExhaustedCallback>,
+}
+
+/// Callback invoked with the role and its budget when the budget runs out.
+pub type BudgetExhaustedCallback = Arc<dyn Fn(Role, Budget) + Send + Sync>;
+
+impl std::fmt::Debug for DefaultRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefaultRouter")
+            .field("prefer_local", &self.prefer_local)
+            .field("budget_overrides", &self.budget_overrides)
+            .field("spent_usd", &self.spent_usd)
+            .field(
+                "on_budget_exhausted",
+                &self.on_budget_exhausted.as_ref().map(|_| "<callback>"),
+            )
+            .finish()
+    }
 }
 
 impl DefaultRouter {
@@ -242,6 +268,73 @@ impl DefaultRouter {
         self.prefer_local = prefer_local;
         self
     }
+
+    /// Builder: override the budget for a role
+    pub fn with_role_budget(mut self, role: Role, budget: Budget) -> Self {
+        self.budget_overrides.insert(role, budget);
+        self
+    }
+
+    /// Builder: register a callback fired when a role exhausts its budget
+    pub fn on_budget_exhausted(
+        mut self,
+        callback: impl Fn(Role, Budget) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_budget_exhausted = Some(Arc::new(callback));
+        self
+    }
+
+    /// Total spend (USD) recorded for a role so far
+    pub fn spent_usd(&self, role: Role) -> f64 {
+        self.spent_usd
+            .lock()
+            .map(|spent| spent.get(&role).copied().unwrap_or(0.0))
+            .unwrap_or(0.0)
+    }
+
+    /// Record spend for a role and enforce its `max_cost_usd`.
+    ///
+    /// Returns `SpecKitError::BudgetExhausted` (after invoking the registered
+    /// callback) once cumulative spend exceeds the limit. A limit of 0.0 means
+    /// unlimited.
+    pub fn record_spend(&self, role: Role, ctx: &RoutingContext, cost_usd: f64) -> Result<()> {
+        let spent = {
+            let mut spent = self
+                .spent_usd
+                .lock()
+                .map_err(|_| SpecKitError::Other("router spend lock poisoned".into()))?;
+            let total = spent.entry(role).or_insert(0.0);
+            *total += cost_usd;
+            *total
+        };
+        self.check_budget(role, ctx, spent)
+    }
+
+    /// Select a worker, failing if the role has already exhausted its budget.
+    pub fn try_select_worker(&self, role: Role, ctx: &RoutingContext) -> Result<WorkerSpec> {
+        let spent = self.spent_usd(role);
+        let spec = self.select_worker(role, ctx);
+        if spec.budget.max_cost_usd > 0.0 && spent >= spec.budget.max_cost_usd {
+            return Err(self.budget_exhausted(role, spec.budget, spent));
+        }
+        Ok(spec)
+    }
+
+    fn check_budget(&self, role: Role, ctx: &RoutingContext, spent: f64) -> Result<()> {
+        let budget = self.select_worker(role, ctx).budget;
+        if budget.max_cost_usd > 0.0 && spent > budget.max_cost_usd {
+            return Err(self.budget_exhausted(role, budget, spent));
+        }
+        Ok(())
+    }
+
+    fn budget_exhausted(&self, role: Role, budget: Budget, spent: f64) -> SpecKitError {
+        let limit = budget.max_cost_usd;
+        if let Some(callback) = &self.on_budget_exhausted {
+            callback(role, budget);
+        }
+        SpecKitError::BudgetExhausted { role, spent, limit }
+    }
 }
 
 impl Router for DefaultRouter {
@@ -297,26 +390,30 @@ impl Router for DefaultRouter {
             },
         };
 
-        // Set budget based on role complexity
-        spec.budget = match role {
-            Role::Architect | Role::Judge => Budget {
-                max_input_tokens: 100_000,
-                max_output_tokens: 16_000,
-                max_cost_usd: 1.0,
-                max_time_seconds: 300,
-            },
-            Role::Implementer => Budget {
-                max_input_tokens: 150_000,
-                max_output_tokens: 32_000,
-                max_cost_usd: 2.0,
-                max_time_seconds: 600,
-            },
-            _ => Budget {
-                max_input_tokens: 50_000,
-                max_output_tokens: 8_000,
-                max_cost_usd: 0.5,
-                max_time_seconds: 180,
-            },
+        // Set budget based on role complexity (explicit overrides win)
+        spec.budget = if let Some(budget) = self.budget_overrides.get(&role) {
+            budget.clone()
+        } else {
+            match role {
+                Role::Architect | Role::Judge => Budget {
+                    max_input_tokens: 100_000,
+                    max_output_tokens: 16_000,
+                    max_cost_usd: 1.0,
+                    max_time_seconds: 300,
+                },
+                Role::Implementer => Budget {
+                    max_input_tokens: 150_000,
+                    max_output_tokens: 32_000,
+                    max_cost_usd: 2.0,
+                    max_time_seconds: 600,
+                },
+                _ => Budget {
+                    max_input_tokens: 50_000,
+                    max_output_tokens: 8_000,
+                    max_cost_usd: 0.5,
+                    max_time_seconds: 180,
+                },
+            }
         };
 
         // Label for UI
@@ -455,4 +552,55 @@ mod tests {
         assert_eq!(workers.len(), 1);
         assert_eq!(workers[0].role, Role::Architect);
     }
+
+    #[test]
+    fn test_budget_exhaustion_fires_callback_and_typed_error() {
+        let fired: Arc<Mutex<Vec<(Role, f64)>>> = Arc::new(Mutex::new(Vec::new()));
+        let fired_clone = fired.clone();
+        let router = DefaultRouter::new()
+            .with_role_budget(
+                Role::Implementer,
+                Budget {
+                    max_cost_usd: 0.01,
+                    ..Default::default()
+                },
+            )
+            .on_budget_exhausted(move |role, budget| {
+                fired_clone
+                    .lock()
+                    .unwrap()
+                    .push((role, budget.max_cost_usd));
+            });
+        let ctx = RoutingContext::default();
+
+        // Within budget
+        assert!(router.try_select_worker(Role::Implementer, &ctx).is_ok());
+        assert!(router.record_spend(Role::Implementer, &ctx, 0.005).is_ok());
+        assert!(fired.lock().unwrap().is_empty());
+
+        // Past budget
+        let err = router
+            .record_spend(Role::Implementer, &ctx, 0.02)
+            .expect_err("spend past budget");
+        match err {
+            SpecKitError::BudgetExhausted { role, spent, limit } => {
+                assert_eq!(role, Role::Implementer);
+                assert!((spent - 0.025).abs() < 1e-9);
+                assert!((limit - 0.01).abs() < 1e-9);
+            }
+            other => panic!("expected BudgetExhausted, got {other:?}"),
+        }
+        assert_eq!(
+            fired.lock().unwrap().as_slice(),
+            &[(Role::Implementer, 0.01)]
+        );
+
+        // Further routing for the exhausted role fails; other roles are unaffected
+        assert!(matches!(
+            router.try_select_worker(Role::Implementer, &ctx),
+            Err(SpecKitError::BudgetExhausted { .. })
+        ));
+        assert!(router.try_select_worker(Role::Architect, &ctx).is_ok());
+        assert_eq!(fired.lock().unwrap().len(), 2);
+    }
 }
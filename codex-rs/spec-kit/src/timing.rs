@@ -48,8 +48,24 @@
 //!     }).await
 //! }
 //! ```
+//!
+//! ## Hierarchical spans
+//!
+//! ```rust,ignore
+//! use codex_spec_kit::timing::Timer;
+//!
+//! let timer = Timer::new("stage0");
+//! {
+//!     let _iqo = timer.span("iqo_gen");
+//!     let _llm = timer.span("llm_call"); // nests under iqo_gen
+//! }
+//! let _retrieval = timer.span("retrieval");
+//! println!("{}", timer.report());
+//! ```
 
-use std::time::Instant;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// Measure execution time of a synchronous block
 ///
@@ -136,10 +152,14 @@ macro_rules! measure_time_async {
 ///     Ok(())
 /// }
 /// ```
+///
+/// `Timer::span` opens named child spans for a breakdown of where the time
+/// went; see [`Timer::report`].
 #[derive(Debug)]
 pub struct Timer {
     operation: String,
     start: Instant,
+    spans: Mutex<SpanTree>,
 }
 
 impl Timer {
@@ -148,9 +168,40 @@ impl Timer {
         Self {
             operation: operation.into(),
             start: Instant::now(),
+            spans: Mutex::new(SpanTree::default()),
+        }
+    }
+
+    /// Open a span nested under the innermost span still open
+    ///
+    /// The span is closed when the returned guard is dropped. Spans with the
+    /// same name under the same parent are aggregated (times summed, calls
+    /// counted).
+    pub fn span(&self, name: impl Into<String>) -> SpanGuard<'_> {
+        let node = self.lock_spans().open(name.into());
+        SpanGuard {
+            timer: self,
+            node,
+            start: Instant::now(),
         }
     }
 
+    /// Aggregated span tree rooted at this timer's operation
+    ///
+    /// The root's total is the time elapsed so far; spans still open only
+    /// contribute time from earlier, closed calls.
+    pub fn report(&self) -> SpanReport {
+        let spans = self.lock_spans();
+        spans.build(ROOT, &self.operation, self.start.elapsed(), 1)
+    }
+
+    fn lock_spans(&self) -> std::sync::MutexGuard<'_, SpanTree> {
+        // Span bookkeeping cannot leave the tree inconsistent, so recover from poisoning.
+        self.spans
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
     /// Get elapsed time without stopping the timer
     pub fn elapsed(&self) -> std::time::Duration {
         self.start.elapsed()
@@ -173,6 +224,158 @@ impl Drop for Timer {
     }
 }
 
+/// Index of the timer's own (root) node in [`SpanTree::nodes`]
+const ROOT: usize = 0;
+
+/// Arena of aggregated spans plus the stack of currently open ones
+#[derive(Debug)]
+struct SpanTree {
+    nodes: Vec<SpanNode>,
+    open: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct SpanNode {
+    name: String,
+    total: Duration,
+    count: u32,
+    children: Vec<usize>,
+}
+
+impl Default for SpanTree {
+    fn default() -> Self {
+        Self {
+            nodes: vec![SpanNode {
+                name: String::new(),
+                total: Duration::ZERO,
+                count: 0,
+                children: Vec::new(),
+            }],
+            open: vec![ROOT],
+        }
+    }
+}
+
+impl SpanTree {
+    /// Find or create `name` under the innermost open span and open it
+    fn open(&mut self, name: String) -> usize {
+        let parent = self.open.last().copied().unwrap_or(ROOT);
+        let existing = self.nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|&child| self.nodes[child].name == name);
+        let node = existing.unwrap_or_else(|| {
+            self.nodes.push(SpanNode {
+                name,
+                total: Duration::ZERO,
+                count: 0,
+                children: Vec::new(),
+            });
+            let node = self.nodes.len() - 1;
+            self.nodes[parent].children.push(node);
+            node
+        });
+        self.open.push(node);
+        node
+    }
+
+    /// Record one call of `node` and pop it (and anything opened after it)
+    fn close(&mut self, node: usize, elapsed: Duration) {
+        let entry = &mut self.nodes[node];
+        entry.total += elapsed;
+        entry.count += 1;
+        if let Some(pos) = self.open.iter().rposition(|&open| open == node) {
+            self.open.truncate(pos.max(1));
+        }
+    }
+
+    fn build(&self, node: usize, name: &str, total: Duration, count: u32) -> SpanReport {
+        let children: Vec<SpanReport> = self.nodes[node]
+            .children
+            .iter()
+            .map(|&child| {
+                let child_node = &self.nodes[child];
+                self.build(child, &child_node.name, child_node.total, child_node.count)
+            })
+            .collect();
+        let child_total: Duration = children.iter().map(|c| c.total).sum();
+        SpanReport {
+            name: name.to_string(),
+            total,
+            self_time: total.saturating_sub(child_total),
+            count,
+            children,
+        }
+    }
+}
+
+/// Guard for an open span; records its duration when dropped
+#[derive(Debug)]
+pub struct SpanGuard<'a> {
+    timer: &'a Timer,
+    node: usize,
+    start: Instant,
+}
+
+impl SpanGuard<'_> {
+    /// Get elapsed time of this call without closing the span
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Drop for SpanGuard<'_> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        self.timer.lock_spans().close(self.node, elapsed);
+    }
+}
+
+/// Aggregated timing for one span and its children
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanReport {
+    /// Span name (the timer's operation for the root)
+    pub name: String,
+    /// Cumulative time across all calls, children included
+    pub total: Duration,
+    /// `total` minus the children's totals
+    pub self_time: Duration,
+    /// Number of closed calls aggregated into this span
+    pub count: u32,
+    /// Child spans in first-opened order
+    pub children: Vec<SpanReport>,
+}
+
+impl SpanReport {
+    /// Find a direct child by name
+    pub fn child(&self, name: &str) -> Option<&SpanReport> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        writeln!(
+            f,
+            "{}{}: total {:.2}ms, self {:.2}ms, calls {}",
+            "  ".repeat(depth),
+            self.name,
+            self.total.as_secs_f64() * 1000.0,
+            self.self_time.as_secs_f64() * 1000.0,
+            self.count
+        )?;
+        for child in &self.children {
+            child.write_tree(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SpanReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_tree(f, 0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,6 +482,70 @@ mod tests {
         assert!(elapsed.as_millis() >= 10);
     }
 
+    #[test]
+    fn test_nested_spans_aggregate_into_tree() {
+        let timer = Timer::new("stage0");
+        {
+            let _iqo = timer.span("iqo_gen");
+            std::thread::sleep(Duration::from_millis(5));
+            for _ in 0..2 {
+                let _llm = timer.span("llm_call");
+                std::thread::sleep(Duration::from_millis(5));
+            }
+        }
+        {
+            let _retrieval = timer.span("retrieval");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        {
+            let _tier2 = timer.span("tier2");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        let report = timer.report();
+
+        assert_eq!(report.name, "stage0");
+        let names: Vec<&str> = report.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["iqo_gen", "retrieval", "tier2"]);
+
+        let iqo = report.child("iqo_gen").unwrap();
+        let llm = iqo.child("llm_call").unwrap();
+        assert_eq!(llm.count, 2, "repeated spans aggregate");
+        assert!(llm.total >= Duration::from_millis(10));
+        assert!(iqo.total >= llm.total);
+        assert_eq!(iqo.self_time, iqo.total - llm.total);
+
+        let child_sum: Duration = report.children.iter().map(|c| c.total).sum();
+        assert!(report.total >= child_sum);
+        assert_eq!(report.self_time, report.total - child_sum);
+
+        let rendered = report.to_string();
+        assert!(rendered.starts_with("stage0: total "));
+        assert!(rendered.contains("\n  iqo_gen: total "));
+        assert!(rendered.contains("\n    llm_call: total "));
+        assert!(rendered.contains("calls 2"));
+    }
+
+    #[test]
+    fn test_span_dropped_out_of_order_closes_inner_spans() {
+        let timer = Timer::new("out_of_order");
+        let outer = timer.span("outer");
+        let inner = timer.span("inner");
+        drop(outer);
+        drop(inner);
+
+        // With outer closed, new spans attach to the root again
+        drop(timer.span("after"));
+
+        let report = timer.report();
+        let names: Vec<&str> = report.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["outer", "after"]);
+        assert_eq!(
+            report.child("outer").unwrap().child("inner").unwrap().count,
+            1
+        );
+    }
+
     #[test]
     fn test_timer_elapsed_multiple_calls() {
         // Ensure elapsed() can be called multiple times
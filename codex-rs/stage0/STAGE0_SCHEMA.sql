@@ -62,6 +62,21 @@ CREATE INDEX IF NOT EXISTS idx_constitution_version
 INSERT OR IGNORE INTO constitution_meta (id, version, updated_at)
 VALUES (1, 0, datetime('now'));
 
+-- Per-version snapshot of constitution memories, used to explain cache
+-- invalidation by diffing two versions (added/removed/modified ids)
+CREATE TABLE IF NOT EXISTS constitution_history (
+    version      INTEGER NOT NULL,
+    memory_id    TEXT NOT NULL,
+    content_hash TEXT NOT NULL,    -- SHA-256 of content_raw at this version
+    PRIMARY KEY (version, memory_id)
+);
+
+-- Last constitution version seen by a Stage 0 run (single row)
+CREATE TABLE IF NOT EXISTS constitution_observed (
+    id      INTEGER PRIMARY KEY CHECK (id = 1),
+    version INTEGER NOT NULL
+);
+
 -- ─────────────────────────────────────────────────────────────────────────────
 -- SPEC-KIT-103 P98: Librarian Audit Trail Tables
 -- ─────────────────────────────────────────────────────────────────────────────
@@ -86,6 +86,10 @@ pub struct Stage0Config {
     #[serde(default)]
     pub vector_index: VectorIndexConfig,
 
+    /// Hybrid fusion settings (keyword + vector rank fusion)
+    #[serde(default)]
+    pub hybrid: HybridFusionConfig,
+
     // ─────────────────────────────────────────────────────────────────────────────
     // P91/SPEC-KIT-105: Constitution gate settings
     // ─────────────────────────────────────────────────────────────────────────────
@@ -297,6 +301,14 @@ pub struct ContextCompilerConfig {
     #[serde(default = "default_iqo_llm_enabled")]
     pub iqo_llm_enabled: bool,
 
+    /// Minimum combined score for a candidate to enter MMR selection (0 = disabled)
+    #[serde(default)]
+    pub min_combined_score: f32,
+
+    /// Maximum number of selected memories from any single domain (None = unlimited)
+    #[serde(default)]
+    pub per_domain_cap: Option<usize>,
+
     // ─────────────────────────────────────────────────────────────────────────────
     // V2.5: Hybrid retrieval configuration
     // ─────────────────────────────────────────────────────────────────────────────
@@ -304,8 +316,10 @@ pub struct ContextCompilerConfig {
     #[serde(default = "default_hybrid_enabled")]
     pub hybrid_enabled: bool,
 
-    /// Weight for vector/TF-IDF score in combined ranking
-    /// Combined formula: sim_weight*sim + dyn_weight*dyn + vec_weight*vec
+    /// Vector/TF-IDF share of the combined ranking, in [0, 1]
+    /// Keyword relevance gets the remaining `1 - vector_weight`, split
+    /// between similarity and dynamic score by their weights. Applies to both
+    /// `hybrid.fusion` modes.
     #[serde(default = "default_vector_weight")]
     pub vector_weight: f32,
 
@@ -386,6 +400,8 @@ impl Default for ContextCompilerConfig {
             pre_filter_limit: default_pre_filter_limit(),
             diversity_lambda: default_diversity_lambda(),
             iqo_llm_enabled: default_iqo_llm_enabled(),
+            min_combined_score: 0.0,
+            per_domain_cap: None,
             hybrid_enabled: default_hybrid_enabled(),
             vector_weight: default_vector_weight(),
             vector_top_k: default_vector_top_k(),
@@ -396,6 +412,57 @@ impl Default for ContextCompilerConfig {
     }
 }
 
+/// How DCC fuses keyword (local-memory) and vector rankings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HybridFusionMode {
+    /// Weighted sum of similarity, dynamic and vector scores (default)
+    #[default]
+    Linear,
+    /// Weighted reciprocal-rank fusion of the two ranked lists
+    Rrf,
+}
+
+/// Hybrid fusion configuration
+///
+/// Only applies when `context_compiler.hybrid_enabled` is true and the
+/// vector backend returned results. Both modes weight the vector signal by
+/// `context_compiler.vector_weight`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct HybridFusionConfig {
+    /// Fusion strategy (linear | rrf)
+    #[serde(default)]
+    pub fusion: HybridFusionMode,
+
+    /// RRF constant k (higher = flatter rank contribution)
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: f32,
+}
+
+fn default_rrf_k() -> f32 {
+    60.0
+}
+
+impl Default for HybridFusionConfig {
+    fn default() -> Self {
+        Self {
+            fusion: HybridFusionMode::default(),
+            rrf_k: default_rrf_k(),
+        }
+    }
+}
+
+/// How the Tier 2 cache key is derived from the TASK_BRIEF
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tier2CacheKeyMode {
+    /// Hash the brief verbatim (default)
+    #[default]
+    Full,
+    /// Hash the brief with volatile sections (scores, timestamps) stripped
+    Normalized,
+}
+
 /// Tier 2 (NotebookLM) configuration
 #[derive(Debug, Deserialize, Clone)]
 pub struct Tier2Config {
@@ -430,6 +497,10 @@ pub struct Tier2Config {
     /// Call timeout (e.g., "30s")
     #[serde(default = "default_call_timeout")]
     pub call_timeout: String,
+
+    /// Cache key derivation (full | normalized)
+    #[serde(default)]
+    pub cache_key_mode: Tier2CacheKeyMode,
 }
 
 fn default_tier2_enabled() -> bool {
@@ -451,6 +522,7 @@ impl Default for Tier2Config {
             cache_ttl_hours: default_cache_ttl_hours(),
             mcp_tool_name: None,
             call_timeout: default_call_timeout(),
+            cache_key_mode: Tier2CacheKeyMode::default(),
         }
     }
 }
@@ -627,6 +699,7 @@ impl Default for Stage0Config {
             context_compiler: ContextCompilerConfig::default(),
             tier2: Tier2Config::default(),
             vector_index: VectorIndexConfig::default(),
+            hybrid: HybridFusionConfig::default(),
             phase1_gate_mode: GateMode::default(),
             store_system_pointers: default_store_system_pointers(),
             memory_backend: MemoryBackend::default(),
@@ -934,6 +1007,7 @@ mod tests {
             cache_ttl_hours = 48
             mcp_tool_name = "custom-mcp"
             call_timeout = "60s"
+            cache_key_mode = "normalized"
         "#;
 
         let cfg = Stage0Config::parse(toml).expect("should parse");
@@ -944,5 +1018,6 @@ mod tests {
         assert_eq!(cfg.context_compiler.max_tokens, 4000);
         assert!(!cfg.tier2.enabled);
         assert_eq!(cfg.tier2.notebook, "test-notebook-id");
+        assert_eq!(cfg.tier2.cache_key_mode, Tier2CacheKeyMode::Normalized);
     }
 }
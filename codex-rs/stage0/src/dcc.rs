@@ -9,17 +9,17 @@
 //!
 //! See docs/stage0/STAGE0_SCORING_AND_DCC.md and docs/stage0/STAGE0_IQO_PROMPT.md for specifications.
 
-use crate::config::Stage0Config;
+use crate::config::{HybridFusionConfig, HybridFusionMode, Stage0Config};
 use crate::errors::Result;
 use crate::guardians::LlmClient;
 use crate::overlay_db::{CONSTITUTION_DOMAIN, CONSTITUTION_MIN_COUNT, OverlayDb};
 use crate::scoring::{ScoringInput, calculate_dynamic_score};
-use crate::vector::{DocumentKind, VectorBackend, VectorFilters};
+use crate::vector::{DocumentKind, ScoredVector, VectorBackend, VectorFilters};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Data Types
@@ -49,6 +49,25 @@ pub struct Iqo {
     pub exclude_tags: Vec<String>,
 }
 
+/// Where the IQO used by a DCC run came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IqoSource {
+    /// Generated by the LLM
+    Llm,
+    /// Heuristics only (LLM IQO generation disabled)
+    Heuristic,
+    /// Heuristics after LLM IQO generation failed
+    HeuristicFallback,
+}
+
+impl IqoSource {
+    /// Whether the heuristic path was taken because the LLM failed
+    pub fn is_fallback(&self) -> bool {
+        matches!(self, Self::HeuristicFallback)
+    }
+}
+
 /// Environment context passed to IQO generation
 #[derive(Debug, Clone, Default)]
 pub struct EnvCtx {
@@ -325,12 +344,44 @@ pub struct ExplainScore {
     pub age_penalty: f64,
     pub novelty_factor: f64,
     pub base_score: f64,
+    /// Normalized RRF score when `hybrid.fusion = "rrf"` was applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fused_score: Option<f64>,
+}
+
+/// DCC pipeline stage that dropped a candidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectionReason {
+    /// Same memory ID already returned earlier in the search results
+    Duplicate,
+    /// Carries a tag listed in the IQO's `exclude_tags`
+    ExcludedTag,
+    /// Combined score below `context_compiler.min_combined_score`
+    BelowThreshold,
+    /// Not picked by MMR diversity selection within `top_k`
+    MmrPruned,
+    /// Its domain already filled `context_compiler.per_domain_cap`
+    DomainCapped,
+    /// Picked by MMR, then given up to a backfilled constitution memory
+    ConstitutionDisplaced,
+}
+
+/// A candidate that did not make it into the TASK_BRIEF, and why
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RejectedCandidate {
+    pub id: String,
+    pub reason: RejectionReason,
 }
 
 /// Collection of explain scores for all candidates
 #[derive(Debug, Clone, Serialize)]
 pub struct ExplainScores {
     pub memories: Vec<ExplainScore>,
+    /// Whether the IQO came from the LLM or the heuristic path
+    pub iqo_source: IqoSource,
+    /// Candidates dropped during compilation, in pipeline order
+    pub rejected: Vec<RejectedCandidate>,
 }
 
 /// Result of DCC compilation
@@ -342,6 +393,8 @@ pub struct CompileContextResult {
     pub memories_used: Vec<String>,
     /// Score breakdown if explain=true
     pub explain_scores: Option<ExplainScores>,
+    /// Final IQO used for retrieval (after normalization and constitution union)
+    pub iqo: Iqo,
     /// P85: Code candidates selected for Code Context section
     pub code_candidates: Vec<CodeCandidate>,
     /// ADR-003: Product knowledge lane markdown (if enabled and items found)
@@ -416,18 +469,32 @@ pub async fn build_iqo<L: LlmClient>(
     spec_content: &str,
     env: &EnvCtx,
 ) -> Result<Iqo> {
+    let (iqo, _source) = build_iqo_with_source(llm, cfg, spec_content, env).await?;
+    Ok(iqo)
+}
+
+/// Build an IQO and report whether it came from the LLM or heuristics
+pub async fn build_iqo_with_source<L: LlmClient>(
+    llm: &L,
+    cfg: &Stage0Config,
+    spec_content: &str,
+    env: &EnvCtx,
+) -> Result<(Iqo, IqoSource)> {
     if !cfg.context_compiler.iqo_llm_enabled {
-        return Ok(heuristic_iqo(spec_content, cfg));
+        return Ok((heuristic_iqo(spec_content, cfg), IqoSource::Heuristic));
     }
 
     match llm.generate_iqo(spec_content, env).await {
-        Ok(iqo) => Ok(normalize_iqo(iqo, cfg)),
+        Ok(iqo) => Ok((normalize_iqo(iqo, cfg), IqoSource::Llm)),
         Err(e) => {
             tracing::warn!(
                 error = %e,
                 "IQO generation failed, falling back to heuristics"
             );
-            Ok(heuristic_iqo(spec_content, cfg))
+            Ok((
+                heuristic_iqo(spec_content, cfg),
+                IqoSource::HeuristicFallback,
+            ))
         }
     }
 }
@@ -645,8 +712,13 @@ fn build_constitution_search_params(limit: usize) -> LocalMemorySearchParams {
 /// 5. (V2.5) Query vector backend for hybrid retrieval
 /// 6. Merge and join with overlay scores
 /// 7. Apply MMR diversity reranking
-/// 8. (P89) Ensure minimum constitution memories in selection
+/// 8. (P89) Ensure minimum constitution memories in selection, displacing the
+///    lowest-ranked MMR picks so at most `top_k` memories are used
 /// 9. Assemble TASK_BRIEF.md
+///
+/// Duplicate search hits and memories carrying an `exclude_tags` tag are
+/// dropped whether or not `explain` is set; `explain` only controls whether
+/// the drops are reported.
 pub async fn compile_context<Lm, Ll, V>(
     ctx: &DccContext<'_, Lm, Ll>,
     vector: Option<&V>,
@@ -662,7 +734,7 @@ where
     V: VectorBackend,
 {
     // 1. Build IQO
-    let mut iqo = build_iqo(ctx.llm, ctx.cfg, spec_content, env).await?;
+    let (mut iqo, iqo_source) = build_iqo_with_source(ctx.llm, ctx.cfg, spec_content, env).await?;
 
     // 2. (P89) Ensure constitution domain is included via union
     ensure_constitution_domain(&mut iqo);
@@ -671,6 +743,7 @@ where
         keywords = ?iqo.keywords,
         domains = ?iqo.domains,
         max_candidates = iqo.max_candidates,
+        source = ?iqo_source,
         "Built IQO (with constitution domain)"
     );
 
@@ -698,7 +771,7 @@ where
     );
 
     // 5. (V2.5) Query vector backend if hybrid enabled
-    let vector_scores = if ctx.cfg.context_compiler.hybrid_enabled {
+    let vector_results: Vec<ScoredVector> = if ctx.cfg.context_compiler.hybrid_enabled {
         if let Some(vec_backend) = vector {
             // Build query from spec keywords
             let query_text = iqo.keywords.join(" ");
@@ -710,24 +783,24 @@ where
             {
                 Ok(results) => {
                     tracing::debug!(count = results.len(), "Retrieved vector search results");
-                    // Build map of id -> score
                     results
-                        .into_iter()
-                        .map(|sv| (sv.id, sv.score))
-                        .collect::<HashMap<String, f64>>()
                 }
                 Err(e) => {
                     tracing::warn!(error = %e, "Vector search failed, continuing without hybrid scores");
-                    HashMap::new()
+                    Vec::new()
                 }
             }
         } else {
             tracing::debug!("Hybrid enabled but no vector backend provided");
-            HashMap::new()
+            Vec::new()
         }
     } else {
-        HashMap::new()
+        Vec::new()
     };
+    let vector_scores: HashMap<String, f64> = vector_results
+        .iter()
+        .map(|sv| (sv.id.clone(), sv.score))
+        .collect();
 
     // 6. Join with overlay and compute combined scores (including vector scores)
     let mut candidates: Vec<MemoryCandidate> = Vec::new();
@@ -743,11 +816,8 @@ where
         0.0
     };
 
-    // Normalize weights if vector is enabled (should sum to ~1.0)
-    let total_weight = sim_weight + dyn_weight + vec_weight;
-    let norm_sim = sim_weight / total_weight;
-    let norm_dyn = dyn_weight / total_weight;
-    let norm_vec = vec_weight / total_weight;
+    // vector_weight is the vector share; similarity and dynamic split the rest
+    let (norm_sim, norm_dyn, norm_vec) = normalize_weights(sim_weight, dyn_weight, vec_weight);
 
     // Helper closure to convert a LocalMemorySummary to a MemoryCandidate
     let mut process_summary =
@@ -788,6 +858,7 @@ where
                     age_penalty: components.age_penalty,
                     novelty_factor: components.novelty_factor,
                     base_score: components.base_score,
+                    fused_score: None,
                 });
             }
 
@@ -804,8 +875,29 @@ where
             })
         };
 
-    // Process main summaries
+    // Process main summaries, recording why any candidate is dropped. Dedupe and
+    // exclude_tags filtering apply in every mode, not just explain.
+    let mut rejected: Vec<RejectedCandidate> = Vec::new();
+    let mut reject = |id: &str, reason: RejectionReason| {
+        if explain {
+            rejected.push(RejectedCandidate {
+                id: id.to_string(),
+                reason,
+            });
+        }
+    };
+    let min_combined_score = ctx.cfg.context_compiler.min_combined_score as f64;
+    let mut seen_ids: HashSet<String> = HashSet::new();
+
     for s in summaries {
+        if !seen_ids.insert(s.id.clone()) {
+            reject(&s.id, RejectionReason::Duplicate);
+            continue;
+        }
+        if s.tags.iter().any(|t| iqo.exclude_tags.contains(t)) {
+            reject(&s.id, RejectionReason::ExcludedTag);
+            continue;
+        }
         let candidate = process_summary(s, false)?;
         candidates.push(candidate);
     }
@@ -816,6 +908,58 @@ where
         constitution_candidates.push(candidate);
     }
 
+    // Reciprocal-rank fusion of keyword and vector rankings
+    if ctx.cfg.context_compiler.hybrid_enabled
+        && ctx.cfg.hybrid.fusion == HybridFusionMode::Rrf
+        && !vector_scores.is_empty()
+    {
+        // Fuse the union of both lists: vector hits the keyword search missed
+        // join the pool with no keyword contribution
+        let mut vector_only: HashSet<String> = HashSet::new();
+        if let Some(vec_backend) = vector {
+            for sv in &vector_results {
+                if seen_ids.contains(&sv.id)
+                    || constitution_candidates.iter().any(|c| c.id == sv.id)
+                {
+                    continue;
+                }
+                let Some(s) = vector_only_summary(vec_backend, sv).await else {
+                    continue;
+                };
+                seen_ids.insert(s.id.clone());
+                if s.tags.iter().any(|t| iqo.exclude_tags.contains(t)) {
+                    reject(&s.id, RejectionReason::ExcludedTag);
+                    continue;
+                }
+                vector_only.insert(s.id.clone());
+                candidates.push(process_summary(s, false)?);
+            }
+        }
+
+        apply_rrf_fusion(
+            &mut candidates,
+            &vector_scores,
+            &vector_only,
+            (norm_sim, norm_dyn, norm_vec),
+            &ctx.cfg.hybrid,
+        );
+
+        for score in explain_scores.iter_mut() {
+            if let Some(c) = candidates.iter().find(|c| c.id == score.id) {
+                score.combined_score = c.combined_score;
+                score.fused_score = Some(c.combined_score);
+            }
+        }
+    }
+
+    candidates.retain(|c| {
+        let keep = c.combined_score >= min_combined_score;
+        if !keep {
+            reject(&c.id, RejectionReason::BelowThreshold);
+        }
+        keep
+    });
+
     tracing::debug!(
         main_candidates = candidates.len(),
         constitution_pool = constitution_candidates.len(),
@@ -830,10 +974,16 @@ where
     });
 
     // 8. Apply MMR diversity reranking
-    let mut selected = select_with_mmr(
+    let mmr_pool_ids: Vec<String> = if explain {
+        candidates.iter().map(|c| c.id.clone()).collect()
+    } else {
+        Vec::new()
+    };
+    let (mut selected, domain_capped) = select_with_mmr(
         candidates,
         ctx.cfg.context_compiler.top_k,
         ctx.cfg.context_compiler.diversity_lambda as f64,
+        ctx.cfg.context_compiler.per_domain_cap,
     );
 
     tracing::debug!(
@@ -846,12 +996,43 @@ where
 
     // 9. (P89) Ensure minimum constitution memories in selection
     // Per SPEC-KIT-105 Section 4.3: "At least 3 constitution memories always included"
+    let mmr_selected = selected.len();
     ensure_constitution_minimum(
         &mut selected,
         &constitution_candidates,
         CONSTITUTION_MIN_COUNT,
     );
 
+    // Backfilled constitution memories take their slots from the lowest-ranked
+    // non-constitution MMR picks so the selection stays within top_k
+    let mut displaced: HashSet<String> = HashSet::new();
+    let mut overflow = selected
+        .len()
+        .saturating_sub(ctx.cfg.context_compiler.top_k);
+    let mut idx = mmr_selected;
+    while overflow > 0 && idx > 0 {
+        idx -= 1;
+        if !is_constitution_candidate(&selected[idx]) {
+            displaced.insert(selected.remove(idx).id);
+            overflow -= 1;
+        }
+    }
+
+    // Anything that entered MMR but isn't in the final selection was pruned
+    let selected_ids: HashSet<&str> = selected.iter().map(|c| c.id.as_str()).collect();
+    for id in &mmr_pool_ids {
+        if !selected_ids.contains(id.as_str()) {
+            let reason = if displaced.contains(id) {
+                RejectionReason::ConstitutionDisplaced
+            } else if domain_capped.contains(id) {
+                RejectionReason::DomainCapped
+            } else {
+                RejectionReason::MmrPruned
+            };
+            reject(id, reason);
+        }
+    }
+
     // 10. P85: Query code units if code lane enabled
     let code_candidates = if ctx.cfg.context_compiler.code_lane_enabled {
         if let Some(vec_backend) = vector {
@@ -1031,6 +1212,8 @@ where
     let explain_opt = if explain {
         Some(ExplainScores {
             memories: explain_scores,
+            iqo_source,
+            rejected,
         })
     } else {
         None
@@ -1040,6 +1223,7 @@ where
         task_brief_md,
         memories_used,
         explain_scores: explain_opt,
+        iqo,
         code_candidates,
         product_knowledge_lane,
         product_knowledge_pack,
@@ -1419,18 +1603,33 @@ fn pairwise_similarity(a: &MemoryCandidate, b: &MemoryCandidate) -> f64 {
 ///
 /// MMR balances relevance (combined_score) against diversity (pairwise similarity).
 /// lambda=1.0 → pure relevance, lambda=0.0 → pure diversity
+///
+/// A candidate whose domain already has `per_domain_cap` selected memories is
+/// skipped so the remaining slots go to other domains. Candidates without a
+/// domain are never capped. Also returns the IDs of candidates that were
+/// skipped for their domain and never selected.
 fn select_with_mmr(
     mut candidates: Vec<MemoryCandidate>,
     top_k: usize,
     lambda: f64,
-) -> Vec<MemoryCandidate> {
+    per_domain_cap: Option<usize>,
+) -> (Vec<MemoryCandidate>, HashSet<String>) {
     let mut selected: Vec<MemoryCandidate> = Vec::new();
+    let mut capped: HashSet<String> = HashSet::new();
 
     while !candidates.is_empty() && selected.len() < top_k {
-        let mut best_idx = 0;
+        let mut best_idx = None;
         let mut best_mmr = f64::NEG_INFINITY;
+        let counts = domain_counts(&selected);
 
         for (i, c) in candidates.iter().enumerate() {
+            if let (Some(cap), Some(domain)) = (per_domain_cap, c.domain.as_deref())
+                && counts.get(domain).copied().unwrap_or(0) >= cap
+            {
+                capped.insert(c.id.clone());
+                continue;
+            }
+
             let diversity_penalty = if selected.is_empty() {
                 0.0
             } else {
@@ -1442,16 +1641,166 @@ fn select_with_mmr(
 
             let mmr_score = lambda * c.combined_score - (1.0 - lambda) * diversity_penalty;
 
-            if mmr_score > best_mmr {
+            if best_idx.is_none() || mmr_score > best_mmr {
                 best_mmr = mmr_score;
-                best_idx = i;
+                best_idx = Some(i);
             }
         }
 
-        selected.push(candidates.remove(best_idx));
+        // Every remaining candidate belongs to a capped domain
+        let Some(idx) = best_idx else {
+            break;
+        };
+        selected.push(candidates.remove(idx));
+    }
+
+    (selected, capped)
+}
+
+/// Count selected memories per domain (memories without a domain are skipped)
+fn domain_counts(selected: &[MemoryCandidate]) -> HashMap<&str, usize> {
+    let mut counts = HashMap::new();
+    for c in selected {
+        if let Some(domain) = c.domain.as_deref() {
+            *counts.entry(domain).or_insert(0) += 1;
+        }
     }
+    counts
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Hybrid Rank Fusion
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Split the ranking weight between keyword relevance and the vector score
+///
+/// `vec` is the vector share, clamped to [0, 1]; similarity and dynamic
+/// score split the remaining `1 - vec` in proportion to their weights.
+/// Negative or NaN weights count as zero. If similarity and dynamic are both
+/// zero they split the keyword share evenly rather than dividing by zero.
+fn normalize_weights(sim: f64, dyn_: f64, vec: f64) -> (f64, f64, f64) {
+    let vec = if vec.is_nan() {
+        0.0
+    } else {
+        vec.clamp(0.0, 1.0)
+    };
+    let keyword = 1.0 - vec;
+    let (sim, dyn_) = (sim.max(0.0), dyn_.max(0.0));
+    let total = sim + dyn_;
+    if total > 0.0 && total.is_finite() {
+        (keyword * sim / total, keyword * dyn_ / total, vec)
+    } else {
+        (keyword / 2.0, keyword / 2.0, vec)
+    }
+}
+
+/// Build a candidate summary for a vector hit the keyword search missed
+///
+/// The text comes from the backend's stored document (or the hit's `text`
+/// metadata); hits without text are skipped since they cannot be rendered.
+async fn vector_only_summary<V: VectorBackend>(
+    backend: &V,
+    sv: &ScoredVector,
+) -> Option<LocalMemorySummary> {
+    let text = match backend.get_document(&sv.id).await {
+        Ok(Some(doc)) => Some(doc.text),
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!(id = %sv.id, error = %e, "Failed to load vector-only hit");
+            None
+        }
+    }
+    .or_else(|| {
+        sv.metadata
+            .extra
+            .get("text")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+    })?;
+
+    Some(LocalMemorySummary {
+        id: sv.id.clone(),
+        domain: sv.metadata.domain.clone(),
+        tags: sv.metadata.tags.clone(),
+        created_at: sv
+            .metadata
+            .created_at
+            .as_deref()
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|ts| ts.with_timezone(&Utc)),
+        snippet: text,
+        similarity_score: 0.0,
+    })
+}
+
+/// Replace candidates' combined scores with weighted reciprocal-rank fusion
+///
+/// Ranks candidates twice: by keyword relevance (similarity + dynamic score)
+/// and by vector score, using the `(sim, dyn, vec)` weights from
+/// `normalize_weights`. The fused score is
+/// `w/(k + rank_vec) + (1-w)/(k + rank_kw)` with `w` the vector share,
+/// normalized so rank 1 in both lists scores 1.0. Candidates
+/// absent from a list (including `vector_only` hits in the keyword ranking)
+/// get no contribution from it.
+fn apply_rrf_fusion(
+    candidates: &mut [MemoryCandidate],
+    vector_scores: &HashMap<String, f64>,
+    vector_only: &HashSet<String>,
+    weights: (f64, f64, f64),
+    cfg: &HybridFusionConfig,
+) {
+    let (sim_w, dyn_w, w) = weights;
+    let w = w.clamp(0.0, 1.0);
+    let k = (cfg.rrf_k as f64).max(0.0);
+    let (sim_w, dyn_w) = if sim_w + dyn_w > 0.0 {
+        (sim_w / (sim_w + dyn_w), dyn_w / (sim_w + dyn_w))
+    } else {
+        (0.5, 0.5)
+    };
+
+    let mut keyword_order: Vec<(String, f64)> = candidates
+        .iter()
+        .filter(|c| !vector_only.contains(&c.id))
+        .map(|c| {
+            (
+                c.id.clone(),
+                sim_w * c.similarity_score + dyn_w * c.dynamic_score,
+            )
+        })
+        .collect();
+    keyword_order.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(Ordering::Equal)
+            .then(a.0.cmp(&b.0))
+    });
+    let keyword_rank: HashMap<String, usize> = keyword_order
+        .into_iter()
+        .enumerate()
+        .map(|(i, (id, _))| (id, i + 1))
+        .collect();
+
+    let mut vector_order: Vec<(&String, &f64)> = vector_scores.iter().collect();
+    vector_order.sort_by(|a, b| {
+        b.1.partial_cmp(a.1)
+            .unwrap_or(Ordering::Equal)
+            .then(a.0.cmp(b.0))
+    });
+    let vector_rank: HashMap<&str, usize> = vector_order
+        .into_iter()
+        .enumerate()
+        .map(|(i, (id, _))| (id.as_str(), i + 1))
+        .collect();
 
-    selected
+    let max_fused = 1.0 / (k + 1.0);
+    for c in candidates.iter_mut() {
+        let kw = keyword_rank
+            .get(&c.id)
+            .map_or(0.0, |r| 1.0 / (k + *r as f64));
+        let vec = vector_rank
+            .get(c.id.as_str())
+            .map_or(0.0, |r| 1.0 / (k + *r as f64));
+        c.combined_score = (w * vec + (1.0 - w) * kw) / max_fused;
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -2106,7 +2455,7 @@ mod tests {
             })
             .collect();
 
-        let selected = select_with_mmr(candidates, 3, 0.7);
+        let selected = select_with_mmr(candidates, 3, 0.7, None).0;
         assert_eq!(selected.len(), 3);
     }
 
@@ -2154,7 +2503,7 @@ mod tests {
         // similar-2: mmr = 0.4 * 0.85 - 0.6 * 1.0 = 0.34 - 0.6 = -0.26
         // diverse:   mmr = 0.4 * 0.80 - 0.6 * 0.70 = 0.32 - 0.42 = -0.10
         // diverse wins because lower similarity to selected means lower diversity penalty
-        let selected = select_with_mmr(candidates, 2, 0.4);
+        let selected = select_with_mmr(candidates, 2, 0.4, None).0;
         assert_eq!(selected.len(), 2);
 
         // First should be highest score
@@ -2483,6 +2832,532 @@ mod tests {
         assert!(result.task_brief_md.contains("custom-domain"));
     }
 
+    #[tokio::test]
+    async fn test_compile_context_explains_rejected_candidates() {
+        let db = crate::overlay_db::OverlayDb::connect_in_memory().expect("db");
+        let llm = MockLlmClient::new();
+        let env = EnvCtx::default();
+        let noop_vector: Option<&NoopVectorBackend> = None;
+
+        let mut memories: Vec<LocalMemorySummary> = sample_memories().into_iter().take(3).collect();
+        memories.push(LocalMemorySummary {
+            id: "mem-low".to_string(),
+            domain: Some("spec-kit".to_string()),
+            tags: vec!["type:pattern".to_string()],
+            created_at: Some(Utc::now()),
+            snippet: "Barely related memory".to_string(),
+            similarity_score: 0.01,
+        });
+        let local_mem = MockLocalMemoryClient::new(memories);
+
+        // First pass without a threshold to learn the combined scores
+        let mut cfg = Stage0Config::default();
+        cfg.context_compiler.top_k = 2;
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+        let baseline =
+            compile_context(&ctx, noop_vector, "SPEC", "content", &env, true, Utc::now())
+                .await
+                .expect("compile_context should succeed");
+        let scores = baseline.explain_scores.expect("explain scores");
+        let low_score = scores
+            .memories
+            .iter()
+            .find(|m| m.id == "mem-low")
+            .expect("mem-low scored")
+            .combined_score;
+        let next_lowest = scores
+            .memories
+            .iter()
+            .filter(|m| m.id != "mem-low")
+            .map(|m| m.combined_score)
+            .fold(f64::INFINITY, f64::min);
+        assert!(low_score < next_lowest);
+
+        // Threshold between mem-low and the rest; top_k=2 leaves one for MMR to prune
+        cfg.context_compiler.min_combined_score = ((low_score + next_lowest) / 2.0) as f32;
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+        let result = compile_context(&ctx, noop_vector, "SPEC", "content", &env, true, Utc::now())
+            .await
+            .expect("compile_context should succeed");
+
+        let rejected = result.explain_scores.expect("explain scores").rejected;
+        assert!(rejected.contains(&RejectedCandidate {
+            id: "mem-low".to_string(),
+            reason: RejectionReason::BelowThreshold,
+        }));
+
+        let pruned: Vec<&RejectedCandidate> = rejected
+            .iter()
+            .filter(|r| r.reason == RejectionReason::MmrPruned)
+            .collect();
+        assert_eq!(pruned.len(), 1);
+        assert!(!result.memories_used.contains(&pruned[0].id));
+        assert_eq!(result.memories_used.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_compile_context_drops_duplicates_and_excluded_tags_without_explain() {
+        let mut cfg = Stage0Config::default();
+        cfg.context_compiler.iqo_llm_enabled = true;
+
+        let db = crate::overlay_db::OverlayDb::connect_in_memory().expect("db");
+        let mut memories: Vec<LocalMemorySummary> = sample_memories().into_iter().take(2).collect();
+        memories.push(memories[0].clone());
+        memories.push(LocalMemorySummary {
+            id: "mem-system".to_string(),
+            domain: Some("spec-kit".to_string()),
+            tags: vec!["system:true".to_string()],
+            created_at: Some(Utc::now()),
+            snippet: "Internal bookkeeping memory".to_string(),
+            similarity_score: 0.99,
+        });
+        let local_mem = MockLocalMemoryClient::new(memories);
+
+        let llm = MockLlmClient::new().with_iqo(Iqo {
+            domains: vec!["spec-kit".to_string()],
+            keywords: vec!["memory".to_string()],
+            max_candidates: 50,
+            ..Default::default()
+        });
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+
+        let env = EnvCtx::default();
+        let noop_vector: Option<&NoopVectorBackend> = None;
+        let result = compile_context(
+            &ctx,
+            noop_vector,
+            "SPEC",
+            "content",
+            &env,
+            false,
+            Utc::now(),
+        )
+        .await
+        .expect("compile_context should succeed");
+
+        assert!(result.explain_scores.is_none());
+        assert_eq!(result.memories_used, vec!["mem-001", "mem-002"]);
+    }
+
+    /// Local memory client that answers the constitution pass separately
+    struct SplitLocalMemoryClient {
+        main: Vec<LocalMemorySummary>,
+        constitution: Vec<LocalMemorySummary>,
+    }
+
+    #[async_trait]
+    impl LocalMemoryClient for SplitLocalMemoryClient {
+        async fn search_memories(
+            &self,
+            params: LocalMemorySearchParams,
+        ) -> Result<Vec<LocalMemorySummary>> {
+            if params.iqo.domains == [CONSTITUTION_DOMAIN] {
+                Ok(self.constitution.clone())
+            } else {
+                Ok(self.main.clone())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compile_context_explains_constitution_displacement() {
+        let db = crate::overlay_db::OverlayDb::connect_in_memory().expect("db");
+        let llm = MockLlmClient::new();
+        let env = EnvCtx::default();
+        let noop_vector: Option<&NoopVectorBackend> = None;
+
+        let memory =
+            |id: String, domain: &str, tag: &str, similarity_score: f64| LocalMemorySummary {
+                id,
+                domain: Some(domain.to_string()),
+                tags: vec![tag.to_string()],
+                created_at: Some(Utc::now()),
+                snippet: "Displacement memory".to_string(),
+                similarity_score,
+            };
+        let local_mem = SplitLocalMemoryClient {
+            main: (0..4)
+                .map(|i| {
+                    memory(
+                        format!("reg-{i}"),
+                        "spec-kit",
+                        "type:pattern",
+                        0.9 - i as f64 * 0.1,
+                    )
+                })
+                .collect(),
+            constitution: (0..2)
+                .map(|i| {
+                    memory(
+                        format!("const-{i}"),
+                        CONSTITUTION_DOMAIN,
+                        "type:guardrail",
+                        0.5,
+                    )
+                })
+                .collect(),
+        };
+
+        let mut cfg = Stage0Config::default();
+        cfg.context_compiler.top_k = 3;
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+        let result = compile_context(&ctx, noop_vector, "SPEC", "content", &env, true, Utc::now())
+            .await
+            .expect("compile_context should succeed");
+
+        // Both constitution memories are backfilled without growing past top_k
+        assert_eq!(result.memories_used.len(), 3);
+        assert!(result.memories_used.contains(&"const-0".to_string()));
+        assert!(result.memories_used.contains(&"const-1".to_string()));
+
+        let rejected = result.explain_scores.expect("explain scores").rejected;
+        let reasons = |reason: RejectionReason| -> Vec<&str> {
+            rejected
+                .iter()
+                .filter(|r| r.reason == reason)
+                .map(|r| r.id.as_str())
+                .collect()
+        };
+        let displaced = reasons(RejectionReason::ConstitutionDisplaced);
+        assert_eq!(displaced.len(), 2);
+        assert_eq!(reasons(RejectionReason::MmrPruned).len(), 1);
+        for id in displaced {
+            assert!(!result.memories_used.iter().any(|used| used == id));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compile_context_per_domain_cap() {
+        let db = crate::overlay_db::OverlayDb::connect_in_memory().expect("db");
+        let llm = MockLlmClient::new();
+        let env = EnvCtx::default();
+        let noop_vector: Option<&NoopVectorBackend> = None;
+
+        let memory = |id: String, domain: &str, similarity_score: f64| LocalMemorySummary {
+            id,
+            domain: Some(domain.to_string()),
+            tags: vec!["type:pattern".to_string()],
+            created_at: Some(Utc::now()),
+            snippet: "Domain quota memory".to_string(),
+            similarity_score,
+        };
+        let mut memories: Vec<LocalMemorySummary> = (0..5)
+            .map(|i| memory(format!("hot-{i}"), "hot", 0.95 - i as f64 * 0.01))
+            .collect();
+        memories.extend((0..3).map(|i| memory(format!("cold-{i}"), "cold", 0.3 - i as f64 * 0.01)));
+        let local_mem = MockLocalMemoryClient::new(memories);
+
+        let mut cfg = Stage0Config::default();
+        cfg.context_compiler.top_k = 4;
+        cfg.context_compiler.per_domain_cap = Some(2);
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+        let result = compile_context(&ctx, noop_vector, "SPEC", "content", &env, true, Utc::now())
+            .await
+            .expect("compile_context should succeed");
+
+        let hot = result
+            .memories_used
+            .iter()
+            .filter(|id| id.starts_with("hot-"))
+            .count();
+        assert_eq!(hot, 2, "hot domain should be capped at 2");
+        // Freed slots go to the next-best memories from other domains
+        assert_eq!(result.memories_used.len(), 4);
+
+        let capped = result
+            .explain_scores
+            .expect("explain scores")
+            .rejected
+            .iter()
+            .filter(|r| r.reason == RejectionReason::DomainCapped)
+            .count();
+        assert_eq!(capped, 3);
+    }
+
+    /// Vector backend returning a fixed ranked list
+    struct StaticVectorBackend {
+        results: Vec<(&'static str, f64)>,
+    }
+
+    #[async_trait]
+    impl VectorBackend for StaticVectorBackend {
+        async fn index_documents(
+            &self,
+            _docs: Vec<crate::vector::VectorDocument>,
+        ) -> Result<crate::vector::IndexStats> {
+            Ok(crate::vector::IndexStats::default())
+        }
+
+        async fn search(
+            &self,
+            _query_text: &str,
+            _filters: &VectorFilters,
+            _top_k: usize,
+        ) -> Result<Vec<crate::vector::ScoredVector>> {
+            Ok(self
+                .results
+                .iter()
+                .map(|(id, score)| {
+                    crate::vector::ScoredVector::new(*id, *score, DocumentKind::Memory)
+                })
+                .collect())
+        }
+
+        async fn document_count(&self) -> Result<usize> {
+            Ok(self.results.len())
+        }
+
+        async fn clear(&self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_document(&self, id: &str) -> Result<Option<crate::vector::VectorDocument>> {
+            Ok(self
+                .results
+                .iter()
+                .find(|(result_id, _)| *result_id == id)
+                .map(|(result_id, _)| {
+                    crate::vector::VectorDocument::new(
+                        *result_id,
+                        DocumentKind::Memory,
+                        format!("Document {result_id}"),
+                    )
+                }))
+        }
+
+        async fn delete_document(&self, _id: &str) -> Result<bool> {
+            Ok(false)
+        }
+    }
+
+    /// Run DCC with RRF fusion at `vector_weight` and return memory IDs by
+    /// descending fused score
+    async fn fused_ranking_with_vector_weight(vector_weight: f32) -> Vec<String> {
+        let db = crate::overlay_db::OverlayDb::connect_in_memory().expect("db");
+        let llm = MockLlmClient::new();
+        let env = EnvCtx::default();
+
+        // Keyword search ranks mem-a > mem-b > mem-c; vector search ranks the
+        // same three in reverse
+        let summary = |id: &str, similarity_score: f64| LocalMemorySummary {
+            id: id.to_string(),
+            domain: Some("spec-kit".to_string()),
+            tags: vec!["type:pattern".to_string()],
+            created_at: Some(Utc::now()),
+            snippet: format!("Memory {id}"),
+            similarity_score,
+        };
+        let local_mem = MockLocalMemoryClient::new(vec![
+            summary("mem-a", 0.9),
+            summary("mem-b", 0.6),
+            summary("mem-c", 0.3),
+        ]);
+        let vector = StaticVectorBackend {
+            results: vec![("mem-c", 0.9), ("mem-b", 0.6), ("mem-a", 0.3)],
+        };
+
+        let mut cfg = Stage0Config::default();
+        cfg.context_compiler.hybrid_enabled = true;
+        cfg.context_compiler.top_k = 3;
+        cfg.hybrid.fusion = HybridFusionMode::Rrf;
+        cfg.context_compiler.vector_weight = vector_weight;
+
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+        let result = compile_context(
+            &ctx,
+            Some(&vector),
+            "SPEC",
+            "content",
+            &env,
+            true,
+            Utc::now(),
+        )
+        .await
+        .expect("compile_context should succeed");
+
+        let scores = result.explain_scores.expect("explain scores");
+        let mut fused: Vec<(String, f64)> = scores
+            .memories
+            .iter()
+            .map(|m| (m.id.clone(), m.fused_score.expect("fused score")))
+            .collect();
+        fused.sort_by(|a, b| b.1.total_cmp(&a.1));
+        assert_eq!(fused.len(), 3);
+        assert!(
+            fused.windows(2).all(|pair| pair[0].1 > pair[1].1),
+            "fused scores must be distinct: {fused:?}"
+        );
+        fused.into_iter().map(|(id, _)| id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_compile_context_rrf_fusion_follows_vector_weight() {
+        // At the extremes the order is exactly one list's
+        assert_eq!(
+            fused_ranking_with_vector_weight(0.0).await,
+            vec!["mem-a", "mem-b", "mem-c"]
+        );
+        assert_eq!(
+            fused_ranking_with_vector_weight(1.0).await,
+            vec!["mem-c", "mem-b", "mem-a"]
+        );
+        // In between, the larger share decides which favourite leads
+        assert_eq!(fused_ranking_with_vector_weight(0.2).await[0], "mem-a");
+        assert_eq!(fused_ranking_with_vector_weight(0.8).await[0], "mem-c");
+    }
+
+    #[test]
+    fn test_normalize_weights_treats_vector_weight_as_share() {
+        let (sim, dyn_, vec) = normalize_weights(0.6, 0.4, 0.2);
+        assert!((sim - 0.48).abs() < 1e-12);
+        assert!((dyn_ - 0.32).abs() < 1e-12);
+        assert_eq!(vec, 0.2);
+
+        assert_eq!(normalize_weights(0.6, 0.4, 0.0), (0.6, 0.4, 0.0));
+        assert_eq!(normalize_weights(0.6, 0.4, 1.0), (0.0, 0.0, 1.0));
+        assert_eq!(normalize_weights(0.6, 0.4, 2.0), (0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_normalize_weights_handles_zero_total() {
+        assert_eq!(normalize_weights(0.0, 0.0, 0.0), (0.5, 0.5, 0.0));
+        assert_eq!(normalize_weights(f64::NAN, -1.0, f64::NAN), (0.5, 0.5, 0.0));
+        assert_eq!(normalize_weights(0.0, 0.0, 0.5), (0.25, 0.25, 0.5));
+    }
+
+    #[tokio::test]
+    async fn test_compile_context_rrf_includes_vector_only_hits() {
+        let db = crate::overlay_db::OverlayDb::connect_in_memory().expect("db");
+        let llm = MockLlmClient::new();
+        let env = EnvCtx::default();
+        let local_mem = MockLocalMemoryClient::new(sample_memories());
+
+        // Returned by vector search only, never by keyword search
+        let vector = StaticVectorBackend {
+            results: vec![("mem-vector-only", 0.9)],
+        };
+
+        let mut cfg = Stage0Config::default();
+        cfg.context_compiler.hybrid_enabled = true;
+        cfg.context_compiler.vector_weight = 1.0;
+        cfg.hybrid.fusion = HybridFusionMode::Rrf;
+
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+        let result = compile_context(
+            &ctx,
+            Some(&vector),
+            "SPEC",
+            "async pattern",
+            &env,
+            true,
+            Utc::now(),
+        )
+        .await
+        .expect("compile_context should succeed");
+
+        assert!(
+            result
+                .memories_used
+                .contains(&"mem-vector-only".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compile_context_exposes_iqo_and_source() {
+        let mut cfg = Stage0Config::default();
+        cfg.context_compiler.iqo_llm_enabled = true;
+
+        let db = crate::overlay_db::OverlayDb::connect_in_memory().expect("db");
+        let local_mem = MockLocalMemoryClient::new(sample_memories());
+        let env = EnvCtx::default();
+        let noop_vector: Option<&NoopVectorBackend> = None;
+
+        let custom_iqo = Iqo {
+            domains: vec!["custom-domain".to_string()],
+            keywords: vec!["custom".to_string(), "keywords".to_string()],
+            max_candidates: 50,
+            ..Default::default()
+        };
+        let llm = MockLlmClient::new().with_iqo(custom_iqo);
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &llm,
+        };
+
+        let result = compile_context(&ctx, noop_vector, "SPEC", "content", &env, true, Utc::now())
+            .await
+            .expect("compile_context should succeed");
+
+        // Returned IQO is the LLM's, plus the constitution domain union
+        assert_eq!(
+            result.iqo.domains,
+            vec!["custom-domain".to_string(), CONSTITUTION_DOMAIN.to_string()]
+        );
+        assert_eq!(
+            result.iqo.keywords,
+            vec!["custom".to_string(), "keywords".to_string()]
+        );
+        assert_eq!(result.iqo.max_candidates, 50);
+        let scores = result.explain_scores.expect("explain scores");
+        assert_eq!(scores.iqo_source, IqoSource::Llm);
+        assert!(!scores.iqo_source.is_fallback());
+
+        // LLM without a configured IQO errors -> heuristic fallback
+        let failing_llm = MockLlmClient::new();
+        let ctx = DccContext {
+            cfg: &cfg,
+            db: &db,
+            local_mem: &local_mem,
+            llm: &failing_llm,
+        };
+
+        let result = compile_context(&ctx, noop_vector, "SPEC", "content", &env, true, Utc::now())
+            .await
+            .expect("compile_context should succeed");
+
+        let scores = result.explain_scores.expect("explain scores");
+        assert_eq!(scores.iqo_source, IqoSource::HeuristicFallback);
+        assert!(scores.iqo_source.is_fallback());
+        assert!(result.iqo.domains.contains(&"spec-kit".to_string()));
+    }
+
     // V2.5: Test hybrid retrieval with vector backend
     #[tokio::test]
     async fn test_compile_context_with_hybrid_enabled() {
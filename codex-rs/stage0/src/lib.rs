@@ -30,7 +30,7 @@ pub mod tfidf;
 pub mod tier2;
 pub mod vector;
 
-pub use config::{GateMode, MemoryBackend, Stage0Config, VectorIndexConfig};
+pub use config::{GateMode, MemoryBackend, Stage0Config, Tier2CacheKeyMode, VectorIndexConfig};
 pub use dcc::{
     CompileContextResult,
     DccContext,
@@ -38,6 +38,7 @@ pub use dcc::{
     ExplainScore,
     ExplainScores,
     Iqo,
+    IqoSource,
     LocalMemoryClient,
     LocalMemorySearchParams,
     LocalMemorySummary,
@@ -53,6 +54,8 @@ pub use dcc::{
     ProductKnowledgeIntegrity,
     ProductKnowledgeItem,
     ProductKnowledgeQuery,
+    RejectedCandidate,
+    RejectionReason,
     precheck_product_knowledge,
 };
 pub use errors::{ErrorCategory, Result, Stage0Error};
@@ -69,7 +72,7 @@ pub use guardians::{
 };
 pub use hybrid::{HybridBackend, HybridConfig};
 pub use overlay_db::{
-    ConstitutionType, OverlayDb, OverlayMemory, StructureStatus, Tier2CacheEntry,
+    ConstitutionDiff, ConstitutionType, OverlayDb, OverlayMemory, StructureStatus, Tier2CacheEntry,
 };
 pub use policy::{
     ChangeCategory, GovernancePolicy, ModelConfig, PolicyDiff, PolicyFieldChange, PolicySnapshot,
@@ -125,12 +128,18 @@ pub struct Stage0Result {
     /// Whether Tier 2 (NotebookLM) was actually used
     pub tier2_used: bool,
 
+    /// Why Tier 2 was skipped when its health probe failed, for display
+    pub tier2_skip_reason: Option<String>,
+
     /// Total execution latency in milliseconds
     pub latency_ms: u64,
 
     /// Optional score breakdown (when explain=true)
     pub explain_scores: Option<ExplainScores>,
 
+    /// Final IQO used by DCC for retrieval
+    pub iqo: Iqo,
+
     // ─────────────────────────────────────────────────────────────────────────────
     // P91/SPEC-KIT-105: Constitution conflict detection fields
     // ─────────────────────────────────────────────────────────────────────────────
@@ -343,6 +352,50 @@ impl Stage0Engine {
         Ok(guarded)
     }
 
+    /// Guard a batch of memory drafts with bounded LLM concurrency
+    ///
+    /// MetadataGuardian runs synchronously per draft; TemplateGuardian LLM
+    /// calls run concurrently, at most `concurrency` at a time. Each draft
+    /// gets its own `Result`, so one failure doesn't abort the batch.
+    /// Results are returned in input order.
+    pub async fn guard_memories_batch<L: LlmClient>(
+        &self,
+        llm: &L,
+        drafts: Vec<MemoryDraft>,
+        concurrency: usize,
+    ) -> Vec<Result<GuardedMemory>> {
+        use futures::StreamExt;
+
+        let now = chrono::Utc::now();
+        let mut results: Vec<Option<Result<GuardedMemory>>> = Vec::with_capacity(drafts.len());
+        let mut pending = Vec::new();
+
+        for (idx, draft) in drafts.iter().enumerate() {
+            match apply_metadata_guardian(&self.cfg, draft, now) {
+                Ok(base) => {
+                    results.push(None);
+                    pending.push((idx, base));
+                }
+                Err(e) => results.push(Some(Err(e))),
+            }
+        }
+
+        let mut guarded = futures::stream::iter(pending)
+            .map(|(idx, base)| async move { (idx, apply_template_guardian(llm, base).await) })
+            .buffer_unordered(concurrency.max(1));
+
+        while let Some((idx, result)) = guarded.next().await {
+            results[idx] = Some(result);
+        }
+
+        results
+            .into_iter()
+            .map(|r| {
+                r.unwrap_or_else(|| Err(Stage0Error::internal("memory draft was not guarded")))
+            })
+            .collect()
+    }
+
     /// Apply MetadataGuardian only, skipping LLM template processing
     ///
     /// Useful when:
@@ -521,6 +574,9 @@ impl Stage0Engine {
             "Starting Stage 0 run"
         );
 
+        // Explain constitution-driven cache invalidation when the version moved
+        self.log_constitution_changes();
+
         // 2. Run DCC to compile TASK_BRIEF (with optional hybrid retrieval)
         let dcc_result = self
             .compile_context(local_mem, llm, vector, spec_id, spec_content, env, explain)
@@ -532,10 +588,18 @@ impl Stage0Engine {
             "DCC completed"
         );
 
-        // 3. Compute cache key from spec + brief
+        // 3. Compute cache key from spec + brief (optionally normalized)
+        let keyed_brief = match self.cfg.tier2.cache_key_mode {
+            Tier2CacheKeyMode::Full => {
+                std::borrow::Cow::Borrowed(dcc_result.task_brief_md.as_str())
+            }
+            Tier2CacheKeyMode::Normalized => {
+                std::borrow::Cow::Owned(normalize_brief_for_cache(&dcc_result.task_brief_md))
+            }
+        };
         let spec_hash = compute_hash(spec_content);
-        let brief_hash = compute_hash(&dcc_result.task_brief_md);
-        let input_hash = compute_cache_key(spec_content, &dcc_result.task_brief_md);
+        let brief_hash = compute_hash(&keyed_brief);
+        let input_hash = compute_cache_key(spec_content, &keyed_brief);
 
         // 4. Check Tier 2 cache (with TTL)
         let ttl_hours = self.cfg.tier2.cache_ttl_hours;
@@ -543,6 +607,7 @@ impl Stage0Engine {
             .db
             .get_tier2_cache_with_ttl(&input_hash, ttl_hours, now)?;
 
+        let mut tier2_skip_reason = None;
         let (divine_truth, cache_hit, tier2_used) = if let Some(entry) = cached_entry {
             // Cache hit - parse cached result
             tracing::info!(
@@ -564,6 +629,17 @@ impl Stage0Engine {
                 // Tier 2 disabled - use fallback
                 tracing::info!("Tier 2 disabled, using fallback");
 
+                let fallback = tier2::build_fallback_divine_truth(
+                    spec_id,
+                    spec_content,
+                    &dcc_result.task_brief_md,
+                );
+                (fallback, false, false)
+            } else if let Err(reason) = probe_tier2_health(tier2).await {
+                // Tier 2 unreachable - skip synthesis instead of waiting on a timeout
+                tracing::warn!(reason = %reason, "NotebookLM unavailable, using local context");
+                tier2_skip_reason = Some(format!("NotebookLM unavailable ({reason})"));
+
                 let fallback = tier2::build_fallback_divine_truth(
                     spec_id,
                     spec_content,
@@ -690,13 +766,62 @@ impl Stage0Engine {
             memories_used: dcc_result.memories_used,
             cache_hit,
             tier2_used,
+            tier2_skip_reason,
             latency_ms,
             explain_scores: dcc_result.explain_scores,
+            iqo: dcc_result.iqo,
             constitution_conflicts,
             constitution_aligned_ids,
             product_knowledge_pack: dcc_result.product_knowledge_pack,
         })
     }
+
+    /// Log which constitution memories changed since the last Stage 0 run
+    ///
+    /// Soft-fails: diff/observation errors are logged and otherwise ignored.
+    fn log_constitution_changes(&self) {
+        let current = match self.db.get_constitution_version() {
+            Ok(v) => v,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read constitution version");
+                return;
+            }
+        };
+
+        let previous = match self.db.observe_constitution_version(current) {
+            Ok(prev) => prev,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to record observed constitution version");
+                return;
+            }
+        };
+
+        let Some(previous) = previous.filter(|prev| *prev != current) else {
+            return;
+        };
+
+        match self.db.diff_constitution_versions(previous, current) {
+            Ok(diff) => tracing::info!(
+                target: "stage0",
+                from_version = diff.from_version,
+                to_version = diff.to_version,
+                added = ?diff.added,
+                removed = ?diff.removed,
+                modified = ?diff.modified,
+                "Constitution changed since last run; dependent Tier 2 cache entries were invalidated"
+            ),
+            Err(e) => tracing::warn!(error = %e, "Failed to diff constitution versions"),
+        }
+    }
+}
+
+/// Run the Tier 2 health probe, mapping unhealthy/error to a reason string
+async fn probe_tier2_health<T2: tier2::Tier2Client>(tier2: &T2) -> std::result::Result<(), String> {
+    match tier2.health_check().await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err("health check reported unavailable".to_string()),
+        Err(e) => Err(format!("health check failed: {e}")),
+    }
 }
 
 /// Compute SHA-256 hash of input, returning hex string
@@ -714,6 +839,85 @@ pub fn compute_cache_key(spec_content: &str, brief_content: &str) -> String {
     compute_hash(&format!("{spec_hash}{brief_hash}"))
 }
 
+/// Strip volatile content from a TASK_BRIEF before cache-key hashing
+///
+/// Drops `**Score:**` annotation lines and replaces ISO-8601 timestamps with a
+/// placeholder, so briefs that differ only in per-run scores or times hash
+/// identically under `Tier2CacheKeyMode::Normalized`.
+pub fn normalize_brief_for_cache(brief: &str) -> String {
+    let mut out = String::with_capacity(brief.len());
+    for line in brief.lines() {
+        if line.trim_start().starts_with("- **Score:**") {
+            continue;
+        }
+        out.push_str(&mask_timestamps(line));
+        out.push('\n');
+    }
+    out
+}
+
+/// Replace `YYYY-MM-DD[T ]HH:MM[:SS[.fff]][Z|±HH:MM]` runs with `<timestamp>`
+fn mask_timestamps(line: &str) -> String {
+    fn digits(b: &[u8], start: usize, n: usize) -> bool {
+        b.len() >= start + n && b[start..start + n].iter().all(u8::is_ascii_digit)
+    }
+
+    fn timestamp_len(b: &[u8]) -> Option<usize> {
+        // Date + separator + HH:MM is the minimum we treat as a timestamp
+        if !(digits(b, 0, 4)
+            && b.get(4) == Some(&b'-')
+            && digits(b, 5, 2)
+            && b.get(7) == Some(&b'-')
+            && digits(b, 8, 2)
+            && matches!(b.get(10), Some(b'T') | Some(b' '))
+            && digits(b, 11, 2)
+            && b.get(13) == Some(&b':')
+            && digits(b, 14, 2))
+        {
+            return None;
+        }
+        let mut end = 16;
+        if b.get(end) == Some(&b':') && digits(b, end + 1, 2) {
+            end += 3;
+            if b.get(end) == Some(&b'.') {
+                end += 1;
+                while b.get(end).is_some_and(u8::is_ascii_digit) {
+                    end += 1;
+                }
+            }
+        }
+        match b.get(end) {
+            Some(b'Z') => end += 1,
+            Some(b'+') | Some(b'-')
+                if digits(b, end + 1, 2)
+                    && b.get(end + 3) == Some(&b':')
+                    && digits(b, end + 4, 2) =>
+            {
+                end += 6
+            }
+            _ => {}
+        }
+        Some(end)
+    }
+
+    let bytes = line.as_bytes();
+    let mut out = String::with_capacity(line.len());
+    let mut i = 0;
+    let mut copied = 0;
+    while i < bytes.len() {
+        if let Some(len) = timestamp_len(&bytes[i..]) {
+            out.push_str(&line[copied..i]);
+            out.push_str("<timestamp>");
+            i += len;
+            copied = i;
+        } else {
+            i += 1;
+        }
+    }
+    out.push_str(&line[copied..]);
+    out
+}
+
 // Need hex encoding for hashes
 mod hex {
     const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
@@ -760,6 +964,42 @@ mod tests {
         assert_eq!(key1, key3); // Same inputs = same key
     }
 
+    #[test]
+    fn test_normalize_brief_for_cache_strips_volatile_sections() {
+        let brief = "# Task Brief: SPEC-1\n\
+                     - **Type:** pattern\n\
+                     - **Score:** 0.812 (sim=0.900, dyn=0.724)\n\
+                     Updated 2025-01-15T10:30:00Z by sync\n";
+
+        let normalized = normalize_brief_for_cache(brief);
+        assert!(!normalized.contains("Score"));
+        assert!(normalized.contains("- **Type:** pattern"));
+        assert!(normalized.contains("Updated <timestamp> by sync"));
+        assert!(!normalized.contains("2025-01-15"));
+    }
+
+    #[test]
+    fn test_normalized_cache_key_ignores_scores() {
+        let spec = "# SPEC-1\n\nSame spec content.";
+        let run1 = "# Task Brief: SPEC-1\n\
+                    #### Memory 1 – `mem-001`\n\
+                    - **Score:** 0.812 (sim=0.900, dyn=0.724)\n\
+                    Generated at 2025-01-15 10:30:00\n";
+        let run2 = "# Task Brief: SPEC-1\n\
+                    #### Memory 1 – `mem-001`\n\
+                    - **Score:** 0.845 (sim=0.900, dyn=0.790)\n\
+                    Generated at 2025-01-16 08:00:12\n";
+
+        // Full mode: scores change the key
+        assert_ne!(compute_cache_key(spec, run1), compute_cache_key(spec, run2));
+
+        // Normalized mode: same key
+        assert_eq!(
+            compute_cache_key(spec, &normalize_brief_for_cache(run1)),
+            compute_cache_key(spec, &normalize_brief_for_cache(run2))
+        );
+    }
+
     // V1.3: Scoring integration tests
     #[test]
     fn test_engine_calculate_memory_score() {
@@ -826,6 +1066,95 @@ mod tests {
         assert!(missing.is_none());
     }
 
+    // Batch guarding: bounded concurrency for TemplateGuardian LLM calls
+    mod batch_guard_tests {
+        use super::*;
+        use crate::guardians::MemoryKind;
+        use async_trait::async_trait;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Mock LLM that tracks the maximum number of in-flight restructure calls
+        #[derive(Default)]
+        struct CountingLlmClient {
+            in_flight: AtomicUsize,
+            max_in_flight: AtomicUsize,
+        }
+
+        #[async_trait]
+        impl LlmClient for CountingLlmClient {
+            async fn classify_kind(&self, _input: &str) -> Result<MemoryKind> {
+                Ok(MemoryKind::Pattern)
+            }
+
+            async fn restructure_template(&self, input: &str, _kind: MemoryKind) -> Result<String> {
+                let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+                for _ in 0..5 {
+                    tokio::task::yield_now().await;
+                }
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(format!("[PATTERN]: {input}"))
+            }
+
+            async fn generate_iqo(&self, _spec_content: &str, _env: &EnvCtx) -> Result<dcc::Iqo> {
+                Ok(dcc::Iqo::default())
+            }
+        }
+
+        fn valid_draft(content: &str) -> MemoryDraft {
+            MemoryDraft {
+                raw_content: content.to_string(),
+                created_at: Some(chrono::Utc::now()),
+                agent_type_tag: Some("agent:human".to_string()),
+                ..Default::default()
+            }
+        }
+
+        #[tokio::test]
+        async fn test_guard_memories_batch_respects_concurrency_bound() {
+            let engine = Stage0Engine::in_memory().expect("should create");
+            let llm = CountingLlmClient::default();
+
+            let drafts: Vec<MemoryDraft> = (0..10)
+                .map(|i| valid_draft(&format!("memory {i}")))
+                .collect();
+
+            let results = engine.guard_memories_batch(&llm, drafts, 3).await;
+
+            assert_eq!(results.len(), 10);
+            let max = llm.max_in_flight.load(Ordering::SeqCst);
+            assert!(max <= 3, "max in-flight {max} exceeded bound");
+            assert!(max > 1, "calls should run concurrently");
+
+            // Results come back in input order
+            for (i, result) in results.iter().enumerate() {
+                let guarded = result.as_ref().expect("guarded");
+                assert_eq!(guarded.content_raw, format!("memory {i}"));
+            }
+        }
+
+        #[tokio::test]
+        async fn test_guard_memories_batch_isolates_failures() {
+            // Default config uses strict metadata, so a draft without
+            // created_at fails validation
+            let engine = Stage0Engine::in_memory().expect("should create");
+            let llm = CountingLlmClient::default();
+
+            let drafts = vec![
+                valid_draft("first"),
+                MemoryDraft::new("missing metadata"),
+                valid_draft("third"),
+            ];
+
+            let results = engine.guard_memories_batch(&llm, drafts, 2).await;
+
+            assert_eq!(results.len(), 3);
+            assert!(results[0].is_ok());
+            assert!(results[1].is_err());
+            assert!(results[2].is_ok());
+        }
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // V1.5: run_stage0 integration tests
     // ─────────────────────────────────────────────────────────────────────────────
@@ -912,6 +1241,7 @@ mod tests {
         struct MockTier2Client {
             call_count: AtomicU32,
             should_fail: bool,
+            healthy: bool,
             response: Option<Tier2Response>,
         }
 
@@ -920,6 +1250,7 @@ mod tests {
                 Self {
                     call_count: AtomicU32::new(0),
                     should_fail: false,
+                    healthy: true,
                     response: Some(Tier2Response {
                         divine_truth_md: r#"# Divine Truth Brief: SPEC-TEST
 
@@ -959,10 +1290,18 @@ mod tests {
                 Self {
                     call_count: AtomicU32::new(0),
                     should_fail: true,
+                    healthy: true,
                     response: None,
                 }
             }
 
+            fn unhealthy() -> Self {
+                Self {
+                    healthy: false,
+                    ..Self::success()
+                }
+            }
+
             fn get_call_count(&self) -> u32 {
                 self.call_count.load(Ordering::SeqCst)
             }
@@ -986,6 +1325,14 @@ mod tests {
                         .ok_or_else(|| Stage0Error::tier2("No mock response"))
                 }
             }
+
+            async fn health_check(&self) -> Result<bool> {
+                if self.healthy {
+                    Ok(true)
+                } else {
+                    Err(Stage0Error::tier2("Mock Tier 2 unreachable"))
+                }
+            }
         }
 
         #[tokio::test]
@@ -1135,6 +1482,41 @@ mod tests {
             assert_eq!(tier2.get_call_count(), 1);
         }
 
+        #[tokio::test]
+        async fn test_run_stage0_tier2_health_check_failure_skips_synthesis() {
+            let engine = Stage0Engine::in_memory().expect("create");
+            let local_mem = MockLocalMemoryClient::with_sample_memories();
+            let llm = MockLlmClient;
+            let tier2 = MockTier2Client::unhealthy();
+            let noop_vector: Option<&NoopVectorBackend> = None;
+
+            let result = engine
+                .run_stage0(
+                    &local_mem,
+                    &llm,
+                    noop_vector,
+                    &tier2,
+                    "SPEC-TEST",
+                    "Test spec content",
+                    &EnvCtx::default(),
+                    false,
+                )
+                .await
+                .expect("run_stage0 should succeed when Tier 2 is unreachable");
+
+            assert!(!result.tier2_used);
+            assert!(result.divine_truth.is_fallback());
+            assert_eq!(
+                result.tier2_skip_reason.as_deref(),
+                Some(
+                    "NotebookLM unavailable (health check failed: Tier2 error: Mock Tier 2 unreachable)"
+                )
+            );
+
+            // Synthesis was never attempted
+            assert_eq!(tier2.get_call_count(), 0);
+        }
+
         #[tokio::test]
         async fn test_run_stage0_cache_hit() {
             // Use two separate engines - one to populate the cache, one to test hit
@@ -1313,8 +1695,10 @@ mod tests {
                 memories_used: vec!["mem-1".to_string()],
                 cache_hit: false,
                 tier2_used: true,
+                tier2_skip_reason: None,
                 latency_ms: 100,
                 explain_scores: None,
+                iqo: Default::default(),
                 constitution_conflicts: None,
                 constitution_aligned_ids: vec![],
                 product_knowledge_pack: None,
@@ -1336,8 +1720,10 @@ mod tests {
                 memories_used: vec!["mem-1".to_string()],
                 cache_hit: false,
                 tier2_used: false,
+                tier2_skip_reason: None,
                 latency_ms: 0,
                 explain_scores: None,
+                iqo: Default::default(),
                 constitution_conflicts: None,
                 constitution_aligned_ids: vec![],
                 product_knowledge_pack: None,
@@ -1354,8 +1740,10 @@ mod tests {
                 memories_used: vec![],
                 cache_hit: false,
                 tier2_used: false,
+                tier2_skip_reason: None,
                 latency_ms: 0,
                 explain_scores: None,
+                iqo: Default::default(),
                 constitution_conflicts: None,
                 constitution_aligned_ids: vec![],
                 product_knowledge_pack: None,
@@ -1369,8 +1757,10 @@ mod tests {
                 memories_used: vec![],
                 cache_hit: false,
                 tier2_used: false,
+                tier2_skip_reason: None,
                 latency_ms: 0,
                 explain_scores: None,
+                iqo: Default::default(),
                 constitution_conflicts: None,
                 constitution_aligned_ids: vec![],
                 product_knowledge_pack: None,
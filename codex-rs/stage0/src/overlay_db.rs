@@ -163,6 +163,29 @@ pub struct Tier2CacheEntry {
     pub last_hit_at: Option<DateTime<Utc>>,
 }
 
+/// Difference between two constitution versions
+///
+/// Produced by `OverlayDb::diff_constitution_versions` to explain why
+/// constitution-dependent Tier 2 cache entries were invalidated.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstitutionDiff {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Memory IDs present in `to_version` but not in `from_version`
+    pub added: Vec<String>,
+    /// Memory IDs present in `from_version` but not in `to_version`
+    pub removed: Vec<String>,
+    /// Memory IDs present in both versions with different content
+    pub modified: Vec<String>,
+}
+
+impl ConstitutionDiff {
+    /// True if the two versions contain identical constitution memories
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
 /// Overlay database wrapper
 pub struct OverlayDb {
     conn: Connection,
@@ -1001,6 +1024,7 @@ impl OverlayDb {
         })?;
 
         let new_version = self.get_constitution_version()?;
+        self.snapshot_constitution_version(new_version)?;
 
         tracing::info!(
             version = new_version,
@@ -1011,6 +1035,157 @@ impl OverlayDb {
         Ok(new_version)
     }
 
+    /// Record the current constitution memories as the snapshot for `version`
+    ///
+    /// Stores (memory_id, content hash) pairs in `constitution_history` so that
+    /// later versions can be diffed against this one.
+    fn snapshot_constitution_version(&self, version: u32) -> Result<()> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT memory_id, COALESCE(content_raw, '') FROM overlay_memories WHERE initial_priority >= 8",
+            )
+            .map_err(|e| Stage0Error::overlay_db_with_source("failed to prepare query", e))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| {
+                Stage0Error::overlay_db_with_source("failed to query constitution memories", e)
+            })?;
+
+        let mut snapshot = Vec::new();
+        for row in rows {
+            let (memory_id, content) = row.map_err(|e| {
+                Stage0Error::overlay_db_with_source("failed to read constitution memory row", e)
+            })?;
+            snapshot.push((memory_id, crate::compute_hash(&content)));
+        }
+
+        self.conn
+            .execute(
+                "DELETE FROM constitution_history WHERE version = ?1",
+                params![version as i64],
+            )
+            .map_err(|e| {
+                Stage0Error::overlay_db_with_source("failed to clear constitution snapshot", e)
+            })?;
+
+        for (memory_id, content_hash) in &snapshot {
+            self.conn
+                .execute(
+                    r#"
+                    INSERT INTO constitution_history (version, memory_id, content_hash)
+                    VALUES (?1, ?2, ?3)
+                    "#,
+                    params![version as i64, memory_id, content_hash],
+                )
+                .map_err(|e| {
+                    Stage0Error::overlay_db_with_source("failed to store constitution snapshot", e)
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Load the (memory_id -> content hash) snapshot for a constitution version
+    ///
+    /// Versions without a recorded snapshot (e.g. version 0) are empty.
+    fn get_constitution_snapshot(
+        &self,
+        version: u32,
+    ) -> Result<std::collections::BTreeMap<String, String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT memory_id, content_hash FROM constitution_history WHERE version = ?1")
+            .map_err(|e| Stage0Error::overlay_db_with_source("failed to prepare query", e))?;
+
+        let rows = stmt
+            .query_map(params![version as i64], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| {
+                Stage0Error::overlay_db_with_source("failed to query constitution history", e)
+            })?;
+
+        let mut snapshot = std::collections::BTreeMap::new();
+        for row in rows {
+            let (memory_id, content_hash) = row.map_err(|e| {
+                Stage0Error::overlay_db_with_source("failed to read constitution history row", e)
+            })?;
+            snapshot.insert(memory_id, content_hash);
+        }
+        Ok(snapshot)
+    }
+
+    /// Diff the constitution memories between two versions
+    ///
+    /// Reports memory IDs that were added, removed, or modified going from
+    /// `from` to `to`. Results are sorted by memory ID.
+    pub fn diff_constitution_versions(&self, from: u32, to: u32) -> Result<ConstitutionDiff> {
+        let before = self.get_constitution_snapshot(from)?;
+        let after = self.get_constitution_snapshot(to)?;
+
+        let mut diff = ConstitutionDiff {
+            from_version: from,
+            to_version: to,
+            ..Default::default()
+        };
+
+        for (memory_id, hash) in &after {
+            match before.get(memory_id) {
+                None => diff.added.push(memory_id.clone()),
+                Some(old_hash) if old_hash != hash => diff.modified.push(memory_id.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = before
+            .keys()
+            .filter(|id| !after.contains_key(*id))
+            .cloned()
+            .collect();
+
+        Ok(diff)
+    }
+
+    /// Record `version` as the last constitution version seen by a Stage 0 run
+    ///
+    /// Returns the previously observed version, or None on the first run.
+    pub fn observe_constitution_version(&self, version: u32) -> Result<Option<u32>> {
+        let previous: Option<i64> = self
+            .conn
+            .query_row(
+                "SELECT version FROM constitution_observed WHERE id = 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| {
+                Stage0Error::overlay_db_with_source(
+                    "failed to get observed constitution version",
+                    e,
+                )
+            })?;
+
+        self.conn
+            .execute(
+                r#"
+                INSERT INTO constitution_observed (id, version) VALUES (1, ?1)
+                ON CONFLICT(id) DO UPDATE SET version = ?1
+                "#,
+                params![version as i64],
+            )
+            .map_err(|e| {
+                Stage0Error::overlay_db_with_source(
+                    "failed to record observed constitution version",
+                    e,
+                )
+            })?;
+
+        Ok(previous.map(|v| v as u32))
+    }
+
     /// Get constitution metadata (version, hash, updated_at)
     ///
     /// Returns (version, content_hash, updated_at)
@@ -1741,6 +1916,49 @@ APPROVED_BY: architect on 2025-01-15"#;
         assert!(updated_at.is_some());
     }
 
+    #[test]
+    fn test_diff_constitution_versions_reports_added_principle() {
+        let db = OverlayDb::connect_in_memory().expect("should connect");
+
+        db.upsert_constitution_memory("const-g1", ConstitutionType::Guardrail, "Guardrail 1")
+            .expect("insert");
+        let v1 = db.increment_constitution_version(None).expect("increment");
+
+        db.upsert_constitution_memory("const-p1", ConstitutionType::Principle, "Principle 1")
+            .expect("insert");
+        let v2 = db.increment_constitution_version(None).expect("increment");
+
+        let diff = db.diff_constitution_versions(v1, v2).expect("diff");
+        assert_eq!(diff.from_version, 1);
+        assert_eq!(diff.to_version, 2);
+        assert_eq!(diff.added, vec!["const-p1".to_string()]);
+        assert!(diff.removed.is_empty());
+        assert!(diff.modified.is_empty());
+
+        // Modifying content shows up as modified, not added
+        db.upsert_constitution_memory("const-g1", ConstitutionType::Guardrail, "Guardrail 1b")
+            .expect("update");
+        let v3 = db.increment_constitution_version(None).expect("increment");
+        let diff = db.diff_constitution_versions(v2, v3).expect("diff");
+        assert_eq!(diff.modified, vec!["const-g1".to_string()]);
+        assert!(diff.added.is_empty());
+    }
+
+    #[test]
+    fn test_observe_constitution_version() {
+        let db = OverlayDb::connect_in_memory().expect("should connect");
+
+        assert_eq!(db.observe_constitution_version(1).expect("observe"), None);
+        assert_eq!(
+            db.observe_constitution_version(2).expect("observe"),
+            Some(1)
+        );
+        assert_eq!(
+            db.observe_constitution_version(2).expect("observe"),
+            Some(2)
+        );
+    }
+
     #[test]
     fn test_get_constitution_memories() {
         let db = OverlayDb::connect_in_memory().expect("should connect");
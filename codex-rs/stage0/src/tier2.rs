@@ -123,6 +123,15 @@ pub trait Tier2Client: Send + Sync {
         spec_content: &str,
         task_brief_md: &str,
     ) -> Result<Tier2Response>;
+
+    /// Cheap reachability probe run before synthesis
+    ///
+    /// `run_stage0` skips straight to the fallback Divine Truth when this
+    /// returns `Ok(false)` or an error, instead of waiting out a long
+    /// synthesis timeout. Defaults to `Ok(true)`.
+    async fn health_check(&self) -> Result<bool> {
+        Ok(true)
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -2973,6 +2973,11 @@ impl App<'_> {
                         widget.on_device_code_denied(provider);
                     }
                 }
+                AppEvent::DeviceCodeLogoutComplete { provider, result } => {
+                    if let AppState::Chat { widget } = &mut self.app_state {
+                        widget.on_device_code_logout_complete(provider, result);
+                    }
+                }
 
                 // SPEC-954: Handle user message timeout
                 AppEvent::UserMessageTimeout {
@@ -479,6 +479,12 @@ pub(crate) enum AppEvent {
     DeviceCodeLoginDenied {
         provider: codex_login::DeviceCodeProvider,
     },
+
+    /// `/auth logout` finished revoking and removing the stored token
+    DeviceCodeLogoutComplete {
+        provider: codex_login::DeviceCodeProvider,
+        result: Result<(), String>,
+    },
     // === END FORK-SPECIFIC: Device Code OAuth Events ===
     /// Start a new chat session by resuming from the given rollout file
     ResumeFrom(std::path::PathBuf),
@@ -1995,6 +1995,7 @@ impl WidgetRef for ChatComposer {
                                 DeviceCodeProvider::OpenAI => "O",
                                 DeviceCodeProvider::Google => "G",
                                 DeviceCodeProvider::Anthropic => "C",
+                                DeviceCodeProvider::Azure => "A",
                             };
                             let (icon, color) = match token_status {
                                 TokenStatus::Valid => ("✓", ratatui::style::Color::Green),
@@ -285,6 +285,8 @@ impl DeviceCodeLoginState {
             DeviceCodeProvider::Google => {
                 self.app_event_tx.send(AppEvent::LoginCancelGemini);
             }
+            // Azure only logs in via device code; no other login view to notify
+            DeviceCodeProvider::Azure => {}
         }
     }
 
@@ -305,6 +307,11 @@ impl DeviceCodeLoginState {
                     provider: DeviceCodeProvider::Google,
                 });
             }
+            DeviceCodeProvider::Azure => {
+                self.app_event_tx.send(AppEvent::DeviceCodeLoginStart {
+                    provider: DeviceCodeProvider::Azure,
+                });
+            }
         }
     }
 
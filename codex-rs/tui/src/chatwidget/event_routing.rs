@@ -993,12 +993,12 @@ impl ChatWidget<'_> {
                         .filter(|s| !s.is_empty())
                         .map(|s| s.to_string());
                     let total = update.plan.len();
-                    let completed = update
+                    let finished = update
                         .plan
                         .iter()
-                        .filter(|p| matches!(p.status, StepStatus::Completed))
+                        .filter(|p| p.status.is_terminal())
                         .count();
-                    let active = total > 0 && completed < total;
+                    let active = total > 0 && finished < total;
                     (title, active)
                 };
                 // Insert plan updates at the time they occur. If the provider
@@ -906,64 +906,6 @@ impl ChatWidget<'_> {
         false
     }
 
-    async fn git_short_status(path: &std::path::Path) -> Result<String, String> {
-        use tokio::process::Command;
-        match Command::new("git")
-            .current_dir(path)
-            .args(["status", "--short"])
-            .output()
-            .await
-        {
-            Ok(out) if out.status.success() => Ok(String::from_utf8_lossy(&out.stdout).to_string()),
-            Ok(out) => {
-                let stderr_s = String::from_utf8_lossy(&out.stderr).trim().to_string();
-                let stdout_s = String::from_utf8_lossy(&out.stdout).trim().to_string();
-                if !stderr_s.is_empty() {
-                    Err(stderr_s)
-                } else if !stdout_s.is_empty() {
-                    Err(stdout_s)
-                } else {
-                    let code = out
-                        .status
-                        .code()
-                        .map(|c| format!("exit status {c}"))
-                        .unwrap_or_else(|| "terminated by signal".to_string());
-                    Err(format!("git status failed: {}", code))
-                }
-            }
-            Err(err) => Err(err.to_string()),
-        }
-    }
-
-    async fn git_diff_stat(path: &std::path::Path) -> Result<String, String> {
-        use tokio::process::Command;
-        match Command::new("git")
-            .current_dir(path)
-            .args(["diff", "--stat"])
-            .output()
-            .await
-        {
-            Ok(out) if out.status.success() => Ok(String::from_utf8_lossy(&out.stdout).to_string()),
-            Ok(out) => {
-                let stderr_s = String::from_utf8_lossy(&out.stderr).trim().to_string();
-                let stdout_s = String::from_utf8_lossy(&out.stdout).trim().to_string();
-                if !stderr_s.is_empty() {
-                    Err(stderr_s)
-                } else if !stdout_s.is_empty() {
-                    Err(stdout_s)
-                } else {
-                    let code = out
-                        .status
-                        .code()
-                        .map(|c| format!("exit status {c}"))
-                        .unwrap_or_else(|| "terminated by signal".to_string());
-                    Err(format!("git diff --stat failed: {code}"))
-                }
-            }
-            Err(err) => Err(err.to_string()),
-        }
-    }
-
     /// Compute an OrderKey for system (non‑LLM) notices in a way that avoids
     /// creating multiple synthetic request buckets before the first provider turn.
     fn system_order_key(
@@ -3030,9 +2972,14 @@ impl ChatWidget<'_> {
     fn reload_config(&mut self) {
         match self.config.reload_from_file() {
             Ok(new_config) => {
+                let diff = self.config.diff(&new_config);
                 self.config = new_config;
-                self.app_event_tx
-                    .send_background_event("✅ Config reloaded successfully".to_string());
+                let message = if diff.is_empty() {
+                    "✅ Config reloaded successfully (no effective changes)".to_string()
+                } else {
+                    format!("✅ Config reloaded: {diff}")
+                };
+                self.app_event_tx.send_background_event(message);
                 tracing::info!("Config reloaded from disk");
 
                 // Refresh components that depend on config
@@ -3073,6 +3020,12 @@ impl ChatWidget<'_> {
             Ok(storage) => {
                 match storage.status_summary() {
                     Ok(status) => {
+                        // Footer shows one indicator per provider: its default account
+                        let status: Vec<_> = status
+                            .into_iter()
+                            .filter(|(_, label, _)| label.is_none())
+                            .map(|(provider, _, token_status)| (provider, token_status))
+                            .collect();
                         // Only show if at least one provider has a non-default status
                         let has_any_status = status
                             .iter()
@@ -4033,6 +3986,46 @@ impl ChatWidget<'_> {
             )));
             return;
         }
+        // Expand slash command aliases, unambiguous prefixes and deprecated names to
+        // the canonical command before any other routing. Saved subagent commands
+        // keep their names.
+        let typed_command = message
+            .display_text
+            .trim()
+            .strip_prefix('/')
+            .and_then(|rest| rest.split_whitespace().next())
+            .unwrap_or("");
+        let is_subagent = self
+            .config
+            .subagent_commands
+            .iter()
+            .any(|c| c.name.eq_ignore_ascii_case(typed_command));
+        if !typed_command.is_empty() && !is_subagent {
+            let aliases = &self.config.tui.slash_aliases;
+            match crate::slash_command::canonicalize_command_text(&message.display_text, aliases) {
+                Ok(Some(canonical)) => {
+                    message.display_text = canonical.text;
+                    if let Some(InputItem::Text { text }) = message
+                        .ordered_items
+                        .iter_mut()
+                        .find(|it| matches!(it, InputItem::Text { .. }))
+                        && let Ok(Some(rewritten)) =
+                            crate::slash_command::canonicalize_command_text(text, aliases)
+                    {
+                        *text = rewritten.text;
+                    }
+                    if let Some(deprecation) = canonical.deprecation {
+                        self.history_push(history_cell::new_warning_event(deprecation.notice()));
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    self.history_push(history_cell::new_error_event(err.to_string()));
+                    return;
+                }
+            }
+        }
+
         let original_text = message.display_text.clone();
         // Build a combined string view of the text-only parts to process slash commands
         let mut text_only = String::new();
@@ -5033,7 +5026,7 @@ impl ChatWidget<'_> {
     /// P6-SYNC Phase 5: Handle /auth command for device code OAuth management
     /// Subcommands:
     /// - /auth or /auth status - Show token status for all providers
-    /// - /auth login <provider> - Start device code flow for provider (openai/google/anthropic)
+    /// - /auth login <provider> - Start device code flow for provider (openai/google/anthropic/azure)
     /// - /auth logout <provider> - Remove stored token for provider
     pub(crate) fn handle_auth_command(&mut self, args: &str) {
         use codex_login::{DeviceCodeProvider, DeviceCodeTokenStorage, TokenStatus};
@@ -5060,11 +5053,12 @@ impl ChatWidget<'_> {
                 match DeviceCodeTokenStorage::new() {
                     Ok(storage) => match storage.status_summary() {
                         Ok(status) => {
-                            for (provider, token_status) in &status {
+                            for (provider, account_label, token_status) in &status {
                                 let provider_name = match provider {
                                     DeviceCodeProvider::OpenAI => "OpenAI",
                                     DeviceCodeProvider::Google => "Google (Gemini)",
                                     DeviceCodeProvider::Anthropic => "Anthropic (Claude)",
+                                    DeviceCodeProvider::Azure => "Azure OpenAI",
                                 };
                                 let status_text = match token_status {
                                     TokenStatus::Valid => "✓ authenticated",
@@ -5072,8 +5066,16 @@ impl ChatWidget<'_> {
                                     TokenStatus::Expired => "✗ expired",
                                     TokenStatus::NotAuthenticated => "· not authenticated",
                                 };
-                                message
-                                    .push_str(&format!("  {}: {}\n", provider_name, status_text));
+                                match account_label {
+                                    Some(label) => message.push_str(&format!(
+                                        "  {} [{}]: {}\n",
+                                        provider_name, label, status_text
+                                    )),
+                                    None => message.push_str(&format!(
+                                        "  {}: {}\n",
+                                        provider_name, status_text
+                                    )),
+                                }
                             }
                             message.push_str("\nUse /auth login <provider> to authenticate");
                         }
@@ -5122,12 +5124,30 @@ impl ChatWidget<'_> {
                             provider: DeviceCodeProvider::Anthropic,
                         });
                     }
+                    Some("azure") => {
+                        // Check if Azure OAuth client is configured
+                        if std::env::var("AZURE_OAUTH_CLIENT_ID").is_err() {
+                            show_message(
+                                self,
+                                String::from(
+                                    "Azure OAuth requires configuration.\n\n\
+                                 Set AZURE_OAUTH_CLIENT_ID (and optionally AZURE_TENANT_ID).\n\
+                                 Register a public client app at:\n\
+                                 https://portal.azure.com (Entra ID > App registrations)",
+                                ),
+                            );
+                        } else {
+                            self.app_event_tx.send(AppEvent::DeviceCodeLoginStart {
+                                provider: DeviceCodeProvider::Azure,
+                            });
+                        }
+                    }
                     _ => {
                         show_message(
                             self,
                             String::from(
                                 "Usage: /auth login <provider>\n\
-                             Providers: openai, google, anthropic (or gemini, claude)",
+                             Providers: openai, google, anthropic, azure (or gemini, claude)",
                             ),
                         );
                     }
@@ -5135,61 +5155,21 @@ impl ChatWidget<'_> {
             }
             Some("logout") => {
                 let provider = parts.get(1).map(|s| s.to_lowercase());
-                match provider.as_deref() {
-                    Some("openai") => {
-                        if let Ok(storage) = DeviceCodeTokenStorage::new() {
-                            match storage.remove_token(DeviceCodeProvider::OpenAI) {
-                                Ok(()) => {
-                                    show_message(
-                                        self,
-                                        String::from("OpenAI device code token removed."),
-                                    );
-                                    self.update_device_token_status();
-                                }
-                                Err(e) => {
-                                    show_message(self, format!("Failed to remove token: {}", e));
-                                }
-                            }
-                        }
-                    }
-                    Some("google") | Some("gemini") => {
-                        if let Ok(storage) = DeviceCodeTokenStorage::new() {
-                            match storage.remove_token(DeviceCodeProvider::Google) {
-                                Ok(()) => {
-                                    show_message(
-                                        self,
-                                        String::from("Google device code token removed."),
-                                    );
-                                    self.update_device_token_status();
-                                }
-                                Err(e) => {
-                                    show_message(self, format!("Failed to remove token: {}", e));
-                                }
-                            }
-                        }
-                    }
-                    Some("anthropic") | Some("claude") => {
-                        if let Ok(storage) = DeviceCodeTokenStorage::new() {
-                            match storage.remove_token(DeviceCodeProvider::Anthropic) {
-                                Ok(()) => {
-                                    show_message(
-                                        self,
-                                        String::from("Anthropic device code token removed."),
-                                    );
-                                    self.update_device_token_status();
-                                }
-                                Err(e) => {
-                                    show_message(self, format!("Failed to remove token: {}", e));
-                                }
-                            }
-                        }
-                    }
+                let provider = match provider.as_deref() {
+                    Some("openai") => Some(DeviceCodeProvider::OpenAI),
+                    Some("google") | Some("gemini") => Some(DeviceCodeProvider::Google),
+                    Some("anthropic") | Some("claude") => Some(DeviceCodeProvider::Anthropic),
+                    Some("azure") => Some(DeviceCodeProvider::Azure),
+                    _ => None,
+                };
+                match provider {
+                    Some(provider) => self.start_device_code_logout(provider),
                     _ => {
                         show_message(
                             self,
                             String::from(
                                 "Usage: /auth logout <provider>\n\
-                             Providers: openai, google, anthropic (or gemini, claude)",
+                             Providers: openai, google, anthropic, azure (or gemini, claude)",
                             ),
                         );
                     }
@@ -5301,33 +5281,13 @@ impl ChatWidget<'_> {
         let tx = self.app_event_tx.clone();
         let provider_clone = provider;
         tokio::spawn(async move {
-            use codex_login::{
-                AnthropicDeviceCode, DeviceCodeAuth, GoogleDeviceCode, OpenAIDeviceCode,
-            };
-
             let result: Result<codex_login::DeviceAuthorizationResponse, String> =
-                match provider_clone {
-                    codex_login::DeviceCodeProvider::OpenAI => {
-                        let client = OpenAIDeviceCode::new();
-                        client
-                            .start_device_authorization()
-                            .await
-                            .map_err(|e| e.to_string())
-                    }
-                    codex_login::DeviceCodeProvider::Google => match GoogleDeviceCode::from_env() {
-                        Ok(client) => client
-                            .start_device_authorization()
-                            .await
-                            .map_err(|e| e.to_string()),
-                        Err(e) => Err(e.to_string()),
-                    },
-                    codex_login::DeviceCodeProvider::Anthropic => {
-                        let client = AnthropicDeviceCode::new();
-                        client
-                            .start_device_authorization()
-                            .await
-                            .map_err(|e| e.to_string())
-                    }
+                match device_code_client(provider_clone) {
+                    Ok(client) => client
+                        .start_device_authorization()
+                        .await
+                        .map_err(|e| e.to_string()),
+                    Err(e) => Err(e),
                 };
 
             match result {
@@ -5352,6 +5312,50 @@ impl ChatWidget<'_> {
         });
     }
 
+    /// Log out of a device code provider: revoke the token with the provider,
+    /// then delete it locally
+    fn start_device_code_logout(&mut self, provider: codex_login::DeviceCodeProvider) {
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = match codex_login::DeviceCodeTokenStorage::new() {
+                Ok(storage) => match device_code_client(provider) {
+                    Ok(client) => storage.logout(client.as_ref()).await,
+                    // Without a configured client there is nothing to revoke
+                    // with; still remove the local token.
+                    Err(e) => {
+                        tracing::warn!("Skipping token revocation for {provider}: {e}");
+                        storage.remove_token(provider)
+                    }
+                }
+                .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            tx.send(AppEvent::DeviceCodeLogoutComplete { provider, result });
+        });
+    }
+
+    /// Report the outcome of `/auth logout`
+    pub(crate) fn on_device_code_logout_complete(
+        &mut self,
+        provider: codex_login::DeviceCodeProvider,
+        result: Result<(), String>,
+    ) {
+        let message = match result {
+            Ok(()) => format!("Logged out of {}.", provider.display_name()),
+            Err(e) => format!("Failed to log out of {}: {e}", provider.display_name()),
+        };
+        let cell = history_cell::new_background_event(message);
+        self.push_system_cell(
+            cell,
+            SystemPlacement::EndOfCurrent,
+            None,
+            None,
+            "auth:result",
+        );
+        self.update_device_token_status();
+        self.request_redraw();
+    }
+
     /// Handle device code received - update UI and start polling
     pub(crate) fn on_device_code_received(
         &mut self,
@@ -5382,7 +5386,8 @@ impl ChatWidget<'_> {
         tokio::spawn(async move {
             use codex_login::device_code_storage::DeviceCodeTokenStorage;
             use codex_login::{
-                AnthropicDeviceCode, DeviceCodeAuth, GoogleDeviceCode, OpenAIDeviceCode, PollError,
+                AnthropicDeviceCode, AzureDeviceCode, DeviceCodeAuth, GoogleDeviceCode,
+                OpenAIDeviceCode, PollError,
             };
 
             let mut poll_count = 0u32;
@@ -5419,6 +5424,10 @@ impl ChatWidget<'_> {
                         let client = AnthropicDeviceCode::new();
                         client.poll_for_token(&device_code_clone).await
                     }
+                    codex_login::DeviceCodeProvider::Azure => match AzureDeviceCode::from_env() {
+                        Ok(client) => client.poll_for_token(&device_code_clone).await,
+                        Err(e) => Err(PollError::Server(e.to_string())),
+                    },
                 };
 
                 match poll_result {
@@ -9015,6 +9024,28 @@ impl ChatWidget<'_> {
     // removed legacy ensure_stream_order_key; strict variant is used instead
 }
 
+/// Device code client for `provider`, or why it is not configured
+fn device_code_client(
+    provider: codex_login::DeviceCodeProvider,
+) -> Result<Box<dyn codex_login::DeviceCodeAuth>, String> {
+    use codex_login::{
+        AnthropicDeviceCode, AzureDeviceCode, DeviceCodeAuth, DeviceCodeProvider, GoogleDeviceCode,
+        OpenAIDeviceCode,
+    };
+
+    let client: Box<dyn DeviceCodeAuth> = match provider {
+        DeviceCodeProvider::OpenAI => Box::new(OpenAIDeviceCode::new()),
+        DeviceCodeProvider::Google => {
+            Box::new(GoogleDeviceCode::from_env().map_err(|e| e.to_string())?)
+        }
+        DeviceCodeProvider::Anthropic => Box::new(AnthropicDeviceCode::new()),
+        DeviceCodeProvider::Azure => {
+            Box::new(AzureDeviceCode::from_env().map_err(|e| e.to_string())?)
+        }
+    };
+    Ok(client)
+}
+
 #[cfg(test)]
 mod tests {
     // SPEC-957: Allow print statements in test code for debugging
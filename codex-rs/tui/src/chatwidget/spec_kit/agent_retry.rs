@@ -13,8 +13,7 @@
 use codex_spec_kit::retry::classifier::{
     ErrorClass, PermanentError, RetryClassifiable, RetryableError,
 };
-use codex_spec_kit::retry::strategy::RetryConfig;
-use rand::Rng;
+use codex_spec_kit::retry::strategy::{RetryConfig, retry_delay};
 use std::time::Duration;
 use thiserror::Error;
 
@@ -50,8 +49,8 @@ impl RetryClassifiable for AgentError {
                 if msg_lower.contains("timeout") || msg_lower.contains("timed out") {
                     ErrorClass::Retryable(RetryableError::NetworkTimeout(30))
                 } else if msg_lower.contains("rate limit") || msg_lower.contains("429") {
-                    // Extract retry_after if available (default 60s)
-                    ErrorClass::Retryable(RetryableError::RateLimitExceeded { retry_after: 60 })
+                    // No Retry-After in the message: retry on the shared backoff
+                    ErrorClass::Retryable(RetryableError::RateLimitExceeded { retry_after: None })
                 } else if msg_lower.contains("service unavailable")
                     || msg_lower.contains("503")
                     || msg_lower.contains("502")
@@ -99,9 +98,7 @@ impl RetryClassifiable for AgentError {
     fn suggested_backoff(&self) -> Option<Duration> {
         match self.classify() {
             ErrorClass::Retryable(ref err) => match err {
-                RetryableError::RateLimitExceeded { retry_after } => {
-                    Some(Duration::from_secs(*retry_after))
-                }
+                RetryableError::RateLimitExceeded { retry_after } => *retry_after,
                 RetryableError::NetworkTimeout(_) => Some(Duration::from_secs(5)),
                 RetryableError::ServiceUnavailable => Some(Duration::from_secs(10)),
                 RetryableError::ConnectionRefused => Some(Duration::from_secs(2)),
@@ -139,13 +136,14 @@ where
         backoff_multiplier: 2.0,
         jitter_factor: 0.5,
         max_elapsed_ms: None, // Use max_attempts only
+        ..Default::default()
     };
 
     let agent_name = agent_name.to_string();
     let mut attempt = 0;
 
-    // Manual retry loop (execute_with_backoff requires Fn, we have FnMut)
-    let mut backoff_ms = config.initial_backoff_ms;
+    // Manual retry loop so each attempt can be logged and fault-injected;
+    // delays come from the shared retry strategy
 
     loop {
         attempt += 1;
@@ -228,23 +226,18 @@ where
                     )));
                 }
 
-                // Apply backoff with jitter
-                let jitter_range = (backoff_ms as f64 * config.jitter_factor) as u64;
-                let jitter = rand::rng().random_range(0..=jitter_range);
-                let actual_backoff = backoff_ms + jitter;
+                let (delay, is_rate_limit) =
+                    retry_delay(&agent_error, &config, attempt as u32, &mut rand::rng());
 
                 tracing::info!(
                     agent = agent_name,
-                    backoff_ms = actual_backoff,
+                    backoff_ms = delay.as_millis() as u64,
                     attempt = attempt,
+                    is_rate_limit = is_rate_limit,
                     "Backing off before retry"
                 );
 
-                tokio::time::sleep(Duration::from_millis(actual_backoff)).await;
-
-                // Exponential backoff for next attempt
-                backoff_ms = ((backoff_ms as f64 * config.backoff_multiplier) as u64)
-                    .min(config.max_backoff_ms);
+                tokio::time::sleep(delay).await;
             }
         }
     }
@@ -282,9 +275,10 @@ mod tests {
 
     #[test]
     fn test_suggested_backoff_rate_limit() {
+        // Without a Retry-After the shared backoff decides the wait
         let err = AgentError::SpawnFailed("429 Rate Limit".to_string());
-        assert!(err.suggested_backoff().is_some());
-        assert_eq!(err.suggested_backoff().unwrap(), Duration::from_secs(60));
+        assert!(err.is_retryable());
+        assert_eq!(err.suggested_backoff(), None);
     }
 
     #[test]
@@ -157,6 +157,7 @@ pub static SPEC_KIT_REGISTRY: Lazy<Mutex<CommandRegistry>> = Lazy::new(|| {
     registry.register(Box::new(SpecKitCancelCommand)); // SPEC-DOGFOOD-001
     registry.register(Box::new(SpecKitStatusCommand));
     registry.register(Box::new(SpecKitConfigureCommand)); // SPEC-947 Phase 4
+    registry.register(Box::new(SpecKitDbRepairCommand));
     registry.register(Box::new(SpecKitStageAgentsCommand)); // SPEC-KIT-983: Stage→agent defaults
     registry.register(Box::new(SpecKitProjectCommand)); // SPEC-KIT-960
     registry.register(Box::new(SpecKitProjectNewCommand)); // /speckit.projectnew - full project setup
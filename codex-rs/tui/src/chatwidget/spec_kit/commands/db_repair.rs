@@ -0,0 +1,54 @@
+//! /speckit.db-repair command implementation
+//!
+//! Rebuilds the consensus database after a failed open found it damaged.
+//! The damaged file is kept next to the rebuilt one as a backup.
+
+use super::super::super::ChatWidget;
+use super::super::command_registry::SpecKitCommand;
+use super::super::consensus_db::ConsensusDb;
+use crate::history_cell;
+
+/// Command: /speckit.db-repair
+/// Back up and rebuild a damaged consensus database
+pub struct SpecKitDbRepairCommand;
+
+impl SpecKitCommand for SpecKitDbRepairCommand {
+    fn name(&self) -> &'static str {
+        "speckit.db-repair"
+    }
+
+    fn description(&self) -> &'static str {
+        "back up and rebuild a damaged consensus database"
+    }
+
+    fn execute(&self, widget: &mut ChatWidget, _args: String) {
+        match ConsensusDb::repair_default() {
+            Ok(outcome) => {
+                let mut message = format!(
+                    "Consensus database rebuilt: {} rows recovered, damaged file kept at {}",
+                    outcome.rows_recovered,
+                    outcome.backup_path.display()
+                );
+                if !outcome.incomplete.is_empty() {
+                    message.push_str(&format!(
+                        "\nNot fully restored: {}",
+                        outcome.incomplete.join(", ")
+                    ));
+                }
+                if !outcome.report.is_ok() {
+                    message.push_str(&format!("\n{}", outcome.report.summary()));
+                }
+                widget.history_push(history_cell::new_background_event(message));
+            }
+            Err(err) => {
+                widget.history_push(history_cell::new_error_event(format!(
+                    "Failed to repair consensus database: {err}"
+                )));
+            }
+        }
+    }
+
+    fn is_prompt_expanding(&self) -> bool {
+        false
+    }
+}
@@ -8,6 +8,7 @@
 mod cancel; // SPEC-DOGFOOD-001: Cancel stale pipeline state
 mod capsule; // SPEC-KIT-971: Memvid capsule commands
 mod configure; // SPEC-947 Phase 4: Pipeline configurator command
+mod db_repair;
 mod guardrail;
 mod intel; // SPEC-KIT-2XX: Project Intel for NotebookLM
 mod librarian; // SPEC-KIT-103: Librarian memory quality engine
@@ -32,6 +33,7 @@ pub mod verify;
 pub use cancel::*;
 pub use capsule::*;
 pub use configure::*;
+pub use db_repair::*;
 pub use guardrail::*;
 pub use intel::*;
 pub use librarian::*;
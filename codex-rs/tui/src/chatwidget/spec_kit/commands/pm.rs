@@ -19,6 +19,7 @@ use std::path::PathBuf;
 
 const SOCKET_FILENAME: &str = "codex-pm.sock";
 const PROTOCOL_VERSION: &str = "1.0";
+const TOKEN_ENV: &str = "CODEX_PM_TOKEN";
 
 fn default_socket_path() -> PathBuf {
     if let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") {
@@ -61,6 +62,7 @@ fn connect_and_handshake() -> Result<
         "params": {
             "protocol_version": PROTOCOL_VERSION,
             "client_version": env!("CARGO_PKG_VERSION"),
+            "token": std::env::var(TOKEN_ENV).ok().filter(|t| !t.is_empty()),
         }
     });
 
@@ -17,7 +17,7 @@
 //! All SQLite operations wrapped with exponential backoff retry to handle
 //! SQLITE_BUSY and SQLITE_LOCKED errors gracefully.
 
-use codex_core::db::DbError;
+use codex_core::db::{DbError, IntegrityReport, RepairOutcome};
 use codex_spec_kit::retry::strategy::{
     RetryConfig, execute_with_backoff, execute_with_backoff_sync,
 };
@@ -43,6 +43,40 @@ pub struct ConsensusArtifact {
     pub created_at: String,
 }
 
+/// A database that failed to open and did not pass the integrity check
+#[derive(Debug, Clone)]
+pub struct DamagedDb {
+    pub path: PathBuf,
+    pub report: IntegrityReport,
+}
+
+/// Damage found on the last failed open, waiting to be shown to the user
+static DAMAGED_DB: Mutex<Option<DamagedDb>> = Mutex::new(None);
+
+/// Take the damage report left by a failed open, if any
+pub fn take_damaged_db() -> Option<DamagedDb> {
+    DAMAGED_DB.lock().ok()?.take()
+}
+
+/// Check the database after a failed open and keep the report for the TUI
+/// to prompt for repair; failures on a healthy file are left to the caller
+fn check_after_failed_open(db_path: &Path) {
+    let Ok(mut pending) = DAMAGED_DB.lock() else {
+        return;
+    };
+    if pending.as_ref().is_some_and(|d| d.path == db_path) {
+        return;
+    }
+    let report = codex_core::db::check_integrity_at(db_path);
+    if !report.is_ok() {
+        warn!("{} ({})", report.summary(), db_path.display());
+        *pending = Some(DamagedDb {
+            path: db_path.to_path_buf(),
+            report,
+        });
+    }
+}
+
 /// Thread-safe database connection pool
 ///
 /// SPEC-945B Dual-Write: Contains both old (single connection) and new (connection pool)
@@ -76,6 +110,21 @@ impl ConsensusDb {
     /// SPEC-945B Phase 1 Complete: Uses new schema only (consensus_runs + agent_outputs).
     /// Connection pool with WAL mode provides optimized concurrent access.
     pub fn init(db_path: &Path) -> SqlResult<Self> {
+        Self::open(db_path).inspect_err(|_| check_after_failed_open(db_path))
+    }
+
+    /// Back up the damaged default database and rebuild it from what is
+    /// still readable
+    pub fn repair_default() -> Result<RepairOutcome, DbError> {
+        let db_path = Self::default_db_path()?;
+        let outcome = codex_core::db::attempt_repair(&db_path)?;
+        if let Ok(mut pending) = DAMAGED_DB.lock() {
+            pending.take_if(|d| d.path == db_path);
+        }
+        Ok(outcome)
+    }
+
+    fn open(db_path: &Path) -> SqlResult<Self> {
         let conn = Connection::open(db_path)?;
 
         // Agent execution tracking table (for definitive routing)
@@ -146,23 +195,40 @@ impl ConsensusDb {
                     Ok(mut conn) => {
                         if let Err(e) = codex_core::db::migrations::migrate_to_latest(&mut conn) {
                             warn!("Failed to migrate new schema: {}", e);
+                            check_after_failed_open(db_path);
                             return None;
                         }
                         Some(pool)
                     }
                     Err(e) => {
                         warn!("Failed to get connection from pool: {}", e);
+                        check_after_failed_open(db_path);
                         None
                     }
                 }
             }
             Err(e) => {
                 warn!("Failed to initialize new schema pool: {}", e);
+                check_after_failed_open(db_path);
                 None
             }
         }
     }
 
+    /// Truncate the WAL if it has grown past the threshold; runs when a
+    /// handle is dropped, which is when callers are done with the database
+    fn checkpoint_if_large(&self) {
+        let Ok(conn) = self.conn.try_lock() else {
+            return;
+        };
+        if let Err(e) = codex_core::db::maybe_checkpoint(
+            &conn,
+            codex_core::db::checkpoint::DEFAULT_WAL_CHECKPOINT_THRESHOLD_BYTES,
+        ) {
+            warn!("WAL checkpoint failed: {}", e);
+        }
+    }
+
     /// Store agent artifact (from cached response)
     ///
     /// SPEC-945B Phase 1 Complete: Writes to new schema (consensus_runs + agent_outputs).
@@ -202,6 +268,7 @@ impl ConsensusDb {
             backoff_multiplier: 1.5,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         // Write to NEW schema using async wrapper with retry
@@ -278,6 +345,7 @@ impl ConsensusDb {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         // Use Runtime::new() to avoid nested runtime issues
@@ -371,6 +439,7 @@ impl ConsensusDb {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         // Use Runtime::new() to avoid nested runtime issues
@@ -482,6 +551,7 @@ impl ConsensusDb {
             backoff_multiplier: 1.5,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         // Write to NEW schema using async wrapper with retry
@@ -558,6 +628,7 @@ impl ConsensusDb {
             backoff_multiplier: 1.5,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         // Clone parameters for move into closure
@@ -605,6 +676,7 @@ impl ConsensusDb {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         let agent_id = agent_id.to_string();
@@ -644,6 +716,7 @@ impl ConsensusDb {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         let agent_id = agent_id.to_string();
@@ -683,6 +756,7 @@ impl ConsensusDb {
             backoff_multiplier: 1.5,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         let agent_id = agent_id.to_string();
@@ -728,6 +802,7 @@ impl ConsensusDb {
             backoff_multiplier: 1.5,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         let agent_id = agent_id.to_string();
@@ -773,6 +848,7 @@ impl ConsensusDb {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         let spec_id = spec_id.to_string();
@@ -823,6 +899,7 @@ impl ConsensusDb {
             backoff_multiplier: 1.5,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         execute_with_backoff_sync(
@@ -881,6 +958,7 @@ impl ConsensusDb {
             backoff_multiplier: 1.5,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         // Write to NEW schema using async wrapper with retry
@@ -948,6 +1026,7 @@ impl ConsensusDb {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         let spec_id = spec_id.to_string();
@@ -1010,6 +1089,7 @@ impl ConsensusDb {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None,
+            ..Default::default()
         };
 
         let agent_id = agent_id.to_string();
@@ -1045,6 +1125,12 @@ impl ConsensusDb {
     }
 }
 
+impl Drop for ConsensusDb {
+    fn drop(&mut self) {
+        self.checkpoint_if_large();
+    }
+}
+
 /// Agent execution record for branch filtering
 #[derive(Debug, Clone)]
 pub struct AgentExecution {
@@ -14,8 +14,8 @@ use std::sync::{Arc, Mutex};
 use crate::spec_prompts::SpecStage;
 
 /// Model pricing rates (USD per 1M tokens)
-/// Updated: 2025-11-19 from official pricing pages
-/// Sources: ai.google.dev/pricing, claude.com/pricing, platform.openai.com/docs/pricing
+///
+/// Rates come from the shared table in `codex_core::model_prices`.
 #[derive(Debug, Clone)]
 pub struct ModelPricing {
     pub input_per_million: f64,
@@ -25,97 +25,13 @@ pub struct ModelPricing {
 impl ModelPricing {
     /// Get pricing for a model by name
     pub fn for_model(model: &str) -> Self {
-        match model {
-            // Claude models (Updated 2025-11-19)
-            // Source: claude.com/pricing
-            "claude-haiku" | "claude-haiku-4.5" | "claude-haiku-3.5" | "haiku" => Self {
-                input_per_million: 1.0,  // Was 0.25 (4x increase!)
-                output_per_million: 5.0, // Was 1.25 (4x increase!)
+        match codex_core::model_prices::model_prices(model) {
+            Some(prices) => Self {
+                input_per_million: prices.input_per_million,
+                output_per_million: prices.output_per_million,
             },
-            "claude-sonnet" | "claude-sonnet-4.5" | "claude-sonnet-4" | "sonnet" => Self {
-                input_per_million: 3.0,
-                output_per_million: 15.0,
-            },
-            "claude-opus" | "claude-opus-4.5" | "claude-opus-4.1" | "claude-opus-4" | "opus" => {
-                Self {
-                    input_per_million: 15.0,
-                    output_per_million: 75.0,
-                }
-            }
-
-            // Gemini models (Updated 2025-11-19)
-            // Source: ai.google.dev/pricing
-            // Gemini 3 family (Released 2025-11-18)
-            "gemini-3-pro" | "gemini-3.0-pro" => Self {
-                input_per_million: 2.0,   // Standard pricing (≤200k tokens)
-                output_per_million: 12.0, // Top LMArena model (1501 Elo)
-            },
-            // Note: Gemini 3 Deep Think not yet publicly priced, rolling out to AI Ultra
-
-            // Gemini 2.5 family
-            "gemini-2.5-flash" | "gemini-flash-2.5" | "flash-2.5" => Self {
-                input_per_million: 0.30,  // Was 0.10 (3x increase!)
-                output_per_million: 2.50, // Was 0.40 (6.25x increase!)
-            },
-            "gemini-2.0-flash" | "flash-2.0" => Self {
-                input_per_million: 0.30,
-                output_per_million: 2.50,
-            },
-            "gemini-1.5-flash" | "flash-1.5" | "flash" => Self {
-                input_per_million: 0.075,
-                output_per_million: 0.30,
-            },
-            "gemini-2.5-flash-lite" | "flash-lite" => Self {
-                input_per_million: 0.05,
-                output_per_million: 0.20,
-            },
-            "gemini-2.5-pro" | "gemini-pro-2.5" => Self {
-                input_per_million: 1.25,
-                output_per_million: 10.0, // Was 5.0 (2x increase!)
-            },
-            "gemini-1.5-pro" | "gemini-pro-1.5" | "gemini-pro" => Self {
-                input_per_million: 1.25,
-                output_per_million: 10.0, // Updated to match 2.5-pro
-            },
-
-            // OpenAI models (Updated 2025-11-19)
-            // Source: platform.openai.com/docs/pricing
-
-            // GPT-5 family (Released Aug 2025, GPT-5.1 Nov 2025)
-            "gpt-5" | "gpt-5.1" | "gpt5_1" | "gpt5_1_minimal" | "gpt-5.1-minimal"
-            | "gpt-5.1-instant" => Self {
-                input_per_million: 1.25,  // Was 10.0 (estimate)
-                output_per_million: 10.0, // Was 30.0 (estimate)
-            },
-            "gpt-5-mini" | "gpt5_1_mini" => Self {
-                input_per_million: 0.25,
-                output_per_million: 2.0,
-            },
-            "gpt-5-codex" | "gpt5_1_codex" | "gpt-5.1-codex" => Self {
-                input_per_million: 1.25, // Same as GPT-5.1 (codex variant)
-                output_per_million: 10.0,
-            },
-
-            // GPT-4 family (legacy/deprecated - kept for compatibility)
-            "gpt-4o" => Self {
-                input_per_million: 2.50,
-                output_per_million: 10.0,
-            },
-            "gpt-4o-mini" => Self {
-                input_per_million: 0.15,
-                output_per_million: 0.60,
-            },
-            "gpt-4-turbo" | "gpt-4" => Self {
-                input_per_million: 10.0,
-                output_per_million: 30.0,
-            },
-            "gpt-3.5-turbo" => Self {
-                input_per_million: 0.50,
-                output_per_million: 1.50,
-            },
-
             // Unknown model - use expensive default for safety
-            _ => Self {
+            None => Self {
                 input_per_million: 10.0,
                 output_per_million: 30.0,
             },
@@ -375,9 +375,7 @@ impl RetryClassifiable for SpecKitError {
     fn suggested_backoff(&self) -> Option<Duration> {
         match self.classify() {
             ErrorClass::Retryable(ref err) => match err {
-                RetryableError::RateLimitExceeded { retry_after } => {
-                    Some(Duration::from_secs(*retry_after))
-                }
+                RetryableError::RateLimitExceeded { retry_after } => *retry_after,
                 RetryableError::DatabaseLocked => Some(Duration::from_millis(200)),
                 RetryableError::NetworkTimeout(_) | RetryableError::ConnectionRefused => None,
                 RetryableError::ServiceUnavailable => Some(Duration::from_secs(5)),
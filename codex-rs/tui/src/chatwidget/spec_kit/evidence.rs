@@ -177,6 +177,7 @@ impl FilesystemEvidence {
             backoff_multiplier: 2.0,
             jitter_factor: 0.5,
             max_elapsed_ms: None, // Use max_attempts only
+            ..Default::default()
         };
 
         // Lock auto-released when lock_file drops (RAII)
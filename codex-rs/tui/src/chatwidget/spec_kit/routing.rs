@@ -7,6 +7,7 @@
 
 use super::super::ChatWidget;
 use super::command_registry::SPEC_KIT_REGISTRY;
+use super::consensus_db;
 use super::subagent_defaults;
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
@@ -99,6 +100,17 @@ pub fn try_dispatch_spec_kit_command(
         return false;
     };
 
+    // A failed database open found damage: offer the repair command
+    if spec_cmd.name() != "speckit.db-repair"
+        && let Some(damaged) = consensus_db::take_damaged_db()
+    {
+        widget.history_push(crate::history_cell::new_warning_event(format!(
+            "{} ({}). Run /speckit.db-repair to back it up and rebuild it.",
+            damaged.report.summary(),
+            damaged.path.display()
+        )));
+    }
+
     // Extract arguments (everything after command name)
     let args = command_text
         .trim_start_matches('/')
@@ -124,6 +136,7 @@ pub fn try_dispatch_spec_kit_command(
         | "speckit.ace-status"     // ACE playbook status
         | "speckit.stage-agents"   // SPEC-KIT-983: Stage→agent UI
         | "speckit.pm"             // SPEC-PM-004: PM overlay + service commands
+        | "speckit.db-repair"      // Consensus DB corruption repair
         | "pm"                     // SPEC-PM-004: PM alias
         // Legacy aliases
         | "spec-auto"
@@ -784,8 +784,13 @@ pub fn run_stage0_for_spec(
                 },
             );
 
-            // If tier2 was used, clear the skip reason
-            let final_tier2_skip = if tier2_used { None } else { tier2_skip_reason };
+            // If tier2 was used, clear the skip reason; otherwise fall back to the
+            // engine's own health probe so the notice says why it was skipped
+            let final_tier2_skip = if tier2_used {
+                None
+            } else {
+                tier2_skip_reason.or_else(|| result.tier2_skip_reason.clone())
+            };
 
             // ADR-003 Prompt F: Post-curation (background thread, non-blocking)
             // Curate Tier2 output into codex-product if enabled and tier2 was used
@@ -324,6 +324,8 @@ impl ChatWidget<'_> {
         self.request_redraw();
 
         tokio::spawn(async move {
+            use codex_core::git_info::GitWorktreeStatus;
+            use codex_core::git_info::worktree_status;
             use tokio::process::Command;
 
             fn send_background(tx: &AppEventSender, message: String) {
@@ -334,6 +336,30 @@ impl ChatWidget<'_> {
                 tx.send_background_event(message);
             }
 
+            /// One-line status for the agent handoff prompt.
+            fn describe_status(status: Option<&GitWorktreeStatus>) -> String {
+                let Some(status) = status else {
+                    return "status unavailable".to_string();
+                };
+                let branch = status.branch.as_deref().unwrap_or("detached HEAD");
+                let mut text = format!("{branch}: ");
+                if status.is_clean() {
+                    text.push_str("clean");
+                } else {
+                    text.push_str(&format!(
+                        "{} staged, {} unstaged, {} untracked",
+                        status.staged, status.unstaged, status.untracked
+                    ));
+                }
+                if status.ahead > 0 || status.behind > 0 {
+                    text.push_str(&format!(
+                        " (ahead {}, behind {})",
+                        status.ahead, status.behind
+                    ));
+                }
+                text
+            }
+
             let git_root = match codex_core::git_info::resolve_root_git_project_for_trust(&work_cwd)
             {
                 Some(p) => p,
@@ -358,31 +384,13 @@ impl ChatWidget<'_> {
                 }
             };
 
-            let worktree_status_raw = ChatWidget::git_short_status(&work_cwd).await;
-            let worktree_status_for_agent = match &worktree_status_raw {
-                Ok(s) if s.trim().is_empty() => "clean".to_string(),
-                Ok(s) => s.clone(),
-                Err(err) => format!("status unavailable: {}", err),
-            };
-            let worktree_dirty = matches!(&worktree_status_raw, Ok(s) if !s.trim().is_empty());
-
-            let worktree_diff_stat = if worktree_dirty {
-                ChatWidget::git_diff_stat(&work_cwd)
-                    .await
-                    .ok()
-                    .map(|d| d.trim().to_string())
-                    .filter(|d| !d.is_empty())
-            } else {
-                None
-            };
+            let worktree_summary = worktree_status(&work_cwd).await;
+            let worktree_status_for_agent = describe_status(worktree_summary.as_ref());
+            let worktree_dirty = worktree_summary.as_ref().is_some_and(|s| !s.is_clean());
 
-            let repo_status_raw = ChatWidget::git_short_status(&git_root).await;
-            let repo_status_for_agent = match &repo_status_raw {
-                Ok(s) if s.trim().is_empty() => "clean".to_string(),
-                Ok(s) => s.clone(),
-                Err(err) => format!("status unavailable: {}", err),
-            };
-            let repo_dirty = matches!(&repo_status_raw, Ok(s) if !s.trim().is_empty());
+            let repo_summary = worktree_status(&git_root).await;
+            let repo_status_for_agent = describe_status(repo_summary.as_ref());
+            let repo_dirty = repo_summary.as_ref().is_some_and(|s| !s.is_clean());
 
             let default_branch_opt =
                 codex_core::git_worktree::detect_default_branch(&git_root).await;
@@ -391,14 +399,17 @@ impl ChatWidget<'_> {
                 .unwrap_or_else(|| "<detect default branch>".to_string());
 
             let mut handoff_reasons: Vec<String> = Vec::new();
-            if let Err(err) = &worktree_status_raw {
-                handoff_reasons.push(format!("unable to read worktree status: {}", err));
+            if worktree_summary.is_none() {
+                handoff_reasons.push("unable to read worktree status".to_string());
             }
-            if worktree_dirty {
-                handoff_reasons.push("worktree has uncommitted changes".to_string());
+            if let Some(summary) = worktree_summary.as_ref().filter(|_| worktree_dirty) {
+                handoff_reasons.push(format!(
+                    "worktree has uncommitted changes ({} staged, {} unstaged, {} untracked)",
+                    summary.staged, summary.unstaged, summary.untracked
+                ));
             }
-            if let Err(err) = &repo_status_raw {
-                handoff_reasons.push(format!("unable to read repo status: {}", err));
+            if repo_summary.is_none() {
+                handoff_reasons.push("unable to read repo status".to_string());
             }
             if repo_dirty {
                 handoff_reasons.push("default branch checkout has uncommitted changes".to_string());
@@ -412,58 +423,52 @@ impl ChatWidget<'_> {
             let worktree_display = work_cwd.display().to_string();
             let tx_for_switch = tx.clone();
             let git_root_for_switch = git_root.clone();
-            let send_agent_handoff =
-                |mut reasons: Vec<String>,
-                 extra_note: Option<String>,
-                 worktree_status: String,
-                 repo_status: String,
-                 worktree_diff: Option<String>| {
-                    if reasons.is_empty() {
-                        reasons.push("manual follow-up requested".to_string());
-                    }
-                    let reason_text = reasons.join(", ");
-                    send_background(
-                        &tx,
-                        format!("`/merge` — handing off to agent ({})", reason_text),
-                    );
-                    let mut preface = format!(
-                        "[developer] Non-trivial git state detected while finalizing the branch. Reasons: {}.\n\nRepository context:\n- Repo root: {}\n- Worktree: {}\n- Branch to merge: {}\n- Default branch target: {}\n\nCurrent git status:\nWorktree status:\n{}\n\nRepo root status:\n{}\n\nRequired actions:\n1. cd {}\n   - Inspect status. Review the diff summary below and stage/commit only the changes that belong in this merge (`git add -A` + `git commit -m \"merge {} via /merge\"`). Stash or drop anything that should stay local.\n2. git fetch origin {}\n3. Merge the default branch into the worktree branch (`git merge origin/{}`) and resolve conflicts.\n4. cd {}\n   - Ensure the local {} branch exists (create tracking branch if needed). If checkout complains about local changes, stash safely, then checkout and pop/apply before finishing.\n5. Merge {} into {} from {} (`git merge --no-ff {}`) and resolve conflicts.\n6. Remove the worktree (`git worktree remove {} --force`) and delete the branch (`git branch -D {}`).\n7. End inside {} with a clean working tree and no leftover stashes. Pop/apply anything you created.\n\nReport back with a concise summary of the steps or explain any blockers.",
-                        reason_text,
-                        root_display,
-                        worktree_display,
-                        branch_label,
-                        default_branch_hint,
-                        worktree_status,
-                        repo_status,
-                        worktree_display,
-                        branch_label,
-                        default_branch_hint,
-                        default_branch_hint,
-                        root_display,
-                        default_branch_hint,
-                        branch_label,
-                        default_branch_hint,
-                        root_display,
-                        branch_label,
-                        worktree_display,
-                        branch_label,
-                        root_display
-                    );
-                    if let Some(note) = extra_note {
-                        preface.push_str("\n\nAdditional notes:\n");
-                        preface.push_str(&note);
-                    }
-                    if let Some(diff) = worktree_diff {
-                        preface.push_str("\n\nWorktree diff summary:\n");
-                        preface.push_str(&diff);
-                    }
-                    let visible = format!(
-                        "Finalize branch '{}' via /merge (agent handoff)",
-                        branch_label
-                    );
-                    tx_for_switch.send(AppEvent::SwitchCwd(git_root_for_switch.clone(), None));
-                    tx.send(AppEvent::SubmitTextWithPreface { visible, preface });
-                };
+            let send_agent_handoff = |mut reasons: Vec<String>,
+                                      extra_note: Option<String>,
+                                      worktree_status: String,
+                                      repo_status: String| {
+                if reasons.is_empty() {
+                    reasons.push("manual follow-up requested".to_string());
+                }
+                let reason_text = reasons.join(", ");
+                send_background(
+                    &tx,
+                    format!("`/merge` — handing off to agent ({})", reason_text),
+                );
+                let mut preface = format!(
+                    "[developer] Non-trivial git state detected while finalizing the branch. Reasons: {}.\n\nRepository context:\n- Repo root: {}\n- Worktree: {}\n- Branch to merge: {}\n- Default branch target: {}\n\nCurrent git status:\nWorktree status:\n{}\n\nRepo root status:\n{}\n\nRequired actions:\n1. cd {}\n   - Inspect status. Review `git status` and `git diff --stat`, then stage/commit only the changes that belong in this merge (`git add -A` + `git commit -m \"merge {} via /merge\"`). Stash or drop anything that should stay local.\n2. git fetch origin {}\n3. Merge the default branch into the worktree branch (`git merge origin/{}`) and resolve conflicts.\n4. cd {}\n   - Ensure the local {} branch exists (create tracking branch if needed). If checkout complains about local changes, stash safely, then checkout and pop/apply before finishing.\n5. Merge {} into {} from {} (`git merge --no-ff {}`) and resolve conflicts.\n6. Remove the worktree (`git worktree remove {} --force`) and delete the branch (`git branch -D {}`).\n7. End inside {} with a clean working tree and no leftover stashes. Pop/apply anything you created.\n\nReport back with a concise summary of the steps or explain any blockers.",
+                    reason_text,
+                    root_display,
+                    worktree_display,
+                    branch_label,
+                    default_branch_hint,
+                    worktree_status,
+                    repo_status,
+                    worktree_display,
+                    branch_label,
+                    default_branch_hint,
+                    default_branch_hint,
+                    root_display,
+                    default_branch_hint,
+                    branch_label,
+                    default_branch_hint,
+                    root_display,
+                    branch_label,
+                    worktree_display,
+                    branch_label,
+                    root_display
+                );
+                if let Some(note) = extra_note {
+                    preface.push_str("\n\nAdditional notes:\n");
+                    preface.push_str(&note);
+                }
+                let visible = format!(
+                    "Finalize branch '{}' via /merge (agent handoff)",
+                    branch_label
+                );
+                tx_for_switch.send(AppEvent::SwitchCwd(git_root_for_switch.clone(), None));
+                tx.send(AppEvent::SubmitTextWithPreface { visible, preface });
+            };
 
             if !handoff_reasons.is_empty() {
                 send_agent_handoff(
@@ -471,7 +476,6 @@ impl ChatWidget<'_> {
                     None,
                     worktree_status_for_agent.clone(),
                     repo_status_for_agent.clone(),
-                    worktree_diff_stat.clone(),
                 );
                 return;
             }
@@ -547,22 +551,8 @@ impl ChatWidget<'_> {
                             .output()
                             .await;
                     } else {
-                        let updated_worktree_status = ChatWidget::git_short_status(&work_cwd)
-                            .await
-                            .map(|s| {
-                                if s.trim().is_empty() {
-                                    "clean".to_string()
-                                } else {
-                                    s
-                                }
-                            })
-                            .unwrap_or_else(|err| format!("status unavailable: {}", err));
-                        let updated_diff = ChatWidget::git_diff_stat(&work_cwd)
-                            .await
-                            .ok()
-                            .map(|d| d.trim().to_string())
-                            .filter(|d| !d.is_empty())
-                            .or(worktree_diff_stat.clone());
+                        let updated_worktree_status =
+                            describe_status(worktree_status(&work_cwd).await.as_ref());
                         send_agent_handoff(
                             vec![format!(
                                 "merge conflicts while merging '{}' into '{}'",
@@ -573,7 +563,6 @@ impl ChatWidget<'_> {
                             ),
                             updated_worktree_status,
                             repo_status_for_agent.clone(),
-                            updated_diff,
                         );
                         return;
                     }
@@ -618,22 +607,8 @@ impl ChatWidget<'_> {
                                 .output()
                                 .await;
                         } else {
-                            let updated_worktree_status = ChatWidget::git_short_status(&work_cwd)
-                                .await
-                                .map(|s| {
-                                    if s.trim().is_empty() {
-                                        "clean".to_string()
-                                    } else {
-                                        s
-                                    }
-                                })
-                                .unwrap_or_else(|err| format!("status unavailable: {}", err));
-                            let updated_diff = ChatWidget::git_diff_stat(&work_cwd)
-                                .await
-                                .ok()
-                                .map(|d| d.trim().to_string())
-                                .filter(|d| !d.is_empty())
-                                .or(worktree_diff_stat.clone());
+                            let updated_worktree_status =
+                                describe_status(worktree_status(&work_cwd).await.as_ref());
                             send_agent_handoff(
                                 vec![format!(
                                     "merge conflicts while merging local '{}' into '{}'",
@@ -644,27 +619,12 @@ impl ChatWidget<'_> {
                                 ),
                                 updated_worktree_status,
                                 repo_status_for_agent.clone(),
-                                updated_diff,
                             );
                             return;
                         }
                     } else {
-                        let updated_worktree_status = ChatWidget::git_short_status(&work_cwd)
-                            .await
-                            .map(|s| {
-                                if s.trim().is_empty() {
-                                    "clean".to_string()
-                                } else {
-                                    s
-                                }
-                            })
-                            .unwrap_or_else(|err| format!("status unavailable: {}", err));
-                        let updated_diff = ChatWidget::git_diff_stat(&work_cwd)
-                            .await
-                            .ok()
-                            .map(|d| d.trim().to_string())
-                            .filter(|d| !d.is_empty())
-                            .or(worktree_diff_stat.clone());
+                        let updated_worktree_status =
+                            describe_status(worktree_status(&work_cwd).await.as_ref());
                         send_agent_handoff(
                             vec![format!(
                                 "failed to merge local '{}' into '{}'",
@@ -673,7 +633,6 @@ impl ChatWidget<'_> {
                             None,
                             updated_worktree_status,
                             repo_status_for_agent.clone(),
-                            updated_diff,
                         );
                         return;
                     }
@@ -786,22 +745,8 @@ impl ChatWidget<'_> {
                         }
                     }
 
-                    let updated_repo_status = ChatWidget::git_short_status(&git_root)
-                        .await
-                        .map(|s| {
-                            if s.trim().is_empty() {
-                                "clean".to_string()
-                            } else {
-                                s
-                            }
-                        })
-                        .unwrap_or_else(|err| format!("status unavailable: {}", err));
-                    let updated_diff = ChatWidget::git_diff_stat(&work_cwd)
-                        .await
-                        .ok()
-                        .map(|d| d.trim().to_string())
-                        .filter(|d| !d.is_empty())
-                        .or(worktree_diff_stat.clone());
+                    let updated_repo_status =
+                        describe_status(worktree_status(&git_root).await.as_ref());
 
                     send_agent_handoff(
                         vec![format!(
@@ -811,7 +756,6 @@ impl ChatWidget<'_> {
                         if note.is_empty() { None } else { Some(note) },
                         worktree_status_for_agent.clone(),
                         updated_repo_status,
-                        updated_diff,
                     );
                     return;
                 }
@@ -827,22 +771,8 @@ impl ChatWidget<'_> {
                     .ok()
                     .and_then(|o| String::from_utf8(o.stderr).ok())
                     .unwrap_or_else(|| "unknown error".to_string());
-                let updated_repo_status = ChatWidget::git_short_status(&git_root)
-                    .await
-                    .map(|s| {
-                        if s.trim().is_empty() {
-                            "clean".to_string()
-                        } else {
-                            s
-                        }
-                    })
-                    .unwrap_or_else(|e| format!("status unavailable: {}", e));
-                let updated_diff = ChatWidget::git_diff_stat(&work_cwd)
-                    .await
-                    .ok()
-                    .map(|d| d.trim().to_string())
-                    .filter(|d| !d.is_empty())
-                    .or(worktree_diff_stat.clone());
+                let updated_repo_status =
+                    describe_status(worktree_status(&git_root).await.as_ref());
                 send_agent_handoff(
                     vec![format!(
                         "merge of '{}' into '{}' failed: {}",
@@ -853,7 +783,6 @@ impl ChatWidget<'_> {
                     None,
                     worktree_status_for_agent.clone(),
                     updated_repo_status,
-                    updated_diff,
                 );
                 return;
             }
@@ -1816,6 +1745,9 @@ impl ChatWidget<'_> {
                             startup_timeout_sec: None,
                             startup_timeout_ms: None,
                             tool_timeout_sec: None,
+                            reconnect_attempts: None,
+                            lazy: false,
+                            tools: Vec::new(),
                         };
                         match codex_core::config::add_mcp_server(&home, &name, cfg.clone()) {
                             Ok(()) => {
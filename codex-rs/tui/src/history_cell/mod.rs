@@ -8014,16 +8014,18 @@ pub(crate) fn new_plan_update(update: UpdatePlanArgs) -> PlanUpdateCell {
 
     let mut lines: Vec<Line<'static>> = Vec::new();
     let total = plan.len();
-    let completed = plan
-        .iter()
-        .filter(|p| matches!(p.status, StepStatus::Completed))
-        .count();
+    // Failed steps are finished too: they count toward progress and a plan
+    // whose remaining steps all failed is no longer active.
+    let completed = plan.iter().filter(|p| p.status.is_terminal()).count();
+    let any_failed = plan.iter().any(|p| p.status == StepStatus::Failed);
     let icon = plan_progress_icon(total, completed);
     let is_complete = total > 0 && completed >= total;
-    let header_color = if is_complete {
-        crate::colors::success()
-    } else {
+    let header_color = if !is_complete {
         crate::colors::info()
+    } else if any_failed {
+        crate::colors::error()
+    } else {
+        crate::colors::success()
     };
 
     let width: usize = 10;
@@ -8085,6 +8087,10 @@ pub(crate) fn new_plan_update(update: UpdatePlanArgs) -> PlanUpdateCell {
                     Span::raw("□"),
                     Span::styled(step, Style::default().add_modifier(Modifier::DIM)),
                 ),
+                StepStatus::Failed => (
+                    Span::styled("✘", Style::default().fg(crate::colors::error())),
+                    Span::styled(step, Style::default().add_modifier(Modifier::DIM)),
+                ),
             };
             let prefix = if idx == 0 {
                 Span::raw("└ ")
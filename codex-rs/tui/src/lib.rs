@@ -637,6 +637,19 @@ pub async fn run_main(
         }
     }
 
+    // Prune archived rollouts past the configured retention in the background.
+    if let Some(days) = config.rollout.retention_days {
+        let codex_home = config.codex_home.clone();
+        tokio::spawn(async move {
+            let older_than = std::time::Duration::from_secs(days.saturating_mul(24 * 60 * 60));
+            match codex_core::RolloutRecorder::prune_archived(&codex_home, older_than).await {
+                Ok(0) => {}
+                Ok(removed) => tracing::info!("Pruned {removed} archived rollout(s)"),
+                Err(e) => tracing::warn!("Failed to prune archived rollouts: {e}"),
+            }
+        });
+    }
+
     // FORK-SPECIFIC (SPEC-945D): Initialize config hot-reload watcher
     let config_watcher = {
         use codex_spec_kit::config::HotReloadWatcher;
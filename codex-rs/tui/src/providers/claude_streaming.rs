@@ -201,10 +201,14 @@ impl ClaudeStreamingProvider {
                 provider: "Claude".to_string(),
                 message: stderr,
             },
-            CliError::Timeout { elapsed } => ProviderError::Provider {
+            CliError::Timeout { elapsed, .. } => ProviderError::Provider {
                 provider: "Claude".to_string(),
                 message: format!("Request timed out after {:?}", elapsed),
             },
+            CliError::IdleTimeout { idle, .. } => ProviderError::Provider {
+                provider: "Claude".to_string(),
+                message: format!("No output for {:?}; CLI presumed hung", idle),
+            },
             CliError::ParseError { details } => ProviderError::Provider {
                 provider: "Claude".to_string(),
                 message: format!("Parse error: {}", details),
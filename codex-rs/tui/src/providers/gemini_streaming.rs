@@ -222,10 +222,14 @@ impl GeminiStreamingProvider {
                 provider: "Gemini".to_string(),
                 message: stderr,
             },
-            CliError::Timeout { elapsed } => ProviderError::Provider {
+            CliError::Timeout { elapsed, .. } => ProviderError::Provider {
                 provider: "Gemini".to_string(),
                 message: format!("Request timed out after {:?}", elapsed),
             },
+            CliError::IdleTimeout { idle, .. } => ProviderError::Provider {
+                provider: "Gemini".to_string(),
+                message: format!("No output for {:?}; CLI presumed hung", idle),
+            },
             CliError::ParseError { details } => ProviderError::Provider {
                 provider: "Gemini".to_string(),
                 message: format!("Parse error: {}", details),
@@ -232,8 +232,7 @@ fn fallback_scan_sessions_for_cwd(cwd: &Path, codex_home: &Path) -> Vec<ResumeCa
 }
 
 fn parse_rollout_candidate(path: &Path, target_cwd: &Path) -> Option<ResumeCandidate> {
-    let file = fs::File::open(path).ok()?;
-    let reader = BufReader::new(file);
+    let reader = codex_core::open_rollout_reader(path).ok()?;
 
     let mut created_ts: Option<String> = None;
     let mut modified_ts: Option<String> = None;
@@ -1,11 +1,19 @@
+use std::collections::HashMap;
 use std::sync::OnceLock;
 
+use codex_core::slash_commands::Deprecation;
+use codex_core::slash_commands::SlashCommandDef;
+use codex_core::slash_commands::SlashMatchKind;
+use codex_core::slash_commands::SlashResolveError;
+use codex_core::slash_commands::resolve_slash_command;
+
 use strum::IntoEnumIterator;
 use strum_macros::AsRefStr;
 use strum_macros::EnumIter;
 use strum_macros::EnumString;
 use strum_macros::IntoStaticStr;
 
+use crate::chatwidget::spec_kit::command_registry::SPEC_KIT_REGISTRY;
 use crate::spec_prompts;
 use crate::spec_prompts::SpecStage;
 use thiserror::Error;
@@ -375,6 +383,82 @@ pub fn built_in_slash_commands() -> Vec<(&'static str, SlashCommand)> {
         .collect()
 }
 
+/// Renamed commands that still run as their replacement, with a notice.
+/// SPEC-KIT-902: legacy /spec-* and /spec-ops-* map onto /speckit.* and /guardrail.*.
+const DEPRECATED_SLASH_COMMANDS: &[SlashCommandDef] = &[
+    SlashCommandDef::deprecated("spec-plan", "speckit.plan"),
+    SlashCommandDef::deprecated("spec-tasks", "speckit.tasks"),
+    SlashCommandDef::deprecated("spec-implement", "speckit.implement"),
+    SlashCommandDef::deprecated("spec-validate", "speckit.validate"),
+    SlashCommandDef::deprecated("spec-audit", "speckit.audit"),
+    SlashCommandDef::deprecated("spec-unlock", "speckit.unlock"),
+    SlashCommandDef::deprecated("spec-auto", "speckit.auto"),
+    SlashCommandDef::deprecated("spec-ops-plan", "guardrail.plan"),
+    SlashCommandDef::deprecated("spec-ops-tasks", "guardrail.tasks"),
+    SlashCommandDef::deprecated("spec-ops-implement", "guardrail.implement"),
+    SlashCommandDef::deprecated("spec-ops-validate", "guardrail.validate"),
+    SlashCommandDef::deprecated("spec-ops-audit", "guardrail.audit"),
+    SlashCommandDef::deprecated("spec-ops-unlock", "guardrail.unlock"),
+    SlashCommandDef::deprecated("spec-ops-auto", "guardrail.auto"),
+];
+
+/// Command text rewritten to its canonical command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalCommandText {
+    pub text: String,
+    pub deprecation: Option<Deprecation>,
+}
+
+/// Rewrite `/name args` so that `name` is the canonical command when it was
+/// typed as a configured alias, an unambiguous prefix, or a deprecated name.
+/// Returns `Ok(None)` when the text is left unchanged (not a command, an
+/// exact name, or no match) and an error when a prefix matches several
+/// commands.
+pub fn canonicalize_command_text(
+    text: &str,
+    aliases: &HashMap<String, String>,
+) -> Result<Option<CanonicalCommandText>, SlashResolveError> {
+    let trimmed = text.trim_start();
+    let Some(body) = trimmed.strip_prefix('/') else {
+        return Ok(None);
+    };
+    let name_len = body.find(char::is_whitespace).unwrap_or(body.len());
+    let (name, rest) = body.split_at(name_len);
+    if name.is_empty() {
+        return Ok(None);
+    }
+
+    let mut commands: Vec<SlashCommandDef> = SlashCommand::iter()
+        .map(|c| SlashCommandDef::new(c.command()))
+        .chain(DEPRECATED_SLASH_COMMANDS.iter().copied())
+        .collect();
+    // Spec-kit commands that only live in the registry (`speckit.pm`,
+    // `stage0.*`, ...) are valid alias targets and prefix matches too.
+    if let Ok(registry) = SPEC_KIT_REGISTRY.lock() {
+        let registry_names: Vec<&'static str> = registry
+            .iter()
+            .flat_map(|cmd| std::iter::once(cmd.name()).chain(cmd.aliases().iter().copied()))
+            .collect();
+        for name in registry_names {
+            if !commands.iter().any(|c| c.name.eq_ignore_ascii_case(name)) {
+                commands.push(SlashCommandDef::new(name));
+            }
+        }
+    }
+    match resolve_slash_command(name, &commands, aliases) {
+        Ok(resolution)
+            if resolution.kind != SlashMatchKind::Exact || resolution.deprecation.is_some() =>
+        {
+            Ok(Some(CanonicalCommandText {
+                text: format!("/{}{rest}", resolution.command),
+                deprecation: resolution.deprecation,
+            }))
+        }
+        Ok(_) | Err(SlashResolveError::Unknown(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct SpecOpsCommand {
     pub display: &'static str,
@@ -659,6 +743,22 @@ fn parse_stage_name(value: &str) -> Option<SpecStage> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn aliases_resolve_to_registry_only_commands() {
+        let aliases = HashMap::from([
+            ("p".to_string(), "speckit.pm".to_string()),
+            ("li".to_string(), "/stage0.librarian".to_string()),
+        ]);
+        let pm = canonicalize_command_text("/p list", &aliases)
+            .unwrap()
+            .expect("alias rewritten");
+        assert_eq!(pm.text, "/speckit.pm list");
+        let librarian = canonicalize_command_text("/li", &aliases)
+            .unwrap()
+            .expect("alias rewritten");
+        assert_eq!(librarian.text, "/stage0.librarian");
+    }
+
     // SPEC-KIT-902: legacy_spec_alias_emits_notice test removed.
     // /spec-plan variant removed; use /speckit.plan instead.
 
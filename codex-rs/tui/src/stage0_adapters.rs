@@ -452,6 +452,31 @@ impl Tier2Client for Tier2HttpAdapter {
             Err(_) => Err(Stage0Error::tier2("Tier2 thread panicked")),
         }
     }
+
+    async fn health_check(&self) -> Result<bool> {
+        // Same runtime isolation as generate_divine_truth: blocking reqwest on
+        // its own thread, with a short timeout so an absent service fails fast.
+        let health_url = format!("{}/health", self.base_url.trim_end_matches('/'));
+
+        let handle = std::thread::spawn(move || {
+            let client = reqwest::blocking::Client::builder()
+                .timeout(Duration::from_secs(2))
+                .build()
+                .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+            match client.get(&health_url).send() {
+                Ok(resp) => Ok(resp.status().is_success()),
+                Err(e) if e.is_timeout() || e.is_connect() => Ok(false),
+                Err(e) => Err(format!("NotebookLM health check failed: {e}")),
+            }
+        });
+
+        match handle.join() {
+            Ok(Ok(healthy)) => Ok(healthy),
+            Ok(Err(e)) => Err(Stage0Error::tier2(e)),
+            Err(_) => Err(Stage0Error::tier2("Tier2 health check thread panicked")),
+        }
+    }
 }
 
 #[async_trait]
@@ -661,6 +686,10 @@ impl Tier2Client for NoopTier2Client {
     ) -> Result<Tier2Response> {
         Err(Stage0Error::tier2("NotebookLM not available (noop client)"))
     }
+
+    async fn health_check(&self) -> Result<bool> {
+        Ok(false)
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
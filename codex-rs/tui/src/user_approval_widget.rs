@@ -6,6 +6,8 @@
 //! UI to Rust using [`ratatui`]. The goal is feature‑parity for the keyboard
 //! driven workflow – a fully‑fledged visual match is not required.
 
+use codex_core::command_risk::CommandRisk;
+use codex_core::parse_command::parse_command_with_risk;
 use codex_core::protocol::Op;
 use codex_core::protocol::ReviewDecision;
 use crossterm::event::KeyCode;
@@ -104,6 +106,10 @@ impl UserApprovalWidget<'_> {
                     ]),
                     Line::from(""),
                 ];
+                if let Some(warning) = exec_risk_warning(command) {
+                    contents.push(warning);
+                    contents.push(Line::from(""));
+                }
                 if let Some(reason) = reason {
                     contents.push(Line::from(reason.clone().italic()));
                     contents.push(Line::from(""));
@@ -561,6 +567,19 @@ fn is_shell_wrapper(shell: &str, flag: &str) -> bool {
     matches!(file_name.as_str(), "bash" | "sh" | "zsh") && matches!(flag, "-lc" | "-c")
 }
 
+/// Warning shown above the options when the command looks destructive.
+fn exec_risk_warning(command: &[String]) -> Option<Line<'static>> {
+    match parse_command_with_risk(command).risk {
+        CommandRisk::Dangerous => Some(Line::from(vec![
+            "⚠ ".fg(crate::colors::warning()),
+            "This command may delete or overwrite data."
+                .fg(crate::colors::warning())
+                .bold(),
+        ])),
+        CommandRisk::Safe | CommandRisk::Caution => None,
+    }
+}
+
 fn hotkey_suffix(key: KeyCode) -> String {
     match key {
         KeyCode::Char(c) => format!(" ({})", c.to_ascii_lowercase()),
@@ -662,6 +681,17 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn dangerous_exec_shows_risk_warning() {
+        let warning = exec_risk_warning(&["bash".into(), "-lc".into(), "rm -rf target".into()])
+            .expect("rm -rf should be flagged");
+        let text: String = warning.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("may delete or overwrite data"));
+
+        assert!(exec_risk_warning(&["ls".into()]).is_none());
+        assert!(exec_risk_warning(&["cargo".into(), "build".into()]).is_none());
+    }
+
     #[test]
     fn prefix_candidate_skips_flags_and_paths() {
         assert_eq!(prefix_candidate(&["git".into(), "status".into()]), None);
@@ -34,6 +34,9 @@ async fn bench_mcp_initialization() {
                 startup_timeout_sec: None,
                 startup_timeout_ms: Some(5000),
                 tool_timeout_sec: None,
+                reconnect_attempts: None,
+                lazy: false,
+                tools: Vec::new(),
             },
         )]);
 
@@ -86,6 +89,9 @@ async fn bench_mcp_search_calls() {
             startup_timeout_sec: None,
             startup_timeout_ms: Some(5000),
             tool_timeout_sec: None,
+            reconnect_attempts: None,
+            lazy: false,
+            tools: Vec::new(),
         },
     )]);
 
@@ -209,6 +215,9 @@ async fn bench_mcp_vs_subprocess() {
             startup_timeout_sec: None,
             startup_timeout_ms: Some(5000),
             tool_timeout_sec: None,
+            reconnect_attempts: None,
+            lazy: false,
+            tools: Vec::new(),
         },
     )]);
 
@@ -20,6 +20,9 @@ async fn is_local_memory_available() -> bool {
             startup_timeout_sec: None,
             startup_timeout_ms: Some(2000),
             tool_timeout_sec: None,
+            reconnect_attempts: None,
+            lazy: false,
+            tools: Vec::new(),
         },
     )]);
 
@@ -41,6 +44,9 @@ async fn test_mcp_connection_initialization() {
             startup_timeout_sec: None,
             startup_timeout_ms: Some(5000),
             tool_timeout_sec: None,
+            reconnect_attempts: None,
+            lazy: false,
+            tools: Vec::new(),
         },
     )]);
 
@@ -99,6 +105,9 @@ async fn test_mcp_retry_logic_handles_delayed_initialization() {
                 startup_timeout_sec: None,
                 startup_timeout_ms: Some(5000),
                 tool_timeout_sec: None,
+                reconnect_attempts: None,
+                lazy: false,
+                tools: Vec::new(),
             },
         )]);
 
@@ -153,6 +162,9 @@ async fn test_mcp_tool_call_format() {
             startup_timeout_sec: None,
             startup_timeout_ms: Some(5000),
             tool_timeout_sec: None,
+            reconnect_attempts: None,
+            lazy: false,
+            tools: Vec::new(),
         },
     )]);
 
@@ -220,6 +232,9 @@ async fn test_full_consensus_workflow_with_mcp() {
             startup_timeout_sec: None,
             startup_timeout_ms: Some(5000),
             tool_timeout_sec: None,
+            reconnect_attempts: None,
+            lazy: false,
+            tools: Vec::new(),
         },
     )]);
 
@@ -234,8 +234,10 @@ fn stage0_result_combined_context_md() {
         memories_used: vec!["mem-1".to_string()],
         cache_hit: false,
         tier2_used: true,
+        tier2_skip_reason: None,
         latency_ms: 100,
         explain_scores: None,
+        iqo: Default::default(),
         constitution_conflicts: None,
         constitution_aligned_ids: vec![],
         product_knowledge_pack: None,
@@ -269,8 +271,10 @@ fn stage0_result_has_context() {
         memories_used: vec!["mem-1".to_string()],
         cache_hit: false,
         tier2_used: false,
+        tier2_skip_reason: None,
         latency_ms: 50,
         explain_scores: None,
+        iqo: Default::default(),
         constitution_conflicts: None,
         constitution_aligned_ids: vec![],
         product_knowledge_pack: None,
@@ -294,8 +298,10 @@ fn stage0_result_has_context() {
         memories_used: vec![],
         cache_hit: false,
         tier2_used: false,
+        tier2_skip_reason: None,
         latency_ms: 0,
         explain_scores: None,
+        iqo: Default::default(),
         constitution_conflicts: None,
         constitution_aligned_ids: vec![],
         product_knowledge_pack: None,
@@ -2622,7 +2622,7 @@ impl ChatWidget {
             } else if preset.id == "auto" {
                 #[cfg(target_os = "windows")]
                 {
-                    if codex_core::get_platform_sandbox().is_none() {
+                    if !codex_core::get_platform_sandbox().is_available() {
                         let preset_clone = preset.clone();
                         vec![Box::new(move |tx| {
                             tx.send(AppEvent::OpenWindowsSandboxEnablePrompt {
@@ -2971,7 +2971,7 @@ impl ChatWidget {
     #[cfg(target_os = "windows")]
     pub(crate) fn maybe_prompt_windows_sandbox_enable(&mut self) {
         if self.config.forced_auto_mode_downgraded_on_windows
-            && codex_core::get_platform_sandbox().is_none()
+            && !codex_core::get_platform_sandbox().is_available()
             && let Some(preset) = builtin_approval_presets()
                 .into_iter()
                 .find(|preset| preset.id == "auto")
@@ -3002,7 +3002,7 @@ impl ChatWidget {
     pub(crate) fn set_sandbox_policy(&mut self, policy: SandboxPolicy) -> ConstraintResult<()> {
         #[cfg(target_os = "windows")]
         let should_clear_downgrade = !matches!(&policy, SandboxPolicy::ReadOnly)
-            || codex_core::get_platform_sandbox().is_some();
+            || codex_core::get_platform_sandbox().is_available();
 
         // NOTE: Fork's sandbox_policy.set() returns (), not Result
         self.config.sandbox_policy.set(policy);
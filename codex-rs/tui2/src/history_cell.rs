@@ -1279,6 +1279,7 @@ impl HistoryCell for PlanUpdateCell {
                 StepStatus::Completed => ("✔ ", Style::default().crossed_out().dim()),
                 StepStatus::InProgress => ("□ ", Style::default().cyan().bold()),
                 StepStatus::Pending => ("□ ", Style::default().dim()),
+                StepStatus::Failed => ("✘ ", Style::default().red().dim()),
             };
             let wrap_width = (width as usize)
                 .saturating_sub(4)
@@ -575,7 +575,7 @@ async fn load_config_or_exit(
 /// Note: Simplified for local fork - trust screen not shown since
 /// the required config fields are not available locally.
 fn should_show_trust_screen(_config: &Config) -> bool {
-    if cfg!(target_os = "windows") && get_platform_sandbox().is_none() {
+    if cfg!(target_os = "windows") && !get_platform_sandbox().is_available() {
         // If the experimental sandbox is not enabled, Native Windows cannot enforce sandboxed write access; skip the trust prompt entirely.
         return false;
     }